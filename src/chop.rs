@@ -17,7 +17,10 @@ enum CharType {
 fn get_chars(subject: &str, start: usize, end: usize) -> String {
     match subject.len() {
         0 => subject.to_string(),
-        _ => crate::split::chars(subject)[start..end].join(""),
+        _ => crate::split::chars_iter(subject)
+            .skip(start)
+            .take(end - start)
+            .collect(),
     }
 }
 
@@ -27,7 +30,10 @@ fn get_subject_length(
     point_type: PointType,
     char_type: CharType,
 ) -> usize {
-    let subject_len = crate::count::count_graphemes(subject);
+    // the graphemes are counted only up to the position, which is enough for the bounds check
+    let subject_len = crate::split::graphemes_iter(subject)
+        .take(position.saturating_add(1))
+        .count();
     let position_substruction = match point_type {
         PointType::Length => 0,
         PointType::Position => 1,
@@ -336,13 +342,15 @@ pub fn foreign_key(subject: &str) -> String {
 /// // => "é"
 /// ```
 pub fn grapheme_at(subject: &str, position: usize) -> String {
-    let subject_len = crate::count::count_graphemes(subject);
-    match subject_len {
+    match subject.len() {
         0 => subject.to_string(),
         _ => {
             let the_position =
                 get_subject_length(subject, position, PointType::Position, CharType::Grapheme);
-            crate::split::graphemes(subject)[the_position].to_string()
+            crate::split::graphemes_iter(subject)
+                .nth(the_position)
+                .unwrap_or("")
+                .to_string()
         }
     }
 }
@@ -371,10 +379,12 @@ pub fn last(subject: &str, length: usize) -> String {
     match length {
         0 => "".to_string(),
         _ => {
-            let subject_length = crate::split::chars(subject).len();
             let the_length =
                 get_subject_length(subject, length, PointType::Length, CharType::Grapheme);
-            get_chars(subject, subject_length - the_length, subject_length)
+            match subject.char_indices().rev().take(the_length).last() {
+                Some((index, _)) => subject[index..].to_string(),
+                None => "".to_string(),
+            }
         }
     }
 }
@@ -460,6 +470,63 @@ pub fn prune(subject: &str, length: usize, end: &str) -> String {
     format!("{}{}", get_chars(subject, 0, position_end), suffix)
 }
 
+/// Truncates `subject` to a new display `width` and does not break the words. Guarantees that the truncated string takes no more than `width` terminal columns.
+/// The display width is measured with `count::display_width`. The `end` string is omitted if it does not fit into `width` itself.
+///
+/// # Arguments
+///
+/// * `subject` - The string to prune.
+/// * `width` - The display width to prune the string.
+/// * `end` - The string to be added at the end. Default value is "...".
+///
+/// # Example
+/// ```
+/// use voca_rs::*;
+/// chop::prune_width("Once upon a time", 7, "");
+/// // => "Once..."
+/// chop::prune_width("東京 大阪 京都", 10, "…");
+/// // => "東京 大阪…"
+/// chop::prune_width("Once upon", 10, "");
+/// // => "Once upon"
+/// use voca_rs::Voca;
+/// "Once upon a time"._prune_width(7, "");
+/// // => "Once..."
+/// ```
+pub fn prune_width(subject: &str, width: usize, end: &str) -> String {
+    if crate::count::display_width(subject) <= width {
+        return subject.to_string();
+    }
+    let (suffix, budget) = width_suffix(end, width);
+    let mut used = 0;
+    let mut position = 0;
+    let mut cut = 0;
+    let mut in_word = false;
+    for grapheme in crate::split::graphemes(subject) {
+        let is_separator = crate::utils::WHITESPACE.contains(grapheme)
+            || crate::utils::PUNCTUATION.contains(grapheme);
+        if is_separator && in_word {
+            cut = position;
+        }
+        in_word = !is_separator;
+        used += crate::count::grapheme_width(grapheme);
+        if used > budget {
+            break;
+        }
+        position += grapheme.len();
+    }
+    format!("{}{}", &subject[..cut], suffix)
+}
+
+fn width_suffix(end: &str, width: usize) -> (&str, usize) {
+    let suffix = if end.is_empty() { "..." } else { end };
+    let suffix_width = crate::count::display_width(suffix);
+    if suffix_width > width {
+        ("", width)
+    } else {
+        (suffix, width - suffix_width)
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum CutType {
     StartsWith,
@@ -712,6 +779,45 @@ pub fn truncate(subject: &str, length: usize, end: &str) -> String {
     format!("{}{}", get_chars(subject, 0, position_end), suffix)
 }
 
+/// Truncates `subject` to a new display `width`. Guarantees that the truncated string takes no more than `width` terminal columns and the graphemes are not split.
+/// The display width is measured with `count::display_width`. The `end` string is omitted if it does not fit into `width` itself.
+///
+/// # Arguments
+///
+/// * `subject` - The string to truncate.
+/// * `width` - The display width to truncate the string.
+/// * `end` - The string to be added at the end. Default value is "...".
+///
+/// # Example
+/// ```
+/// use voca_rs::*;
+/// chop::truncate_width("Once upon a time", 7, "");
+/// // => "Once..."
+/// chop::truncate_width("日本語のテキスト", 9, "…");
+/// // => "日本語の…"
+/// chop::truncate_width("Once upon", 10, "");
+/// // => "Once upon"
+/// use voca_rs::Voca;
+/// "Once upon a time"._truncate_width(7, "");
+/// // => "Once..."
+/// ```
+pub fn truncate_width(subject: &str, width: usize, end: &str) -> String {
+    if crate::count::display_width(subject) <= width {
+        return subject.to_string();
+    }
+    let (suffix, budget) = width_suffix(end, width);
+    let mut used = 0;
+    let mut position = 0;
+    for grapheme in crate::split::graphemes(subject) {
+        used += crate::count::grapheme_width(grapheme);
+        if used > budget {
+            break;
+        }
+        position += grapheme.len();
+    }
+    format!("{}{}", &subject[..position], suffix)
+}
+
 /// Returns the max character from the `subject` by its code point.
 /// NOTE: Unicode escape must not be a surrogate
 ///
@@ -0,0 +1,514 @@
+//! Measures the distance and similarity between `subject` and another string.
+
+use split::{units, Unit};
+use std::collections::HashMap;
+
+/// Calculates the Levenshtein distance between `subject` and `other`: the minimum number of insertions, deletions and substitutions.
+///
+/// # Arguments
+///
+/// * `subject` - The string to measure.
+/// * `other` - The string to measure with.
+/// * `unit` - The unit to measure in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::levenshtein("kitten", "sitting", split::Unit::Char);
+/// // => 3
+/// distance::levenshtein("cafe\u{0301}", "cafe", split::Unit::Char);
+/// // => 1
+/// distance::levenshtein("cafe\u{0301}", "cafe", split::Unit::Grapheme);
+/// // => 1
+/// distance::levenshtein("👨‍👩‍👧", "👨‍👩‍👦", split::Unit::Grapheme);
+/// // => 1
+/// use voca_rs::Voca;
+/// "kitten"._levenshtein("sitting", split::Unit::Char);
+/// // => 3
+/// ```
+pub fn levenshtein(subject: &str, other: &str, unit: Unit) -> usize {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_unit) in a.iter().enumerate() {
+        let mut previous_diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_unit) in b.iter().enumerate() {
+            let substitution = previous_diagonal + usize::from(a_unit != b_unit);
+            previous_diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Calculates the Damerau-Levenshtein distance between `subject` and `other`: like Levenshtein distance, but a transposition of two adjacent units counts as a single edit.
+///
+/// # Arguments
+///
+/// * `subject` - The string to measure.
+/// * `other` - The string to measure with.
+/// * `unit` - The unit to measure in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::damerau_levenshtein("ca", "abc", split::Unit::Char);
+/// // => 2
+/// distance::damerau_levenshtein("teh", "the", split::Unit::Char);
+/// // => 1
+/// use voca_rs::Voca;
+/// "teh"._damerau_levenshtein("the", split::Unit::Char);
+/// // => 1
+/// ```
+pub fn damerau_levenshtein(subject: &str, other: &str, unit: Unit) -> usize {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+    // https://en.wikipedia.org/wiki/Damerau%E2%80%93Levenshtein_distance#Distance_with_adjacent_transpositions
+    let max_distance = a.len() + b.len();
+    let width = b.len() + 2;
+    let mut d = vec![0; (a.len() + 2) * width];
+    d[0] = max_distance;
+    for i in 0..=a.len() {
+        d[(i + 1) * width] = max_distance;
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b.len() {
+        d[j + 1] = max_distance;
+        d[width + j + 1] = j;
+    }
+    let mut last_row: HashMap<&str, usize> = HashMap::new();
+    for i in 1..=a.len() {
+        let mut last_match_column = 0;
+        for j in 1..=b.len() {
+            let k = *last_row.get(b[j - 1]).unwrap_or(&0);
+            let l = last_match_column;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_column = j;
+                0
+            } else {
+                1
+            };
+            d[(i + 1) * width + j + 1] = (d[i * width + j] + cost)
+                .min(d[(i + 1) * width + j] + 1)
+                .min(d[i * width + j + 1] + 1)
+                .min(d[k * width + l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    d[(a.len() + 1) * width + b.len() + 1]
+}
+
+/// Calculates the Hamming distance between `subject` and `other`: the number of positions at which the units are different.
+/// Returns `None` if the strings have different lengths.
+///
+/// # Arguments
+///
+/// * `subject` - The string to measure.
+/// * `other` - The string to measure with.
+/// * `unit` - The unit to measure in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::hamming("karolin", "kathrin", split::Unit::Char);
+/// // => Some(3)
+/// distance::hamming("łódź", "lodz", split::Unit::Char);
+/// // => Some(3)
+/// distance::hamming("abc", "ab", split::Unit::Char);
+/// // => None
+/// use voca_rs::Voca;
+/// "karolin"._hamming("kathrin", split::Unit::Char);
+/// // => Some(3)
+/// ```
+pub fn hamming(subject: &str, other: &str, unit: Unit) -> Option<usize> {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    if a.len() != b.len() {
+        return None;
+    }
+    Some(a.iter().zip(b.iter()).filter(|(x, y)| x != y).count())
+}
+
+/// Calculates the Jaro similarity between `subject` and `other`. The result is between 0.0 (no similarity) and 1.0 (equal strings).
+///
+/// # Arguments
+///
+/// * `subject` - The string to measure.
+/// * `other` - The string to measure with.
+/// * `unit` - The unit to measure in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::jaro("martha", "marhta", split::Unit::Char);
+/// // => 0.9444444444444445
+/// distance::jaro("abc", "xyz", split::Unit::Char);
+/// // => 0.0
+/// use voca_rs::Voca;
+/// "martha"._jaro("marhta", split::Unit::Char);
+/// // => 0.9444444444444445
+/// ```
+pub fn jaro(subject: &str, other: &str, unit: Unit) -> f64 {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    jaro_units(&a, &b)
+}
+
+fn jaro_units(a: &[&str], b: &[&str]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let match_distance = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matches = vec![false; a.len()];
+    let mut b_matches = vec![false; b.len()];
+    let mut matches = 0;
+    for (i, a_unit) in a.iter().enumerate() {
+        let start = i.saturating_sub(match_distance);
+        let end = (i + match_distance + 1).min(b.len());
+        for j in start..end {
+            if !b_matches[j] && a_unit == &b[j] {
+                a_matches[i] = true;
+                b_matches[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+    let a_matched = a.iter().zip(a_matches.iter()).filter(|(_, m)| **m);
+    let b_matched = b.iter().zip(b_matches.iter()).filter(|(_, m)| **m);
+    let transpositions = a_matched
+        .zip(b_matched)
+        .filter(|((x, _), (y, _))| x != y)
+        .count()
+        / 2;
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Calculates the Jaro-Winkler similarity between `subject` and `other`. Like Jaro similarity, but gives a higher score to strings with a common prefix.
+/// The result is between 0.0 (no similarity) and 1.0 (equal strings).
+///
+/// # Arguments
+///
+/// * `subject` - The string to measure.
+/// * `other` - The string to measure with.
+/// * `unit` - The unit to measure in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::jaro_winkler("martha", "marhta", split::Unit::Char);
+/// // => 0.9611111111111111
+/// distance::jaro_winkler("dixon", "dicksonx", split::Unit::Char);
+/// // => 0.8133333333333332
+/// use voca_rs::Voca;
+/// "martha"._jaro_winkler("marhta", split::Unit::Char);
+/// // => 0.9611111111111111
+/// ```
+pub fn jaro_winkler(subject: &str, other: &str, unit: Unit) -> f64 {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    let similarity = jaro_units(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(b.iter())
+        .take(4)
+        .take_while(|(x, y)| x == y)
+        .count();
+    similarity + prefix as f64 * 0.1 * (1.0 - similarity)
+}
+
+/// Returns the longest common subsequence of `subject` and `other`, i.e. the longest sequence of units which appear in both strings in the same order.
+///
+/// # Arguments
+///
+/// * `subject` - The string to compare.
+/// * `other` - The string to compare with.
+/// * `unit` - The unit to compare in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`. The words of the result are joined by a space.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::longest_common_subsequence("ABCBDAB", "BDCABA", split::Unit::Char);
+/// // => "BDAB"
+/// distance::longest_common_subsequence("Zażółć gęślą", "Zazolc gesla", split::Unit::Char);
+/// // => "Za gl"
+/// use voca_rs::Voca;
+/// "ABCBDAB"._longest_common_subsequence("BDCABA", split::Unit::Char);
+/// // => "BDAB"
+/// ```
+pub fn longest_common_subsequence(subject: &str, other: &str, unit: Unit) -> String {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    let width = b.len() + 1;
+    let mut lengths = vec![0usize; (a.len() + 1) * width];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lengths[i * width + j] = if a[i] == b[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let mut result = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            result.push(a[i]);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    join_units(&result, unit)
+}
+
+/// Returns the longest common substring of `subject` and `other`. If there are several, returns the first one found in `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to compare.
+/// * `other` - The string to compare with.
+/// * `unit` - The unit to compare in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`. The words of the result are joined by a space.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::longest_common_substring("The quick brown fox", "a quick brown dog", split::Unit::Char);
+/// // => " quick brown "
+/// distance::longest_common_substring("abc", "xyz", split::Unit::Char);
+/// // => ""
+/// use voca_rs::Voca;
+/// "The quick brown fox"._longest_common_substring("a quick brown dog", split::Unit::Char);
+/// // => " quick brown "
+/// ```
+pub fn longest_common_substring(subject: &str, other: &str, unit: Unit) -> String {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    let mut row = vec![0usize; b.len() + 1];
+    let mut longest = 0;
+    let mut longest_end = 0;
+    for (i, a_unit) in a.iter().enumerate() {
+        for (j, b_unit) in b.iter().enumerate().rev() {
+            row[j + 1] = if a_unit == b_unit { row[j] + 1 } else { 0 };
+            if row[j + 1] > longest {
+                longest = row[j + 1];
+                longest_end = i + 1;
+            }
+        }
+    }
+    join_units(&a[longest_end - longest..longest_end], unit)
+}
+
+/// Joins `units` back into a string, the words are separated by a space.
+fn join_units(units: &[&str], unit: Unit) -> String {
+    match unit {
+        Unit::Word => units.join(" "),
+        _ => units.concat(),
+    }
+}
+
+/// Calculates the Sørensen-Dice similarity between `subject` and `other` using bigrams of units.
+/// The result is between 0.0 (no common bigrams) and 1.0 (equal strings).
+///
+/// # Arguments
+///
+/// * `subject` - The string to measure.
+/// * `other` - The string to measure with.
+/// * `unit` - The unit to measure in: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::sorensen_dice("night", "nacht", split::Unit::Char);
+/// // => 0.25
+/// distance::sorensen_dice("Jürgen", "Jürgen", split::Unit::Grapheme);
+/// // => 1.0
+/// use voca_rs::Voca;
+/// "night"._sorensen_dice("nacht", split::Unit::Char);
+/// // => 0.25
+/// ```
+pub fn sorensen_dice(subject: &str, other: &str, unit: Unit) -> f64 {
+    let a = units(subject, unit);
+    let b = units(other, unit);
+    if a == b {
+        return 1.0;
+    }
+    if a.len() < 2 || b.len() < 2 {
+        return 0.0;
+    }
+    let mut bigrams: HashMap<(&str, &str), usize> = HashMap::new();
+    for pair in a.windows(2) {
+        *bigrams.entry((pair[0], pair[1])).or_insert(0) += 1;
+    }
+    let mut intersection = 0;
+    for pair in b.windows(2) {
+        if let Some(count) = bigrams.get_mut(&(pair[0], pair[1])) {
+            if *count > 0 {
+                *count -= 1;
+                intersection += 1;
+            }
+        }
+    }
+    2.0 * intersection as f64 / (a.len() + b.len() - 2) as f64
+}
+
+/// Options for `distance::suggest_with`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SuggestOptions {
+    /// The minimum score (between 0.0 and 1.0) a candidate needs to be suggested. Default value is 0.5.
+    pub min_score: f64,
+    /// The maximum Damerau-Levenshtein distance (in graphemes) between the input and a candidate,
+    /// unless the input is a prefix or a subsequence of the candidate. Default value is `None`, i.e. no limit.
+    pub max_distance: Option<usize>,
+    /// The options which define how the input and candidates are compared. Ignores case, diacritics and width by default.
+    pub match_options: crate::utils::MatchOptions,
+}
+
+impl Default for SuggestOptions {
+    fn default() -> SuggestOptions {
+        SuggestOptions {
+            min_score: 0.5,
+            max_distance: None,
+            match_options: crate::utils::MatchOptions::insensitive(),
+        }
+    }
+}
+
+/// Suggests the best matches for `input` from `candidates` ("did you mean ...?"). Returns at most `max` candidates with their scores, the best match first.
+/// Candidates are ranked by the combination of the edit distance, the common prefix and the subsequence match. The comparison ignores case and diacritics.
+///
+/// # Arguments
+///
+/// * `input` - The string to find suggestions for.
+/// * `candidates` - The strings to choose from.
+/// * `max` - The maximum number of suggestions.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// distance::suggest("stauts", &["status", "stash", "commit", "checkout"], 1);
+/// // => [("status", 0.625)]
+/// distance::suggest("CHEKOUT", &["status", "stash", "commit", "checkout"], 3);
+/// // => [("checkout", 0.7821428571428571)]
+/// distance::suggest("zzz", &["status", "stash"], 3);
+/// // => []
+/// use voca_rs::Voca;
+/// "stauts"._suggest(&["status", "stash", "commit", "checkout"], 1);
+/// // => [("status", 0.625)]
+/// ```
+pub fn suggest<'a>(input: &str, candidates: &[&'a str], max: usize) -> Vec<(&'a str, f64)> {
+    suggest_with(input, candidates, max, &SuggestOptions::default())
+}
+
+/// Suggests the best matches for `input` from `candidates` using the given `options`. See `distance::suggest`.
+///
+/// # Arguments
+///
+/// * `input` - The string to find suggestions for.
+/// * `candidates` - The strings to choose from.
+/// * `max` - The maximum number of suggestions.
+/// * `options` - The thresholds and the comparison options.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let options = distance::SuggestOptions {
+///     min_score: 0.3,
+///     max_distance: Some(2),
+///     ..Default::default()
+/// };
+/// distance::suggest_with("colour", &["color", "column", "cooler"], 3, &options);
+/// // => [("color", 0.6666666666666666), ("cooler", 0.48333333333333334)]
+/// ```
+pub fn suggest_with<'a>(
+    input: &str,
+    candidates: &[&'a str],
+    max: usize,
+    options: &SuggestOptions,
+) -> Vec<(&'a str, f64)> {
+    let folded_input = crate::utils::fold(input, &options.match_options);
+    if folded_input.is_empty() || max == 0 {
+        return vec![];
+    }
+    let mut suggestions: Vec<(&'a str, f64, usize)> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let folded_candidate = crate::utils::fold(candidate, &options.match_options);
+            let (score, distance) = suggestion_score(&folded_input, &folded_candidate);
+            let is_partial_match = folded_candidate.starts_with(&folded_input)
+                || crate::query::query(&folded_candidate, &folded_input, 0);
+            let within_distance = match options.max_distance {
+                Some(max_distance) => distance <= max_distance || is_partial_match,
+                None => true,
+            };
+            if score >= options.min_score && within_distance {
+                Some((*candidate, score, distance))
+            } else {
+                None
+            }
+        })
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.2.cmp(&b.2))
+    });
+    suggestions
+        .into_iter()
+        .take(max)
+        .map(|(candidate, score, _)| (candidate, score))
+        .collect()
+}
+
+fn suggestion_score(input: &str, candidate: &str) -> (f64, usize) {
+    let distance = damerau_levenshtein(input, candidate, Unit::Grapheme);
+    if distance == 0 {
+        return (1.0, 0);
+    }
+    let input_len = crate::count::count_graphemes(input);
+    let candidate_len = crate::count::count_graphemes(candidate);
+    let similarity = 1.0 - distance as f64 / input_len.max(candidate_len) as f64;
+    let common_prefix = units(input, Unit::Grapheme)
+        .iter()
+        .zip(units(candidate, Unit::Grapheme).iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let prefix = common_prefix as f64 / input_len as f64;
+    let subsequence = if crate::query::query(candidate, input, 0) {
+        1.0
+    } else {
+        0.0
+    };
+    (
+        0.6 * similarity + 0.25 * prefix + 0.15 * subsequence,
+        distance,
+    )
+}
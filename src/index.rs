@@ -83,6 +83,54 @@ pub fn index_of(subject: &str, search: &str, from_index: usize) -> i8 {
     }
 }
 
+/// Returns the first occurrence index of `search` in `subject` or -1 if not found, comparing the strings according to `options`.
+///
+/// # Arguments
+///
+/// * `subject` - The string where to search.
+/// * `search` - The string to search.
+/// * `from_index` - The index to start searching
+/// * `options` - The options which define how the strings are compared.
+///
+/// # Example
+/// ```
+/// use voca_rs::*;
+/// let options = utils::MatchOptions::insensitive();
+/// index::index_of_folded("Morning", "MORN", 0, &options);
+/// // => 0
+/// index::index_of_folded("Zażółć gęślą jaźń", "GESLA", 0, &options);
+/// // => 7
+/// index::index_of_folded("evening", "o", 0, &options);
+/// // => -1
+/// use voca_rs::Voca;
+/// "Zażółć gęślą jaźń"._index_of_folded("GESLA", 0, &options);
+/// // => 7
+/// ```
+pub fn index_of_folded(
+    subject: &str,
+    search: &str,
+    from_index: usize,
+    options: &crate::utils::MatchOptions,
+) -> i8 {
+    match search.len() {
+        0 => 0,
+        _ => {
+            if crate::count::count(subject) < from_index {
+                return -1;
+            }
+            let string_slice = &subject[subject
+                .char_indices()
+                .nth(from_index)
+                .map_or(subject.len(), |(i, _)| i)..];
+            let (folded, offsets) = crate::utils::fold_with_offsets(string_slice, options);
+            match folded.find(&crate::utils::fold(search, options)) {
+                Some(x) => string_slice[..offsets[x].0].chars().count() as i8,
+                None => -1,
+            }
+        }
+    }
+}
+
 /// Returns the last occurrence index of `search` in `subject` or -1 if not found. Case sensitive.
 ///
 /// # Arguments
@@ -165,3 +213,35 @@ pub fn search(subject: &str, pattern: &str, from_index: usize) -> i8 {
         }
     }
 }
+
+/// Returns the index of the first unit (character, grapheme or word) of `subject` for which `predicate` returns true, or `None` if there is no such unit.
+///
+/// # Arguments
+///
+/// * `subject` - The string where to search.
+/// * `unit` - The unit to iterate: `Unit::Char`, `Unit::Grapheme` or `Unit::Word`.
+/// * `predicate` - The predicate function or closure invoked on each unit with a parameter `(string)`.
+///
+/// # Example
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::split::Unit;
+/// index::find_where("hello 2 you", Unit::Char, query::is_digit);
+/// // => Some(6)
+/// index::find_where("cafe\u{0301}!", Unit::Grapheme, |g| g == "e\u{0301}");
+/// // => Some(3)
+/// index::find_where("the quick brown fox", Unit::Word, |w| w.len() > 4);
+/// // => Some(1)
+/// use voca_rs::Voca;
+/// "hello 2 you"._find_where(Unit::Char, &query::is_digit);
+/// // => Some(6)
+/// ```
+pub fn find_where(
+    subject: &str,
+    unit: crate::split::Unit,
+    predicate: impl Fn(&str) -> bool,
+) -> Option<usize> {
+    crate::split::units(subject, unit)
+        .into_iter()
+        .position(predicate)
+}
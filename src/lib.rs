@@ -40,6 +40,7 @@
 
 extern crate regex;
 extern crate stfu8;
+extern crate unicode_normalization;
 extern crate unicode_segmentation;
 
 // #[macro_use]
@@ -47,11 +48,17 @@ extern crate unicode_segmentation;
 
 pub mod case;
 pub mod chop;
+pub mod compare;
 pub mod count;
+pub mod distance;
 pub mod escape;
 pub mod index;
 pub mod manipulate;
+pub mod parse;
+pub mod phonetic;
 pub mod query;
+pub mod readability;
+pub mod security;
 pub mod split;
 pub mod strip;
 pub mod utils;
@@ -86,22 +93,48 @@ pub trait Voca {
     fn _grapheme_at(&self, param: usize) -> String;
     fn _last(&self, param: usize) -> String;
     fn _prune(&self, param1: usize, param2: &str) -> String;
+    fn _prune_width(&self, param1: usize, param2: &str) -> String;
     fn _removeprefix(&self, param2: &str) -> String;
     fn _removesuffix(&self, param2: &str) -> String;
     fn _slice(&self, param1: isize, param2: isize) -> String;
     fn _substr(&self, param1: usize, param2: usize) -> String;
     fn _substring(&self, param1: usize, param2: usize) -> String;
     fn _truncate(&self, param1: usize, param2: &str) -> String;
+    fn _truncate_width(&self, param1: usize, param2: &str) -> String;
     fn _limit_words(&self, param1: usize, param2: &str) -> String;
     fn _max_code_point(&self) -> String;
     fn _min_code_point(&self) -> String;
+    // compare
+    fn _compare_case_insensitive(&self, param1: &str) -> std::cmp::Ordering;
+    fn _compare_collate(&self, param1: &str) -> std::cmp::Ordering;
+    fn _compare_ignore_diacritics(&self, param1: &str) -> std::cmp::Ordering;
+    fn _compare_natural(&self, param1: &str) -> std::cmp::Ordering;
     // count
     fn _count(&self) -> usize;
     fn _count_graphemes(&self) -> usize;
+    fn _count_emoji(&self) -> usize;
     fn _count_substrings(&self, param1: &str) -> usize;
+    fn _count_substrings_folded(&self, param1: &str, param2: &utils::MatchOptions) -> usize;
     fn _count_where(&self, param2: fn(&str) -> bool) -> usize;
+    fn _count_units_where(&self, param1: split::Unit, param2: &dyn Fn(&str) -> bool) -> usize;
     fn _count_words(&self, param1: &str) -> usize;
+    fn _count_words_cjk(&self) -> usize;
     fn _count_unique_words(&self, param1: &str) -> usize;
+    fn _word_frequencies(&self, param1: &count::WordFrequencyOptions) -> Vec<(String, usize)>;
+    fn _char_frequencies(&self) -> Vec<(&str, usize)>;
+    fn _text_stats(&self) -> count::TextStats;
+    fn _reading_time(&self, param1: usize, param2: usize) -> std::time::Duration;
+    fn _display_width(&self) -> usize;
+    // distance
+    fn _damerau_levenshtein(&self, param1: &str, param2: split::Unit) -> usize;
+    fn _hamming(&self, param1: &str, param2: split::Unit) -> Option<usize>;
+    fn _jaro(&self, param1: &str, param2: split::Unit) -> f64;
+    fn _jaro_winkler(&self, param1: &str, param2: split::Unit) -> f64;
+    fn _levenshtein(&self, param1: &str, param2: split::Unit) -> usize;
+    fn _longest_common_subsequence(&self, param1: &str, param2: split::Unit) -> String;
+    fn _longest_common_substring(&self, param1: &str, param2: split::Unit) -> String;
+    fn _sorensen_dice(&self, param1: &str, param2: split::Unit) -> f64;
+    fn _suggest<'a>(&self, param1: &[&'a str], param2: usize) -> Vec<(&'a str, f64)>;
     // escape
     fn _escape_html(&self) -> String;
     fn _escape_regexp(&self) -> String;
@@ -109,20 +142,33 @@ pub trait Voca {
     // index
     fn _index_all(&self, param1: &str, param2: usize) -> Vec<usize>;
     fn _index_of(&self, param1: &str, param2: usize) -> i8;
+    fn _index_of_folded(&self, param1: &str, param2: usize, param3: &utils::MatchOptions) -> i8;
     fn _last_index_of(&self, param1: &str, param2: usize) -> i8;
     fn _search(&self, param1: &str, param2: usize) -> i8;
+    fn _find_where(&self, param1: split::Unit, param2: &dyn Fn(&str) -> bool) -> Option<usize>;
     // manipulate
     fn _expand_tabs(&self, param1: usize) -> String;
     fn _expand_spaces(&self, param1: usize) -> String;
     fn _finish(&self, param1: &str) -> String;
+    fn _fill(&self, param1: usize) -> String;
+    fn _fill_with(&self, param1: &manipulate::FillOptions) -> String;
     fn _insert(&self, param1: &str, param2: usize) -> String;
     fn _latinise(&self) -> String;
     fn _pad(&self, param1: usize, param2: &str) -> String;
+    fn _pad_width(&self, param1: usize, param2: &str) -> String;
     fn _pad_left(&self, param1: usize, param2: &str) -> String;
+    fn _pad_left_width(&self, param1: usize, param2: &str) -> String;
     fn _pad_right(&self, param1: usize, param2: &str) -> String;
+    fn _pad_right_width(&self, param1: usize, param2: &str) -> String;
     fn _repeat(&self, param1: usize) -> String;
     fn _replace(&self, param1: &str, param2: &str) -> String;
     fn _replace_all(&self, param1: &str, param2: &str) -> String;
+    fn _replace_all_folded(
+        &self,
+        param1: &str,
+        param2: &str,
+        param3: &utils::MatchOptions,
+    ) -> String;
     fn _reverse(&self) -> String;
     fn _reverse_grapheme(&self) -> String;
     fn _slugify(&self) -> String;
@@ -134,10 +180,39 @@ pub trait Voca {
     fn _zfill(&self, param1: usize) -> String;
     fn _tr(&self, param1: &str, param2: &str) -> String;
     fn _word_wrap(&self, param1: usize, param2: &str, param3: &str) -> String;
+    fn _word_wrap_width(&self, param1: usize, param2: &str, param3: &str) -> String;
+    fn _word_wrap_with(&self, param1: &manipulate::WrapOptions) -> String;
+    fn _dedent(&self) -> String;
+    fn _indent(&self, param1: &str, param2: Option<&dyn Fn(&str) -> bool>) -> String;
+    fn _normalize_line_endings(&self, param1: split::LineEnding) -> String;
+    fn _bidi_isolate(&self) -> String;
+    fn _shortcodes_to_emoji(&self) -> String;
+    fn _emoji_to_shortcodes(&self) -> String;
+    // parse
+    fn _parse_number(&self) -> Option<parse::Number>;
+    fn _parse_number_with(&self, param1: &parse::NumberOptions) -> Option<parse::Number>;
+    // phonetic
+    fn _caverphone(&self) -> String;
+    fn _cologne(&self) -> String;
+    fn _double_metaphone(&self) -> (String, String);
+    fn _metaphone(&self) -> String;
+    fn _nysiis(&self) -> String;
+    fn _refined_soundex(&self) -> String;
+    fn _soundex(&self) -> String;
+    // readability
+    fn _automated_readability_index(&self) -> f64;
+    fn _coleman_liau(&self) -> f64;
+    fn _flesch_kincaid_grade(&self) -> f64;
+    fn _flesch_reading_ease(&self) -> f64;
+    fn _gunning_fog(&self) -> f64;
+    fn _smog(&self) -> f64;
+    fn _syllables(&self) -> usize;
     // query
     fn _is_foreign_key(&self) -> bool;
     fn _ends_with(&self, param1: &str) -> bool;
+    fn _ends_with_folded(&self, param1: &str, param2: &utils::MatchOptions) -> bool;
     fn _includes(&self, param1: &str, param2: usize) -> bool;
+    fn _includes_folded(&self, param1: &str, param2: usize, param3: &utils::MatchOptions) -> bool;
     fn _is_alpha(&self) -> bool;
     fn _is_alphadigit(&self) -> bool;
     fn _is_blank(&self) -> bool;
@@ -146,10 +221,12 @@ pub trait Voca {
     fn _is_decapitalize(&self) -> bool;
     fn _is_digit(&self) -> bool;
     fn _is_empty(&self) -> bool;
+    fn _is_emoji(&self) -> bool;
     fn _is_lowercase(&self) -> bool;
     fn _is_lower_first(&self) -> bool;
     fn _is_kebab_case(&self) -> bool;
     fn _is_numeric(&self) -> bool;
+    fn _is_numeric_with(&self, param1: &parse::NumberOptions) -> bool;
     fn _is_pascal_case(&self) -> bool;
     fn _is_shouty_kebab_case(&self) -> bool;
     fn _is_snake_case(&self) -> bool;
@@ -160,16 +237,62 @@ pub trait Voca {
     fn _is_upper_first(&self) -> bool;
     fn _matches(&self, param1: &str, param2: usize) -> bool;
     fn _query(&self, param1: &str, param2: usize) -> bool;
+    fn _fuzzy_match(&self, param1: &str, param2: split::Unit) -> Option<query::FuzzyMatch>;
+    fn _has_mixed_line_endings(&self) -> bool;
+    fn _line_ending(&self) -> Option<split::LineEnding>;
+    fn _scripts(&self) -> Vec<query::Script>;
+    fn _dominant_script(&self) -> Option<query::Script>;
+    fn _is_single_script(&self) -> bool;
+    fn _filter_by_category(&self, param1: &[query::GeneralCategory]) -> String;
+    fn _has_bidi_controls(&self) -> bool;
+    fn _base_direction(&self) -> Option<query::Direction>;
     fn _starts_with(&self, param1: &str) -> bool;
+    fn _starts_with_folded(&self, param1: &str, param2: &utils::MatchOptions) -> bool;
+    // security
+    fn _is_confusable(&self, param1: &str) -> bool;
+    fn _is_mixed_script(&self) -> bool;
+    fn _restriction_level(&self) -> security::RestrictionLevel;
+    fn _skeleton(&self) -> String;
     // split
     fn _chars(&self) -> Vec<&str>;
+    fn _chars_iter(&self) -> Box<dyn DoubleEndedIterator<Item = &str> + '_>;
     fn _split(&self, param1: &str) -> Vec<&str>;
+    fn _split_iter<'a>(&'a self, param1: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a>;
+    fn _splitn(&self, param1: &str, param2: usize) -> Vec<&str>;
+    fn _rsplitn(&self, param1: &str, param2: usize) -> Vec<&str>;
+    fn _split_regex(&self, param1: &str) -> Vec<&str>;
+    fn _split_regex_with(&self, param1: &str, param2: &split::SplitRegexOptions) -> Vec<&str>;
+    fn _split_quoted(
+        &self,
+        param1: &split::SplitOptions,
+    ) -> Result<Vec<std::borrow::Cow<'_, str>>, split::SplitError>;
+    fn _shell_words(&self) -> Result<Vec<std::borrow::Cow<'_, str>>, split::SplitError>;
     fn _words(&self) -> Vec<&str>;
+    fn _words_iter(&self) -> Box<dyn DoubleEndedIterator<Item = &str> + '_>;
+    fn _word_bounds(&self) -> Vec<split::WordBound<'_>>;
+    fn _sentences(&self) -> Vec<&str>;
     fn _graphemes(&self) -> Vec<&str>;
+    fn _graphemes_iter(&self) -> Box<dyn DoubleEndedIterator<Item = &str> + '_>;
+    fn _emoji(&self) -> Vec<(usize, &str)>;
+    fn _chunk(&self, param1: usize) -> Vec<&str>;
+    fn _partition(&self, param1: &str) -> (&str, &str, &str);
+    fn _rpartition(&self, param1: &str) -> (&str, &str, &str);
+    fn _split_at_grapheme(&self, param1: usize) -> (&str, &str);
+    fn _split_at_width(&self, param1: usize) -> (&str, &str);
+    fn _char_ngrams(&self, param1: usize, param2: &str) -> Vec<String>;
+    fn _word_ngrams(&self, param1: usize) -> Vec<String>;
+    fn _filter_where(&self, param1: split::Unit, param2: &dyn Fn(&str) -> bool) -> Vec<&str>;
+    fn _shingles(&self, param1: usize) -> std::collections::HashSet<String>;
+    fn _line_breaks(&self) -> Vec<(usize, bool)>;
+    fn _lines(&self, param1: bool) -> Vec<&str>;
     fn _code_points(&self) -> Vec<u16>;
     // strip
     fn _strip_bom(&self) -> String;
     fn _strip_tags(&self) -> String;
+    fn _strip_trailing_whitespace(&self) -> String;
+    fn _strip_bidi_controls(&self) -> String;
+    fn _strip_emoji(&self) -> String;
+    fn _strip_skin_tones(&self) -> String;
 }
 
 macro_rules! implement_string_for {
@@ -256,6 +379,9 @@ macro_rules! implement_string_for {
                 fn _prune(&self, param1: usize, param2: &str) -> String {
                     chop::prune(&self, param1, param2)
                 }
+                fn _prune_width(&self, param1: usize, param2: &str) -> String {
+                    chop::prune_width(&self, param1, param2)
+                }
                 fn _removeprefix(&self, param2: &str) -> String {
                     chop::removeprefix(&self, param2)
                 }
@@ -274,6 +400,9 @@ macro_rules! implement_string_for {
                 fn _truncate(&self, param1: usize, param2: &str) -> String {
                     chop::truncate(&self, param1, param2)
                 }
+                fn _truncate_width(&self, param1: usize, param2: &str) -> String {
+                    chop::truncate_width(&self, param1, param2)
+                }
                 fn _limit_words(&self, param1: usize, param2: &str) -> String {
                     chop::limit_words(&self, param1, param2)
                 }
@@ -283,6 +412,19 @@ macro_rules! implement_string_for {
                 fn _min_code_point(&self) -> String {
                     chop::min(&self)
                 }
+                // compare
+                fn _compare_case_insensitive(&self, param1: &str) -> std::cmp::Ordering {
+                    compare::case_insensitive(&self, param1)
+                }
+                fn _compare_collate(&self, param1: &str) -> std::cmp::Ordering {
+                    compare::collate(&self, param1)
+                }
+                fn _compare_ignore_diacritics(&self, param1: &str) -> std::cmp::Ordering {
+                    compare::ignore_diacritics(&self, param1)
+                }
+                fn _compare_natural(&self, param1: &str) -> std::cmp::Ordering {
+                    compare::natural(&self, param1)
+                }
                 // count
                 fn _count(&self) -> usize {
                     count::count(&self)
@@ -290,18 +432,73 @@ macro_rules! implement_string_for {
                 fn _count_graphemes(&self) -> usize {
                     count::count_graphemes(&self)
                 }
+                fn _count_emoji(&self) -> usize {
+                    count::count_emoji(&self)
+                }
                 fn _count_substrings(&self, param1: &str) -> usize {
                     count::count_substrings(&self, param1)
                 }
+                fn _count_substrings_folded(&self, param1: &str, param2: &utils::MatchOptions) -> usize {
+                    count::count_substrings_folded(&self, param1, param2)
+                }
                 fn _count_where(&self, param1: fn(&str) -> bool) -> usize {
                     count::count_where(&self, param1)
                 }
+                fn _count_units_where(&self, param1: split::Unit, param2: &dyn Fn(&str) -> bool) -> usize {
+                    count::count_units_where(&self, param1, param2)
+                }
                 fn _count_words(&self, param1: &str) -> usize {
                     count::count_words(&self, param1)
                 }
+                fn _count_words_cjk(&self) -> usize {
+                    count::count_words_cjk(&self)
+                }
                 fn _count_unique_words(&self, param1: &str) -> usize {
                     count::count_unique_words(&self, param1)
                 }
+                fn _word_frequencies(&self, param1: &count::WordFrequencyOptions) -> Vec<(String, usize)> {
+                    count::word_frequencies(&self, param1)
+                }
+                fn _char_frequencies(&self) -> Vec<(&str, usize)> {
+                    count::char_frequencies(&self)
+                }
+                fn _text_stats(&self) -> count::TextStats {
+                    count::text_stats(&self)
+                }
+                fn _reading_time(&self, param1: usize, param2: usize) -> std::time::Duration {
+                    count::reading_time(&self, param1, param2)
+                }
+                fn _display_width(&self) -> usize {
+                    count::display_width(&self)
+                }
+                // distance
+                fn _damerau_levenshtein(&self, param1: &str, param2: split::Unit) -> usize {
+                    distance::damerau_levenshtein(&self, param1, param2)
+                }
+                fn _hamming(&self, param1: &str, param2: split::Unit) -> Option<usize> {
+                    distance::hamming(&self, param1, param2)
+                }
+                fn _jaro(&self, param1: &str, param2: split::Unit) -> f64 {
+                    distance::jaro(&self, param1, param2)
+                }
+                fn _jaro_winkler(&self, param1: &str, param2: split::Unit) -> f64 {
+                    distance::jaro_winkler(&self, param1, param2)
+                }
+                fn _levenshtein(&self, param1: &str, param2: split::Unit) -> usize {
+                    distance::levenshtein(&self, param1, param2)
+                }
+                fn _longest_common_subsequence(&self, param1: &str, param2: split::Unit) -> String {
+                    distance::longest_common_subsequence(&self, param1, param2)
+                }
+                fn _longest_common_substring(&self, param1: &str, param2: split::Unit) -> String {
+                    distance::longest_common_substring(&self, param1, param2)
+                }
+                fn _sorensen_dice(&self, param1: &str, param2: split::Unit) -> f64 {
+                    distance::sorensen_dice(&self, param1, param2)
+                }
+                fn _suggest<'a>(&self, param1: &[&'a str], param2: usize) -> Vec<(&'a str, f64)> {
+                    distance::suggest(&self, param1, param2)
+                }
                 // escape
                 fn _escape_html(&self) -> String {
                     escape::escape_html(&self)
@@ -319,12 +516,18 @@ macro_rules! implement_string_for {
                 fn _index_of(&self, param1: &str, param2: usize) -> i8 {
                     index::index_of(&self, param1, param2)
                 }
+                fn _index_of_folded(&self, param1: &str, param2: usize, param3: &utils::MatchOptions) -> i8 {
+                    index::index_of_folded(&self, param1, param2, param3)
+                }
                 fn _last_index_of(&self, param1: &str, param2: usize) -> i8 {
                     index::last_index_of(&self, param1, param2)
                 }
                 fn _search(&self, param1: &str, param2: usize) -> i8 {
                     index::search(&self, param1, param2)
                 }
+                fn _find_where(&self, param1: split::Unit, param2: &dyn Fn(&str) -> bool) -> Option<usize> {
+                    index::find_where(&self, param1, param2)
+                }
                 // manipulate
                 fn _expand_tabs(&self, param1: usize) -> String {
                     manipulate::expand_tabs(&self, param1)
@@ -335,6 +538,12 @@ macro_rules! implement_string_for {
                 fn _finish(&self, param1: &str) -> String {
                     manipulate::finish(&self, param1)
                 }
+                fn _fill(&self, param1: usize) -> String {
+                    manipulate::fill(&self, param1)
+                }
+                fn _fill_with(&self, param1: &manipulate::FillOptions) -> String {
+                    manipulate::fill_with(&self, param1)
+                }
                 fn _insert(&self, param1: &str, param2: usize) -> String {
                     manipulate::insert(&self, param1, param2)
                 }
@@ -344,12 +553,21 @@ macro_rules! implement_string_for {
                 fn _pad(&self, param1: usize, param2: &str ) -> String {
                     manipulate::pad(&self, param1, param2)
                 }
+                fn _pad_width(&self, param1: usize, param2: &str) -> String {
+                    manipulate::pad_width(&self, param1, param2)
+                }
                 fn _pad_left(&self, param1: usize, param2: &str ) -> String {
                     manipulate::pad_left(&self, param1, param2)
                 }
+                fn _pad_left_width(&self, param1: usize, param2: &str) -> String {
+                    manipulate::pad_left_width(&self, param1, param2)
+                }
                 fn _pad_right(&self, param1: usize, param2: &str ) -> String {
                     manipulate::pad_right(&self, param1, param2)
                 }
+                fn _pad_right_width(&self, param1: usize, param2: &str) -> String {
+                    manipulate::pad_right_width(&self, param1, param2)
+                }
                 fn _repeat(&self, param1: usize) -> String {
                     manipulate::repeat(&self, param1)
                 }
@@ -359,6 +577,9 @@ macro_rules! implement_string_for {
                 fn _replace_all(&self, param1: &str, param2: &str) -> String {
                     manipulate::replace_all(&self, param1, param2)
                 }
+                fn _replace_all_folded(&self, param1: &str, param2: &str, param3: &utils::MatchOptions) -> String {
+                    manipulate::replace_all_folded(&self, param1, param2, param3)
+                }
                 fn _reverse(&self) -> String {
                     manipulate::reverse(&self)
                 }
@@ -392,6 +613,81 @@ macro_rules! implement_string_for {
                 fn _word_wrap(&self, param1: usize, param2: &str, param3: &str) -> String {
                     manipulate::word_wrap(&self, param1, param2, param3)
                 }
+                fn _word_wrap_width(&self, param1: usize, param2: &str, param3: &str) -> String {
+                    manipulate::word_wrap_width(&self, param1, param2, param3)
+                }
+                fn _word_wrap_with(&self, param1: &manipulate::WrapOptions) -> String {
+                    manipulate::word_wrap_with(&self, param1)
+                }
+                fn _dedent(&self) -> String {
+                    manipulate::dedent(&self)
+                }
+                fn _indent(&self, param1: &str, param2: Option<&dyn Fn(&str) -> bool>) -> String {
+                    manipulate::indent(&self, param1, param2)
+                }
+                fn _normalize_line_endings(&self, param1: split::LineEnding) -> String {
+                    manipulate::normalize_line_endings(&self, param1)
+                }
+                fn _bidi_isolate(&self) -> String {
+                    manipulate::bidi_isolate(&self)
+                }
+                fn _shortcodes_to_emoji(&self) -> String {
+                    manipulate::shortcodes_to_emoji(&self)
+                }
+                fn _emoji_to_shortcodes(&self) -> String {
+                    manipulate::emoji_to_shortcodes(&self)
+                }
+                // parse
+                fn _parse_number(&self) -> Option<parse::Number> {
+                    parse::parse_number(&self)
+                }
+                fn _parse_number_with(&self, param1: &parse::NumberOptions) -> Option<parse::Number> {
+                    parse::parse_number_with(&self, param1)
+                }
+                // phonetic
+                fn _caverphone(&self) -> String {
+                    phonetic::caverphone(&self)
+                }
+                fn _cologne(&self) -> String {
+                    phonetic::cologne(&self)
+                }
+                fn _double_metaphone(&self) -> (String, String) {
+                    phonetic::double_metaphone(&self)
+                }
+                fn _metaphone(&self) -> String {
+                    phonetic::metaphone(&self)
+                }
+                fn _nysiis(&self) -> String {
+                    phonetic::nysiis(&self)
+                }
+                fn _refined_soundex(&self) -> String {
+                    phonetic::refined_soundex(&self)
+                }
+                fn _soundex(&self) -> String {
+                    phonetic::soundex(&self)
+                }
+                // readability
+                fn _automated_readability_index(&self) -> f64 {
+                    readability::automated_readability_index(&self)
+                }
+                fn _coleman_liau(&self) -> f64 {
+                    readability::coleman_liau(&self)
+                }
+                fn _flesch_kincaid_grade(&self) -> f64 {
+                    readability::flesch_kincaid_grade(&self)
+                }
+                fn _flesch_reading_ease(&self) -> f64 {
+                    readability::flesch_reading_ease(&self)
+                }
+                fn _gunning_fog(&self) -> f64 {
+                    readability::gunning_fog(&self)
+                }
+                fn _smog(&self) -> f64 {
+                    readability::smog(&self)
+                }
+                fn _syllables(&self) -> usize {
+                    readability::syllables(&self)
+                }
                 // query
                 fn _is_foreign_key(&self) -> bool {
                     query::is_foreign_key(&self)
@@ -399,9 +695,15 @@ macro_rules! implement_string_for {
                 fn _ends_with(&self, param1: &str) -> bool {
                     query::ends_with(&self, param1)
                 }
+                fn _ends_with_folded(&self, param1: &str, param2: &utils::MatchOptions) -> bool {
+                    query::ends_with_folded(&self, param1, param2)
+                }
                 fn _includes(&self, param1: &str, param2: usize) -> bool {
                     query::includes(&self, param1, param2)
                 }
+                fn _includes_folded(&self, param1: &str, param2: usize, param3: &utils::MatchOptions) -> bool {
+                    query::includes_folded(&self, param1, param2, param3)
+                }
                 fn _is_alpha(&self) -> bool {
                     query::is_alpha(&self)
                 }
@@ -426,6 +728,9 @@ macro_rules! implement_string_for {
                 fn _is_empty(&self) -> bool {
                     query::is_empty(&self)
                 }
+                fn _is_emoji(&self) -> bool {
+                    query::is_emoji(&self)
+                }
                 fn _is_lowercase(&self) -> bool {
                     query::is_lowercase(&self)
                 }
@@ -438,6 +743,9 @@ macro_rules! implement_string_for {
                 fn _is_numeric(&self) -> bool {
                     query::is_numeric(&self)
                 }
+                fn _is_numeric_with(&self, param1: &parse::NumberOptions) -> bool {
+                    query::is_numeric_with(&self, param1)
+                }
                 fn _is_pascal_case(&self) -> bool {
                     query::is_pascal_case(&self)
                 }
@@ -468,22 +776,137 @@ macro_rules! implement_string_for {
                 fn _query(&self, param1: &str, param2: usize) -> bool {
                     query::query(&self, param1, param2)
                 }
+                fn _fuzzy_match(&self, param1: &str, param2: split::Unit) -> Option<query::FuzzyMatch> {
+                    query::fuzzy_match(&self, param1, param2)
+                }
+                fn _has_mixed_line_endings(&self) -> bool {
+                    query::has_mixed_line_endings(&self)
+                }
+                fn _line_ending(&self) -> Option<split::LineEnding> {
+                    query::line_ending(&self)
+                }
+                fn _scripts(&self) -> Vec<query::Script> {
+                    query::scripts(&self)
+                }
+                fn _dominant_script(&self) -> Option<query::Script> {
+                    query::dominant_script(&self)
+                }
+                fn _is_single_script(&self) -> bool {
+                    query::is_single_script(&self)
+                }
+                fn _filter_by_category(&self, param1: &[query::GeneralCategory]) -> String {
+                    query::filter_by_category(&self, param1)
+                }
+                fn _has_bidi_controls(&self) -> bool {
+                    query::has_bidi_controls(&self)
+                }
+                fn _base_direction(&self) -> Option<query::Direction> {
+                    query::base_direction(&self)
+                }
                 fn _starts_with(&self, param1: &str) -> bool {
                     query::starts_with(&self, param1)
                 }
+                fn _starts_with_folded(&self, param1: &str, param2: &utils::MatchOptions) -> bool {
+                    query::starts_with_folded(&self, param1, param2)
+                }
+                // security
+                fn _is_confusable(&self, param1: &str) -> bool {
+                    security::is_confusable(&self, param1)
+                }
+                fn _is_mixed_script(&self) -> bool {
+                    security::is_mixed_script(&self)
+                }
+                fn _restriction_level(&self) -> security::RestrictionLevel {
+                    security::restriction_level(&self)
+                }
+                fn _skeleton(&self) -> String {
+                    security::skeleton(&self)
+                }
                 // split
                 fn _chars(&self) -> Vec<&str> {
                     split::chars(&self)
                 }
+                fn _chars_iter(&self) -> Box<dyn DoubleEndedIterator<Item = &str> + '_> {
+                    Box::new(split::chars_iter(self))
+                }
                 fn _split(&self, param1: &str) -> Vec<&str> {
                     split::split(&self, param1)
                 }
+                fn _split_iter<'a>(&'a self, param1: &'a str) -> Box<dyn Iterator<Item = &'a str> + 'a> {
+                    Box::new(split::split_iter(self, param1))
+                }
+                fn _splitn(&self, param1: &str, param2: usize) -> Vec<&str> {
+                    split::splitn(&self, param1, param2)
+                }
+                fn _rsplitn(&self, param1: &str, param2: usize) -> Vec<&str> {
+                    split::rsplitn(&self, param1, param2)
+                }
+                fn _split_regex(&self, param1: &str) -> Vec<&str> {
+                    split::split_regex(&self, param1)
+                }
+                fn _split_regex_with(&self, param1: &str, param2: &split::SplitRegexOptions) -> Vec<&str> {
+                    split::split_regex_with(&self, param1, param2)
+                }
+                fn _split_quoted(&self, param1: &split::SplitOptions) -> Result<Vec<std::borrow::Cow<'_, str>>, split::SplitError> {
+                    split::split_quoted(&self, param1)
+                }
+                fn _shell_words(&self) -> Result<Vec<std::borrow::Cow<'_, str>>, split::SplitError> {
+                    split::shell_words(&self)
+                }
                 fn _words(&self) -> Vec<&str> {
                     split::words(&self)
                 }
+                fn _words_iter(&self) -> Box<dyn DoubleEndedIterator<Item = &str> + '_> {
+                    Box::new(split::words_iter(self))
+                }
+                fn _word_bounds(&self) -> Vec<split::WordBound<'_>> {
+                    split::word_bounds(&self)
+                }
+                fn _sentences(&self) -> Vec<&str> {
+                    split::sentences(&self)
+                }
                 fn _graphemes(&self) -> Vec<&str> {
                     split::graphemes(&self)
                 }
+                fn _graphemes_iter(&self) -> Box<dyn DoubleEndedIterator<Item = &str> + '_> {
+                    Box::new(split::graphemes_iter(self))
+                }
+                fn _emoji(&self) -> Vec<(usize, &str)> {
+                    split::emoji(&self)
+                }
+                fn _chunk(&self, param1: usize) -> Vec<&str> {
+                    split::chunk(&self, param1)
+                }
+                fn _partition(&self, param1: &str) -> (&str, &str, &str) {
+                    split::partition(&self, param1)
+                }
+                fn _rpartition(&self, param1: &str) -> (&str, &str, &str) {
+                    split::rpartition(&self, param1)
+                }
+                fn _split_at_grapheme(&self, param1: usize) -> (&str, &str) {
+                    split::split_at_grapheme(&self, param1)
+                }
+                fn _split_at_width(&self, param1: usize) -> (&str, &str) {
+                    split::split_at_width(&self, param1)
+                }
+                fn _char_ngrams(&self, param1: usize, param2: &str) -> Vec<String> {
+                    split::char_ngrams(&self, param1, param2)
+                }
+                fn _word_ngrams(&self, param1: usize) -> Vec<String> {
+                    split::word_ngrams(&self, param1)
+                }
+                fn _filter_where(&self, param1: split::Unit, param2: &dyn Fn(&str) -> bool) -> Vec<&str> {
+                    split::filter_where(&self, param1, param2)
+                }
+                fn _shingles(&self, param1: usize) -> std::collections::HashSet<String> {
+                    split::shingles(&self, param1)
+                }
+                fn _line_breaks(&self) -> Vec<(usize, bool)> {
+                    split::line_breaks(&self)
+                }
+                fn _lines(&self, param1: bool) -> Vec<&str> {
+                    split::lines(&self, param1)
+                }
                 fn _code_points(&self) -> Vec<u16> {
                     split::code_points(&self)
                 }
@@ -494,6 +917,18 @@ macro_rules! implement_string_for {
                 fn _strip_tags(&self) -> String {
                     strip::strip_tags(&self)
                 }
+                fn _strip_trailing_whitespace(&self) -> String {
+                    strip::strip_trailing_whitespace(&self)
+                }
+                fn _strip_bidi_controls(&self) -> String {
+                    strip::strip_bidi_controls(&self)
+                }
+                fn _strip_emoji(&self) -> String {
+                    strip::strip_emoji(&self)
+                }
+                fn _strip_skin_tones(&self) -> String {
+                    strip::strip_skin_tones(&self)
+                }
             }
         )*
     }
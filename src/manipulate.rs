@@ -264,6 +264,109 @@ pub fn pad_right(subject: &str, length: usize, pad: &str) -> String {
     }
 }
 
+/// Pads `subject` to a new display `width`, i.e. the number of terminal columns measured with `count::display_width`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to pad.
+/// * `width` - The display width to pad the string. No changes are made if `width` is less than the display width of `subject`.
+/// * `pad` - The string to be used for padding. If a wide padding character does not fit, the gap is filled with spaces.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::pad_width("dog", 5, "");
+/// // => " dog "
+/// manipulate::pad_width("日本", 8, "-");
+/// // => "--日本--"
+/// manipulate::pad_width("bird", 8, "・");
+/// // => "・bird・"
+/// use voca_rs::Voca;
+/// "日本"._pad_width(8, "");
+/// // => "  日本  "
+/// ```
+pub fn pad_width(subject: &str, width: usize, pad: &str) -> String {
+    let missing = width.saturating_sub(crate::count::display_width(subject));
+    format!(
+        "{}{}{}",
+        padding_of_width(pad, missing / 2),
+        subject,
+        padding_of_width(pad, missing - missing / 2)
+    )
+}
+
+/// Pads `subject` from left to a new display `width`, i.e. the number of terminal columns measured with `count::display_width`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to pad.
+/// * `width` - The display width to pad the string. No changes are made if `width` is less than the display width of `subject`.
+/// * `pad` - The string to be used for padding. If a wide padding character does not fit, the gap is filled with spaces.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::pad_left_width("dog", 5, "");
+/// // => "  dog"
+/// manipulate::pad_left_width("日本", 6, "-");
+/// // => "--日本"
+/// use voca_rs::Voca;
+/// "日本"._pad_left_width(6, "");
+/// // => "  日本"
+/// ```
+pub fn pad_left_width(subject: &str, width: usize, pad: &str) -> String {
+    let missing = width.saturating_sub(crate::count::display_width(subject));
+    format!("{}{}", padding_of_width(pad, missing), subject)
+}
+
+/// Pads `subject` from right to a new display `width`, i.e. the number of terminal columns measured with `count::display_width`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to pad.
+/// * `width` - The display width to pad the string. No changes are made if `width` is less than the display width of `subject`.
+/// * `pad` - The string to be used for padding. If a wide padding character does not fit, the gap is filled with spaces.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::pad_right_width("dog", 5, "");
+/// // => "dog  "
+/// manipulate::pad_right_width("日本", 6, "-");
+/// // => "日本--"
+/// use voca_rs::Voca;
+/// "日本"._pad_right_width(6, "");
+/// // => "日本  "
+/// ```
+pub fn pad_right_width(subject: &str, width: usize, pad: &str) -> String {
+    let missing = width.saturating_sub(crate::count::display_width(subject));
+    format!("{}{}", subject, padding_of_width(pad, missing))
+}
+
+fn padding_of_width(pad: &str, width: usize) -> String {
+    let pad_graphemes: Vec<&str> = crate::split::graphemes(pad)
+        .into_iter()
+        .filter(|g| crate::count::grapheme_width(g) > 0)
+        .collect();
+    let mut padding = String::new();
+    let mut used = 0;
+    if !pad_graphemes.is_empty() {
+        for grapheme in pad_graphemes.iter().cycle() {
+            let grapheme_width = crate::count::grapheme_width(grapheme);
+            if used + grapheme_width > width {
+                break;
+            }
+            padding.push_str(grapheme);
+            used += grapheme_width;
+        }
+    }
+    padding.push_str(&" ".repeat(width - used));
+    padding
+}
+
 /// Repeats the `subject` number of `times`.
 ///
 /// # Arguments
@@ -357,6 +460,67 @@ pub fn replace_all(subject: &str, pattern: &str, replacement: &str) -> String {
     subject.replace(pattern, replacement)
 }
 
+/// Replaces all matches of `pattern` with `replacement`, comparing the strings according to `options`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+/// * `pattern` - The pattern which match is replaced. All matches are replaced.
+/// * `replacement` - The string which replaces `pattern` match.
+/// * `options` - The options which define how the strings are compared.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let options = utils::MatchOptions::insensitive();
+/// manipulate::replace_all_folded("Straße or STRASSE", "strasse", "street", &options);
+/// // => "street or street"
+/// manipulate::replace_all_folded("Café del Mar cafe\u{0301}", "cafe", "Bar", &options);
+/// // => "Bar del Mar Bar"
+/// manipulate::replace_all_folded("ﬁle profile", "fi", "FI", &options);
+/// // => "FIle proFIle"
+/// manipulate::replace_all_folded("ﬁle", "i", "I", &options);
+/// // => "ﬁle"
+/// use voca_rs::Voca;
+/// "Straße or STRASSE"._replace_all_folded("strasse", "street", &options);
+/// // => "street or street"
+/// ```
+pub fn replace_all_folded(
+    subject: &str,
+    pattern: &str,
+    replacement: &str,
+    options: &crate::utils::MatchOptions,
+) -> String {
+    if subject.is_empty() || pattern.is_empty() {
+        return subject.to_string();
+    }
+    let folded_pattern = crate::utils::fold(pattern, options);
+    if folded_pattern.is_empty() {
+        return subject.to_string();
+    }
+    let (folded, offsets) = crate::utils::fold_with_offsets(subject, options);
+    let mut result = String::with_capacity(subject.len());
+    let mut last_end = 0;
+    let mut search_from = 0;
+    while let Some(found) = folded[search_from..].find(&folded_pattern) {
+        let start = search_from + found;
+        let end = start + folded_pattern.len();
+        match crate::utils::unfold_range(&offsets, start, end) {
+            Some((source_start, source_end)) => {
+                result.push_str(&subject[last_end..source_start]);
+                result.push_str(replacement);
+                last_end = source_end;
+                search_from = end;
+            }
+            // the match covers only a part of a character, e.g. "i" of "ﬁ", so the search continues after its start
+            None => search_from = start + folded[start..].chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    result.push_str(&subject[last_end..]);
+    result
+}
+
 /// Reverses the `subject`.
 ///
 /// # Arguments
@@ -674,6 +838,7 @@ pub fn tr(subject: &str, from: &str, to: &str) -> String {
 }
 
 /// Wraps `subject` to a given number of characters using a string break character.
+/// NOTE: Use `manipulate::word_wrap_with` to wrap at the Unicode line break opportunities.
 ///
 /// # Arguments
 ///
@@ -734,6 +899,758 @@ pub fn word_wrap(subject: &str, width: usize, newline: &str, indent: &str) -> St
     format!("{}{}{}", result, indent_sym, string)
 }
 
+/// Wraps `subject` to a given display `width`, i.e. the number of terminal columns measured with `count::display_width`.
+/// The words are separated by whitespace other than the no-break spaces, the words wider than `width` are kept on their own lines.
+///
+/// # Arguments
+///
+/// * `subject` - The string to wrap.
+/// * `width` - The number of columns at which to wrap.
+/// * `newline` - The string to add at the end of line. Default value is "\n" (if it's not given).
+/// * `indent` - The string to intend the line. Default value is "" (if it's not given).
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::word_wrap_width("Hello world", 5, "", "");
+/// // => "Hello\nworld"
+/// manipulate::word_wrap_width("東京 大阪 京都", 9, "", "");
+/// // => "東京 大阪\n京都"
+/// manipulate::word_wrap_width("東京 大阪 京都", 9, "<br/>", "__");
+/// // => "__東京 大阪<br/>__京都"
+/// use voca_rs::Voca;
+/// "東京 大阪 京都"._word_wrap_width(9, "", "");
+/// // => "東京 大阪\n京都"
+/// ```
+pub fn word_wrap_width(subject: &str, width: usize, newline: &str, indent: &str) -> String {
+    if subject.is_empty() || (crate::count::display_width(subject) <= width && indent.is_empty()) {
+        return subject.to_owned();
+    }
+    let new_line = if newline.is_empty() { "\n" } else { newline };
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+    let mut line_width = 0;
+    for word in breaking_words(subject) {
+        let word_width = crate::count::display_width(word);
+        if !line.is_empty() && line_width + 1 + word_width > width {
+            lines.push(line);
+            line = String::new();
+            line_width = 0;
+        }
+        if !line.is_empty() {
+            line.push(' ');
+            line_width += 1;
+        }
+        line.push_str(word);
+        line_width += word_width;
+    }
+    lines.push(line);
+    lines
+        .iter()
+        .map(|line| format!("{}{}", indent, line))
+        .collect::<Vec<String>>()
+        .join(new_line)
+}
+
+/// The options of `manipulate::word_wrap_with`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrapOptions {
+    /// The maximum display width of a line (see `count::display_width`), indentation included. Default value is 80.
+    pub width: usize,
+    /// The string to add at the end of line. Default value is "\n".
+    pub newline: String,
+    /// The string to indent the first line of each paragraph. Default value is "".
+    pub initial_indent: String,
+    /// The string to indent the other lines of each paragraph (hanging indent). Default value is "".
+    pub subsequent_indent: String,
+    /// Whether the words wider than the line are broken between graphemes. Otherwise they overflow the line. Default value is `true`.
+    pub break_long_words: bool,
+    /// Whether the existing line breaks of `subject` are kept. Otherwise the text is reflowed as a single paragraph. Default value is `true`.
+    pub preserve_paragraphs: bool,
+    /// Whether the whitespace at the end of the lines is removed. Default value is `true`.
+    pub trim_trailing_whitespace: bool,
+}
+
+impl Default for WrapOptions {
+    fn default() -> WrapOptions {
+        WrapOptions {
+            width: 80,
+            newline: "\n".to_string(),
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+            break_long_words: true,
+            preserve_paragraphs: true,
+            trim_trailing_whitespace: true,
+        }
+    }
+}
+
+/// Wraps `subject` at the line break opportunities of the Unicode Line Breaking Algorithm (UAX #14), see `split::line_breaks`.
+/// Unlike `manipulate::word_wrap`, it breaks the Chinese and Japanese text without spaces, after hyphens and soft hyphens, and keeps the existing line breaks.
+/// A line broken at a soft hyphen ends with a visible hyphen.
+///
+/// # Arguments
+///
+/// * `subject` - The string to wrap.
+/// * `options` - The wrapping options.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let options = manipulate::WrapOptions { width: 10, ..Default::default() };
+/// manipulate::word_wrap_with("The quick brown fox", &options);
+/// // => "The quick\nbrown fox"
+/// manipulate::word_wrap_with("日本語のテキストです。", &options);
+/// // => "日本語のテ\nキストで\nす。"
+/// manipulate::word_wrap_with("https://example.com/path", &options);
+/// // => "https://\nexample.co\nm/path"
+/// let options = manipulate::WrapOptions {
+///     width: 12,
+///     initial_indent: "* ".to_string(),
+///     subsequent_indent: "  ".to_string(),
+///     ..Default::default()
+/// };
+/// manipulate::word_wrap_with("one two three four\nfive", &options);
+/// // => "* one two\n  three four\n* five"
+/// use voca_rs::Voca;
+/// "The quick brown fox"._word_wrap_with(&manipulate::WrapOptions { width: 10, ..Default::default() });
+/// // => "The quick\nbrown fox"
+/// ```
+pub fn word_wrap_with(subject: &str, options: &WrapOptions) -> String {
+    if subject.is_empty() {
+        return String::new();
+    }
+    let text = if options.preserve_paragraphs {
+        subject.to_string()
+    } else {
+        subject
+            .replace("\r\n", " ")
+            .replace(is_line_terminator, " ")
+    };
+    let indent_of = |is_first_line: bool| {
+        if is_first_line {
+            &options.initial_indent
+        } else {
+            &options.subsequent_indent
+        }
+    };
+    let available = |is_first_line: bool| {
+        options
+            .width
+            .saturating_sub(crate::count::display_width(indent_of(is_first_line)))
+            .max(1)
+    };
+    let mut lines: Vec<(String, bool)> = vec![];
+    let mut line = String::new();
+    let mut line_width = 0;
+    let mut is_first_line = true;
+    let mut start = 0;
+    let mut breaks = crate::split::line_breaks(&text);
+    breaks.push((text.len(), text.ends_with(is_line_terminator)));
+    for (end, mandatory) in breaks {
+        let segment = text[start..end].trim_end_matches(is_line_terminator);
+        start = end;
+        let segment_width = crate::count::display_width(segment.trim_end());
+        if !line.is_empty() && line_width + segment_width > available(is_first_line) {
+            lines.push((line, is_first_line));
+            line = String::new();
+            line_width = 0;
+            is_first_line = false;
+        }
+        let mut rest = segment;
+        while options.break_long_words
+            && line.is_empty()
+            && crate::count::display_width(rest.trim_end()) > available(is_first_line)
+        {
+            let mut used = 0;
+            let mut position = 0;
+            for grapheme in crate::split::graphemes(rest) {
+                let grapheme_width = crate::count::grapheme_width(grapheme);
+                if used + grapheme_width > available(is_first_line) && position > 0 {
+                    break;
+                }
+                used += grapheme_width;
+                position += grapheme.len();
+            }
+            lines.push((rest[..position].to_string(), is_first_line));
+            is_first_line = false;
+            rest = &rest[position..];
+        }
+        line.push_str(rest);
+        line_width += crate::count::display_width(rest);
+        if mandatory {
+            lines.push((line, is_first_line));
+            line = String::new();
+            line_width = 0;
+            is_first_line = true;
+        }
+    }
+    if !line.is_empty() || is_first_line {
+        lines.push((line, is_first_line));
+    }
+    let last = lines.len() - 1;
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, (mut line, is_first_line))| {
+            if options.trim_trailing_whitespace {
+                line.truncate(line.trim_end().len());
+            }
+            if i != last && line.ends_with('\u{AD}') {
+                line.pop();
+                line.push('-');
+            }
+            if line.trim().is_empty() {
+                return line;
+            }
+            format!("{}{}", indent_of(is_first_line), line)
+        })
+        .collect::<Vec<String>>()
+        .join(&options.newline)
+}
+
+fn is_line_terminator(c: char) -> bool {
+    matches!(
+        c,
+        '\n' | '\r' | '\u{0B}' | '\u{0C}' | '\u{85}' | '\u{2028}' | '\u{2029}'
+    )
+}
+
+/// The alignment of the lines used by `manipulate::fill_with`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Align {
+    /// Aligns the lines to the left.
+    Left,
+    /// Aligns the lines to the right.
+    Right,
+    /// Centers the lines.
+    Center,
+    /// Distributes the spaces so that all the lines of a paragraph but the last one take the whole width.
+    Justify,
+}
+
+/// The options of `manipulate::fill_with`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FillOptions {
+    /// The maximum display width of a line (see `count::display_width`). Default value is 80.
+    pub width: usize,
+    /// The alignment of the lines. Default value is `Align::Left`.
+    pub align: Align,
+    /// The hyphenation dictionary: the words with the hyphenation points marked by "-", e.g. "hy-phen-ation". Default value is empty.
+    pub hyphenation: Vec<String>,
+}
+
+impl Default for FillOptions {
+    fn default() -> FillOptions {
+        FillOptions {
+            width: 80,
+            align: Align::Left,
+            hyphenation: vec![],
+        }
+    }
+}
+
+/// Fills the paragraphs of `subject` into lines of a given display `width` with the minimum raggedness,
+/// i.e. chooses the line breaks that minimize the sum of squared gaps at the end of the lines (Knuth-Plass style) rather than greedily.
+/// The paragraphs are separated by blank lines, the other whitespace is collapsed except the no-break spaces.
+///
+/// # Arguments
+///
+/// * `subject` - The string to fill.
+/// * `width` - The maximum display width of a line.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::fill("aaa bb cc ddddd", 6);
+/// // => "aaa\nbb cc\nddddd"
+/// manipulate::fill("one two\n\nthree four", 9);
+/// // => "one two\n\nthree\nfour"
+/// use voca_rs::Voca;
+/// "aaa bb cc ddddd"._fill(6);
+/// // => "aaa\nbb cc\nddddd"
+/// ```
+pub fn fill(subject: &str, width: usize) -> String {
+    fill_with(
+        subject,
+        &FillOptions {
+            width,
+            ..Default::default()
+        },
+    )
+}
+
+/// Fills the paragraphs of `subject` into lines with the minimum raggedness like `manipulate::fill`, then aligns the lines.
+/// The words which do not fit into a line are hyphenated if they are found in the hyphenation dictionary. The grapheme clusters are never split.
+///
+/// # Arguments
+///
+/// * `subject` - The string to fill.
+/// * `options` - The filling options.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::manipulate::{Align, FillOptions};
+/// let options = FillOptions { width: 12, align: Align::Justify, ..Default::default() };
+/// manipulate::fill_with("The quick brown fox jumps over the lazy dog", &options);
+/// // => "The    quick\nbrown    fox\njumps   over\nthe lazy dog"
+/// let options = FillOptions { width: 9, align: Align::Right, ..Default::default() };
+/// manipulate::fill_with("one two three", &options);
+/// // => "  one two\n    three"
+/// let options = FillOptions {
+///     width: 10,
+///     hyphenation: vec!["hy-phen-ation".to_string()],
+///     ..Default::default()
+/// };
+/// manipulate::fill_with("a hyphenation test", &options);
+/// // => "a hyphen-\nation test"
+/// use voca_rs::Voca;
+/// "one two three"._fill_with(&FillOptions { width: 9, align: Align::Center, ..Default::default() });
+/// // => " one two\n  three"
+/// ```
+pub fn fill_with(subject: &str, options: &FillOptions) -> String {
+    let mut paragraphs: Vec<Vec<&str>> = vec![vec![]];
+    for line in subject.lines() {
+        if line.trim().is_empty() {
+            if !paragraphs[paragraphs.len() - 1].is_empty() {
+                paragraphs.push(vec![]);
+            }
+        } else {
+            paragraphs.last_mut().unwrap().extend(breaking_words(line));
+        }
+    }
+    paragraphs
+        .iter()
+        .filter(|words| !words.is_empty())
+        .map(|words| fill_paragraph(words, options))
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+// splits at the whitespace except the no-break spaces U+00A0, U+2007 and U+202F, which keep the words together
+fn breaking_words(subject: &str) -> impl Iterator<Item = &str> {
+    subject
+        .split(|c: char| c.is_whitespace() && !matches!(c, '\u{00A0}' | '\u{2007}' | '\u{202F}'))
+        .filter(|word| !word.is_empty())
+}
+
+struct FillFragment<'a> {
+    text: &'a str,
+    width: usize,
+    // whether the fragment ends a word, otherwise it ends at a hyphenation point
+    is_word_end: bool,
+}
+
+const FILL_HYPHEN_PENALTY: usize = 50;
+const FILL_OVERFLOW_PENALTY: usize = 1_000_000;
+
+fn fill_paragraph(words: &[&str], options: &FillOptions) -> String {
+    let fragments: Vec<FillFragment> = words
+        .iter()
+        .flat_map(|word| hyphenate(word, &options.hyphenation))
+        .collect();
+    let count = fragments.len();
+    let line_width = |start: usize, end: usize| -> usize {
+        let text_width: usize = fragments[start..end].iter().map(|f| f.width).sum();
+        let spaces = fragments[start..end - 1]
+            .iter()
+            .filter(|f| f.is_word_end)
+            .count();
+        let hyphen = usize::from(!fragments[end - 1].is_word_end);
+        text_width + spaces + hyphen
+    };
+    // cost[i] is the minimum cost of the lines for fragments[i..], next[i] is the end of the first line
+    let mut cost = vec![0usize; count + 1];
+    let mut next = vec![count; count + 1];
+    for start in (0..count).rev() {
+        let mut best = usize::MAX;
+        for end in start + 1..=count {
+            let width = line_width(start, end);
+            if width > options.width && end > start + 1 {
+                break;
+            }
+            let line_cost = if width > options.width {
+                FILL_OVERFLOW_PENALTY
+            } else if end == count {
+                0
+            } else {
+                let slack = options.width - width;
+                slack * slack
+                    + if fragments[end - 1].is_word_end {
+                        0
+                    } else {
+                        FILL_HYPHEN_PENALTY
+                    }
+            };
+            let total = line_cost.saturating_add(cost[end]);
+            if total < best {
+                best = total;
+                next[start] = end;
+            }
+        }
+        cost[start] = best;
+    }
+    let mut lines: Vec<(String, usize)> = vec![];
+    let mut start = 0;
+    while start < count {
+        let end = next[start];
+        let mut line = String::new();
+        let mut gaps = 0;
+        for (i, fragment) in fragments[start..end].iter().enumerate() {
+            line.push_str(fragment.text);
+            if start + i + 1 < end && fragment.is_word_end {
+                line.push(' ');
+                gaps += 1;
+            }
+        }
+        if !fragments[end - 1].is_word_end {
+            line.push('-');
+        }
+        lines.push((line, gaps));
+        start = end;
+    }
+    let last = lines.len() - 1;
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, (line, gaps))| align_line(&line, gaps, options, i == last))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn align_line(line: &str, gaps: usize, options: &FillOptions, is_last: bool) -> String {
+    let line_width = crate::count::display_width(line);
+    if line_width >= options.width {
+        return line.to_string();
+    }
+    let missing = options.width - line_width;
+    match options.align {
+        Align::Left => line.to_string(),
+        Align::Right => pad_left_width(line, options.width, " "),
+        Align::Center => pad_left_width(line, line_width + missing / 2, " "),
+        Align::Justify if is_last || gaps == 0 => line.to_string(),
+        Align::Justify => {
+            let mut result = String::new();
+            let mut gap = 0;
+            for (i, word) in line.split(' ').enumerate() {
+                if i > 0 {
+                    let extra = missing / gaps + usize::from(gap < missing % gaps);
+                    result.push_str(&" ".repeat(1 + extra));
+                    gap += 1;
+                }
+                result.push_str(word);
+            }
+            result
+        }
+    }
+}
+
+fn hyphenate<'a>(word: &'a str, dictionary: &[String]) -> Vec<FillFragment<'a>> {
+    let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+    let core_start = word.find(core).unwrap_or(0);
+    let whole = vec![FillFragment {
+        text: word,
+        width: crate::count::display_width(word),
+        is_word_end: true,
+    }];
+    if core.is_empty() {
+        return whole;
+    }
+    let lowercase_core = core.to_lowercase();
+    let entry = dictionary
+        .iter()
+        .find(|entry| entry.replace('-', "").to_lowercase() == lowercase_core);
+    let parts: Vec<usize> = match entry {
+        Some(entry) => entry
+            .split('-')
+            .map(|part| crate::split::graphemes(part).len())
+            .collect(),
+        None => return whole,
+    };
+    let graphemes = crate::split::graphemes(core);
+    if parts.iter().sum::<usize>() != graphemes.len() {
+        return whole;
+    }
+    let mut breaks = vec![];
+    let mut position = core_start;
+    let mut grapheme_index = 0;
+    for part in &parts[..parts.len() - 1] {
+        for grapheme in &graphemes[grapheme_index..grapheme_index + part] {
+            position += grapheme.len();
+        }
+        grapheme_index += part;
+        breaks.push(position);
+    }
+    breaks.push(word.len());
+    let mut start = 0;
+    breaks
+        .iter()
+        .map(|&end| {
+            let text = &word[start..end];
+            start = end;
+            FillFragment {
+                text,
+                width: crate::count::display_width(text),
+                is_word_end: end == word.len(),
+            }
+        })
+        .collect()
+}
+
+/// Removes the common leading whitespace from every line of `subject`, like Python's `textwrap.dedent`.
+/// Only spaces and tabs are considered as whitespace. Lines consisting solely of whitespace are kept as blank lines and ignored when computing the common indentation.
+///
+/// # Arguments
+///
+/// * `subject` - The string to dedent.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::dedent("    hello\n      world\n\n    !");
+/// // => "hello\n  world\n\n!"
+/// manipulate::dedent("\tone\n  two");
+/// // => "\tone\n  two"
+/// use voca_rs::Voca;
+/// "    hello\n      world"._dedent();
+/// // => "hello\n  world"
+/// ```
+pub fn dedent(subject: &str) -> String {
+    let is_indent = |c: char| c == ' ' || c == '\t';
+    let lines = crate::split::lines(subject, true);
+    let mut margin: Option<&str> = None;
+    for line in &lines {
+        let (content, _) = crate::split::line_terminator(line);
+        if content.trim_start_matches(is_indent).is_empty() {
+            continue;
+        }
+        let indent = &content[..content.len() - content.trim_start_matches(is_indent).len()];
+        margin = Some(match margin {
+            None => indent,
+            Some(margin) => {
+                let common = margin
+                    .bytes()
+                    .zip(indent.bytes())
+                    .take_while(|(a, b)| a == b)
+                    .count();
+                &margin[..common]
+            }
+        });
+    }
+    let margin = margin.unwrap_or("");
+    lines
+        .iter()
+        .map(|line| {
+            let (content, terminator) = crate::split::line_terminator(line);
+            if content.trim_start_matches(is_indent).is_empty() {
+                terminator
+            } else {
+                &line[margin.len()..]
+            }
+        })
+        .collect()
+}
+
+/// Adds `prefix` to the beginning of the lines of `subject`, like Python's `textwrap.indent`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to indent.
+/// * `prefix` - The string to add to the lines.
+/// * `predicate` - The function which receives a line without its terminator and decides whether to indent it. When `None`, the lines consisting solely of whitespace are not indented.
+///   A bare `None` needs the type of the function, e.g. `None::<fn(&str) -> bool>`. `Voca::_indent` takes `Option<&dyn Fn(&str) -> bool>`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::indent("hello\n\nworld", "> ", None::<fn(&str) -> bool>);
+/// // => "> hello\n\n> world"
+/// manipulate::indent("hello\n\nworld", "> ", Some(|_: &str| true));
+/// // => "> hello\n> \n> world"
+/// let marker = "-";
+/// manipulate::indent("- one\ntwo", "  ", Some(|line: &str| !line.starts_with(marker)));
+/// // => "- one\n  two"
+/// use voca_rs::Voca;
+/// "hello\nworld"._indent("  ", None);
+/// // => "  hello\n  world"
+/// "- one\ntwo"._indent("  ", Some(&|line: &str| !line.starts_with(marker)));
+/// // => "- one\n  two"
+/// ```
+pub fn indent<F>(subject: &str, prefix: &str, predicate: Option<F>) -> String
+where
+    F: Fn(&str) -> bool,
+{
+    crate::split::lines(subject, true)
+        .iter()
+        .map(|line| {
+            let (content, _) = crate::split::line_terminator(line);
+            let indented = match &predicate {
+                Some(predicate) => predicate(content),
+                None => !content.trim().is_empty(),
+            };
+            if indented {
+                format!("{}{}", prefix, line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+/// Replaces all the line terminators (`"\n"`, `"\r\n"` and `"\r"`) in `subject` with the given `ending`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to normalize.
+/// * `ending` - The line terminator to use.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::normalize_line_endings("one\r\ntwo\rthree\n", split::LineEnding::Lf);
+/// // => "one\ntwo\nthree\n"
+/// manipulate::normalize_line_endings("one\ntwo", split::LineEnding::CrLf);
+/// // => "one\r\ntwo"
+/// use voca_rs::Voca;
+/// "one\r\ntwo"._normalize_line_endings(split::LineEnding::Lf);
+/// // => "one\ntwo"
+/// ```
+pub fn normalize_line_endings(subject: &str, ending: crate::split::LineEnding) -> String {
+    crate::split::lines(subject, true)
+        .iter()
+        .map(|line| {
+            let (content, terminator) = crate::split::line_terminator(line);
+            if terminator.is_empty() {
+                content.to_string()
+            } else {
+                format!("{}{}", content, ending.as_str())
+            }
+        })
+        .collect()
+}
+
+/// Wraps `subject` in the first strong isolate characters FSI (U+2068) and PDI (U+2069), so that its direction
+/// is detected on its own and does not affect the surrounding text, e.g. a right-to-left user name inside a left-to-right message.
+/// The unbalanced isolates in `subject` are closed before the final PDI, the unmatched PDIs are removed.
+///
+/// # Arguments
+///
+/// * `subject` - The string to isolate.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// format!("{} commented", manipulate::bidi_isolate("שלום"));
+/// // => "\u{2068}שלום\u{2069} commented"
+/// manipulate::bidi_isolate("\u{2067}abc");
+/// // => "\u{2068}\u{2067}abc\u{2069}\u{2069}"
+/// manipulate::bidi_isolate("\u{2069}abc");
+/// // => "\u{2068}abc\u{2069}"
+/// use voca_rs::Voca;
+/// "שלום"._bidi_isolate();
+/// // => "\u{2068}שלום\u{2069}"
+/// ```
+pub fn bidi_isolate(subject: &str) -> String {
+    if subject.is_empty() {
+        return "".to_string();
+    }
+    let mut result = String::with_capacity(subject.len() + 6);
+    result.push('\u{2068}');
+    let mut open_isolates = 0;
+    for c in subject.chars() {
+        match c {
+            '\u{2066}'..='\u{2068}' => open_isolates += 1,
+            // an unmatched PDI would close the wrapping FSI
+            '\u{2069}' if open_isolates == 0 => continue,
+            '\u{2069}' => open_isolates -= 1,
+            _ => {}
+        }
+        result.push(c);
+    }
+    for _ in 0..=open_isolates {
+        result.push('\u{2069}');
+    }
+    result
+}
+
+/// Replaces the emoji shortcodes like `:smile:` in `subject` with the emoji. The unknown shortcodes are kept.
+/// The shortcodes are the ones of GitHub (gemoji 4.1.0), which are mostly shared with Slack; the emoji with a skin tone
+/// modifier have no shortcodes.
+///
+/// # Arguments
+///
+/// * `subject` - The string with the shortcodes.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::shortcodes_to_emoji("Ship it :rocket::tada:");
+/// // => "Ship it 🚀🎉"
+/// manipulate::shortcodes_to_emoji(":+1: at 10:30 :unknown:");
+/// // => "👍 at 10:30 :unknown:"
+/// use voca_rs::Voca;
+/// "I :heart: Rust"._shortcodes_to_emoji();
+/// // => "I ❤️ Rust"
+/// ```
+pub fn shortcodes_to_emoji(subject: &str) -> String {
+    static SHORTCODE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = SHORTCODE.get_or_init(|| regex::Regex::new(r":([a-z0-9_+-]+):").unwrap());
+    re.replace_all(subject, |caps: &regex::Captures| {
+        match EMOJI_SHORTCODES.binary_search_by_key(&&caps[1], |&(shortcode, _)| shortcode) {
+            Ok(i) => EMOJI_SHORTCODES[i].1.to_string(),
+            Err(_) => caps[0].to_string(),
+        }
+    })
+    .into_owned()
+}
+
+/// Replaces the emoji in `subject` with their shortcodes (see `manipulate::shortcodes_to_emoji`).
+/// Only the graphemes which are emoji according to `query::is_emoji` are replaced, e.g. "❤" without the variation
+/// selector U+FE0F is text, not emoji. The emoji without a known shortcode, e.g. with a skin tone modifier, are kept.
+///
+/// # Arguments
+///
+/// * `subject` - The string with the emoji.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// manipulate::emoji_to_shortcodes("Ship it 🚀🎉");
+/// // => "Ship it :rocket::tada:"
+/// manipulate::emoji_to_shortcodes("I ❤\u{FE0F} Rust 👍🏽");
+/// // => "I :heart: Rust 👍🏽"
+/// manipulate::emoji_to_shortcodes("I ❤ Rust");
+/// // => "I ❤ Rust"
+/// use voca_rs::Voca;
+/// "Ship it 🚀"._emoji_to_shortcodes();
+/// // => "Ship it :rocket:"
+/// ```
+pub fn emoji_to_shortcodes(subject: &str) -> String {
+    crate::split::graphemes_iter(subject)
+        .map(|grapheme| {
+            if !crate::query::is_emoji_grapheme(grapheme) {
+                return grapheme.to_string();
+            }
+            let emoji = grapheme.replace('\u{FE0F}', "");
+            match SHORTCODE_BY_EMOJI.binary_search_by_key(&emoji.as_str(), |&(e, _)| e) {
+                Ok(i) => format!(":{}:", SHORTCODE_BY_EMOJI[i].1),
+                Err(_) => grapheme.to_string(),
+            }
+        })
+        .collect()
+}
+
 /// Adds the `suffix` to the end of the string unless it already exists in the `subject`.
 ///
 /// # Arguments
@@ -784,3 +1701,3875 @@ pub fn start(subject: &str, prefix: &str) -> String {
     }
     format!("{}{}", prefix, subject)
 }
+
+/// The GitHub emoji shortcodes (gemoji 4.1.0) sorted by the shortcode, an emoji may have several shortcodes.
+static EMOJI_SHORTCODES: [(&str, &str); 1913] = [
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("1234", "🔢"),
+    ("1st_place_medal", "🥇"),
+    ("2nd_place_medal", "🥈"),
+    ("3rd_place_medal", "🥉"),
+    ("8ball", "🎱"),
+    ("a", "🅰\u{FE0F}"),
+    ("ab", "🆎"),
+    ("abacus", "🧮"),
+    ("abc", "🔤"),
+    ("abcd", "🔡"),
+    ("accept", "🉑"),
+    ("accordion", "🪗"),
+    ("adhesive_bandage", "🩹"),
+    ("adult", "🧑"),
+    ("aerial_tramway", "🚡"),
+    ("afghanistan", "🇦🇫"),
+    ("airplane", "✈\u{FE0F}"),
+    ("aland_islands", "🇦🇽"),
+    ("alarm_clock", "⏰"),
+    ("albania", "🇦🇱"),
+    ("alembic", "⚗\u{FE0F}"),
+    ("algeria", "🇩🇿"),
+    ("alien", "👽"),
+    ("ambulance", "🚑"),
+    ("american_samoa", "🇦🇸"),
+    ("amphora", "🏺"),
+    ("anatomical_heart", "🫀"),
+    ("anchor", "⚓"),
+    ("andorra", "🇦🇩"),
+    ("angel", "👼"),
+    ("anger", "💢"),
+    ("angola", "🇦🇴"),
+    ("angry", "😠"),
+    ("anguilla", "🇦🇮"),
+    ("anguished", "😧"),
+    ("ant", "🐜"),
+    ("antarctica", "🇦🇶"),
+    ("antigua_barbuda", "🇦🇬"),
+    ("apple", "🍎"),
+    ("aquarius", "♒"),
+    ("argentina", "🇦🇷"),
+    ("aries", "♈"),
+    ("armenia", "🇦🇲"),
+    ("arrow_backward", "◀\u{FE0F}"),
+    ("arrow_double_down", "⏬"),
+    ("arrow_double_up", "⏫"),
+    ("arrow_down", "⬇\u{FE0F}"),
+    ("arrow_down_small", "🔽"),
+    ("arrow_forward", "▶\u{FE0F}"),
+    ("arrow_heading_down", "⤵\u{FE0F}"),
+    ("arrow_heading_up", "⤴\u{FE0F}"),
+    ("arrow_left", "⬅\u{FE0F}"),
+    ("arrow_lower_left", "↙\u{FE0F}"),
+    ("arrow_lower_right", "↘\u{FE0F}"),
+    ("arrow_right", "➡\u{FE0F}"),
+    ("arrow_right_hook", "↪\u{FE0F}"),
+    ("arrow_up", "⬆\u{FE0F}"),
+    ("arrow_up_down", "↕\u{FE0F}"),
+    ("arrow_up_small", "🔼"),
+    ("arrow_upper_left", "↖\u{FE0F}"),
+    ("arrow_upper_right", "↗\u{FE0F}"),
+    ("arrows_clockwise", "🔃"),
+    ("arrows_counterclockwise", "🔄"),
+    ("art", "🎨"),
+    ("articulated_lorry", "🚛"),
+    ("artificial_satellite", "🛰\u{FE0F}"),
+    ("artist", "🧑\u{200D}🎨"),
+    ("aruba", "🇦🇼"),
+    ("ascension_island", "🇦🇨"),
+    ("asterisk", "*\u{FE0F}\u{20E3}"),
+    ("astonished", "😲"),
+    ("astronaut", "🧑\u{200D}🚀"),
+    ("athletic_shoe", "👟"),
+    ("atm", "🏧"),
+    ("atom_symbol", "⚛\u{FE0F}"),
+    ("australia", "🇦🇺"),
+    ("austria", "🇦🇹"),
+    ("auto_rickshaw", "🛺"),
+    ("avocado", "🥑"),
+    ("axe", "🪓"),
+    ("azerbaijan", "🇦🇿"),
+    ("b", "🅱\u{FE0F}"),
+    ("baby", "👶"),
+    ("baby_bottle", "🍼"),
+    ("baby_chick", "🐤"),
+    ("baby_symbol", "🚼"),
+    ("back", "🔙"),
+    ("bacon", "🥓"),
+    ("badger", "🦡"),
+    ("badminton", "🏸"),
+    ("bagel", "🥯"),
+    ("baggage_claim", "🛄"),
+    ("baguette_bread", "🥖"),
+    ("bahamas", "🇧🇸"),
+    ("bahrain", "🇧🇭"),
+    ("balance_scale", "⚖\u{FE0F}"),
+    ("bald_man", "👨\u{200D}🦲"),
+    ("bald_woman", "👩\u{200D}🦲"),
+    ("ballet_shoes", "🩰"),
+    ("balloon", "🎈"),
+    ("ballot_box", "🗳\u{FE0F}"),
+    ("ballot_box_with_check", "☑\u{FE0F}"),
+    ("bamboo", "🎍"),
+    ("banana", "🍌"),
+    ("bangbang", "‼\u{FE0F}"),
+    ("bangladesh", "🇧🇩"),
+    ("banjo", "🪕"),
+    ("bank", "🏦"),
+    ("bar_chart", "📊"),
+    ("barbados", "🇧🇧"),
+    ("barber", "💈"),
+    ("baseball", "⚾"),
+    ("basket", "🧺"),
+    ("basketball", "🏀"),
+    ("basketball_man", "⛹\u{FE0F}\u{200D}♂\u{FE0F}"),
+    ("basketball_woman", "⛹\u{FE0F}\u{200D}♀\u{FE0F}"),
+    ("bat", "🦇"),
+    ("bath", "🛀"),
+    ("bathtub", "🛁"),
+    ("battery", "🔋"),
+    ("beach_umbrella", "🏖\u{FE0F}"),
+    ("beans", "🫘"),
+    ("bear", "🐻"),
+    ("bearded_person", "🧔"),
+    ("beaver", "🦫"),
+    ("bed", "🛏\u{FE0F}"),
+    ("bee", "🐝"),
+    ("beer", "🍺"),
+    ("beers", "🍻"),
+    ("beetle", "🪲"),
+    ("beginner", "🔰"),
+    ("belarus", "🇧🇾"),
+    ("belgium", "🇧🇪"),
+    ("belize", "🇧🇿"),
+    ("bell", "🔔"),
+    ("bell_pepper", "🫑"),
+    ("bellhop_bell", "🛎\u{FE0F}"),
+    ("benin", "🇧🇯"),
+    ("bento", "🍱"),
+    ("bermuda", "🇧🇲"),
+    ("beverage_box", "🧃"),
+    ("bhutan", "🇧🇹"),
+    ("bicyclist", "🚴"),
+    ("bike", "🚲"),
+    ("biking_man", "🚴\u{200D}♂\u{FE0F}"),
+    ("biking_woman", "🚴\u{200D}♀\u{FE0F}"),
+    ("bikini", "👙"),
+    ("billed_cap", "🧢"),
+    ("biohazard", "☣\u{FE0F}"),
+    ("bird", "🐦"),
+    ("birthday", "🎂"),
+    ("bison", "🦬"),
+    ("biting_lip", "🫦"),
+    ("black_bird", "🐦\u{200D}⬛"),
+    ("black_cat", "🐈\u{200D}⬛"),
+    ("black_circle", "⚫"),
+    ("black_flag", "🏴"),
+    ("black_heart", "🖤"),
+    ("black_joker", "🃏"),
+    ("black_large_square", "⬛"),
+    ("black_medium_small_square", "◾"),
+    ("black_medium_square", "◼\u{FE0F}"),
+    ("black_nib", "✒\u{FE0F}"),
+    ("black_small_square", "▪\u{FE0F}"),
+    ("black_square_button", "🔲"),
+    ("blond_haired_man", "👱\u{200D}♂\u{FE0F}"),
+    ("blond_haired_person", "👱"),
+    ("blond_haired_woman", "👱\u{200D}♀\u{FE0F}"),
+    ("blonde_woman", "👱\u{200D}♀\u{FE0F}"),
+    ("blossom", "🌼"),
+    ("blowfish", "🐡"),
+    ("blue_book", "📘"),
+    ("blue_car", "🚙"),
+    ("blue_heart", "💙"),
+    ("blue_square", "🟦"),
+    ("blueberries", "🫐"),
+    ("blush", "😊"),
+    ("boar", "🐗"),
+    ("boat", "⛵"),
+    ("bolivia", "🇧🇴"),
+    ("bomb", "💣"),
+    ("bone", "🦴"),
+    ("book", "📖"),
+    ("bookmark", "🔖"),
+    ("bookmark_tabs", "📑"),
+    ("books", "📚"),
+    ("boom", "💥"),
+    ("boomerang", "🪃"),
+    ("boot", "👢"),
+    ("bosnia_herzegovina", "🇧🇦"),
+    ("botswana", "🇧🇼"),
+    ("bouncing_ball_man", "⛹\u{FE0F}\u{200D}♂\u{FE0F}"),
+    ("bouncing_ball_person", "⛹\u{FE0F}"),
+    ("bouncing_ball_woman", "⛹\u{FE0F}\u{200D}♀\u{FE0F}"),
+    ("bouquet", "💐"),
+    ("bouvet_island", "🇧🇻"),
+    ("bow", "🙇"),
+    ("bow_and_arrow", "🏹"),
+    ("bowing_man", "🙇\u{200D}♂\u{FE0F}"),
+    ("bowing_woman", "🙇\u{200D}♀\u{FE0F}"),
+    ("bowl_with_spoon", "🥣"),
+    ("bowling", "🎳"),
+    ("boxing_glove", "🥊"),
+    ("boy", "👦"),
+    ("brain", "🧠"),
+    ("brazil", "🇧🇷"),
+    ("bread", "🍞"),
+    ("breast_feeding", "🤱"),
+    ("bricks", "🧱"),
+    ("bride_with_veil", "👰\u{200D}♀\u{FE0F}"),
+    ("bridge_at_night", "🌉"),
+    ("briefcase", "💼"),
+    ("british_indian_ocean_territory", "🇮🇴"),
+    ("british_virgin_islands", "🇻🇬"),
+    ("broccoli", "🥦"),
+    ("broken_heart", "💔"),
+    ("broom", "🧹"),
+    ("brown_circle", "🟤"),
+    ("brown_heart", "🤎"),
+    ("brown_square", "🟫"),
+    ("brunei", "🇧🇳"),
+    ("bubble_tea", "🧋"),
+    ("bubbles", "🫧"),
+    ("bucket", "🪣"),
+    ("bug", "🐛"),
+    ("building_construction", "🏗\u{FE0F}"),
+    ("bulb", "💡"),
+    ("bulgaria", "🇧🇬"),
+    ("bullettrain_front", "🚅"),
+    ("bullettrain_side", "🚄"),
+    ("burkina_faso", "🇧🇫"),
+    ("burrito", "🌯"),
+    ("burundi", "🇧🇮"),
+    ("bus", "🚌"),
+    ("business_suit_levitating", "🕴\u{FE0F}"),
+    ("busstop", "🚏"),
+    ("bust_in_silhouette", "👤"),
+    ("busts_in_silhouette", "👥"),
+    ("butter", "🧈"),
+    ("butterfly", "🦋"),
+    ("cactus", "🌵"),
+    ("cake", "🍰"),
+    ("calendar", "📆"),
+    ("call_me_hand", "🤙"),
+    ("calling", "📲"),
+    ("cambodia", "🇰🇭"),
+    ("camel", "🐫"),
+    ("camera", "📷"),
+    ("camera_flash", "📸"),
+    ("cameroon", "🇨🇲"),
+    ("camping", "🏕\u{FE0F}"),
+    ("canada", "🇨🇦"),
+    ("canary_islands", "🇮🇨"),
+    ("cancer", "♋"),
+    ("candle", "🕯\u{FE0F}"),
+    ("candy", "🍬"),
+    ("canned_food", "🥫"),
+    ("canoe", "🛶"),
+    ("cape_verde", "🇨🇻"),
+    ("capital_abcd", "🔠"),
+    ("capricorn", "♑"),
+    ("car", "🚗"),
+    ("card_file_box", "🗃\u{FE0F}"),
+    ("card_index", "📇"),
+    ("card_index_dividers", "🗂\u{FE0F}"),
+    ("caribbean_netherlands", "🇧🇶"),
+    ("carousel_horse", "🎠"),
+    ("carpentry_saw", "🪚"),
+    ("carrot", "🥕"),
+    ("cartwheeling", "🤸"),
+    ("cat", "🐱"),
+    ("cat2", "🐈"),
+    ("cayman_islands", "🇰🇾"),
+    ("cd", "💿"),
+    ("central_african_republic", "🇨🇫"),
+    ("ceuta_melilla", "🇪🇦"),
+    ("chad", "🇹🇩"),
+    ("chains", "⛓\u{FE0F}"),
+    ("chair", "🪑"),
+    ("champagne", "🍾"),
+    ("chart", "💹"),
+    ("chart_with_downwards_trend", "📉"),
+    ("chart_with_upwards_trend", "📈"),
+    ("checkered_flag", "🏁"),
+    ("cheese", "🧀"),
+    ("cherries", "🍒"),
+    ("cherry_blossom", "🌸"),
+    ("chess_pawn", "♟\u{FE0F}"),
+    ("chestnut", "🌰"),
+    ("chicken", "🐔"),
+    ("child", "🧒"),
+    ("children_crossing", "🚸"),
+    ("chile", "🇨🇱"),
+    ("chipmunk", "🐿\u{FE0F}"),
+    ("chocolate_bar", "🍫"),
+    ("chopsticks", "🥢"),
+    ("christmas_island", "🇨🇽"),
+    ("christmas_tree", "🎄"),
+    ("church", "⛪"),
+    ("cinema", "🎦"),
+    ("circus_tent", "🎪"),
+    ("city_sunrise", "🌇"),
+    ("city_sunset", "🌆"),
+    ("cityscape", "🏙\u{FE0F}"),
+    ("cl", "🆑"),
+    ("clamp", "🗜\u{FE0F}"),
+    ("clap", "👏"),
+    ("clapper", "🎬"),
+    ("classical_building", "🏛\u{FE0F}"),
+    ("climbing", "🧗"),
+    ("climbing_man", "🧗\u{200D}♂\u{FE0F}"),
+    ("climbing_woman", "🧗\u{200D}♀\u{FE0F}"),
+    ("clinking_glasses", "🥂"),
+    ("clipboard", "📋"),
+    ("clipperton_island", "🇨🇵"),
+    ("clock1", "🕐"),
+    ("clock10", "🕙"),
+    ("clock1030", "🕥"),
+    ("clock11", "🕚"),
+    ("clock1130", "🕦"),
+    ("clock12", "🕛"),
+    ("clock1230", "🕧"),
+    ("clock130", "🕜"),
+    ("clock2", "🕑"),
+    ("clock230", "🕝"),
+    ("clock3", "🕒"),
+    ("clock330", "🕞"),
+    ("clock4", "🕓"),
+    ("clock430", "🕟"),
+    ("clock5", "🕔"),
+    ("clock530", "🕠"),
+    ("clock6", "🕕"),
+    ("clock630", "🕡"),
+    ("clock7", "🕖"),
+    ("clock730", "🕢"),
+    ("clock8", "🕗"),
+    ("clock830", "🕣"),
+    ("clock9", "🕘"),
+    ("clock930", "🕤"),
+    ("closed_book", "📕"),
+    ("closed_lock_with_key", "🔐"),
+    ("closed_umbrella", "🌂"),
+    ("cloud", "☁\u{FE0F}"),
+    ("cloud_with_lightning", "🌩\u{FE0F}"),
+    ("cloud_with_lightning_and_rain", "⛈\u{FE0F}"),
+    ("cloud_with_rain", "🌧\u{FE0F}"),
+    ("cloud_with_snow", "🌨\u{FE0F}"),
+    ("clown_face", "🤡"),
+    ("clubs", "♣\u{FE0F}"),
+    ("cn", "🇨🇳"),
+    ("coat", "🧥"),
+    ("cockroach", "🪳"),
+    ("cocktail", "🍸"),
+    ("coconut", "🥥"),
+    ("cocos_islands", "🇨🇨"),
+    ("coffee", "☕"),
+    ("coffin", "⚰\u{FE0F}"),
+    ("coin", "🪙"),
+    ("cold_face", "🥶"),
+    ("cold_sweat", "😰"),
+    ("collision", "💥"),
+    ("colombia", "🇨🇴"),
+    ("comet", "☄\u{FE0F}"),
+    ("comoros", "🇰🇲"),
+    ("compass", "🧭"),
+    ("computer", "💻"),
+    ("computer_mouse", "🖱\u{FE0F}"),
+    ("confetti_ball", "🎊"),
+    ("confounded", "😖"),
+    ("confused", "😕"),
+    ("congo_brazzaville", "🇨🇬"),
+    ("congo_kinshasa", "🇨🇩"),
+    ("congratulations", "㊗\u{FE0F}"),
+    ("construction", "🚧"),
+    ("construction_worker", "👷"),
+    ("construction_worker_man", "👷\u{200D}♂\u{FE0F}"),
+    ("construction_worker_woman", "👷\u{200D}♀\u{FE0F}"),
+    ("control_knobs", "🎛\u{FE0F}"),
+    ("convenience_store", "🏪"),
+    ("cook", "🧑\u{200D}🍳"),
+    ("cook_islands", "🇨🇰"),
+    ("cookie", "🍪"),
+    ("cool", "🆒"),
+    ("cop", "👮"),
+    ("copyright", "©\u{FE0F}"),
+    ("coral", "🪸"),
+    ("corn", "🌽"),
+    ("costa_rica", "🇨🇷"),
+    ("cote_divoire", "🇨🇮"),
+    ("couch_and_lamp", "🛋\u{FE0F}"),
+    ("couple", "👫"),
+    ("couple_with_heart", "💑"),
+    ("couple_with_heart_man_man", "👨\u{200D}❤\u{FE0F}\u{200D}👨"),
+    (
+        "couple_with_heart_woman_man",
+        "👩\u{200D}❤\u{FE0F}\u{200D}👨",
+    ),
+    (
+        "couple_with_heart_woman_woman",
+        "👩\u{200D}❤\u{FE0F}\u{200D}👩",
+    ),
+    ("couplekiss", "💏"),
+    (
+        "couplekiss_man_man",
+        "👨\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👨",
+    ),
+    (
+        "couplekiss_man_woman",
+        "👩\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👨",
+    ),
+    (
+        "couplekiss_woman_woman",
+        "👩\u{200D}❤\u{FE0F}\u{200D}💋\u{200D}👩",
+    ),
+    ("cow", "🐮"),
+    ("cow2", "🐄"),
+    ("cowboy_hat_face", "🤠"),
+    ("crab", "🦀"),
+    ("crayon", "🖍\u{FE0F}"),
+    ("credit_card", "💳"),
+    ("crescent_moon", "🌙"),
+    ("cricket", "🦗"),
+    ("cricket_game", "🏏"),
+    ("croatia", "🇭🇷"),
+    ("crocodile", "🐊"),
+    ("croissant", "🥐"),
+    ("crossed_fingers", "🤞"),
+    ("crossed_flags", "🎌"),
+    ("crossed_swords", "⚔\u{FE0F}"),
+    ("crown", "👑"),
+    ("crutch", "🩼"),
+    ("cry", "😢"),
+    ("crying_cat_face", "😿"),
+    ("crystal_ball", "🔮"),
+    ("cuba", "🇨🇺"),
+    ("cucumber", "🥒"),
+    ("cup_with_straw", "🥤"),
+    ("cupcake", "🧁"),
+    ("cupid", "💘"),
+    ("curacao", "🇨🇼"),
+    ("curling_stone", "🥌"),
+    ("curly_haired_man", "👨\u{200D}🦱"),
+    ("curly_haired_woman", "👩\u{200D}🦱"),
+    ("curly_loop", "➰"),
+    ("currency_exchange", "💱"),
+    ("curry", "🍛"),
+    ("cursing_face", "🤬"),
+    ("custard", "🍮"),
+    ("customs", "🛃"),
+    ("cut_of_meat", "🥩"),
+    ("cyclone", "🌀"),
+    ("cyprus", "🇨🇾"),
+    ("czech_republic", "🇨🇿"),
+    ("dagger", "🗡\u{FE0F}"),
+    ("dancer", "💃"),
+    ("dancers", "👯"),
+    ("dancing_men", "👯\u{200D}♂\u{FE0F}"),
+    ("dancing_women", "👯\u{200D}♀\u{FE0F}"),
+    ("dango", "🍡"),
+    ("dark_sunglasses", "🕶\u{FE0F}"),
+    ("dart", "🎯"),
+    ("dash", "💨"),
+    ("date", "📅"),
+    ("de", "🇩🇪"),
+    ("deaf_man", "🧏\u{200D}♂\u{FE0F}"),
+    ("deaf_person", "🧏"),
+    ("deaf_woman", "🧏\u{200D}♀\u{FE0F}"),
+    ("deciduous_tree", "🌳"),
+    ("deer", "🦌"),
+    ("denmark", "🇩🇰"),
+    ("department_store", "🏬"),
+    ("derelict_house", "🏚\u{FE0F}"),
+    ("desert", "🏜\u{FE0F}"),
+    ("desert_island", "🏝\u{FE0F}"),
+    ("desktop_computer", "🖥\u{FE0F}"),
+    ("detective", "🕵\u{FE0F}"),
+    ("diamond_shape_with_a_dot_inside", "💠"),
+    ("diamonds", "♦\u{FE0F}"),
+    ("diego_garcia", "🇩🇬"),
+    ("disappointed", "😞"),
+    ("disappointed_relieved", "😥"),
+    ("disguised_face", "🥸"),
+    ("diving_mask", "🤿"),
+    ("diya_lamp", "🪔"),
+    ("dizzy", "💫"),
+    ("dizzy_face", "😵"),
+    ("djibouti", "🇩🇯"),
+    ("dna", "🧬"),
+    ("do_not_litter", "🚯"),
+    ("dodo", "🦤"),
+    ("dog", "🐶"),
+    ("dog2", "🐕"),
+    ("dollar", "💵"),
+    ("dolls", "🎎"),
+    ("dolphin", "🐬"),
+    ("dominica", "🇩🇲"),
+    ("dominican_republic", "🇩🇴"),
+    ("donkey", "🫏"),
+    ("door", "🚪"),
+    ("dotted_line_face", "🫥"),
+    ("doughnut", "🍩"),
+    ("dove", "🕊\u{FE0F}"),
+    ("dragon", "🐉"),
+    ("dragon_face", "🐲"),
+    ("dress", "👗"),
+    ("dromedary_camel", "🐪"),
+    ("drooling_face", "🤤"),
+    ("drop_of_blood", "🩸"),
+    ("droplet", "💧"),
+    ("drum", "🥁"),
+    ("duck", "🦆"),
+    ("dumpling", "🥟"),
+    ("dvd", "📀"),
+    ("e-mail", "📧"),
+    ("eagle", "🦅"),
+    ("ear", "👂"),
+    ("ear_of_rice", "🌾"),
+    ("ear_with_hearing_aid", "🦻"),
+    ("earth_africa", "🌍"),
+    ("earth_americas", "🌎"),
+    ("earth_asia", "🌏"),
+    ("ecuador", "🇪🇨"),
+    ("egg", "🥚"),
+    ("eggplant", "🍆"),
+    ("egypt", "🇪🇬"),
+    ("eight", "8\u{FE0F}\u{20E3}"),
+    ("eight_pointed_black_star", "✴\u{FE0F}"),
+    ("eight_spoked_asterisk", "✳\u{FE0F}"),
+    ("eject_button", "⏏\u{FE0F}"),
+    ("el_salvador", "🇸🇻"),
+    ("electric_plug", "🔌"),
+    ("elephant", "🐘"),
+    ("elevator", "🛗"),
+    ("elf", "🧝"),
+    ("elf_man", "🧝\u{200D}♂\u{FE0F}"),
+    ("elf_woman", "🧝\u{200D}♀\u{FE0F}"),
+    ("email", "📧"),
+    ("empty_nest", "🪹"),
+    ("end", "🔚"),
+    (
+        "england",
+        "🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+    ),
+    ("envelope", "✉\u{FE0F}"),
+    ("envelope_with_arrow", "📩"),
+    ("equatorial_guinea", "🇬🇶"),
+    ("eritrea", "🇪🇷"),
+    ("es", "🇪🇸"),
+    ("estonia", "🇪🇪"),
+    ("ethiopia", "🇪🇹"),
+    ("eu", "🇪🇺"),
+    ("euro", "💶"),
+    ("european_castle", "🏰"),
+    ("european_post_office", "🏤"),
+    ("european_union", "🇪🇺"),
+    ("evergreen_tree", "🌲"),
+    ("exclamation", "❗"),
+    ("exploding_head", "🤯"),
+    ("expressionless", "😑"),
+    ("eye", "👁\u{FE0F}"),
+    ("eye_speech_bubble", "👁\u{FE0F}\u{200D}🗨\u{FE0F}"),
+    ("eyeglasses", "👓"),
+    ("eyes", "👀"),
+    ("face_exhaling", "😮\u{200D}💨"),
+    ("face_holding_back_tears", "🥹"),
+    ("face_in_clouds", "😶\u{200D}🌫\u{FE0F}"),
+    ("face_with_diagonal_mouth", "🫤"),
+    ("face_with_head_bandage", "🤕"),
+    ("face_with_open_eyes_and_hand_over_mouth", "🫢"),
+    ("face_with_peeking_eye", "🫣"),
+    ("face_with_spiral_eyes", "😵\u{200D}💫"),
+    ("face_with_thermometer", "🤒"),
+    ("facepalm", "🤦"),
+    ("facepunch", "👊"),
+    ("factory", "🏭"),
+    ("factory_worker", "🧑\u{200D}🏭"),
+    ("fairy", "🧚"),
+    ("fairy_man", "🧚\u{200D}♂\u{FE0F}"),
+    ("fairy_woman", "🧚\u{200D}♀\u{FE0F}"),
+    ("falafel", "🧆"),
+    ("falkland_islands", "🇫🇰"),
+    ("fallen_leaf", "🍂"),
+    ("family", "👪"),
+    ("family_man_boy", "👨\u{200D}👦"),
+    ("family_man_boy_boy", "👨\u{200D}👦\u{200D}👦"),
+    ("family_man_girl", "👨\u{200D}👧"),
+    ("family_man_girl_boy", "👨\u{200D}👧\u{200D}👦"),
+    ("family_man_girl_girl", "👨\u{200D}👧\u{200D}👧"),
+    ("family_man_man_boy", "👨\u{200D}👨\u{200D}👦"),
+    ("family_man_man_boy_boy", "👨\u{200D}👨\u{200D}👦\u{200D}👦"),
+    ("family_man_man_girl", "👨\u{200D}👨\u{200D}👧"),
+    (
+        "family_man_man_girl_boy",
+        "👨\u{200D}👨\u{200D}👧\u{200D}👦",
+    ),
+    (
+        "family_man_man_girl_girl",
+        "👨\u{200D}👨\u{200D}👧\u{200D}👧",
+    ),
+    ("family_man_woman_boy", "👨\u{200D}👩\u{200D}👦"),
+    (
+        "family_man_woman_boy_boy",
+        "👨\u{200D}👩\u{200D}👦\u{200D}👦",
+    ),
+    ("family_man_woman_girl", "👨\u{200D}👩\u{200D}👧"),
+    (
+        "family_man_woman_girl_boy",
+        "👨\u{200D}👩\u{200D}👧\u{200D}👦",
+    ),
+    (
+        "family_man_woman_girl_girl",
+        "👨\u{200D}👩\u{200D}👧\u{200D}👧",
+    ),
+    ("family_woman_boy", "👩\u{200D}👦"),
+    ("family_woman_boy_boy", "👩\u{200D}👦\u{200D}👦"),
+    ("family_woman_girl", "👩\u{200D}👧"),
+    ("family_woman_girl_boy", "👩\u{200D}👧\u{200D}👦"),
+    ("family_woman_girl_girl", "👩\u{200D}👧\u{200D}👧"),
+    ("family_woman_woman_boy", "👩\u{200D}👩\u{200D}👦"),
+    (
+        "family_woman_woman_boy_boy",
+        "👩\u{200D}👩\u{200D}👦\u{200D}👦",
+    ),
+    ("family_woman_woman_girl", "👩\u{200D}👩\u{200D}👧"),
+    (
+        "family_woman_woman_girl_boy",
+        "👩\u{200D}👩\u{200D}👧\u{200D}👦",
+    ),
+    (
+        "family_woman_woman_girl_girl",
+        "👩\u{200D}👩\u{200D}👧\u{200D}👧",
+    ),
+    ("farmer", "🧑\u{200D}🌾"),
+    ("faroe_islands", "🇫🇴"),
+    ("fast_forward", "⏩"),
+    ("fax", "📠"),
+    ("fearful", "😨"),
+    ("feather", "🪶"),
+    ("feet", "🐾"),
+    ("female_detective", "🕵\u{FE0F}\u{200D}♀\u{FE0F}"),
+    ("female_sign", "♀\u{FE0F}"),
+    ("ferris_wheel", "🎡"),
+    ("ferry", "⛴\u{FE0F}"),
+    ("field_hockey", "🏑"),
+    ("fiji", "🇫🇯"),
+    ("file_cabinet", "🗄\u{FE0F}"),
+    ("file_folder", "📁"),
+    ("film_projector", "📽\u{FE0F}"),
+    ("film_strip", "🎞\u{FE0F}"),
+    ("finland", "🇫🇮"),
+    ("fire", "🔥"),
+    ("fire_engine", "🚒"),
+    ("fire_extinguisher", "🧯"),
+    ("firecracker", "🧨"),
+    ("firefighter", "🧑\u{200D}🚒"),
+    ("fireworks", "🎆"),
+    ("first_quarter_moon", "🌓"),
+    ("first_quarter_moon_with_face", "🌛"),
+    ("fish", "🐟"),
+    ("fish_cake", "🍥"),
+    ("fishing_pole_and_fish", "🎣"),
+    ("fist", "✊"),
+    ("fist_left", "🤛"),
+    ("fist_oncoming", "👊"),
+    ("fist_raised", "✊"),
+    ("fist_right", "🤜"),
+    ("five", "5\u{FE0F}\u{20E3}"),
+    ("flags", "🎏"),
+    ("flamingo", "🦩"),
+    ("flashlight", "🔦"),
+    ("flat_shoe", "🥿"),
+    ("flatbread", "🫓"),
+    ("fleur_de_lis", "⚜\u{FE0F}"),
+    ("flight_arrival", "🛬"),
+    ("flight_departure", "🛫"),
+    ("flipper", "🐬"),
+    ("floppy_disk", "💾"),
+    ("flower_playing_cards", "🎴"),
+    ("flushed", "😳"),
+    ("flute", "🪈"),
+    ("fly", "🪰"),
+    ("flying_disc", "🥏"),
+    ("flying_saucer", "🛸"),
+    ("fog", "🌫\u{FE0F}"),
+    ("foggy", "🌁"),
+    ("folding_hand_fan", "🪭"),
+    ("fondue", "🫕"),
+    ("foot", "🦶"),
+    ("football", "🏈"),
+    ("footprints", "👣"),
+    ("fork_and_knife", "🍴"),
+    ("fortune_cookie", "🥠"),
+    ("fountain", "⛲"),
+    ("fountain_pen", "🖋\u{FE0F}"),
+    ("four", "4\u{FE0F}\u{20E3}"),
+    ("four_leaf_clover", "🍀"),
+    ("fox_face", "🦊"),
+    ("fr", "🇫🇷"),
+    ("framed_picture", "🖼\u{FE0F}"),
+    ("free", "🆓"),
+    ("french_guiana", "🇬🇫"),
+    ("french_polynesia", "🇵🇫"),
+    ("french_southern_territories", "🇹🇫"),
+    ("fried_egg", "🍳"),
+    ("fried_shrimp", "🍤"),
+    ("fries", "🍟"),
+    ("frog", "🐸"),
+    ("frowning", "😦"),
+    ("frowning_face", "☹\u{FE0F}"),
+    ("frowning_man", "🙍\u{200D}♂\u{FE0F}"),
+    ("frowning_person", "🙍"),
+    ("frowning_woman", "🙍\u{200D}♀\u{FE0F}"),
+    ("fu", "🖕"),
+    ("fuelpump", "⛽"),
+    ("full_moon", "🌕"),
+    ("full_moon_with_face", "🌝"),
+    ("funeral_urn", "⚱\u{FE0F}"),
+    ("gabon", "🇬🇦"),
+    ("gambia", "🇬🇲"),
+    ("game_die", "🎲"),
+    ("garlic", "🧄"),
+    ("gb", "🇬🇧"),
+    ("gear", "⚙\u{FE0F}"),
+    ("gem", "💎"),
+    ("gemini", "♊"),
+    ("genie", "🧞"),
+    ("genie_man", "🧞\u{200D}♂\u{FE0F}"),
+    ("genie_woman", "🧞\u{200D}♀\u{FE0F}"),
+    ("georgia", "🇬🇪"),
+    ("ghana", "🇬🇭"),
+    ("ghost", "👻"),
+    ("gibraltar", "🇬🇮"),
+    ("gift", "🎁"),
+    ("gift_heart", "💝"),
+    ("ginger_root", "🫚"),
+    ("giraffe", "🦒"),
+    ("girl", "👧"),
+    ("globe_with_meridians", "🌐"),
+    ("gloves", "🧤"),
+    ("goal_net", "🥅"),
+    ("goat", "🐐"),
+    ("goggles", "🥽"),
+    ("golf", "⛳"),
+    ("golfing", "🏌\u{FE0F}"),
+    ("golfing_man", "🏌\u{FE0F}\u{200D}♂\u{FE0F}"),
+    ("golfing_woman", "🏌\u{FE0F}\u{200D}♀\u{FE0F}"),
+    ("goose", "🪿"),
+    ("gorilla", "🦍"),
+    ("grapes", "🍇"),
+    ("greece", "🇬🇷"),
+    ("green_apple", "🍏"),
+    ("green_book", "📗"),
+    ("green_circle", "🟢"),
+    ("green_heart", "💚"),
+    ("green_salad", "🥗"),
+    ("green_square", "🟩"),
+    ("greenland", "🇬🇱"),
+    ("grenada", "🇬🇩"),
+    ("grey_exclamation", "❕"),
+    ("grey_heart", "🩶"),
+    ("grey_question", "❔"),
+    ("grimacing", "😬"),
+    ("grin", "😁"),
+    ("grinning", "😀"),
+    ("guadeloupe", "🇬🇵"),
+    ("guam", "🇬🇺"),
+    ("guard", "💂"),
+    ("guardsman", "💂\u{200D}♂\u{FE0F}"),
+    ("guardswoman", "💂\u{200D}♀\u{FE0F}"),
+    ("guatemala", "🇬🇹"),
+    ("guernsey", "🇬🇬"),
+    ("guide_dog", "🦮"),
+    ("guinea", "🇬🇳"),
+    ("guinea_bissau", "🇬🇼"),
+    ("guitar", "🎸"),
+    ("gun", "🔫"),
+    ("guyana", "🇬🇾"),
+    ("hair_pick", "🪮"),
+    ("haircut", "💇"),
+    ("haircut_man", "💇\u{200D}♂\u{FE0F}"),
+    ("haircut_woman", "💇\u{200D}♀\u{FE0F}"),
+    ("haiti", "🇭🇹"),
+    ("hamburger", "🍔"),
+    ("hammer", "🔨"),
+    ("hammer_and_pick", "⚒\u{FE0F}"),
+    ("hammer_and_wrench", "🛠\u{FE0F}"),
+    ("hamsa", "🪬"),
+    ("hamster", "🐹"),
+    ("hand", "✋"),
+    ("hand_over_mouth", "🤭"),
+    ("hand_with_index_finger_and_thumb_crossed", "🫰"),
+    ("handbag", "👜"),
+    ("handball_person", "🤾"),
+    ("handshake", "🤝"),
+    ("hankey", "💩"),
+    ("hash", "#\u{FE0F}\u{20E3}"),
+    ("hatched_chick", "🐥"),
+    ("hatching_chick", "🐣"),
+    ("headphones", "🎧"),
+    ("headstone", "🪦"),
+    ("health_worker", "🧑\u{200D}⚕\u{FE0F}"),
+    ("hear_no_evil", "🙉"),
+    ("heard_mcdonald_islands", "🇭🇲"),
+    ("heart", "❤\u{FE0F}"),
+    ("heart_decoration", "💟"),
+    ("heart_eyes", "😍"),
+    ("heart_eyes_cat", "😻"),
+    ("heart_hands", "🫶"),
+    ("heart_on_fire", "❤\u{FE0F}\u{200D}🔥"),
+    ("heartbeat", "💓"),
+    ("heartpulse", "💗"),
+    ("hearts", "♥\u{FE0F}"),
+    ("heavy_check_mark", "✔\u{FE0F}"),
+    ("heavy_division_sign", "➗"),
+    ("heavy_dollar_sign", "💲"),
+    ("heavy_equals_sign", "🟰"),
+    ("heavy_exclamation_mark", "❗"),
+    ("heavy_heart_exclamation", "❣\u{FE0F}"),
+    ("heavy_minus_sign", "➖"),
+    ("heavy_multiplication_x", "✖\u{FE0F}"),
+    ("heavy_plus_sign", "➕"),
+    ("hedgehog", "🦔"),
+    ("helicopter", "🚁"),
+    ("herb", "🌿"),
+    ("hibiscus", "🌺"),
+    ("high_brightness", "🔆"),
+    ("high_heel", "👠"),
+    ("hiking_boot", "🥾"),
+    ("hindu_temple", "🛕"),
+    ("hippopotamus", "🦛"),
+    ("hocho", "🔪"),
+    ("hole", "🕳\u{FE0F}"),
+    ("honduras", "🇭🇳"),
+    ("honey_pot", "🍯"),
+    ("honeybee", "🐝"),
+    ("hong_kong", "🇭🇰"),
+    ("hook", "🪝"),
+    ("horse", "🐴"),
+    ("horse_racing", "🏇"),
+    ("hospital", "🏥"),
+    ("hot_face", "🥵"),
+    ("hot_pepper", "🌶\u{FE0F}"),
+    ("hotdog", "🌭"),
+    ("hotel", "🏨"),
+    ("hotsprings", "♨\u{FE0F}"),
+    ("hourglass", "⌛"),
+    ("hourglass_flowing_sand", "⏳"),
+    ("house", "🏠"),
+    ("house_with_garden", "🏡"),
+    ("houses", "🏘\u{FE0F}"),
+    ("hugs", "🤗"),
+    ("hungary", "🇭🇺"),
+    ("hushed", "😯"),
+    ("hut", "🛖"),
+    ("hyacinth", "🪻"),
+    ("ice_cream", "🍨"),
+    ("ice_cube", "🧊"),
+    ("ice_hockey", "🏒"),
+    ("ice_skate", "⛸\u{FE0F}"),
+    ("icecream", "🍦"),
+    ("iceland", "🇮🇸"),
+    ("id", "🆔"),
+    ("identification_card", "🪪"),
+    ("ideograph_advantage", "🉐"),
+    ("imp", "👿"),
+    ("inbox_tray", "📥"),
+    ("incoming_envelope", "📨"),
+    ("index_pointing_at_the_viewer", "🫵"),
+    ("india", "🇮🇳"),
+    ("indonesia", "🇮🇩"),
+    ("infinity", "♾\u{FE0F}"),
+    ("information_desk_person", "💁"),
+    ("information_source", "ℹ\u{FE0F}"),
+    ("innocent", "😇"),
+    ("interrobang", "⁉\u{FE0F}"),
+    ("iphone", "📱"),
+    ("iran", "🇮🇷"),
+    ("iraq", "🇮🇶"),
+    ("ireland", "🇮🇪"),
+    ("isle_of_man", "🇮🇲"),
+    ("israel", "🇮🇱"),
+    ("it", "🇮🇹"),
+    ("izakaya_lantern", "🏮"),
+    ("jack_o_lantern", "🎃"),
+    ("jamaica", "🇯🇲"),
+    ("japan", "🗾"),
+    ("japanese_castle", "🏯"),
+    ("japanese_goblin", "👺"),
+    ("japanese_ogre", "👹"),
+    ("jar", "🫙"),
+    ("jeans", "👖"),
+    ("jellyfish", "🪼"),
+    ("jersey", "🇯🇪"),
+    ("jigsaw", "🧩"),
+    ("jordan", "🇯🇴"),
+    ("joy", "😂"),
+    ("joy_cat", "😹"),
+    ("joystick", "🕹\u{FE0F}"),
+    ("jp", "🇯🇵"),
+    ("judge", "🧑\u{200D}⚖\u{FE0F}"),
+    ("juggling_person", "🤹"),
+    ("kaaba", "🕋"),
+    ("kangaroo", "🦘"),
+    ("kazakhstan", "🇰🇿"),
+    ("kenya", "🇰🇪"),
+    ("key", "🔑"),
+    ("keyboard", "⌨\u{FE0F}"),
+    ("keycap_ten", "🔟"),
+    ("khanda", "🪯"),
+    ("kick_scooter", "🛴"),
+    ("kimono", "👘"),
+    ("kiribati", "🇰🇮"),
+    ("kiss", "💋"),
+    ("kissing", "😗"),
+    ("kissing_cat", "😽"),
+    ("kissing_closed_eyes", "😚"),
+    ("kissing_heart", "😘"),
+    ("kissing_smiling_eyes", "😙"),
+    ("kite", "🪁"),
+    ("kiwi_fruit", "🥝"),
+    ("kneeling_man", "🧎\u{200D}♂\u{FE0F}"),
+    ("kneeling_person", "🧎"),
+    ("kneeling_woman", "🧎\u{200D}♀\u{FE0F}"),
+    ("knife", "🔪"),
+    ("knot", "🪢"),
+    ("koala", "🐨"),
+    ("koko", "🈁"),
+    ("kosovo", "🇽🇰"),
+    ("kr", "🇰🇷"),
+    ("kuwait", "🇰🇼"),
+    ("kyrgyzstan", "🇰🇬"),
+    ("lab_coat", "🥼"),
+    ("label", "🏷\u{FE0F}"),
+    ("lacrosse", "🥍"),
+    ("ladder", "🪜"),
+    ("lady_beetle", "🐞"),
+    ("lantern", "🏮"),
+    ("laos", "🇱🇦"),
+    ("large_blue_circle", "🔵"),
+    ("large_blue_diamond", "🔷"),
+    ("large_orange_diamond", "🔶"),
+    ("last_quarter_moon", "🌗"),
+    ("last_quarter_moon_with_face", "🌜"),
+    ("latin_cross", "✝\u{FE0F}"),
+    ("latvia", "🇱🇻"),
+    ("laughing", "😆"),
+    ("leafy_green", "🥬"),
+    ("leaves", "🍃"),
+    ("lebanon", "🇱🇧"),
+    ("ledger", "📒"),
+    ("left_luggage", "🛅"),
+    ("left_right_arrow", "↔\u{FE0F}"),
+    ("left_speech_bubble", "🗨\u{FE0F}"),
+    ("leftwards_arrow_with_hook", "↩\u{FE0F}"),
+    ("leftwards_hand", "🫲"),
+    ("leftwards_pushing_hand", "🫷"),
+    ("leg", "🦵"),
+    ("lemon", "🍋"),
+    ("leo", "♌"),
+    ("leopard", "🐆"),
+    ("lesotho", "🇱🇸"),
+    ("level_slider", "🎚\u{FE0F}"),
+    ("liberia", "🇱🇷"),
+    ("libra", "♎"),
+    ("libya", "🇱🇾"),
+    ("liechtenstein", "🇱🇮"),
+    ("light_blue_heart", "🩵"),
+    ("light_rail", "🚈"),
+    ("link", "🔗"),
+    ("lion", "🦁"),
+    ("lips", "👄"),
+    ("lipstick", "💄"),
+    ("lithuania", "🇱🇹"),
+    ("lizard", "🦎"),
+    ("llama", "🦙"),
+    ("lobster", "🦞"),
+    ("lock", "🔒"),
+    ("lock_with_ink_pen", "🔏"),
+    ("lollipop", "🍭"),
+    ("long_drum", "🪘"),
+    ("loop", "➿"),
+    ("lotion_bottle", "🧴"),
+    ("lotus", "🪷"),
+    ("lotus_position", "🧘"),
+    ("lotus_position_man", "🧘\u{200D}♂\u{FE0F}"),
+    ("lotus_position_woman", "🧘\u{200D}♀\u{FE0F}"),
+    ("loud_sound", "🔊"),
+    ("loudspeaker", "📢"),
+    ("love_hotel", "🏩"),
+    ("love_letter", "💌"),
+    ("love_you_gesture", "🤟"),
+    ("low_battery", "🪫"),
+    ("low_brightness", "🔅"),
+    ("luggage", "🧳"),
+    ("lungs", "🫁"),
+    ("luxembourg", "🇱🇺"),
+    ("lying_face", "🤥"),
+    ("m", "Ⓜ\u{FE0F}"),
+    ("macau", "🇲🇴"),
+    ("macedonia", "🇲🇰"),
+    ("madagascar", "🇲🇬"),
+    ("mag", "🔍"),
+    ("mag_right", "🔎"),
+    ("mage", "🧙"),
+    ("mage_man", "🧙\u{200D}♂\u{FE0F}"),
+    ("mage_woman", "🧙\u{200D}♀\u{FE0F}"),
+    ("magic_wand", "🪄"),
+    ("magnet", "🧲"),
+    ("mahjong", "🀄"),
+    ("mailbox", "📫"),
+    ("mailbox_closed", "📪"),
+    ("mailbox_with_mail", "📬"),
+    ("mailbox_with_no_mail", "📭"),
+    ("malawi", "🇲🇼"),
+    ("malaysia", "🇲🇾"),
+    ("maldives", "🇲🇻"),
+    ("male_detective", "🕵\u{FE0F}\u{200D}♂\u{FE0F}"),
+    ("male_sign", "♂\u{FE0F}"),
+    ("mali", "🇲🇱"),
+    ("malta", "🇲🇹"),
+    ("mammoth", "🦣"),
+    ("man", "👨"),
+    ("man_artist", "👨\u{200D}🎨"),
+    ("man_astronaut", "👨\u{200D}🚀"),
+    ("man_beard", "🧔\u{200D}♂\u{FE0F}"),
+    ("man_cartwheeling", "🤸\u{200D}♂\u{FE0F}"),
+    ("man_cook", "👨\u{200D}🍳"),
+    ("man_dancing", "🕺"),
+    ("man_facepalming", "🤦\u{200D}♂\u{FE0F}"),
+    ("man_factory_worker", "👨\u{200D}🏭"),
+    ("man_farmer", "👨\u{200D}🌾"),
+    ("man_feeding_baby", "👨\u{200D}🍼"),
+    ("man_firefighter", "👨\u{200D}🚒"),
+    ("man_health_worker", "👨\u{200D}⚕\u{FE0F}"),
+    ("man_in_manual_wheelchair", "👨\u{200D}🦽"),
+    ("man_in_motorized_wheelchair", "👨\u{200D}🦼"),
+    ("man_in_tuxedo", "🤵\u{200D}♂\u{FE0F}"),
+    ("man_judge", "👨\u{200D}⚖\u{FE0F}"),
+    ("man_juggling", "🤹\u{200D}♂\u{FE0F}"),
+    ("man_mechanic", "👨\u{200D}🔧"),
+    ("man_office_worker", "👨\u{200D}💼"),
+    ("man_pilot", "👨\u{200D}✈\u{FE0F}"),
+    ("man_playing_handball", "🤾\u{200D}♂\u{FE0F}"),
+    ("man_playing_water_polo", "🤽\u{200D}♂\u{FE0F}"),
+    ("man_scientist", "👨\u{200D}🔬"),
+    ("man_shrugging", "🤷\u{200D}♂\u{FE0F}"),
+    ("man_singer", "👨\u{200D}🎤"),
+    ("man_student", "👨\u{200D}🎓"),
+    ("man_teacher", "👨\u{200D}🏫"),
+    ("man_technologist", "👨\u{200D}💻"),
+    ("man_with_gua_pi_mao", "👲"),
+    ("man_with_probing_cane", "👨\u{200D}🦯"),
+    ("man_with_turban", "👳\u{200D}♂\u{FE0F}"),
+    ("man_with_veil", "👰\u{200D}♂\u{FE0F}"),
+    ("mandarin", "🍊"),
+    ("mango", "🥭"),
+    ("mans_shoe", "👞"),
+    ("mantelpiece_clock", "🕰\u{FE0F}"),
+    ("manual_wheelchair", "🦽"),
+    ("maple_leaf", "🍁"),
+    ("maracas", "🪇"),
+    ("marshall_islands", "🇲🇭"),
+    ("martial_arts_uniform", "🥋"),
+    ("martinique", "🇲🇶"),
+    ("mask", "😷"),
+    ("massage", "💆"),
+    ("massage_man", "💆\u{200D}♂\u{FE0F}"),
+    ("massage_woman", "💆\u{200D}♀\u{FE0F}"),
+    ("mate", "🧉"),
+    ("mauritania", "🇲🇷"),
+    ("mauritius", "🇲🇺"),
+    ("mayotte", "🇾🇹"),
+    ("meat_on_bone", "🍖"),
+    ("mechanic", "🧑\u{200D}🔧"),
+    ("mechanical_arm", "🦾"),
+    ("mechanical_leg", "🦿"),
+    ("medal_military", "🎖\u{FE0F}"),
+    ("medal_sports", "🏅"),
+    ("medical_symbol", "⚕\u{FE0F}"),
+    ("mega", "📣"),
+    ("melon", "🍈"),
+    ("melting_face", "🫠"),
+    ("memo", "📝"),
+    ("men_wrestling", "🤼\u{200D}♂\u{FE0F}"),
+    ("mending_heart", "❤\u{FE0F}\u{200D}🩹"),
+    ("menorah", "🕎"),
+    ("mens", "🚹"),
+    ("mermaid", "🧜\u{200D}♀\u{FE0F}"),
+    ("merman", "🧜\u{200D}♂\u{FE0F}"),
+    ("merperson", "🧜"),
+    ("metal", "🤘"),
+    ("metro", "🚇"),
+    ("mexico", "🇲🇽"),
+    ("microbe", "🦠"),
+    ("micronesia", "🇫🇲"),
+    ("microphone", "🎤"),
+    ("microscope", "🔬"),
+    ("middle_finger", "🖕"),
+    ("military_helmet", "🪖"),
+    ("milk_glass", "🥛"),
+    ("milky_way", "🌌"),
+    ("minibus", "🚐"),
+    ("minidisc", "💽"),
+    ("mirror", "🪞"),
+    ("mirror_ball", "🪩"),
+    ("mobile_phone_off", "📴"),
+    ("moldova", "🇲🇩"),
+    ("monaco", "🇲🇨"),
+    ("money_mouth_face", "🤑"),
+    ("money_with_wings", "💸"),
+    ("moneybag", "💰"),
+    ("mongolia", "🇲🇳"),
+    ("monkey", "🐒"),
+    ("monkey_face", "🐵"),
+    ("monocle_face", "🧐"),
+    ("monorail", "🚝"),
+    ("montenegro", "🇲🇪"),
+    ("montserrat", "🇲🇸"),
+    ("moon", "🌔"),
+    ("moon_cake", "🥮"),
+    ("moose", "🫎"),
+    ("morocco", "🇲🇦"),
+    ("mortar_board", "🎓"),
+    ("mosque", "🕌"),
+    ("mosquito", "🦟"),
+    ("motor_boat", "🛥\u{FE0F}"),
+    ("motor_scooter", "🛵"),
+    ("motorcycle", "🏍\u{FE0F}"),
+    ("motorized_wheelchair", "🦼"),
+    ("motorway", "🛣\u{FE0F}"),
+    ("mount_fuji", "🗻"),
+    ("mountain", "⛰\u{FE0F}"),
+    ("mountain_bicyclist", "🚵"),
+    ("mountain_biking_man", "🚵\u{200D}♂\u{FE0F}"),
+    ("mountain_biking_woman", "🚵\u{200D}♀\u{FE0F}"),
+    ("mountain_cableway", "🚠"),
+    ("mountain_railway", "🚞"),
+    ("mountain_snow", "🏔\u{FE0F}"),
+    ("mouse", "🐭"),
+    ("mouse2", "🐁"),
+    ("mouse_trap", "🪤"),
+    ("movie_camera", "🎥"),
+    ("moyai", "🗿"),
+    ("mozambique", "🇲🇿"),
+    ("mrs_claus", "🤶"),
+    ("muscle", "💪"),
+    ("mushroom", "🍄"),
+    ("musical_keyboard", "🎹"),
+    ("musical_note", "🎵"),
+    ("musical_score", "🎼"),
+    ("mute", "🔇"),
+    ("mx_claus", "🧑\u{200D}🎄"),
+    ("myanmar", "🇲🇲"),
+    ("nail_care", "💅"),
+    ("name_badge", "📛"),
+    ("namibia", "🇳🇦"),
+    ("national_park", "🏞\u{FE0F}"),
+    ("nauru", "🇳🇷"),
+    ("nauseated_face", "🤢"),
+    ("nazar_amulet", "🧿"),
+    ("necktie", "👔"),
+    ("negative_squared_cross_mark", "❎"),
+    ("nepal", "🇳🇵"),
+    ("nerd_face", "🤓"),
+    ("nest_with_eggs", "🪺"),
+    ("nesting_dolls", "🪆"),
+    ("netherlands", "🇳🇱"),
+    ("neutral_face", "😐"),
+    ("new", "🆕"),
+    ("new_caledonia", "🇳🇨"),
+    ("new_moon", "🌑"),
+    ("new_moon_with_face", "🌚"),
+    ("new_zealand", "🇳🇿"),
+    ("newspaper", "📰"),
+    ("newspaper_roll", "🗞\u{FE0F}"),
+    ("next_track_button", "⏭\u{FE0F}"),
+    ("ng", "🆖"),
+    ("ng_man", "🙅\u{200D}♂\u{FE0F}"),
+    ("ng_woman", "🙅\u{200D}♀\u{FE0F}"),
+    ("nicaragua", "🇳🇮"),
+    ("niger", "🇳🇪"),
+    ("nigeria", "🇳🇬"),
+    ("night_with_stars", "🌃"),
+    ("nine", "9\u{FE0F}\u{20E3}"),
+    ("ninja", "🥷"),
+    ("niue", "🇳🇺"),
+    ("no_bell", "🔕"),
+    ("no_bicycles", "🚳"),
+    ("no_entry", "⛔"),
+    ("no_entry_sign", "🚫"),
+    ("no_good", "🙅"),
+    ("no_good_man", "🙅\u{200D}♂\u{FE0F}"),
+    ("no_good_woman", "🙅\u{200D}♀\u{FE0F}"),
+    ("no_mobile_phones", "📵"),
+    ("no_mouth", "😶"),
+    ("no_pedestrians", "🚷"),
+    ("no_smoking", "🚭"),
+    ("non-potable_water", "🚱"),
+    ("norfolk_island", "🇳🇫"),
+    ("north_korea", "🇰🇵"),
+    ("northern_mariana_islands", "🇲🇵"),
+    ("norway", "🇳🇴"),
+    ("nose", "👃"),
+    ("notebook", "📓"),
+    ("notebook_with_decorative_cover", "📔"),
+    ("notes", "🎶"),
+    ("nut_and_bolt", "🔩"),
+    ("o", "⭕"),
+    ("o2", "🅾\u{FE0F}"),
+    ("ocean", "🌊"),
+    ("octopus", "🐙"),
+    ("oden", "🍢"),
+    ("office", "🏢"),
+    ("office_worker", "🧑\u{200D}💼"),
+    ("oil_drum", "🛢\u{FE0F}"),
+    ("ok", "🆗"),
+    ("ok_hand", "👌"),
+    ("ok_man", "🙆\u{200D}♂\u{FE0F}"),
+    ("ok_person", "🙆"),
+    ("ok_woman", "🙆\u{200D}♀\u{FE0F}"),
+    ("old_key", "🗝\u{FE0F}"),
+    ("older_adult", "🧓"),
+    ("older_man", "👴"),
+    ("older_woman", "👵"),
+    ("olive", "🫒"),
+    ("om", "🕉\u{FE0F}"),
+    ("oman", "🇴🇲"),
+    ("on", "🔛"),
+    ("oncoming_automobile", "🚘"),
+    ("oncoming_bus", "🚍"),
+    ("oncoming_police_car", "🚔"),
+    ("oncoming_taxi", "🚖"),
+    ("one", "1\u{FE0F}\u{20E3}"),
+    ("one_piece_swimsuit", "🩱"),
+    ("onion", "🧅"),
+    ("open_book", "📖"),
+    ("open_file_folder", "📂"),
+    ("open_hands", "👐"),
+    ("open_mouth", "😮"),
+    ("open_umbrella", "☂\u{FE0F}"),
+    ("ophiuchus", "⛎"),
+    ("orange", "🍊"),
+    ("orange_book", "📙"),
+    ("orange_circle", "🟠"),
+    ("orange_heart", "🧡"),
+    ("orange_square", "🟧"),
+    ("orangutan", "🦧"),
+    ("orthodox_cross", "☦\u{FE0F}"),
+    ("otter", "🦦"),
+    ("outbox_tray", "📤"),
+    ("owl", "🦉"),
+    ("ox", "🐂"),
+    ("oyster", "🦪"),
+    ("package", "📦"),
+    ("page_facing_up", "📄"),
+    ("page_with_curl", "📃"),
+    ("pager", "📟"),
+    ("paintbrush", "🖌\u{FE0F}"),
+    ("pakistan", "🇵🇰"),
+    ("palau", "🇵🇼"),
+    ("palestinian_territories", "🇵🇸"),
+    ("palm_down_hand", "🫳"),
+    ("palm_tree", "🌴"),
+    ("palm_up_hand", "🫴"),
+    ("palms_up_together", "🤲"),
+    ("panama", "🇵🇦"),
+    ("pancakes", "🥞"),
+    ("panda_face", "🐼"),
+    ("paperclip", "📎"),
+    ("paperclips", "🖇\u{FE0F}"),
+    ("papua_new_guinea", "🇵🇬"),
+    ("parachute", "🪂"),
+    ("paraguay", "🇵🇾"),
+    ("parasol_on_ground", "⛱\u{FE0F}"),
+    ("parking", "🅿\u{FE0F}"),
+    ("parrot", "🦜"),
+    ("part_alternation_mark", "〽\u{FE0F}"),
+    ("partly_sunny", "⛅"),
+    ("partying_face", "🥳"),
+    ("passenger_ship", "🛳\u{FE0F}"),
+    ("passport_control", "🛂"),
+    ("pause_button", "⏸\u{FE0F}"),
+    ("paw_prints", "🐾"),
+    ("pea_pod", "🫛"),
+    ("peace_symbol", "☮\u{FE0F}"),
+    ("peach", "🍑"),
+    ("peacock", "🦚"),
+    ("peanuts", "🥜"),
+    ("pear", "🍐"),
+    ("pen", "🖊\u{FE0F}"),
+    ("pencil", "📝"),
+    ("pencil2", "✏\u{FE0F}"),
+    ("penguin", "🐧"),
+    ("pensive", "😔"),
+    ("people_holding_hands", "🧑\u{200D}🤝\u{200D}🧑"),
+    ("people_hugging", "🫂"),
+    ("performing_arts", "🎭"),
+    ("persevere", "😣"),
+    ("person_bald", "🧑\u{200D}🦲"),
+    ("person_curly_hair", "🧑\u{200D}🦱"),
+    ("person_feeding_baby", "🧑\u{200D}🍼"),
+    ("person_fencing", "🤺"),
+    ("person_in_manual_wheelchair", "🧑\u{200D}🦽"),
+    ("person_in_motorized_wheelchair", "🧑\u{200D}🦼"),
+    ("person_in_tuxedo", "🤵"),
+    ("person_red_hair", "🧑\u{200D}🦰"),
+    ("person_white_hair", "🧑\u{200D}🦳"),
+    ("person_with_crown", "🫅"),
+    ("person_with_probing_cane", "🧑\u{200D}🦯"),
+    ("person_with_turban", "👳"),
+    ("person_with_veil", "👰"),
+    ("peru", "🇵🇪"),
+    ("petri_dish", "🧫"),
+    ("philippines", "🇵🇭"),
+    ("phone", "☎\u{FE0F}"),
+    ("pick", "⛏\u{FE0F}"),
+    ("pickup_truck", "🛻"),
+    ("pie", "🥧"),
+    ("pig", "🐷"),
+    ("pig2", "🐖"),
+    ("pig_nose", "🐽"),
+    ("pill", "💊"),
+    ("pilot", "🧑\u{200D}✈\u{FE0F}"),
+    ("pinata", "🪅"),
+    ("pinched_fingers", "🤌"),
+    ("pinching_hand", "🤏"),
+    ("pineapple", "🍍"),
+    ("ping_pong", "🏓"),
+    ("pink_heart", "🩷"),
+    ("pirate_flag", "🏴\u{200D}☠\u{FE0F}"),
+    ("pisces", "♓"),
+    ("pitcairn_islands", "🇵🇳"),
+    ("pizza", "🍕"),
+    ("placard", "🪧"),
+    ("place_of_worship", "🛐"),
+    ("plate_with_cutlery", "🍽\u{FE0F}"),
+    ("play_or_pause_button", "⏯\u{FE0F}"),
+    ("playground_slide", "🛝"),
+    ("pleading_face", "🥺"),
+    ("plunger", "🪠"),
+    ("point_down", "👇"),
+    ("point_left", "👈"),
+    ("point_right", "👉"),
+    ("point_up", "☝\u{FE0F}"),
+    ("point_up_2", "👆"),
+    ("poland", "🇵🇱"),
+    ("polar_bear", "🐻\u{200D}❄\u{FE0F}"),
+    ("police_car", "🚓"),
+    ("police_officer", "👮"),
+    ("policeman", "👮\u{200D}♂\u{FE0F}"),
+    ("policewoman", "👮\u{200D}♀\u{FE0F}"),
+    ("poodle", "🐩"),
+    ("poop", "💩"),
+    ("popcorn", "🍿"),
+    ("portugal", "🇵🇹"),
+    ("post_office", "🏣"),
+    ("postal_horn", "📯"),
+    ("postbox", "📮"),
+    ("potable_water", "🚰"),
+    ("potato", "🥔"),
+    ("potted_plant", "🪴"),
+    ("pouch", "👝"),
+    ("poultry_leg", "🍗"),
+    ("pound", "💷"),
+    ("pouring_liquid", "🫗"),
+    ("pout", "😡"),
+    ("pouting_cat", "😾"),
+    ("pouting_face", "🙎"),
+    ("pouting_man", "🙎\u{200D}♂\u{FE0F}"),
+    ("pouting_woman", "🙎\u{200D}♀\u{FE0F}"),
+    ("pray", "🙏"),
+    ("prayer_beads", "📿"),
+    ("pregnant_man", "🫃"),
+    ("pregnant_person", "🫄"),
+    ("pregnant_woman", "🤰"),
+    ("pretzel", "🥨"),
+    ("previous_track_button", "⏮\u{FE0F}"),
+    ("prince", "🤴"),
+    ("princess", "👸"),
+    ("printer", "🖨\u{FE0F}"),
+    ("probing_cane", "🦯"),
+    ("puerto_rico", "🇵🇷"),
+    ("punch", "👊"),
+    ("purple_circle", "🟣"),
+    ("purple_heart", "💜"),
+    ("purple_square", "🟪"),
+    ("purse", "👛"),
+    ("pushpin", "📌"),
+    ("put_litter_in_its_place", "🚮"),
+    ("qatar", "🇶🇦"),
+    ("question", "❓"),
+    ("rabbit", "🐰"),
+    ("rabbit2", "🐇"),
+    ("raccoon", "🦝"),
+    ("racehorse", "🐎"),
+    ("racing_car", "🏎\u{FE0F}"),
+    ("radio", "📻"),
+    ("radio_button", "🔘"),
+    ("radioactive", "☢\u{FE0F}"),
+    ("rage", "😡"),
+    ("railway_car", "🚃"),
+    ("railway_track", "🛤\u{FE0F}"),
+    ("rainbow", "🌈"),
+    ("rainbow_flag", "🏳\u{FE0F}\u{200D}🌈"),
+    ("raised_back_of_hand", "🤚"),
+    ("raised_eyebrow", "🤨"),
+    ("raised_hand", "✋"),
+    ("raised_hand_with_fingers_splayed", "🖐\u{FE0F}"),
+    ("raised_hands", "🙌"),
+    ("raising_hand", "🙋"),
+    ("raising_hand_man", "🙋\u{200D}♂\u{FE0F}"),
+    ("raising_hand_woman", "🙋\u{200D}♀\u{FE0F}"),
+    ("ram", "🐏"),
+    ("ramen", "🍜"),
+    ("rat", "🐀"),
+    ("razor", "🪒"),
+    ("receipt", "🧾"),
+    ("record_button", "⏺\u{FE0F}"),
+    ("recycle", "♻\u{FE0F}"),
+    ("red_car", "🚗"),
+    ("red_circle", "🔴"),
+    ("red_envelope", "🧧"),
+    ("red_haired_man", "👨\u{200D}🦰"),
+    ("red_haired_woman", "👩\u{200D}🦰"),
+    ("red_square", "🟥"),
+    ("registered", "®\u{FE0F}"),
+    ("relaxed", "☺\u{FE0F}"),
+    ("relieved", "😌"),
+    ("reminder_ribbon", "🎗\u{FE0F}"),
+    ("repeat", "🔁"),
+    ("repeat_one", "🔂"),
+    ("rescue_worker_helmet", "⛑\u{FE0F}"),
+    ("restroom", "🚻"),
+    ("reunion", "🇷🇪"),
+    ("revolving_hearts", "💞"),
+    ("rewind", "⏪"),
+    ("rhinoceros", "🦏"),
+    ("ribbon", "🎀"),
+    ("rice", "🍚"),
+    ("rice_ball", "🍙"),
+    ("rice_cracker", "🍘"),
+    ("rice_scene", "🎑"),
+    ("right_anger_bubble", "🗯\u{FE0F}"),
+    ("rightwards_hand", "🫱"),
+    ("rightwards_pushing_hand", "🫸"),
+    ("ring", "💍"),
+    ("ring_buoy", "🛟"),
+    ("ringed_planet", "🪐"),
+    ("robot", "🤖"),
+    ("rock", "🪨"),
+    ("rocket", "🚀"),
+    ("rofl", "🤣"),
+    ("roll_eyes", "🙄"),
+    ("roll_of_paper", "🧻"),
+    ("roller_coaster", "🎢"),
+    ("roller_skate", "🛼"),
+    ("romania", "🇷🇴"),
+    ("rooster", "🐓"),
+    ("rose", "🌹"),
+    ("rosette", "🏵\u{FE0F}"),
+    ("rotating_light", "🚨"),
+    ("round_pushpin", "📍"),
+    ("rowboat", "🚣"),
+    ("rowing_man", "🚣\u{200D}♂\u{FE0F}"),
+    ("rowing_woman", "🚣\u{200D}♀\u{FE0F}"),
+    ("ru", "🇷🇺"),
+    ("rugby_football", "🏉"),
+    ("runner", "🏃"),
+    ("running", "🏃"),
+    ("running_man", "🏃\u{200D}♂\u{FE0F}"),
+    ("running_shirt_with_sash", "🎽"),
+    ("running_woman", "🏃\u{200D}♀\u{FE0F}"),
+    ("rwanda", "🇷🇼"),
+    ("sa", "🈂\u{FE0F}"),
+    ("safety_pin", "🧷"),
+    ("safety_vest", "🦺"),
+    ("sagittarius", "♐"),
+    ("sailboat", "⛵"),
+    ("sake", "🍶"),
+    ("salt", "🧂"),
+    ("saluting_face", "🫡"),
+    ("samoa", "🇼🇸"),
+    ("san_marino", "🇸🇲"),
+    ("sandal", "👡"),
+    ("sandwich", "🥪"),
+    ("santa", "🎅"),
+    ("sao_tome_principe", "🇸🇹"),
+    ("sari", "🥻"),
+    ("sassy_man", "💁\u{200D}♂\u{FE0F}"),
+    ("sassy_woman", "💁\u{200D}♀\u{FE0F}"),
+    ("satellite", "📡"),
+    ("satisfied", "😆"),
+    ("saudi_arabia", "🇸🇦"),
+    ("sauna_man", "🧖\u{200D}♂\u{FE0F}"),
+    ("sauna_person", "🧖"),
+    ("sauna_woman", "🧖\u{200D}♀\u{FE0F}"),
+    ("sauropod", "🦕"),
+    ("saxophone", "🎷"),
+    ("scarf", "🧣"),
+    ("school", "🏫"),
+    ("school_satchel", "🎒"),
+    ("scientist", "🧑\u{200D}🔬"),
+    ("scissors", "✂\u{FE0F}"),
+    ("scorpion", "🦂"),
+    ("scorpius", "♏"),
+    (
+        "scotland",
+        "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+    ),
+    ("scream", "😱"),
+    ("scream_cat", "🙀"),
+    ("screwdriver", "🪛"),
+    ("scroll", "📜"),
+    ("seal", "🦭"),
+    ("seat", "💺"),
+    ("secret", "㊙\u{FE0F}"),
+    ("see_no_evil", "🙈"),
+    ("seedling", "🌱"),
+    ("selfie", "🤳"),
+    ("senegal", "🇸🇳"),
+    ("serbia", "🇷🇸"),
+    ("service_dog", "🐕\u{200D}🦺"),
+    ("seven", "7\u{FE0F}\u{20E3}"),
+    ("sewing_needle", "🪡"),
+    ("seychelles", "🇸🇨"),
+    ("shaking_face", "🫨"),
+    ("shallow_pan_of_food", "🥘"),
+    ("shamrock", "☘\u{FE0F}"),
+    ("shark", "🦈"),
+    ("shaved_ice", "🍧"),
+    ("sheep", "🐑"),
+    ("shell", "🐚"),
+    ("shield", "🛡\u{FE0F}"),
+    ("shinto_shrine", "⛩\u{FE0F}"),
+    ("ship", "🚢"),
+    ("shirt", "👕"),
+    ("shit", "💩"),
+    ("shoe", "👞"),
+    ("shopping", "🛍\u{FE0F}"),
+    ("shopping_cart", "🛒"),
+    ("shorts", "🩳"),
+    ("shower", "🚿"),
+    ("shrimp", "🦐"),
+    ("shrug", "🤷"),
+    ("shushing_face", "🤫"),
+    ("sierra_leone", "🇸🇱"),
+    ("signal_strength", "📶"),
+    ("singapore", "🇸🇬"),
+    ("singer", "🧑\u{200D}🎤"),
+    ("sint_maarten", "🇸🇽"),
+    ("six", "6\u{FE0F}\u{20E3}"),
+    ("six_pointed_star", "🔯"),
+    ("skateboard", "🛹"),
+    ("ski", "🎿"),
+    ("skier", "⛷\u{FE0F}"),
+    ("skull", "💀"),
+    ("skull_and_crossbones", "☠\u{FE0F}"),
+    ("skunk", "🦨"),
+    ("sled", "🛷"),
+    ("sleeping", "😴"),
+    ("sleeping_bed", "🛌"),
+    ("sleepy", "😪"),
+    ("slightly_frowning_face", "🙁"),
+    ("slightly_smiling_face", "🙂"),
+    ("slot_machine", "🎰"),
+    ("sloth", "🦥"),
+    ("slovakia", "🇸🇰"),
+    ("slovenia", "🇸🇮"),
+    ("small_airplane", "🛩\u{FE0F}"),
+    ("small_blue_diamond", "🔹"),
+    ("small_orange_diamond", "🔸"),
+    ("small_red_triangle", "🔺"),
+    ("small_red_triangle_down", "🔻"),
+    ("smile", "😄"),
+    ("smile_cat", "😸"),
+    ("smiley", "😃"),
+    ("smiley_cat", "😺"),
+    ("smiling_face_with_tear", "🥲"),
+    ("smiling_face_with_three_hearts", "🥰"),
+    ("smiling_imp", "😈"),
+    ("smirk", "😏"),
+    ("smirk_cat", "😼"),
+    ("smoking", "🚬"),
+    ("snail", "🐌"),
+    ("snake", "🐍"),
+    ("sneezing_face", "🤧"),
+    ("snowboarder", "🏂"),
+    ("snowflake", "❄\u{FE0F}"),
+    ("snowman", "⛄"),
+    ("snowman_with_snow", "☃\u{FE0F}"),
+    ("soap", "🧼"),
+    ("sob", "😭"),
+    ("soccer", "⚽"),
+    ("socks", "🧦"),
+    ("softball", "🥎"),
+    ("solomon_islands", "🇸🇧"),
+    ("somalia", "🇸🇴"),
+    ("soon", "🔜"),
+    ("sos", "🆘"),
+    ("sound", "🔉"),
+    ("south_africa", "🇿🇦"),
+    ("south_georgia_south_sandwich_islands", "🇬🇸"),
+    ("south_sudan", "🇸🇸"),
+    ("space_invader", "👾"),
+    ("spades", "♠\u{FE0F}"),
+    ("spaghetti", "🍝"),
+    ("sparkle", "❇\u{FE0F}"),
+    ("sparkler", "🎇"),
+    ("sparkles", "✨"),
+    ("sparkling_heart", "💖"),
+    ("speak_no_evil", "🙊"),
+    ("speaker", "🔈"),
+    ("speaking_head", "🗣\u{FE0F}"),
+    ("speech_balloon", "💬"),
+    ("speedboat", "🚤"),
+    ("spider", "🕷\u{FE0F}"),
+    ("spider_web", "🕸\u{FE0F}"),
+    ("spiral_calendar", "🗓\u{FE0F}"),
+    ("spiral_notepad", "🗒\u{FE0F}"),
+    ("sponge", "🧽"),
+    ("spoon", "🥄"),
+    ("squid", "🦑"),
+    ("sri_lanka", "🇱🇰"),
+    ("st_barthelemy", "🇧🇱"),
+    ("st_helena", "🇸🇭"),
+    ("st_kitts_nevis", "🇰🇳"),
+    ("st_lucia", "🇱🇨"),
+    ("st_martin", "🇲🇫"),
+    ("st_pierre_miquelon", "🇵🇲"),
+    ("st_vincent_grenadines", "🇻🇨"),
+    ("stadium", "🏟\u{FE0F}"),
+    ("standing_man", "🧍\u{200D}♂\u{FE0F}"),
+    ("standing_person", "🧍"),
+    ("standing_woman", "🧍\u{200D}♀\u{FE0F}"),
+    ("star", "⭐"),
+    ("star2", "🌟"),
+    ("star_and_crescent", "☪\u{FE0F}"),
+    ("star_of_david", "✡\u{FE0F}"),
+    ("star_struck", "🤩"),
+    ("stars", "🌠"),
+    ("station", "🚉"),
+    ("statue_of_liberty", "🗽"),
+    ("steam_locomotive", "🚂"),
+    ("stethoscope", "🩺"),
+    ("stew", "🍲"),
+    ("stop_button", "⏹\u{FE0F}"),
+    ("stop_sign", "🛑"),
+    ("stopwatch", "⏱\u{FE0F}"),
+    ("straight_ruler", "📏"),
+    ("strawberry", "🍓"),
+    ("stuck_out_tongue", "😛"),
+    ("stuck_out_tongue_closed_eyes", "😝"),
+    ("stuck_out_tongue_winking_eye", "😜"),
+    ("student", "🧑\u{200D}🎓"),
+    ("studio_microphone", "🎙\u{FE0F}"),
+    ("stuffed_flatbread", "🥙"),
+    ("sudan", "🇸🇩"),
+    ("sun_behind_large_cloud", "🌥\u{FE0F}"),
+    ("sun_behind_rain_cloud", "🌦\u{FE0F}"),
+    ("sun_behind_small_cloud", "🌤\u{FE0F}"),
+    ("sun_with_face", "🌞"),
+    ("sunflower", "🌻"),
+    ("sunglasses", "😎"),
+    ("sunny", "☀\u{FE0F}"),
+    ("sunrise", "🌅"),
+    ("sunrise_over_mountains", "🌄"),
+    ("superhero", "🦸"),
+    ("superhero_man", "🦸\u{200D}♂\u{FE0F}"),
+    ("superhero_woman", "🦸\u{200D}♀\u{FE0F}"),
+    ("supervillain", "🦹"),
+    ("supervillain_man", "🦹\u{200D}♂\u{FE0F}"),
+    ("supervillain_woman", "🦹\u{200D}♀\u{FE0F}"),
+    ("surfer", "🏄"),
+    ("surfing_man", "🏄\u{200D}♂\u{FE0F}"),
+    ("surfing_woman", "🏄\u{200D}♀\u{FE0F}"),
+    ("suriname", "🇸🇷"),
+    ("sushi", "🍣"),
+    ("suspension_railway", "🚟"),
+    ("svalbard_jan_mayen", "🇸🇯"),
+    ("swan", "🦢"),
+    ("swaziland", "🇸🇿"),
+    ("sweat", "😓"),
+    ("sweat_drops", "💦"),
+    ("sweat_smile", "😅"),
+    ("sweden", "🇸🇪"),
+    ("sweet_potato", "🍠"),
+    ("swim_brief", "🩲"),
+    ("swimmer", "🏊"),
+    ("swimming_man", "🏊\u{200D}♂\u{FE0F}"),
+    ("swimming_woman", "🏊\u{200D}♀\u{FE0F}"),
+    ("switzerland", "🇨🇭"),
+    ("symbols", "🔣"),
+    ("synagogue", "🕍"),
+    ("syria", "🇸🇾"),
+    ("syringe", "💉"),
+    ("t-rex", "🦖"),
+    ("taco", "🌮"),
+    ("tada", "🎉"),
+    ("taiwan", "🇹🇼"),
+    ("tajikistan", "🇹🇯"),
+    ("takeout_box", "🥡"),
+    ("tamale", "🫔"),
+    ("tanabata_tree", "🎋"),
+    ("tangerine", "🍊"),
+    ("tanzania", "🇹🇿"),
+    ("taurus", "♉"),
+    ("taxi", "🚕"),
+    ("tea", "🍵"),
+    ("teacher", "🧑\u{200D}🏫"),
+    ("teapot", "🫖"),
+    ("technologist", "🧑\u{200D}💻"),
+    ("teddy_bear", "🧸"),
+    ("telephone", "☎\u{FE0F}"),
+    ("telephone_receiver", "📞"),
+    ("telescope", "🔭"),
+    ("tennis", "🎾"),
+    ("tent", "⛺"),
+    ("test_tube", "🧪"),
+    ("thailand", "🇹🇭"),
+    ("thermometer", "🌡\u{FE0F}"),
+    ("thinking", "🤔"),
+    ("thong_sandal", "🩴"),
+    ("thought_balloon", "💭"),
+    ("thread", "🧵"),
+    ("three", "3\u{FE0F}\u{20E3}"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("ticket", "🎫"),
+    ("tickets", "🎟\u{FE0F}"),
+    ("tiger", "🐯"),
+    ("tiger2", "🐅"),
+    ("timer_clock", "⏲\u{FE0F}"),
+    ("timor_leste", "🇹🇱"),
+    ("tipping_hand_man", "💁\u{200D}♂\u{FE0F}"),
+    ("tipping_hand_person", "💁"),
+    ("tipping_hand_woman", "💁\u{200D}♀\u{FE0F}"),
+    ("tired_face", "😫"),
+    ("tm", "™\u{FE0F}"),
+    ("togo", "🇹🇬"),
+    ("toilet", "🚽"),
+    ("tokelau", "🇹🇰"),
+    ("tokyo_tower", "🗼"),
+    ("tomato", "🍅"),
+    ("tonga", "🇹🇴"),
+    ("tongue", "👅"),
+    ("toolbox", "🧰"),
+    ("tooth", "🦷"),
+    ("toothbrush", "🪥"),
+    ("top", "🔝"),
+    ("tophat", "🎩"),
+    ("tornado", "🌪\u{FE0F}"),
+    ("tr", "🇹🇷"),
+    ("trackball", "🖲\u{FE0F}"),
+    ("tractor", "🚜"),
+    ("traffic_light", "🚥"),
+    ("train", "🚋"),
+    ("train2", "🚆"),
+    ("tram", "🚊"),
+    ("transgender_flag", "🏳\u{FE0F}\u{200D}⚧\u{FE0F}"),
+    ("transgender_symbol", "⚧\u{FE0F}"),
+    ("triangular_flag_on_post", "🚩"),
+    ("triangular_ruler", "📐"),
+    ("trident", "🔱"),
+    ("trinidad_tobago", "🇹🇹"),
+    ("tristan_da_cunha", "🇹🇦"),
+    ("triumph", "😤"),
+    ("troll", "🧌"),
+    ("trolleybus", "🚎"),
+    ("trophy", "🏆"),
+    ("tropical_drink", "🍹"),
+    ("tropical_fish", "🐠"),
+    ("truck", "🚚"),
+    ("trumpet", "🎺"),
+    ("tshirt", "👕"),
+    ("tulip", "🌷"),
+    ("tumbler_glass", "🥃"),
+    ("tunisia", "🇹🇳"),
+    ("turkey", "🦃"),
+    ("turkmenistan", "🇹🇲"),
+    ("turks_caicos_islands", "🇹🇨"),
+    ("turtle", "🐢"),
+    ("tuvalu", "🇹🇻"),
+    ("tv", "📺"),
+    ("twisted_rightwards_arrows", "🔀"),
+    ("two", "2\u{FE0F}\u{20E3}"),
+    ("two_hearts", "💕"),
+    ("two_men_holding_hands", "👬"),
+    ("two_women_holding_hands", "👭"),
+    ("u5272", "🈹"),
+    ("u5408", "🈴"),
+    ("u55b6", "🈺"),
+    ("u6307", "🈯"),
+    ("u6708", "🈷\u{FE0F}"),
+    ("u6709", "🈶"),
+    ("u6e80", "🈵"),
+    ("u7121", "🈚"),
+    ("u7533", "🈸"),
+    ("u7981", "🈲"),
+    ("u7a7a", "🈳"),
+    ("uganda", "🇺🇬"),
+    ("uk", "🇬🇧"),
+    ("ukraine", "🇺🇦"),
+    ("umbrella", "☔"),
+    ("unamused", "😒"),
+    ("underage", "🔞"),
+    ("unicorn", "🦄"),
+    ("united_arab_emirates", "🇦🇪"),
+    ("united_nations", "🇺🇳"),
+    ("unlock", "🔓"),
+    ("up", "🆙"),
+    ("upside_down_face", "🙃"),
+    ("uruguay", "🇺🇾"),
+    ("us", "🇺🇸"),
+    ("us_outlying_islands", "🇺🇲"),
+    ("us_virgin_islands", "🇻🇮"),
+    ("uzbekistan", "🇺🇿"),
+    ("v", "✌\u{FE0F}"),
+    ("vampire", "🧛"),
+    ("vampire_man", "🧛\u{200D}♂\u{FE0F}"),
+    ("vampire_woman", "🧛\u{200D}♀\u{FE0F}"),
+    ("vanuatu", "🇻🇺"),
+    ("vatican_city", "🇻🇦"),
+    ("venezuela", "🇻🇪"),
+    ("vertical_traffic_light", "🚦"),
+    ("vhs", "📼"),
+    ("vibration_mode", "📳"),
+    ("video_camera", "📹"),
+    ("video_game", "🎮"),
+    ("vietnam", "🇻🇳"),
+    ("violin", "🎻"),
+    ("virgo", "♍"),
+    ("volcano", "🌋"),
+    ("volleyball", "🏐"),
+    ("vomiting_face", "🤮"),
+    ("vs", "🆚"),
+    ("vulcan_salute", "🖖"),
+    ("waffle", "🧇"),
+    (
+        "wales",
+        "🏴\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}",
+    ),
+    ("walking", "🚶"),
+    ("walking_man", "🚶\u{200D}♂\u{FE0F}"),
+    ("walking_woman", "🚶\u{200D}♀\u{FE0F}"),
+    ("wallis_futuna", "🇼🇫"),
+    ("waning_crescent_moon", "🌘"),
+    ("waning_gibbous_moon", "🌖"),
+    ("warning", "⚠\u{FE0F}"),
+    ("wastebasket", "🗑\u{FE0F}"),
+    ("watch", "⌚"),
+    ("water_buffalo", "🐃"),
+    ("water_polo", "🤽"),
+    ("watermelon", "🍉"),
+    ("wave", "👋"),
+    ("wavy_dash", "〰\u{FE0F}"),
+    ("waxing_crescent_moon", "🌒"),
+    ("waxing_gibbous_moon", "🌔"),
+    ("wc", "🚾"),
+    ("weary", "😩"),
+    ("wedding", "💒"),
+    ("weight_lifting", "🏋\u{FE0F}"),
+    ("weight_lifting_man", "🏋\u{FE0F}\u{200D}♂\u{FE0F}"),
+    ("weight_lifting_woman", "🏋\u{FE0F}\u{200D}♀\u{FE0F}"),
+    ("western_sahara", "🇪🇭"),
+    ("whale", "🐳"),
+    ("whale2", "🐋"),
+    ("wheel", "🛞"),
+    ("wheel_of_dharma", "☸\u{FE0F}"),
+    ("wheelchair", "♿"),
+    ("white_check_mark", "✅"),
+    ("white_circle", "⚪"),
+    ("white_flag", "🏳\u{FE0F}"),
+    ("white_flower", "💮"),
+    ("white_haired_man", "👨\u{200D}🦳"),
+    ("white_haired_woman", "👩\u{200D}🦳"),
+    ("white_heart", "🤍"),
+    ("white_large_square", "⬜"),
+    ("white_medium_small_square", "◽"),
+    ("white_medium_square", "◻\u{FE0F}"),
+    ("white_small_square", "▫\u{FE0F}"),
+    ("white_square_button", "🔳"),
+    ("wilted_flower", "🥀"),
+    ("wind_chime", "🎐"),
+    ("wind_face", "🌬\u{FE0F}"),
+    ("window", "🪟"),
+    ("wine_glass", "🍷"),
+    ("wing", "🪽"),
+    ("wink", "😉"),
+    ("wireless", "🛜"),
+    ("wolf", "🐺"),
+    ("woman", "👩"),
+    ("woman_artist", "👩\u{200D}🎨"),
+    ("woman_astronaut", "👩\u{200D}🚀"),
+    ("woman_beard", "🧔\u{200D}♀\u{FE0F}"),
+    ("woman_cartwheeling", "🤸\u{200D}♀\u{FE0F}"),
+    ("woman_cook", "👩\u{200D}🍳"),
+    ("woman_dancing", "💃"),
+    ("woman_facepalming", "🤦\u{200D}♀\u{FE0F}"),
+    ("woman_factory_worker", "👩\u{200D}🏭"),
+    ("woman_farmer", "👩\u{200D}🌾"),
+    ("woman_feeding_baby", "👩\u{200D}🍼"),
+    ("woman_firefighter", "👩\u{200D}🚒"),
+    ("woman_health_worker", "👩\u{200D}⚕\u{FE0F}"),
+    ("woman_in_manual_wheelchair", "👩\u{200D}🦽"),
+    ("woman_in_motorized_wheelchair", "👩\u{200D}🦼"),
+    ("woman_in_tuxedo", "🤵\u{200D}♀\u{FE0F}"),
+    ("woman_judge", "👩\u{200D}⚖\u{FE0F}"),
+    ("woman_juggling", "🤹\u{200D}♀\u{FE0F}"),
+    ("woman_mechanic", "👩\u{200D}🔧"),
+    ("woman_office_worker", "👩\u{200D}💼"),
+    ("woman_pilot", "👩\u{200D}✈\u{FE0F}"),
+    ("woman_playing_handball", "🤾\u{200D}♀\u{FE0F}"),
+    ("woman_playing_water_polo", "🤽\u{200D}♀\u{FE0F}"),
+    ("woman_scientist", "👩\u{200D}🔬"),
+    ("woman_shrugging", "🤷\u{200D}♀\u{FE0F}"),
+    ("woman_singer", "👩\u{200D}🎤"),
+    ("woman_student", "👩\u{200D}🎓"),
+    ("woman_teacher", "👩\u{200D}🏫"),
+    ("woman_technologist", "👩\u{200D}💻"),
+    ("woman_with_headscarf", "🧕"),
+    ("woman_with_probing_cane", "👩\u{200D}🦯"),
+    ("woman_with_turban", "👳\u{200D}♀\u{FE0F}"),
+    ("woman_with_veil", "👰\u{200D}♀\u{FE0F}"),
+    ("womans_clothes", "👚"),
+    ("womans_hat", "👒"),
+    ("women_wrestling", "🤼\u{200D}♀\u{FE0F}"),
+    ("womens", "🚺"),
+    ("wood", "🪵"),
+    ("woozy_face", "🥴"),
+    ("world_map", "🗺\u{FE0F}"),
+    ("worm", "🪱"),
+    ("worried", "😟"),
+    ("wrench", "🔧"),
+    ("wrestling", "🤼"),
+    ("writing_hand", "✍\u{FE0F}"),
+    ("x", "❌"),
+    ("x_ray", "🩻"),
+    ("yarn", "🧶"),
+    ("yawning_face", "🥱"),
+    ("yellow_circle", "🟡"),
+    ("yellow_heart", "💛"),
+    ("yellow_square", "🟨"),
+    ("yemen", "🇾🇪"),
+    ("yen", "💴"),
+    ("yin_yang", "☯\u{FE0F}"),
+    ("yo_yo", "🪀"),
+    ("yum", "😋"),
+    ("zambia", "🇿🇲"),
+    ("zany_face", "🤪"),
+    ("zap", "⚡"),
+    ("zebra", "🦓"),
+    ("zero", "0\u{FE0F}\u{20E3}"),
+    ("zimbabwe", "🇿🇼"),
+    ("zipper_mouth_face", "🤐"),
+    ("zombie", "🧟"),
+    ("zombie_man", "🧟\u{200D}♂\u{FE0F}"),
+    ("zombie_woman", "🧟\u{200D}♀\u{FE0F}"),
+    ("zzz", "💤"),
+];
+
+/// The first GitHub shortcode of every emoji, sorted by the emoji without the variation selector U+FE0F.
+static SHORTCODE_BY_EMOJI: [(&str, &str); 1870] = [
+    ("#\u{20E3}", "hash"),
+    ("*\u{20E3}", "asterisk"),
+    ("0\u{20E3}", "zero"),
+    ("1\u{20E3}", "one"),
+    ("2\u{20E3}", "two"),
+    ("3\u{20E3}", "three"),
+    ("4\u{20E3}", "four"),
+    ("5\u{20E3}", "five"),
+    ("6\u{20E3}", "six"),
+    ("7\u{20E3}", "seven"),
+    ("8\u{20E3}", "eight"),
+    ("9\u{20E3}", "nine"),
+    ("©", "copyright"),
+    ("®", "registered"),
+    ("‼", "bangbang"),
+    ("⁉", "interrobang"),
+    ("™", "tm"),
+    ("ℹ", "information_source"),
+    ("↔", "left_right_arrow"),
+    ("↕", "arrow_up_down"),
+    ("↖", "arrow_upper_left"),
+    ("↗", "arrow_upper_right"),
+    ("↘", "arrow_lower_right"),
+    ("↙", "arrow_lower_left"),
+    ("↩", "leftwards_arrow_with_hook"),
+    ("↪", "arrow_right_hook"),
+    ("⌚", "watch"),
+    ("⌛", "hourglass"),
+    ("⌨", "keyboard"),
+    ("⏏", "eject_button"),
+    ("⏩", "fast_forward"),
+    ("⏪", "rewind"),
+    ("⏫", "arrow_double_up"),
+    ("⏬", "arrow_double_down"),
+    ("⏭", "next_track_button"),
+    ("⏮", "previous_track_button"),
+    ("⏯", "play_or_pause_button"),
+    ("⏰", "alarm_clock"),
+    ("⏱", "stopwatch"),
+    ("⏲", "timer_clock"),
+    ("⏳", "hourglass_flowing_sand"),
+    ("⏸", "pause_button"),
+    ("⏹", "stop_button"),
+    ("⏺", "record_button"),
+    ("Ⓜ", "m"),
+    ("▪", "black_small_square"),
+    ("▫", "white_small_square"),
+    ("▶", "arrow_forward"),
+    ("◀", "arrow_backward"),
+    ("◻", "white_medium_square"),
+    ("◼", "black_medium_square"),
+    ("◽", "white_medium_small_square"),
+    ("◾", "black_medium_small_square"),
+    ("☀", "sunny"),
+    ("☁", "cloud"),
+    ("☂", "open_umbrella"),
+    ("☃", "snowman_with_snow"),
+    ("☄", "comet"),
+    ("☎", "phone"),
+    ("☑", "ballot_box_with_check"),
+    ("☔", "umbrella"),
+    ("☕", "coffee"),
+    ("☘", "shamrock"),
+    ("☝", "point_up"),
+    ("☠", "skull_and_crossbones"),
+    ("☢", "radioactive"),
+    ("☣", "biohazard"),
+    ("☦", "orthodox_cross"),
+    ("☪", "star_and_crescent"),
+    ("☮", "peace_symbol"),
+    ("☯", "yin_yang"),
+    ("☸", "wheel_of_dharma"),
+    ("☹", "frowning_face"),
+    ("☺", "relaxed"),
+    ("♀", "female_sign"),
+    ("♂", "male_sign"),
+    ("♈", "aries"),
+    ("♉", "taurus"),
+    ("♊", "gemini"),
+    ("♋", "cancer"),
+    ("♌", "leo"),
+    ("♍", "virgo"),
+    ("♎", "libra"),
+    ("♏", "scorpius"),
+    ("♐", "sagittarius"),
+    ("♑", "capricorn"),
+    ("♒", "aquarius"),
+    ("♓", "pisces"),
+    ("♟", "chess_pawn"),
+    ("♠", "spades"),
+    ("♣", "clubs"),
+    ("♥", "hearts"),
+    ("♦", "diamonds"),
+    ("♨", "hotsprings"),
+    ("♻", "recycle"),
+    ("♾", "infinity"),
+    ("♿", "wheelchair"),
+    ("⚒", "hammer_and_pick"),
+    ("⚓", "anchor"),
+    ("⚔", "crossed_swords"),
+    ("⚕", "medical_symbol"),
+    ("⚖", "balance_scale"),
+    ("⚗", "alembic"),
+    ("⚙", "gear"),
+    ("⚛", "atom_symbol"),
+    ("⚜", "fleur_de_lis"),
+    ("⚠", "warning"),
+    ("⚡", "zap"),
+    ("⚧", "transgender_symbol"),
+    ("⚪", "white_circle"),
+    ("⚫", "black_circle"),
+    ("⚰", "coffin"),
+    ("⚱", "funeral_urn"),
+    ("⚽", "soccer"),
+    ("⚾", "baseball"),
+    ("⛄", "snowman"),
+    ("⛅", "partly_sunny"),
+    ("⛈", "cloud_with_lightning_and_rain"),
+    ("⛎", "ophiuchus"),
+    ("⛏", "pick"),
+    ("⛑", "rescue_worker_helmet"),
+    ("⛓", "chains"),
+    ("⛔", "no_entry"),
+    ("⛩", "shinto_shrine"),
+    ("⛪", "church"),
+    ("⛰", "mountain"),
+    ("⛱", "parasol_on_ground"),
+    ("⛲", "fountain"),
+    ("⛳", "golf"),
+    ("⛴", "ferry"),
+    ("⛵", "boat"),
+    ("⛷", "skier"),
+    ("⛸", "ice_skate"),
+    ("⛹", "bouncing_ball_person"),
+    ("⛹\u{200D}♀", "bouncing_ball_woman"),
+    ("⛹\u{200D}♂", "bouncing_ball_man"),
+    ("⛺", "tent"),
+    ("⛽", "fuelpump"),
+    ("✂", "scissors"),
+    ("✅", "white_check_mark"),
+    ("✈", "airplane"),
+    ("✉", "envelope"),
+    ("✊", "fist_raised"),
+    ("✋", "hand"),
+    ("✌", "v"),
+    ("✍", "writing_hand"),
+    ("✏", "pencil2"),
+    ("✒", "black_nib"),
+    ("✔", "heavy_check_mark"),
+    ("✖", "heavy_multiplication_x"),
+    ("✝", "latin_cross"),
+    ("✡", "star_of_david"),
+    ("✨", "sparkles"),
+    ("✳", "eight_spoked_asterisk"),
+    ("✴", "eight_pointed_black_star"),
+    ("❄", "snowflake"),
+    ("❇", "sparkle"),
+    ("❌", "x"),
+    ("❎", "negative_squared_cross_mark"),
+    ("❓", "question"),
+    ("❔", "grey_question"),
+    ("❕", "grey_exclamation"),
+    ("❗", "exclamation"),
+    ("❣", "heavy_heart_exclamation"),
+    ("❤", "heart"),
+    ("❤\u{200D}🔥", "heart_on_fire"),
+    ("❤\u{200D}🩹", "mending_heart"),
+    ("➕", "heavy_plus_sign"),
+    ("➖", "heavy_minus_sign"),
+    ("➗", "heavy_division_sign"),
+    ("➡", "arrow_right"),
+    ("➰", "curly_loop"),
+    ("➿", "loop"),
+    ("⤴", "arrow_heading_up"),
+    ("⤵", "arrow_heading_down"),
+    ("⬅", "arrow_left"),
+    ("⬆", "arrow_up"),
+    ("⬇", "arrow_down"),
+    ("⬛", "black_large_square"),
+    ("⬜", "white_large_square"),
+    ("⭐", "star"),
+    ("⭕", "o"),
+    ("〰", "wavy_dash"),
+    ("〽", "part_alternation_mark"),
+    ("㊗", "congratulations"),
+    ("㊙", "secret"),
+    ("🀄", "mahjong"),
+    ("🃏", "black_joker"),
+    ("🅰", "a"),
+    ("🅱", "b"),
+    ("🅾", "o2"),
+    ("🅿", "parking"),
+    ("🆎", "ab"),
+    ("🆑", "cl"),
+    ("🆒", "cool"),
+    ("🆓", "free"),
+    ("🆔", "id"),
+    ("🆕", "new"),
+    ("🆖", "ng"),
+    ("🆗", "ok"),
+    ("🆘", "sos"),
+    ("🆙", "up"),
+    ("🆚", "vs"),
+    ("🇦🇨", "ascension_island"),
+    ("🇦🇩", "andorra"),
+    ("🇦🇪", "united_arab_emirates"),
+    ("🇦🇫", "afghanistan"),
+    ("🇦🇬", "antigua_barbuda"),
+    ("🇦🇮", "anguilla"),
+    ("🇦🇱", "albania"),
+    ("🇦🇲", "armenia"),
+    ("🇦🇴", "angola"),
+    ("🇦🇶", "antarctica"),
+    ("🇦🇷", "argentina"),
+    ("🇦🇸", "american_samoa"),
+    ("🇦🇹", "austria"),
+    ("🇦🇺", "australia"),
+    ("🇦🇼", "aruba"),
+    ("🇦🇽", "aland_islands"),
+    ("🇦🇿", "azerbaijan"),
+    ("🇧🇦", "bosnia_herzegovina"),
+    ("🇧🇧", "barbados"),
+    ("🇧🇩", "bangladesh"),
+    ("🇧🇪", "belgium"),
+    ("🇧🇫", "burkina_faso"),
+    ("🇧🇬", "bulgaria"),
+    ("🇧🇭", "bahrain"),
+    ("🇧🇮", "burundi"),
+    ("🇧🇯", "benin"),
+    ("🇧🇱", "st_barthelemy"),
+    ("🇧🇲", "bermuda"),
+    ("🇧🇳", "brunei"),
+    ("🇧🇴", "bolivia"),
+    ("🇧🇶", "caribbean_netherlands"),
+    ("🇧🇷", "brazil"),
+    ("🇧🇸", "bahamas"),
+    ("🇧🇹", "bhutan"),
+    ("🇧🇻", "bouvet_island"),
+    ("🇧🇼", "botswana"),
+    ("🇧🇾", "belarus"),
+    ("🇧🇿", "belize"),
+    ("🇨🇦", "canada"),
+    ("🇨🇨", "cocos_islands"),
+    ("🇨🇩", "congo_kinshasa"),
+    ("🇨🇫", "central_african_republic"),
+    ("🇨🇬", "congo_brazzaville"),
+    ("🇨🇭", "switzerland"),
+    ("🇨🇮", "cote_divoire"),
+    ("🇨🇰", "cook_islands"),
+    ("🇨🇱", "chile"),
+    ("🇨🇲", "cameroon"),
+    ("🇨🇳", "cn"),
+    ("🇨🇴", "colombia"),
+    ("🇨🇵", "clipperton_island"),
+    ("🇨🇷", "costa_rica"),
+    ("🇨🇺", "cuba"),
+    ("🇨🇻", "cape_verde"),
+    ("🇨🇼", "curacao"),
+    ("🇨🇽", "christmas_island"),
+    ("🇨🇾", "cyprus"),
+    ("🇨🇿", "czech_republic"),
+    ("🇩🇪", "de"),
+    ("🇩🇬", "diego_garcia"),
+    ("🇩🇯", "djibouti"),
+    ("🇩🇰", "denmark"),
+    ("🇩🇲", "dominica"),
+    ("🇩🇴", "dominican_republic"),
+    ("🇩🇿", "algeria"),
+    ("🇪🇦", "ceuta_melilla"),
+    ("🇪🇨", "ecuador"),
+    ("🇪🇪", "estonia"),
+    ("🇪🇬", "egypt"),
+    ("🇪🇭", "western_sahara"),
+    ("🇪🇷", "eritrea"),
+    ("🇪🇸", "es"),
+    ("🇪🇹", "ethiopia"),
+    ("🇪🇺", "eu"),
+    ("🇫🇮", "finland"),
+    ("🇫🇯", "fiji"),
+    ("🇫🇰", "falkland_islands"),
+    ("🇫🇲", "micronesia"),
+    ("🇫🇴", "faroe_islands"),
+    ("🇫🇷", "fr"),
+    ("🇬🇦", "gabon"),
+    ("🇬🇧", "gb"),
+    ("🇬🇩", "grenada"),
+    ("🇬🇪", "georgia"),
+    ("🇬🇫", "french_guiana"),
+    ("🇬🇬", "guernsey"),
+    ("🇬🇭", "ghana"),
+    ("🇬🇮", "gibraltar"),
+    ("🇬🇱", "greenland"),
+    ("🇬🇲", "gambia"),
+    ("🇬🇳", "guinea"),
+    ("🇬🇵", "guadeloupe"),
+    ("🇬🇶", "equatorial_guinea"),
+    ("🇬🇷", "greece"),
+    ("🇬🇸", "south_georgia_south_sandwich_islands"),
+    ("🇬🇹", "guatemala"),
+    ("🇬🇺", "guam"),
+    ("🇬🇼", "guinea_bissau"),
+    ("🇬🇾", "guyana"),
+    ("🇭🇰", "hong_kong"),
+    ("🇭🇲", "heard_mcdonald_islands"),
+    ("🇭🇳", "honduras"),
+    ("🇭🇷", "croatia"),
+    ("🇭🇹", "haiti"),
+    ("🇭🇺", "hungary"),
+    ("🇮🇨", "canary_islands"),
+    ("🇮🇩", "indonesia"),
+    ("🇮🇪", "ireland"),
+    ("🇮🇱", "israel"),
+    ("🇮🇲", "isle_of_man"),
+    ("🇮🇳", "india"),
+    ("🇮🇴", "british_indian_ocean_territory"),
+    ("🇮🇶", "iraq"),
+    ("🇮🇷", "iran"),
+    ("🇮🇸", "iceland"),
+    ("🇮🇹", "it"),
+    ("🇯🇪", "jersey"),
+    ("🇯🇲", "jamaica"),
+    ("🇯🇴", "jordan"),
+    ("🇯🇵", "jp"),
+    ("🇰🇪", "kenya"),
+    ("🇰🇬", "kyrgyzstan"),
+    ("🇰🇭", "cambodia"),
+    ("🇰🇮", "kiribati"),
+    ("🇰🇲", "comoros"),
+    ("🇰🇳", "st_kitts_nevis"),
+    ("🇰🇵", "north_korea"),
+    ("🇰🇷", "kr"),
+    ("🇰🇼", "kuwait"),
+    ("🇰🇾", "cayman_islands"),
+    ("🇰🇿", "kazakhstan"),
+    ("🇱🇦", "laos"),
+    ("🇱🇧", "lebanon"),
+    ("🇱🇨", "st_lucia"),
+    ("🇱🇮", "liechtenstein"),
+    ("🇱🇰", "sri_lanka"),
+    ("🇱🇷", "liberia"),
+    ("🇱🇸", "lesotho"),
+    ("🇱🇹", "lithuania"),
+    ("🇱🇺", "luxembourg"),
+    ("🇱🇻", "latvia"),
+    ("🇱🇾", "libya"),
+    ("🇲🇦", "morocco"),
+    ("🇲🇨", "monaco"),
+    ("🇲🇩", "moldova"),
+    ("🇲🇪", "montenegro"),
+    ("🇲🇫", "st_martin"),
+    ("🇲🇬", "madagascar"),
+    ("🇲🇭", "marshall_islands"),
+    ("🇲🇰", "macedonia"),
+    ("🇲🇱", "mali"),
+    ("🇲🇲", "myanmar"),
+    ("🇲🇳", "mongolia"),
+    ("🇲🇴", "macau"),
+    ("🇲🇵", "northern_mariana_islands"),
+    ("🇲🇶", "martinique"),
+    ("🇲🇷", "mauritania"),
+    ("🇲🇸", "montserrat"),
+    ("🇲🇹", "malta"),
+    ("🇲🇺", "mauritius"),
+    ("🇲🇻", "maldives"),
+    ("🇲🇼", "malawi"),
+    ("🇲🇽", "mexico"),
+    ("🇲🇾", "malaysia"),
+    ("🇲🇿", "mozambique"),
+    ("🇳🇦", "namibia"),
+    ("🇳🇨", "new_caledonia"),
+    ("🇳🇪", "niger"),
+    ("🇳🇫", "norfolk_island"),
+    ("🇳🇬", "nigeria"),
+    ("🇳🇮", "nicaragua"),
+    ("🇳🇱", "netherlands"),
+    ("🇳🇴", "norway"),
+    ("🇳🇵", "nepal"),
+    ("🇳🇷", "nauru"),
+    ("🇳🇺", "niue"),
+    ("🇳🇿", "new_zealand"),
+    ("🇴🇲", "oman"),
+    ("🇵🇦", "panama"),
+    ("🇵🇪", "peru"),
+    ("🇵🇫", "french_polynesia"),
+    ("🇵🇬", "papua_new_guinea"),
+    ("🇵🇭", "philippines"),
+    ("🇵🇰", "pakistan"),
+    ("🇵🇱", "poland"),
+    ("🇵🇲", "st_pierre_miquelon"),
+    ("🇵🇳", "pitcairn_islands"),
+    ("🇵🇷", "puerto_rico"),
+    ("🇵🇸", "palestinian_territories"),
+    ("🇵🇹", "portugal"),
+    ("🇵🇼", "palau"),
+    ("🇵🇾", "paraguay"),
+    ("🇶🇦", "qatar"),
+    ("🇷🇪", "reunion"),
+    ("🇷🇴", "romania"),
+    ("🇷🇸", "serbia"),
+    ("🇷🇺", "ru"),
+    ("🇷🇼", "rwanda"),
+    ("🇸🇦", "saudi_arabia"),
+    ("🇸🇧", "solomon_islands"),
+    ("🇸🇨", "seychelles"),
+    ("🇸🇩", "sudan"),
+    ("🇸🇪", "sweden"),
+    ("🇸🇬", "singapore"),
+    ("🇸🇭", "st_helena"),
+    ("🇸🇮", "slovenia"),
+    ("🇸🇯", "svalbard_jan_mayen"),
+    ("🇸🇰", "slovakia"),
+    ("🇸🇱", "sierra_leone"),
+    ("🇸🇲", "san_marino"),
+    ("🇸🇳", "senegal"),
+    ("🇸🇴", "somalia"),
+    ("🇸🇷", "suriname"),
+    ("🇸🇸", "south_sudan"),
+    ("🇸🇹", "sao_tome_principe"),
+    ("🇸🇻", "el_salvador"),
+    ("🇸🇽", "sint_maarten"),
+    ("🇸🇾", "syria"),
+    ("🇸🇿", "swaziland"),
+    ("🇹🇦", "tristan_da_cunha"),
+    ("🇹🇨", "turks_caicos_islands"),
+    ("🇹🇩", "chad"),
+    ("🇹🇫", "french_southern_territories"),
+    ("🇹🇬", "togo"),
+    ("🇹🇭", "thailand"),
+    ("🇹🇯", "tajikistan"),
+    ("🇹🇰", "tokelau"),
+    ("🇹🇱", "timor_leste"),
+    ("🇹🇲", "turkmenistan"),
+    ("🇹🇳", "tunisia"),
+    ("🇹🇴", "tonga"),
+    ("🇹🇷", "tr"),
+    ("🇹🇹", "trinidad_tobago"),
+    ("🇹🇻", "tuvalu"),
+    ("🇹🇼", "taiwan"),
+    ("🇹🇿", "tanzania"),
+    ("🇺🇦", "ukraine"),
+    ("🇺🇬", "uganda"),
+    ("🇺🇲", "us_outlying_islands"),
+    ("🇺🇳", "united_nations"),
+    ("🇺🇸", "us"),
+    ("🇺🇾", "uruguay"),
+    ("🇺🇿", "uzbekistan"),
+    ("🇻🇦", "vatican_city"),
+    ("🇻🇨", "st_vincent_grenadines"),
+    ("🇻🇪", "venezuela"),
+    ("🇻🇬", "british_virgin_islands"),
+    ("🇻🇮", "us_virgin_islands"),
+    ("🇻🇳", "vietnam"),
+    ("🇻🇺", "vanuatu"),
+    ("🇼🇫", "wallis_futuna"),
+    ("🇼🇸", "samoa"),
+    ("🇽🇰", "kosovo"),
+    ("🇾🇪", "yemen"),
+    ("🇾🇹", "mayotte"),
+    ("🇿🇦", "south_africa"),
+    ("🇿🇲", "zambia"),
+    ("🇿🇼", "zimbabwe"),
+    ("🈁", "koko"),
+    ("🈂", "sa"),
+    ("🈚", "u7121"),
+    ("🈯", "u6307"),
+    ("🈲", "u7981"),
+    ("🈳", "u7a7a"),
+    ("🈴", "u5408"),
+    ("🈵", "u6e80"),
+    ("🈶", "u6709"),
+    ("🈷", "u6708"),
+    ("🈸", "u7533"),
+    ("🈹", "u5272"),
+    ("🈺", "u55b6"),
+    ("🉐", "ideograph_advantage"),
+    ("🉑", "accept"),
+    ("🌀", "cyclone"),
+    ("🌁", "foggy"),
+    ("🌂", "closed_umbrella"),
+    ("🌃", "night_with_stars"),
+    ("🌄", "sunrise_over_mountains"),
+    ("🌅", "sunrise"),
+    ("🌆", "city_sunset"),
+    ("🌇", "city_sunrise"),
+    ("🌈", "rainbow"),
+    ("🌉", "bridge_at_night"),
+    ("🌊", "ocean"),
+    ("🌋", "volcano"),
+    ("🌌", "milky_way"),
+    ("🌍", "earth_africa"),
+    ("🌎", "earth_americas"),
+    ("🌏", "earth_asia"),
+    ("🌐", "globe_with_meridians"),
+    ("🌑", "new_moon"),
+    ("🌒", "waxing_crescent_moon"),
+    ("🌓", "first_quarter_moon"),
+    ("🌔", "moon"),
+    ("🌕", "full_moon"),
+    ("🌖", "waning_gibbous_moon"),
+    ("🌗", "last_quarter_moon"),
+    ("🌘", "waning_crescent_moon"),
+    ("🌙", "crescent_moon"),
+    ("🌚", "new_moon_with_face"),
+    ("🌛", "first_quarter_moon_with_face"),
+    ("🌜", "last_quarter_moon_with_face"),
+    ("🌝", "full_moon_with_face"),
+    ("🌞", "sun_with_face"),
+    ("🌟", "star2"),
+    ("🌠", "stars"),
+    ("🌡", "thermometer"),
+    ("🌤", "sun_behind_small_cloud"),
+    ("🌥", "sun_behind_large_cloud"),
+    ("🌦", "sun_behind_rain_cloud"),
+    ("🌧", "cloud_with_rain"),
+    ("🌨", "cloud_with_snow"),
+    ("🌩", "cloud_with_lightning"),
+    ("🌪", "tornado"),
+    ("🌫", "fog"),
+    ("🌬", "wind_face"),
+    ("🌭", "hotdog"),
+    ("🌮", "taco"),
+    ("🌯", "burrito"),
+    ("🌰", "chestnut"),
+    ("🌱", "seedling"),
+    ("🌲", "evergreen_tree"),
+    ("🌳", "deciduous_tree"),
+    ("🌴", "palm_tree"),
+    ("🌵", "cactus"),
+    ("🌶", "hot_pepper"),
+    ("🌷", "tulip"),
+    ("🌸", "cherry_blossom"),
+    ("🌹", "rose"),
+    ("🌺", "hibiscus"),
+    ("🌻", "sunflower"),
+    ("🌼", "blossom"),
+    ("🌽", "corn"),
+    ("🌾", "ear_of_rice"),
+    ("🌿", "herb"),
+    ("🍀", "four_leaf_clover"),
+    ("🍁", "maple_leaf"),
+    ("🍂", "fallen_leaf"),
+    ("🍃", "leaves"),
+    ("🍄", "mushroom"),
+    ("🍅", "tomato"),
+    ("🍆", "eggplant"),
+    ("🍇", "grapes"),
+    ("🍈", "melon"),
+    ("🍉", "watermelon"),
+    ("🍊", "tangerine"),
+    ("🍋", "lemon"),
+    ("🍌", "banana"),
+    ("🍍", "pineapple"),
+    ("🍎", "apple"),
+    ("🍏", "green_apple"),
+    ("🍐", "pear"),
+    ("🍑", "peach"),
+    ("🍒", "cherries"),
+    ("🍓", "strawberry"),
+    ("🍔", "hamburger"),
+    ("🍕", "pizza"),
+    ("🍖", "meat_on_bone"),
+    ("🍗", "poultry_leg"),
+    ("🍘", "rice_cracker"),
+    ("🍙", "rice_ball"),
+    ("🍚", "rice"),
+    ("🍛", "curry"),
+    ("🍜", "ramen"),
+    ("🍝", "spaghetti"),
+    ("🍞", "bread"),
+    ("🍟", "fries"),
+    ("🍠", "sweet_potato"),
+    ("🍡", "dango"),
+    ("🍢", "oden"),
+    ("🍣", "sushi"),
+    ("🍤", "fried_shrimp"),
+    ("🍥", "fish_cake"),
+    ("🍦", "icecream"),
+    ("🍧", "shaved_ice"),
+    ("🍨", "ice_cream"),
+    ("🍩", "doughnut"),
+    ("🍪", "cookie"),
+    ("🍫", "chocolate_bar"),
+    ("🍬", "candy"),
+    ("🍭", "lollipop"),
+    ("🍮", "custard"),
+    ("🍯", "honey_pot"),
+    ("🍰", "cake"),
+    ("🍱", "bento"),
+    ("🍲", "stew"),
+    ("🍳", "fried_egg"),
+    ("🍴", "fork_and_knife"),
+    ("🍵", "tea"),
+    ("🍶", "sake"),
+    ("🍷", "wine_glass"),
+    ("🍸", "cocktail"),
+    ("🍹", "tropical_drink"),
+    ("🍺", "beer"),
+    ("🍻", "beers"),
+    ("🍼", "baby_bottle"),
+    ("🍽", "plate_with_cutlery"),
+    ("🍾", "champagne"),
+    ("🍿", "popcorn"),
+    ("🎀", "ribbon"),
+    ("🎁", "gift"),
+    ("🎂", "birthday"),
+    ("🎃", "jack_o_lantern"),
+    ("🎄", "christmas_tree"),
+    ("🎅", "santa"),
+    ("🎆", "fireworks"),
+    ("🎇", "sparkler"),
+    ("🎈", "balloon"),
+    ("🎉", "tada"),
+    ("🎊", "confetti_ball"),
+    ("🎋", "tanabata_tree"),
+    ("🎌", "crossed_flags"),
+    ("🎍", "bamboo"),
+    ("🎎", "dolls"),
+    ("🎏", "flags"),
+    ("🎐", "wind_chime"),
+    ("🎑", "rice_scene"),
+    ("🎒", "school_satchel"),
+    ("🎓", "mortar_board"),
+    ("🎖", "medal_military"),
+    ("🎗", "reminder_ribbon"),
+    ("🎙", "studio_microphone"),
+    ("🎚", "level_slider"),
+    ("🎛", "control_knobs"),
+    ("🎞", "film_strip"),
+    ("🎟", "tickets"),
+    ("🎠", "carousel_horse"),
+    ("🎡", "ferris_wheel"),
+    ("🎢", "roller_coaster"),
+    ("🎣", "fishing_pole_and_fish"),
+    ("🎤", "microphone"),
+    ("🎥", "movie_camera"),
+    ("🎦", "cinema"),
+    ("🎧", "headphones"),
+    ("🎨", "art"),
+    ("🎩", "tophat"),
+    ("🎪", "circus_tent"),
+    ("🎫", "ticket"),
+    ("🎬", "clapper"),
+    ("🎭", "performing_arts"),
+    ("🎮", "video_game"),
+    ("🎯", "dart"),
+    ("🎰", "slot_machine"),
+    ("🎱", "8ball"),
+    ("🎲", "game_die"),
+    ("🎳", "bowling"),
+    ("🎴", "flower_playing_cards"),
+    ("🎵", "musical_note"),
+    ("🎶", "notes"),
+    ("🎷", "saxophone"),
+    ("🎸", "guitar"),
+    ("🎹", "musical_keyboard"),
+    ("🎺", "trumpet"),
+    ("🎻", "violin"),
+    ("🎼", "musical_score"),
+    ("🎽", "running_shirt_with_sash"),
+    ("🎾", "tennis"),
+    ("🎿", "ski"),
+    ("🏀", "basketball"),
+    ("🏁", "checkered_flag"),
+    ("🏂", "snowboarder"),
+    ("🏃", "runner"),
+    ("🏃\u{200D}♀", "running_woman"),
+    ("🏃\u{200D}♂", "running_man"),
+    ("🏄", "surfer"),
+    ("🏄\u{200D}♀", "surfing_woman"),
+    ("🏄\u{200D}♂", "surfing_man"),
+    ("🏅", "medal_sports"),
+    ("🏆", "trophy"),
+    ("🏇", "horse_racing"),
+    ("🏈", "football"),
+    ("🏉", "rugby_football"),
+    ("🏊", "swimmer"),
+    ("🏊\u{200D}♀", "swimming_woman"),
+    ("🏊\u{200D}♂", "swimming_man"),
+    ("🏋", "weight_lifting"),
+    ("🏋\u{200D}♀", "weight_lifting_woman"),
+    ("🏋\u{200D}♂", "weight_lifting_man"),
+    ("🏌", "golfing"),
+    ("🏌\u{200D}♀", "golfing_woman"),
+    ("🏌\u{200D}♂", "golfing_man"),
+    ("🏍", "motorcycle"),
+    ("🏎", "racing_car"),
+    ("🏏", "cricket_game"),
+    ("🏐", "volleyball"),
+    ("🏑", "field_hockey"),
+    ("🏒", "ice_hockey"),
+    ("🏓", "ping_pong"),
+    ("🏔", "mountain_snow"),
+    ("🏕", "camping"),
+    ("🏖", "beach_umbrella"),
+    ("🏗", "building_construction"),
+    ("🏘", "houses"),
+    ("🏙", "cityscape"),
+    ("🏚", "derelict_house"),
+    ("🏛", "classical_building"),
+    ("🏜", "desert"),
+    ("🏝", "desert_island"),
+    ("🏞", "national_park"),
+    ("🏟", "stadium"),
+    ("🏠", "house"),
+    ("🏡", "house_with_garden"),
+    ("🏢", "office"),
+    ("🏣", "post_office"),
+    ("🏤", "european_post_office"),
+    ("🏥", "hospital"),
+    ("🏦", "bank"),
+    ("🏧", "atm"),
+    ("🏨", "hotel"),
+    ("🏩", "love_hotel"),
+    ("🏪", "convenience_store"),
+    ("🏫", "school"),
+    ("🏬", "department_store"),
+    ("🏭", "factory"),
+    ("🏮", "izakaya_lantern"),
+    ("🏯", "japanese_castle"),
+    ("🏰", "european_castle"),
+    ("🏳", "white_flag"),
+    ("🏳\u{200D}⚧", "transgender_flag"),
+    ("🏳\u{200D}🌈", "rainbow_flag"),
+    ("🏴", "black_flag"),
+    ("🏴\u{200D}☠", "pirate_flag"),
+    (
+        "🏴\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}",
+        "england",
+    ),
+    (
+        "🏴\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}",
+        "scotland",
+    ),
+    (
+        "🏴\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}",
+        "wales",
+    ),
+    ("🏵", "rosette"),
+    ("🏷", "label"),
+    ("🏸", "badminton"),
+    ("🏹", "bow_and_arrow"),
+    ("🏺", "amphora"),
+    ("🐀", "rat"),
+    ("🐁", "mouse2"),
+    ("🐂", "ox"),
+    ("🐃", "water_buffalo"),
+    ("🐄", "cow2"),
+    ("🐅", "tiger2"),
+    ("🐆", "leopard"),
+    ("🐇", "rabbit2"),
+    ("🐈", "cat2"),
+    ("🐈\u{200D}⬛", "black_cat"),
+    ("🐉", "dragon"),
+    ("🐊", "crocodile"),
+    ("🐋", "whale2"),
+    ("🐌", "snail"),
+    ("🐍", "snake"),
+    ("🐎", "racehorse"),
+    ("🐏", "ram"),
+    ("🐐", "goat"),
+    ("🐑", "sheep"),
+    ("🐒", "monkey"),
+    ("🐓", "rooster"),
+    ("🐔", "chicken"),
+    ("🐕", "dog2"),
+    ("🐕\u{200D}🦺", "service_dog"),
+    ("🐖", "pig2"),
+    ("🐗", "boar"),
+    ("🐘", "elephant"),
+    ("🐙", "octopus"),
+    ("🐚", "shell"),
+    ("🐛", "bug"),
+    ("🐜", "ant"),
+    ("🐝", "bee"),
+    ("🐞", "lady_beetle"),
+    ("🐟", "fish"),
+    ("🐠", "tropical_fish"),
+    ("🐡", "blowfish"),
+    ("🐢", "turtle"),
+    ("🐣", "hatching_chick"),
+    ("🐤", "baby_chick"),
+    ("🐥", "hatched_chick"),
+    ("🐦", "bird"),
+    ("🐦\u{200D}⬛", "black_bird"),
+    ("🐧", "penguin"),
+    ("🐨", "koala"),
+    ("🐩", "poodle"),
+    ("🐪", "dromedary_camel"),
+    ("🐫", "camel"),
+    ("🐬", "dolphin"),
+    ("🐭", "mouse"),
+    ("🐮", "cow"),
+    ("🐯", "tiger"),
+    ("🐰", "rabbit"),
+    ("🐱", "cat"),
+    ("🐲", "dragon_face"),
+    ("🐳", "whale"),
+    ("🐴", "horse"),
+    ("🐵", "monkey_face"),
+    ("🐶", "dog"),
+    ("🐷", "pig"),
+    ("🐸", "frog"),
+    ("🐹", "hamster"),
+    ("🐺", "wolf"),
+    ("🐻", "bear"),
+    ("🐻\u{200D}❄", "polar_bear"),
+    ("🐼", "panda_face"),
+    ("🐽", "pig_nose"),
+    ("🐾", "feet"),
+    ("🐿", "chipmunk"),
+    ("👀", "eyes"),
+    ("👁", "eye"),
+    ("👁\u{200D}🗨", "eye_speech_bubble"),
+    ("👂", "ear"),
+    ("👃", "nose"),
+    ("👄", "lips"),
+    ("👅", "tongue"),
+    ("👆", "point_up_2"),
+    ("👇", "point_down"),
+    ("👈", "point_left"),
+    ("👉", "point_right"),
+    ("👊", "fist_oncoming"),
+    ("👋", "wave"),
+    ("👌", "ok_hand"),
+    ("👍", "+1"),
+    ("👎", "-1"),
+    ("👏", "clap"),
+    ("👐", "open_hands"),
+    ("👑", "crown"),
+    ("👒", "womans_hat"),
+    ("👓", "eyeglasses"),
+    ("👔", "necktie"),
+    ("👕", "shirt"),
+    ("👖", "jeans"),
+    ("👗", "dress"),
+    ("👘", "kimono"),
+    ("👙", "bikini"),
+    ("👚", "womans_clothes"),
+    ("👛", "purse"),
+    ("👜", "handbag"),
+    ("👝", "pouch"),
+    ("👞", "mans_shoe"),
+    ("👟", "athletic_shoe"),
+    ("👠", "high_heel"),
+    ("👡", "sandal"),
+    ("👢", "boot"),
+    ("👣", "footprints"),
+    ("👤", "bust_in_silhouette"),
+    ("👥", "busts_in_silhouette"),
+    ("👦", "boy"),
+    ("👧", "girl"),
+    ("👨", "man"),
+    ("👨\u{200D}⚕", "man_health_worker"),
+    ("👨\u{200D}⚖", "man_judge"),
+    ("👨\u{200D}✈", "man_pilot"),
+    ("👨\u{200D}❤\u{200D}👨", "couple_with_heart_man_man"),
+    ("👨\u{200D}❤\u{200D}💋\u{200D}👨", "couplekiss_man_man"),
+    ("👨\u{200D}🌾", "man_farmer"),
+    ("👨\u{200D}🍳", "man_cook"),
+    ("👨\u{200D}🍼", "man_feeding_baby"),
+    ("👨\u{200D}🎓", "man_student"),
+    ("👨\u{200D}🎤", "man_singer"),
+    ("👨\u{200D}🎨", "man_artist"),
+    ("👨\u{200D}🏫", "man_teacher"),
+    ("👨\u{200D}🏭", "man_factory_worker"),
+    ("👨\u{200D}👦", "family_man_boy"),
+    ("👨\u{200D}👦\u{200D}👦", "family_man_boy_boy"),
+    ("👨\u{200D}👧", "family_man_girl"),
+    ("👨\u{200D}👧\u{200D}👦", "family_man_girl_boy"),
+    ("👨\u{200D}👧\u{200D}👧", "family_man_girl_girl"),
+    ("👨\u{200D}👨\u{200D}👦", "family_man_man_boy"),
+    ("👨\u{200D}👨\u{200D}👦\u{200D}👦", "family_man_man_boy_boy"),
+    ("👨\u{200D}👨\u{200D}👧", "family_man_man_girl"),
+    (
+        "👨\u{200D}👨\u{200D}👧\u{200D}👦",
+        "family_man_man_girl_boy",
+    ),
+    (
+        "👨\u{200D}👨\u{200D}👧\u{200D}👧",
+        "family_man_man_girl_girl",
+    ),
+    ("👨\u{200D}👩\u{200D}👦", "family_man_woman_boy"),
+    (
+        "👨\u{200D}👩\u{200D}👦\u{200D}👦",
+        "family_man_woman_boy_boy",
+    ),
+    ("👨\u{200D}👩\u{200D}👧", "family_man_woman_girl"),
+    (
+        "👨\u{200D}👩\u{200D}👧\u{200D}👦",
+        "family_man_woman_girl_boy",
+    ),
+    (
+        "👨\u{200D}👩\u{200D}👧\u{200D}👧",
+        "family_man_woman_girl_girl",
+    ),
+    ("👨\u{200D}💻", "man_technologist"),
+    ("👨\u{200D}💼", "man_office_worker"),
+    ("👨\u{200D}🔧", "man_mechanic"),
+    ("👨\u{200D}🔬", "man_scientist"),
+    ("👨\u{200D}🚀", "man_astronaut"),
+    ("👨\u{200D}🚒", "man_firefighter"),
+    ("👨\u{200D}🦯", "man_with_probing_cane"),
+    ("👨\u{200D}🦰", "red_haired_man"),
+    ("👨\u{200D}🦱", "curly_haired_man"),
+    ("👨\u{200D}🦲", "bald_man"),
+    ("👨\u{200D}🦳", "white_haired_man"),
+    ("👨\u{200D}🦼", "man_in_motorized_wheelchair"),
+    ("👨\u{200D}🦽", "man_in_manual_wheelchair"),
+    ("👩", "woman"),
+    ("👩\u{200D}⚕", "woman_health_worker"),
+    ("👩\u{200D}⚖", "woman_judge"),
+    ("👩\u{200D}✈", "woman_pilot"),
+    ("👩\u{200D}❤\u{200D}👨", "couple_with_heart_woman_man"),
+    ("👩\u{200D}❤\u{200D}👩", "couple_with_heart_woman_woman"),
+    ("👩\u{200D}❤\u{200D}💋\u{200D}👨", "couplekiss_man_woman"),
+    ("👩\u{200D}❤\u{200D}💋\u{200D}👩", "couplekiss_woman_woman"),
+    ("👩\u{200D}🌾", "woman_farmer"),
+    ("👩\u{200D}🍳", "woman_cook"),
+    ("👩\u{200D}🍼", "woman_feeding_baby"),
+    ("👩\u{200D}🎓", "woman_student"),
+    ("👩\u{200D}🎤", "woman_singer"),
+    ("👩\u{200D}🎨", "woman_artist"),
+    ("👩\u{200D}🏫", "woman_teacher"),
+    ("👩\u{200D}🏭", "woman_factory_worker"),
+    ("👩\u{200D}👦", "family_woman_boy"),
+    ("👩\u{200D}👦\u{200D}👦", "family_woman_boy_boy"),
+    ("👩\u{200D}👧", "family_woman_girl"),
+    ("👩\u{200D}👧\u{200D}👦", "family_woman_girl_boy"),
+    ("👩\u{200D}👧\u{200D}👧", "family_woman_girl_girl"),
+    ("👩\u{200D}👩\u{200D}👦", "family_woman_woman_boy"),
+    (
+        "👩\u{200D}👩\u{200D}👦\u{200D}👦",
+        "family_woman_woman_boy_boy",
+    ),
+    ("👩\u{200D}👩\u{200D}👧", "family_woman_woman_girl"),
+    (
+        "👩\u{200D}👩\u{200D}👧\u{200D}👦",
+        "family_woman_woman_girl_boy",
+    ),
+    (
+        "👩\u{200D}👩\u{200D}👧\u{200D}👧",
+        "family_woman_woman_girl_girl",
+    ),
+    ("👩\u{200D}💻", "woman_technologist"),
+    ("👩\u{200D}💼", "woman_office_worker"),
+    ("👩\u{200D}🔧", "woman_mechanic"),
+    ("👩\u{200D}🔬", "woman_scientist"),
+    ("👩\u{200D}🚀", "woman_astronaut"),
+    ("👩\u{200D}🚒", "woman_firefighter"),
+    ("👩\u{200D}🦯", "woman_with_probing_cane"),
+    ("👩\u{200D}🦰", "red_haired_woman"),
+    ("👩\u{200D}🦱", "curly_haired_woman"),
+    ("👩\u{200D}🦲", "bald_woman"),
+    ("👩\u{200D}🦳", "white_haired_woman"),
+    ("👩\u{200D}🦼", "woman_in_motorized_wheelchair"),
+    ("👩\u{200D}🦽", "woman_in_manual_wheelchair"),
+    ("👪", "family"),
+    ("👫", "couple"),
+    ("👬", "two_men_holding_hands"),
+    ("👭", "two_women_holding_hands"),
+    ("👮", "police_officer"),
+    ("👮\u{200D}♀", "policewoman"),
+    ("👮\u{200D}♂", "policeman"),
+    ("👯", "dancers"),
+    ("👯\u{200D}♀", "dancing_women"),
+    ("👯\u{200D}♂", "dancing_men"),
+    ("👰", "person_with_veil"),
+    ("👰\u{200D}♀", "woman_with_veil"),
+    ("👰\u{200D}♂", "man_with_veil"),
+    ("👱", "blond_haired_person"),
+    ("👱\u{200D}♀", "blond_haired_woman"),
+    ("👱\u{200D}♂", "blond_haired_man"),
+    ("👲", "man_with_gua_pi_mao"),
+    ("👳", "person_with_turban"),
+    ("👳\u{200D}♀", "woman_with_turban"),
+    ("👳\u{200D}♂", "man_with_turban"),
+    ("👴", "older_man"),
+    ("👵", "older_woman"),
+    ("👶", "baby"),
+    ("👷", "construction_worker"),
+    ("👷\u{200D}♀", "construction_worker_woman"),
+    ("👷\u{200D}♂", "construction_worker_man"),
+    ("👸", "princess"),
+    ("👹", "japanese_ogre"),
+    ("👺", "japanese_goblin"),
+    ("👻", "ghost"),
+    ("👼", "angel"),
+    ("👽", "alien"),
+    ("👾", "space_invader"),
+    ("👿", "imp"),
+    ("💀", "skull"),
+    ("💁", "tipping_hand_person"),
+    ("💁\u{200D}♀", "tipping_hand_woman"),
+    ("💁\u{200D}♂", "tipping_hand_man"),
+    ("💂", "guard"),
+    ("💂\u{200D}♀", "guardswoman"),
+    ("💂\u{200D}♂", "guardsman"),
+    ("💃", "woman_dancing"),
+    ("💄", "lipstick"),
+    ("💅", "nail_care"),
+    ("💆", "massage"),
+    ("💆\u{200D}♀", "massage_woman"),
+    ("💆\u{200D}♂", "massage_man"),
+    ("💇", "haircut"),
+    ("💇\u{200D}♀", "haircut_woman"),
+    ("💇\u{200D}♂", "haircut_man"),
+    ("💈", "barber"),
+    ("💉", "syringe"),
+    ("💊", "pill"),
+    ("💋", "kiss"),
+    ("💌", "love_letter"),
+    ("💍", "ring"),
+    ("💎", "gem"),
+    ("💏", "couplekiss"),
+    ("💐", "bouquet"),
+    ("💑", "couple_with_heart"),
+    ("💒", "wedding"),
+    ("💓", "heartbeat"),
+    ("💔", "broken_heart"),
+    ("💕", "two_hearts"),
+    ("💖", "sparkling_heart"),
+    ("💗", "heartpulse"),
+    ("💘", "cupid"),
+    ("💙", "blue_heart"),
+    ("💚", "green_heart"),
+    ("💛", "yellow_heart"),
+    ("💜", "purple_heart"),
+    ("💝", "gift_heart"),
+    ("💞", "revolving_hearts"),
+    ("💟", "heart_decoration"),
+    ("💠", "diamond_shape_with_a_dot_inside"),
+    ("💡", "bulb"),
+    ("💢", "anger"),
+    ("💣", "bomb"),
+    ("💤", "zzz"),
+    ("💥", "boom"),
+    ("💦", "sweat_drops"),
+    ("💧", "droplet"),
+    ("💨", "dash"),
+    ("💩", "hankey"),
+    ("💪", "muscle"),
+    ("💫", "dizzy"),
+    ("💬", "speech_balloon"),
+    ("💭", "thought_balloon"),
+    ("💮", "white_flower"),
+    ("💯", "100"),
+    ("💰", "moneybag"),
+    ("💱", "currency_exchange"),
+    ("💲", "heavy_dollar_sign"),
+    ("💳", "credit_card"),
+    ("💴", "yen"),
+    ("💵", "dollar"),
+    ("💶", "euro"),
+    ("💷", "pound"),
+    ("💸", "money_with_wings"),
+    ("💹", "chart"),
+    ("💺", "seat"),
+    ("💻", "computer"),
+    ("💼", "briefcase"),
+    ("💽", "minidisc"),
+    ("💾", "floppy_disk"),
+    ("💿", "cd"),
+    ("📀", "dvd"),
+    ("📁", "file_folder"),
+    ("📂", "open_file_folder"),
+    ("📃", "page_with_curl"),
+    ("📄", "page_facing_up"),
+    ("📅", "date"),
+    ("📆", "calendar"),
+    ("📇", "card_index"),
+    ("📈", "chart_with_upwards_trend"),
+    ("📉", "chart_with_downwards_trend"),
+    ("📊", "bar_chart"),
+    ("📋", "clipboard"),
+    ("📌", "pushpin"),
+    ("📍", "round_pushpin"),
+    ("📎", "paperclip"),
+    ("📏", "straight_ruler"),
+    ("📐", "triangular_ruler"),
+    ("📑", "bookmark_tabs"),
+    ("📒", "ledger"),
+    ("📓", "notebook"),
+    ("📔", "notebook_with_decorative_cover"),
+    ("📕", "closed_book"),
+    ("📖", "book"),
+    ("📗", "green_book"),
+    ("📘", "blue_book"),
+    ("📙", "orange_book"),
+    ("📚", "books"),
+    ("📛", "name_badge"),
+    ("📜", "scroll"),
+    ("📝", "memo"),
+    ("📞", "telephone_receiver"),
+    ("📟", "pager"),
+    ("📠", "fax"),
+    ("📡", "satellite"),
+    ("📢", "loudspeaker"),
+    ("📣", "mega"),
+    ("📤", "outbox_tray"),
+    ("📥", "inbox_tray"),
+    ("📦", "package"),
+    ("📧", "email"),
+    ("📨", "incoming_envelope"),
+    ("📩", "envelope_with_arrow"),
+    ("📪", "mailbox_closed"),
+    ("📫", "mailbox"),
+    ("📬", "mailbox_with_mail"),
+    ("📭", "mailbox_with_no_mail"),
+    ("📮", "postbox"),
+    ("📯", "postal_horn"),
+    ("📰", "newspaper"),
+    ("📱", "iphone"),
+    ("📲", "calling"),
+    ("📳", "vibration_mode"),
+    ("📴", "mobile_phone_off"),
+    ("📵", "no_mobile_phones"),
+    ("📶", "signal_strength"),
+    ("📷", "camera"),
+    ("📸", "camera_flash"),
+    ("📹", "video_camera"),
+    ("📺", "tv"),
+    ("📻", "radio"),
+    ("📼", "vhs"),
+    ("📽", "film_projector"),
+    ("📿", "prayer_beads"),
+    ("🔀", "twisted_rightwards_arrows"),
+    ("🔁", "repeat"),
+    ("🔂", "repeat_one"),
+    ("🔃", "arrows_clockwise"),
+    ("🔄", "arrows_counterclockwise"),
+    ("🔅", "low_brightness"),
+    ("🔆", "high_brightness"),
+    ("🔇", "mute"),
+    ("🔈", "speaker"),
+    ("🔉", "sound"),
+    ("🔊", "loud_sound"),
+    ("🔋", "battery"),
+    ("🔌", "electric_plug"),
+    ("🔍", "mag"),
+    ("🔎", "mag_right"),
+    ("🔏", "lock_with_ink_pen"),
+    ("🔐", "closed_lock_with_key"),
+    ("🔑", "key"),
+    ("🔒", "lock"),
+    ("🔓", "unlock"),
+    ("🔔", "bell"),
+    ("🔕", "no_bell"),
+    ("🔖", "bookmark"),
+    ("🔗", "link"),
+    ("🔘", "radio_button"),
+    ("🔙", "back"),
+    ("🔚", "end"),
+    ("🔛", "on"),
+    ("🔜", "soon"),
+    ("🔝", "top"),
+    ("🔞", "underage"),
+    ("🔟", "keycap_ten"),
+    ("🔠", "capital_abcd"),
+    ("🔡", "abcd"),
+    ("🔢", "1234"),
+    ("🔣", "symbols"),
+    ("🔤", "abc"),
+    ("🔥", "fire"),
+    ("🔦", "flashlight"),
+    ("🔧", "wrench"),
+    ("🔨", "hammer"),
+    ("🔩", "nut_and_bolt"),
+    ("🔪", "hocho"),
+    ("🔫", "gun"),
+    ("🔬", "microscope"),
+    ("🔭", "telescope"),
+    ("🔮", "crystal_ball"),
+    ("🔯", "six_pointed_star"),
+    ("🔰", "beginner"),
+    ("🔱", "trident"),
+    ("🔲", "black_square_button"),
+    ("🔳", "white_square_button"),
+    ("🔴", "red_circle"),
+    ("🔵", "large_blue_circle"),
+    ("🔶", "large_orange_diamond"),
+    ("🔷", "large_blue_diamond"),
+    ("🔸", "small_orange_diamond"),
+    ("🔹", "small_blue_diamond"),
+    ("🔺", "small_red_triangle"),
+    ("🔻", "small_red_triangle_down"),
+    ("🔼", "arrow_up_small"),
+    ("🔽", "arrow_down_small"),
+    ("🕉", "om"),
+    ("🕊", "dove"),
+    ("🕋", "kaaba"),
+    ("🕌", "mosque"),
+    ("🕍", "synagogue"),
+    ("🕎", "menorah"),
+    ("🕐", "clock1"),
+    ("🕑", "clock2"),
+    ("🕒", "clock3"),
+    ("🕓", "clock4"),
+    ("🕔", "clock5"),
+    ("🕕", "clock6"),
+    ("🕖", "clock7"),
+    ("🕗", "clock8"),
+    ("🕘", "clock9"),
+    ("🕙", "clock10"),
+    ("🕚", "clock11"),
+    ("🕛", "clock12"),
+    ("🕜", "clock130"),
+    ("🕝", "clock230"),
+    ("🕞", "clock330"),
+    ("🕟", "clock430"),
+    ("🕠", "clock530"),
+    ("🕡", "clock630"),
+    ("🕢", "clock730"),
+    ("🕣", "clock830"),
+    ("🕤", "clock930"),
+    ("🕥", "clock1030"),
+    ("🕦", "clock1130"),
+    ("🕧", "clock1230"),
+    ("🕯", "candle"),
+    ("🕰", "mantelpiece_clock"),
+    ("🕳", "hole"),
+    ("🕴", "business_suit_levitating"),
+    ("🕵", "detective"),
+    ("🕵\u{200D}♀", "female_detective"),
+    ("🕵\u{200D}♂", "male_detective"),
+    ("🕶", "dark_sunglasses"),
+    ("🕷", "spider"),
+    ("🕸", "spider_web"),
+    ("🕹", "joystick"),
+    ("🕺", "man_dancing"),
+    ("🖇", "paperclips"),
+    ("🖊", "pen"),
+    ("🖋", "fountain_pen"),
+    ("🖌", "paintbrush"),
+    ("🖍", "crayon"),
+    ("🖐", "raised_hand_with_fingers_splayed"),
+    ("🖕", "middle_finger"),
+    ("🖖", "vulcan_salute"),
+    ("🖤", "black_heart"),
+    ("🖥", "desktop_computer"),
+    ("🖨", "printer"),
+    ("🖱", "computer_mouse"),
+    ("🖲", "trackball"),
+    ("🖼", "framed_picture"),
+    ("🗂", "card_index_dividers"),
+    ("🗃", "card_file_box"),
+    ("🗄", "file_cabinet"),
+    ("🗑", "wastebasket"),
+    ("🗒", "spiral_notepad"),
+    ("🗓", "spiral_calendar"),
+    ("🗜", "clamp"),
+    ("🗝", "old_key"),
+    ("🗞", "newspaper_roll"),
+    ("🗡", "dagger"),
+    ("🗣", "speaking_head"),
+    ("🗨", "left_speech_bubble"),
+    ("🗯", "right_anger_bubble"),
+    ("🗳", "ballot_box"),
+    ("🗺", "world_map"),
+    ("🗻", "mount_fuji"),
+    ("🗼", "tokyo_tower"),
+    ("🗽", "statue_of_liberty"),
+    ("🗾", "japan"),
+    ("🗿", "moyai"),
+    ("😀", "grinning"),
+    ("😁", "grin"),
+    ("😂", "joy"),
+    ("😃", "smiley"),
+    ("😄", "smile"),
+    ("😅", "sweat_smile"),
+    ("😆", "laughing"),
+    ("😇", "innocent"),
+    ("😈", "smiling_imp"),
+    ("😉", "wink"),
+    ("😊", "blush"),
+    ("😋", "yum"),
+    ("😌", "relieved"),
+    ("😍", "heart_eyes"),
+    ("😎", "sunglasses"),
+    ("😏", "smirk"),
+    ("😐", "neutral_face"),
+    ("😑", "expressionless"),
+    ("😒", "unamused"),
+    ("😓", "sweat"),
+    ("😔", "pensive"),
+    ("😕", "confused"),
+    ("😖", "confounded"),
+    ("😗", "kissing"),
+    ("😘", "kissing_heart"),
+    ("😙", "kissing_smiling_eyes"),
+    ("😚", "kissing_closed_eyes"),
+    ("😛", "stuck_out_tongue"),
+    ("😜", "stuck_out_tongue_winking_eye"),
+    ("😝", "stuck_out_tongue_closed_eyes"),
+    ("😞", "disappointed"),
+    ("😟", "worried"),
+    ("😠", "angry"),
+    ("😡", "rage"),
+    ("😢", "cry"),
+    ("😣", "persevere"),
+    ("😤", "triumph"),
+    ("😥", "disappointed_relieved"),
+    ("😦", "frowning"),
+    ("😧", "anguished"),
+    ("😨", "fearful"),
+    ("😩", "weary"),
+    ("😪", "sleepy"),
+    ("😫", "tired_face"),
+    ("😬", "grimacing"),
+    ("😭", "sob"),
+    ("😮", "open_mouth"),
+    ("😮\u{200D}💨", "face_exhaling"),
+    ("😯", "hushed"),
+    ("😰", "cold_sweat"),
+    ("😱", "scream"),
+    ("😲", "astonished"),
+    ("😳", "flushed"),
+    ("😴", "sleeping"),
+    ("😵", "dizzy_face"),
+    ("😵\u{200D}💫", "face_with_spiral_eyes"),
+    ("😶", "no_mouth"),
+    ("😶\u{200D}🌫", "face_in_clouds"),
+    ("😷", "mask"),
+    ("😸", "smile_cat"),
+    ("😹", "joy_cat"),
+    ("😺", "smiley_cat"),
+    ("😻", "heart_eyes_cat"),
+    ("😼", "smirk_cat"),
+    ("😽", "kissing_cat"),
+    ("😾", "pouting_cat"),
+    ("😿", "crying_cat_face"),
+    ("🙀", "scream_cat"),
+    ("🙁", "slightly_frowning_face"),
+    ("🙂", "slightly_smiling_face"),
+    ("🙃", "upside_down_face"),
+    ("🙄", "roll_eyes"),
+    ("🙅", "no_good"),
+    ("🙅\u{200D}♀", "no_good_woman"),
+    ("🙅\u{200D}♂", "no_good_man"),
+    ("🙆", "ok_person"),
+    ("🙆\u{200D}♀", "ok_woman"),
+    ("🙆\u{200D}♂", "ok_man"),
+    ("🙇", "bow"),
+    ("🙇\u{200D}♀", "bowing_woman"),
+    ("🙇\u{200D}♂", "bowing_man"),
+    ("🙈", "see_no_evil"),
+    ("🙉", "hear_no_evil"),
+    ("🙊", "speak_no_evil"),
+    ("🙋", "raising_hand"),
+    ("🙋\u{200D}♀", "raising_hand_woman"),
+    ("🙋\u{200D}♂", "raising_hand_man"),
+    ("🙌", "raised_hands"),
+    ("🙍", "frowning_person"),
+    ("🙍\u{200D}♀", "frowning_woman"),
+    ("🙍\u{200D}♂", "frowning_man"),
+    ("🙎", "pouting_face"),
+    ("🙎\u{200D}♀", "pouting_woman"),
+    ("🙎\u{200D}♂", "pouting_man"),
+    ("🙏", "pray"),
+    ("🚀", "rocket"),
+    ("🚁", "helicopter"),
+    ("🚂", "steam_locomotive"),
+    ("🚃", "railway_car"),
+    ("🚄", "bullettrain_side"),
+    ("🚅", "bullettrain_front"),
+    ("🚆", "train2"),
+    ("🚇", "metro"),
+    ("🚈", "light_rail"),
+    ("🚉", "station"),
+    ("🚊", "tram"),
+    ("🚋", "train"),
+    ("🚌", "bus"),
+    ("🚍", "oncoming_bus"),
+    ("🚎", "trolleybus"),
+    ("🚏", "busstop"),
+    ("🚐", "minibus"),
+    ("🚑", "ambulance"),
+    ("🚒", "fire_engine"),
+    ("🚓", "police_car"),
+    ("🚔", "oncoming_police_car"),
+    ("🚕", "taxi"),
+    ("🚖", "oncoming_taxi"),
+    ("🚗", "car"),
+    ("🚘", "oncoming_automobile"),
+    ("🚙", "blue_car"),
+    ("🚚", "truck"),
+    ("🚛", "articulated_lorry"),
+    ("🚜", "tractor"),
+    ("🚝", "monorail"),
+    ("🚞", "mountain_railway"),
+    ("🚟", "suspension_railway"),
+    ("🚠", "mountain_cableway"),
+    ("🚡", "aerial_tramway"),
+    ("🚢", "ship"),
+    ("🚣", "rowboat"),
+    ("🚣\u{200D}♀", "rowing_woman"),
+    ("🚣\u{200D}♂", "rowing_man"),
+    ("🚤", "speedboat"),
+    ("🚥", "traffic_light"),
+    ("🚦", "vertical_traffic_light"),
+    ("🚧", "construction"),
+    ("🚨", "rotating_light"),
+    ("🚩", "triangular_flag_on_post"),
+    ("🚪", "door"),
+    ("🚫", "no_entry_sign"),
+    ("🚬", "smoking"),
+    ("🚭", "no_smoking"),
+    ("🚮", "put_litter_in_its_place"),
+    ("🚯", "do_not_litter"),
+    ("🚰", "potable_water"),
+    ("🚱", "non-potable_water"),
+    ("🚲", "bike"),
+    ("🚳", "no_bicycles"),
+    ("🚴", "bicyclist"),
+    ("🚴\u{200D}♀", "biking_woman"),
+    ("🚴\u{200D}♂", "biking_man"),
+    ("🚵", "mountain_bicyclist"),
+    ("🚵\u{200D}♀", "mountain_biking_woman"),
+    ("🚵\u{200D}♂", "mountain_biking_man"),
+    ("🚶", "walking"),
+    ("🚶\u{200D}♀", "walking_woman"),
+    ("🚶\u{200D}♂", "walking_man"),
+    ("🚷", "no_pedestrians"),
+    ("🚸", "children_crossing"),
+    ("🚹", "mens"),
+    ("🚺", "womens"),
+    ("🚻", "restroom"),
+    ("🚼", "baby_symbol"),
+    ("🚽", "toilet"),
+    ("🚾", "wc"),
+    ("🚿", "shower"),
+    ("🛀", "bath"),
+    ("🛁", "bathtub"),
+    ("🛂", "passport_control"),
+    ("🛃", "customs"),
+    ("🛄", "baggage_claim"),
+    ("🛅", "left_luggage"),
+    ("🛋", "couch_and_lamp"),
+    ("🛌", "sleeping_bed"),
+    ("🛍", "shopping"),
+    ("🛎", "bellhop_bell"),
+    ("🛏", "bed"),
+    ("🛐", "place_of_worship"),
+    ("🛑", "stop_sign"),
+    ("🛒", "shopping_cart"),
+    ("🛕", "hindu_temple"),
+    ("🛖", "hut"),
+    ("🛗", "elevator"),
+    ("🛜", "wireless"),
+    ("🛝", "playground_slide"),
+    ("🛞", "wheel"),
+    ("🛟", "ring_buoy"),
+    ("🛠", "hammer_and_wrench"),
+    ("🛡", "shield"),
+    ("🛢", "oil_drum"),
+    ("🛣", "motorway"),
+    ("🛤", "railway_track"),
+    ("🛥", "motor_boat"),
+    ("🛩", "small_airplane"),
+    ("🛫", "flight_departure"),
+    ("🛬", "flight_arrival"),
+    ("🛰", "artificial_satellite"),
+    ("🛳", "passenger_ship"),
+    ("🛴", "kick_scooter"),
+    ("🛵", "motor_scooter"),
+    ("🛶", "canoe"),
+    ("🛷", "sled"),
+    ("🛸", "flying_saucer"),
+    ("🛹", "skateboard"),
+    ("🛺", "auto_rickshaw"),
+    ("🛻", "pickup_truck"),
+    ("🛼", "roller_skate"),
+    ("🟠", "orange_circle"),
+    ("🟡", "yellow_circle"),
+    ("🟢", "green_circle"),
+    ("🟣", "purple_circle"),
+    ("🟤", "brown_circle"),
+    ("🟥", "red_square"),
+    ("🟦", "blue_square"),
+    ("🟧", "orange_square"),
+    ("🟨", "yellow_square"),
+    ("🟩", "green_square"),
+    ("🟪", "purple_square"),
+    ("🟫", "brown_square"),
+    ("🟰", "heavy_equals_sign"),
+    ("🤌", "pinched_fingers"),
+    ("🤍", "white_heart"),
+    ("🤎", "brown_heart"),
+    ("🤏", "pinching_hand"),
+    ("🤐", "zipper_mouth_face"),
+    ("🤑", "money_mouth_face"),
+    ("🤒", "face_with_thermometer"),
+    ("🤓", "nerd_face"),
+    ("🤔", "thinking"),
+    ("🤕", "face_with_head_bandage"),
+    ("🤖", "robot"),
+    ("🤗", "hugs"),
+    ("🤘", "metal"),
+    ("🤙", "call_me_hand"),
+    ("🤚", "raised_back_of_hand"),
+    ("🤛", "fist_left"),
+    ("🤜", "fist_right"),
+    ("🤝", "handshake"),
+    ("🤞", "crossed_fingers"),
+    ("🤟", "love_you_gesture"),
+    ("🤠", "cowboy_hat_face"),
+    ("🤡", "clown_face"),
+    ("🤢", "nauseated_face"),
+    ("🤣", "rofl"),
+    ("🤤", "drooling_face"),
+    ("🤥", "lying_face"),
+    ("🤦", "facepalm"),
+    ("🤦\u{200D}♀", "woman_facepalming"),
+    ("🤦\u{200D}♂", "man_facepalming"),
+    ("🤧", "sneezing_face"),
+    ("🤨", "raised_eyebrow"),
+    ("🤩", "star_struck"),
+    ("🤪", "zany_face"),
+    ("🤫", "shushing_face"),
+    ("🤬", "cursing_face"),
+    ("🤭", "hand_over_mouth"),
+    ("🤮", "vomiting_face"),
+    ("🤯", "exploding_head"),
+    ("🤰", "pregnant_woman"),
+    ("🤱", "breast_feeding"),
+    ("🤲", "palms_up_together"),
+    ("🤳", "selfie"),
+    ("🤴", "prince"),
+    ("🤵", "person_in_tuxedo"),
+    ("🤵\u{200D}♀", "woman_in_tuxedo"),
+    ("🤵\u{200D}♂", "man_in_tuxedo"),
+    ("🤶", "mrs_claus"),
+    ("🤷", "shrug"),
+    ("🤷\u{200D}♀", "woman_shrugging"),
+    ("🤷\u{200D}♂", "man_shrugging"),
+    ("🤸", "cartwheeling"),
+    ("🤸\u{200D}♀", "woman_cartwheeling"),
+    ("🤸\u{200D}♂", "man_cartwheeling"),
+    ("🤹", "juggling_person"),
+    ("🤹\u{200D}♀", "woman_juggling"),
+    ("🤹\u{200D}♂", "man_juggling"),
+    ("🤺", "person_fencing"),
+    ("🤼", "wrestling"),
+    ("🤼\u{200D}♀", "women_wrestling"),
+    ("🤼\u{200D}♂", "men_wrestling"),
+    ("🤽", "water_polo"),
+    ("🤽\u{200D}♀", "woman_playing_water_polo"),
+    ("🤽\u{200D}♂", "man_playing_water_polo"),
+    ("🤾", "handball_person"),
+    ("🤾\u{200D}♀", "woman_playing_handball"),
+    ("🤾\u{200D}♂", "man_playing_handball"),
+    ("🤿", "diving_mask"),
+    ("🥀", "wilted_flower"),
+    ("🥁", "drum"),
+    ("🥂", "clinking_glasses"),
+    ("🥃", "tumbler_glass"),
+    ("🥄", "spoon"),
+    ("🥅", "goal_net"),
+    ("🥇", "1st_place_medal"),
+    ("🥈", "2nd_place_medal"),
+    ("🥉", "3rd_place_medal"),
+    ("🥊", "boxing_glove"),
+    ("🥋", "martial_arts_uniform"),
+    ("🥌", "curling_stone"),
+    ("🥍", "lacrosse"),
+    ("🥎", "softball"),
+    ("🥏", "flying_disc"),
+    ("🥐", "croissant"),
+    ("🥑", "avocado"),
+    ("🥒", "cucumber"),
+    ("🥓", "bacon"),
+    ("🥔", "potato"),
+    ("🥕", "carrot"),
+    ("🥖", "baguette_bread"),
+    ("🥗", "green_salad"),
+    ("🥘", "shallow_pan_of_food"),
+    ("🥙", "stuffed_flatbread"),
+    ("🥚", "egg"),
+    ("🥛", "milk_glass"),
+    ("🥜", "peanuts"),
+    ("🥝", "kiwi_fruit"),
+    ("🥞", "pancakes"),
+    ("🥟", "dumpling"),
+    ("🥠", "fortune_cookie"),
+    ("🥡", "takeout_box"),
+    ("🥢", "chopsticks"),
+    ("🥣", "bowl_with_spoon"),
+    ("🥤", "cup_with_straw"),
+    ("🥥", "coconut"),
+    ("🥦", "broccoli"),
+    ("🥧", "pie"),
+    ("🥨", "pretzel"),
+    ("🥩", "cut_of_meat"),
+    ("🥪", "sandwich"),
+    ("🥫", "canned_food"),
+    ("🥬", "leafy_green"),
+    ("🥭", "mango"),
+    ("🥮", "moon_cake"),
+    ("🥯", "bagel"),
+    ("🥰", "smiling_face_with_three_hearts"),
+    ("🥱", "yawning_face"),
+    ("🥲", "smiling_face_with_tear"),
+    ("🥳", "partying_face"),
+    ("🥴", "woozy_face"),
+    ("🥵", "hot_face"),
+    ("🥶", "cold_face"),
+    ("🥷", "ninja"),
+    ("🥸", "disguised_face"),
+    ("🥹", "face_holding_back_tears"),
+    ("🥺", "pleading_face"),
+    ("🥻", "sari"),
+    ("🥼", "lab_coat"),
+    ("🥽", "goggles"),
+    ("🥾", "hiking_boot"),
+    ("🥿", "flat_shoe"),
+    ("🦀", "crab"),
+    ("🦁", "lion"),
+    ("🦂", "scorpion"),
+    ("🦃", "turkey"),
+    ("🦄", "unicorn"),
+    ("🦅", "eagle"),
+    ("🦆", "duck"),
+    ("🦇", "bat"),
+    ("🦈", "shark"),
+    ("🦉", "owl"),
+    ("🦊", "fox_face"),
+    ("🦋", "butterfly"),
+    ("🦌", "deer"),
+    ("🦍", "gorilla"),
+    ("🦎", "lizard"),
+    ("🦏", "rhinoceros"),
+    ("🦐", "shrimp"),
+    ("🦑", "squid"),
+    ("🦒", "giraffe"),
+    ("🦓", "zebra"),
+    ("🦔", "hedgehog"),
+    ("🦕", "sauropod"),
+    ("🦖", "t-rex"),
+    ("🦗", "cricket"),
+    ("🦘", "kangaroo"),
+    ("🦙", "llama"),
+    ("🦚", "peacock"),
+    ("🦛", "hippopotamus"),
+    ("🦜", "parrot"),
+    ("🦝", "raccoon"),
+    ("🦞", "lobster"),
+    ("🦟", "mosquito"),
+    ("🦠", "microbe"),
+    ("🦡", "badger"),
+    ("🦢", "swan"),
+    ("🦣", "mammoth"),
+    ("🦤", "dodo"),
+    ("🦥", "sloth"),
+    ("🦦", "otter"),
+    ("🦧", "orangutan"),
+    ("🦨", "skunk"),
+    ("🦩", "flamingo"),
+    ("🦪", "oyster"),
+    ("🦫", "beaver"),
+    ("🦬", "bison"),
+    ("🦭", "seal"),
+    ("🦮", "guide_dog"),
+    ("🦯", "probing_cane"),
+    ("🦴", "bone"),
+    ("🦵", "leg"),
+    ("🦶", "foot"),
+    ("🦷", "tooth"),
+    ("🦸", "superhero"),
+    ("🦸\u{200D}♀", "superhero_woman"),
+    ("🦸\u{200D}♂", "superhero_man"),
+    ("🦹", "supervillain"),
+    ("🦹\u{200D}♀", "supervillain_woman"),
+    ("🦹\u{200D}♂", "supervillain_man"),
+    ("🦺", "safety_vest"),
+    ("🦻", "ear_with_hearing_aid"),
+    ("🦼", "motorized_wheelchair"),
+    ("🦽", "manual_wheelchair"),
+    ("🦾", "mechanical_arm"),
+    ("🦿", "mechanical_leg"),
+    ("🧀", "cheese"),
+    ("🧁", "cupcake"),
+    ("🧂", "salt"),
+    ("🧃", "beverage_box"),
+    ("🧄", "garlic"),
+    ("🧅", "onion"),
+    ("🧆", "falafel"),
+    ("🧇", "waffle"),
+    ("🧈", "butter"),
+    ("🧉", "mate"),
+    ("🧊", "ice_cube"),
+    ("🧋", "bubble_tea"),
+    ("🧌", "troll"),
+    ("🧍", "standing_person"),
+    ("🧍\u{200D}♀", "standing_woman"),
+    ("🧍\u{200D}♂", "standing_man"),
+    ("🧎", "kneeling_person"),
+    ("🧎\u{200D}♀", "kneeling_woman"),
+    ("🧎\u{200D}♂", "kneeling_man"),
+    ("🧏", "deaf_person"),
+    ("🧏\u{200D}♀", "deaf_woman"),
+    ("🧏\u{200D}♂", "deaf_man"),
+    ("🧐", "monocle_face"),
+    ("🧑", "adult"),
+    ("🧑\u{200D}⚕", "health_worker"),
+    ("🧑\u{200D}⚖", "judge"),
+    ("🧑\u{200D}✈", "pilot"),
+    ("🧑\u{200D}🌾", "farmer"),
+    ("🧑\u{200D}🍳", "cook"),
+    ("🧑\u{200D}🍼", "person_feeding_baby"),
+    ("🧑\u{200D}🎄", "mx_claus"),
+    ("🧑\u{200D}🎓", "student"),
+    ("🧑\u{200D}🎤", "singer"),
+    ("🧑\u{200D}🎨", "artist"),
+    ("🧑\u{200D}🏫", "teacher"),
+    ("🧑\u{200D}🏭", "factory_worker"),
+    ("🧑\u{200D}💻", "technologist"),
+    ("🧑\u{200D}💼", "office_worker"),
+    ("🧑\u{200D}🔧", "mechanic"),
+    ("🧑\u{200D}🔬", "scientist"),
+    ("🧑\u{200D}🚀", "astronaut"),
+    ("🧑\u{200D}🚒", "firefighter"),
+    ("🧑\u{200D}🤝\u{200D}🧑", "people_holding_hands"),
+    ("🧑\u{200D}🦯", "person_with_probing_cane"),
+    ("🧑\u{200D}🦰", "person_red_hair"),
+    ("🧑\u{200D}🦱", "person_curly_hair"),
+    ("🧑\u{200D}🦲", "person_bald"),
+    ("🧑\u{200D}🦳", "person_white_hair"),
+    ("🧑\u{200D}🦼", "person_in_motorized_wheelchair"),
+    ("🧑\u{200D}🦽", "person_in_manual_wheelchair"),
+    ("🧒", "child"),
+    ("🧓", "older_adult"),
+    ("🧔", "bearded_person"),
+    ("🧔\u{200D}♀", "woman_beard"),
+    ("🧔\u{200D}♂", "man_beard"),
+    ("🧕", "woman_with_headscarf"),
+    ("🧖", "sauna_person"),
+    ("🧖\u{200D}♀", "sauna_woman"),
+    ("🧖\u{200D}♂", "sauna_man"),
+    ("🧗", "climbing"),
+    ("🧗\u{200D}♀", "climbing_woman"),
+    ("🧗\u{200D}♂", "climbing_man"),
+    ("🧘", "lotus_position"),
+    ("🧘\u{200D}♀", "lotus_position_woman"),
+    ("🧘\u{200D}♂", "lotus_position_man"),
+    ("🧙", "mage"),
+    ("🧙\u{200D}♀", "mage_woman"),
+    ("🧙\u{200D}♂", "mage_man"),
+    ("🧚", "fairy"),
+    ("🧚\u{200D}♀", "fairy_woman"),
+    ("🧚\u{200D}♂", "fairy_man"),
+    ("🧛", "vampire"),
+    ("🧛\u{200D}♀", "vampire_woman"),
+    ("🧛\u{200D}♂", "vampire_man"),
+    ("🧜", "merperson"),
+    ("🧜\u{200D}♀", "mermaid"),
+    ("🧜\u{200D}♂", "merman"),
+    ("🧝", "elf"),
+    ("🧝\u{200D}♀", "elf_woman"),
+    ("🧝\u{200D}♂", "elf_man"),
+    ("🧞", "genie"),
+    ("🧞\u{200D}♀", "genie_woman"),
+    ("🧞\u{200D}♂", "genie_man"),
+    ("🧟", "zombie"),
+    ("🧟\u{200D}♀", "zombie_woman"),
+    ("🧟\u{200D}♂", "zombie_man"),
+    ("🧠", "brain"),
+    ("🧡", "orange_heart"),
+    ("🧢", "billed_cap"),
+    ("🧣", "scarf"),
+    ("🧤", "gloves"),
+    ("🧥", "coat"),
+    ("🧦", "socks"),
+    ("🧧", "red_envelope"),
+    ("🧨", "firecracker"),
+    ("🧩", "jigsaw"),
+    ("🧪", "test_tube"),
+    ("🧫", "petri_dish"),
+    ("🧬", "dna"),
+    ("🧭", "compass"),
+    ("🧮", "abacus"),
+    ("🧯", "fire_extinguisher"),
+    ("🧰", "toolbox"),
+    ("🧱", "bricks"),
+    ("🧲", "magnet"),
+    ("🧳", "luggage"),
+    ("🧴", "lotion_bottle"),
+    ("🧵", "thread"),
+    ("🧶", "yarn"),
+    ("🧷", "safety_pin"),
+    ("🧸", "teddy_bear"),
+    ("🧹", "broom"),
+    ("🧺", "basket"),
+    ("🧻", "roll_of_paper"),
+    ("🧼", "soap"),
+    ("🧽", "sponge"),
+    ("🧾", "receipt"),
+    ("🧿", "nazar_amulet"),
+    ("🩰", "ballet_shoes"),
+    ("🩱", "one_piece_swimsuit"),
+    ("🩲", "swim_brief"),
+    ("🩳", "shorts"),
+    ("🩴", "thong_sandal"),
+    ("🩵", "light_blue_heart"),
+    ("🩶", "grey_heart"),
+    ("🩷", "pink_heart"),
+    ("🩸", "drop_of_blood"),
+    ("🩹", "adhesive_bandage"),
+    ("🩺", "stethoscope"),
+    ("🩻", "x_ray"),
+    ("🩼", "crutch"),
+    ("🪀", "yo_yo"),
+    ("🪁", "kite"),
+    ("🪂", "parachute"),
+    ("🪃", "boomerang"),
+    ("🪄", "magic_wand"),
+    ("🪅", "pinata"),
+    ("🪆", "nesting_dolls"),
+    ("🪇", "maracas"),
+    ("🪈", "flute"),
+    ("🪐", "ringed_planet"),
+    ("🪑", "chair"),
+    ("🪒", "razor"),
+    ("🪓", "axe"),
+    ("🪔", "diya_lamp"),
+    ("🪕", "banjo"),
+    ("🪖", "military_helmet"),
+    ("🪗", "accordion"),
+    ("🪘", "long_drum"),
+    ("🪙", "coin"),
+    ("🪚", "carpentry_saw"),
+    ("🪛", "screwdriver"),
+    ("🪜", "ladder"),
+    ("🪝", "hook"),
+    ("🪞", "mirror"),
+    ("🪟", "window"),
+    ("🪠", "plunger"),
+    ("🪡", "sewing_needle"),
+    ("🪢", "knot"),
+    ("🪣", "bucket"),
+    ("🪤", "mouse_trap"),
+    ("🪥", "toothbrush"),
+    ("🪦", "headstone"),
+    ("🪧", "placard"),
+    ("🪨", "rock"),
+    ("🪩", "mirror_ball"),
+    ("🪪", "identification_card"),
+    ("🪫", "low_battery"),
+    ("🪬", "hamsa"),
+    ("🪭", "folding_hand_fan"),
+    ("🪮", "hair_pick"),
+    ("🪯", "khanda"),
+    ("🪰", "fly"),
+    ("🪱", "worm"),
+    ("🪲", "beetle"),
+    ("🪳", "cockroach"),
+    ("🪴", "potted_plant"),
+    ("🪵", "wood"),
+    ("🪶", "feather"),
+    ("🪷", "lotus"),
+    ("🪸", "coral"),
+    ("🪹", "empty_nest"),
+    ("🪺", "nest_with_eggs"),
+    ("🪻", "hyacinth"),
+    ("🪼", "jellyfish"),
+    ("🪽", "wing"),
+    ("🪿", "goose"),
+    ("🫀", "anatomical_heart"),
+    ("🫁", "lungs"),
+    ("🫂", "people_hugging"),
+    ("🫃", "pregnant_man"),
+    ("🫄", "pregnant_person"),
+    ("🫅", "person_with_crown"),
+    ("🫎", "moose"),
+    ("🫏", "donkey"),
+    ("🫐", "blueberries"),
+    ("🫑", "bell_pepper"),
+    ("🫒", "olive"),
+    ("🫓", "flatbread"),
+    ("🫔", "tamale"),
+    ("🫕", "fondue"),
+    ("🫖", "teapot"),
+    ("🫗", "pouring_liquid"),
+    ("🫘", "beans"),
+    ("🫙", "jar"),
+    ("🫚", "ginger_root"),
+    ("🫛", "pea_pod"),
+    ("🫠", "melting_face"),
+    ("🫡", "saluting_face"),
+    ("🫢", "face_with_open_eyes_and_hand_over_mouth"),
+    ("🫣", "face_with_peeking_eye"),
+    ("🫤", "face_with_diagonal_mouth"),
+    ("🫥", "dotted_line_face"),
+    ("🫦", "biting_lip"),
+    ("🫧", "bubbles"),
+    ("🫨", "shaking_face"),
+    ("🫰", "hand_with_index_finger_and_thumb_crossed"),
+    ("🫱", "rightwards_hand"),
+    ("🫲", "leftwards_hand"),
+    ("🫳", "palm_down_hand"),
+    ("🫴", "palm_up_hand"),
+    ("🫵", "index_pointing_at_the_viewer"),
+    ("🫶", "heart_hands"),
+    ("🫷", "leftwards_pushing_hand"),
+    ("🫸", "rightwards_pushing_hand"),
+];
@@ -0,0 +1,247 @@
+//! Parses the numbers written in `subject`.
+
+/// The parsed number, the variant is the kind of the number.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Number {
+    /// An integer, e.g. "42", "1_000" or "0xFF".
+    Integer(i128),
+    /// A number with a fractional part or an exponent, e.g. "1.5" or "1e3", or an integer too big for `i128`.
+    Float(f64),
+}
+
+impl Number {
+    /// Returns the number as `f64`. The big integers lose precision.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Number::Integer(value) => value as f64,
+            Number::Float(value) => value,
+        }
+    }
+}
+
+/// The options of `parse::parse_number_with` and `query::is_numeric_with`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberOptions {
+    /// The character which groups the digits of the integer part by three, e.g. "1,234,567". `None` disallows grouping.
+    /// Ignored when it is the same as the decimal separator. Default value is `Some(',')`.
+    pub thousands_separator: Option<char>,
+    /// The character which separates the fractional part, e.g. ',' for "1.234,56". Default value is '.'.
+    pub decimal_separator: char,
+    /// Whether the underscores are allowed between the digits, e.g. "1_000_000". Default value is `true`.
+    pub underscores: bool,
+    /// Whether the integers with the radix prefixes "0x", "0o" and "0b" are allowed. Default value is `true`.
+    pub radix_prefixes: bool,
+    /// Whether the decimal digits of any script are allowed, e.g. the Arabic-Indic "١٢٣" or the fullwidth "１２３". Default value is `false`.
+    pub unicode_digits: bool,
+}
+
+impl Default for NumberOptions {
+    fn default() -> Self {
+        NumberOptions {
+            thousands_separator: Some(','),
+            decimal_separator: '.',
+            underscores: true,
+            radix_prefixes: true,
+            unicode_digits: false,
+        }
+    }
+}
+
+/// Parses the number written in `subject` with the default `NumberOptions`: an optional sign, the digits grouped by ","
+/// or separated by "_", an optional fractional part after "." and an optional exponent, or an integer with the radix prefix
+/// "0x", "0o" or "0b". Returns `None` if `subject` is not a number or it is too big for `f64`, e.g. "1e400".
+///
+/// # Arguments
+///
+/// * `subject` - The string to parse.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::parse::Number;
+/// parse::parse_number("3000000000");
+/// // => Some(Number::Integer(3000000000))
+/// parse::parse_number("1,234.56");
+/// // => Some(Number::Float(1234.56))
+/// parse::parse_number("0b1010");
+/// // => Some(Number::Integer(10))
+/// parse::parse_number("e5e");
+/// // => None
+/// use voca_rs::Voca;
+/// "1_000"._parse_number();
+/// // => Some(Number::Integer(1000))
+/// ```
+pub fn parse_number(subject: &str) -> Option<Number> {
+    parse_number_with(subject, &NumberOptions::default())
+}
+
+/// Parses the number written in `subject` according to `options`, see `parse::parse_number`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to parse.
+/// * `options` - The separators and the allowed notations.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::parse::{Number, NumberOptions};
+/// let german = NumberOptions {
+///     thousands_separator: Some('.'),
+///     decimal_separator: ',',
+///     ..NumberOptions::default()
+/// };
+/// parse::parse_number_with("1.234,56", &german);
+/// // => Some(Number::Float(1234.56))
+/// let unicode = NumberOptions {
+///     unicode_digits: true,
+///     ..NumberOptions::default()
+/// };
+/// parse::parse_number_with("٤٢", &unicode);
+/// // => Some(Number::Integer(42))
+/// use voca_rs::Voca;
+/// "１２３"._parse_number_with(&unicode);
+/// // => Some(Number::Integer(123))
+/// ```
+pub fn parse_number_with(subject: &str, options: &NumberOptions) -> Option<Number> {
+    let chars: Vec<char> = subject
+        .chars()
+        .map(|c| match c {
+            _ if options.unicode_digits && !c.is_ascii() => unicode_digit(c).unwrap_or(c),
+            _ => c,
+        })
+        .collect();
+    let (negative, unsigned) = match chars.split_first() {
+        Some((&'-', rest)) => (true, rest),
+        Some((&'+', rest)) => (false, rest),
+        _ => (false, &chars[..]),
+    };
+    let sign = if negative { "-" } else { "" };
+    if options.radix_prefixes && unsigned.len() > 2 && unsigned[0] == '0' {
+        let radix = match unsigned[1] {
+            'x' | 'X' => 16,
+            'o' | 'O' => 8,
+            'b' | 'B' => 2,
+            _ => 0,
+        };
+        if radix != 0 {
+            let digits = digits(&unsigned[2..], radix, options.underscores)?;
+            return i128::from_str_radix(&format!("{}{}", sign, digits), radix)
+                .ok()
+                .map(Number::Integer);
+        }
+    }
+    let (mantissa, exponent) = match unsigned.iter().position(|&c| c == 'e' || c == 'E') {
+        Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+        None => (unsigned, None),
+    };
+    let (integer, fraction) = match mantissa
+        .iter()
+        .position(|&c| c == options.decimal_separator)
+    {
+        Some(i) => (&mantissa[..i], Some(&mantissa[i + 1..])),
+        None => (mantissa, None),
+    };
+    let thousands_separator = options
+        .thousands_separator
+        .filter(|&separator| separator != options.decimal_separator);
+    let integer = match thousands_separator {
+        Some(separator) if integer.contains(&separator) => grouped_digits(integer, separator)?,
+        _ => digits(integer, 10, options.underscores)?,
+    };
+    let fraction = match fraction {
+        Some(fraction) => Some(digits(fraction, 10, options.underscores)?),
+        None => None,
+    };
+    if integer.is_empty() && fraction.as_ref().map_or(true, |f| f.is_empty()) {
+        return None;
+    }
+    let exponent = match exponent {
+        Some(exponent) => {
+            let (exponent_sign, exponent_digits) = match exponent.split_first() {
+                Some((&c, rest)) if c == '-' || c == '+' => (c.to_string(), rest),
+                _ => ("".to_string(), exponent),
+            };
+            let exponent_digits = digits(exponent_digits, 10, options.underscores)?;
+            if exponent_digits.is_empty() {
+                return None;
+            }
+            Some(format!("{}{}", exponent_sign, exponent_digits))
+        }
+        None => None,
+    };
+    if fraction.is_none() && exponent.is_none() {
+        if let Ok(value) = format!("{}{}", sign, integer).parse::<i128>() {
+            return Some(Number::Integer(value));
+        }
+    }
+    let mut normalized = format!("{}{}", sign, integer);
+    if let Some(fraction) = fraction {
+        normalized = format!("{}.{}", normalized, fraction);
+    }
+    if let Some(exponent) = exponent {
+        normalized = format!("{}e{}", normalized, exponent);
+    }
+    normalized
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .map(Number::Float)
+}
+
+/// Returns the ASCII digits of `chars` in `radix`, or `None` if there are other characters.
+/// The underscores, if `underscores` is true, are allowed between two digits and removed.
+fn digits(chars: &[char], radix: u32, underscores: bool) -> Option<String> {
+    let mut result = String::with_capacity(chars.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_digit(radix) {
+            result.push(c);
+        } else if c == '_'
+            && underscores
+            && i > 0
+            && chars[i - 1].is_digit(radix)
+            && chars.get(i + 1).is_some_and(|next| next.is_digit(radix))
+        {
+            continue;
+        } else {
+            return None;
+        }
+    }
+    Some(result)
+}
+
+/// Returns the decimal digits of `chars` grouped by three with `separator`, e.g. "1,234,567".
+fn grouped_digits(chars: &[char], separator: char) -> Option<String> {
+    let mut result = String::with_capacity(chars.len());
+    for (i, group) in chars.split(|&c| c == separator).enumerate() {
+        let is_valid = match i {
+            0 => (1..=3).contains(&group.len()),
+            _ => group.len() == 3,
+        };
+        if !is_valid || !group.iter().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        result.extend(group);
+    }
+    Some(result)
+}
+
+/// Returns the ASCII digit with the value of the Unicode decimal digit `c`, e.g. '3' for the Arabic-Indic '٣'.
+fn unicode_digit(c: char) -> Option<char> {
+    static DECIMAL_DIGIT: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    let re = DECIMAL_DIGIT.get_or_init(|| regex::Regex::new(r"^\p{Nd}$").unwrap());
+    let is_digit = |code: u32| {
+        std::char::from_u32(code).is_some_and(|c| re.is_match(c.encode_utf8(&mut [0; 4])))
+    };
+    if !is_digit(c as u32) {
+        return None;
+    }
+    // the decimal digits are encoded in the runs of ten from zero to nine, some runs are adjacent
+    let mut zero = c as u32;
+    while is_digit(zero - 1) {
+        zero -= 1;
+    }
+    std::char::from_digit((c as u32 - zero) % 10, 10)
+}
@@ -0,0 +1,1159 @@
+//! Encodes `subject` into a phonetic key, so that the names which sound alike get the same key.
+//! The non-Latin and accented characters are transliterated with `utils::unidecode` first, all the other non-letter characters are ignored.
+
+use regex::Regex;
+
+/// Encodes `subject` with the American Soundex algorithm: the first letter followed by three digits.
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::soundex("Robert");
+/// // => "R163"
+/// phonetic::soundex("Rupert");
+/// // => "R163"
+/// phonetic::soundex("Ashcraft");
+/// // => "A261"
+/// phonetic::soundex("Щербаков");
+/// // => "S612"
+/// use voca_rs::Voca;
+/// "Robert"._soundex();
+/// // => "R163"
+/// ```
+pub fn soundex(subject: &str) -> String {
+    let letters = latin_letters(subject);
+    let first = match letters.first() {
+        Some(&c) => c,
+        None => return String::new(),
+    };
+    let mut key = first.to_string();
+    let mut last_code = soundex_code(first);
+    for &c in &letters[1..] {
+        if key.len() == 4 {
+            break;
+        }
+        // "H" and "W" do not separate the letters with the same code
+        if c == 'H' || c == 'W' {
+            continue;
+        }
+        let code = soundex_code(c);
+        if code != '0' && code != last_code {
+            key.push(code);
+        }
+        last_code = code;
+    }
+    format!("{:0<4}", key)
+}
+
+fn soundex_code(c: char) -> char {
+    match c {
+        'B' | 'F' | 'P' | 'V' => '1',
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => '2',
+        'D' | 'T' => '3',
+        'L' => '4',
+        'M' | 'N' => '5',
+        'R' => '6',
+        _ => '0',
+    }
+}
+
+/// Encodes `subject` with the Refined Soundex algorithm: the first letter followed by the codes of all the letters.
+/// Unlike Soundex, the key is not truncated and the vowels are encoded as well.
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::refined_soundex("Braz");
+/// // => "B1905"
+/// phonetic::refined_soundex("testing");
+/// // => "T6036084"
+/// use voca_rs::Voca;
+/// "Braz"._refined_soundex();
+/// // => "B1905"
+/// ```
+pub fn refined_soundex(subject: &str) -> String {
+    let letters = latin_letters(subject);
+    let first = match letters.first() {
+        Some(&c) => c,
+        None => return String::new(),
+    };
+    let mut key = first.to_string();
+    let mut last_code = ' ';
+    for &c in &letters {
+        let code = match c {
+            'B' | 'P' => '1',
+            'F' | 'V' => '2',
+            'C' | 'K' | 'S' => '3',
+            'G' | 'J' => '4',
+            'Q' | 'X' | 'Z' => '5',
+            'D' | 'T' => '6',
+            'L' => '7',
+            'M' | 'N' => '8',
+            'R' => '9',
+            _ => '0',
+        };
+        if code != last_code {
+            key.push(code);
+        }
+        last_code = code;
+    }
+    key
+}
+
+/// Encodes `subject` with the original Metaphone algorithm by Lawrence Philips. The key is not truncated.
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::metaphone("Thompson");
+/// // => "0MPSN"
+/// phonetic::metaphone("Knight");
+/// // => "NT"
+/// phonetic::metaphone("Schmidt");
+/// // => "SKMTT"
+/// use voca_rs::Voca;
+/// "Thompson"._metaphone();
+/// // => "0MPSN"
+/// ```
+pub fn metaphone(subject: &str) -> String {
+    let mut word = latin_letters(subject);
+    match (word.first(), word.get(1)) {
+        (None, _) => return String::new(),
+        (Some(&c), None) => return c.to_string(),
+        (Some('K'), Some('N')) | (Some('G'), Some('N')) | (Some('P'), Some('N')) => {
+            word.remove(0);
+        }
+        (Some('A'), Some('E')) | (Some('W'), Some('R')) => {
+            word.remove(0);
+        }
+        (Some('W'), Some('H')) => {
+            word.remove(1);
+        }
+        (Some('X'), _) => word[0] = 'S',
+        _ => {}
+    }
+    let at = |i: usize| word.get(i).cloned().unwrap_or('\0');
+    let is_vowel = |c: char| "AEIOU".contains(c);
+    let is_front_vowel = |c: char| "EIY".contains(c);
+    let matches_at = |i: usize, pattern: &str| {
+        pattern
+            .chars()
+            .enumerate()
+            .all(|(offset, c)| at(i + offset) == c)
+    };
+    let len = word.len();
+    let mut key = String::new();
+    let mut n = 0;
+    while n < len {
+        let c = word[n];
+        let previous = if n > 0 { word[n - 1] } else { '\0' };
+        let next = at(n + 1);
+        let is_last = n + 1 == len;
+        // the doubled letters are encoded once, except "C"
+        if c != 'C' && previous == c {
+            n += 1;
+            continue;
+        }
+        match c {
+            'A' | 'E' | 'I' | 'O' | 'U' => {
+                if n == 0 {
+                    key.push(c);
+                }
+            }
+            'B' => {
+                // silent in "-MB"
+                if !(previous == 'M' && is_last) {
+                    key.push('B');
+                }
+            }
+            'C' => {
+                if previous == 'S' && is_front_vowel(next) {
+                    // silent in "SCI", "SCE", "SCY"
+                } else if matches_at(n, "CIA") {
+                    key.push('X');
+                } else if is_front_vowel(next) {
+                    key.push('S');
+                } else if previous == 'S' && next == 'H' {
+                    key.push('K');
+                } else if next == 'H' {
+                    key.push(if n == 0 && !is_vowel(at(2)) { 'K' } else { 'X' });
+                } else {
+                    key.push('K');
+                }
+            }
+            'D' => {
+                if next == 'G' && is_front_vowel(at(n + 2)) {
+                    key.push('J');
+                    n += 2;
+                } else {
+                    key.push('T');
+                }
+            }
+            'G' => {
+                let silent_gh = next == 'H' && (n + 2 == len || !is_vowel(at(n + 2)));
+                let silent_gn = n > 0 && next == 'N' && (n + 2 == len || matches_at(n, "GNED"));
+                if !silent_gh && !silent_gn {
+                    key.push(if is_front_vowel(next) { 'J' } else { 'K' });
+                }
+            }
+            'H' => {
+                if !is_last && !"CSPTG".contains(previous) && is_vowel(next) {
+                    key.push('H');
+                }
+            }
+            'K' => {
+                if previous != 'C' {
+                    key.push('K');
+                }
+            }
+            'P' => key.push(if next == 'H' { 'F' } else { 'P' }),
+            'Q' => key.push('K'),
+            'S' => {
+                if next == 'H' || matches_at(n, "SIO") || matches_at(n, "SIA") {
+                    key.push('X');
+                } else {
+                    key.push('S');
+                }
+            }
+            'T' => {
+                if matches_at(n, "TIA") || matches_at(n, "TIO") {
+                    key.push('X');
+                } else if matches_at(n, "TCH") {
+                    // silent in "TCH"
+                } else if next == 'H' {
+                    key.push('0');
+                } else {
+                    key.push('T');
+                }
+            }
+            'V' => key.push('F'),
+            'W' | 'Y' => {
+                if is_vowel(next) {
+                    key.push(c);
+                }
+            }
+            'X' => key.push_str("KS"),
+            'Z' => key.push('S'),
+            _ => key.push(c),
+        }
+        n += 1;
+    }
+    key
+}
+
+/// Encodes `subject` with the Double Metaphone algorithm by Lawrence Philips.
+/// Returns the primary and the alternate keys, both up to 4 characters long.
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::double_metaphone("Smith");
+/// // => ("SM0", "XMT")
+/// phonetic::double_metaphone("Schmidt");
+/// // => ("XMT", "SMT")
+/// phonetic::double_metaphone("Jose");
+/// // => ("HS", "HS")
+/// use voca_rs::Voca;
+/// "Smith"._double_metaphone();
+/// // => ("SM0", "XMT")
+/// ```
+pub fn double_metaphone(subject: &str) -> (String, String) {
+    let value: Vec<char> = crate::utils::unidecode(subject)
+        .to_uppercase()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .chars()
+        .collect();
+    DoubleMetaphone::new(value).encode()
+}
+
+const DOUBLE_METAPHONE_LENGTH: usize = 4;
+
+struct DoubleMetaphone {
+    value: Vec<char>,
+    primary: String,
+    alternate: String,
+    slavo_germanic: bool,
+}
+
+impl DoubleMetaphone {
+    fn new(value: Vec<char>) -> DoubleMetaphone {
+        let text: String = value.iter().collect();
+        DoubleMetaphone {
+            slavo_germanic: text.contains('W')
+                || text.contains('K')
+                || text.contains("CZ")
+                || text.contains("WITZ"),
+            value,
+            primary: String::new(),
+            alternate: String::new(),
+        }
+    }
+
+    fn at(&self, index: isize) -> char {
+        if index < 0 {
+            return '\0';
+        }
+        self.value.get(index as usize).cloned().unwrap_or('\0')
+    }
+
+    fn contains(&self, start: isize, length: usize, patterns: &[&str]) -> bool {
+        if start < 0 || start as usize + length > self.value.len() {
+            return false;
+        }
+        let target: String = self.value[start as usize..start as usize + length]
+            .iter()
+            .collect();
+        patterns.iter().any(|p| *p == target)
+    }
+
+    fn is_vowel(&self, index: isize) -> bool {
+        "AEIOUY".contains(self.at(index))
+    }
+
+    fn last(&self) -> isize {
+        self.value.len() as isize - 1
+    }
+
+    fn add(&mut self, primary: &str, alternate: &str) {
+        let primary_room = DOUBLE_METAPHONE_LENGTH.saturating_sub(self.primary.len());
+        let alternate_room = DOUBLE_METAPHONE_LENGTH.saturating_sub(self.alternate.len());
+        self.primary
+            .push_str(&primary[..primary.len().min(primary_room)]);
+        self.alternate
+            .push_str(&alternate[..alternate.len().min(alternate_room)]);
+    }
+
+    fn add_both(&mut self, code: &str) {
+        self.add(code, code);
+    }
+
+    fn is_complete(&self) -> bool {
+        self.primary.len() >= DOUBLE_METAPHONE_LENGTH
+            && self.alternate.len() >= DOUBLE_METAPHONE_LENGTH
+    }
+
+    fn skip_double(&self, index: isize, c: char) -> isize {
+        if self.at(index + 1) == c {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn encode(mut self) -> (String, String) {
+        let mut index: isize = if self.contains(0, 2, &["GN", "KN", "PN", "WR", "PS"]) {
+            1
+        } else {
+            0
+        };
+        while !self.is_complete() && index <= self.last() {
+            index = match self.at(index) {
+                'A' | 'E' | 'I' | 'O' | 'U' | 'Y' => {
+                    if index == 0 {
+                        self.add_both("A");
+                    }
+                    index + 1
+                }
+                'B' => {
+                    self.add_both("P");
+                    self.skip_double(index, 'B')
+                }
+                'C' => self.encode_c(index),
+                'D' => self.encode_d(index),
+                'F' => {
+                    self.add_both("F");
+                    self.skip_double(index, 'F')
+                }
+                'G' => self.encode_g(index),
+                'H' => {
+                    // only kept if it is first or between the vowels
+                    if (index == 0 || self.is_vowel(index - 1)) && self.is_vowel(index + 1) {
+                        self.add_both("H");
+                        index + 2
+                    } else {
+                        index + 1
+                    }
+                }
+                'J' => self.encode_j(index),
+                'K' => {
+                    self.add_both("K");
+                    self.skip_double(index, 'K')
+                }
+                'L' => {
+                    if self.at(index + 1) == 'L' {
+                        if self.is_spanish_ll(index) {
+                            self.add("L", "");
+                        } else {
+                            self.add_both("L");
+                        }
+                        index + 2
+                    } else {
+                        self.add_both("L");
+                        index + 1
+                    }
+                }
+                'M' => {
+                    self.add_both("M");
+                    if self.at(index + 1) == 'M'
+                        || (self.contains(index - 1, 3, &["UMB"])
+                            && (index + 1 == self.last() || self.contains(index + 2, 2, &["ER"])))
+                    {
+                        index + 2
+                    } else {
+                        index + 1
+                    }
+                }
+                'N' => {
+                    self.add_both("N");
+                    self.skip_double(index, 'N')
+                }
+                'P' => {
+                    if self.at(index + 1) == 'H' {
+                        self.add_both("F");
+                        index + 2
+                    } else {
+                        self.add_both("P");
+                        if self.contains(index + 1, 1, &["P", "B"]) {
+                            index + 2
+                        } else {
+                            index + 1
+                        }
+                    }
+                }
+                'Q' => {
+                    self.add_both("K");
+                    self.skip_double(index, 'Q')
+                }
+                'R' => {
+                    // French "Rogier"
+                    if index == self.last()
+                        && !self.slavo_germanic
+                        && self.contains(index - 2, 2, &["IE"])
+                        && !self.contains(index - 4, 2, &["ME", "MA"])
+                    {
+                        self.add("", "R");
+                    } else {
+                        self.add_both("R");
+                    }
+                    self.skip_double(index, 'R')
+                }
+                'S' => self.encode_s(index),
+                'T' => self.encode_t(index),
+                'V' => {
+                    self.add_both("F");
+                    self.skip_double(index, 'V')
+                }
+                'W' => self.encode_w(index),
+                'X' => self.encode_x(index),
+                'Z' => self.encode_z(index),
+                _ => index + 1,
+            };
+        }
+        (self.primary, self.alternate)
+    }
+
+    fn is_germanic(&self) -> bool {
+        self.contains(0, 4, &["VAN ", "VON "]) || self.contains(0, 3, &["SCH"])
+    }
+
+    fn is_spanish_ll(&self, index: isize) -> bool {
+        (index == self.last() - 2 && self.contains(index - 1, 4, &["ILLO", "ILLA", "ALLE"]))
+            || ((self.contains(self.last() - 1, 2, &["AS", "OS"])
+                || self.contains(self.last(), 1, &["A", "O"]))
+                && self.contains(index - 1, 4, &["ALLE"]))
+    }
+
+    fn encode_c(&mut self, index: isize) -> isize {
+        if self.is_germanic_ach(index) {
+            // "Bacher", "Macher"
+            self.add_both("K");
+            index + 2
+        } else if index == 0 && self.contains(index, 6, &["CAESAR"]) {
+            self.add_both("S");
+            index + 2
+        } else if self.contains(index, 2, &["CH"]) {
+            self.encode_ch(index)
+        } else if self.contains(index, 2, &["CZ"]) && !self.contains(index - 2, 4, &["WICZ"]) {
+            // "Czerny"
+            self.add("S", "X");
+            index + 2
+        } else if self.contains(index + 1, 3, &["CIA"]) {
+            // "focaccia"
+            self.add_both("X");
+            index + 3
+        } else if self.contains(index, 2, &["CC"]) && !(index == 1 && self.at(0) == 'M') {
+            // double "CC", but not "McClelland"
+            if self.contains(index + 2, 1, &["I", "E", "H"])
+                && !self.contains(index + 2, 2, &["HU"])
+            {
+                if (index == 1 && self.at(index - 1) == 'A')
+                    || self.contains(index - 1, 5, &["UCCEE", "UCCES"])
+                {
+                    // "accident", "accede", "succeed"
+                    self.add_both("KS");
+                } else {
+                    // "bacci", "bertucci"
+                    self.add_both("X");
+                }
+                index + 3
+            } else {
+                self.add_both("K");
+                index + 2
+            }
+        } else if self.contains(index, 2, &["CK", "CG", "CQ"]) {
+            self.add_both("K");
+            index + 2
+        } else if self.contains(index, 2, &["CI", "CE", "CY"]) {
+            if self.contains(index, 3, &["CIO", "CIE", "CIA"]) {
+                self.add("S", "X");
+            } else {
+                self.add_both("S");
+            }
+            index + 2
+        } else {
+            self.add_both("K");
+            if self.contains(index + 1, 2, &[" C", " Q", " G"]) {
+                // "Mac Caffrey", "Mac Gregor"
+                index + 3
+            } else if self.contains(index + 1, 1, &["C", "K", "Q"])
+                && !self.contains(index + 1, 2, &["CE", "CI"])
+            {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn is_germanic_ach(&self, index: isize) -> bool {
+        if self.contains(index, 4, &["CHIA"]) {
+            return true;
+        }
+        if index <= 1 || self.is_vowel(index - 2) || !self.contains(index - 1, 3, &["ACH"]) {
+            return false;
+        }
+        let c = self.at(index + 2);
+        (c != 'I' && c != 'E') || self.contains(index - 2, 6, &["BACHER", "MACHER"])
+    }
+
+    fn encode_ch(&mut self, index: isize) -> isize {
+        if index > 0 && self.contains(index, 4, &["CHAE"]) {
+            // "Michael"
+            self.add("K", "X");
+        } else if index == 0
+            && (self.contains(index + 1, 5, &["HARAC", "HARIS"])
+                || self.contains(index + 1, 3, &["HOR", "HYM", "HIA", "HEM"]))
+            && !self.contains(0, 5, &["CHORE"])
+        {
+            // Greek roots: "chemistry", "chorus"
+            self.add_both("K");
+        } else if self.is_germanic()
+            || self.contains(index - 2, 6, &["ORCHES", "ARCHIT", "ORCHID"])
+            || self.contains(index + 2, 1, &["T", "S"])
+            || ((self.contains(index - 1, 1, &["A", "O", "U", "E"]) || index == 0)
+                && (self.contains(
+                    index + 2,
+                    1,
+                    &["L", "R", "N", "M", "B", "H", "F", "V", "W", " "],
+                ) || index + 1 == self.last()))
+        {
+            self.add_both("K");
+        } else if index > 0 {
+            if self.contains(0, 2, &["MC"]) {
+                // "McHugh"
+                self.add_both("K");
+            } else {
+                self.add("X", "K");
+            }
+        } else {
+            self.add_both("X");
+        }
+        index + 2
+    }
+
+    fn encode_d(&mut self, index: isize) -> isize {
+        if self.contains(index, 2, &["DG"]) {
+            if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+                // "edge"
+                self.add_both("J");
+                index + 3
+            } else {
+                // "Edgar"
+                self.add_both("TK");
+                index + 2
+            }
+        } else if self.contains(index, 2, &["DT", "DD"]) {
+            self.add_both("T");
+            index + 2
+        } else {
+            self.add_both("T");
+            index + 1
+        }
+    }
+
+    fn encode_g(&mut self, index: isize) -> isize {
+        let next = self.at(index + 1);
+        if next == 'H' {
+            return self.encode_gh(index);
+        }
+        if next == 'N' {
+            if index == 1 && self.is_vowel(0) && !self.slavo_germanic {
+                self.add("KN", "N");
+            } else if !self.contains(index + 2, 2, &["EY"]) && !self.slavo_germanic {
+                self.add("N", "KN");
+            } else {
+                self.add_both("KN");
+            }
+            index + 2
+        } else if self.contains(index + 1, 2, &["LI"]) && !self.slavo_germanic {
+            // "tagliaro"
+            self.add("KL", "L");
+            index + 2
+        } else if index == 0
+            && (next == 'Y'
+                || self.contains(
+                    index + 1,
+                    2,
+                    &[
+                        "ES", "EP", "EB", "EL", "EY", "IB", "IL", "IN", "IE", "EI", "ER",
+                    ],
+                ))
+        {
+            // "-ges-", "-gep-", "-gel-", "-gie-" at the beginning
+            self.add("K", "J");
+            index + 2
+        } else if (self.contains(index + 1, 2, &["ER"]) || next == 'Y')
+            && !self.contains(0, 6, &["DANGER", "RANGER", "MANGER"])
+            && !self.contains(index - 1, 1, &["E", "I"])
+            && !self.contains(index - 1, 3, &["RGY", "OGY"])
+        {
+            // "-ger-", "-gy-"
+            self.add("K", "J");
+            index + 2
+        } else if self.contains(index + 1, 1, &["E", "I", "Y"])
+            || self.contains(index - 1, 4, &["AGGI", "OGGI"])
+        {
+            if self.is_germanic() || self.contains(index + 1, 2, &["ET"]) {
+                self.add_both("K");
+            } else if self.contains(index + 1, 3, &["IER"]) {
+                self.add_both("J");
+            } else {
+                self.add("J", "K");
+            }
+            index + 2
+        } else if next == 'G' {
+            self.add_both("K");
+            index + 2
+        } else {
+            self.add_both("K");
+            index + 1
+        }
+    }
+
+    fn encode_gh(&mut self, index: isize) -> isize {
+        if index > 0 && !self.is_vowel(index - 1) {
+            self.add_both("K");
+        } else if index == 0 {
+            // "ghislane", "ghiradelli"
+            if self.at(index + 2) == 'I' {
+                self.add_both("J");
+            } else {
+                self.add_both("K");
+            }
+        } else if (index > 1 && self.contains(index - 2, 1, &["B", "H", "D"]))
+            || (index > 2 && self.contains(index - 3, 1, &["B", "H", "D"]))
+            || (index > 3 && self.contains(index - 4, 1, &["B", "H"]))
+        {
+            // Parker's rule: "Hugh"
+        } else if index > 2
+            && self.at(index - 1) == 'U'
+            && self.contains(index - 3, 1, &["C", "G", "L", "R", "T"])
+        {
+            // "laugh", "McLaughlin", "cough", "rough"
+            self.add_both("F");
+        } else if index > 0 && self.at(index - 1) != 'I' {
+            self.add_both("K");
+        }
+        index + 2
+    }
+
+    fn encode_j(&mut self, index: isize) -> isize {
+        if self.contains(index, 4, &["JOSE"]) || self.contains(0, 4, &["SAN "]) {
+            // Spanish "Jose", "San Jacinto"
+            if (index == 0 && (self.at(index + 4) == ' ' || self.value.len() == 4))
+                || self.contains(0, 4, &["SAN "])
+            {
+                self.add_both("H");
+            } else {
+                self.add("J", "H");
+            }
+            return index + 1;
+        }
+        if index == 0 {
+            // "Yankelovich", "Jankelowicz"
+            self.add("J", "A");
+        } else if self.is_vowel(index - 1)
+            && !self.slavo_germanic
+            && (self.at(index + 1) == 'A' || self.at(index + 1) == 'O')
+        {
+            // Spanish "bajador"
+            self.add("J", "H");
+        } else if index == self.last() {
+            self.add("J", "");
+        } else if !self.contains(index + 1, 1, &["L", "T", "K", "S", "N", "M", "B", "Z"])
+            && !self.contains(index - 1, 1, &["S", "K", "L"])
+        {
+            self.add_both("J");
+        }
+        self.skip_double(index, 'J')
+    }
+
+    fn encode_s(&mut self, index: isize) -> isize {
+        if self.contains(index - 1, 3, &["ISL", "YSL"]) {
+            // "island", "isle", "carlisle"
+            index + 1
+        } else if index == 0 && self.contains(index, 5, &["SUGAR"]) {
+            self.add("X", "S");
+            index + 1
+        } else if self.contains(index, 2, &["SH"]) {
+            if self.contains(index + 1, 4, &["HEIM", "HOEK", "HOLM", "HOLZ"]) {
+                self.add_both("S");
+            } else {
+                self.add_both("X");
+            }
+            index + 2
+        } else if self.contains(index, 3, &["SIO", "SIA"]) {
+            // Italian and Armenian
+            if self.slavo_germanic {
+                self.add_both("S");
+            } else {
+                self.add("S", "X");
+            }
+            index + 3
+        } else if (index == 0 && self.contains(index + 1, 1, &["M", "N", "L", "W"]))
+            || self.contains(index + 1, 1, &["Z"])
+        {
+            // "Smith" matches "Schmidt", "Snider" matches "Schneider"
+            self.add("S", "X");
+            if self.contains(index + 1, 1, &["Z"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        } else if self.contains(index, 2, &["SC"]) {
+            self.encode_sc(index)
+        } else {
+            if index == self.last() && self.contains(index - 2, 2, &["AI", "OI"]) {
+                // French "Resnais", "Artois"
+                self.add("", "S");
+            } else {
+                self.add_both("S");
+            }
+            if self.contains(index + 1, 1, &["S", "Z"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn encode_sc(&mut self, index: isize) -> isize {
+        if self.at(index + 2) == 'H' {
+            if self.contains(index + 3, 2, &["OO", "ER", "EN", "UY", "ED", "EM"]) {
+                // Dutch "school", "schooner", "schermerhorn"
+                if self.contains(index + 3, 2, &["ER", "EN"]) {
+                    self.add("X", "SK");
+                } else {
+                    self.add_both("SK");
+                }
+            } else if index == 0 && !self.is_vowel(3) && self.at(3) != 'W' {
+                self.add("X", "S");
+            } else {
+                self.add_both("X");
+            }
+        } else if self.contains(index + 2, 1, &["I", "E", "Y"]) {
+            self.add_both("S");
+        } else {
+            self.add_both("SK");
+        }
+        index + 3
+    }
+
+    fn encode_t(&mut self, index: isize) -> isize {
+        if self.contains(index, 4, &["TION"]) || self.contains(index, 3, &["TIA", "TCH"]) {
+            self.add_both("X");
+            index + 3
+        } else if self.contains(index, 2, &["TH"]) || self.contains(index, 3, &["TTH"]) {
+            // "Thomas", "Thames"
+            if self.contains(index + 2, 2, &["OM", "AM"]) || self.is_germanic() {
+                self.add_both("T");
+            } else {
+                self.add("0", "T");
+            }
+            index + 2
+        } else {
+            self.add_both("T");
+            if self.contains(index + 1, 1, &["T", "D"]) {
+                index + 2
+            } else {
+                index + 1
+            }
+        }
+    }
+
+    fn encode_w(&mut self, index: isize) -> isize {
+        if self.contains(index, 2, &["WR"]) {
+            self.add_both("R");
+            return index + 2;
+        }
+        if index == 0 && (self.is_vowel(index + 1) || self.contains(index, 2, &["WH"])) {
+            // "Wasserman" matches "Vasserman", "Uomo" matches "Womo"
+            if self.is_vowel(index + 1) {
+                self.add("A", "F");
+            } else {
+                self.add_both("A");
+            }
+            index + 1
+        } else if (index == self.last() && self.is_vowel(index - 1))
+            || self.contains(index - 1, 5, &["EWSKI", "EWSKY", "OWSKI", "OWSKY"])
+            || self.contains(0, 3, &["SCH"])
+        {
+            // "Arnow" matches "Arnoff"
+            self.add("", "F");
+            index + 1
+        } else if self.contains(index, 4, &["WICZ", "WITZ"]) {
+            // Polish "Filipowicz"
+            self.add("TS", "FX");
+            index + 4
+        } else {
+            index + 1
+        }
+    }
+
+    fn encode_x(&mut self, index: isize) -> isize {
+        if index == 0 {
+            self.add_both("S");
+            return index + 1;
+        }
+        // French "breaux"
+        if !(index == self.last()
+            && (self.contains(index - 3, 3, &["IAU", "EAU"])
+                || self.contains(index - 2, 2, &["AU", "OU"])))
+        {
+            self.add_both("KS");
+        }
+        if self.contains(index + 1, 1, &["C", "X"]) {
+            index + 2
+        } else {
+            index + 1
+        }
+    }
+
+    fn encode_z(&mut self, index: isize) -> isize {
+        if self.at(index + 1) == 'H' {
+            // Chinese "Zhao"
+            self.add_both("J");
+            return index + 2;
+        }
+        if self.contains(index + 1, 2, &["ZO", "ZI", "ZA"])
+            || (self.slavo_germanic && index > 0 && self.at(index - 1) != 'T')
+        {
+            self.add("S", "TS");
+        } else {
+            self.add_both("S");
+        }
+        self.skip_double(index, 'Z')
+    }
+}
+
+/// Encodes `subject` with the NYSIIS (New York State Identification and Intelligence System) algorithm.
+/// The key is truncated to 6 characters as in the original algorithm.
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::nysiis("Bishop");
+/// // => "BASAP"
+/// phonetic::nysiis("Macintosh");
+/// // => "MCANT"
+/// phonetic::nysiis("Brown");
+/// // => "BRAN"
+/// use voca_rs::Voca;
+/// "Bishop"._nysiis();
+/// // => "BASAP"
+/// ```
+pub fn nysiis(subject: &str) -> String {
+    let mut name: String = latin_letters(subject).into_iter().collect();
+    if name.is_empty() {
+        return name;
+    }
+    for &(prefix, replacement) in &[
+        ("MAC", "MCC"),
+        ("KN", "NN"),
+        ("K", "C"),
+        ("PH", "FF"),
+        ("PF", "FF"),
+        ("SCH", "SSS"),
+    ] {
+        if name.starts_with(prefix) {
+            name = format!("{}{}", replacement, &name[prefix.len()..]);
+            break;
+        }
+    }
+    for &(suffix, replacement) in &[
+        ("EE", "Y"),
+        ("IE", "Y"),
+        ("DT", "D"),
+        ("RT", "D"),
+        ("RD", "D"),
+        ("NT", "D"),
+        ("ND", "D"),
+    ] {
+        if name.ends_with(suffix) {
+            name = format!("{}{}", &name[..name.len() - suffix.len()], replacement);
+            break;
+        }
+    }
+    let is_vowel = |c: char| "AEIOU".contains(c);
+    let mut chars: Vec<char> = name.chars().collect();
+    let mut key = chars[0].to_string();
+    for i in 1..chars.len() {
+        let previous = chars[i - 1];
+        let next = chars.get(i + 1).cloned().unwrap_or(' ');
+        let after_next = chars.get(i + 2).cloned().unwrap_or(' ');
+        let translated = match chars[i] {
+            'E' if next == 'V' => vec!['A', 'F'],
+            c if is_vowel(c) => vec!['A'],
+            'Q' => vec!['G'],
+            'Z' => vec!['S'],
+            'M' => vec!['N'],
+            'K' if next == 'N' => vec!['N'],
+            'K' => vec!['C'],
+            'S' if next == 'C' && after_next == 'H' => vec!['S', 'S', 'S'],
+            'P' if next == 'H' => vec!['F', 'F'],
+            'H' if !is_vowel(previous) || !is_vowel(next) => vec![previous],
+            'W' if is_vowel(previous) => vec![previous],
+            c => vec![c],
+        };
+        for (offset, &c) in translated.iter().enumerate() {
+            if i + offset < chars.len() {
+                chars[i + offset] = c;
+            }
+        }
+        if chars[i] != previous {
+            key.push(chars[i]);
+        }
+    }
+    if key.len() > 1 && key.ends_with('S') {
+        key.pop();
+    }
+    if key.len() > 2 && key.ends_with("AY") {
+        key.truncate(key.len() - 2);
+        key.push('Y');
+    }
+    if key.len() > 1 && key.ends_with('A') {
+        key.pop();
+    }
+    key.truncate(6);
+    key
+}
+
+/// Encodes `subject` with the Cologne phonetics (Kölner Phonetik) algorithm, which is designed for German names.
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::cologne("Müller-Lüdenscheidt");
+/// // => "65752682"
+/// phonetic::cologne("Meier");
+/// // => "67"
+/// phonetic::cologne("Mayr");
+/// // => "67"
+/// use voca_rs::Voca;
+/// "Müller-Lüdenscheidt"._cologne();
+/// // => "65752682"
+/// ```
+pub fn cologne(subject: &str) -> String {
+    let letters = latin_letters(subject);
+    let mut codes = String::new();
+    for (i, &c) in letters.iter().enumerate() {
+        let previous = if i > 0 { letters[i - 1] } else { '\0' };
+        let next = letters.get(i + 1).cloned().unwrap_or('\0');
+        let code = match c {
+            'A' | 'E' | 'I' | 'J' | 'O' | 'U' | 'Y' => "0",
+            'H' => continue,
+            'B' => "1",
+            'P' if next == 'H' => "3",
+            'P' => "1",
+            'D' | 'T' if next == 'C' || next == 'S' || next == 'Z' => "8",
+            'D' | 'T' => "2",
+            'F' | 'V' | 'W' => "3",
+            'G' | 'K' | 'Q' => "4",
+            'C' if i == 0 && "AHKLOQRUX".contains(next) => "4",
+            'C' if previous != 'S' && previous != 'Z' && "AHKOQUX".contains(next) => "4",
+            'C' => "8",
+            'X' if previous == 'C' || previous == 'K' || previous == 'Q' => "8",
+            'X' => "48",
+            'L' => "5",
+            'M' | 'N' => "6",
+            'R' => "7",
+            'S' | 'Z' => "8",
+            _ => continue,
+        };
+        codes.push_str(code);
+    }
+    let mut key = String::new();
+    let mut last_code = None;
+    for (i, code) in codes.chars().enumerate() {
+        if last_code != Some(code) && (code != '0' || i == 0) {
+            key.push(code);
+        }
+        last_code = Some(code);
+    }
+    key
+}
+
+/// Encodes `subject` with the Caverphone 2.0 algorithm, which is designed for English names.
+/// The key is always 10 characters long, padded with "1".
+///
+/// # Arguments
+///
+/// * `subject` - The string to encode.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// phonetic::caverphone("Thompson");
+/// // => "TMPSN11111"
+/// phonetic::caverphone("Stevenson");
+/// // => "STFNSN1111"
+/// phonetic::caverphone("Lee");
+/// // => "LA11111111"
+/// use voca_rs::Voca;
+/// "Thompson"._caverphone();
+/// // => "TMPSN11111"
+/// ```
+pub fn caverphone(subject: &str) -> String {
+    let letters: String = latin_letters(subject)
+        .into_iter()
+        .collect::<String>()
+        .to_lowercase();
+    if letters.is_empty() {
+        return letters;
+    }
+    static RULES: std::sync::OnceLock<Vec<(Regex, &str)>> = std::sync::OnceLock::new();
+    let rules = RULES.get_or_init(|| {
+        CAVERPHONE_RULES
+            .iter()
+            .map(|&(pattern, replacement)| (Regex::new(pattern).unwrap(), replacement))
+            .collect()
+    });
+    let mut key = rules.iter().fold(letters, |key, (re, replacement)| {
+        re.replace_all(&key, *replacement).into_owned()
+    });
+    key.push_str("1111111111");
+    key.truncate(10);
+    key
+}
+
+/// The Caverphone 2.0 rules applied in order, the patterns are regular expressions.
+static CAVERPHONE_RULES: [(&str, &str); 56] = [
+    ("e$", ""),
+    ("^cough", "cou2f"),
+    ("^rough", "rou2f"),
+    ("^tough", "tou2f"),
+    ("^enough", "enou2f"),
+    ("^trough", "trou2f"),
+    ("^gn", "2n"),
+    ("mb$", "m2"),
+    ("cq", "2q"),
+    ("ci", "si"),
+    ("ce", "se"),
+    ("cy", "sy"),
+    ("tch", "2ch"),
+    ("c", "k"),
+    ("q", "k"),
+    ("x", "k"),
+    ("v", "f"),
+    ("dg", "2g"),
+    ("tio", "sio"),
+    ("tia", "sia"),
+    ("d", "t"),
+    ("ph", "fh"),
+    ("b", "p"),
+    ("sh", "s2"),
+    ("z", "s"),
+    ("^[aeiou]", "A"),
+    ("[aeiou]", "3"),
+    ("j", "y"),
+    ("^y3", "Y3"),
+    ("^y", "A"),
+    ("y", "3"),
+    ("3gh3", "3kh3"),
+    ("gh", "22"),
+    ("g", "k"),
+    ("s+", "S"),
+    ("t+", "T"),
+    ("p+", "P"),
+    ("k+", "K"),
+    ("f+", "F"),
+    ("m+", "M"),
+    ("n+", "N"),
+    ("w3", "W3"),
+    ("wh3", "Wh3"),
+    ("w$", "3"),
+    ("w", "2"),
+    ("^h", "A"),
+    ("h", "2"),
+    ("r3", "R3"),
+    ("r$", "3"),
+    ("r", "2"),
+    ("l3", "L3"),
+    ("l$", "3"),
+    ("l", "2"),
+    ("2", ""),
+    ("3$", "A"),
+    ("3", ""),
+];
+
+fn latin_letters(subject: &str) -> Vec<char> {
+    crate::utils::unidecode(subject)
+        .to_uppercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .collect()
+}
@@ -27,6 +27,34 @@ pub fn ends_with(subject: &str, end: &str) -> bool {
     subject.ends_with(end)
 }
 
+/// Checks whether `subject` ends with `end`, comparing the strings according to `options`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+/// * `end` - The ending string.
+/// * `options` - The options which define how the strings are compared.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let options = utils::MatchOptions::insensitive();
+/// query::ends_with_folded("Hauptstraße", "STRASSE", &options);
+/// // => true
+/// query::ends_with_folded("say hello to my little friend", "Little", &options);
+/// // => false
+/// use voca_rs::Voca;
+/// "Hauptstraße"._ends_with_folded("STRASSE", &options);
+/// // => true
+/// ```
+pub fn ends_with_folded(subject: &str, end: &str, options: &crate::utils::MatchOptions) -> bool {
+    if subject.is_empty() || end.is_empty() {
+        return true;
+    }
+    crate::utils::fold(subject, options).ends_with(&crate::utils::fold(end, options))
+}
+
 /// Checks whether `subject` includes `search` starting from `position`.
 ///
 /// # Arguments
@@ -62,6 +90,50 @@ pub fn includes(subject: &str, search: &str, position: usize) -> bool {
         .contains(search)
 }
 
+/// Checks whether `subject` includes `search` starting from `position`, comparing the strings according to `options`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+/// * `search` - The string to search.
+/// * `position` - The position to start searching.
+/// * `options` - The options which define how the strings are compared.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let options = utils::MatchOptions::insensitive();
+/// query::includes_folded("Straße", "STRASSE", 0, &options);
+/// // => true
+/// query::includes_folded("Crème brûlée", "BRULEE", 0, &options);
+/// // => true
+/// query::includes_folded("ＶＯＣＡ", "voca", 0, &options);
+/// // => true
+/// use voca_rs::Voca;
+/// "Straße"._includes_folded("STRASSE", 0, &options);
+/// // => true
+/// ```
+pub fn includes_folded(
+    subject: &str,
+    search: &str,
+    position: usize,
+    options: &crate::utils::MatchOptions,
+) -> bool {
+    let subject_len = crate::count::count(subject);
+    if subject_len < position {
+        return false;
+    }
+    if subject_len == 0 || search.is_empty() {
+        return true;
+    }
+    let string_slice = &subject[subject
+        .char_indices()
+        .nth(position)
+        .map_or(subject.len(), |(i, _)| i)..];
+    crate::utils::fold(string_slice, options).contains(&crate::utils::fold(search, options))
+}
+
 /// Checks whether `subject` contains only alpha characters.
 ///
 /// # Arguments
@@ -336,6 +408,49 @@ pub fn is_empty(subject: &str) -> bool {
     false
 }
 
+/// Checks whether `subject` consists of emoji only. The emoji are the graphemes which are displayed as emoji by default
+/// or by the variation selector U+FE0F, including the keycaps, flags, skin tone modifier and ZWJ sequences.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// query::is_emoji("👍🏽");
+/// // => true
+/// query::is_emoji("👨‍👩‍👧🇺🇦");
+/// // => true
+/// query::is_emoji("1");
+/// // => false
+/// query::is_emoji("ok 👌");
+/// // => false
+/// use voca_rs::Voca;
+/// "❤️"._is_emoji();
+/// // => true
+/// ```
+pub fn is_emoji(subject: &str) -> bool {
+    !subject.is_empty() && crate::split::graphemes_iter(subject).all(is_emoji_grapheme)
+}
+
+pub(crate) fn is_emoji_grapheme(grapheme: &str) -> bool {
+    static EMOJI: std::sync::OnceLock<(Regex, Regex)> = std::sync::OnceLock::new();
+    let (emoji, presentation) = EMOJI.get_or_init(|| {
+        (
+            Regex::new(r"^\p{Emoji}").unwrap(),
+            Regex::new(r"\p{Emoji_Presentation}").unwrap(),
+        )
+    });
+    // "#", "1" or "©" are emoji only with the variation selector or in a keycap, "\u{FE0E}" requests the text style
+    emoji.is_match(grapheme)
+        && !grapheme.contains('\u{FE0E}')
+        && (presentation.is_match(grapheme)
+            || grapheme.contains('\u{FE0F}')
+            || grapheme.contains('\u{20E3}'))
+}
+
 /// Checks whether `subject` is is a `foreign_key`.
 ///
 /// # Arguments
@@ -412,7 +527,7 @@ pub fn is_lower_first(subject: &str) -> bool {
     match subject.len() {
         0 => true,
         _ => {
-            let first_letter = crate::split::chars(subject)[0];
+            let first_letter = crate::split::chars_iter(subject).next().unwrap_or("");
             is_upper_or_lowercase(first_letter, true)
         }
     }
@@ -444,7 +559,7 @@ pub fn is_kebab_case(subject: &str) -> bool {
     subject == crate::case::kebab_case(subject)
 }
 
-/// Checks whether `subject` is numeric.
+/// Checks whether `subject` is numeric, see `parse::parse_number` for the accepted notations. An empty `subject` is numeric.
 ///
 /// # Arguments
 ///
@@ -462,6 +577,8 @@ pub fn is_kebab_case(subject: &str) -> bool {
 /// // => true
 /// query::is_numeric("1.5E+2");
 /// // => true
+/// query::is_numeric("1,234.56");
+/// // => true
 /// query::is_numeric("five");
 /// // => false
 /// use voca_rs::Voca;
@@ -469,32 +586,35 @@ pub fn is_kebab_case(subject: &str) -> bool {
 /// // => true
 /// ```
 pub fn is_numeric(subject: &str) -> bool {
-    if subject.is_empty() {
-        return true;
-    }
-
-    fn parse_str_num(n: &str) -> bool {
-        match n.find('.') {
-            Some(_) => n.parse::<f32>().is_ok(),
-            None => n.parse::<i32>().is_ok(),
-        }
-    }
+    is_numeric_with(subject, &crate::parse::NumberOptions::default())
+}
 
-    let sbj = subject.to_lowercase();
-    match subject.to_lowercase().find('e') {
-        Some(_) => {
-            let s: Vec<&str> = sbj.split('e').collect();
-            parse_str_num(s[0]) && parse_str_num(s[1])
-        }
-        None => {
-            if starts_with(&subject.to_lowercase(), "0x") {
-                let s = sbj.trim_start_matches("0x");
-                i32::from_str_radix(s, 16).is_ok()
-            } else {
-                parse_str_num(subject)
-            }
-        }
-    }
+/// Checks whether `subject` is numeric according to `options`, e.g. with the decimal comma. An empty `subject` is numeric.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+/// * `options` - The separators and the allowed notations, see `parse::parse_number_with`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let french = parse::NumberOptions {
+///     thousands_separator: Some('\u{202F}'),
+///     decimal_separator: ',',
+///     ..parse::NumberOptions::default()
+/// };
+/// query::is_numeric_with("1\u{202F}234,5", &french);
+/// // => true
+/// query::is_numeric_with("1,234.5", &french);
+/// // => false
+/// use voca_rs::Voca;
+/// "3,14"._is_numeric_with(&french);
+/// // => true
+/// ```
+pub fn is_numeric_with(subject: &str, options: &crate::parse::NumberOptions) -> bool {
+    subject.is_empty() || crate::parse::parse_number_with(subject, options).is_some()
 }
 
 /// Checks whether `subject` is PascalCased.
@@ -714,7 +834,7 @@ pub fn is_upper_first(subject: &str) -> bool {
     match subject.len() {
         0 => true,
         _ => {
-            let first_letter = crate::split::chars(subject)[0];
+            let first_letter = crate::split::chars_iter(subject).next().unwrap_or("");
             is_upper_or_lowercase(first_letter, false)
         }
     }
@@ -736,6 +856,615 @@ fn is_upper_or_lowercase(subject: &str, lowercase: bool) -> bool {
     res
 }
 
+/// Checks whether `subject` uses more than one kind of line terminator (`"\n"`, `"\r\n"` or `"\r"`).
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// query::has_mixed_line_endings("one\r\ntwo\nthree");
+/// // => true
+/// query::has_mixed_line_endings("one\r\ntwo\r\n");
+/// // => false
+/// use voca_rs::Voca;
+/// "one\r\ntwo\nthree"._has_mixed_line_endings();
+/// // => true
+/// ```
+pub fn has_mixed_line_endings(subject: &str) -> bool {
+    let endings = crate::split::line_endings(subject);
+    endings.iter().any(|&(_, ending)| ending != endings[0].1)
+}
+
+/// Detects the line terminator used in `subject`. When the terminators are mixed returns the most frequent one,
+/// the ties are resolved in favor of the one which appears first. Returns `None` if `subject` has no line terminators.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// query::line_ending("one\r\ntwo\r\n");
+/// // => Some(split::LineEnding::CrLf)
+/// query::line_ending("one\ntwo\r\nthree\n");
+/// // => Some(split::LineEnding::Lf)
+/// query::line_ending("one");
+/// // => None
+/// use voca_rs::Voca;
+/// "one\r\ntwo"._line_ending();
+/// // => Some(split::LineEnding::CrLf)
+/// ```
+pub fn line_ending(subject: &str) -> Option<crate::split::LineEnding> {
+    let endings = crate::split::line_endings(subject);
+    let mut counts: Vec<(crate::split::LineEnding, usize)> = vec![];
+    for &(_, ending) in &endings {
+        match counts.iter_mut().find(|(kind, _)| *kind == ending) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((ending, 1)),
+        }
+    }
+    // max_by_key returns the last maximum, so the counts are reversed to prefer the first seen
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .map(|(ending, _)| ending)
+}
+
+macro_rules! scripts {
+    ($($variant:ident => $name:literal,)*) => {
+        /// The Unicode script of a character, see `query::scripts`. Covers the scripts of Unicode 16.0.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Script {
+            $(
+                #[doc = concat!("The `", $name, "` script.")]
+                $variant,
+            )*
+        }
+
+        pub(crate) const SCRIPTS: &[Script] = &[$(Script::$variant,)*];
+
+        impl Script {
+            /// Returns the Unicode name of the script, e.g. `"Old_Italic"`.
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Script::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+scripts! {
+    Adlam => "Adlam",
+    Ahom => "Ahom",
+    AnatolianHieroglyphs => "Anatolian_Hieroglyphs",
+    Arabic => "Arabic",
+    Armenian => "Armenian",
+    Avestan => "Avestan",
+    Balinese => "Balinese",
+    Bamum => "Bamum",
+    BassaVah => "Bassa_Vah",
+    Batak => "Batak",
+    Bengali => "Bengali",
+    Bhaiksuki => "Bhaiksuki",
+    Bopomofo => "Bopomofo",
+    Brahmi => "Brahmi",
+    Braille => "Braille",
+    Buginese => "Buginese",
+    Buhid => "Buhid",
+    CanadianAboriginal => "Canadian_Aboriginal",
+    Carian => "Carian",
+    CaucasianAlbanian => "Caucasian_Albanian",
+    Chakma => "Chakma",
+    Cham => "Cham",
+    Cherokee => "Cherokee",
+    Chorasmian => "Chorasmian",
+    Common => "Common",
+    Coptic => "Coptic",
+    Cuneiform => "Cuneiform",
+    Cypriot => "Cypriot",
+    CyproMinoan => "Cypro_Minoan",
+    Cyrillic => "Cyrillic",
+    Deseret => "Deseret",
+    Devanagari => "Devanagari",
+    DivesAkuru => "Dives_Akuru",
+    Dogra => "Dogra",
+    Duployan => "Duployan",
+    EgyptianHieroglyphs => "Egyptian_Hieroglyphs",
+    Elbasan => "Elbasan",
+    Elymaic => "Elymaic",
+    Ethiopic => "Ethiopic",
+    Garay => "Garay",
+    Georgian => "Georgian",
+    Glagolitic => "Glagolitic",
+    Gothic => "Gothic",
+    Grantha => "Grantha",
+    Greek => "Greek",
+    Gujarati => "Gujarati",
+    GunjalaGondi => "Gunjala_Gondi",
+    Gurmukhi => "Gurmukhi",
+    GurungKhema => "Gurung_Khema",
+    Han => "Han",
+    Hangul => "Hangul",
+    HanifiRohingya => "Hanifi_Rohingya",
+    Hanunoo => "Hanunoo",
+    Hatran => "Hatran",
+    Hebrew => "Hebrew",
+    Hiragana => "Hiragana",
+    ImperialAramaic => "Imperial_Aramaic",
+    Inherited => "Inherited",
+    InscriptionalPahlavi => "Inscriptional_Pahlavi",
+    InscriptionalParthian => "Inscriptional_Parthian",
+    Javanese => "Javanese",
+    Kaithi => "Kaithi",
+    Kannada => "Kannada",
+    Katakana => "Katakana",
+    Kawi => "Kawi",
+    KayahLi => "Kayah_Li",
+    Kharoshthi => "Kharoshthi",
+    KhitanSmallScript => "Khitan_Small_Script",
+    Khmer => "Khmer",
+    Khojki => "Khojki",
+    Khudawadi => "Khudawadi",
+    KiratRai => "Kirat_Rai",
+    Lao => "Lao",
+    Latin => "Latin",
+    Lepcha => "Lepcha",
+    Limbu => "Limbu",
+    LinearA => "Linear_A",
+    LinearB => "Linear_B",
+    Lisu => "Lisu",
+    Lycian => "Lycian",
+    Lydian => "Lydian",
+    Mahajani => "Mahajani",
+    Makasar => "Makasar",
+    Malayalam => "Malayalam",
+    Mandaic => "Mandaic",
+    Manichaean => "Manichaean",
+    Marchen => "Marchen",
+    MasaramGondi => "Masaram_Gondi",
+    Medefaidrin => "Medefaidrin",
+    MeeteiMayek => "Meetei_Mayek",
+    MendeKikakui => "Mende_Kikakui",
+    MeroiticCursive => "Meroitic_Cursive",
+    MeroiticHieroglyphs => "Meroitic_Hieroglyphs",
+    Miao => "Miao",
+    Modi => "Modi",
+    Mongolian => "Mongolian",
+    Mro => "Mro",
+    Multani => "Multani",
+    Myanmar => "Myanmar",
+    Nabataean => "Nabataean",
+    NagMundari => "Nag_Mundari",
+    Nandinagari => "Nandinagari",
+    NewTaiLue => "New_Tai_Lue",
+    Newa => "Newa",
+    Nko => "Nko",
+    Nushu => "Nushu",
+    NyiakengPuachueHmong => "Nyiakeng_Puachue_Hmong",
+    Ogham => "Ogham",
+    OlChiki => "Ol_Chiki",
+    OlOnal => "Ol_Onal",
+    OldHungarian => "Old_Hungarian",
+    OldItalic => "Old_Italic",
+    OldNorthArabian => "Old_North_Arabian",
+    OldPermic => "Old_Permic",
+    OldPersian => "Old_Persian",
+    OldSogdian => "Old_Sogdian",
+    OldSouthArabian => "Old_South_Arabian",
+    OldTurkic => "Old_Turkic",
+    OldUyghur => "Old_Uyghur",
+    Oriya => "Oriya",
+    Osage => "Osage",
+    Osmanya => "Osmanya",
+    PahawhHmong => "Pahawh_Hmong",
+    Palmyrene => "Palmyrene",
+    PauCinHau => "Pau_Cin_Hau",
+    PhagsPa => "Phags_Pa",
+    Phoenician => "Phoenician",
+    PsalterPahlavi => "Psalter_Pahlavi",
+    Rejang => "Rejang",
+    Runic => "Runic",
+    Samaritan => "Samaritan",
+    Saurashtra => "Saurashtra",
+    Sharada => "Sharada",
+    Shavian => "Shavian",
+    Siddham => "Siddham",
+    SignWriting => "SignWriting",
+    Sinhala => "Sinhala",
+    Sogdian => "Sogdian",
+    SoraSompeng => "Sora_Sompeng",
+    Soyombo => "Soyombo",
+    Sundanese => "Sundanese",
+    Sunuwar => "Sunuwar",
+    SylotiNagri => "Syloti_Nagri",
+    Syriac => "Syriac",
+    Tagalog => "Tagalog",
+    Tagbanwa => "Tagbanwa",
+    TaiLe => "Tai_Le",
+    TaiTham => "Tai_Tham",
+    TaiViet => "Tai_Viet",
+    Takri => "Takri",
+    Tamil => "Tamil",
+    Tangsa => "Tangsa",
+    Tangut => "Tangut",
+    Telugu => "Telugu",
+    Thaana => "Thaana",
+    Thai => "Thai",
+    Tibetan => "Tibetan",
+    Tifinagh => "Tifinagh",
+    Tirhuta => "Tirhuta",
+    Todhri => "Todhri",
+    Toto => "Toto",
+    TuluTigalari => "Tulu_Tigalari",
+    Ugaritic => "Ugaritic",
+    Vai => "Vai",
+    Vithkuqi => "Vithkuqi",
+    Wancho => "Wancho",
+    WarangCiti => "Warang_Citi",
+    Yezidi => "Yezidi",
+    Yi => "Yi",
+    ZanabazarSquare => "Zanabazar_Square",
+}
+
+/// Returns the Unicode scripts used in `subject` in the order of their first appearance. The scripts are taken from the
+/// Unicode Script property; the characters shared by many scripts (`Script::Common` and `Script::Inherited`, e.g. digits,
+/// spaces, punctuation and combining marks) are not reported.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::query::Script;
+/// query::scripts("Hello, мир!");
+/// // => [Script::Latin, Script::Cyrillic]
+/// query::scripts("日本語のテキスト");
+/// // => [Script::Han, Script::Hiragana, Script::Katakana]
+/// query::scripts("1 + 2");
+/// // => []
+/// use voca_rs::Voca;
+/// "Hello, мир!"._scripts();
+/// // => [Script::Latin, Script::Cyrillic]
+/// ```
+pub fn scripts(subject: &str) -> Vec<Script> {
+    let mut found: Vec<Script> = vec![];
+    for script in subject.chars().filter_map(char_script) {
+        if !found.contains(&script) {
+            found.push(script);
+        }
+    }
+    found
+}
+
+/// Returns the Unicode script with the most characters in `subject`, the ties are resolved in favor of the one which
+/// appears first. The characters shared by many scripts are not counted (see `query::scripts`).
+/// Returns `None` if `subject` has no script-specific characters.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::query::Script;
+/// query::dominant_script("раypal");
+/// // => Some(Script::Latin)
+/// query::dominant_script("Привет, world");
+/// // => Some(Script::Cyrillic)
+/// query::dominant_script("42");
+/// // => None
+/// use voca_rs::Voca;
+/// "раypal"._dominant_script();
+/// // => Some(Script::Latin)
+/// ```
+pub fn dominant_script(subject: &str) -> Option<Script> {
+    let mut counts: Vec<(Script, usize)> = vec![];
+    for script in subject.chars().filter_map(char_script) {
+        match counts.iter_mut().find(|(kind, _)| *kind == script) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((script, 1)),
+        }
+    }
+    // max_by_key returns the last maximum, so the counts are reversed to prefer the first seen
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .map(|(script, _)| script)
+}
+
+/// Checks whether all the characters of `subject` can belong to a single script, as defined by Unicode Technical Standard #39.
+/// The check is based on the Unicode Script_Extensions property, so the characters used by several scripts (like the
+/// Japanese prolonged sound mark "ー" or the Arabic comma) are compatible with each of them. Han is allowed together with
+/// Hiragana and Katakana (Japanese), Hangul (Korean) or Bopomofo (Chinese).
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// query::is_single_script("paypal");
+/// // => true
+/// query::is_single_script("раypal");
+/// // => false
+/// query::is_single_script("日本語のテキスト");
+/// // => true
+/// query::is_single_script("12:30");
+/// // => true
+/// use voca_rs::Voca;
+/// "раypal"._is_single_script();
+/// // => false
+/// ```
+pub fn is_single_script(subject: &str) -> bool {
+    common_scripts(subject.chars()).map_or(true, |common| !common.is_empty())
+}
+
+/// Returns the intersection of the augmented Script_Extensions sets of `chars` (see `resolved_scripts`),
+/// or `None` if none of `chars` is script-specific.
+pub(crate) fn common_scripts(chars: impl Iterator<Item = char>) -> Option<Vec<usize>> {
+    let mut common: Option<Vec<usize>> = None;
+    for c in chars {
+        let resolved = resolved_scripts(c);
+        if resolved.is_empty() {
+            continue;
+        }
+        let intersection: Vec<usize> = match common {
+            Some(common) => common
+                .into_iter()
+                .filter(|s| resolved.contains(s))
+                .collect(),
+            None => resolved,
+        };
+        if intersection.is_empty() {
+            return Some(intersection);
+        }
+        common = Some(intersection);
+    }
+    common
+}
+
+/// Returns the value of the range of `table` which contains `c`.
+fn range_value<T>(table: &'static [(u32, u32, T)], c: char) -> Option<&'static T> {
+    let code = c as u32;
+    table
+        .binary_search_by(|&(start, end, _)| {
+            if end < code {
+                std::cmp::Ordering::Less
+            } else if start > code {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| &table[i].2)
+}
+
+/// Returns the Script property value of `c`, or `None` for the characters of `Script::Common` and `Script::Inherited`.
+pub(crate) fn char_script(c: char) -> Option<Script> {
+    range_value(SCRIPT_RANGES, c).copied()
+}
+
+// the pseudo scripts of UTS #39 for the writing systems which combine Han with other scripts
+pub(crate) const JAPANESE: usize = SCRIPTS.len();
+pub(crate) const KOREAN: usize = SCRIPTS.len() + 1;
+pub(crate) const HAN_WITH_BOPOMOFO: usize = SCRIPTS.len() + 2;
+
+/// Returns the augmented Script_Extensions set of `c` as indices into `SCRIPTS` and the pseudo scripts.
+/// The set is empty for the characters of `Script::Common` and `Script::Inherited`, which are compatible with any script.
+pub(crate) fn resolved_scripts(c: char) -> Vec<usize> {
+    let mut resolved: Vec<usize> = range_value(SCRIPT_EXTENSION_RANGES, c)
+        .map_or(&[][..], |scripts| scripts)
+        .iter()
+        .map(|&script| script as usize)
+        .collect();
+    let augmented: Vec<usize> = resolved
+        .iter()
+        .flat_map(|&i| match SCRIPTS[i] {
+            Script::Han => vec![JAPANESE, KOREAN, HAN_WITH_BOPOMOFO],
+            Script::Hiragana | Script::Katakana => vec![JAPANESE],
+            Script::Hangul => vec![KOREAN],
+            Script::Bopomofo => vec![HAN_WITH_BOPOMOFO],
+            _ => vec![],
+        })
+        .collect();
+    for i in augmented {
+        if !resolved.contains(&i) {
+            resolved.push(i);
+        }
+    }
+    resolved
+}
+
+/// The major class of the Unicode General_Category property, see `query::filter_by_category`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeneralCategory {
+    /// Letters `L`: uppercase, lowercase, titlecase, modifier and other letters.
+    Letter,
+    /// Marks `M`: combining marks like the acute accent in "e\u{0301}".
+    Mark,
+    /// Numbers `N`: decimal digits, letter numbers like "Ⅻ" and other numbers like "½".
+    Number,
+    /// Punctuation `P`: connectors, dashes, brackets, quotes and other punctuation.
+    Punctuation,
+    /// Symbols `S`: math, currency, modifier and other symbols, including emoji.
+    Symbol,
+    /// Separators `Z`: spaces, line and paragraph separators.
+    Separator,
+    /// Other `C`: control, format, surrogate, private use and unassigned code points.
+    Other,
+}
+
+const GENERAL_CATEGORIES: [GeneralCategory; 7] = [
+    GeneralCategory::Letter,
+    GeneralCategory::Mark,
+    GeneralCategory::Number,
+    GeneralCategory::Punctuation,
+    GeneralCategory::Symbol,
+    GeneralCategory::Separator,
+    GeneralCategory::Other,
+];
+
+impl GeneralCategory {
+    fn abbreviation(self) -> &'static str {
+        match self {
+            GeneralCategory::Letter => "L",
+            GeneralCategory::Mark => "M",
+            GeneralCategory::Number => "N",
+            GeneralCategory::Punctuation => "P",
+            GeneralCategory::Symbol => "S",
+            GeneralCategory::Separator => "Z",
+            GeneralCategory::Other => "C",
+        }
+    }
+}
+
+/// Returns the characters of `subject` which belong to one of the Unicode general `categories`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to filter.
+/// * `categories` - The general categories of the characters to keep.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::query::GeneralCategory;
+/// query::filter_by_category("R2-D2 & C-3PO!", &[GeneralCategory::Letter]);
+/// // => "RDCPO"
+/// query::filter_by_category("R2-D2 & C-3PO!", &[GeneralCategory::Number, GeneralCategory::Punctuation]);
+/// // => "2-2&-3!"
+/// query::filter_by_category("Price: 12 €", &[GeneralCategory::Symbol]);
+/// // => "€"
+/// use voca_rs::Voca;
+/// "R2-D2"._filter_by_category(&[GeneralCategory::Letter]);
+/// // => "RD"
+/// ```
+pub fn filter_by_category(subject: &str, categories: &[GeneralCategory]) -> String {
+    if subject.is_empty() || categories.is_empty() {
+        return "".to_string();
+    }
+    static CATEGORY_SET: std::sync::OnceLock<regex::RegexSet> = std::sync::OnceLock::new();
+    let set = CATEGORY_SET.get_or_init(|| {
+        regex::RegexSet::new(
+            GENERAL_CATEGORIES
+                .iter()
+                .map(|category| format!(r"^\p{{{}}}$", category.abbreviation())),
+        )
+        .unwrap()
+    });
+    subject
+        .chars()
+        .filter(|c| {
+            let matches = set.matches(c.encode_utf8(&mut [0; 4]));
+            categories
+                .iter()
+                .any(|&category| matches.matched(category as usize))
+        })
+        .collect()
+}
+
+/// Checks whether `subject` contains the Unicode bidirectional formatting characters: the embeddings, overrides and
+/// isolates (U+202A..U+202E, U+2066..U+2069) or the implicit marks (U+200E, U+200F, U+061C).
+/// These characters can reorder the displayed text, e.g. to hide the code in "Trojan Source" attacks.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// query::has_bidi_controls("access\u{202E}level");
+/// // => true
+/// query::has_bidi_controls("access level");
+/// // => false
+/// use voca_rs::Voca;
+/// "user\u{2067}name\u{2069}"._has_bidi_controls();
+/// // => true
+/// ```
+pub fn has_bidi_controls(subject: &str) -> bool {
+    subject.chars().any(is_bidi_control)
+}
+
+pub(crate) fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// The text direction, see `query::base_direction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Left-to-right, e.g. Latin, Cyrillic or Han.
+    LeftToRight,
+    /// Right-to-left, e.g. Arabic or Hebrew.
+    RightToLeft,
+}
+
+/// Detects the base direction of the first paragraph of `subject` by its first strong character, as in the rules P2 and
+/// P3 of the Unicode Bidirectional Algorithm. The characters inside the directional isolates are skipped.
+/// The characters of the Bidi_Class R and AL (e.g. the Hebrew and Arabic letters, U+05BE MAQAF and the marks U+200F and
+/// U+061C) are strong right-to-left, the characters of the Bidi_Class L (e.g. the Latin letters and the mark U+200E)
+/// are strong left-to-right.
+/// Returns `None` if there is no strong character before the first paragraph separator.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::query::Direction;
+/// query::base_direction("Hello, שלום");
+/// // => Some(Direction::LeftToRight)
+/// query::base_direction("123 مرحبا world");
+/// // => Some(Direction::RightToLeft)
+/// query::base_direction("123!");
+/// // => None
+/// use voca_rs::Voca;
+/// "שלום"._base_direction();
+/// // => Some(Direction::RightToLeft)
+/// ```
+pub fn base_direction(subject: &str) -> Option<Direction> {
+    let mut isolate_depth = 0;
+    for c in subject.chars() {
+        match c {
+            '\n' | '\r' | '\u{001C}'..='\u{001E}' | '\u{0085}' | '\u{2029}' => return None,
+            '\u{2066}'..='\u{2068}' => isolate_depth += 1,
+            '\u{2069}' => isolate_depth = std::cmp::max(isolate_depth, 1) - 1,
+            _ if isolate_depth > 0 => {}
+            _ => {
+                if let Some(&direction) = range_value(STRONG_DIRECTION_RANGES, c) {
+                    return Some(direction);
+                }
+            }
+        }
+    }
+    None
+}
+
 /// Checks whether `subject` matches the regular expression `pattern`.
 /// NOTE: Executes regular expressions only on valid UTF-8 while exposing match locations as byte indices into the search string (see case #4).
 /// # Arguments
@@ -810,25 +1539,177 @@ pub fn query(subject: &str, search: &str, position: usize) -> bool {
     if subject_len == 0 || search.is_empty() {
         return true;
     }
+    let q: Vec<char> = search.chars().collect();
+    let start = subject
+        .char_indices()
+        .nth(position)
+        .map_or(subject.len(), |(i, _)| i);
     let mut i: usize = 0;
-    let q = crate::split::chars(search);
-    let q_len = crate::split::chars(search).len();
-    crate::split::chars(&subject.to_owned()[subject.char_indices().nth(position).unwrap().0..])
-        .into_iter()
-        .filter(|c| {
-            if i < q_len {
-                if c == &q[i] {
-                    i += 1;
-                    true
-                } else {
-                    false
+    for c in subject[start..].chars() {
+        if c == q[i] {
+            i += 1;
+            if i == q.len() {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// The result of `query::fuzzy_match`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// The match score. The higher score means the better match.
+    pub score: i32,
+    /// The positions of the matched units (characters or graphemes) in `subject`.
+    pub positions: Vec<usize>,
+}
+
+const SCORE_MATCH: i32 = 16;
+const SCORE_GAP_START: i32 = -3;
+const SCORE_GAP_EXTENSION: i32 = -1;
+const BONUS_BOUNDARY: i32 = 8;
+const BONUS_CAMEL: i32 = 7;
+const BONUS_CONSECUTIVE: i32 = 4;
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+/// Fuzzy matches `search` against `subject` the way fzf does: all units of `search` have to appear in `subject` in the same order.
+/// Returns the score and the positions of the matched units, or `None` if `subject` does not match.
+/// Matches at word starts (see `split::words`), camelCase humps and consecutive matches get a bonus, gaps are penalized.
+/// The matching is case-insensitive unless `search` contains an uppercase character.
+///
+/// # Arguments
+///
+/// * `subject` - The string to match.
+/// * `search` - The string to search.
+/// * `unit` - The unit of the returned positions: `split::Unit::Char`, `split::Unit::Grapheme` or `split::Unit::Word`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// query::fuzzy_match("src/main.rs", "smr", split::Unit::Char).unwrap().positions;
+/// // => [0, 4, 9]
+/// query::fuzzy_match("getElementById", "gebi", split::Unit::Char).unwrap().positions;
+/// // => [0, 3, 10, 12]
+/// query::fuzzy_match("Café del Mar", "cdm", split::Unit::Grapheme).unwrap().positions;
+/// // => [0, 5, 9]
+/// query::fuzzy_match("shipyard", "ys", split::Unit::Char);
+/// // => None
+/// use voca_rs::Voca;
+/// "src/main.rs"._fuzzy_match("smr", split::Unit::Char).unwrap().positions;
+/// // => [0, 4, 9]
+/// ```
+pub fn fuzzy_match(subject: &str, search: &str, unit: crate::split::Unit) -> Option<FuzzyMatch> {
+    let subject_units = crate::split::units(subject, unit);
+    let search_units = crate::split::units(search, unit);
+    if search_units.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: vec![],
+        });
+    }
+    if search_units.len() > subject_units.len() {
+        return None;
+    }
+    let options = crate::utils::MatchOptions {
+        ignore_case: !search.chars().any(char::is_uppercase),
+        ..Default::default()
+    };
+    let subject_folded: Vec<String> = subject_units
+        .iter()
+        .map(|u| crate::utils::fold(u, &options))
+        .collect();
+    let search_folded: Vec<String> = search_units
+        .iter()
+        .map(|u| crate::utils::fold(u, &options))
+        .collect();
+    let bonuses = fuzzy_bonuses(subject, &subject_units);
+
+    let n = subject_units.len();
+    let m = search_units.len();
+    let none = i32::MIN / 2;
+    let mut scores = vec![none; n * m];
+    let mut previous = vec![0usize; n * m];
+    for (i, q) in search_folded.iter().enumerate() {
+        let mut best_gap = none;
+        let mut best_gap_position = 0;
+        for j in 0..n {
+            if i > 0 && j >= 2 {
+                let gap_start = scores[(i - 1) * n + j - 2] + SCORE_GAP_START;
+                best_gap += SCORE_GAP_EXTENSION;
+                if gap_start >= best_gap {
+                    best_gap = gap_start;
+                    best_gap_position = j - 2;
                 }
+            }
+            if &subject_folded[j] != q {
+                continue;
+            }
+            if i == 0 {
+                scores[j] = SCORE_MATCH + bonuses[j] * BONUS_FIRST_CHAR_MULTIPLIER;
+                continue;
+            }
+            if j == 0 {
+                continue;
+            }
+            let consecutive = scores[(i - 1) * n + j - 1];
+            let consecutive_score = if consecutive > none {
+                consecutive + SCORE_MATCH + bonuses[j].max(BONUS_CONSECUTIVE)
             } else {
-                false
+                none
+            };
+            let gap_score = if best_gap > none / 2 {
+                best_gap + SCORE_MATCH + bonuses[j]
+            } else {
+                none
+            };
+            if consecutive_score >= gap_score && consecutive_score > none {
+                scores[i * n + j] = consecutive_score;
+                previous[i * n + j] = j - 1;
+            } else if gap_score > none {
+                scores[i * n + j] = gap_score;
+                previous[i * n + j] = best_gap_position;
             }
+        }
+    }
+    let (last_position, score) = (0..n)
+        .map(|j| (j, scores[(m - 1) * n + j]))
+        .filter(|(_, score)| *score > none)
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))?;
+    let mut positions = vec![last_position; m];
+    for i in (1..m).rev() {
+        positions[i - 1] = previous[i * n + positions[i]];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+fn fuzzy_bonuses(subject: &str, subject_units: &[&str]) -> Vec<i32> {
+    let offset = |part: &str| part.as_ptr() as usize - subject.as_ptr() as usize;
+    let word_starts: Vec<usize> = crate::split::words(subject)
+        .iter()
+        .map(|w| offset(w))
+        .collect();
+    let mut previous_char: Option<char> = None;
+    subject_units
+        .iter()
+        .map(|u| {
+            let first_char = u.chars().next();
+            let bonus = match (previous_char, first_char) {
+                (None, _) => BONUS_BOUNDARY,
+                (Some(p), Some(c)) if word_starts.contains(&offset(u)) => {
+                    if (p.is_lowercase() || p.is_numeric()) && c.is_uppercase() {
+                        BONUS_CAMEL
+                    } else {
+                        BONUS_BOUNDARY
+                    }
+                }
+                _ => 0,
+            };
+            previous_char = u.chars().last();
+            bonus
         })
-        .count()
-        == crate::count::count(search)
+        .collect()
 }
 
 /// Checks whether `subject` starts with `start`.
@@ -856,3 +1737,3384 @@ pub fn starts_with(subject: &str, start: &str) -> bool {
     }
     subject.starts_with(start)
 }
+
+/// Checks whether `subject` starts with `start`, comparing the strings according to `options`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+/// * `start` - The starting string.
+/// * `options` - The options which define how the strings are compared.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let options = utils::MatchOptions::insensitive();
+/// query::starts_with_folded("Ärger im Paradies", "arger", &options);
+/// // => true
+/// query::starts_with_folded("say hello to my little friend", "hello", &options);
+/// // => false
+/// use voca_rs::Voca;
+/// "Ärger im Paradies"._starts_with_folded("arger", &options);
+/// // => true
+/// ```
+pub fn starts_with_folded(
+    subject: &str,
+    start: &str,
+    options: &crate::utils::MatchOptions,
+) -> bool {
+    if subject.is_empty() || start.is_empty() {
+        return true;
+    }
+    crate::utils::fold(subject, options).starts_with(&crate::utils::fold(start, options))
+}
+
+/// The Script property ranges from `Scripts.txt` of Unicode 16.0, without `Script::Common` and `Script::Inherited`.
+static SCRIPT_RANGES: &[(u32, u32, Script)] = &[
+    (0x0041, 0x005A, Script::Latin),
+    (0x0061, 0x007A, Script::Latin),
+    (0x00AA, 0x00AA, Script::Latin),
+    (0x00BA, 0x00BA, Script::Latin),
+    (0x00C0, 0x00D6, Script::Latin),
+    (0x00D8, 0x00F6, Script::Latin),
+    (0x00F8, 0x02B8, Script::Latin),
+    (0x02E0, 0x02E4, Script::Latin),
+    (0x02EA, 0x02EB, Script::Bopomofo),
+    (0x0370, 0x0373, Script::Greek),
+    (0x0375, 0x0377, Script::Greek),
+    (0x037A, 0x037D, Script::Greek),
+    (0x037F, 0x037F, Script::Greek),
+    (0x0384, 0x0384, Script::Greek),
+    (0x0386, 0x0386, Script::Greek),
+    (0x0388, 0x038A, Script::Greek),
+    (0x038C, 0x038C, Script::Greek),
+    (0x038E, 0x03A1, Script::Greek),
+    (0x03A3, 0x03E1, Script::Greek),
+    (0x03E2, 0x03EF, Script::Coptic),
+    (0x03F0, 0x03FF, Script::Greek),
+    (0x0400, 0x0484, Script::Cyrillic),
+    (0x0487, 0x052F, Script::Cyrillic),
+    (0x0531, 0x0556, Script::Armenian),
+    (0x0559, 0x058A, Script::Armenian),
+    (0x058D, 0x058F, Script::Armenian),
+    (0x0591, 0x05C7, Script::Hebrew),
+    (0x05D0, 0x05EA, Script::Hebrew),
+    (0x05EF, 0x05F4, Script::Hebrew),
+    (0x0600, 0x0604, Script::Arabic),
+    (0x0606, 0x060B, Script::Arabic),
+    (0x060D, 0x061A, Script::Arabic),
+    (0x061C, 0x061E, Script::Arabic),
+    (0x0620, 0x063F, Script::Arabic),
+    (0x0641, 0x064A, Script::Arabic),
+    (0x0656, 0x066F, Script::Arabic),
+    (0x0671, 0x06DC, Script::Arabic),
+    (0x06DE, 0x06FF, Script::Arabic),
+    (0x0700, 0x070D, Script::Syriac),
+    (0x070F, 0x074A, Script::Syriac),
+    (0x074D, 0x074F, Script::Syriac),
+    (0x0750, 0x077F, Script::Arabic),
+    (0x0780, 0x07B1, Script::Thaana),
+    (0x07C0, 0x07FA, Script::Nko),
+    (0x07FD, 0x07FF, Script::Nko),
+    (0x0800, 0x082D, Script::Samaritan),
+    (0x0830, 0x083E, Script::Samaritan),
+    (0x0840, 0x085B, Script::Mandaic),
+    (0x085E, 0x085E, Script::Mandaic),
+    (0x0860, 0x086A, Script::Syriac),
+    (0x0870, 0x088E, Script::Arabic),
+    (0x0890, 0x0891, Script::Arabic),
+    (0x0897, 0x08E1, Script::Arabic),
+    (0x08E3, 0x08FF, Script::Arabic),
+    (0x0900, 0x0950, Script::Devanagari),
+    (0x0955, 0x0963, Script::Devanagari),
+    (0x0966, 0x097F, Script::Devanagari),
+    (0x0980, 0x0983, Script::Bengali),
+    (0x0985, 0x098C, Script::Bengali),
+    (0x098F, 0x0990, Script::Bengali),
+    (0x0993, 0x09A8, Script::Bengali),
+    (0x09AA, 0x09B0, Script::Bengali),
+    (0x09B2, 0x09B2, Script::Bengali),
+    (0x09B6, 0x09B9, Script::Bengali),
+    (0x09BC, 0x09C4, Script::Bengali),
+    (0x09C7, 0x09C8, Script::Bengali),
+    (0x09CB, 0x09CE, Script::Bengali),
+    (0x09D7, 0x09D7, Script::Bengali),
+    (0x09DC, 0x09DD, Script::Bengali),
+    (0x09DF, 0x09E3, Script::Bengali),
+    (0x09E6, 0x09FE, Script::Bengali),
+    (0x0A01, 0x0A03, Script::Gurmukhi),
+    (0x0A05, 0x0A0A, Script::Gurmukhi),
+    (0x0A0F, 0x0A10, Script::Gurmukhi),
+    (0x0A13, 0x0A28, Script::Gurmukhi),
+    (0x0A2A, 0x0A30, Script::Gurmukhi),
+    (0x0A32, 0x0A33, Script::Gurmukhi),
+    (0x0A35, 0x0A36, Script::Gurmukhi),
+    (0x0A38, 0x0A39, Script::Gurmukhi),
+    (0x0A3C, 0x0A3C, Script::Gurmukhi),
+    (0x0A3E, 0x0A42, Script::Gurmukhi),
+    (0x0A47, 0x0A48, Script::Gurmukhi),
+    (0x0A4B, 0x0A4D, Script::Gurmukhi),
+    (0x0A51, 0x0A51, Script::Gurmukhi),
+    (0x0A59, 0x0A5C, Script::Gurmukhi),
+    (0x0A5E, 0x0A5E, Script::Gurmukhi),
+    (0x0A66, 0x0A76, Script::Gurmukhi),
+    (0x0A81, 0x0A83, Script::Gujarati),
+    (0x0A85, 0x0A8D, Script::Gujarati),
+    (0x0A8F, 0x0A91, Script::Gujarati),
+    (0x0A93, 0x0AA8, Script::Gujarati),
+    (0x0AAA, 0x0AB0, Script::Gujarati),
+    (0x0AB2, 0x0AB3, Script::Gujarati),
+    (0x0AB5, 0x0AB9, Script::Gujarati),
+    (0x0ABC, 0x0AC5, Script::Gujarati),
+    (0x0AC7, 0x0AC9, Script::Gujarati),
+    (0x0ACB, 0x0ACD, Script::Gujarati),
+    (0x0AD0, 0x0AD0, Script::Gujarati),
+    (0x0AE0, 0x0AE3, Script::Gujarati),
+    (0x0AE6, 0x0AF1, Script::Gujarati),
+    (0x0AF9, 0x0AFF, Script::Gujarati),
+    (0x0B01, 0x0B03, Script::Oriya),
+    (0x0B05, 0x0B0C, Script::Oriya),
+    (0x0B0F, 0x0B10, Script::Oriya),
+    (0x0B13, 0x0B28, Script::Oriya),
+    (0x0B2A, 0x0B30, Script::Oriya),
+    (0x0B32, 0x0B33, Script::Oriya),
+    (0x0B35, 0x0B39, Script::Oriya),
+    (0x0B3C, 0x0B44, Script::Oriya),
+    (0x0B47, 0x0B48, Script::Oriya),
+    (0x0B4B, 0x0B4D, Script::Oriya),
+    (0x0B55, 0x0B57, Script::Oriya),
+    (0x0B5C, 0x0B5D, Script::Oriya),
+    (0x0B5F, 0x0B63, Script::Oriya),
+    (0x0B66, 0x0B77, Script::Oriya),
+    (0x0B82, 0x0B83, Script::Tamil),
+    (0x0B85, 0x0B8A, Script::Tamil),
+    (0x0B8E, 0x0B90, Script::Tamil),
+    (0x0B92, 0x0B95, Script::Tamil),
+    (0x0B99, 0x0B9A, Script::Tamil),
+    (0x0B9C, 0x0B9C, Script::Tamil),
+    (0x0B9E, 0x0B9F, Script::Tamil),
+    (0x0BA3, 0x0BA4, Script::Tamil),
+    (0x0BA8, 0x0BAA, Script::Tamil),
+    (0x0BAE, 0x0BB9, Script::Tamil),
+    (0x0BBE, 0x0BC2, Script::Tamil),
+    (0x0BC6, 0x0BC8, Script::Tamil),
+    (0x0BCA, 0x0BCD, Script::Tamil),
+    (0x0BD0, 0x0BD0, Script::Tamil),
+    (0x0BD7, 0x0BD7, Script::Tamil),
+    (0x0BE6, 0x0BFA, Script::Tamil),
+    (0x0C00, 0x0C0C, Script::Telugu),
+    (0x0C0E, 0x0C10, Script::Telugu),
+    (0x0C12, 0x0C28, Script::Telugu),
+    (0x0C2A, 0x0C39, Script::Telugu),
+    (0x0C3C, 0x0C44, Script::Telugu),
+    (0x0C46, 0x0C48, Script::Telugu),
+    (0x0C4A, 0x0C4D, Script::Telugu),
+    (0x0C55, 0x0C56, Script::Telugu),
+    (0x0C58, 0x0C5A, Script::Telugu),
+    (0x0C5D, 0x0C5D, Script::Telugu),
+    (0x0C60, 0x0C63, Script::Telugu),
+    (0x0C66, 0x0C6F, Script::Telugu),
+    (0x0C77, 0x0C7F, Script::Telugu),
+    (0x0C80, 0x0C8C, Script::Kannada),
+    (0x0C8E, 0x0C90, Script::Kannada),
+    (0x0C92, 0x0CA8, Script::Kannada),
+    (0x0CAA, 0x0CB3, Script::Kannada),
+    (0x0CB5, 0x0CB9, Script::Kannada),
+    (0x0CBC, 0x0CC4, Script::Kannada),
+    (0x0CC6, 0x0CC8, Script::Kannada),
+    (0x0CCA, 0x0CCD, Script::Kannada),
+    (0x0CD5, 0x0CD6, Script::Kannada),
+    (0x0CDD, 0x0CDE, Script::Kannada),
+    (0x0CE0, 0x0CE3, Script::Kannada),
+    (0x0CE6, 0x0CEF, Script::Kannada),
+    (0x0CF1, 0x0CF3, Script::Kannada),
+    (0x0D00, 0x0D0C, Script::Malayalam),
+    (0x0D0E, 0x0D10, Script::Malayalam),
+    (0x0D12, 0x0D44, Script::Malayalam),
+    (0x0D46, 0x0D48, Script::Malayalam),
+    (0x0D4A, 0x0D4F, Script::Malayalam),
+    (0x0D54, 0x0D63, Script::Malayalam),
+    (0x0D66, 0x0D7F, Script::Malayalam),
+    (0x0D81, 0x0D83, Script::Sinhala),
+    (0x0D85, 0x0D96, Script::Sinhala),
+    (0x0D9A, 0x0DB1, Script::Sinhala),
+    (0x0DB3, 0x0DBB, Script::Sinhala),
+    (0x0DBD, 0x0DBD, Script::Sinhala),
+    (0x0DC0, 0x0DC6, Script::Sinhala),
+    (0x0DCA, 0x0DCA, Script::Sinhala),
+    (0x0DCF, 0x0DD4, Script::Sinhala),
+    (0x0DD6, 0x0DD6, Script::Sinhala),
+    (0x0DD8, 0x0DDF, Script::Sinhala),
+    (0x0DE6, 0x0DEF, Script::Sinhala),
+    (0x0DF2, 0x0DF4, Script::Sinhala),
+    (0x0E01, 0x0E3A, Script::Thai),
+    (0x0E40, 0x0E5B, Script::Thai),
+    (0x0E81, 0x0E82, Script::Lao),
+    (0x0E84, 0x0E84, Script::Lao),
+    (0x0E86, 0x0E8A, Script::Lao),
+    (0x0E8C, 0x0EA3, Script::Lao),
+    (0x0EA5, 0x0EA5, Script::Lao),
+    (0x0EA7, 0x0EBD, Script::Lao),
+    (0x0EC0, 0x0EC4, Script::Lao),
+    (0x0EC6, 0x0EC6, Script::Lao),
+    (0x0EC8, 0x0ECE, Script::Lao),
+    (0x0ED0, 0x0ED9, Script::Lao),
+    (0x0EDC, 0x0EDF, Script::Lao),
+    (0x0F00, 0x0F47, Script::Tibetan),
+    (0x0F49, 0x0F6C, Script::Tibetan),
+    (0x0F71, 0x0F97, Script::Tibetan),
+    (0x0F99, 0x0FBC, Script::Tibetan),
+    (0x0FBE, 0x0FCC, Script::Tibetan),
+    (0x0FCE, 0x0FD4, Script::Tibetan),
+    (0x0FD9, 0x0FDA, Script::Tibetan),
+    (0x1000, 0x109F, Script::Myanmar),
+    (0x10A0, 0x10C5, Script::Georgian),
+    (0x10C7, 0x10C7, Script::Georgian),
+    (0x10CD, 0x10CD, Script::Georgian),
+    (0x10D0, 0x10FA, Script::Georgian),
+    (0x10FC, 0x10FF, Script::Georgian),
+    (0x1100, 0x11FF, Script::Hangul),
+    (0x1200, 0x1248, Script::Ethiopic),
+    (0x124A, 0x124D, Script::Ethiopic),
+    (0x1250, 0x1256, Script::Ethiopic),
+    (0x1258, 0x1258, Script::Ethiopic),
+    (0x125A, 0x125D, Script::Ethiopic),
+    (0x1260, 0x1288, Script::Ethiopic),
+    (0x128A, 0x128D, Script::Ethiopic),
+    (0x1290, 0x12B0, Script::Ethiopic),
+    (0x12B2, 0x12B5, Script::Ethiopic),
+    (0x12B8, 0x12BE, Script::Ethiopic),
+    (0x12C0, 0x12C0, Script::Ethiopic),
+    (0x12C2, 0x12C5, Script::Ethiopic),
+    (0x12C8, 0x12D6, Script::Ethiopic),
+    (0x12D8, 0x1310, Script::Ethiopic),
+    (0x1312, 0x1315, Script::Ethiopic),
+    (0x1318, 0x135A, Script::Ethiopic),
+    (0x135D, 0x137C, Script::Ethiopic),
+    (0x1380, 0x1399, Script::Ethiopic),
+    (0x13A0, 0x13F5, Script::Cherokee),
+    (0x13F8, 0x13FD, Script::Cherokee),
+    (0x1400, 0x167F, Script::CanadianAboriginal),
+    (0x1680, 0x169C, Script::Ogham),
+    (0x16A0, 0x16EA, Script::Runic),
+    (0x16EE, 0x16F8, Script::Runic),
+    (0x1700, 0x1715, Script::Tagalog),
+    (0x171F, 0x171F, Script::Tagalog),
+    (0x1720, 0x1734, Script::Hanunoo),
+    (0x1740, 0x1753, Script::Buhid),
+    (0x1760, 0x176C, Script::Tagbanwa),
+    (0x176E, 0x1770, Script::Tagbanwa),
+    (0x1772, 0x1773, Script::Tagbanwa),
+    (0x1780, 0x17DD, Script::Khmer),
+    (0x17E0, 0x17E9, Script::Khmer),
+    (0x17F0, 0x17F9, Script::Khmer),
+    (0x1800, 0x1801, Script::Mongolian),
+    (0x1804, 0x1804, Script::Mongolian),
+    (0x1806, 0x1819, Script::Mongolian),
+    (0x1820, 0x1878, Script::Mongolian),
+    (0x1880, 0x18AA, Script::Mongolian),
+    (0x18B0, 0x18F5, Script::CanadianAboriginal),
+    (0x1900, 0x191E, Script::Limbu),
+    (0x1920, 0x192B, Script::Limbu),
+    (0x1930, 0x193B, Script::Limbu),
+    (0x1940, 0x1940, Script::Limbu),
+    (0x1944, 0x194F, Script::Limbu),
+    (0x1950, 0x196D, Script::TaiLe),
+    (0x1970, 0x1974, Script::TaiLe),
+    (0x1980, 0x19AB, Script::NewTaiLue),
+    (0x19B0, 0x19C9, Script::NewTaiLue),
+    (0x19D0, 0x19DA, Script::NewTaiLue),
+    (0x19DE, 0x19DF, Script::NewTaiLue),
+    (0x19E0, 0x19FF, Script::Khmer),
+    (0x1A00, 0x1A1B, Script::Buginese),
+    (0x1A1E, 0x1A1F, Script::Buginese),
+    (0x1A20, 0x1A5E, Script::TaiTham),
+    (0x1A60, 0x1A7C, Script::TaiTham),
+    (0x1A7F, 0x1A89, Script::TaiTham),
+    (0x1A90, 0x1A99, Script::TaiTham),
+    (0x1AA0, 0x1AAD, Script::TaiTham),
+    (0x1B00, 0x1B4C, Script::Balinese),
+    (0x1B4E, 0x1B7F, Script::Balinese),
+    (0x1B80, 0x1BBF, Script::Sundanese),
+    (0x1BC0, 0x1BF3, Script::Batak),
+    (0x1BFC, 0x1BFF, Script::Batak),
+    (0x1C00, 0x1C37, Script::Lepcha),
+    (0x1C3B, 0x1C49, Script::Lepcha),
+    (0x1C4D, 0x1C4F, Script::Lepcha),
+    (0x1C50, 0x1C7F, Script::OlChiki),
+    (0x1C80, 0x1C8A, Script::Cyrillic),
+    (0x1C90, 0x1CBA, Script::Georgian),
+    (0x1CBD, 0x1CBF, Script::Georgian),
+    (0x1CC0, 0x1CC7, Script::Sundanese),
+    (0x1D00, 0x1D25, Script::Latin),
+    (0x1D26, 0x1D2A, Script::Greek),
+    (0x1D2B, 0x1D2B, Script::Cyrillic),
+    (0x1D2C, 0x1D5C, Script::Latin),
+    (0x1D5D, 0x1D61, Script::Greek),
+    (0x1D62, 0x1D65, Script::Latin),
+    (0x1D66, 0x1D6A, Script::Greek),
+    (0x1D6B, 0x1D77, Script::Latin),
+    (0x1D78, 0x1D78, Script::Cyrillic),
+    (0x1D79, 0x1DBE, Script::Latin),
+    (0x1DBF, 0x1DBF, Script::Greek),
+    (0x1E00, 0x1EFF, Script::Latin),
+    (0x1F00, 0x1F15, Script::Greek),
+    (0x1F18, 0x1F1D, Script::Greek),
+    (0x1F20, 0x1F45, Script::Greek),
+    (0x1F48, 0x1F4D, Script::Greek),
+    (0x1F50, 0x1F57, Script::Greek),
+    (0x1F59, 0x1F59, Script::Greek),
+    (0x1F5B, 0x1F5B, Script::Greek),
+    (0x1F5D, 0x1F5D, Script::Greek),
+    (0x1F5F, 0x1F7D, Script::Greek),
+    (0x1F80, 0x1FB4, Script::Greek),
+    (0x1FB6, 0x1FC4, Script::Greek),
+    (0x1FC6, 0x1FD3, Script::Greek),
+    (0x1FD6, 0x1FDB, Script::Greek),
+    (0x1FDD, 0x1FEF, Script::Greek),
+    (0x1FF2, 0x1FF4, Script::Greek),
+    (0x1FF6, 0x1FFE, Script::Greek),
+    (0x2071, 0x2071, Script::Latin),
+    (0x207F, 0x207F, Script::Latin),
+    (0x2090, 0x209C, Script::Latin),
+    (0x2126, 0x2126, Script::Greek),
+    (0x212A, 0x212B, Script::Latin),
+    (0x2132, 0x2132, Script::Latin),
+    (0x214E, 0x214E, Script::Latin),
+    (0x2160, 0x2188, Script::Latin),
+    (0x2800, 0x28FF, Script::Braille),
+    (0x2C00, 0x2C5F, Script::Glagolitic),
+    (0x2C60, 0x2C7F, Script::Latin),
+    (0x2C80, 0x2CF3, Script::Coptic),
+    (0x2CF9, 0x2CFF, Script::Coptic),
+    (0x2D00, 0x2D25, Script::Georgian),
+    (0x2D27, 0x2D27, Script::Georgian),
+    (0x2D2D, 0x2D2D, Script::Georgian),
+    (0x2D30, 0x2D67, Script::Tifinagh),
+    (0x2D6F, 0x2D70, Script::Tifinagh),
+    (0x2D7F, 0x2D7F, Script::Tifinagh),
+    (0x2D80, 0x2D96, Script::Ethiopic),
+    (0x2DA0, 0x2DA6, Script::Ethiopic),
+    (0x2DA8, 0x2DAE, Script::Ethiopic),
+    (0x2DB0, 0x2DB6, Script::Ethiopic),
+    (0x2DB8, 0x2DBE, Script::Ethiopic),
+    (0x2DC0, 0x2DC6, Script::Ethiopic),
+    (0x2DC8, 0x2DCE, Script::Ethiopic),
+    (0x2DD0, 0x2DD6, Script::Ethiopic),
+    (0x2DD8, 0x2DDE, Script::Ethiopic),
+    (0x2DE0, 0x2DFF, Script::Cyrillic),
+    (0x2E80, 0x2E99, Script::Han),
+    (0x2E9B, 0x2EF3, Script::Han),
+    (0x2F00, 0x2FD5, Script::Han),
+    (0x3005, 0x3005, Script::Han),
+    (0x3007, 0x3007, Script::Han),
+    (0x3021, 0x3029, Script::Han),
+    (0x302E, 0x302F, Script::Hangul),
+    (0x3038, 0x303B, Script::Han),
+    (0x3041, 0x3096, Script::Hiragana),
+    (0x309D, 0x309F, Script::Hiragana),
+    (0x30A1, 0x30FA, Script::Katakana),
+    (0x30FD, 0x30FF, Script::Katakana),
+    (0x3105, 0x312F, Script::Bopomofo),
+    (0x3131, 0x318E, Script::Hangul),
+    (0x31A0, 0x31BF, Script::Bopomofo),
+    (0x31F0, 0x31FF, Script::Katakana),
+    (0x3200, 0x321E, Script::Hangul),
+    (0x3260, 0x327E, Script::Hangul),
+    (0x32D0, 0x32FE, Script::Katakana),
+    (0x3300, 0x3357, Script::Katakana),
+    (0x3400, 0x4DBF, Script::Han),
+    (0x4E00, 0x9FFF, Script::Han),
+    (0xA000, 0xA48C, Script::Yi),
+    (0xA490, 0xA4C6, Script::Yi),
+    (0xA4D0, 0xA4FF, Script::Lisu),
+    (0xA500, 0xA62B, Script::Vai),
+    (0xA640, 0xA69F, Script::Cyrillic),
+    (0xA6A0, 0xA6F7, Script::Bamum),
+    (0xA722, 0xA787, Script::Latin),
+    (0xA78B, 0xA7CD, Script::Latin),
+    (0xA7D0, 0xA7D1, Script::Latin),
+    (0xA7D3, 0xA7D3, Script::Latin),
+    (0xA7D5, 0xA7DC, Script::Latin),
+    (0xA7F2, 0xA7FF, Script::Latin),
+    (0xA800, 0xA82C, Script::SylotiNagri),
+    (0xA840, 0xA877, Script::PhagsPa),
+    (0xA880, 0xA8C5, Script::Saurashtra),
+    (0xA8CE, 0xA8D9, Script::Saurashtra),
+    (0xA8E0, 0xA8FF, Script::Devanagari),
+    (0xA900, 0xA92D, Script::KayahLi),
+    (0xA92F, 0xA92F, Script::KayahLi),
+    (0xA930, 0xA953, Script::Rejang),
+    (0xA95F, 0xA95F, Script::Rejang),
+    (0xA960, 0xA97C, Script::Hangul),
+    (0xA980, 0xA9CD, Script::Javanese),
+    (0xA9D0, 0xA9D9, Script::Javanese),
+    (0xA9DE, 0xA9DF, Script::Javanese),
+    (0xA9E0, 0xA9FE, Script::Myanmar),
+    (0xAA00, 0xAA36, Script::Cham),
+    (0xAA40, 0xAA4D, Script::Cham),
+    (0xAA50, 0xAA59, Script::Cham),
+    (0xAA5C, 0xAA5F, Script::Cham),
+    (0xAA60, 0xAA7F, Script::Myanmar),
+    (0xAA80, 0xAAC2, Script::TaiViet),
+    (0xAADB, 0xAADF, Script::TaiViet),
+    (0xAAE0, 0xAAF6, Script::MeeteiMayek),
+    (0xAB01, 0xAB06, Script::Ethiopic),
+    (0xAB09, 0xAB0E, Script::Ethiopic),
+    (0xAB11, 0xAB16, Script::Ethiopic),
+    (0xAB20, 0xAB26, Script::Ethiopic),
+    (0xAB28, 0xAB2E, Script::Ethiopic),
+    (0xAB30, 0xAB5A, Script::Latin),
+    (0xAB5C, 0xAB64, Script::Latin),
+    (0xAB65, 0xAB65, Script::Greek),
+    (0xAB66, 0xAB69, Script::Latin),
+    (0xAB70, 0xABBF, Script::Cherokee),
+    (0xABC0, 0xABED, Script::MeeteiMayek),
+    (0xABF0, 0xABF9, Script::MeeteiMayek),
+    (0xAC00, 0xD7A3, Script::Hangul),
+    (0xD7B0, 0xD7C6, Script::Hangul),
+    (0xD7CB, 0xD7FB, Script::Hangul),
+    (0xF900, 0xFA6D, Script::Han),
+    (0xFA70, 0xFAD9, Script::Han),
+    (0xFB00, 0xFB06, Script::Latin),
+    (0xFB13, 0xFB17, Script::Armenian),
+    (0xFB1D, 0xFB36, Script::Hebrew),
+    (0xFB38, 0xFB3C, Script::Hebrew),
+    (0xFB3E, 0xFB3E, Script::Hebrew),
+    (0xFB40, 0xFB41, Script::Hebrew),
+    (0xFB43, 0xFB44, Script::Hebrew),
+    (0xFB46, 0xFB4F, Script::Hebrew),
+    (0xFB50, 0xFBC2, Script::Arabic),
+    (0xFBD3, 0xFD3D, Script::Arabic),
+    (0xFD40, 0xFD8F, Script::Arabic),
+    (0xFD92, 0xFDC7, Script::Arabic),
+    (0xFDCF, 0xFDCF, Script::Arabic),
+    (0xFDF0, 0xFDFF, Script::Arabic),
+    (0xFE2E, 0xFE2F, Script::Cyrillic),
+    (0xFE70, 0xFE74, Script::Arabic),
+    (0xFE76, 0xFEFC, Script::Arabic),
+    (0xFF21, 0xFF3A, Script::Latin),
+    (0xFF41, 0xFF5A, Script::Latin),
+    (0xFF66, 0xFF6F, Script::Katakana),
+    (0xFF71, 0xFF9D, Script::Katakana),
+    (0xFFA0, 0xFFBE, Script::Hangul),
+    (0xFFC2, 0xFFC7, Script::Hangul),
+    (0xFFCA, 0xFFCF, Script::Hangul),
+    (0xFFD2, 0xFFD7, Script::Hangul),
+    (0xFFDA, 0xFFDC, Script::Hangul),
+    (0x10000, 0x1000B, Script::LinearB),
+    (0x1000D, 0x10026, Script::LinearB),
+    (0x10028, 0x1003A, Script::LinearB),
+    (0x1003C, 0x1003D, Script::LinearB),
+    (0x1003F, 0x1004D, Script::LinearB),
+    (0x10050, 0x1005D, Script::LinearB),
+    (0x10080, 0x100FA, Script::LinearB),
+    (0x10140, 0x1018E, Script::Greek),
+    (0x101A0, 0x101A0, Script::Greek),
+    (0x10280, 0x1029C, Script::Lycian),
+    (0x102A0, 0x102D0, Script::Carian),
+    (0x10300, 0x10323, Script::OldItalic),
+    (0x1032D, 0x1032F, Script::OldItalic),
+    (0x10330, 0x1034A, Script::Gothic),
+    (0x10350, 0x1037A, Script::OldPermic),
+    (0x10380, 0x1039D, Script::Ugaritic),
+    (0x1039F, 0x1039F, Script::Ugaritic),
+    (0x103A0, 0x103C3, Script::OldPersian),
+    (0x103C8, 0x103D5, Script::OldPersian),
+    (0x10400, 0x1044F, Script::Deseret),
+    (0x10450, 0x1047F, Script::Shavian),
+    (0x10480, 0x1049D, Script::Osmanya),
+    (0x104A0, 0x104A9, Script::Osmanya),
+    (0x104B0, 0x104D3, Script::Osage),
+    (0x104D8, 0x104FB, Script::Osage),
+    (0x10500, 0x10527, Script::Elbasan),
+    (0x10530, 0x10563, Script::CaucasianAlbanian),
+    (0x1056F, 0x1056F, Script::CaucasianAlbanian),
+    (0x10570, 0x1057A, Script::Vithkuqi),
+    (0x1057C, 0x1058A, Script::Vithkuqi),
+    (0x1058C, 0x10592, Script::Vithkuqi),
+    (0x10594, 0x10595, Script::Vithkuqi),
+    (0x10597, 0x105A1, Script::Vithkuqi),
+    (0x105A3, 0x105B1, Script::Vithkuqi),
+    (0x105B3, 0x105B9, Script::Vithkuqi),
+    (0x105BB, 0x105BC, Script::Vithkuqi),
+    (0x105C0, 0x105F3, Script::Todhri),
+    (0x10600, 0x10736, Script::LinearA),
+    (0x10740, 0x10755, Script::LinearA),
+    (0x10760, 0x10767, Script::LinearA),
+    (0x10780, 0x10785, Script::Latin),
+    (0x10787, 0x107B0, Script::Latin),
+    (0x107B2, 0x107BA, Script::Latin),
+    (0x10800, 0x10805, Script::Cypriot),
+    (0x10808, 0x10808, Script::Cypriot),
+    (0x1080A, 0x10835, Script::Cypriot),
+    (0x10837, 0x10838, Script::Cypriot),
+    (0x1083C, 0x1083C, Script::Cypriot),
+    (0x1083F, 0x1083F, Script::Cypriot),
+    (0x10840, 0x10855, Script::ImperialAramaic),
+    (0x10857, 0x1085F, Script::ImperialAramaic),
+    (0x10860, 0x1087F, Script::Palmyrene),
+    (0x10880, 0x1089E, Script::Nabataean),
+    (0x108A7, 0x108AF, Script::Nabataean),
+    (0x108E0, 0x108F2, Script::Hatran),
+    (0x108F4, 0x108F5, Script::Hatran),
+    (0x108FB, 0x108FF, Script::Hatran),
+    (0x10900, 0x1091B, Script::Phoenician),
+    (0x1091F, 0x1091F, Script::Phoenician),
+    (0x10920, 0x10939, Script::Lydian),
+    (0x1093F, 0x1093F, Script::Lydian),
+    (0x10980, 0x1099F, Script::MeroiticHieroglyphs),
+    (0x109A0, 0x109B7, Script::MeroiticCursive),
+    (0x109BC, 0x109CF, Script::MeroiticCursive),
+    (0x109D2, 0x109FF, Script::MeroiticCursive),
+    (0x10A00, 0x10A03, Script::Kharoshthi),
+    (0x10A05, 0x10A06, Script::Kharoshthi),
+    (0x10A0C, 0x10A13, Script::Kharoshthi),
+    (0x10A15, 0x10A17, Script::Kharoshthi),
+    (0x10A19, 0x10A35, Script::Kharoshthi),
+    (0x10A38, 0x10A3A, Script::Kharoshthi),
+    (0x10A3F, 0x10A48, Script::Kharoshthi),
+    (0x10A50, 0x10A58, Script::Kharoshthi),
+    (0x10A60, 0x10A7F, Script::OldSouthArabian),
+    (0x10A80, 0x10A9F, Script::OldNorthArabian),
+    (0x10AC0, 0x10AE6, Script::Manichaean),
+    (0x10AEB, 0x10AF6, Script::Manichaean),
+    (0x10B00, 0x10B35, Script::Avestan),
+    (0x10B39, 0x10B3F, Script::Avestan),
+    (0x10B40, 0x10B55, Script::InscriptionalParthian),
+    (0x10B58, 0x10B5F, Script::InscriptionalParthian),
+    (0x10B60, 0x10B72, Script::InscriptionalPahlavi),
+    (0x10B78, 0x10B7F, Script::InscriptionalPahlavi),
+    (0x10B80, 0x10B91, Script::PsalterPahlavi),
+    (0x10B99, 0x10B9C, Script::PsalterPahlavi),
+    (0x10BA9, 0x10BAF, Script::PsalterPahlavi),
+    (0x10C00, 0x10C48, Script::OldTurkic),
+    (0x10C80, 0x10CB2, Script::OldHungarian),
+    (0x10CC0, 0x10CF2, Script::OldHungarian),
+    (0x10CFA, 0x10CFF, Script::OldHungarian),
+    (0x10D00, 0x10D27, Script::HanifiRohingya),
+    (0x10D30, 0x10D39, Script::HanifiRohingya),
+    (0x10D40, 0x10D65, Script::Garay),
+    (0x10D69, 0x10D85, Script::Garay),
+    (0x10D8E, 0x10D8F, Script::Garay),
+    (0x10E60, 0x10E7E, Script::Arabic),
+    (0x10E80, 0x10EA9, Script::Yezidi),
+    (0x10EAB, 0x10EAD, Script::Yezidi),
+    (0x10EB0, 0x10EB1, Script::Yezidi),
+    (0x10EC2, 0x10EC4, Script::Arabic),
+    (0x10EFC, 0x10EFF, Script::Arabic),
+    (0x10F00, 0x10F27, Script::OldSogdian),
+    (0x10F30, 0x10F59, Script::Sogdian),
+    (0x10F70, 0x10F89, Script::OldUyghur),
+    (0x10FB0, 0x10FCB, Script::Chorasmian),
+    (0x10FE0, 0x10FF6, Script::Elymaic),
+    (0x11000, 0x1104D, Script::Brahmi),
+    (0x11052, 0x11075, Script::Brahmi),
+    (0x1107F, 0x1107F, Script::Brahmi),
+    (0x11080, 0x110C2, Script::Kaithi),
+    (0x110CD, 0x110CD, Script::Kaithi),
+    (0x110D0, 0x110E8, Script::SoraSompeng),
+    (0x110F0, 0x110F9, Script::SoraSompeng),
+    (0x11100, 0x11134, Script::Chakma),
+    (0x11136, 0x11147, Script::Chakma),
+    (0x11150, 0x11176, Script::Mahajani),
+    (0x11180, 0x111DF, Script::Sharada),
+    (0x111E1, 0x111F4, Script::Sinhala),
+    (0x11200, 0x11211, Script::Khojki),
+    (0x11213, 0x11241, Script::Khojki),
+    (0x11280, 0x11286, Script::Multani),
+    (0x11288, 0x11288, Script::Multani),
+    (0x1128A, 0x1128D, Script::Multani),
+    (0x1128F, 0x1129D, Script::Multani),
+    (0x1129F, 0x112A9, Script::Multani),
+    (0x112B0, 0x112EA, Script::Khudawadi),
+    (0x112F0, 0x112F9, Script::Khudawadi),
+    (0x11300, 0x11303, Script::Grantha),
+    (0x11305, 0x1130C, Script::Grantha),
+    (0x1130F, 0x11310, Script::Grantha),
+    (0x11313, 0x11328, Script::Grantha),
+    (0x1132A, 0x11330, Script::Grantha),
+    (0x11332, 0x11333, Script::Grantha),
+    (0x11335, 0x11339, Script::Grantha),
+    (0x1133C, 0x11344, Script::Grantha),
+    (0x11347, 0x11348, Script::Grantha),
+    (0x1134B, 0x1134D, Script::Grantha),
+    (0x11350, 0x11350, Script::Grantha),
+    (0x11357, 0x11357, Script::Grantha),
+    (0x1135D, 0x11363, Script::Grantha),
+    (0x11366, 0x1136C, Script::Grantha),
+    (0x11370, 0x11374, Script::Grantha),
+    (0x11380, 0x11389, Script::TuluTigalari),
+    (0x1138B, 0x1138B, Script::TuluTigalari),
+    (0x1138E, 0x1138E, Script::TuluTigalari),
+    (0x11390, 0x113B5, Script::TuluTigalari),
+    (0x113B7, 0x113C0, Script::TuluTigalari),
+    (0x113C2, 0x113C2, Script::TuluTigalari),
+    (0x113C5, 0x113C5, Script::TuluTigalari),
+    (0x113C7, 0x113CA, Script::TuluTigalari),
+    (0x113CC, 0x113D5, Script::TuluTigalari),
+    (0x113D7, 0x113D8, Script::TuluTigalari),
+    (0x113E1, 0x113E2, Script::TuluTigalari),
+    (0x11400, 0x1145B, Script::Newa),
+    (0x1145D, 0x11461, Script::Newa),
+    (0x11480, 0x114C7, Script::Tirhuta),
+    (0x114D0, 0x114D9, Script::Tirhuta),
+    (0x11580, 0x115B5, Script::Siddham),
+    (0x115B8, 0x115DD, Script::Siddham),
+    (0x11600, 0x11644, Script::Modi),
+    (0x11650, 0x11659, Script::Modi),
+    (0x11660, 0x1166C, Script::Mongolian),
+    (0x11680, 0x116B9, Script::Takri),
+    (0x116C0, 0x116C9, Script::Takri),
+    (0x116D0, 0x116E3, Script::Myanmar),
+    (0x11700, 0x1171A, Script::Ahom),
+    (0x1171D, 0x1172B, Script::Ahom),
+    (0x11730, 0x11746, Script::Ahom),
+    (0x11800, 0x1183B, Script::Dogra),
+    (0x118A0, 0x118F2, Script::WarangCiti),
+    (0x118FF, 0x118FF, Script::WarangCiti),
+    (0x11900, 0x11906, Script::DivesAkuru),
+    (0x11909, 0x11909, Script::DivesAkuru),
+    (0x1190C, 0x11913, Script::DivesAkuru),
+    (0x11915, 0x11916, Script::DivesAkuru),
+    (0x11918, 0x11935, Script::DivesAkuru),
+    (0x11937, 0x11938, Script::DivesAkuru),
+    (0x1193B, 0x11946, Script::DivesAkuru),
+    (0x11950, 0x11959, Script::DivesAkuru),
+    (0x119A0, 0x119A7, Script::Nandinagari),
+    (0x119AA, 0x119D7, Script::Nandinagari),
+    (0x119DA, 0x119E4, Script::Nandinagari),
+    (0x11A00, 0x11A47, Script::ZanabazarSquare),
+    (0x11A50, 0x11AA2, Script::Soyombo),
+    (0x11AB0, 0x11ABF, Script::CanadianAboriginal),
+    (0x11AC0, 0x11AF8, Script::PauCinHau),
+    (0x11B00, 0x11B09, Script::Devanagari),
+    (0x11BC0, 0x11BE1, Script::Sunuwar),
+    (0x11BF0, 0x11BF9, Script::Sunuwar),
+    (0x11C00, 0x11C08, Script::Bhaiksuki),
+    (0x11C0A, 0x11C36, Script::Bhaiksuki),
+    (0x11C38, 0x11C45, Script::Bhaiksuki),
+    (0x11C50, 0x11C6C, Script::Bhaiksuki),
+    (0x11C70, 0x11C8F, Script::Marchen),
+    (0x11C92, 0x11CA7, Script::Marchen),
+    (0x11CA9, 0x11CB6, Script::Marchen),
+    (0x11D00, 0x11D06, Script::MasaramGondi),
+    (0x11D08, 0x11D09, Script::MasaramGondi),
+    (0x11D0B, 0x11D36, Script::MasaramGondi),
+    (0x11D3A, 0x11D3A, Script::MasaramGondi),
+    (0x11D3C, 0x11D3D, Script::MasaramGondi),
+    (0x11D3F, 0x11D47, Script::MasaramGondi),
+    (0x11D50, 0x11D59, Script::MasaramGondi),
+    (0x11D60, 0x11D65, Script::GunjalaGondi),
+    (0x11D67, 0x11D68, Script::GunjalaGondi),
+    (0x11D6A, 0x11D8E, Script::GunjalaGondi),
+    (0x11D90, 0x11D91, Script::GunjalaGondi),
+    (0x11D93, 0x11D98, Script::GunjalaGondi),
+    (0x11DA0, 0x11DA9, Script::GunjalaGondi),
+    (0x11EE0, 0x11EF8, Script::Makasar),
+    (0x11F00, 0x11F10, Script::Kawi),
+    (0x11F12, 0x11F3A, Script::Kawi),
+    (0x11F3E, 0x11F5A, Script::Kawi),
+    (0x11FB0, 0x11FB0, Script::Lisu),
+    (0x11FC0, 0x11FF1, Script::Tamil),
+    (0x11FFF, 0x11FFF, Script::Tamil),
+    (0x12000, 0x12399, Script::Cuneiform),
+    (0x12400, 0x1246E, Script::Cuneiform),
+    (0x12470, 0x12474, Script::Cuneiform),
+    (0x12480, 0x12543, Script::Cuneiform),
+    (0x12F90, 0x12FF2, Script::CyproMinoan),
+    (0x13000, 0x13455, Script::EgyptianHieroglyphs),
+    (0x13460, 0x143FA, Script::EgyptianHieroglyphs),
+    (0x14400, 0x14646, Script::AnatolianHieroglyphs),
+    (0x16100, 0x16139, Script::GurungKhema),
+    (0x16800, 0x16A38, Script::Bamum),
+    (0x16A40, 0x16A5E, Script::Mro),
+    (0x16A60, 0x16A69, Script::Mro),
+    (0x16A6E, 0x16A6F, Script::Mro),
+    (0x16A70, 0x16ABE, Script::Tangsa),
+    (0x16AC0, 0x16AC9, Script::Tangsa),
+    (0x16AD0, 0x16AED, Script::BassaVah),
+    (0x16AF0, 0x16AF5, Script::BassaVah),
+    (0x16B00, 0x16B45, Script::PahawhHmong),
+    (0x16B50, 0x16B59, Script::PahawhHmong),
+    (0x16B5B, 0x16B61, Script::PahawhHmong),
+    (0x16B63, 0x16B77, Script::PahawhHmong),
+    (0x16B7D, 0x16B8F, Script::PahawhHmong),
+    (0x16D40, 0x16D79, Script::KiratRai),
+    (0x16E40, 0x16E9A, Script::Medefaidrin),
+    (0x16F00, 0x16F4A, Script::Miao),
+    (0x16F4F, 0x16F87, Script::Miao),
+    (0x16F8F, 0x16F9F, Script::Miao),
+    (0x16FE0, 0x16FE0, Script::Tangut),
+    (0x16FE1, 0x16FE1, Script::Nushu),
+    (0x16FE2, 0x16FE3, Script::Han),
+    (0x16FE4, 0x16FE4, Script::KhitanSmallScript),
+    (0x16FF0, 0x16FF1, Script::Han),
+    (0x17000, 0x187F7, Script::Tangut),
+    (0x18800, 0x18AFF, Script::Tangut),
+    (0x18B00, 0x18CD5, Script::KhitanSmallScript),
+    (0x18CFF, 0x18CFF, Script::KhitanSmallScript),
+    (0x18D00, 0x18D08, Script::Tangut),
+    (0x1AFF0, 0x1AFF3, Script::Katakana),
+    (0x1AFF5, 0x1AFFB, Script::Katakana),
+    (0x1AFFD, 0x1AFFE, Script::Katakana),
+    (0x1B000, 0x1B000, Script::Katakana),
+    (0x1B001, 0x1B11F, Script::Hiragana),
+    (0x1B120, 0x1B122, Script::Katakana),
+    (0x1B132, 0x1B132, Script::Hiragana),
+    (0x1B150, 0x1B152, Script::Hiragana),
+    (0x1B155, 0x1B155, Script::Katakana),
+    (0x1B164, 0x1B167, Script::Katakana),
+    (0x1B170, 0x1B2FB, Script::Nushu),
+    (0x1BC00, 0x1BC6A, Script::Duployan),
+    (0x1BC70, 0x1BC7C, Script::Duployan),
+    (0x1BC80, 0x1BC88, Script::Duployan),
+    (0x1BC90, 0x1BC99, Script::Duployan),
+    (0x1BC9C, 0x1BC9F, Script::Duployan),
+    (0x1D200, 0x1D245, Script::Greek),
+    (0x1D800, 0x1DA8B, Script::SignWriting),
+    (0x1DA9B, 0x1DA9F, Script::SignWriting),
+    (0x1DAA1, 0x1DAAF, Script::SignWriting),
+    (0x1DF00, 0x1DF1E, Script::Latin),
+    (0x1DF25, 0x1DF2A, Script::Latin),
+    (0x1E000, 0x1E006, Script::Glagolitic),
+    (0x1E008, 0x1E018, Script::Glagolitic),
+    (0x1E01B, 0x1E021, Script::Glagolitic),
+    (0x1E023, 0x1E024, Script::Glagolitic),
+    (0x1E026, 0x1E02A, Script::Glagolitic),
+    (0x1E030, 0x1E06D, Script::Cyrillic),
+    (0x1E08F, 0x1E08F, Script::Cyrillic),
+    (0x1E100, 0x1E12C, Script::NyiakengPuachueHmong),
+    (0x1E130, 0x1E13D, Script::NyiakengPuachueHmong),
+    (0x1E140, 0x1E149, Script::NyiakengPuachueHmong),
+    (0x1E14E, 0x1E14F, Script::NyiakengPuachueHmong),
+    (0x1E290, 0x1E2AE, Script::Toto),
+    (0x1E2C0, 0x1E2F9, Script::Wancho),
+    (0x1E2FF, 0x1E2FF, Script::Wancho),
+    (0x1E4D0, 0x1E4F9, Script::NagMundari),
+    (0x1E5D0, 0x1E5FA, Script::OlOnal),
+    (0x1E5FF, 0x1E5FF, Script::OlOnal),
+    (0x1E7E0, 0x1E7E6, Script::Ethiopic),
+    (0x1E7E8, 0x1E7EB, Script::Ethiopic),
+    (0x1E7ED, 0x1E7EE, Script::Ethiopic),
+    (0x1E7F0, 0x1E7FE, Script::Ethiopic),
+    (0x1E800, 0x1E8C4, Script::MendeKikakui),
+    (0x1E8C7, 0x1E8D6, Script::MendeKikakui),
+    (0x1E900, 0x1E94B, Script::Adlam),
+    (0x1E950, 0x1E959, Script::Adlam),
+    (0x1E95E, 0x1E95F, Script::Adlam),
+    (0x1EE00, 0x1EE03, Script::Arabic),
+    (0x1EE05, 0x1EE1F, Script::Arabic),
+    (0x1EE21, 0x1EE22, Script::Arabic),
+    (0x1EE24, 0x1EE24, Script::Arabic),
+    (0x1EE27, 0x1EE27, Script::Arabic),
+    (0x1EE29, 0x1EE32, Script::Arabic),
+    (0x1EE34, 0x1EE37, Script::Arabic),
+    (0x1EE39, 0x1EE39, Script::Arabic),
+    (0x1EE3B, 0x1EE3B, Script::Arabic),
+    (0x1EE42, 0x1EE42, Script::Arabic),
+    (0x1EE47, 0x1EE47, Script::Arabic),
+    (0x1EE49, 0x1EE49, Script::Arabic),
+    (0x1EE4B, 0x1EE4B, Script::Arabic),
+    (0x1EE4D, 0x1EE4F, Script::Arabic),
+    (0x1EE51, 0x1EE52, Script::Arabic),
+    (0x1EE54, 0x1EE54, Script::Arabic),
+    (0x1EE57, 0x1EE57, Script::Arabic),
+    (0x1EE59, 0x1EE59, Script::Arabic),
+    (0x1EE5B, 0x1EE5B, Script::Arabic),
+    (0x1EE5D, 0x1EE5D, Script::Arabic),
+    (0x1EE5F, 0x1EE5F, Script::Arabic),
+    (0x1EE61, 0x1EE62, Script::Arabic),
+    (0x1EE64, 0x1EE64, Script::Arabic),
+    (0x1EE67, 0x1EE6A, Script::Arabic),
+    (0x1EE6C, 0x1EE72, Script::Arabic),
+    (0x1EE74, 0x1EE77, Script::Arabic),
+    (0x1EE79, 0x1EE7C, Script::Arabic),
+    (0x1EE7E, 0x1EE7E, Script::Arabic),
+    (0x1EE80, 0x1EE89, Script::Arabic),
+    (0x1EE8B, 0x1EE9B, Script::Arabic),
+    (0x1EEA1, 0x1EEA3, Script::Arabic),
+    (0x1EEA5, 0x1EEA9, Script::Arabic),
+    (0x1EEAB, 0x1EEBB, Script::Arabic),
+    (0x1EEF0, 0x1EEF1, Script::Arabic),
+    (0x1F200, 0x1F200, Script::Hiragana),
+    (0x20000, 0x2A6DF, Script::Han),
+    (0x2A700, 0x2B739, Script::Han),
+    (0x2B740, 0x2B81D, Script::Han),
+    (0x2B820, 0x2CEA1, Script::Han),
+    (0x2CEB0, 0x2EBE0, Script::Han),
+    (0x2EBF0, 0x2EE5D, Script::Han),
+    (0x2F800, 0x2FA1D, Script::Han),
+    (0x30000, 0x3134A, Script::Han),
+    (0x31350, 0x323AF, Script::Han),
+];
+
+/// The Script_Extensions property ranges from `ScriptExtensions.txt` of Unicode 16.0, without `Script::Common` and `Script::Inherited`.
+static SCRIPT_EXTENSION_RANGES: &[(u32, u32, &[Script])] = &[
+    (0x0041, 0x005A, &[Script::Latin]),
+    (0x0061, 0x007A, &[Script::Latin]),
+    (0x00AA, 0x00AA, &[Script::Latin]),
+    (
+        0x00B7,
+        0x00B7,
+        &[
+            Script::Avestan,
+            Script::Carian,
+            Script::Coptic,
+            Script::Duployan,
+            Script::Elbasan,
+            Script::Georgian,
+            Script::Glagolitic,
+            Script::Gothic,
+            Script::Greek,
+            Script::GunjalaGondi,
+            Script::Han,
+            Script::Latin,
+            Script::Lydian,
+            Script::Mahajani,
+            Script::OldPermic,
+            Script::Shavian,
+        ],
+    ),
+    (0x00BA, 0x00BA, &[Script::Latin]),
+    (0x00C0, 0x00D6, &[Script::Latin]),
+    (0x00D8, 0x00F6, &[Script::Latin]),
+    (0x00F8, 0x02B8, &[Script::Latin]),
+    (
+        0x02BC,
+        0x02BC,
+        &[
+            Script::Bengali,
+            Script::Cyrillic,
+            Script::Devanagari,
+            Script::Latin,
+            Script::Lisu,
+            Script::Thai,
+            Script::Toto,
+        ],
+    ),
+    (0x02C7, 0x02C7, &[Script::Bopomofo, Script::Latin]),
+    (0x02C9, 0x02CB, &[Script::Bopomofo, Script::Latin]),
+    (0x02CD, 0x02CD, &[Script::Latin, Script::Lisu]),
+    (0x02D7, 0x02D7, &[Script::Latin, Script::Thai]),
+    (0x02D9, 0x02D9, &[Script::Bopomofo, Script::Latin]),
+    (0x02E0, 0x02E4, &[Script::Latin]),
+    (0x02EA, 0x02EB, &[Script::Bopomofo]),
+    (
+        0x0300,
+        0x0300,
+        &[
+            Script::Cherokee,
+            Script::Coptic,
+            Script::Cyrillic,
+            Script::Greek,
+            Script::Latin,
+            Script::OldPermic,
+            Script::Sunuwar,
+            Script::TaiLe,
+        ],
+    ),
+    (
+        0x0301,
+        0x0301,
+        &[
+            Script::Cherokee,
+            Script::Cyrillic,
+            Script::Greek,
+            Script::Latin,
+            Script::Osage,
+            Script::Sunuwar,
+            Script::TaiLe,
+            Script::Todhri,
+        ],
+    ),
+    (
+        0x0302,
+        0x0302,
+        &[
+            Script::Cherokee,
+            Script::Cyrillic,
+            Script::Latin,
+            Script::Tifinagh,
+        ],
+    ),
+    (
+        0x0303,
+        0x0303,
+        &[
+            Script::Glagolitic,
+            Script::Latin,
+            Script::Sunuwar,
+            Script::Syriac,
+            Script::Thai,
+        ],
+    ),
+    (
+        0x0304,
+        0x0304,
+        &[
+            Script::CaucasianAlbanian,
+            Script::Cherokee,
+            Script::Coptic,
+            Script::Cyrillic,
+            Script::Gothic,
+            Script::Greek,
+            Script::Latin,
+            Script::Osage,
+            Script::Syriac,
+            Script::Tifinagh,
+            Script::Todhri,
+        ],
+    ),
+    (
+        0x0305,
+        0x0305,
+        &[
+            Script::Coptic,
+            Script::Elbasan,
+            Script::Glagolitic,
+            Script::Gothic,
+            Script::Katakana,
+            Script::Latin,
+        ],
+    ),
+    (
+        0x0306,
+        0x0306,
+        &[
+            Script::Cyrillic,
+            Script::Greek,
+            Script::Latin,
+            Script::OldPermic,
+        ],
+    ),
+    (
+        0x0307,
+        0x0307,
+        &[
+            Script::Coptic,
+            Script::Duployan,
+            Script::Hebrew,
+            Script::Latin,
+            Script::OldPermic,
+            Script::Syriac,
+            Script::TaiLe,
+            Script::Tifinagh,
+            Script::Todhri,
+        ],
+    ),
+    (
+        0x0308,
+        0x0308,
+        &[
+            Script::Armenian,
+            Script::Cyrillic,
+            Script::Duployan,
+            Script::Gothic,
+            Script::Greek,
+            Script::Hebrew,
+            Script::Latin,
+            Script::OldPermic,
+            Script::Syriac,
+            Script::TaiLe,
+        ],
+    ),
+    (0x0309, 0x0309, &[Script::Latin, Script::Tifinagh]),
+    (
+        0x030A,
+        0x030A,
+        &[Script::Duployan, Script::Latin, Script::Syriac],
+    ),
+    (
+        0x030B,
+        0x030B,
+        &[
+            Script::Cherokee,
+            Script::Cyrillic,
+            Script::Latin,
+            Script::Osage,
+        ],
+    ),
+    (
+        0x030C,
+        0x030C,
+        &[Script::Cherokee, Script::Latin, Script::TaiLe],
+    ),
+    (0x030D, 0x030D, &[Script::Latin, Script::Sunuwar]),
+    (0x030E, 0x030E, &[Script::Ethiopic, Script::Latin]),
+    (0x0310, 0x0310, &[Script::Latin, Script::Sunuwar]),
+    (
+        0x0311,
+        0x0311,
+        &[Script::Cyrillic, Script::Latin, Script::Todhri],
+    ),
+    (
+        0x0313,
+        0x0313,
+        &[
+            Script::Greek,
+            Script::Latin,
+            Script::OldPermic,
+            Script::Todhri,
+        ],
+    ),
+    (0x0320, 0x0320, &[Script::Latin, Script::Syriac]),
+    (
+        0x0323,
+        0x0323,
+        &[
+            Script::Cherokee,
+            Script::Duployan,
+            Script::Katakana,
+            Script::Latin,
+            Script::Syriac,
+        ],
+    ),
+    (
+        0x0324,
+        0x0324,
+        &[
+            Script::Cherokee,
+            Script::Duployan,
+            Script::Latin,
+            Script::Syriac,
+        ],
+    ),
+    (0x0325, 0x0325, &[Script::Latin, Script::Syriac]),
+    (
+        0x032D,
+        0x032D,
+        &[Script::Latin, Script::Sunuwar, Script::Syriac],
+    ),
+    (0x032E, 0x032E, &[Script::Latin, Script::Syriac]),
+    (
+        0x0330,
+        0x0330,
+        &[Script::Cherokee, Script::Latin, Script::Syriac],
+    ),
+    (
+        0x0331,
+        0x0331,
+        &[
+            Script::CaucasianAlbanian,
+            Script::Cherokee,
+            Script::Gothic,
+            Script::Latin,
+            Script::Sunuwar,
+            Script::Thai,
+        ],
+    ),
+    (0x0342, 0x0342, &[Script::Greek]),
+    (0x0345, 0x0345, &[Script::Greek]),
+    (0x0358, 0x0358, &[Script::Latin, Script::Osage]),
+    (
+        0x035E,
+        0x035E,
+        &[Script::CaucasianAlbanian, Script::Latin, Script::Todhri],
+    ),
+    (0x0363, 0x036F, &[Script::Latin]),
+    (0x0370, 0x0373, &[Script::Greek]),
+    (0x0374, 0x0375, &[Script::Coptic, Script::Greek]),
+    (0x0376, 0x0377, &[Script::Greek]),
+    (0x037A, 0x037D, &[Script::Greek]),
+    (0x037F, 0x037F, &[Script::Greek]),
+    (0x0384, 0x0384, &[Script::Greek]),
+    (0x0386, 0x0386, &[Script::Greek]),
+    (0x0388, 0x038A, &[Script::Greek]),
+    (0x038C, 0x038C, &[Script::Greek]),
+    (0x038E, 0x03A1, &[Script::Greek]),
+    (0x03A3, 0x03E1, &[Script::Greek]),
+    (0x03E2, 0x03EF, &[Script::Coptic]),
+    (0x03F0, 0x03FF, &[Script::Greek]),
+    (0x0400, 0x0482, &[Script::Cyrillic]),
+    (0x0483, 0x0483, &[Script::Cyrillic, Script::OldPermic]),
+    (0x0484, 0x0484, &[Script::Cyrillic, Script::Glagolitic]),
+    (0x0485, 0x0486, &[Script::Cyrillic, Script::Latin]),
+    (0x0487, 0x0487, &[Script::Cyrillic, Script::Glagolitic]),
+    (0x0488, 0x052F, &[Script::Cyrillic]),
+    (0x0531, 0x0556, &[Script::Armenian]),
+    (0x0559, 0x0588, &[Script::Armenian]),
+    (
+        0x0589,
+        0x0589,
+        &[Script::Armenian, Script::Georgian, Script::Glagolitic],
+    ),
+    (0x058A, 0x058A, &[Script::Armenian]),
+    (0x058D, 0x058F, &[Script::Armenian]),
+    (0x0591, 0x05C7, &[Script::Hebrew]),
+    (0x05D0, 0x05EA, &[Script::Hebrew]),
+    (0x05EF, 0x05F4, &[Script::Hebrew]),
+    (0x0600, 0x0604, &[Script::Arabic]),
+    (0x0606, 0x060B, &[Script::Arabic]),
+    (
+        0x060C,
+        0x060C,
+        &[
+            Script::Arabic,
+            Script::Garay,
+            Script::HanifiRohingya,
+            Script::Nko,
+            Script::Syriac,
+            Script::Thaana,
+            Script::Yezidi,
+        ],
+    ),
+    (0x060D, 0x061A, &[Script::Arabic]),
+    (
+        0x061B,
+        0x061B,
+        &[
+            Script::Arabic,
+            Script::Garay,
+            Script::HanifiRohingya,
+            Script::Nko,
+            Script::Syriac,
+            Script::Thaana,
+            Script::Yezidi,
+        ],
+    ),
+    (
+        0x061C,
+        0x061C,
+        &[Script::Arabic, Script::Syriac, Script::Thaana],
+    ),
+    (0x061D, 0x061E, &[Script::Arabic]),
+    (
+        0x061F,
+        0x061F,
+        &[
+            Script::Adlam,
+            Script::Arabic,
+            Script::Garay,
+            Script::HanifiRohingya,
+            Script::Nko,
+            Script::Syriac,
+            Script::Thaana,
+            Script::Yezidi,
+        ],
+    ),
+    (0x0620, 0x063F, &[Script::Arabic]),
+    (
+        0x0640,
+        0x0640,
+        &[
+            Script::Adlam,
+            Script::Arabic,
+            Script::HanifiRohingya,
+            Script::Mandaic,
+            Script::Manichaean,
+            Script::OldUyghur,
+            Script::PsalterPahlavi,
+            Script::Sogdian,
+            Script::Syriac,
+        ],
+    ),
+    (0x0641, 0x064A, &[Script::Arabic]),
+    (0x064B, 0x0655, &[Script::Arabic, Script::Syriac]),
+    (0x0656, 0x065F, &[Script::Arabic]),
+    (
+        0x0660,
+        0x0669,
+        &[Script::Arabic, Script::Thaana, Script::Yezidi],
+    ),
+    (0x066A, 0x066F, &[Script::Arabic]),
+    (0x0670, 0x0670, &[Script::Arabic, Script::Syriac]),
+    (0x0671, 0x06D3, &[Script::Arabic]),
+    (0x06D4, 0x06D4, &[Script::Arabic, Script::HanifiRohingya]),
+    (0x06D5, 0x06DC, &[Script::Arabic]),
+    (0x06DE, 0x06FF, &[Script::Arabic]),
+    (0x0700, 0x070D, &[Script::Syriac]),
+    (0x070F, 0x074A, &[Script::Syriac]),
+    (0x074D, 0x074F, &[Script::Syriac]),
+    (0x0750, 0x077F, &[Script::Arabic]),
+    (0x0780, 0x07B1, &[Script::Thaana]),
+    (0x07C0, 0x07FA, &[Script::Nko]),
+    (0x07FD, 0x07FF, &[Script::Nko]),
+    (0x0800, 0x082D, &[Script::Samaritan]),
+    (0x0830, 0x083E, &[Script::Samaritan]),
+    (0x0840, 0x085B, &[Script::Mandaic]),
+    (0x085E, 0x085E, &[Script::Mandaic]),
+    (0x0860, 0x086A, &[Script::Syriac]),
+    (0x0870, 0x088E, &[Script::Arabic]),
+    (0x0890, 0x0891, &[Script::Arabic]),
+    (0x0897, 0x08E1, &[Script::Arabic]),
+    (0x08E3, 0x08FF, &[Script::Arabic]),
+    (0x0900, 0x0950, &[Script::Devanagari]),
+    (
+        0x0951,
+        0x0951,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Grantha,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kannada,
+            Script::Latin,
+            Script::Malayalam,
+            Script::Oriya,
+            Script::Sharada,
+            Script::Tamil,
+            Script::Telugu,
+            Script::Tirhuta,
+        ],
+    ),
+    (
+        0x0952,
+        0x0952,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Grantha,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kannada,
+            Script::Latin,
+            Script::Malayalam,
+            Script::Oriya,
+            Script::Tamil,
+            Script::Telugu,
+            Script::Tirhuta,
+        ],
+    ),
+    (0x0955, 0x0963, &[Script::Devanagari]),
+    (
+        0x0964,
+        0x0964,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Grantha,
+            Script::Gujarati,
+            Script::GunjalaGondi,
+            Script::Gurmukhi,
+            Script::Kannada,
+            Script::Khudawadi,
+            Script::Mahajani,
+            Script::Malayalam,
+            Script::MasaramGondi,
+            Script::Nandinagari,
+            Script::OlOnal,
+            Script::Oriya,
+            Script::Sinhala,
+            Script::SylotiNagri,
+            Script::Takri,
+            Script::Tamil,
+            Script::Telugu,
+            Script::Tirhuta,
+        ],
+    ),
+    (
+        0x0965,
+        0x0965,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Grantha,
+            Script::Gujarati,
+            Script::GunjalaGondi,
+            Script::Gurmukhi,
+            Script::GurungKhema,
+            Script::Kannada,
+            Script::Khudawadi,
+            Script::Limbu,
+            Script::Mahajani,
+            Script::Malayalam,
+            Script::MasaramGondi,
+            Script::Nandinagari,
+            Script::OlOnal,
+            Script::Oriya,
+            Script::Sinhala,
+            Script::SylotiNagri,
+            Script::Takri,
+            Script::Tamil,
+            Script::Telugu,
+            Script::Tirhuta,
+        ],
+    ),
+    (
+        0x0966,
+        0x096F,
+        &[
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Kaithi,
+            Script::Mahajani,
+        ],
+    ),
+    (0x0970, 0x097F, &[Script::Devanagari]),
+    (0x0980, 0x0983, &[Script::Bengali]),
+    (0x0985, 0x098C, &[Script::Bengali]),
+    (0x098F, 0x0990, &[Script::Bengali]),
+    (0x0993, 0x09A8, &[Script::Bengali]),
+    (0x09AA, 0x09B0, &[Script::Bengali]),
+    (0x09B2, 0x09B2, &[Script::Bengali]),
+    (0x09B6, 0x09B9, &[Script::Bengali]),
+    (0x09BC, 0x09C4, &[Script::Bengali]),
+    (0x09C7, 0x09C8, &[Script::Bengali]),
+    (0x09CB, 0x09CE, &[Script::Bengali]),
+    (0x09D7, 0x09D7, &[Script::Bengali]),
+    (0x09DC, 0x09DD, &[Script::Bengali]),
+    (0x09DF, 0x09E3, &[Script::Bengali]),
+    (
+        0x09E6,
+        0x09EF,
+        &[Script::Bengali, Script::Chakma, Script::SylotiNagri],
+    ),
+    (0x09F0, 0x09FE, &[Script::Bengali]),
+    (0x0A01, 0x0A03, &[Script::Gurmukhi]),
+    (0x0A05, 0x0A0A, &[Script::Gurmukhi]),
+    (0x0A0F, 0x0A10, &[Script::Gurmukhi]),
+    (0x0A13, 0x0A28, &[Script::Gurmukhi]),
+    (0x0A2A, 0x0A30, &[Script::Gurmukhi]),
+    (0x0A32, 0x0A33, &[Script::Gurmukhi]),
+    (0x0A35, 0x0A36, &[Script::Gurmukhi]),
+    (0x0A38, 0x0A39, &[Script::Gurmukhi]),
+    (0x0A3C, 0x0A3C, &[Script::Gurmukhi]),
+    (0x0A3E, 0x0A42, &[Script::Gurmukhi]),
+    (0x0A47, 0x0A48, &[Script::Gurmukhi]),
+    (0x0A4B, 0x0A4D, &[Script::Gurmukhi]),
+    (0x0A51, 0x0A51, &[Script::Gurmukhi]),
+    (0x0A59, 0x0A5C, &[Script::Gurmukhi]),
+    (0x0A5E, 0x0A5E, &[Script::Gurmukhi]),
+    (0x0A66, 0x0A6F, &[Script::Gurmukhi, Script::Multani]),
+    (0x0A70, 0x0A76, &[Script::Gurmukhi]),
+    (0x0A81, 0x0A83, &[Script::Gujarati]),
+    (0x0A85, 0x0A8D, &[Script::Gujarati]),
+    (0x0A8F, 0x0A91, &[Script::Gujarati]),
+    (0x0A93, 0x0AA8, &[Script::Gujarati]),
+    (0x0AAA, 0x0AB0, &[Script::Gujarati]),
+    (0x0AB2, 0x0AB3, &[Script::Gujarati]),
+    (0x0AB5, 0x0AB9, &[Script::Gujarati]),
+    (0x0ABC, 0x0AC5, &[Script::Gujarati]),
+    (0x0AC7, 0x0AC9, &[Script::Gujarati]),
+    (0x0ACB, 0x0ACD, &[Script::Gujarati]),
+    (0x0AD0, 0x0AD0, &[Script::Gujarati]),
+    (0x0AE0, 0x0AE3, &[Script::Gujarati]),
+    (0x0AE6, 0x0AEF, &[Script::Gujarati, Script::Khojki]),
+    (0x0AF0, 0x0AF1, &[Script::Gujarati]),
+    (0x0AF9, 0x0AFF, &[Script::Gujarati]),
+    (0x0B01, 0x0B03, &[Script::Oriya]),
+    (0x0B05, 0x0B0C, &[Script::Oriya]),
+    (0x0B0F, 0x0B10, &[Script::Oriya]),
+    (0x0B13, 0x0B28, &[Script::Oriya]),
+    (0x0B2A, 0x0B30, &[Script::Oriya]),
+    (0x0B32, 0x0B33, &[Script::Oriya]),
+    (0x0B35, 0x0B39, &[Script::Oriya]),
+    (0x0B3C, 0x0B44, &[Script::Oriya]),
+    (0x0B47, 0x0B48, &[Script::Oriya]),
+    (0x0B4B, 0x0B4D, &[Script::Oriya]),
+    (0x0B55, 0x0B57, &[Script::Oriya]),
+    (0x0B5C, 0x0B5D, &[Script::Oriya]),
+    (0x0B5F, 0x0B63, &[Script::Oriya]),
+    (0x0B66, 0x0B77, &[Script::Oriya]),
+    (0x0B82, 0x0B83, &[Script::Tamil]),
+    (0x0B85, 0x0B8A, &[Script::Tamil]),
+    (0x0B8E, 0x0B90, &[Script::Tamil]),
+    (0x0B92, 0x0B95, &[Script::Tamil]),
+    (0x0B99, 0x0B9A, &[Script::Tamil]),
+    (0x0B9C, 0x0B9C, &[Script::Tamil]),
+    (0x0B9E, 0x0B9F, &[Script::Tamil]),
+    (0x0BA3, 0x0BA4, &[Script::Tamil]),
+    (0x0BA8, 0x0BAA, &[Script::Tamil]),
+    (0x0BAE, 0x0BB9, &[Script::Tamil]),
+    (0x0BBE, 0x0BC2, &[Script::Tamil]),
+    (0x0BC6, 0x0BC8, &[Script::Tamil]),
+    (0x0BCA, 0x0BCD, &[Script::Tamil]),
+    (0x0BD0, 0x0BD0, &[Script::Tamil]),
+    (0x0BD7, 0x0BD7, &[Script::Tamil]),
+    (0x0BE6, 0x0BF3, &[Script::Grantha, Script::Tamil]),
+    (0x0BF4, 0x0BFA, &[Script::Tamil]),
+    (0x0C00, 0x0C0C, &[Script::Telugu]),
+    (0x0C0E, 0x0C10, &[Script::Telugu]),
+    (0x0C12, 0x0C28, &[Script::Telugu]),
+    (0x0C2A, 0x0C39, &[Script::Telugu]),
+    (0x0C3C, 0x0C44, &[Script::Telugu]),
+    (0x0C46, 0x0C48, &[Script::Telugu]),
+    (0x0C4A, 0x0C4D, &[Script::Telugu]),
+    (0x0C55, 0x0C56, &[Script::Telugu]),
+    (0x0C58, 0x0C5A, &[Script::Telugu]),
+    (0x0C5D, 0x0C5D, &[Script::Telugu]),
+    (0x0C60, 0x0C63, &[Script::Telugu]),
+    (0x0C66, 0x0C6F, &[Script::Telugu]),
+    (0x0C77, 0x0C7F, &[Script::Telugu]),
+    (0x0C80, 0x0C8C, &[Script::Kannada]),
+    (0x0C8E, 0x0C90, &[Script::Kannada]),
+    (0x0C92, 0x0CA8, &[Script::Kannada]),
+    (0x0CAA, 0x0CB3, &[Script::Kannada]),
+    (0x0CB5, 0x0CB9, &[Script::Kannada]),
+    (0x0CBC, 0x0CC4, &[Script::Kannada]),
+    (0x0CC6, 0x0CC8, &[Script::Kannada]),
+    (0x0CCA, 0x0CCD, &[Script::Kannada]),
+    (0x0CD5, 0x0CD6, &[Script::Kannada]),
+    (0x0CDD, 0x0CDE, &[Script::Kannada]),
+    (0x0CE0, 0x0CE3, &[Script::Kannada]),
+    (
+        0x0CE6,
+        0x0CEF,
+        &[Script::Kannada, Script::Nandinagari, Script::TuluTigalari],
+    ),
+    (0x0CF1, 0x0CF3, &[Script::Kannada]),
+    (0x0D00, 0x0D0C, &[Script::Malayalam]),
+    (0x0D0E, 0x0D10, &[Script::Malayalam]),
+    (0x0D12, 0x0D44, &[Script::Malayalam]),
+    (0x0D46, 0x0D48, &[Script::Malayalam]),
+    (0x0D4A, 0x0D4F, &[Script::Malayalam]),
+    (0x0D54, 0x0D63, &[Script::Malayalam]),
+    (0x0D66, 0x0D7F, &[Script::Malayalam]),
+    (0x0D81, 0x0D83, &[Script::Sinhala]),
+    (0x0D85, 0x0D96, &[Script::Sinhala]),
+    (0x0D9A, 0x0DB1, &[Script::Sinhala]),
+    (0x0DB3, 0x0DBB, &[Script::Sinhala]),
+    (0x0DBD, 0x0DBD, &[Script::Sinhala]),
+    (0x0DC0, 0x0DC6, &[Script::Sinhala]),
+    (0x0DCA, 0x0DCA, &[Script::Sinhala]),
+    (0x0DCF, 0x0DD4, &[Script::Sinhala]),
+    (0x0DD6, 0x0DD6, &[Script::Sinhala]),
+    (0x0DD8, 0x0DDF, &[Script::Sinhala]),
+    (0x0DE6, 0x0DEF, &[Script::Sinhala]),
+    (0x0DF2, 0x0DF4, &[Script::Sinhala]),
+    (0x0E01, 0x0E3A, &[Script::Thai]),
+    (0x0E40, 0x0E5B, &[Script::Thai]),
+    (0x0E81, 0x0E82, &[Script::Lao]),
+    (0x0E84, 0x0E84, &[Script::Lao]),
+    (0x0E86, 0x0E8A, &[Script::Lao]),
+    (0x0E8C, 0x0EA3, &[Script::Lao]),
+    (0x0EA5, 0x0EA5, &[Script::Lao]),
+    (0x0EA7, 0x0EBD, &[Script::Lao]),
+    (0x0EC0, 0x0EC4, &[Script::Lao]),
+    (0x0EC6, 0x0EC6, &[Script::Lao]),
+    (0x0EC8, 0x0ECE, &[Script::Lao]),
+    (0x0ED0, 0x0ED9, &[Script::Lao]),
+    (0x0EDC, 0x0EDF, &[Script::Lao]),
+    (0x0F00, 0x0F47, &[Script::Tibetan]),
+    (0x0F49, 0x0F6C, &[Script::Tibetan]),
+    (0x0F71, 0x0F97, &[Script::Tibetan]),
+    (0x0F99, 0x0FBC, &[Script::Tibetan]),
+    (0x0FBE, 0x0FCC, &[Script::Tibetan]),
+    (0x0FCE, 0x0FD4, &[Script::Tibetan]),
+    (0x0FD9, 0x0FDA, &[Script::Tibetan]),
+    (0x1000, 0x103F, &[Script::Myanmar]),
+    (
+        0x1040,
+        0x1049,
+        &[Script::Chakma, Script::Myanmar, Script::TaiLe],
+    ),
+    (0x104A, 0x109F, &[Script::Myanmar]),
+    (0x10A0, 0x10C5, &[Script::Georgian]),
+    (0x10C7, 0x10C7, &[Script::Georgian]),
+    (0x10CD, 0x10CD, &[Script::Georgian]),
+    (0x10D0, 0x10FA, &[Script::Georgian]),
+    (
+        0x10FB,
+        0x10FB,
+        &[Script::Georgian, Script::Glagolitic, Script::Latin],
+    ),
+    (0x10FC, 0x10FF, &[Script::Georgian]),
+    (0x1100, 0x11FF, &[Script::Hangul]),
+    (0x1200, 0x1248, &[Script::Ethiopic]),
+    (0x124A, 0x124D, &[Script::Ethiopic]),
+    (0x1250, 0x1256, &[Script::Ethiopic]),
+    (0x1258, 0x1258, &[Script::Ethiopic]),
+    (0x125A, 0x125D, &[Script::Ethiopic]),
+    (0x1260, 0x1288, &[Script::Ethiopic]),
+    (0x128A, 0x128D, &[Script::Ethiopic]),
+    (0x1290, 0x12B0, &[Script::Ethiopic]),
+    (0x12B2, 0x12B5, &[Script::Ethiopic]),
+    (0x12B8, 0x12BE, &[Script::Ethiopic]),
+    (0x12C0, 0x12C0, &[Script::Ethiopic]),
+    (0x12C2, 0x12C5, &[Script::Ethiopic]),
+    (0x12C8, 0x12D6, &[Script::Ethiopic]),
+    (0x12D8, 0x1310, &[Script::Ethiopic]),
+    (0x1312, 0x1315, &[Script::Ethiopic]),
+    (0x1318, 0x135A, &[Script::Ethiopic]),
+    (0x135D, 0x137C, &[Script::Ethiopic]),
+    (0x1380, 0x1399, &[Script::Ethiopic]),
+    (0x13A0, 0x13F5, &[Script::Cherokee]),
+    (0x13F8, 0x13FD, &[Script::Cherokee]),
+    (0x1400, 0x167F, &[Script::CanadianAboriginal]),
+    (0x1680, 0x169C, &[Script::Ogham]),
+    (0x16A0, 0x16F8, &[Script::Runic]),
+    (0x1700, 0x1715, &[Script::Tagalog]),
+    (0x171F, 0x171F, &[Script::Tagalog]),
+    (0x1720, 0x1734, &[Script::Hanunoo]),
+    (
+        0x1735,
+        0x1736,
+        &[
+            Script::Buhid,
+            Script::Hanunoo,
+            Script::Tagalog,
+            Script::Tagbanwa,
+        ],
+    ),
+    (0x1740, 0x1753, &[Script::Buhid]),
+    (0x1760, 0x176C, &[Script::Tagbanwa]),
+    (0x176E, 0x1770, &[Script::Tagbanwa]),
+    (0x1772, 0x1773, &[Script::Tagbanwa]),
+    (0x1780, 0x17DD, &[Script::Khmer]),
+    (0x17E0, 0x17E9, &[Script::Khmer]),
+    (0x17F0, 0x17F9, &[Script::Khmer]),
+    (0x1800, 0x1801, &[Script::Mongolian]),
+    (0x1802, 0x1803, &[Script::Mongolian, Script::PhagsPa]),
+    (0x1804, 0x1804, &[Script::Mongolian]),
+    (0x1805, 0x1805, &[Script::Mongolian, Script::PhagsPa]),
+    (0x1806, 0x1819, &[Script::Mongolian]),
+    (0x1820, 0x1878, &[Script::Mongolian]),
+    (0x1880, 0x18AA, &[Script::Mongolian]),
+    (0x18B0, 0x18F5, &[Script::CanadianAboriginal]),
+    (0x1900, 0x191E, &[Script::Limbu]),
+    (0x1920, 0x192B, &[Script::Limbu]),
+    (0x1930, 0x193B, &[Script::Limbu]),
+    (0x1940, 0x1940, &[Script::Limbu]),
+    (0x1944, 0x194F, &[Script::Limbu]),
+    (0x1950, 0x196D, &[Script::TaiLe]),
+    (0x1970, 0x1974, &[Script::TaiLe]),
+    (0x1980, 0x19AB, &[Script::NewTaiLue]),
+    (0x19B0, 0x19C9, &[Script::NewTaiLue]),
+    (0x19D0, 0x19DA, &[Script::NewTaiLue]),
+    (0x19DE, 0x19DF, &[Script::NewTaiLue]),
+    (0x19E0, 0x19FF, &[Script::Khmer]),
+    (0x1A00, 0x1A1B, &[Script::Buginese]),
+    (0x1A1E, 0x1A1F, &[Script::Buginese]),
+    (0x1A20, 0x1A5E, &[Script::TaiTham]),
+    (0x1A60, 0x1A7C, &[Script::TaiTham]),
+    (0x1A7F, 0x1A89, &[Script::TaiTham]),
+    (0x1A90, 0x1A99, &[Script::TaiTham]),
+    (0x1AA0, 0x1AAD, &[Script::TaiTham]),
+    (0x1B00, 0x1B4C, &[Script::Balinese]),
+    (0x1B4E, 0x1B7F, &[Script::Balinese]),
+    (0x1B80, 0x1BBF, &[Script::Sundanese]),
+    (0x1BC0, 0x1BF3, &[Script::Batak]),
+    (0x1BFC, 0x1BFF, &[Script::Batak]),
+    (0x1C00, 0x1C37, &[Script::Lepcha]),
+    (0x1C3B, 0x1C49, &[Script::Lepcha]),
+    (0x1C4D, 0x1C4F, &[Script::Lepcha]),
+    (0x1C50, 0x1C7F, &[Script::OlChiki]),
+    (0x1C80, 0x1C8A, &[Script::Cyrillic]),
+    (0x1C90, 0x1CBA, &[Script::Georgian]),
+    (0x1CBD, 0x1CBF, &[Script::Georgian]),
+    (0x1CC0, 0x1CC7, &[Script::Sundanese]),
+    (
+        0x1CD0,
+        0x1CD0,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Grantha,
+            Script::Kannada,
+        ],
+    ),
+    (0x1CD1, 0x1CD1, &[Script::Devanagari]),
+    (
+        0x1CD2,
+        0x1CD2,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Grantha,
+            Script::Kannada,
+        ],
+    ),
+    (
+        0x1CD3,
+        0x1CD3,
+        &[Script::Devanagari, Script::Grantha, Script::Kannada],
+    ),
+    (0x1CD4, 0x1CD4, &[Script::Devanagari]),
+    (0x1CD5, 0x1CD6, &[Script::Bengali, Script::Devanagari]),
+    (0x1CD7, 0x1CD7, &[Script::Devanagari, Script::Sharada]),
+    (0x1CD8, 0x1CD8, &[Script::Bengali, Script::Devanagari]),
+    (0x1CD9, 0x1CD9, &[Script::Devanagari, Script::Sharada]),
+    (
+        0x1CDA,
+        0x1CDA,
+        &[
+            Script::Devanagari,
+            Script::Kannada,
+            Script::Malayalam,
+            Script::Oriya,
+            Script::Tamil,
+            Script::Telugu,
+        ],
+    ),
+    (0x1CDB, 0x1CDB, &[Script::Devanagari]),
+    (0x1CDC, 0x1CDD, &[Script::Devanagari, Script::Sharada]),
+    (0x1CDE, 0x1CDF, &[Script::Devanagari]),
+    (0x1CE0, 0x1CE0, &[Script::Devanagari, Script::Sharada]),
+    (0x1CE1, 0x1CE1, &[Script::Bengali, Script::Devanagari]),
+    (0x1CE2, 0x1CE8, &[Script::Devanagari]),
+    (0x1CE9, 0x1CE9, &[Script::Devanagari, Script::Nandinagari]),
+    (0x1CEA, 0x1CEA, &[Script::Bengali, Script::Devanagari]),
+    (0x1CEB, 0x1CEC, &[Script::Devanagari]),
+    (0x1CED, 0x1CED, &[Script::Bengali, Script::Devanagari]),
+    (0x1CEE, 0x1CF1, &[Script::Devanagari]),
+    (
+        0x1CF2,
+        0x1CF2,
+        &[
+            Script::Bengali,
+            Script::Devanagari,
+            Script::Grantha,
+            Script::Kannada,
+            Script::Malayalam,
+            Script::Nandinagari,
+            Script::Oriya,
+            Script::Sinhala,
+            Script::Telugu,
+            Script::Tirhuta,
+            Script::TuluTigalari,
+        ],
+    ),
+    (0x1CF3, 0x1CF3, &[Script::Devanagari, Script::Grantha]),
+    (
+        0x1CF4,
+        0x1CF4,
+        &[
+            Script::Devanagari,
+            Script::Grantha,
+            Script::Kannada,
+            Script::TuluTigalari,
+        ],
+    ),
+    (0x1CF5, 0x1CF6, &[Script::Bengali, Script::Devanagari]),
+    (0x1CF7, 0x1CF7, &[Script::Bengali]),
+    (0x1CF8, 0x1CF9, &[Script::Devanagari, Script::Grantha]),
+    (0x1CFA, 0x1CFA, &[Script::Nandinagari]),
+    (0x1D00, 0x1D25, &[Script::Latin]),
+    (0x1D26, 0x1D2A, &[Script::Greek]),
+    (0x1D2B, 0x1D2B, &[Script::Cyrillic]),
+    (0x1D2C, 0x1D5C, &[Script::Latin]),
+    (0x1D5D, 0x1D61, &[Script::Greek]),
+    (0x1D62, 0x1D65, &[Script::Latin]),
+    (0x1D66, 0x1D6A, &[Script::Greek]),
+    (0x1D6B, 0x1D77, &[Script::Latin]),
+    (0x1D78, 0x1D78, &[Script::Cyrillic]),
+    (0x1D79, 0x1DBE, &[Script::Latin]),
+    (0x1DBF, 0x1DC1, &[Script::Greek]),
+    (
+        0x1DF8,
+        0x1DF8,
+        &[Script::Cyrillic, Script::Latin, Script::Syriac],
+    ),
+    (0x1DFA, 0x1DFA, &[Script::Syriac]),
+    (0x1E00, 0x1EFF, &[Script::Latin]),
+    (0x1F00, 0x1F15, &[Script::Greek]),
+    (0x1F18, 0x1F1D, &[Script::Greek]),
+    (0x1F20, 0x1F45, &[Script::Greek]),
+    (0x1F48, 0x1F4D, &[Script::Greek]),
+    (0x1F50, 0x1F57, &[Script::Greek]),
+    (0x1F59, 0x1F59, &[Script::Greek]),
+    (0x1F5B, 0x1F5B, &[Script::Greek]),
+    (0x1F5D, 0x1F5D, &[Script::Greek]),
+    (0x1F5F, 0x1F7D, &[Script::Greek]),
+    (0x1F80, 0x1FB4, &[Script::Greek]),
+    (0x1FB6, 0x1FC4, &[Script::Greek]),
+    (0x1FC6, 0x1FD3, &[Script::Greek]),
+    (0x1FD6, 0x1FDB, &[Script::Greek]),
+    (0x1FDD, 0x1FEF, &[Script::Greek]),
+    (0x1FF2, 0x1FF4, &[Script::Greek]),
+    (0x1FF6, 0x1FFE, &[Script::Greek]),
+    (
+        0x202F,
+        0x202F,
+        &[Script::Latin, Script::Mongolian, Script::PhagsPa],
+    ),
+    (0x204F, 0x204F, &[Script::Adlam, Script::Arabic]),
+    (
+        0x205A,
+        0x205A,
+        &[
+            Script::Carian,
+            Script::Georgian,
+            Script::Glagolitic,
+            Script::Lycian,
+            Script::OldHungarian,
+            Script::OldTurkic,
+        ],
+    ),
+    (
+        0x205D,
+        0x205D,
+        &[
+            Script::Carian,
+            Script::Greek,
+            Script::MeroiticHieroglyphs,
+            Script::OldHungarian,
+        ],
+    ),
+    (0x2071, 0x2071, &[Script::Latin]),
+    (0x207F, 0x207F, &[Script::Latin]),
+    (0x2090, 0x209C, &[Script::Latin]),
+    (
+        0x20F0,
+        0x20F0,
+        &[Script::Devanagari, Script::Grantha, Script::Latin],
+    ),
+    (0x2126, 0x2126, &[Script::Greek]),
+    (0x212A, 0x212B, &[Script::Latin]),
+    (0x2132, 0x2132, &[Script::Latin]),
+    (0x214E, 0x214E, &[Script::Latin]),
+    (0x2160, 0x2188, &[Script::Latin]),
+    (0x2800, 0x28FF, &[Script::Braille]),
+    (0x2C00, 0x2C5F, &[Script::Glagolitic]),
+    (0x2C60, 0x2C7F, &[Script::Latin]),
+    (0x2C80, 0x2CF3, &[Script::Coptic]),
+    (0x2CF9, 0x2CFF, &[Script::Coptic]),
+    (0x2D00, 0x2D25, &[Script::Georgian]),
+    (0x2D27, 0x2D27, &[Script::Georgian]),
+    (0x2D2D, 0x2D2D, &[Script::Georgian]),
+    (0x2D30, 0x2D67, &[Script::Tifinagh]),
+    (0x2D6F, 0x2D70, &[Script::Tifinagh]),
+    (0x2D7F, 0x2D7F, &[Script::Tifinagh]),
+    (0x2D80, 0x2D96, &[Script::Ethiopic]),
+    (0x2DA0, 0x2DA6, &[Script::Ethiopic]),
+    (0x2DA8, 0x2DAE, &[Script::Ethiopic]),
+    (0x2DB0, 0x2DB6, &[Script::Ethiopic]),
+    (0x2DB8, 0x2DBE, &[Script::Ethiopic]),
+    (0x2DC0, 0x2DC6, &[Script::Ethiopic]),
+    (0x2DC8, 0x2DCE, &[Script::Ethiopic]),
+    (0x2DD0, 0x2DD6, &[Script::Ethiopic]),
+    (0x2DD8, 0x2DDE, &[Script::Ethiopic]),
+    (0x2DE0, 0x2DFF, &[Script::Cyrillic]),
+    (0x2E17, 0x2E17, &[Script::Coptic, Script::Latin]),
+    (0x2E30, 0x2E30, &[Script::Avestan, Script::OldTurkic]),
+    (
+        0x2E31,
+        0x2E31,
+        &[
+            Script::Avestan,
+            Script::Carian,
+            Script::Georgian,
+            Script::Kaithi,
+            Script::Lydian,
+            Script::OldHungarian,
+            Script::Samaritan,
+        ],
+    ),
+    (0x2E3C, 0x2E3C, &[Script::Duployan]),
+    (
+        0x2E41,
+        0x2E41,
+        &[Script::Adlam, Script::Arabic, Script::OldHungarian],
+    ),
+    (0x2E43, 0x2E43, &[Script::Cyrillic, Script::Glagolitic]),
+    (0x2E80, 0x2E99, &[Script::Han]),
+    (0x2E9B, 0x2EF3, &[Script::Han]),
+    (0x2F00, 0x2FD5, &[Script::Han]),
+    (0x2FF0, 0x2FFF, &[Script::Han, Script::Tangut]),
+    (
+        0x3001,
+        0x3001,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Mongolian,
+            Script::Yi,
+        ],
+    ),
+    (
+        0x3002,
+        0x3002,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Mongolian,
+            Script::PhagsPa,
+            Script::Yi,
+        ],
+    ),
+    (
+        0x3003,
+        0x3003,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+    ),
+    (0x3005, 0x3007, &[Script::Han]),
+    (
+        0x3008,
+        0x3009,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Mongolian,
+            Script::Tibetan,
+            Script::Yi,
+        ],
+    ),
+    (
+        0x300A,
+        0x300B,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Lisu,
+            Script::Mongolian,
+            Script::Tibetan,
+            Script::Yi,
+        ],
+    ),
+    (
+        0x300C,
+        0x3011,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Yi,
+        ],
+    ),
+    (
+        0x3013,
+        0x3013,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+    ),
+    (
+        0x3014,
+        0x301B,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Yi,
+        ],
+    ),
+    (
+        0x301C,
+        0x301F,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+    ),
+    (0x3021, 0x3029, &[Script::Han]),
+    (0x302A, 0x302D, &[Script::Bopomofo, Script::Han]),
+    (0x302E, 0x302F, &[Script::Hangul]),
+    (
+        0x3030,
+        0x3030,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+    ),
+    (0x3031, 0x3035, &[Script::Hiragana, Script::Katakana]),
+    (
+        0x3037,
+        0x3037,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+    ),
+    (0x3038, 0x303B, &[Script::Han]),
+    (
+        0x303C,
+        0x303D,
+        &[Script::Han, Script::Hiragana, Script::Katakana],
+    ),
+    (0x303E, 0x303F, &[Script::Han]),
+    (0x3041, 0x3096, &[Script::Hiragana]),
+    (0x3099, 0x309C, &[Script::Hiragana, Script::Katakana]),
+    (0x309D, 0x309F, &[Script::Hiragana]),
+    (0x30A0, 0x30A0, &[Script::Hiragana, Script::Katakana]),
+    (0x30A1, 0x30FA, &[Script::Katakana]),
+    (
+        0x30FB,
+        0x30FB,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Yi,
+        ],
+    ),
+    (0x30FC, 0x30FC, &[Script::Hiragana, Script::Katakana]),
+    (0x30FD, 0x30FF, &[Script::Katakana]),
+    (0x3105, 0x312F, &[Script::Bopomofo]),
+    (0x3131, 0x318E, &[Script::Hangul]),
+    (0x3190, 0x319F, &[Script::Han]),
+    (0x31A0, 0x31BF, &[Script::Bopomofo]),
+    (0x31C0, 0x31E5, &[Script::Han]),
+    (0x31EF, 0x31EF, &[Script::Han, Script::Tangut]),
+    (0x31F0, 0x31FF, &[Script::Katakana]),
+    (0x3200, 0x321E, &[Script::Hangul]),
+    (0x3220, 0x3247, &[Script::Han]),
+    (0x3260, 0x327E, &[Script::Hangul]),
+    (0x3280, 0x32B0, &[Script::Han]),
+    (0x32C0, 0x32CB, &[Script::Han]),
+    (0x32D0, 0x32FE, &[Script::Katakana]),
+    (0x32FF, 0x32FF, &[Script::Han]),
+    (0x3300, 0x3357, &[Script::Katakana]),
+    (0x3358, 0x3370, &[Script::Han]),
+    (0x337B, 0x337F, &[Script::Han]),
+    (0x33E0, 0x33FE, &[Script::Han]),
+    (0x3400, 0x4DBF, &[Script::Han]),
+    (0x4E00, 0x9FFF, &[Script::Han]),
+    (0xA000, 0xA48C, &[Script::Yi]),
+    (0xA490, 0xA4C6, &[Script::Yi]),
+    (0xA4D0, 0xA4FF, &[Script::Lisu]),
+    (0xA500, 0xA62B, &[Script::Vai]),
+    (0xA640, 0xA66E, &[Script::Cyrillic]),
+    (0xA66F, 0xA66F, &[Script::Cyrillic, Script::Glagolitic]),
+    (0xA670, 0xA69F, &[Script::Cyrillic]),
+    (0xA6A0, 0xA6F7, &[Script::Bamum]),
+    (0xA700, 0xA707, &[Script::Han, Script::Latin]),
+    (0xA722, 0xA787, &[Script::Latin]),
+    (0xA78B, 0xA7CD, &[Script::Latin]),
+    (0xA7D0, 0xA7D1, &[Script::Latin]),
+    (0xA7D3, 0xA7D3, &[Script::Latin]),
+    (0xA7D5, 0xA7DC, &[Script::Latin]),
+    (0xA7F2, 0xA7FF, &[Script::Latin]),
+    (0xA800, 0xA82C, &[Script::SylotiNagri]),
+    (
+        0xA830,
+        0xA832,
+        &[
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kaithi,
+            Script::Kannada,
+            Script::Khojki,
+            Script::Khudawadi,
+            Script::Mahajani,
+            Script::Malayalam,
+            Script::Modi,
+            Script::Nandinagari,
+            Script::Sharada,
+            Script::Takri,
+            Script::Tirhuta,
+            Script::TuluTigalari,
+        ],
+    ),
+    (
+        0xA833,
+        0xA835,
+        &[
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kaithi,
+            Script::Kannada,
+            Script::Khojki,
+            Script::Khudawadi,
+            Script::Mahajani,
+            Script::Modi,
+            Script::Nandinagari,
+            Script::Sharada,
+            Script::Takri,
+            Script::Tirhuta,
+            Script::TuluTigalari,
+        ],
+    ),
+    (
+        0xA836,
+        0xA837,
+        &[
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kaithi,
+            Script::Khojki,
+            Script::Khudawadi,
+            Script::Mahajani,
+            Script::Modi,
+            Script::Takri,
+            Script::Tirhuta,
+        ],
+    ),
+    (
+        0xA838,
+        0xA838,
+        &[
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kaithi,
+            Script::Khojki,
+            Script::Khudawadi,
+            Script::Mahajani,
+            Script::Modi,
+            Script::Sharada,
+            Script::Takri,
+            Script::Tirhuta,
+        ],
+    ),
+    (
+        0xA839,
+        0xA839,
+        &[
+            Script::Devanagari,
+            Script::Dogra,
+            Script::Gujarati,
+            Script::Gurmukhi,
+            Script::Kaithi,
+            Script::Khojki,
+            Script::Khudawadi,
+            Script::Mahajani,
+            Script::Modi,
+            Script::Takri,
+            Script::Tirhuta,
+        ],
+    ),
+    (0xA840, 0xA877, &[Script::PhagsPa]),
+    (0xA880, 0xA8C5, &[Script::Saurashtra]),
+    (0xA8CE, 0xA8D9, &[Script::Saurashtra]),
+    (0xA8E0, 0xA8F0, &[Script::Devanagari]),
+    (
+        0xA8F1,
+        0xA8F1,
+        &[Script::Bengali, Script::Devanagari, Script::TuluTigalari],
+    ),
+    (0xA8F2, 0xA8F2, &[Script::Devanagari]),
+    (0xA8F3, 0xA8F3, &[Script::Devanagari, Script::Tamil]),
+    (0xA8F4, 0xA8FF, &[Script::Devanagari]),
+    (0xA900, 0xA92D, &[Script::KayahLi]),
+    (
+        0xA92E,
+        0xA92E,
+        &[Script::KayahLi, Script::Latin, Script::Myanmar],
+    ),
+    (0xA92F, 0xA92F, &[Script::KayahLi]),
+    (0xA930, 0xA953, &[Script::Rejang]),
+    (0xA95F, 0xA95F, &[Script::Rejang]),
+    (0xA960, 0xA97C, &[Script::Hangul]),
+    (0xA980, 0xA9CD, &[Script::Javanese]),
+    (0xA9CF, 0xA9CF, &[Script::Buginese, Script::Javanese]),
+    (0xA9D0, 0xA9D9, &[Script::Javanese]),
+    (0xA9DE, 0xA9DF, &[Script::Javanese]),
+    (0xA9E0, 0xA9FE, &[Script::Myanmar]),
+    (0xAA00, 0xAA36, &[Script::Cham]),
+    (0xAA40, 0xAA4D, &[Script::Cham]),
+    (0xAA50, 0xAA59, &[Script::Cham]),
+    (0xAA5C, 0xAA5F, &[Script::Cham]),
+    (0xAA60, 0xAA7F, &[Script::Myanmar]),
+    (0xAA80, 0xAAC2, &[Script::TaiViet]),
+    (0xAADB, 0xAADF, &[Script::TaiViet]),
+    (0xAAE0, 0xAAF6, &[Script::MeeteiMayek]),
+    (0xAB01, 0xAB06, &[Script::Ethiopic]),
+    (0xAB09, 0xAB0E, &[Script::Ethiopic]),
+    (0xAB11, 0xAB16, &[Script::Ethiopic]),
+    (0xAB20, 0xAB26, &[Script::Ethiopic]),
+    (0xAB28, 0xAB2E, &[Script::Ethiopic]),
+    (0xAB30, 0xAB5A, &[Script::Latin]),
+    (0xAB5C, 0xAB64, &[Script::Latin]),
+    (0xAB65, 0xAB65, &[Script::Greek]),
+    (0xAB66, 0xAB69, &[Script::Latin]),
+    (0xAB70, 0xABBF, &[Script::Cherokee]),
+    (0xABC0, 0xABED, &[Script::MeeteiMayek]),
+    (0xABF0, 0xABF9, &[Script::MeeteiMayek]),
+    (0xAC00, 0xD7A3, &[Script::Hangul]),
+    (0xD7B0, 0xD7C6, &[Script::Hangul]),
+    (0xD7CB, 0xD7FB, &[Script::Hangul]),
+    (0xF900, 0xFA6D, &[Script::Han]),
+    (0xFA70, 0xFAD9, &[Script::Han]),
+    (0xFB00, 0xFB06, &[Script::Latin]),
+    (0xFB13, 0xFB17, &[Script::Armenian]),
+    (0xFB1D, 0xFB36, &[Script::Hebrew]),
+    (0xFB38, 0xFB3C, &[Script::Hebrew]),
+    (0xFB3E, 0xFB3E, &[Script::Hebrew]),
+    (0xFB40, 0xFB41, &[Script::Hebrew]),
+    (0xFB43, 0xFB44, &[Script::Hebrew]),
+    (0xFB46, 0xFB4F, &[Script::Hebrew]),
+    (0xFB50, 0xFBC2, &[Script::Arabic]),
+    (0xFBD3, 0xFD3D, &[Script::Arabic]),
+    (0xFD3E, 0xFD3F, &[Script::Arabic, Script::Nko]),
+    (0xFD40, 0xFD8F, &[Script::Arabic]),
+    (0xFD92, 0xFDC7, &[Script::Arabic]),
+    (0xFDCF, 0xFDCF, &[Script::Arabic]),
+    (0xFDF0, 0xFDF1, &[Script::Arabic]),
+    (0xFDF2, 0xFDF2, &[Script::Arabic, Script::Thaana]),
+    (0xFDF3, 0xFDFC, &[Script::Arabic]),
+    (0xFDFD, 0xFDFD, &[Script::Arabic, Script::Thaana]),
+    (0xFDFE, 0xFDFF, &[Script::Arabic]),
+    (0xFE2E, 0xFE2F, &[Script::Cyrillic]),
+    (
+        0xFE45,
+        0xFE46,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+        ],
+    ),
+    (0xFE70, 0xFE74, &[Script::Arabic]),
+    (0xFE76, 0xFEFC, &[Script::Arabic]),
+    (0xFF21, 0xFF3A, &[Script::Latin]),
+    (0xFF41, 0xFF5A, &[Script::Latin]),
+    (
+        0xFF61,
+        0xFF65,
+        &[
+            Script::Bopomofo,
+            Script::Han,
+            Script::Hangul,
+            Script::Hiragana,
+            Script::Katakana,
+            Script::Yi,
+        ],
+    ),
+    (0xFF66, 0xFF6F, &[Script::Katakana]),
+    (0xFF70, 0xFF70, &[Script::Hiragana, Script::Katakana]),
+    (0xFF71, 0xFF9D, &[Script::Katakana]),
+    (0xFF9E, 0xFF9F, &[Script::Hiragana, Script::Katakana]),
+    (0xFFA0, 0xFFBE, &[Script::Hangul]),
+    (0xFFC2, 0xFFC7, &[Script::Hangul]),
+    (0xFFCA, 0xFFCF, &[Script::Hangul]),
+    (0xFFD2, 0xFFD7, &[Script::Hangul]),
+    (0xFFDA, 0xFFDC, &[Script::Hangul]),
+    (0x10000, 0x1000B, &[Script::LinearB]),
+    (0x1000D, 0x10026, &[Script::LinearB]),
+    (0x10028, 0x1003A, &[Script::LinearB]),
+    (0x1003C, 0x1003D, &[Script::LinearB]),
+    (0x1003F, 0x1004D, &[Script::LinearB]),
+    (0x10050, 0x1005D, &[Script::LinearB]),
+    (0x10080, 0x100FA, &[Script::LinearB]),
+    (
+        0x10100,
+        0x10101,
+        &[Script::Cypriot, Script::CyproMinoan, Script::LinearB],
+    ),
+    (0x10102, 0x10102, &[Script::Cypriot, Script::LinearB]),
+    (
+        0x10107,
+        0x10133,
+        &[Script::Cypriot, Script::LinearA, Script::LinearB],
+    ),
+    (0x10137, 0x1013F, &[Script::Cypriot, Script::LinearB]),
+    (0x10140, 0x1018E, &[Script::Greek]),
+    (0x101A0, 0x101A0, &[Script::Greek]),
+    (0x10280, 0x1029C, &[Script::Lycian]),
+    (0x102A0, 0x102D0, &[Script::Carian]),
+    (0x102E0, 0x102FB, &[Script::Arabic, Script::Coptic]),
+    (0x10300, 0x10323, &[Script::OldItalic]),
+    (0x1032D, 0x1032F, &[Script::OldItalic]),
+    (0x10330, 0x1034A, &[Script::Gothic]),
+    (0x10350, 0x1037A, &[Script::OldPermic]),
+    (0x10380, 0x1039D, &[Script::Ugaritic]),
+    (0x1039F, 0x1039F, &[Script::Ugaritic]),
+    (0x103A0, 0x103C3, &[Script::OldPersian]),
+    (0x103C8, 0x103D5, &[Script::OldPersian]),
+    (0x10400, 0x1044F, &[Script::Deseret]),
+    (0x10450, 0x1047F, &[Script::Shavian]),
+    (0x10480, 0x1049D, &[Script::Osmanya]),
+    (0x104A0, 0x104A9, &[Script::Osmanya]),
+    (0x104B0, 0x104D3, &[Script::Osage]),
+    (0x104D8, 0x104FB, &[Script::Osage]),
+    (0x10500, 0x10527, &[Script::Elbasan]),
+    (0x10530, 0x10563, &[Script::CaucasianAlbanian]),
+    (0x1056F, 0x1056F, &[Script::CaucasianAlbanian]),
+    (0x10570, 0x1057A, &[Script::Vithkuqi]),
+    (0x1057C, 0x1058A, &[Script::Vithkuqi]),
+    (0x1058C, 0x10592, &[Script::Vithkuqi]),
+    (0x10594, 0x10595, &[Script::Vithkuqi]),
+    (0x10597, 0x105A1, &[Script::Vithkuqi]),
+    (0x105A3, 0x105B1, &[Script::Vithkuqi]),
+    (0x105B3, 0x105B9, &[Script::Vithkuqi]),
+    (0x105BB, 0x105BC, &[Script::Vithkuqi]),
+    (0x105C0, 0x105F3, &[Script::Todhri]),
+    (0x10600, 0x10736, &[Script::LinearA]),
+    (0x10740, 0x10755, &[Script::LinearA]),
+    (0x10760, 0x10767, &[Script::LinearA]),
+    (0x10780, 0x10785, &[Script::Latin]),
+    (0x10787, 0x107B0, &[Script::Latin]),
+    (0x107B2, 0x107BA, &[Script::Latin]),
+    (0x10800, 0x10805, &[Script::Cypriot]),
+    (0x10808, 0x10808, &[Script::Cypriot]),
+    (0x1080A, 0x10835, &[Script::Cypriot]),
+    (0x10837, 0x10838, &[Script::Cypriot]),
+    (0x1083C, 0x1083C, &[Script::Cypriot]),
+    (0x1083F, 0x1083F, &[Script::Cypriot]),
+    (0x10840, 0x10855, &[Script::ImperialAramaic]),
+    (0x10857, 0x1085F, &[Script::ImperialAramaic]),
+    (0x10860, 0x1087F, &[Script::Palmyrene]),
+    (0x10880, 0x1089E, &[Script::Nabataean]),
+    (0x108A7, 0x108AF, &[Script::Nabataean]),
+    (0x108E0, 0x108F2, &[Script::Hatran]),
+    (0x108F4, 0x108F5, &[Script::Hatran]),
+    (0x108FB, 0x108FF, &[Script::Hatran]),
+    (0x10900, 0x1091B, &[Script::Phoenician]),
+    (0x1091F, 0x1091F, &[Script::Phoenician]),
+    (0x10920, 0x10939, &[Script::Lydian]),
+    (0x1093F, 0x1093F, &[Script::Lydian]),
+    (0x10980, 0x1099F, &[Script::MeroiticHieroglyphs]),
+    (0x109A0, 0x109B7, &[Script::MeroiticCursive]),
+    (0x109BC, 0x109CF, &[Script::MeroiticCursive]),
+    (0x109D2, 0x109FF, &[Script::MeroiticCursive]),
+    (0x10A00, 0x10A03, &[Script::Kharoshthi]),
+    (0x10A05, 0x10A06, &[Script::Kharoshthi]),
+    (0x10A0C, 0x10A13, &[Script::Kharoshthi]),
+    (0x10A15, 0x10A17, &[Script::Kharoshthi]),
+    (0x10A19, 0x10A35, &[Script::Kharoshthi]),
+    (0x10A38, 0x10A3A, &[Script::Kharoshthi]),
+    (0x10A3F, 0x10A48, &[Script::Kharoshthi]),
+    (0x10A50, 0x10A58, &[Script::Kharoshthi]),
+    (0x10A60, 0x10A7F, &[Script::OldSouthArabian]),
+    (0x10A80, 0x10A9F, &[Script::OldNorthArabian]),
+    (0x10AC0, 0x10AE6, &[Script::Manichaean]),
+    (0x10AEB, 0x10AF1, &[Script::Manichaean]),
+    (0x10AF2, 0x10AF2, &[Script::Manichaean, Script::OldUyghur]),
+    (0x10AF3, 0x10AF6, &[Script::Manichaean]),
+    (0x10B00, 0x10B35, &[Script::Avestan]),
+    (0x10B39, 0x10B3F, &[Script::Avestan]),
+    (0x10B40, 0x10B55, &[Script::InscriptionalParthian]),
+    (0x10B58, 0x10B5F, &[Script::InscriptionalParthian]),
+    (0x10B60, 0x10B72, &[Script::InscriptionalPahlavi]),
+    (0x10B78, 0x10B7F, &[Script::InscriptionalPahlavi]),
+    (0x10B80, 0x10B91, &[Script::PsalterPahlavi]),
+    (0x10B99, 0x10B9C, &[Script::PsalterPahlavi]),
+    (0x10BA9, 0x10BAF, &[Script::PsalterPahlavi]),
+    (0x10C00, 0x10C48, &[Script::OldTurkic]),
+    (0x10C80, 0x10CB2, &[Script::OldHungarian]),
+    (0x10CC0, 0x10CF2, &[Script::OldHungarian]),
+    (0x10CFA, 0x10CFF, &[Script::OldHungarian]),
+    (0x10D00, 0x10D27, &[Script::HanifiRohingya]),
+    (0x10D30, 0x10D39, &[Script::HanifiRohingya]),
+    (0x10D40, 0x10D65, &[Script::Garay]),
+    (0x10D69, 0x10D85, &[Script::Garay]),
+    (0x10D8E, 0x10D8F, &[Script::Garay]),
+    (0x10E60, 0x10E7E, &[Script::Arabic]),
+    (0x10E80, 0x10EA9, &[Script::Yezidi]),
+    (0x10EAB, 0x10EAD, &[Script::Yezidi]),
+    (0x10EB0, 0x10EB1, &[Script::Yezidi]),
+    (0x10EC2, 0x10EC4, &[Script::Arabic]),
+    (0x10EFC, 0x10EFF, &[Script::Arabic]),
+    (0x10F00, 0x10F27, &[Script::OldSogdian]),
+    (0x10F30, 0x10F59, &[Script::Sogdian]),
+    (0x10F70, 0x10F89, &[Script::OldUyghur]),
+    (0x10FB0, 0x10FCB, &[Script::Chorasmian]),
+    (0x10FE0, 0x10FF6, &[Script::Elymaic]),
+    (0x11000, 0x1104D, &[Script::Brahmi]),
+    (0x11052, 0x11075, &[Script::Brahmi]),
+    (0x1107F, 0x1107F, &[Script::Brahmi]),
+    (0x11080, 0x110C2, &[Script::Kaithi]),
+    (0x110CD, 0x110CD, &[Script::Kaithi]),
+    (0x110D0, 0x110E8, &[Script::SoraSompeng]),
+    (0x110F0, 0x110F9, &[Script::SoraSompeng]),
+    (0x11100, 0x11134, &[Script::Chakma]),
+    (0x11136, 0x11147, &[Script::Chakma]),
+    (0x11150, 0x11176, &[Script::Mahajani]),
+    (0x11180, 0x111DF, &[Script::Sharada]),
+    (0x111E1, 0x111F4, &[Script::Sinhala]),
+    (0x11200, 0x11211, &[Script::Khojki]),
+    (0x11213, 0x11241, &[Script::Khojki]),
+    (0x11280, 0x11286, &[Script::Multani]),
+    (0x11288, 0x11288, &[Script::Multani]),
+    (0x1128A, 0x1128D, &[Script::Multani]),
+    (0x1128F, 0x1129D, &[Script::Multani]),
+    (0x1129F, 0x112A9, &[Script::Multani]),
+    (0x112B0, 0x112EA, &[Script::Khudawadi]),
+    (0x112F0, 0x112F9, &[Script::Khudawadi]),
+    (0x11300, 0x11300, &[Script::Grantha]),
+    (0x11301, 0x11301, &[Script::Grantha, Script::Tamil]),
+    (0x11302, 0x11302, &[Script::Grantha]),
+    (0x11303, 0x11303, &[Script::Grantha, Script::Tamil]),
+    (0x11305, 0x1130C, &[Script::Grantha]),
+    (0x1130F, 0x11310, &[Script::Grantha]),
+    (0x11313, 0x11328, &[Script::Grantha]),
+    (0x1132A, 0x11330, &[Script::Grantha]),
+    (0x11332, 0x11333, &[Script::Grantha]),
+    (0x11335, 0x11339, &[Script::Grantha]),
+    (0x1133B, 0x1133C, &[Script::Grantha, Script::Tamil]),
+    (0x1133D, 0x11344, &[Script::Grantha]),
+    (0x11347, 0x11348, &[Script::Grantha]),
+    (0x1134B, 0x1134D, &[Script::Grantha]),
+    (0x11350, 0x11350, &[Script::Grantha]),
+    (0x11357, 0x11357, &[Script::Grantha]),
+    (0x1135D, 0x11363, &[Script::Grantha]),
+    (0x11366, 0x1136C, &[Script::Grantha]),
+    (0x11370, 0x11374, &[Script::Grantha]),
+    (0x11380, 0x11389, &[Script::TuluTigalari]),
+    (0x1138B, 0x1138B, &[Script::TuluTigalari]),
+    (0x1138E, 0x1138E, &[Script::TuluTigalari]),
+    (0x11390, 0x113B5, &[Script::TuluTigalari]),
+    (0x113B7, 0x113C0, &[Script::TuluTigalari]),
+    (0x113C2, 0x113C2, &[Script::TuluTigalari]),
+    (0x113C5, 0x113C5, &[Script::TuluTigalari]),
+    (0x113C7, 0x113CA, &[Script::TuluTigalari]),
+    (0x113CC, 0x113D5, &[Script::TuluTigalari]),
+    (0x113D7, 0x113D8, &[Script::TuluTigalari]),
+    (0x113E1, 0x113E2, &[Script::TuluTigalari]),
+    (0x11400, 0x1145B, &[Script::Newa]),
+    (0x1145D, 0x11461, &[Script::Newa]),
+    (0x11480, 0x114C7, &[Script::Tirhuta]),
+    (0x114D0, 0x114D9, &[Script::Tirhuta]),
+    (0x11580, 0x115B5, &[Script::Siddham]),
+    (0x115B8, 0x115DD, &[Script::Siddham]),
+    (0x11600, 0x11644, &[Script::Modi]),
+    (0x11650, 0x11659, &[Script::Modi]),
+    (0x11660, 0x1166C, &[Script::Mongolian]),
+    (0x11680, 0x116B9, &[Script::Takri]),
+    (0x116C0, 0x116C9, &[Script::Takri]),
+    (0x116D0, 0x116E3, &[Script::Myanmar]),
+    (0x11700, 0x1171A, &[Script::Ahom]),
+    (0x1171D, 0x1172B, &[Script::Ahom]),
+    (0x11730, 0x11746, &[Script::Ahom]),
+    (0x11800, 0x1183B, &[Script::Dogra]),
+    (0x118A0, 0x118F2, &[Script::WarangCiti]),
+    (0x118FF, 0x118FF, &[Script::WarangCiti]),
+    (0x11900, 0x11906, &[Script::DivesAkuru]),
+    (0x11909, 0x11909, &[Script::DivesAkuru]),
+    (0x1190C, 0x11913, &[Script::DivesAkuru]),
+    (0x11915, 0x11916, &[Script::DivesAkuru]),
+    (0x11918, 0x11935, &[Script::DivesAkuru]),
+    (0x11937, 0x11938, &[Script::DivesAkuru]),
+    (0x1193B, 0x11946, &[Script::DivesAkuru]),
+    (0x11950, 0x11959, &[Script::DivesAkuru]),
+    (0x119A0, 0x119A7, &[Script::Nandinagari]),
+    (0x119AA, 0x119D7, &[Script::Nandinagari]),
+    (0x119DA, 0x119E4, &[Script::Nandinagari]),
+    (0x11A00, 0x11A47, &[Script::ZanabazarSquare]),
+    (0x11A50, 0x11AA2, &[Script::Soyombo]),
+    (0x11AB0, 0x11ABF, &[Script::CanadianAboriginal]),
+    (0x11AC0, 0x11AF8, &[Script::PauCinHau]),
+    (0x11B00, 0x11B09, &[Script::Devanagari]),
+    (0x11BC0, 0x11BE1, &[Script::Sunuwar]),
+    (0x11BF0, 0x11BF9, &[Script::Sunuwar]),
+    (0x11C00, 0x11C08, &[Script::Bhaiksuki]),
+    (0x11C0A, 0x11C36, &[Script::Bhaiksuki]),
+    (0x11C38, 0x11C45, &[Script::Bhaiksuki]),
+    (0x11C50, 0x11C6C, &[Script::Bhaiksuki]),
+    (0x11C70, 0x11C8F, &[Script::Marchen]),
+    (0x11C92, 0x11CA7, &[Script::Marchen]),
+    (0x11CA9, 0x11CB6, &[Script::Marchen]),
+    (0x11D00, 0x11D06, &[Script::MasaramGondi]),
+    (0x11D08, 0x11D09, &[Script::MasaramGondi]),
+    (0x11D0B, 0x11D36, &[Script::MasaramGondi]),
+    (0x11D3A, 0x11D3A, &[Script::MasaramGondi]),
+    (0x11D3C, 0x11D3D, &[Script::MasaramGondi]),
+    (0x11D3F, 0x11D47, &[Script::MasaramGondi]),
+    (0x11D50, 0x11D59, &[Script::MasaramGondi]),
+    (0x11D60, 0x11D65, &[Script::GunjalaGondi]),
+    (0x11D67, 0x11D68, &[Script::GunjalaGondi]),
+    (0x11D6A, 0x11D8E, &[Script::GunjalaGondi]),
+    (0x11D90, 0x11D91, &[Script::GunjalaGondi]),
+    (0x11D93, 0x11D98, &[Script::GunjalaGondi]),
+    (0x11DA0, 0x11DA9, &[Script::GunjalaGondi]),
+    (0x11EE0, 0x11EF8, &[Script::Makasar]),
+    (0x11F00, 0x11F10, &[Script::Kawi]),
+    (0x11F12, 0x11F3A, &[Script::Kawi]),
+    (0x11F3E, 0x11F5A, &[Script::Kawi]),
+    (0x11FB0, 0x11FB0, &[Script::Lisu]),
+    (0x11FC0, 0x11FCF, &[Script::Tamil]),
+    (0x11FD0, 0x11FD1, &[Script::Grantha, Script::Tamil]),
+    (0x11FD2, 0x11FD2, &[Script::Tamil]),
+    (0x11FD3, 0x11FD3, &[Script::Grantha, Script::Tamil]),
+    (0x11FD4, 0x11FF1, &[Script::Tamil]),
+    (0x11FFF, 0x11FFF, &[Script::Tamil]),
+    (0x12000, 0x12399, &[Script::Cuneiform]),
+    (0x12400, 0x1246E, &[Script::Cuneiform]),
+    (0x12470, 0x12474, &[Script::Cuneiform]),
+    (0x12480, 0x12543, &[Script::Cuneiform]),
+    (0x12F90, 0x12FF2, &[Script::CyproMinoan]),
+    (0x13000, 0x13455, &[Script::EgyptianHieroglyphs]),
+    (0x13460, 0x143FA, &[Script::EgyptianHieroglyphs]),
+    (0x14400, 0x14646, &[Script::AnatolianHieroglyphs]),
+    (0x16100, 0x16139, &[Script::GurungKhema]),
+    (0x16800, 0x16A38, &[Script::Bamum]),
+    (0x16A40, 0x16A5E, &[Script::Mro]),
+    (0x16A60, 0x16A69, &[Script::Mro]),
+    (0x16A6E, 0x16A6F, &[Script::Mro]),
+    (0x16A70, 0x16ABE, &[Script::Tangsa]),
+    (0x16AC0, 0x16AC9, &[Script::Tangsa]),
+    (0x16AD0, 0x16AED, &[Script::BassaVah]),
+    (0x16AF0, 0x16AF5, &[Script::BassaVah]),
+    (0x16B00, 0x16B45, &[Script::PahawhHmong]),
+    (0x16B50, 0x16B59, &[Script::PahawhHmong]),
+    (0x16B5B, 0x16B61, &[Script::PahawhHmong]),
+    (0x16B63, 0x16B77, &[Script::PahawhHmong]),
+    (0x16B7D, 0x16B8F, &[Script::PahawhHmong]),
+    (0x16D40, 0x16D79, &[Script::KiratRai]),
+    (0x16E40, 0x16E9A, &[Script::Medefaidrin]),
+    (0x16F00, 0x16F4A, &[Script::Miao]),
+    (0x16F4F, 0x16F87, &[Script::Miao]),
+    (0x16F8F, 0x16F9F, &[Script::Miao]),
+    (0x16FE0, 0x16FE0, &[Script::Tangut]),
+    (0x16FE1, 0x16FE1, &[Script::Nushu]),
+    (0x16FE2, 0x16FE3, &[Script::Han]),
+    (0x16FE4, 0x16FE4, &[Script::KhitanSmallScript]),
+    (0x16FF0, 0x16FF1, &[Script::Han]),
+    (0x17000, 0x187F7, &[Script::Tangut]),
+    (0x18800, 0x18AFF, &[Script::Tangut]),
+    (0x18B00, 0x18CD5, &[Script::KhitanSmallScript]),
+    (0x18CFF, 0x18CFF, &[Script::KhitanSmallScript]),
+    (0x18D00, 0x18D08, &[Script::Tangut]),
+    (0x1AFF0, 0x1AFF3, &[Script::Katakana]),
+    (0x1AFF5, 0x1AFFB, &[Script::Katakana]),
+    (0x1AFFD, 0x1AFFE, &[Script::Katakana]),
+    (0x1B000, 0x1B000, &[Script::Katakana]),
+    (0x1B001, 0x1B11F, &[Script::Hiragana]),
+    (0x1B120, 0x1B122, &[Script::Katakana]),
+    (0x1B132, 0x1B132, &[Script::Hiragana]),
+    (0x1B150, 0x1B152, &[Script::Hiragana]),
+    (0x1B155, 0x1B155, &[Script::Katakana]),
+    (0x1B164, 0x1B167, &[Script::Katakana]),
+    (0x1B170, 0x1B2FB, &[Script::Nushu]),
+    (0x1BC00, 0x1BC6A, &[Script::Duployan]),
+    (0x1BC70, 0x1BC7C, &[Script::Duployan]),
+    (0x1BC80, 0x1BC88, &[Script::Duployan]),
+    (0x1BC90, 0x1BC99, &[Script::Duployan]),
+    (0x1BC9C, 0x1BCA3, &[Script::Duployan]),
+    (0x1D200, 0x1D245, &[Script::Greek]),
+    (0x1D360, 0x1D371, &[Script::Han]),
+    (0x1D800, 0x1DA8B, &[Script::SignWriting]),
+    (0x1DA9B, 0x1DA9F, &[Script::SignWriting]),
+    (0x1DAA1, 0x1DAAF, &[Script::SignWriting]),
+    (0x1DF00, 0x1DF1E, &[Script::Latin]),
+    (0x1DF25, 0x1DF2A, &[Script::Latin]),
+    (0x1E000, 0x1E006, &[Script::Glagolitic]),
+    (0x1E008, 0x1E018, &[Script::Glagolitic]),
+    (0x1E01B, 0x1E021, &[Script::Glagolitic]),
+    (0x1E023, 0x1E024, &[Script::Glagolitic]),
+    (0x1E026, 0x1E02A, &[Script::Glagolitic]),
+    (0x1E030, 0x1E06D, &[Script::Cyrillic]),
+    (0x1E08F, 0x1E08F, &[Script::Cyrillic]),
+    (0x1E100, 0x1E12C, &[Script::NyiakengPuachueHmong]),
+    (0x1E130, 0x1E13D, &[Script::NyiakengPuachueHmong]),
+    (0x1E140, 0x1E149, &[Script::NyiakengPuachueHmong]),
+    (0x1E14E, 0x1E14F, &[Script::NyiakengPuachueHmong]),
+    (0x1E290, 0x1E2AE, &[Script::Toto]),
+    (0x1E2C0, 0x1E2F9, &[Script::Wancho]),
+    (0x1E2FF, 0x1E2FF, &[Script::Wancho]),
+    (0x1E4D0, 0x1E4F9, &[Script::NagMundari]),
+    (0x1E5D0, 0x1E5FA, &[Script::OlOnal]),
+    (0x1E5FF, 0x1E5FF, &[Script::OlOnal]),
+    (0x1E7E0, 0x1E7E6, &[Script::Ethiopic]),
+    (0x1E7E8, 0x1E7EB, &[Script::Ethiopic]),
+    (0x1E7ED, 0x1E7EE, &[Script::Ethiopic]),
+    (0x1E7F0, 0x1E7FE, &[Script::Ethiopic]),
+    (0x1E800, 0x1E8C4, &[Script::MendeKikakui]),
+    (0x1E8C7, 0x1E8D6, &[Script::MendeKikakui]),
+    (0x1E900, 0x1E94B, &[Script::Adlam]),
+    (0x1E950, 0x1E959, &[Script::Adlam]),
+    (0x1E95E, 0x1E95F, &[Script::Adlam]),
+    (0x1EE00, 0x1EE03, &[Script::Arabic]),
+    (0x1EE05, 0x1EE1F, &[Script::Arabic]),
+    (0x1EE21, 0x1EE22, &[Script::Arabic]),
+    (0x1EE24, 0x1EE24, &[Script::Arabic]),
+    (0x1EE27, 0x1EE27, &[Script::Arabic]),
+    (0x1EE29, 0x1EE32, &[Script::Arabic]),
+    (0x1EE34, 0x1EE37, &[Script::Arabic]),
+    (0x1EE39, 0x1EE39, &[Script::Arabic]),
+    (0x1EE3B, 0x1EE3B, &[Script::Arabic]),
+    (0x1EE42, 0x1EE42, &[Script::Arabic]),
+    (0x1EE47, 0x1EE47, &[Script::Arabic]),
+    (0x1EE49, 0x1EE49, &[Script::Arabic]),
+    (0x1EE4B, 0x1EE4B, &[Script::Arabic]),
+    (0x1EE4D, 0x1EE4F, &[Script::Arabic]),
+    (0x1EE51, 0x1EE52, &[Script::Arabic]),
+    (0x1EE54, 0x1EE54, &[Script::Arabic]),
+    (0x1EE57, 0x1EE57, &[Script::Arabic]),
+    (0x1EE59, 0x1EE59, &[Script::Arabic]),
+    (0x1EE5B, 0x1EE5B, &[Script::Arabic]),
+    (0x1EE5D, 0x1EE5D, &[Script::Arabic]),
+    (0x1EE5F, 0x1EE5F, &[Script::Arabic]),
+    (0x1EE61, 0x1EE62, &[Script::Arabic]),
+    (0x1EE64, 0x1EE64, &[Script::Arabic]),
+    (0x1EE67, 0x1EE6A, &[Script::Arabic]),
+    (0x1EE6C, 0x1EE72, &[Script::Arabic]),
+    (0x1EE74, 0x1EE77, &[Script::Arabic]),
+    (0x1EE79, 0x1EE7C, &[Script::Arabic]),
+    (0x1EE7E, 0x1EE7E, &[Script::Arabic]),
+    (0x1EE80, 0x1EE89, &[Script::Arabic]),
+    (0x1EE8B, 0x1EE9B, &[Script::Arabic]),
+    (0x1EEA1, 0x1EEA3, &[Script::Arabic]),
+    (0x1EEA5, 0x1EEA9, &[Script::Arabic]),
+    (0x1EEAB, 0x1EEBB, &[Script::Arabic]),
+    (0x1EEF0, 0x1EEF1, &[Script::Arabic]),
+    (0x1F200, 0x1F200, &[Script::Hiragana]),
+    (0x1F250, 0x1F251, &[Script::Han]),
+    (0x20000, 0x2A6DF, &[Script::Han]),
+    (0x2A700, 0x2B739, &[Script::Han]),
+    (0x2B740, 0x2B81D, &[Script::Han]),
+    (0x2B820, 0x2CEA1, &[Script::Han]),
+    (0x2CEB0, 0x2EBE0, &[Script::Han]),
+    (0x2EBF0, 0x2EE5D, &[Script::Han]),
+    (0x2F800, 0x2FA1D, &[Script::Han]),
+    (0x30000, 0x3134A, &[Script::Han]),
+    (0x31350, 0x323AF, &[Script::Han]),
+];
+
+/// The strong Bidi_Class ranges from `DerivedBidiClass.txt` of Unicode 16.0: L is left-to-right, R and AL are
+/// right-to-left.
+static STRONG_DIRECTION_RANGES: &[(u32, u32, Direction)] = &[
+    (0x0041, 0x005A, Direction::LeftToRight),
+    (0x0061, 0x007A, Direction::LeftToRight),
+    (0x00AA, 0x00AA, Direction::LeftToRight),
+    (0x00B5, 0x00B5, Direction::LeftToRight),
+    (0x00BA, 0x00BA, Direction::LeftToRight),
+    (0x00C0, 0x00D6, Direction::LeftToRight),
+    (0x00D8, 0x00F6, Direction::LeftToRight),
+    (0x00F8, 0x02B8, Direction::LeftToRight),
+    (0x02BB, 0x02C1, Direction::LeftToRight),
+    (0x02D0, 0x02D1, Direction::LeftToRight),
+    (0x02E0, 0x02E4, Direction::LeftToRight),
+    (0x02EE, 0x02EE, Direction::LeftToRight),
+    (0x0370, 0x0373, Direction::LeftToRight),
+    (0x0376, 0x0377, Direction::LeftToRight),
+    (0x037A, 0x037D, Direction::LeftToRight),
+    (0x037F, 0x037F, Direction::LeftToRight),
+    (0x0386, 0x0386, Direction::LeftToRight),
+    (0x0388, 0x038A, Direction::LeftToRight),
+    (0x038C, 0x038C, Direction::LeftToRight),
+    (0x038E, 0x03A1, Direction::LeftToRight),
+    (0x03A3, 0x03F5, Direction::LeftToRight),
+    (0x03F7, 0x0482, Direction::LeftToRight),
+    (0x048A, 0x052F, Direction::LeftToRight),
+    (0x0531, 0x0556, Direction::LeftToRight),
+    (0x0559, 0x0589, Direction::LeftToRight),
+    (0x0590, 0x0590, Direction::RightToLeft),
+    (0x05BE, 0x05BE, Direction::RightToLeft),
+    (0x05C0, 0x05C0, Direction::RightToLeft),
+    (0x05C3, 0x05C3, Direction::RightToLeft),
+    (0x05C6, 0x05C6, Direction::RightToLeft),
+    (0x05C8, 0x05FF, Direction::RightToLeft),
+    (0x0608, 0x0608, Direction::RightToLeft),
+    (0x060B, 0x060B, Direction::RightToLeft),
+    (0x060D, 0x060D, Direction::RightToLeft),
+    (0x061B, 0x064A, Direction::RightToLeft),
+    (0x066D, 0x066F, Direction::RightToLeft),
+    (0x0671, 0x06D5, Direction::RightToLeft),
+    (0x06E5, 0x06E6, Direction::RightToLeft),
+    (0x06EE, 0x06EF, Direction::RightToLeft),
+    (0x06FA, 0x0710, Direction::RightToLeft),
+    (0x0712, 0x072F, Direction::RightToLeft),
+    (0x074B, 0x07A5, Direction::RightToLeft),
+    (0x07B1, 0x07EA, Direction::RightToLeft),
+    (0x07F4, 0x07F5, Direction::RightToLeft),
+    (0x07FA, 0x07FC, Direction::RightToLeft),
+    (0x07FE, 0x0815, Direction::RightToLeft),
+    (0x081A, 0x081A, Direction::RightToLeft),
+    (0x0824, 0x0824, Direction::RightToLeft),
+    (0x0828, 0x0828, Direction::RightToLeft),
+    (0x082E, 0x0858, Direction::RightToLeft),
+    (0x085C, 0x088F, Direction::RightToLeft),
+    (0x0892, 0x0896, Direction::RightToLeft),
+    (0x08A0, 0x08C9, Direction::RightToLeft),
+    (0x0903, 0x0939, Direction::LeftToRight),
+    (0x093B, 0x093B, Direction::LeftToRight),
+    (0x093D, 0x0940, Direction::LeftToRight),
+    (0x0949, 0x094C, Direction::LeftToRight),
+    (0x094E, 0x0950, Direction::LeftToRight),
+    (0x0958, 0x0961, Direction::LeftToRight),
+    (0x0964, 0x0980, Direction::LeftToRight),
+    (0x0982, 0x0983, Direction::LeftToRight),
+    (0x0985, 0x098C, Direction::LeftToRight),
+    (0x098F, 0x0990, Direction::LeftToRight),
+    (0x0993, 0x09A8, Direction::LeftToRight),
+    (0x09AA, 0x09B0, Direction::LeftToRight),
+    (0x09B2, 0x09B2, Direction::LeftToRight),
+    (0x09B6, 0x09B9, Direction::LeftToRight),
+    (0x09BD, 0x09C0, Direction::LeftToRight),
+    (0x09C7, 0x09C8, Direction::LeftToRight),
+    (0x09CB, 0x09CC, Direction::LeftToRight),
+    (0x09CE, 0x09CE, Direction::LeftToRight),
+    (0x09D7, 0x09D7, Direction::LeftToRight),
+    (0x09DC, 0x09DD, Direction::LeftToRight),
+    (0x09DF, 0x09E1, Direction::LeftToRight),
+    (0x09E6, 0x09F1, Direction::LeftToRight),
+    (0x09F4, 0x09FA, Direction::LeftToRight),
+    (0x09FC, 0x09FD, Direction::LeftToRight),
+    (0x0A03, 0x0A03, Direction::LeftToRight),
+    (0x0A05, 0x0A0A, Direction::LeftToRight),
+    (0x0A0F, 0x0A10, Direction::LeftToRight),
+    (0x0A13, 0x0A28, Direction::LeftToRight),
+    (0x0A2A, 0x0A30, Direction::LeftToRight),
+    (0x0A32, 0x0A33, Direction::LeftToRight),
+    (0x0A35, 0x0A36, Direction::LeftToRight),
+    (0x0A38, 0x0A39, Direction::LeftToRight),
+    (0x0A3E, 0x0A40, Direction::LeftToRight),
+    (0x0A59, 0x0A5C, Direction::LeftToRight),
+    (0x0A5E, 0x0A5E, Direction::LeftToRight),
+    (0x0A66, 0x0A6F, Direction::LeftToRight),
+    (0x0A72, 0x0A74, Direction::LeftToRight),
+    (0x0A76, 0x0A76, Direction::LeftToRight),
+    (0x0A83, 0x0A83, Direction::LeftToRight),
+    (0x0A85, 0x0A8D, Direction::LeftToRight),
+    (0x0A8F, 0x0A91, Direction::LeftToRight),
+    (0x0A93, 0x0AA8, Direction::LeftToRight),
+    (0x0AAA, 0x0AB0, Direction::LeftToRight),
+    (0x0AB2, 0x0AB3, Direction::LeftToRight),
+    (0x0AB5, 0x0AB9, Direction::LeftToRight),
+    (0x0ABD, 0x0AC0, Direction::LeftToRight),
+    (0x0AC9, 0x0AC9, Direction::LeftToRight),
+    (0x0ACB, 0x0ACC, Direction::LeftToRight),
+    (0x0AD0, 0x0AD0, Direction::LeftToRight),
+    (0x0AE0, 0x0AE1, Direction::LeftToRight),
+    (0x0AE6, 0x0AF0, Direction::LeftToRight),
+    (0x0AF9, 0x0AF9, Direction::LeftToRight),
+    (0x0B02, 0x0B03, Direction::LeftToRight),
+    (0x0B05, 0x0B0C, Direction::LeftToRight),
+    (0x0B0F, 0x0B10, Direction::LeftToRight),
+    (0x0B13, 0x0B28, Direction::LeftToRight),
+    (0x0B2A, 0x0B30, Direction::LeftToRight),
+    (0x0B32, 0x0B33, Direction::LeftToRight),
+    (0x0B35, 0x0B39, Direction::LeftToRight),
+    (0x0B3D, 0x0B3E, Direction::LeftToRight),
+    (0x0B40, 0x0B40, Direction::LeftToRight),
+    (0x0B47, 0x0B48, Direction::LeftToRight),
+    (0x0B4B, 0x0B4C, Direction::LeftToRight),
+    (0x0B57, 0x0B57, Direction::LeftToRight),
+    (0x0B5C, 0x0B5D, Direction::LeftToRight),
+    (0x0B5F, 0x0B61, Direction::LeftToRight),
+    (0x0B66, 0x0B77, Direction::LeftToRight),
+    (0x0B83, 0x0B83, Direction::LeftToRight),
+    (0x0B85, 0x0B8A, Direction::LeftToRight),
+    (0x0B8E, 0x0B90, Direction::LeftToRight),
+    (0x0B92, 0x0B95, Direction::LeftToRight),
+    (0x0B99, 0x0B9A, Direction::LeftToRight),
+    (0x0B9C, 0x0B9C, Direction::LeftToRight),
+    (0x0B9E, 0x0B9F, Direction::LeftToRight),
+    (0x0BA3, 0x0BA4, Direction::LeftToRight),
+    (0x0BA8, 0x0BAA, Direction::LeftToRight),
+    (0x0BAE, 0x0BB9, Direction::LeftToRight),
+    (0x0BBE, 0x0BBF, Direction::LeftToRight),
+    (0x0BC1, 0x0BC2, Direction::LeftToRight),
+    (0x0BC6, 0x0BC8, Direction::LeftToRight),
+    (0x0BCA, 0x0BCC, Direction::LeftToRight),
+    (0x0BD0, 0x0BD0, Direction::LeftToRight),
+    (0x0BD7, 0x0BD7, Direction::LeftToRight),
+    (0x0BE6, 0x0BF2, Direction::LeftToRight),
+    (0x0C01, 0x0C03, Direction::LeftToRight),
+    (0x0C05, 0x0C0C, Direction::LeftToRight),
+    (0x0C0E, 0x0C10, Direction::LeftToRight),
+    (0x0C12, 0x0C28, Direction::LeftToRight),
+    (0x0C2A, 0x0C39, Direction::LeftToRight),
+    (0x0C3D, 0x0C3D, Direction::LeftToRight),
+    (0x0C41, 0x0C44, Direction::LeftToRight),
+    (0x0C58, 0x0C5A, Direction::LeftToRight),
+    (0x0C5D, 0x0C5D, Direction::LeftToRight),
+    (0x0C60, 0x0C61, Direction::LeftToRight),
+    (0x0C66, 0x0C6F, Direction::LeftToRight),
+    (0x0C77, 0x0C77, Direction::LeftToRight),
+    (0x0C7F, 0x0C80, Direction::LeftToRight),
+    (0x0C82, 0x0C8C, Direction::LeftToRight),
+    (0x0C8E, 0x0C90, Direction::LeftToRight),
+    (0x0C92, 0x0CA8, Direction::LeftToRight),
+    (0x0CAA, 0x0CB3, Direction::LeftToRight),
+    (0x0CB5, 0x0CB9, Direction::LeftToRight),
+    (0x0CBD, 0x0CC4, Direction::LeftToRight),
+    (0x0CC6, 0x0CC8, Direction::LeftToRight),
+    (0x0CCA, 0x0CCB, Direction::LeftToRight),
+    (0x0CD5, 0x0CD6, Direction::LeftToRight),
+    (0x0CDD, 0x0CDE, Direction::LeftToRight),
+    (0x0CE0, 0x0CE1, Direction::LeftToRight),
+    (0x0CE6, 0x0CEF, Direction::LeftToRight),
+    (0x0CF1, 0x0CF3, Direction::LeftToRight),
+    (0x0D02, 0x0D0C, Direction::LeftToRight),
+    (0x0D0E, 0x0D10, Direction::LeftToRight),
+    (0x0D12, 0x0D3A, Direction::LeftToRight),
+    (0x0D3D, 0x0D40, Direction::LeftToRight),
+    (0x0D46, 0x0D48, Direction::LeftToRight),
+    (0x0D4A, 0x0D4C, Direction::LeftToRight),
+    (0x0D4E, 0x0D4F, Direction::LeftToRight),
+    (0x0D54, 0x0D61, Direction::LeftToRight),
+    (0x0D66, 0x0D7F, Direction::LeftToRight),
+    (0x0D82, 0x0D83, Direction::LeftToRight),
+    (0x0D85, 0x0D96, Direction::LeftToRight),
+    (0x0D9A, 0x0DB1, Direction::LeftToRight),
+    (0x0DB3, 0x0DBB, Direction::LeftToRight),
+    (0x0DBD, 0x0DBD, Direction::LeftToRight),
+    (0x0DC0, 0x0DC6, Direction::LeftToRight),
+    (0x0DCF, 0x0DD1, Direction::LeftToRight),
+    (0x0DD8, 0x0DDF, Direction::LeftToRight),
+    (0x0DE6, 0x0DEF, Direction::LeftToRight),
+    (0x0DF2, 0x0DF4, Direction::LeftToRight),
+    (0x0E01, 0x0E30, Direction::LeftToRight),
+    (0x0E32, 0x0E33, Direction::LeftToRight),
+    (0x0E40, 0x0E46, Direction::LeftToRight),
+    (0x0E4F, 0x0E5B, Direction::LeftToRight),
+    (0x0E81, 0x0E82, Direction::LeftToRight),
+    (0x0E84, 0x0E84, Direction::LeftToRight),
+    (0x0E86, 0x0E8A, Direction::LeftToRight),
+    (0x0E8C, 0x0EA3, Direction::LeftToRight),
+    (0x0EA5, 0x0EA5, Direction::LeftToRight),
+    (0x0EA7, 0x0EB0, Direction::LeftToRight),
+    (0x0EB2, 0x0EB3, Direction::LeftToRight),
+    (0x0EBD, 0x0EBD, Direction::LeftToRight),
+    (0x0EC0, 0x0EC4, Direction::LeftToRight),
+    (0x0EC6, 0x0EC6, Direction::LeftToRight),
+    (0x0ED0, 0x0ED9, Direction::LeftToRight),
+    (0x0EDC, 0x0EDF, Direction::LeftToRight),
+    (0x0F00, 0x0F17, Direction::LeftToRight),
+    (0x0F1A, 0x0F34, Direction::LeftToRight),
+    (0x0F36, 0x0F36, Direction::LeftToRight),
+    (0x0F38, 0x0F38, Direction::LeftToRight),
+    (0x0F3E, 0x0F47, Direction::LeftToRight),
+    (0x0F49, 0x0F6C, Direction::LeftToRight),
+    (0x0F7F, 0x0F7F, Direction::LeftToRight),
+    (0x0F85, 0x0F85, Direction::LeftToRight),
+    (0x0F88, 0x0F8C, Direction::LeftToRight),
+    (0x0FBE, 0x0FC5, Direction::LeftToRight),
+    (0x0FC7, 0x0FCC, Direction::LeftToRight),
+    (0x0FCE, 0x0FDA, Direction::LeftToRight),
+    (0x1000, 0x102C, Direction::LeftToRight),
+    (0x1031, 0x1031, Direction::LeftToRight),
+    (0x1038, 0x1038, Direction::LeftToRight),
+    (0x103B, 0x103C, Direction::LeftToRight),
+    (0x103F, 0x1057, Direction::LeftToRight),
+    (0x105A, 0x105D, Direction::LeftToRight),
+    (0x1061, 0x1070, Direction::LeftToRight),
+    (0x1075, 0x1081, Direction::LeftToRight),
+    (0x1083, 0x1084, Direction::LeftToRight),
+    (0x1087, 0x108C, Direction::LeftToRight),
+    (0x108E, 0x109C, Direction::LeftToRight),
+    (0x109E, 0x10C5, Direction::LeftToRight),
+    (0x10C7, 0x10C7, Direction::LeftToRight),
+    (0x10CD, 0x10CD, Direction::LeftToRight),
+    (0x10D0, 0x1248, Direction::LeftToRight),
+    (0x124A, 0x124D, Direction::LeftToRight),
+    (0x1250, 0x1256, Direction::LeftToRight),
+    (0x1258, 0x1258, Direction::LeftToRight),
+    (0x125A, 0x125D, Direction::LeftToRight),
+    (0x1260, 0x1288, Direction::LeftToRight),
+    (0x128A, 0x128D, Direction::LeftToRight),
+    (0x1290, 0x12B0, Direction::LeftToRight),
+    (0x12B2, 0x12B5, Direction::LeftToRight),
+    (0x12B8, 0x12BE, Direction::LeftToRight),
+    (0x12C0, 0x12C0, Direction::LeftToRight),
+    (0x12C2, 0x12C5, Direction::LeftToRight),
+    (0x12C8, 0x12D6, Direction::LeftToRight),
+    (0x12D8, 0x1310, Direction::LeftToRight),
+    (0x1312, 0x1315, Direction::LeftToRight),
+    (0x1318, 0x135A, Direction::LeftToRight),
+    (0x1360, 0x137C, Direction::LeftToRight),
+    (0x1380, 0x138F, Direction::LeftToRight),
+    (0x13A0, 0x13F5, Direction::LeftToRight),
+    (0x13F8, 0x13FD, Direction::LeftToRight),
+    (0x1401, 0x167F, Direction::LeftToRight),
+    (0x1681, 0x169A, Direction::LeftToRight),
+    (0x16A0, 0x16F8, Direction::LeftToRight),
+    (0x1700, 0x1711, Direction::LeftToRight),
+    (0x1715, 0x1715, Direction::LeftToRight),
+    (0x171F, 0x1731, Direction::LeftToRight),
+    (0x1734, 0x1736, Direction::LeftToRight),
+    (0x1740, 0x1751, Direction::LeftToRight),
+    (0x1760, 0x176C, Direction::LeftToRight),
+    (0x176E, 0x1770, Direction::LeftToRight),
+    (0x1780, 0x17B3, Direction::LeftToRight),
+    (0x17B6, 0x17B6, Direction::LeftToRight),
+    (0x17BE, 0x17C5, Direction::LeftToRight),
+    (0x17C7, 0x17C8, Direction::LeftToRight),
+    (0x17D4, 0x17DA, Direction::LeftToRight),
+    (0x17DC, 0x17DC, Direction::LeftToRight),
+    (0x17E0, 0x17E9, Direction::LeftToRight),
+    (0x1810, 0x1819, Direction::LeftToRight),
+    (0x1820, 0x1878, Direction::LeftToRight),
+    (0x1880, 0x1884, Direction::LeftToRight),
+    (0x1887, 0x18A8, Direction::LeftToRight),
+    (0x18AA, 0x18AA, Direction::LeftToRight),
+    (0x18B0, 0x18F5, Direction::LeftToRight),
+    (0x1900, 0x191E, Direction::LeftToRight),
+    (0x1923, 0x1926, Direction::LeftToRight),
+    (0x1929, 0x192B, Direction::LeftToRight),
+    (0x1930, 0x1931, Direction::LeftToRight),
+    (0x1933, 0x1938, Direction::LeftToRight),
+    (0x1946, 0x196D, Direction::LeftToRight),
+    (0x1970, 0x1974, Direction::LeftToRight),
+    (0x1980, 0x19AB, Direction::LeftToRight),
+    (0x19B0, 0x19C9, Direction::LeftToRight),
+    (0x19D0, 0x19DA, Direction::LeftToRight),
+    (0x1A00, 0x1A16, Direction::LeftToRight),
+    (0x1A19, 0x1A1A, Direction::LeftToRight),
+    (0x1A1E, 0x1A55, Direction::LeftToRight),
+    (0x1A57, 0x1A57, Direction::LeftToRight),
+    (0x1A61, 0x1A61, Direction::LeftToRight),
+    (0x1A63, 0x1A64, Direction::LeftToRight),
+    (0x1A6D, 0x1A72, Direction::LeftToRight),
+    (0x1A80, 0x1A89, Direction::LeftToRight),
+    (0x1A90, 0x1A99, Direction::LeftToRight),
+    (0x1AA0, 0x1AAD, Direction::LeftToRight),
+    (0x1B04, 0x1B33, Direction::LeftToRight),
+    (0x1B35, 0x1B35, Direction::LeftToRight),
+    (0x1B3B, 0x1B3B, Direction::LeftToRight),
+    (0x1B3D, 0x1B41, Direction::LeftToRight),
+    (0x1B43, 0x1B4C, Direction::LeftToRight),
+    (0x1B4E, 0x1B6A, Direction::LeftToRight),
+    (0x1B74, 0x1B7F, Direction::LeftToRight),
+    (0x1B82, 0x1BA1, Direction::LeftToRight),
+    (0x1BA6, 0x1BA7, Direction::LeftToRight),
+    (0x1BAA, 0x1BAA, Direction::LeftToRight),
+    (0x1BAE, 0x1BE5, Direction::LeftToRight),
+    (0x1BE7, 0x1BE7, Direction::LeftToRight),
+    (0x1BEA, 0x1BEC, Direction::LeftToRight),
+    (0x1BEE, 0x1BEE, Direction::LeftToRight),
+    (0x1BF2, 0x1BF3, Direction::LeftToRight),
+    (0x1BFC, 0x1C2B, Direction::LeftToRight),
+    (0x1C34, 0x1C35, Direction::LeftToRight),
+    (0x1C3B, 0x1C49, Direction::LeftToRight),
+    (0x1C4D, 0x1C8A, Direction::LeftToRight),
+    (0x1C90, 0x1CBA, Direction::LeftToRight),
+    (0x1CBD, 0x1CC7, Direction::LeftToRight),
+    (0x1CD3, 0x1CD3, Direction::LeftToRight),
+    (0x1CE1, 0x1CE1, Direction::LeftToRight),
+    (0x1CE9, 0x1CEC, Direction::LeftToRight),
+    (0x1CEE, 0x1CF3, Direction::LeftToRight),
+    (0x1CF5, 0x1CF7, Direction::LeftToRight),
+    (0x1CFA, 0x1CFA, Direction::LeftToRight),
+    (0x1D00, 0x1DBF, Direction::LeftToRight),
+    (0x1E00, 0x1F15, Direction::LeftToRight),
+    (0x1F18, 0x1F1D, Direction::LeftToRight),
+    (0x1F20, 0x1F45, Direction::LeftToRight),
+    (0x1F48, 0x1F4D, Direction::LeftToRight),
+    (0x1F50, 0x1F57, Direction::LeftToRight),
+    (0x1F59, 0x1F59, Direction::LeftToRight),
+    (0x1F5B, 0x1F5B, Direction::LeftToRight),
+    (0x1F5D, 0x1F5D, Direction::LeftToRight),
+    (0x1F5F, 0x1F7D, Direction::LeftToRight),
+    (0x1F80, 0x1FB4, Direction::LeftToRight),
+    (0x1FB6, 0x1FBC, Direction::LeftToRight),
+    (0x1FBE, 0x1FBE, Direction::LeftToRight),
+    (0x1FC2, 0x1FC4, Direction::LeftToRight),
+    (0x1FC6, 0x1FCC, Direction::LeftToRight),
+    (0x1FD0, 0x1FD3, Direction::LeftToRight),
+    (0x1FD6, 0x1FDB, Direction::LeftToRight),
+    (0x1FE0, 0x1FEC, Direction::LeftToRight),
+    (0x1FF2, 0x1FF4, Direction::LeftToRight),
+    (0x1FF6, 0x1FFC, Direction::LeftToRight),
+    (0x200E, 0x200E, Direction::LeftToRight),
+    (0x200F, 0x200F, Direction::RightToLeft),
+    (0x2071, 0x2071, Direction::LeftToRight),
+    (0x207F, 0x207F, Direction::LeftToRight),
+    (0x2090, 0x209C, Direction::LeftToRight),
+    (0x2102, 0x2102, Direction::LeftToRight),
+    (0x2107, 0x2107, Direction::LeftToRight),
+    (0x210A, 0x2113, Direction::LeftToRight),
+    (0x2115, 0x2115, Direction::LeftToRight),
+    (0x2119, 0x211D, Direction::LeftToRight),
+    (0x2124, 0x2124, Direction::LeftToRight),
+    (0x2126, 0x2126, Direction::LeftToRight),
+    (0x2128, 0x2128, Direction::LeftToRight),
+    (0x212A, 0x212D, Direction::LeftToRight),
+    (0x212F, 0x2139, Direction::LeftToRight),
+    (0x213C, 0x213F, Direction::LeftToRight),
+    (0x2145, 0x2149, Direction::LeftToRight),
+    (0x214E, 0x214F, Direction::LeftToRight),
+    (0x2160, 0x2188, Direction::LeftToRight),
+    (0x2336, 0x237A, Direction::LeftToRight),
+    (0x2395, 0x2395, Direction::LeftToRight),
+    (0x249C, 0x24E9, Direction::LeftToRight),
+    (0x26AC, 0x26AC, Direction::LeftToRight),
+    (0x2800, 0x28FF, Direction::LeftToRight),
+    (0x2C00, 0x2CE4, Direction::LeftToRight),
+    (0x2CEB, 0x2CEE, Direction::LeftToRight),
+    (0x2CF2, 0x2CF3, Direction::LeftToRight),
+    (0x2D00, 0x2D25, Direction::LeftToRight),
+    (0x2D27, 0x2D27, Direction::LeftToRight),
+    (0x2D2D, 0x2D2D, Direction::LeftToRight),
+    (0x2D30, 0x2D67, Direction::LeftToRight),
+    (0x2D6F, 0x2D70, Direction::LeftToRight),
+    (0x2D80, 0x2D96, Direction::LeftToRight),
+    (0x2DA0, 0x2DA6, Direction::LeftToRight),
+    (0x2DA8, 0x2DAE, Direction::LeftToRight),
+    (0x2DB0, 0x2DB6, Direction::LeftToRight),
+    (0x2DB8, 0x2DBE, Direction::LeftToRight),
+    (0x2DC0, 0x2DC6, Direction::LeftToRight),
+    (0x2DC8, 0x2DCE, Direction::LeftToRight),
+    (0x2DD0, 0x2DD6, Direction::LeftToRight),
+    (0x2DD8, 0x2DDE, Direction::LeftToRight),
+    (0x3005, 0x3007, Direction::LeftToRight),
+    (0x3021, 0x3029, Direction::LeftToRight),
+    (0x302E, 0x302F, Direction::LeftToRight),
+    (0x3031, 0x3035, Direction::LeftToRight),
+    (0x3038, 0x303C, Direction::LeftToRight),
+    (0x3041, 0x3096, Direction::LeftToRight),
+    (0x309D, 0x309F, Direction::LeftToRight),
+    (0x30A1, 0x30FA, Direction::LeftToRight),
+    (0x30FC, 0x30FF, Direction::LeftToRight),
+    (0x3105, 0x312F, Direction::LeftToRight),
+    (0x3131, 0x318E, Direction::LeftToRight),
+    (0x3190, 0x31BF, Direction::LeftToRight),
+    (0x31F0, 0x321C, Direction::LeftToRight),
+    (0x3220, 0x324F, Direction::LeftToRight),
+    (0x3260, 0x327B, Direction::LeftToRight),
+    (0x327F, 0x32B0, Direction::LeftToRight),
+    (0x32C0, 0x32CB, Direction::LeftToRight),
+    (0x32D0, 0x3376, Direction::LeftToRight),
+    (0x337B, 0x33DD, Direction::LeftToRight),
+    (0x33E0, 0x33FE, Direction::LeftToRight),
+    (0x3400, 0x4DBF, Direction::LeftToRight),
+    (0x4E00, 0xA48C, Direction::LeftToRight),
+    (0xA4D0, 0xA60C, Direction::LeftToRight),
+    (0xA610, 0xA62B, Direction::LeftToRight),
+    (0xA640, 0xA66E, Direction::LeftToRight),
+    (0xA680, 0xA69D, Direction::LeftToRight),
+    (0xA6A0, 0xA6EF, Direction::LeftToRight),
+    (0xA6F2, 0xA6F7, Direction::LeftToRight),
+    (0xA722, 0xA787, Direction::LeftToRight),
+    (0xA789, 0xA7CD, Direction::LeftToRight),
+    (0xA7D0, 0xA7D1, Direction::LeftToRight),
+    (0xA7D3, 0xA7D3, Direction::LeftToRight),
+    (0xA7D5, 0xA7DC, Direction::LeftToRight),
+    (0xA7F2, 0xA801, Direction::LeftToRight),
+    (0xA803, 0xA805, Direction::LeftToRight),
+    (0xA807, 0xA80A, Direction::LeftToRight),
+    (0xA80C, 0xA824, Direction::LeftToRight),
+    (0xA827, 0xA827, Direction::LeftToRight),
+    (0xA830, 0xA837, Direction::LeftToRight),
+    (0xA840, 0xA873, Direction::LeftToRight),
+    (0xA880, 0xA8C3, Direction::LeftToRight),
+    (0xA8CE, 0xA8D9, Direction::LeftToRight),
+    (0xA8F2, 0xA8FE, Direction::LeftToRight),
+    (0xA900, 0xA925, Direction::LeftToRight),
+    (0xA92E, 0xA946, Direction::LeftToRight),
+    (0xA952, 0xA953, Direction::LeftToRight),
+    (0xA95F, 0xA97C, Direction::LeftToRight),
+    (0xA983, 0xA9B2, Direction::LeftToRight),
+    (0xA9B4, 0xA9B5, Direction::LeftToRight),
+    (0xA9BA, 0xA9BB, Direction::LeftToRight),
+    (0xA9BE, 0xA9CD, Direction::LeftToRight),
+    (0xA9CF, 0xA9D9, Direction::LeftToRight),
+    (0xA9DE, 0xA9E4, Direction::LeftToRight),
+    (0xA9E6, 0xA9FE, Direction::LeftToRight),
+    (0xAA00, 0xAA28, Direction::LeftToRight),
+    (0xAA2F, 0xAA30, Direction::LeftToRight),
+    (0xAA33, 0xAA34, Direction::LeftToRight),
+    (0xAA40, 0xAA42, Direction::LeftToRight),
+    (0xAA44, 0xAA4B, Direction::LeftToRight),
+    (0xAA4D, 0xAA4D, Direction::LeftToRight),
+    (0xAA50, 0xAA59, Direction::LeftToRight),
+    (0xAA5C, 0xAA7B, Direction::LeftToRight),
+    (0xAA7D, 0xAAAF, Direction::LeftToRight),
+    (0xAAB1, 0xAAB1, Direction::LeftToRight),
+    (0xAAB5, 0xAAB6, Direction::LeftToRight),
+    (0xAAB9, 0xAABD, Direction::LeftToRight),
+    (0xAAC0, 0xAAC0, Direction::LeftToRight),
+    (0xAAC2, 0xAAC2, Direction::LeftToRight),
+    (0xAADB, 0xAAEB, Direction::LeftToRight),
+    (0xAAEE, 0xAAF5, Direction::LeftToRight),
+    (0xAB01, 0xAB06, Direction::LeftToRight),
+    (0xAB09, 0xAB0E, Direction::LeftToRight),
+    (0xAB11, 0xAB16, Direction::LeftToRight),
+    (0xAB20, 0xAB26, Direction::LeftToRight),
+    (0xAB28, 0xAB2E, Direction::LeftToRight),
+    (0xAB30, 0xAB69, Direction::LeftToRight),
+    (0xAB70, 0xABE4, Direction::LeftToRight),
+    (0xABE6, 0xABE7, Direction::LeftToRight),
+    (0xABE9, 0xABEC, Direction::LeftToRight),
+    (0xABF0, 0xABF9, Direction::LeftToRight),
+    (0xAC00, 0xD7A3, Direction::LeftToRight),
+    (0xD7B0, 0xD7C6, Direction::LeftToRight),
+    (0xD7CB, 0xD7FB, Direction::LeftToRight),
+    (0xE000, 0xFA6D, Direction::LeftToRight),
+    (0xFA70, 0xFAD9, Direction::LeftToRight),
+    (0xFB00, 0xFB06, Direction::LeftToRight),
+    (0xFB13, 0xFB17, Direction::LeftToRight),
+    (0xFB1D, 0xFB1D, Direction::RightToLeft),
+    (0xFB1F, 0xFB28, Direction::RightToLeft),
+    (0xFB2A, 0xFD3D, Direction::RightToLeft),
+    (0xFD50, 0xFDCE, Direction::RightToLeft),
+    (0xFDF0, 0xFDFC, Direction::RightToLeft),
+    (0xFE70, 0xFEFE, Direction::RightToLeft),
+    (0xFF21, 0xFF3A, Direction::LeftToRight),
+    (0xFF41, 0xFF5A, Direction::LeftToRight),
+    (0xFF66, 0xFFBE, Direction::LeftToRight),
+    (0xFFC2, 0xFFC7, Direction::LeftToRight),
+    (0xFFCA, 0xFFCF, Direction::LeftToRight),
+    (0xFFD2, 0xFFD7, Direction::LeftToRight),
+    (0xFFDA, 0xFFDC, Direction::LeftToRight),
+    (0x10000, 0x1000B, Direction::LeftToRight),
+    (0x1000D, 0x10026, Direction::LeftToRight),
+    (0x10028, 0x1003A, Direction::LeftToRight),
+    (0x1003C, 0x1003D, Direction::LeftToRight),
+    (0x1003F, 0x1004D, Direction::LeftToRight),
+    (0x10050, 0x1005D, Direction::LeftToRight),
+    (0x10080, 0x100FA, Direction::LeftToRight),
+    (0x10100, 0x10100, Direction::LeftToRight),
+    (0x10102, 0x10102, Direction::LeftToRight),
+    (0x10107, 0x10133, Direction::LeftToRight),
+    (0x10137, 0x1013F, Direction::LeftToRight),
+    (0x1018D, 0x1018E, Direction::LeftToRight),
+    (0x101D0, 0x101FC, Direction::LeftToRight),
+    (0x10280, 0x1029C, Direction::LeftToRight),
+    (0x102A0, 0x102D0, Direction::LeftToRight),
+    (0x10300, 0x10323, Direction::LeftToRight),
+    (0x1032D, 0x1034A, Direction::LeftToRight),
+    (0x10350, 0x10375, Direction::LeftToRight),
+    (0x10380, 0x1039D, Direction::LeftToRight),
+    (0x1039F, 0x103C3, Direction::LeftToRight),
+    (0x103C8, 0x103D5, Direction::LeftToRight),
+    (0x10400, 0x1049D, Direction::LeftToRight),
+    (0x104A0, 0x104A9, Direction::LeftToRight),
+    (0x104B0, 0x104D3, Direction::LeftToRight),
+    (0x104D8, 0x104FB, Direction::LeftToRight),
+    (0x10500, 0x10527, Direction::LeftToRight),
+    (0x10530, 0x10563, Direction::LeftToRight),
+    (0x1056F, 0x1057A, Direction::LeftToRight),
+    (0x1057C, 0x1058A, Direction::LeftToRight),
+    (0x1058C, 0x10592, Direction::LeftToRight),
+    (0x10594, 0x10595, Direction::LeftToRight),
+    (0x10597, 0x105A1, Direction::LeftToRight),
+    (0x105A3, 0x105B1, Direction::LeftToRight),
+    (0x105B3, 0x105B9, Direction::LeftToRight),
+    (0x105BB, 0x105BC, Direction::LeftToRight),
+    (0x105C0, 0x105F3, Direction::LeftToRight),
+    (0x10600, 0x10736, Direction::LeftToRight),
+    (0x10740, 0x10755, Direction::LeftToRight),
+    (0x10760, 0x10767, Direction::LeftToRight),
+    (0x10780, 0x10785, Direction::LeftToRight),
+    (0x10787, 0x107B0, Direction::LeftToRight),
+    (0x107B2, 0x107BA, Direction::LeftToRight),
+    (0x10800, 0x1091E, Direction::RightToLeft),
+    (0x10920, 0x10A00, Direction::RightToLeft),
+    (0x10A04, 0x10A04, Direction::RightToLeft),
+    (0x10A07, 0x10A0B, Direction::RightToLeft),
+    (0x10A10, 0x10A37, Direction::RightToLeft),
+    (0x10A3B, 0x10A3E, Direction::RightToLeft),
+    (0x10A40, 0x10AE4, Direction::RightToLeft),
+    (0x10AE7, 0x10B38, Direction::RightToLeft),
+    (0x10B40, 0x10D23, Direction::RightToLeft),
+    (0x10D28, 0x10D2F, Direction::RightToLeft),
+    (0x10D3A, 0x10D3F, Direction::RightToLeft),
+    (0x10D4A, 0x10D68, Direction::RightToLeft),
+    (0x10D6F, 0x10E5F, Direction::RightToLeft),
+    (0x10E7F, 0x10EAA, Direction::RightToLeft),
+    (0x10EAD, 0x10EFB, Direction::RightToLeft),
+    (0x10F00, 0x10F45, Direction::RightToLeft),
+    (0x10F51, 0x10F81, Direction::RightToLeft),
+    (0x10F86, 0x10FFF, Direction::RightToLeft),
+    (0x11000, 0x11000, Direction::LeftToRight),
+    (0x11002, 0x11037, Direction::LeftToRight),
+    (0x11047, 0x1104D, Direction::LeftToRight),
+    (0x11066, 0x1106F, Direction::LeftToRight),
+    (0x11071, 0x11072, Direction::LeftToRight),
+    (0x11075, 0x11075, Direction::LeftToRight),
+    (0x11082, 0x110B2, Direction::LeftToRight),
+    (0x110B7, 0x110B8, Direction::LeftToRight),
+    (0x110BB, 0x110C1, Direction::LeftToRight),
+    (0x110CD, 0x110CD, Direction::LeftToRight),
+    (0x110D0, 0x110E8, Direction::LeftToRight),
+    (0x110F0, 0x110F9, Direction::LeftToRight),
+    (0x11103, 0x11126, Direction::LeftToRight),
+    (0x1112C, 0x1112C, Direction::LeftToRight),
+    (0x11136, 0x11147, Direction::LeftToRight),
+    (0x11150, 0x11172, Direction::LeftToRight),
+    (0x11174, 0x11176, Direction::LeftToRight),
+    (0x11182, 0x111B5, Direction::LeftToRight),
+    (0x111BF, 0x111C8, Direction::LeftToRight),
+    (0x111CD, 0x111CE, Direction::LeftToRight),
+    (0x111D0, 0x111DF, Direction::LeftToRight),
+    (0x111E1, 0x111F4, Direction::LeftToRight),
+    (0x11200, 0x11211, Direction::LeftToRight),
+    (0x11213, 0x1122E, Direction::LeftToRight),
+    (0x11232, 0x11233, Direction::LeftToRight),
+    (0x11235, 0x11235, Direction::LeftToRight),
+    (0x11238, 0x1123D, Direction::LeftToRight),
+    (0x1123F, 0x11240, Direction::LeftToRight),
+    (0x11280, 0x11286, Direction::LeftToRight),
+    (0x11288, 0x11288, Direction::LeftToRight),
+    (0x1128A, 0x1128D, Direction::LeftToRight),
+    (0x1128F, 0x1129D, Direction::LeftToRight),
+    (0x1129F, 0x112A9, Direction::LeftToRight),
+    (0x112B0, 0x112DE, Direction::LeftToRight),
+    (0x112E0, 0x112E2, Direction::LeftToRight),
+    (0x112F0, 0x112F9, Direction::LeftToRight),
+    (0x11302, 0x11303, Direction::LeftToRight),
+    (0x11305, 0x1130C, Direction::LeftToRight),
+    (0x1130F, 0x11310, Direction::LeftToRight),
+    (0x11313, 0x11328, Direction::LeftToRight),
+    (0x1132A, 0x11330, Direction::LeftToRight),
+    (0x11332, 0x11333, Direction::LeftToRight),
+    (0x11335, 0x11339, Direction::LeftToRight),
+    (0x1133D, 0x1133F, Direction::LeftToRight),
+    (0x11341, 0x11344, Direction::LeftToRight),
+    (0x11347, 0x11348, Direction::LeftToRight),
+    (0x1134B, 0x1134D, Direction::LeftToRight),
+    (0x11350, 0x11350, Direction::LeftToRight),
+    (0x11357, 0x11357, Direction::LeftToRight),
+    (0x1135D, 0x11363, Direction::LeftToRight),
+    (0x11380, 0x11389, Direction::LeftToRight),
+    (0x1138B, 0x1138B, Direction::LeftToRight),
+    (0x1138E, 0x1138E, Direction::LeftToRight),
+    (0x11390, 0x113B5, Direction::LeftToRight),
+    (0x113B7, 0x113BA, Direction::LeftToRight),
+    (0x113C2, 0x113C2, Direction::LeftToRight),
+    (0x113C5, 0x113C5, Direction::LeftToRight),
+    (0x113C7, 0x113CA, Direction::LeftToRight),
+    (0x113CC, 0x113CD, Direction::LeftToRight),
+    (0x113CF, 0x113CF, Direction::LeftToRight),
+    (0x113D1, 0x113D1, Direction::LeftToRight),
+    (0x113D3, 0x113D5, Direction::LeftToRight),
+    (0x113D7, 0x113D8, Direction::LeftToRight),
+    (0x11400, 0x11437, Direction::LeftToRight),
+    (0x11440, 0x11441, Direction::LeftToRight),
+    (0x11445, 0x11445, Direction::LeftToRight),
+    (0x11447, 0x1145B, Direction::LeftToRight),
+    (0x1145D, 0x1145D, Direction::LeftToRight),
+    (0x1145F, 0x11461, Direction::LeftToRight),
+    (0x11480, 0x114B2, Direction::LeftToRight),
+    (0x114B9, 0x114B9, Direction::LeftToRight),
+    (0x114BB, 0x114BE, Direction::LeftToRight),
+    (0x114C1, 0x114C1, Direction::LeftToRight),
+    (0x114C4, 0x114C7, Direction::LeftToRight),
+    (0x114D0, 0x114D9, Direction::LeftToRight),
+    (0x11580, 0x115B1, Direction::LeftToRight),
+    (0x115B8, 0x115BB, Direction::LeftToRight),
+    (0x115BE, 0x115BE, Direction::LeftToRight),
+    (0x115C1, 0x115DB, Direction::LeftToRight),
+    (0x11600, 0x11632, Direction::LeftToRight),
+    (0x1163B, 0x1163C, Direction::LeftToRight),
+    (0x1163E, 0x1163E, Direction::LeftToRight),
+    (0x11641, 0x11644, Direction::LeftToRight),
+    (0x11650, 0x11659, Direction::LeftToRight),
+    (0x11680, 0x116AA, Direction::LeftToRight),
+    (0x116AC, 0x116AC, Direction::LeftToRight),
+    (0x116AE, 0x116AF, Direction::LeftToRight),
+    (0x116B6, 0x116B6, Direction::LeftToRight),
+    (0x116B8, 0x116B9, Direction::LeftToRight),
+    (0x116C0, 0x116C9, Direction::LeftToRight),
+    (0x116D0, 0x116E3, Direction::LeftToRight),
+    (0x11700, 0x1171A, Direction::LeftToRight),
+    (0x1171E, 0x1171E, Direction::LeftToRight),
+    (0x11720, 0x11721, Direction::LeftToRight),
+    (0x11726, 0x11726, Direction::LeftToRight),
+    (0x11730, 0x11746, Direction::LeftToRight),
+    (0x11800, 0x1182E, Direction::LeftToRight),
+    (0x11838, 0x11838, Direction::LeftToRight),
+    (0x1183B, 0x1183B, Direction::LeftToRight),
+    (0x118A0, 0x118F2, Direction::LeftToRight),
+    (0x118FF, 0x11906, Direction::LeftToRight),
+    (0x11909, 0x11909, Direction::LeftToRight),
+    (0x1190C, 0x11913, Direction::LeftToRight),
+    (0x11915, 0x11916, Direction::LeftToRight),
+    (0x11918, 0x11935, Direction::LeftToRight),
+    (0x11937, 0x11938, Direction::LeftToRight),
+    (0x1193D, 0x1193D, Direction::LeftToRight),
+    (0x1193F, 0x11942, Direction::LeftToRight),
+    (0x11944, 0x11946, Direction::LeftToRight),
+    (0x11950, 0x11959, Direction::LeftToRight),
+    (0x119A0, 0x119A7, Direction::LeftToRight),
+    (0x119AA, 0x119D3, Direction::LeftToRight),
+    (0x119DC, 0x119DF, Direction::LeftToRight),
+    (0x119E1, 0x119E4, Direction::LeftToRight),
+    (0x11A00, 0x11A00, Direction::LeftToRight),
+    (0x11A07, 0x11A08, Direction::LeftToRight),
+    (0x11A0B, 0x11A32, Direction::LeftToRight),
+    (0x11A39, 0x11A3A, Direction::LeftToRight),
+    (0x11A3F, 0x11A46, Direction::LeftToRight),
+    (0x11A50, 0x11A50, Direction::LeftToRight),
+    (0x11A57, 0x11A58, Direction::LeftToRight),
+    (0x11A5C, 0x11A89, Direction::LeftToRight),
+    (0x11A97, 0x11A97, Direction::LeftToRight),
+    (0x11A9A, 0x11AA2, Direction::LeftToRight),
+    (0x11AB0, 0x11AF8, Direction::LeftToRight),
+    (0x11B00, 0x11B09, Direction::LeftToRight),
+    (0x11BC0, 0x11BE1, Direction::LeftToRight),
+    (0x11BF0, 0x11BF9, Direction::LeftToRight),
+    (0x11C00, 0x11C08, Direction::LeftToRight),
+    (0x11C0A, 0x11C2F, Direction::LeftToRight),
+    (0x11C3E, 0x11C45, Direction::LeftToRight),
+    (0x11C50, 0x11C6C, Direction::LeftToRight),
+    (0x11C70, 0x11C8F, Direction::LeftToRight),
+    (0x11CA9, 0x11CA9, Direction::LeftToRight),
+    (0x11CB1, 0x11CB1, Direction::LeftToRight),
+    (0x11CB4, 0x11CB4, Direction::LeftToRight),
+    (0x11D00, 0x11D06, Direction::LeftToRight),
+    (0x11D08, 0x11D09, Direction::LeftToRight),
+    (0x11D0B, 0x11D30, Direction::LeftToRight),
+    (0x11D46, 0x11D46, Direction::LeftToRight),
+    (0x11D50, 0x11D59, Direction::LeftToRight),
+    (0x11D60, 0x11D65, Direction::LeftToRight),
+    (0x11D67, 0x11D68, Direction::LeftToRight),
+    (0x11D6A, 0x11D8E, Direction::LeftToRight),
+    (0x11D93, 0x11D94, Direction::LeftToRight),
+    (0x11D96, 0x11D96, Direction::LeftToRight),
+    (0x11D98, 0x11D98, Direction::LeftToRight),
+    (0x11DA0, 0x11DA9, Direction::LeftToRight),
+    (0x11EE0, 0x11EF2, Direction::LeftToRight),
+    (0x11EF5, 0x11EF8, Direction::LeftToRight),
+    (0x11F02, 0x11F10, Direction::LeftToRight),
+    (0x11F12, 0x11F35, Direction::LeftToRight),
+    (0x11F3E, 0x11F3F, Direction::LeftToRight),
+    (0x11F41, 0x11F41, Direction::LeftToRight),
+    (0x11F43, 0x11F59, Direction::LeftToRight),
+    (0x11FB0, 0x11FB0, Direction::LeftToRight),
+    (0x11FC0, 0x11FD4, Direction::LeftToRight),
+    (0x11FFF, 0x12399, Direction::LeftToRight),
+    (0x12400, 0x1246E, Direction::LeftToRight),
+    (0x12470, 0x12474, Direction::LeftToRight),
+    (0x12480, 0x12543, Direction::LeftToRight),
+    (0x12F90, 0x12FF2, Direction::LeftToRight),
+    (0x13000, 0x1343F, Direction::LeftToRight),
+    (0x13441, 0x13446, Direction::LeftToRight),
+    (0x13460, 0x143FA, Direction::LeftToRight),
+    (0x14400, 0x14646, Direction::LeftToRight),
+    (0x16100, 0x1611D, Direction::LeftToRight),
+    (0x1612A, 0x1612C, Direction::LeftToRight),
+    (0x16130, 0x16139, Direction::LeftToRight),
+    (0x16800, 0x16A38, Direction::LeftToRight),
+    (0x16A40, 0x16A5E, Direction::LeftToRight),
+    (0x16A60, 0x16A69, Direction::LeftToRight),
+    (0x16A6E, 0x16ABE, Direction::LeftToRight),
+    (0x16AC0, 0x16AC9, Direction::LeftToRight),
+    (0x16AD0, 0x16AED, Direction::LeftToRight),
+    (0x16AF5, 0x16AF5, Direction::LeftToRight),
+    (0x16B00, 0x16B2F, Direction::LeftToRight),
+    (0x16B37, 0x16B45, Direction::LeftToRight),
+    (0x16B50, 0x16B59, Direction::LeftToRight),
+    (0x16B5B, 0x16B61, Direction::LeftToRight),
+    (0x16B63, 0x16B77, Direction::LeftToRight),
+    (0x16B7D, 0x16B8F, Direction::LeftToRight),
+    (0x16D40, 0x16D79, Direction::LeftToRight),
+    (0x16E40, 0x16E9A, Direction::LeftToRight),
+    (0x16F00, 0x16F4A, Direction::LeftToRight),
+    (0x16F50, 0x16F87, Direction::LeftToRight),
+    (0x16F93, 0x16F9F, Direction::LeftToRight),
+    (0x16FE0, 0x16FE1, Direction::LeftToRight),
+    (0x16FE3, 0x16FE3, Direction::LeftToRight),
+    (0x16FF0, 0x16FF1, Direction::LeftToRight),
+    (0x17000, 0x187F7, Direction::LeftToRight),
+    (0x18800, 0x18CD5, Direction::LeftToRight),
+    (0x18CFF, 0x18D08, Direction::LeftToRight),
+    (0x1AFF0, 0x1AFF3, Direction::LeftToRight),
+    (0x1AFF5, 0x1AFFB, Direction::LeftToRight),
+    (0x1AFFD, 0x1AFFE, Direction::LeftToRight),
+    (0x1B000, 0x1B122, Direction::LeftToRight),
+    (0x1B132, 0x1B132, Direction::LeftToRight),
+    (0x1B150, 0x1B152, Direction::LeftToRight),
+    (0x1B155, 0x1B155, Direction::LeftToRight),
+    (0x1B164, 0x1B167, Direction::LeftToRight),
+    (0x1B170, 0x1B2FB, Direction::LeftToRight),
+    (0x1BC00, 0x1BC6A, Direction::LeftToRight),
+    (0x1BC70, 0x1BC7C, Direction::LeftToRight),
+    (0x1BC80, 0x1BC88, Direction::LeftToRight),
+    (0x1BC90, 0x1BC99, Direction::LeftToRight),
+    (0x1BC9C, 0x1BC9C, Direction::LeftToRight),
+    (0x1BC9F, 0x1BC9F, Direction::LeftToRight),
+    (0x1CCD6, 0x1CCEF, Direction::LeftToRight),
+    (0x1CF50, 0x1CFC3, Direction::LeftToRight),
+    (0x1D000, 0x1D0F5, Direction::LeftToRight),
+    (0x1D100, 0x1D126, Direction::LeftToRight),
+    (0x1D129, 0x1D166, Direction::LeftToRight),
+    (0x1D16A, 0x1D172, Direction::LeftToRight),
+    (0x1D183, 0x1D184, Direction::LeftToRight),
+    (0x1D18C, 0x1D1A9, Direction::LeftToRight),
+    (0x1D1AE, 0x1D1E8, Direction::LeftToRight),
+    (0x1D2C0, 0x1D2D3, Direction::LeftToRight),
+    (0x1D2E0, 0x1D2F3, Direction::LeftToRight),
+    (0x1D360, 0x1D378, Direction::LeftToRight),
+    (0x1D400, 0x1D454, Direction::LeftToRight),
+    (0x1D456, 0x1D49C, Direction::LeftToRight),
+    (0x1D49E, 0x1D49F, Direction::LeftToRight),
+    (0x1D4A2, 0x1D4A2, Direction::LeftToRight),
+    (0x1D4A5, 0x1D4A6, Direction::LeftToRight),
+    (0x1D4A9, 0x1D4AC, Direction::LeftToRight),
+    (0x1D4AE, 0x1D4B9, Direction::LeftToRight),
+    (0x1D4BB, 0x1D4BB, Direction::LeftToRight),
+    (0x1D4BD, 0x1D4C3, Direction::LeftToRight),
+    (0x1D4C5, 0x1D505, Direction::LeftToRight),
+    (0x1D507, 0x1D50A, Direction::LeftToRight),
+    (0x1D50D, 0x1D514, Direction::LeftToRight),
+    (0x1D516, 0x1D51C, Direction::LeftToRight),
+    (0x1D51E, 0x1D539, Direction::LeftToRight),
+    (0x1D53B, 0x1D53E, Direction::LeftToRight),
+    (0x1D540, 0x1D544, Direction::LeftToRight),
+    (0x1D546, 0x1D546, Direction::LeftToRight),
+    (0x1D54A, 0x1D550, Direction::LeftToRight),
+    (0x1D552, 0x1D6A5, Direction::LeftToRight),
+    (0x1D6A8, 0x1D6C0, Direction::LeftToRight),
+    (0x1D6C2, 0x1D6DA, Direction::LeftToRight),
+    (0x1D6DC, 0x1D6FA, Direction::LeftToRight),
+    (0x1D6FC, 0x1D714, Direction::LeftToRight),
+    (0x1D716, 0x1D734, Direction::LeftToRight),
+    (0x1D736, 0x1D74E, Direction::LeftToRight),
+    (0x1D750, 0x1D76E, Direction::LeftToRight),
+    (0x1D770, 0x1D788, Direction::LeftToRight),
+    (0x1D78A, 0x1D7A8, Direction::LeftToRight),
+    (0x1D7AA, 0x1D7C2, Direction::LeftToRight),
+    (0x1D7C4, 0x1D7CB, Direction::LeftToRight),
+    (0x1D800, 0x1D9FF, Direction::LeftToRight),
+    (0x1DA37, 0x1DA3A, Direction::LeftToRight),
+    (0x1DA6D, 0x1DA74, Direction::LeftToRight),
+    (0x1DA76, 0x1DA83, Direction::LeftToRight),
+    (0x1DA85, 0x1DA8B, Direction::LeftToRight),
+    (0x1DF00, 0x1DF1E, Direction::LeftToRight),
+    (0x1DF25, 0x1DF2A, Direction::LeftToRight),
+    (0x1E030, 0x1E06D, Direction::LeftToRight),
+    (0x1E100, 0x1E12C, Direction::LeftToRight),
+    (0x1E137, 0x1E13D, Direction::LeftToRight),
+    (0x1E140, 0x1E149, Direction::LeftToRight),
+    (0x1E14E, 0x1E14F, Direction::LeftToRight),
+    (0x1E290, 0x1E2AD, Direction::LeftToRight),
+    (0x1E2C0, 0x1E2EB, Direction::LeftToRight),
+    (0x1E2F0, 0x1E2F9, Direction::LeftToRight),
+    (0x1E4D0, 0x1E4EB, Direction::LeftToRight),
+    (0x1E4F0, 0x1E4F9, Direction::LeftToRight),
+    (0x1E5D0, 0x1E5ED, Direction::LeftToRight),
+    (0x1E5F0, 0x1E5FA, Direction::LeftToRight),
+    (0x1E5FF, 0x1E5FF, Direction::LeftToRight),
+    (0x1E7E0, 0x1E7E6, Direction::LeftToRight),
+    (0x1E7E8, 0x1E7EB, Direction::LeftToRight),
+    (0x1E7ED, 0x1E7EE, Direction::LeftToRight),
+    (0x1E7F0, 0x1E7FE, Direction::LeftToRight),
+    (0x1E800, 0x1E8CF, Direction::RightToLeft),
+    (0x1E8D7, 0x1E943, Direction::RightToLeft),
+    (0x1E94B, 0x1EEEF, Direction::RightToLeft),
+    (0x1EEF2, 0x1EFFF, Direction::RightToLeft),
+    (0x1F110, 0x1F12E, Direction::LeftToRight),
+    (0x1F130, 0x1F169, Direction::LeftToRight),
+    (0x1F170, 0x1F1AC, Direction::LeftToRight),
+    (0x1F1E6, 0x1F202, Direction::LeftToRight),
+    (0x1F210, 0x1F23B, Direction::LeftToRight),
+    (0x1F240, 0x1F248, Direction::LeftToRight),
+    (0x1F250, 0x1F251, Direction::LeftToRight),
+    (0x20000, 0x2A6DF, Direction::LeftToRight),
+    (0x2A700, 0x2B739, Direction::LeftToRight),
+    (0x2B740, 0x2B81D, Direction::LeftToRight),
+    (0x2B820, 0x2CEA1, Direction::LeftToRight),
+    (0x2CEB0, 0x2EBE0, Direction::LeftToRight),
+    (0x2EBF0, 0x2EE5D, Direction::LeftToRight),
+    (0x2F800, 0x2FA1D, Direction::LeftToRight),
+    (0x30000, 0x3134A, Direction::LeftToRight),
+    (0x31350, 0x323AF, Direction::LeftToRight),
+    (0xF0000, 0xFFFFD, Direction::LeftToRight),
+    (0x100000, 0x10FFFD, Direction::LeftToRight),
+];
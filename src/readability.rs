@@ -0,0 +1,319 @@
+//! Calculates the readability scores of English text.
+//! The words are found at the Unicode word boundaries (see `split::word_bounds`), the sentences with `split::sentences`
+//! and the syllables with `readability::syllables`. All the scores are 0.0 for a text without words.
+//! Unlike `count::count_words` (see `split::words`), the words are not broken at the case changes or underscores,
+//! e.g. "iPhone" is one word here and two words there. Both break the hyphenated words, e.g. "well-known" is two words.
+//! The numbers like "42" are not counted as words.
+
+/// Estimates the number of syllables in an English `word`. The common irregular words are looked up in an exception dictionary,
+/// the others are estimated by the groups of vowels with the corrections for the silent "e", "-ed" and "-es" endings.
+/// The accented characters are transliterated with `utils::unidecode` first. Returns 0 if `word` has no letters.
+///
+/// # Arguments
+///
+/// * `word` - The word to count the syllables in.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::syllables("readability");
+/// // => 5
+/// readability::syllables("table");
+/// // => 2
+/// readability::syllables("jumped");
+/// // => 1
+/// readability::syllables("people");
+/// // => 2
+/// use voca_rs::Voca;
+/// "syllable"._syllables();
+/// // => 3
+/// ```
+pub fn syllables(word: &str) -> usize {
+    let word: String = crate::utils::unidecode(word)
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_lowercase())
+        .collect();
+    if word.is_empty() {
+        return 0;
+    }
+    if let Some(&(_, count)) = SYLLABLE_EXCEPTIONS.iter().find(|&&(w, _)| w == word) {
+        return count;
+    }
+    if word.len() <= 3 {
+        return 1;
+    }
+    let letters = word.as_bytes();
+    // "y" is a vowel everywhere but at the start of the word
+    let is_vowel = |i: usize| {
+        matches!(letters[i], b'a' | b'e' | b'i' | b'o' | b'u') || (i > 0 && letters[i] == b'y')
+    };
+    let mut count = 0;
+    for i in 0..letters.len() {
+        if is_vowel(i) && (i == 0 || !is_vowel(i - 1)) {
+            count += 1;
+        }
+    }
+    // the vowel pairs which are usually pronounced separately, as in "lion" or "piano"
+    for pair in ["ia", "io", "iu", "eo", "uo"].iter() {
+        count += word.matches(pair).count();
+    }
+    for suffix in [
+        "tion", "sion", "cian", "cial", "tial", "gion", "cious", "tious", "gious",
+    ]
+    .iter()
+    {
+        count -= word.matches(suffix).count();
+    }
+    let n = letters.len();
+    let before = |k: usize| letters[n - k];
+    let is_consonant = |i: usize| !is_vowel(i);
+    let silent_e = word.ends_with('e')
+        && is_consonant(n - 2)
+        // unless it is a syllabic "-le" as in "table"
+        && !(before(2) == b'l' && is_consonant(n - 3));
+    let silent_ed =
+        word.ends_with("ed") && is_consonant(n - 3) && !matches!(before(3), b't' | b'd');
+    let silent_es = word.ends_with("es")
+        && is_consonant(n - 3)
+        && !matches!(before(3), b's' | b'x' | b'z' | b'c' | b'g')
+        && !word.ends_with("shes")
+        && !word.ends_with("ches");
+    if silent_e || silent_ed || silent_es {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+const SYLLABLE_EXCEPTIONS: [(&str, usize); 40] = [
+    ("area", 3),
+    ("being", 2),
+    ("business", 2),
+    ("cafe", 2),
+    ("create", 2),
+    ("created", 3),
+    ("every", 2),
+    ("everything", 3),
+    ("everyone", 3),
+    ("eye", 1),
+    ("eyes", 1),
+    ("fire", 1),
+    ("forever", 3),
+    ("going", 2),
+    ("hour", 1),
+    ("hours", 1),
+    ("idea", 3),
+    ("ideas", 3),
+    ("lion", 2),
+    ("maybe", 2),
+    ("naive", 2),
+    ("one", 1),
+    ("people", 2),
+    ("poem", 2),
+    ("poet", 2),
+    ("quiet", 2),
+    ("recipe", 3),
+    ("science", 2),
+    ("shoreline", 2),
+    ("simile", 3),
+    ("someone", 2),
+    ("something", 2),
+    ("sometimes", 2),
+    ("somewhere", 2),
+    ("the", 1),
+    ("through", 1),
+    ("toward", 2),
+    ("very", 2),
+    ("whole", 1),
+    ("wednesday", 2),
+];
+
+struct TextCounts {
+    letters: usize,
+    words: usize,
+    sentences: usize,
+    syllables: usize,
+    polysyllables: usize,
+}
+
+fn text_counts(subject: &str) -> TextCounts {
+    let mut counts = TextCounts {
+        letters: 0,
+        words: 0,
+        sentences: crate::split::sentences(subject).len().max(1),
+        syllables: 0,
+        polysyllables: 0,
+    };
+    let words = crate::split::word_bounds(subject)
+        .into_iter()
+        .filter(|bound| bound.kind == crate::split::WordKind::Word);
+    for word in words.map(|bound| bound.text) {
+        let word_syllables = syllables(word);
+        counts.letters += word.chars().filter(|c| c.is_alphanumeric()).count();
+        counts.words += 1;
+        counts.syllables += word_syllables;
+        if word_syllables >= 3 {
+            counts.polysyllables += 1;
+        }
+    }
+    counts
+}
+
+/// Calculates the Flesch Reading Ease score of `subject`. The higher the score, the easier the text: 90-100 is very easy, 0-30 is very difficult.
+///
+/// # Arguments
+///
+/// * `subject` - The text to score.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::flesch_reading_ease("The cat sat on the mat.");
+/// // => 116.14500000000001
+/// use voca_rs::Voca;
+/// "The cat sat on the mat."._flesch_reading_ease();
+/// // => 116.14500000000001
+/// ```
+pub fn flesch_reading_ease(subject: &str) -> f64 {
+    let counts = text_counts(subject);
+    if counts.words == 0 {
+        return 0.0;
+    }
+    206.835
+        - 1.015 * words_per_sentence(&counts)
+        - 84.6 * (counts.syllables as f64 / counts.words as f64)
+}
+
+/// Calculates the Flesch-Kincaid Grade Level of `subject`, i.e. the U.S. school grade needed to understand the text.
+///
+/// # Arguments
+///
+/// * `subject` - The text to score.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::flesch_kincaid_grade("The cat sat on the mat.");
+/// // => -1.4499999999999993
+/// use voca_rs::Voca;
+/// "The cat sat on the mat."._flesch_kincaid_grade();
+/// // => -1.4499999999999993
+/// ```
+pub fn flesch_kincaid_grade(subject: &str) -> f64 {
+    let counts = text_counts(subject);
+    if counts.words == 0 {
+        return 0.0;
+    }
+    0.39 * words_per_sentence(&counts) + 11.8 * (counts.syllables as f64 / counts.words as f64)
+        - 15.59
+}
+
+/// Calculates the Gunning Fog index of `subject`, i.e. the years of formal education needed to understand the text.
+/// The complex words are the words of three or more syllables.
+///
+/// # Arguments
+///
+/// * `subject` - The text to score.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::gunning_fog("The cat sat on the mat.");
+/// // => 2.4000000000000004
+/// use voca_rs::Voca;
+/// "The cat sat on the mat."._gunning_fog();
+/// // => 2.4000000000000004
+/// ```
+pub fn gunning_fog(subject: &str) -> f64 {
+    let counts = text_counts(subject);
+    if counts.words == 0 {
+        return 0.0;
+    }
+    0.4 * (words_per_sentence(&counts)
+        + 100.0 * (counts.polysyllables as f64 / counts.words as f64))
+}
+
+/// Calculates the SMOG grade of `subject`, i.e. the years of education needed to understand the text.
+/// The formula is normalized to 30 sentences, so it is most reliable for the longer texts.
+///
+/// # Arguments
+///
+/// * `subject` - The text to score.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::smog("The cat sat on the mat.");
+/// // => 3.1291
+/// use voca_rs::Voca;
+/// "The cat sat on the mat."._smog();
+/// // => 3.1291
+/// ```
+pub fn smog(subject: &str) -> f64 {
+    let counts = text_counts(subject);
+    if counts.words == 0 {
+        return 0.0;
+    }
+    1.043 * (counts.polysyllables as f64 * 30.0 / counts.sentences as f64).sqrt() + 3.1291
+}
+
+/// Calculates the Coleman-Liau index of `subject`, i.e. the U.S. school grade needed to understand the text.
+/// Unlike the other scores it is based on the letters rather than on the syllables.
+///
+/// # Arguments
+///
+/// * `subject` - The text to score.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::coleman_liau("The cat sat on the mat.");
+/// // => -4.073333333333334
+/// use voca_rs::Voca;
+/// "The cat sat on the mat."._coleman_liau();
+/// // => -4.073333333333334
+/// ```
+pub fn coleman_liau(subject: &str) -> f64 {
+    let counts = text_counts(subject);
+    if counts.words == 0 {
+        return 0.0;
+    }
+    let letters_per_100_words = counts.letters as f64 / counts.words as f64 * 100.0;
+    let sentences_per_100_words = counts.sentences as f64 / counts.words as f64 * 100.0;
+    0.0588 * letters_per_100_words - 0.296 * sentences_per_100_words - 15.8
+}
+
+/// Calculates the Automated Readability Index of `subject`, i.e. the U.S. school grade needed to understand the text.
+/// Like the Coleman-Liau index it is based on the letters rather than on the syllables.
+///
+/// # Arguments
+///
+/// * `subject` - The text to score.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// readability::automated_readability_index("The cat sat on the mat.");
+/// // => -5.085000000000001
+/// use voca_rs::Voca;
+/// "The cat sat on the mat."._automated_readability_index();
+/// // => -5.085000000000001
+/// ```
+pub fn automated_readability_index(subject: &str) -> f64 {
+    let counts = text_counts(subject);
+    if counts.words == 0 {
+        return 0.0;
+    }
+    4.71 * (counts.letters as f64 / counts.words as f64) + 0.5 * words_per_sentence(&counts) - 21.43
+}
+
+fn words_per_sentence(counts: &TextCounts) -> f64 {
+    counts.words as f64 / counts.sentences as f64
+}
@@ -0,0 +1,6593 @@
+//! Detects the spoofing with the visually confusable characters, following Unicode Technical Standard #39.
+
+use crate::query::{Script, SCRIPTS};
+use unicode_normalization::UnicodeNormalization;
+
+/// Returns the skeleton of `subject` as defined by Unicode Technical Standard #39: the NFD form of `subject` without the
+/// default ignorable characters, with every character replaced by its prototype from the confusables table, normalized
+/// to NFD again. The visually confusable strings have the same skeleton.
+/// NOTE: The skeleton is meant for comparison only, it is not a normalization and should not be displayed.
+/// The compatibility characters like the fullwidth "Ｇ" are not folded, normalize `subject` to NFKC first if needed.
+///
+/// # Arguments
+///
+/// * `subject` - The string to map.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// security::skeleton("раypal");
+/// // => "paypal"
+/// security::skeleton("microsoft");
+/// // => "rnicrosoft"
+/// security::skeleton("ℌe1\u{200B}1o");
+/// // => "Hello"
+/// use voca_rs::Voca;
+/// "раypal"._skeleton();
+/// // => "paypal"
+/// ```
+pub fn skeleton(subject: &str) -> String {
+    let mut result = String::with_capacity(subject.len());
+    for c in subject.nfd().filter(|&c| !is_default_ignorable(c)) {
+        match CONFUSABLES.binary_search_by_key(&c, |&(confusable, _)| confusable) {
+            Ok(i) => result.push_str(CONFUSABLES[i].1),
+            Err(_) => result.push(c),
+        }
+    }
+    result.nfd().collect()
+}
+
+/// Checks whether `subject` and `other` are visually confusable, i.e. have the same skeleton (see `security::skeleton`).
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+/// * `other` - The string to compare with.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// security::is_confusable("раypal", "paypal");
+/// // => true
+/// security::is_confusable("rnicrosoft", "microsoft");
+/// // => true
+/// security::is_confusable("paypal", "PayPal");
+/// // => false
+/// use voca_rs::Voca;
+/// "раypal"._is_confusable("paypal");
+/// // => true
+/// ```
+pub fn is_confusable(subject: &str, other: &str) -> bool {
+    skeleton(subject) == skeleton(other)
+}
+
+/// Checks whether `subject` mixes the characters of several scripts, e.g. Cyrillic letters in a Latin word.
+/// The opposite of `query::is_single_script`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// security::is_mixed_script("раypal");
+/// // => true
+/// security::is_mixed_script("paypal");
+/// // => false
+/// security::is_mixed_script("日本語のテキスト");
+/// // => false
+/// use voca_rs::Voca;
+/// "раypal"._is_mixed_script();
+/// // => true
+/// ```
+pub fn is_mixed_script(subject: &str) -> bool {
+    !crate::query::is_single_script(subject)
+}
+
+/// The identifier restriction level of UTS #39, from the most to the least restrictive, see `security::restriction_level`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RestrictionLevel {
+    /// Only the ASCII characters.
+    Ascii,
+    /// The characters of a single script.
+    SingleScript,
+    /// Latin together with Han, Hiragana and Katakana; Han and Bopomofo; or Han and Hangul.
+    HighlyRestrictive,
+    /// Latin together with any other single recommended script except Cyrillic and Greek.
+    ModeratelyRestrictive,
+    /// Any mix of the recommended scripts.
+    MinimallyRestrictive,
+    /// The characters of the scripts which are not recommended for identifiers.
+    Unrestricted,
+}
+
+/// Returns the UTS #39 identifier restriction level of `subject`. The levels are ordered, so the identifiers can be checked
+/// against the maximum allowed level. The scripts recommended for identifiers are those of Unicode Standard Annex #31.
+///
+/// # Arguments
+///
+/// * `subject` - The identifier to verify.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::security::RestrictionLevel;
+/// security::restriction_level("paypal");
+/// // => RestrictionLevel::Ascii
+/// security::restriction_level("café");
+/// // => RestrictionLevel::SingleScript
+/// security::restriction_level("Tokyo東京");
+/// // => RestrictionLevel::HighlyRestrictive
+/// security::restriction_level("раypal");
+/// // => RestrictionLevel::MinimallyRestrictive
+/// security::restriction_level("раypal") <= RestrictionLevel::ModeratelyRestrictive;
+/// // => false
+/// use voca_rs::Voca;
+/// "раypal"._restriction_level();
+/// // => RestrictionLevel::MinimallyRestrictive
+/// ```
+pub fn restriction_level(subject: &str) -> RestrictionLevel {
+    if subject.is_ascii() {
+        return RestrictionLevel::Ascii;
+    }
+    if subject
+        .chars()
+        .filter_map(crate::query::char_script)
+        .any(|script| !RECOMMENDED_SCRIPTS.contains(&script))
+    {
+        return RestrictionLevel::Unrestricted;
+    }
+    if crate::query::is_single_script(subject) {
+        return RestrictionLevel::SingleScript;
+    }
+    let is_latin = |i: &usize| SCRIPTS.get(*i) == Some(&Script::Latin);
+    let not_latin = subject
+        .chars()
+        .filter(|&c| !crate::query::resolved_scripts(c).iter().any(is_latin));
+    match crate::query::common_scripts(not_latin) {
+        Some(ref common)
+            if common.iter().any(|&i| {
+                i == crate::query::JAPANESE
+                    || i == crate::query::KOREAN
+                    || i == crate::query::HAN_WITH_BOPOMOFO
+            }) =>
+        {
+            RestrictionLevel::HighlyRestrictive
+        }
+        Some(ref common)
+            if common.iter().any(|&i| {
+                SCRIPTS
+                    .get(i)
+                    .is_some_and(|&script| script != Script::Cyrillic && script != Script::Greek)
+            }) =>
+        {
+            RestrictionLevel::ModeratelyRestrictive
+        }
+        _ => RestrictionLevel::MinimallyRestrictive,
+    }
+}
+
+// UAX #31, Table 5: Recommended Scripts
+const RECOMMENDED_SCRIPTS: [Script; 31] = [
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Greek,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Han,
+    Script::Hangul,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Khmer,
+    Script::Lao,
+    Script::Latin,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+    Script::Common,
+    Script::Inherited,
+];
+
+/// The characters with the Default_Ignorable_Code_Point property of Unicode 16.0, which are removed from the skeleton:
+/// the soft hyphen, the zero width and bidirectional formatting characters, the variation selectors, etc.
+fn is_default_ignorable(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00AD}'
+            | '\u{034F}'
+            | '\u{061C}'
+            | '\u{115F}'..='\u{1160}'
+            | '\u{17B4}'..='\u{17B5}'
+            | '\u{180B}'..='\u{180F}'
+            | '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{206F}'
+            | '\u{3164}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+            | '\u{FFA0}'
+            | '\u{FFF0}'..='\u{FFF8}'
+            | '\u{1BCA0}'..='\u{1BCA3}'
+            | '\u{1D173}'..='\u{1D17A}'
+            | '\u{E0000}'..='\u{E0FFF}'
+    )
+}
+
+/// The confusables table of UTS #39 (`confusables.txt` of Unicode 16.0): the characters sorted by their code points
+/// with their prototypes.
+static CONFUSABLES: [(char, &str); 6355] = [
+    ('"', "''"),
+    ('%', "\u{00BA}/\u{2080}"),
+    ('0', "O"),
+    ('1', "l"),
+    ('I', "l"),
+    ('`', "'"),
+    ('m', "rn"),
+    ('|', "l"),
+    ('\u{00A0}', " "),
+    ('\u{00A2}', "c\u{0338}"),
+    ('\u{00A5}', "Y\u{0335}"),
+    ('\u{00AF}', "\u{02C9}"),
+    ('\u{00B4}', "'"),
+    ('\u{00B5}', "\u{03BC}"),
+    ('\u{00B8}', ","),
+    ('\u{00C6}', "AE"),
+    ('\u{00C7}', "C\u{0326}"),
+    ('\u{00D0}', "D\u{0335}"),
+    ('\u{00D7}', "x"),
+    ('\u{00D8}', "O\u{0338}"),
+    ('\u{00E6}', "ae"),
+    ('\u{00E7}', "c\u{0326}"),
+    ('\u{00F0}', "\u{2202}\u{0335}"),
+    ('\u{00F6}', "\u{0629}"),
+    ('\u{00F8}', "o\u{0338}"),
+    ('\u{0110}', "D\u{0335}"),
+    ('\u{0111}', "d\u{0335}"),
+    ('\u{011A}', "\u{0114}"),
+    ('\u{011B}', "\u{0115}"),
+    ('\u{0126}', "H\u{0335}"),
+    ('\u{0127}', "h\u{0335}"),
+    ('\u{0131}', "i"),
+    ('\u{0132}', "lJ"),
+    ('\u{0133}', "ij"),
+    ('\u{013F}', "l\u{00B7}"),
+    ('\u{0140}', "l\u{00B7}"),
+    ('\u{0141}', "L\u{0338}"),
+    ('\u{0142}', "l\u{0338}"),
+    ('\u{0146}', "\u{0272}"),
+    ('\u{0149}', "'n"),
+    ('\u{0150}', "\u{00D6}"),
+    ('\u{0152}', "OE"),
+    ('\u{0153}', "oe"),
+    ('\u{0163}', "\u{01AB}"),
+    ('\u{0166}', "T\u{0335}"),
+    ('\u{0167}', "t\u{0335}"),
+    ('\u{017F}', "f"),
+    ('\u{0180}', "b\u{0335}"),
+    ('\u{0181}', "'B"),
+    ('\u{0182}', "b\u{0304}"),
+    ('\u{0183}', "b\u{0304}"),
+    ('\u{0184}', "b"),
+    ('\u{0187}', "C'"),
+    ('\u{0189}', "D\u{0335}"),
+    ('\u{018A}', "'D"),
+    ('\u{018C}', "d\u{0304}"),
+    ('\u{018D}', "g"),
+    ('\u{0191}', "F\u{0326}"),
+    ('\u{0192}', "f\u{0326}"),
+    ('\u{0193}', "G'"),
+    ('\u{0196}', "l"),
+    ('\u{0197}', "l\u{0335}"),
+    ('\u{0198}', "K'"),
+    ('\u{0199}', "k\u{0314}"),
+    ('\u{019A}', "l\u{0335}"),
+    ('\u{019B}', "\u{03BB}\u{0338}"),
+    ('\u{019D}', "N\u{0326}"),
+    ('\u{019E}', "n\u{0329}"),
+    ('\u{019F}', "O\u{0335}"),
+    ('\u{01A0}', "O'"),
+    ('\u{01A1}', "o'"),
+    ('\u{01A4}', "'P"),
+    ('\u{01A5}', "p\u{0314}"),
+    ('\u{01A6}', "R"),
+    ('\u{01A7}', "2"),
+    ('\u{01AC}', "'T"),
+    ('\u{01AD}', "t\u{0314}"),
+    ('\u{01AE}', "T\u{0328}"),
+    ('\u{01B3}', "'Y"),
+    ('\u{01B4}', "y\u{0314}"),
+    ('\u{01B5}', "Z\u{0335}"),
+    ('\u{01B6}', "z\u{0335}"),
+    ('\u{01B7}', "3"),
+    ('\u{01BB}', "2\u{0335}"),
+    ('\u{01BC}', "5"),
+    ('\u{01BD}', "s"),
+    ('\u{01BF}', "\u{00FE}"),
+    ('\u{01C0}', "l"),
+    ('\u{01C1}', "ll"),
+    ('\u{01C3}', "!"),
+    ('\u{01C4}', "D\u{017D}"),
+    ('\u{01C5}', "D\u{017E}"),
+    ('\u{01C6}', "d\u{017E}"),
+    ('\u{01C7}', "LJ"),
+    ('\u{01C8}', "Lj"),
+    ('\u{01C9}', "lj"),
+    ('\u{01CA}', "NJ"),
+    ('\u{01CB}', "Nj"),
+    ('\u{01CC}', "nj"),
+    ('\u{01CD}', "\u{0102}"),
+    ('\u{01CE}', "\u{0103}"),
+    ('\u{01CF}', "\u{012C}"),
+    ('\u{01D0}', "\u{012D}"),
+    ('\u{01D1}', "\u{014E}"),
+    ('\u{01D2}', "\u{014F}"),
+    ('\u{01D3}', "\u{016C}"),
+    ('\u{01D4}', "\u{016D}"),
+    ('\u{01E4}', "G\u{0335}"),
+    ('\u{01E5}', "g\u{0335}"),
+    ('\u{01E6}', "\u{011E}"),
+    ('\u{01E7}', "\u{011F}"),
+    ('\u{01F1}', "DZ"),
+    ('\u{01F2}', "Dz"),
+    ('\u{01F3}', "dz"),
+    ('\u{01F5}', "\u{0123}"),
+    ('\u{01FE}', "O\u{0338}\u{0301}"),
+    ('\u{021A}', "\u{0162}"),
+    ('\u{021B}', "\u{01AB}"),
+    ('\u{021C}', "3"),
+    ('\u{0222}', "8"),
+    ('\u{0223}', "8"),
+    ('\u{0224}', "Z\u{0326}"),
+    ('\u{0225}', "z\u{0326}"),
+    ('\u{0226}', "\u{00C5}"),
+    ('\u{0227}', "\u{00E5}"),
+    ('\u{023C}', "c\u{0338}"),
+    ('\u{023E}', "T\u{0338}"),
+    ('\u{0241}', "?"),
+    ('\u{0244}', "U\u{0335}"),
+    ('\u{0246}', "E\u{0338}"),
+    ('\u{0247}', "e\u{0338}"),
+    ('\u{0248}', "J\u{0335}"),
+    ('\u{0249}', "j\u{0335}"),
+    ('\u{024D}', "r\u{0335}"),
+    ('\u{024E}', "Y\u{0335}"),
+    ('\u{024F}', "y\u{0335}"),
+    ('\u{0251}', "a"),
+    ('\u{0253}', "b\u{0314}"),
+    ('\u{0256}', "d\u{0328}"),
+    ('\u{0257}', "d\u{0314}"),
+    ('\u{0259}', "\u{01DD}"),
+    ('\u{025A}', "\u{01DD}\u{02DE}"),
+    ('\u{025B}', "\u{A793}"),
+    ('\u{0260}', "g\u{0314}"),
+    ('\u{0261}', "g"),
+    ('\u{0263}', "y"),
+    ('\u{0266}', "h\u{0314}"),
+    ('\u{0268}', "i\u{0335}"),
+    ('\u{0269}', "i"),
+    ('\u{026A}', "i"),
+    ('\u{026B}', "l\u{0334}"),
+    ('\u{026D}', "l\u{0328}"),
+    ('\u{026E}', "l\u{021D}"),
+    ('\u{026F}', "w"),
+    ('\u{0271}', "rn\u{0326}"),
+    ('\u{0273}', "n\u{0328}"),
+    ('\u{0275}', "o\u{0335}"),
+    ('\u{0276}', "o\u{1D07}"),
+    ('\u{027C}', "r\u{0329}"),
+    ('\u{027D}', "r\u{0328}"),
+    ('\u{0282}', "s\u{0328}"),
+    ('\u{028B}', "u"),
+    ('\u{028F}', "y"),
+    ('\u{0290}', "z\u{0328}"),
+    ('\u{0292}', "\u{021D}"),
+    ('\u{0294}', "?"),
+    ('\u{02A0}', "q\u{0314}"),
+    ('\u{02A3}', "dz"),
+    ('\u{02A4}', "d\u{021D}"),
+    ('\u{02A5}', "d\u{0291}"),
+    ('\u{02A6}', "ts"),
+    ('\u{02A7}', "t\u{0283}"),
+    ('\u{02A8}', "t\u{0255}"),
+    ('\u{02A9}', "f\u{014B}"),
+    ('\u{02AA}', "ls"),
+    ('\u{02AB}', "lz"),
+    ('\u{02B3}', "\u{18F4}"),
+    ('\u{02B9}', "'"),
+    ('\u{02BA}', "''"),
+    ('\u{02BB}', "'"),
+    ('\u{02BC}', "'"),
+    ('\u{02BD}', "'"),
+    ('\u{02BE}', "'"),
+    ('\u{02BF}', "\u{0559}"),
+    ('\u{02C2}', "<"),
+    ('\u{02C3}', ">"),
+    ('\u{02C4}', "^"),
+    ('\u{02C6}', "^"),
+    ('\u{02C8}', "'"),
+    ('\u{02CA}', "'"),
+    ('\u{02CB}', "'"),
+    ('\u{02D0}', ":"),
+    ('\u{02D3}', "\u{0559}"),
+    ('\u{02D7}', "-"),
+    ('\u{02D8}', "\u{02C7}"),
+    ('\u{02D9}', "\u{0971}"),
+    ('\u{02DA}', "\u{00B0}"),
+    ('\u{02DB}', "i"),
+    ('\u{02DC}', "~"),
+    ('\u{02DD}', "''"),
+    ('\u{02E1}', "\u{18F3}"),
+    ('\u{02E2}', "\u{18F5}"),
+    ('\u{02E4}', "\u{02C1}"),
+    ('\u{02EE}', "''"),
+    ('\u{02F4}', "'"),
+    ('\u{02F6}', "''"),
+    ('\u{02F8}', ":"),
+    ('\u{02FB}', "\u{02EA}"),
+    ('\u{0305}', "\u{0304}"),
+    ('\u{030C}', "\u{0306}"),
+    ('\u{030D}', "\u{0670}"),
+    ('\u{0310}', "\u{0306}\u{0307}"),
+    ('\u{0311}', "\u{0302}"),
+    ('\u{0315}', "\u{0313}"),
+    ('\u{0317}', "\u{0650}"),
+    ('\u{0320}', "\u{0331}"),
+    ('\u{0321}', "\u{0326}"),
+    ('\u{0322}', "\u{0328}"),
+    ('\u{0327}', "\u{0326}"),
+    ('\u{0336}', "\u{0335}"),
+    ('\u{0337}', "\u{0338}"),
+    ('\u{0339}', "\u{0326}"),
+    ('\u{0340}', "\u{0300}"),
+    ('\u{0341}', "\u{0301}"),
+    ('\u{0342}', "\u{0303}"),
+    ('\u{0343}', "\u{0313}"),
+    ('\u{0345}', "\u{0328}"),
+    ('\u{0347}', "\u{0333}"),
+    ('\u{0357}', "\u{0350}"),
+    ('\u{0358}', "\u{0307}"),
+    ('\u{0366}', "\u{030A}"),
+    ('\u{036E}', "\u{0306}"),
+    ('\u{0370}', "\u{2C75}"),
+    ('\u{0374}', "'"),
+    ('\u{0375}', "\u{02CF}"),
+    ('\u{0376}', "\u{0418}"),
+    ('\u{0377}', "\u{1D0E}"),
+    ('\u{037A}', "i"),
+    ('\u{037B}', "\u{0254}"),
+    ('\u{037D}', "\u{A73F}"),
+    ('\u{037E}', ";"),
+    ('\u{037F}', "J"),
+    ('\u{0384}', "'"),
+    ('\u{0387}', "\u{00B7}"),
+    ('\u{0391}', "A"),
+    ('\u{0392}', "B"),
+    ('\u{0395}', "E"),
+    ('\u{0396}', "Z"),
+    ('\u{0397}', "H"),
+    ('\u{0398}', "O\u{0335}"),
+    ('\u{0399}', "l"),
+    ('\u{039A}', "K"),
+    ('\u{039B}', "\u{0245}"),
+    ('\u{039C}', "M"),
+    ('\u{039D}', "N"),
+    ('\u{039F}', "O"),
+    ('\u{03A1}', "P"),
+    ('\u{03A3}', "\u{01A9}"),
+    ('\u{03A4}', "T"),
+    ('\u{03A5}', "Y"),
+    ('\u{03A7}', "X"),
+    ('\u{03B1}', "a"),
+    ('\u{03B2}', "\u{00DF}"),
+    ('\u{03B3}', "y"),
+    ('\u{03B4}', "\u{1E9F}"),
+    ('\u{03B5}', "\u{A793}"),
+    ('\u{03B7}', "n\u{0329}"),
+    ('\u{03B8}', "O\u{0335}"),
+    ('\u{03B9}', "i"),
+    ('\u{03BA}', "\u{0138}"),
+    ('\u{03BD}', "v"),
+    ('\u{03BF}', "o"),
+    ('\u{03C1}', "p"),
+    ('\u{03C3}', "o"),
+    ('\u{03C4}', "\u{1D1B}"),
+    ('\u{03C5}', "u"),
+    ('\u{03C6}', "\u{0278}"),
+    ('\u{03D0}', "\u{00DF}"),
+    ('\u{03D1}', "O\u{0335}"),
+    ('\u{03D2}', "Y"),
+    ('\u{03D5}', "\u{0278}"),
+    ('\u{03D6}', "\u{03C0}"),
+    ('\u{03DB}', "\u{03C2}"),
+    ('\u{03DC}', "F"),
+    ('\u{03E8}', "2"),
+    ('\u{03E9}', "\u{01A8}"),
+    ('\u{03F0}', "\u{0138}"),
+    ('\u{03F1}', "p"),
+    ('\u{03F2}', "c"),
+    ('\u{03F3}', "j"),
+    ('\u{03F4}', "O\u{0335}"),
+    ('\u{03F5}', "\u{A793}"),
+    ('\u{03F7}', "\u{00DE}"),
+    ('\u{03F8}', "\u{00FE}"),
+    ('\u{03F9}', "C"),
+    ('\u{03FA}', "M"),
+    ('\u{03FD}', "\u{0186}"),
+    ('\u{03FF}', "\u{A73E}"),
+    ('\u{0404}', "\u{A792}"),
+    ('\u{0405}', "S"),
+    ('\u{0406}', "l"),
+    ('\u{0408}', "J"),
+    ('\u{0410}', "A"),
+    ('\u{0411}', "b\u{0304}"),
+    ('\u{0412}', "B"),
+    ('\u{0413}', "\u{0393}"),
+    ('\u{0415}', "E"),
+    ('\u{0417}', "3"),
+    ('\u{0419}', "\u{040D}"),
+    ('\u{041A}', "K"),
+    ('\u{041B}', "\u{0245}"),
+    ('\u{041C}', "M"),
+    ('\u{041D}', "H"),
+    ('\u{041E}', "O"),
+    ('\u{041F}', "\u{03A0}"),
+    ('\u{0420}', "P"),
+    ('\u{0421}', "C"),
+    ('\u{0422}', "T"),
+    ('\u{0423}', "Y"),
+    ('\u{0424}', "\u{03A6}"),
+    ('\u{0425}', "X"),
+    ('\u{042B}', "bl"),
+    ('\u{042C}', "b"),
+    ('\u{042E}', "lO"),
+    ('\u{0430}', "a"),
+    ('\u{0431}', "6"),
+    ('\u{0432}', "\u{0299}"),
+    ('\u{0433}', "r"),
+    ('\u{0435}', "e"),
+    ('\u{0437}', "\u{025C}"),
+    ('\u{0438}', "\u{1D0E}"),
+    ('\u{043A}', "\u{0138}"),
+    ('\u{043C}', "\u{028D}"),
+    ('\u{043D}', "\u{029C}"),
+    ('\u{043E}', "o"),
+    ('\u{043F}', "\u{03C0}"),
+    ('\u{0440}', "p"),
+    ('\u{0441}', "c"),
+    ('\u{0442}', "\u{1D1B}"),
+    ('\u{0443}', "y"),
+    ('\u{0444}', "\u{0278}"),
+    ('\u{0445}', "x"),
+    ('\u{044A}', "\u{02C9}b"),
+    ('\u{044B}', "\u{0185}i"),
+    ('\u{044C}', "\u{0185}"),
+    ('\u{044F}', "\u{1D19}"),
+    ('\u{0454}', "\u{A793}"),
+    ('\u{0455}', "s"),
+    ('\u{0456}', "i"),
+    ('\u{0458}', "j"),
+    ('\u{045B}', "h\u{0335}"),
+    ('\u{045D}', "\u{0439}"),
+    ('\u{0461}', "w"),
+    ('\u{0462}', "b\u{0335}"),
+    ('\u{0463}', "b\u{0335}"),
+    ('\u{0470}', "\u{03A8}"),
+    ('\u{0471}', "\u{03C8}"),
+    ('\u{0472}', "O\u{0335}"),
+    ('\u{0473}', "o\u{0335}"),
+    ('\u{0474}', "V"),
+    ('\u{0475}', "v"),
+    ('\u{047C}', "\u{0460}\u{0486}\u{0487}"),
+    ('\u{047D}', "w\u{0486}\u{0487}"),
+    ('\u{048A}', "\u{040D}\u{0326}"),
+    ('\u{048B}', "\u{0439}\u{0326}"),
+    ('\u{048C}', "b\u{0335}"),
+    ('\u{048D}', "b\u{0335}"),
+    ('\u{0490}', "\u{0393}'"),
+    ('\u{0491}', "r'"),
+    ('\u{0492}', "\u{0393}\u{0335}"),
+    ('\u{0493}', "r\u{0335}"),
+    ('\u{0496}', "\u{0416}\u{0329}"),
+    ('\u{0497}', "\u{0436}\u{0329}"),
+    ('\u{0498}', "3\u{0326}"),
+    ('\u{0499}', "\u{025C}\u{0326}"),
+    ('\u{049A}', "K\u{0329}"),
+    ('\u{049B}', "\u{0138}\u{0329}"),
+    ('\u{049E}', "K\u{0335}"),
+    ('\u{049F}', "\u{0138}\u{0335}"),
+    ('\u{04A2}', "H\u{0329}"),
+    ('\u{04A3}', "\u{029C}\u{0329}"),
+    ('\u{04AA}', "C\u{0326}"),
+    ('\u{04AB}', "c\u{0326}"),
+    ('\u{04AC}', "T\u{0329}"),
+    ('\u{04AD}', "\u{1D1B}\u{0329}"),
+    ('\u{04AE}', "Y"),
+    ('\u{04AF}', "y"),
+    ('\u{04B0}', "Y\u{0335}"),
+    ('\u{04B1}', "y\u{0335}"),
+    ('\u{04B2}', "X\u{0329}"),
+    ('\u{04BB}', "h"),
+    ('\u{04BD}', "e"),
+    ('\u{04BE}', "\u{04BC}\u{0328}"),
+    ('\u{04BF}', "e\u{0328}"),
+    ('\u{04C0}', "l"),
+    ('\u{04C5}', "\u{0245}\u{0326}"),
+    ('\u{04C6}', "\u{043B}\u{0326}"),
+    ('\u{04C7}', "H\u{0326}"),
+    ('\u{04C8}', "\u{029C}\u{0326}"),
+    ('\u{04C9}', "H\u{0326}"),
+    ('\u{04CA}', "\u{029C}\u{0326}"),
+    ('\u{04CB}', "\u{04B6}"),
+    ('\u{04CC}', "\u{04B7}"),
+    ('\u{04CD}', "M\u{0326}"),
+    ('\u{04CE}', "\u{028D}\u{0326}"),
+    ('\u{04CF}', "i"),
+    ('\u{04D4}', "AE"),
+    ('\u{04D5}', "ae"),
+    ('\u{04D8}', "\u{018F}"),
+    ('\u{04D9}', "\u{01DD}"),
+    ('\u{04E0}', "3"),
+    ('\u{04E1}', "\u{021D}"),
+    ('\u{04E8}', "O\u{0335}"),
+    ('\u{04E9}', "o\u{0335}"),
+    ('\u{0501}', "d"),
+    ('\u{050A}', "\u{01F6}"),
+    ('\u{050C}', "G"),
+    ('\u{050D}', "\u{0262}"),
+    ('\u{0510}', "\u{0190}"),
+    ('\u{0511}', "\u{A793}"),
+    ('\u{051B}', "q"),
+    ('\u{051C}', "W"),
+    ('\u{051D}', "w"),
+    ('\u{053B}', "\u{12AE}"),
+    ('\u{0544}', "\u{1206}"),
+    ('\u{054A}', "\u{1323}"),
+    ('\u{054C}', "\u{1261}"),
+    ('\u{054D}', "U"),
+    ('\u{054F}', "S"),
+    ('\u{0553}', "\u{03A6}"),
+    ('\u{0555}', "O"),
+    ('\u{055A}', "'"),
+    ('\u{055D}', "'"),
+    ('\u{0561}', "w"),
+    ('\u{0563}', "q"),
+    ('\u{0566}', "q"),
+    ('\u{056E}', "\u{1E9F}"),
+    ('\u{0570}', "h"),
+    ('\u{0575}', "\u{0237}"),
+    ('\u{0578}', "n"),
+    ('\u{057A}', "\u{0270}"),
+    ('\u{057C}', "n"),
+    ('\u{057D}', "u"),
+    ('\u{0581}', "g"),
+    ('\u{0584}', "f"),
+    ('\u{0585}', "o"),
+    ('\u{0587}', "\u{0565}\u{0582}"),
+    ('\u{0589}', ":"),
+    ('\u{059C}', "\u{0301}"),
+    ('\u{059D}', "\u{0301}"),
+    ('\u{05A4}', "\u{059A}"),
+    ('\u{05A8}', "\u{0599}"),
+    ('\u{05AD}', "\u{0596}"),
+    ('\u{05AE}', "\u{0598}"),
+    ('\u{05AF}', "\u{030A}"),
+    ('\u{05B4}', "\u{0323}"),
+    ('\u{05B9}', "\u{0307}"),
+    ('\u{05BA}', "\u{0307}"),
+    ('\u{05C0}', "l"),
+    ('\u{05C1}', "\u{0307}"),
+    ('\u{05C2}', "\u{0307}"),
+    ('\u{05C3}', ":"),
+    ('\u{05C4}', "\u{0307}"),
+    ('\u{05C5}', "\u{0323}"),
+    ('\u{05D5}', "l"),
+    ('\u{05D8}', "v"),
+    ('\u{05D9}', "'"),
+    ('\u{05DF}', "l"),
+    ('\u{05E1}', "o"),
+    ('\u{05F0}', "ll"),
+    ('\u{05F1}', "l'"),
+    ('\u{05F2}', "''"),
+    ('\u{05F3}', "'"),
+    ('\u{05F4}', "''"),
+    ('\u{0609}', "\u{00BA}/\u{2080}\u{2080}"),
+    ('\u{060A}', "\u{00BA}/\u{2080}\u{2080}\u{2080}"),
+    ('\u{060D}', ","),
+    ('\u{060F}', "\u{0639}"),
+    ('\u{0618}', "\u{0301}"),
+    ('\u{0619}', "\u{0313}"),
+    ('\u{061A}', "\u{0650}"),
+    ('\u{0623}', "l\u{0674}"),
+    ('\u{0624}', "\u{0648}\u{0674}"),
+    ('\u{0625}', "l\u{0655}"),
+    ('\u{0626}', "\u{0649}\u{0674}"),
+    ('\u{0627}', "l"),
+    ('\u{062B}', "\u{0649}\u{06DB}"),
+    ('\u{0634}', "\u{0633}\u{06DB}"),
+    ('\u{063D}', "\u{0649}\u{0302}"),
+    ('\u{063F}', "\u{0649}\u{06DB}"),
+    ('\u{0647}', "o"),
+    ('\u{064A}', "\u{0649}"),
+    ('\u{064B}', "\u{030B}"),
+    ('\u{064E}', "\u{0301}"),
+    ('\u{064F}', "\u{0313}"),
+    ('\u{0652}', "\u{030A}"),
+    ('\u{0653}', "\u{0303}"),
+    ('\u{0656}', "\u{0329}"),
+    ('\u{0657}', "\u{0312}"),
+    ('\u{0658}', "\u{0306}"),
+    ('\u{0659}', "\u{0304}"),
+    ('\u{065A}', "\u{0306}"),
+    ('\u{065B}', "\u{0302}"),
+    ('\u{065C}', "\u{0323}"),
+    ('\u{065D}', "\u{0314}"),
+    ('\u{065F}', "\u{0655}"),
+    ('\u{0660}', "."),
+    ('\u{0661}', "l"),
+    ('\u{0665}', "o"),
+    ('\u{0667}', "V"),
+    ('\u{0668}', "\u{0245}"),
+    ('\u{066A}', "\u{00BA}/\u{2080}"),
+    ('\u{066B}', ","),
+    ('\u{066C}', "\u{060C}"),
+    ('\u{066D}', "*"),
+    ('\u{066E}', "\u{0649}"),
+    ('\u{066F}', "\u{06A1}"),
+    ('\u{0672}', "l\u{0674}"),
+    ('\u{0673}', "l\u{0655}"),
+    ('\u{0675}', "l\u{0674}"),
+    ('\u{0676}', "\u{0648}\u{0674}"),
+    ('\u{0677}', "\u{0648}\u{0313}\u{0674}"),
+    ('\u{0678}', "\u{0649}\u{0674}"),
+    ('\u{0679}', "\u{0649}\u{0615}"),
+    ('\u{067E}', "\u{0649}\u{06DB}"),
+    ('\u{0681}', "\u{062D}\u{0654}"),
+    ('\u{0685}', "\u{062D}\u{06DB}"),
+    ('\u{0688}', "\u{062F}\u{0615}"),
+    ('\u{068B}', "\u{068A}\u{0615}"),
+    ('\u{068E}', "\u{062F}\u{06DB}"),
+    ('\u{0691}', "\u{0631}\u{0615}"),
+    ('\u{0692}', "\u{0631}\u{0306}"),
+    ('\u{0698}', "\u{0631}\u{06DB}"),
+    ('\u{069E}', "\u{0635}\u{06DB}"),
+    ('\u{069F}', "\u{0637}\u{06DB}"),
+    ('\u{06A4}', "\u{06A1}\u{06DB}"),
+    ('\u{06A7}', "\u{0641}"),
+    ('\u{06A8}', "\u{06A1}\u{06DB}"),
+    ('\u{06A9}', "\u{0643}"),
+    ('\u{06AA}', "\u{0643}"),
+    ('\u{06AD}', "\u{0643}\u{06DB}"),
+    ('\u{06B4}', "\u{06AF}\u{06DB}"),
+    ('\u{06B5}', "\u{0644}\u{0306}"),
+    ('\u{06B7}', "\u{0644}\u{06DB}"),
+    ('\u{06BA}', "\u{0649}"),
+    ('\u{06BB}', "\u{0649}\u{0615}"),
+    ('\u{06BD}', "\u{0649}\u{06DB}"),
+    ('\u{06BE}', "o"),
+    ('\u{06C1}', "o"),
+    ('\u{06C2}', "\u{06C0}"),
+    ('\u{06C3}', "\u{0629}"),
+    ('\u{06C6}', "\u{0648}\u{0306}"),
+    ('\u{06C7}', "\u{0648}\u{0313}"),
+    ('\u{06C8}', "\u{0648}\u{0670}"),
+    ('\u{06C9}', "\u{0648}\u{0302}"),
+    ('\u{06CB}', "\u{0648}\u{06DB}"),
+    ('\u{06CC}', "\u{0649}"),
+    ('\u{06CE}', "\u{0649}\u{0306}"),
+    ('\u{06D0}', "\u{067B}"),
+    ('\u{06D1}', "\u{0649}\u{06DB}"),
+    ('\u{06D2}', "\u{0649}"),
+    ('\u{06D4}', "-"),
+    ('\u{06D5}', "o"),
+    ('\u{06DF}', "\u{030A}"),
+    ('\u{06E8}', "\u{0306}\u{0307}"),
+    ('\u{06EC}', "\u{0307}"),
+    ('\u{06EE}', "\u{062F}\u{0302}"),
+    ('\u{06EF}', "\u{0631}\u{0302}"),
+    ('\u{06F0}', "."),
+    ('\u{06F1}', "l"),
+    ('\u{06F2}', "\u{0662}"),
+    ('\u{06F3}', "\u{0663}"),
+    ('\u{06F4}', "\u{0664}"),
+    ('\u{06F5}', "o"),
+    ('\u{06F6}', "\u{0666}"),
+    ('\u{06F7}', "V"),
+    ('\u{06F8}', "\u{0245}"),
+    ('\u{06F9}', "\u{0669}"),
+    ('\u{06FD}', "\u{0621}\u{0348}"),
+    ('\u{06FE}', "\u{0645}\u{0348}"),
+    ('\u{06FF}', "o\u{0302}"),
+    ('\u{0701}', "."),
+    ('\u{0702}', "."),
+    ('\u{0703}', ":"),
+    ('\u{0704}', ":"),
+    ('\u{0740}', "\u{0307}"),
+    ('\u{0741}', "\u{0307}"),
+    ('\u{0742}', "\u{073C}"),
+    ('\u{0747}', "\u{0301}"),
+    ('\u{0751}', "\u{0628}\u{06DB}"),
+    ('\u{0756}', "\u{0649}\u{0306}"),
+    ('\u{0762}', "\u{06AC}"),
+    ('\u{0763}', "\u{0643}\u{06DB}"),
+    ('\u{0767}', "\u{0754}"),
+    ('\u{0768}', "\u{0646}\u{0615}"),
+    ('\u{0769}', "\u{0646}\u{0306}"),
+    ('\u{076C}', "\u{0631}\u{0654}"),
+    ('\u{0771}', "\u{0697}\u{0615}"),
+    ('\u{0772}', "\u{062D}\u{0654}"),
+    ('\u{077E}', "\u{0633}\u{0302}"),
+    ('\u{07C0}', "O"),
+    ('\u{07CA}', "l"),
+    ('\u{07EB}', "\u{0304}"),
+    ('\u{07ED}', "\u{0307}"),
+    ('\u{07EE}', "\u{0302}"),
+    ('\u{07F3}', "\u{0308}"),
+    ('\u{07F4}', "'"),
+    ('\u{07F5}', "'"),
+    ('\u{07FA}', "_"),
+    ('\u{08A1}', "\u{0628}\u{0654}"),
+    ('\u{08A4}', "\u{06A2}\u{06DB}"),
+    ('\u{08A7}', "\u{0645}\u{06DB}"),
+    ('\u{08A8}', "\u{0649}\u{0654}"),
+    ('\u{08A9}', "\u{0754}"),
+    ('\u{08AE}', "\u{062F}\u{0324}\u{0323}"),
+    ('\u{08AF}', "\u{0635}\u{0324}\u{0323}"),
+    ('\u{08B0}', "\u{06AF}"),
+    ('\u{08B1}', "\u{0648}"),
+    ('\u{08B2}', "\u{0632}\u{0302}"),
+    ('\u{08B6}', "\u{0628}\u{06E2}"),
+    ('\u{08B7}', "\u{0649}\u{06DB}\u{06E2}"),
+    ('\u{08B9}', "\u{0631}\u{0306}\u{0307}"),
+    ('\u{08BA}', "\u{0649}\u{0306}\u{0307}"),
+    ('\u{08BB}', "\u{06A1}"),
+    ('\u{08BC}', "\u{06A1}"),
+    ('\u{08BD}', "\u{0649}"),
+    ('\u{08E5}', "\u{064C}"),
+    ('\u{08E8}', "\u{064C}"),
+    ('\u{08EA}', "\u{0307}"),
+    ('\u{08EB}', "\u{0308}"),
+    ('\u{08ED}', "\u{0323}"),
+    ('\u{08EE}', "\u{0324}"),
+    ('\u{08F0}', "\u{030B}"),
+    ('\u{08F1}', "\u{064C}"),
+    ('\u{08F2}', "\u{064D}"),
+    ('\u{08F3}', "\u{0313}"),
+    ('\u{08F8}', "\u{0350}"),
+    ('\u{08F9}', "\u{0354}"),
+    ('\u{08FA}', "\u{0355}"),
+    ('\u{08FF}', "\u{0350}"),
+    ('\u{0900}', "\u{0352}"),
+    ('\u{0901}', "\u{0306}\u{0307}"),
+    ('\u{0902}', "\u{0307}"),
+    ('\u{0903}', ":"),
+    ('\u{0904}', "\u{0905}\u{0946}"),
+    ('\u{0906}', "\u{0905}\u{093E}"),
+    ('\u{0908}', "\u{0930}\u{094D}\u{0907}"),
+    ('\u{090D}', "\u{090F}\u{0945}"),
+    ('\u{090E}', "\u{090F}\u{0946}"),
+    ('\u{0910}', "\u{090F}\u{0947}"),
+    ('\u{0911}', "\u{0905}\u{0949}"),
+    ('\u{0912}', "\u{0905}\u{093E}\u{0946}"),
+    ('\u{0913}', "\u{0905}\u{093E}\u{0947}"),
+    ('\u{0914}', "\u{0905}\u{093E}\u{0948}"),
+    ('\u{093C}', "\u{0323}"),
+    ('\u{0952}', "\u{0331}"),
+    ('\u{0953}', "\u{0300}"),
+    ('\u{0954}', "\u{0301}"),
+    ('\u{0965}', "\u{0964}\u{0964}"),
+    ('\u{0966}', "o"),
+    ('\u{0967}', "\u{0669}"),
+    ('\u{097D}', "?"),
+    ('\u{0981}', "\u{0306}\u{0307}"),
+    ('\u{0986}', "\u{0985}\u{09BE}"),
+    ('\u{09BC}', "\u{0323}"),
+    ('\u{09E0}', "\u{098B}\u{09C3}"),
+    ('\u{09E1}', "\u{098B}\u{09C3}"),
+    ('\u{09E6}', "O"),
+    ('\u{09EA}', "8"),
+    ('\u{09ED}', "9"),
+    ('\u{0A02}', "\u{0307}"),
+    ('\u{0A03}', "\u{0983}"),
+    ('\u{0A06}', "\u{0A05}\u{0A3E}"),
+    ('\u{0A07}', "\u{0A72}\u{0A3F}"),
+    ('\u{0A08}', "\u{0A72}\u{0A40}"),
+    ('\u{0A09}', "\u{0A73}\u{0A41}"),
+    ('\u{0A0A}', "\u{0A73}\u{0A42}"),
+    ('\u{0A0F}', "\u{0A72}\u{0A47}"),
+    ('\u{0A10}', "\u{0A05}\u{0A48}"),
+    ('\u{0A14}', "\u{0A05}\u{0A4C}"),
+    ('\u{0A3C}', "\u{0323}"),
+    ('\u{0A4B}', "\u{0946}"),
+    ('\u{0A4D}', "\u{094D}"),
+    ('\u{0A66}', "o"),
+    ('\u{0A67}', "9"),
+    ('\u{0A6A}', "8"),
+    ('\u{0A81}', "\u{0306}\u{0307}"),
+    ('\u{0A82}', "\u{0307}"),
+    ('\u{0A83}', ":"),
+    ('\u{0A86}', "\u{0A85}\u{0ABE}"),
+    ('\u{0A8D}', "\u{0A85}\u{0AC5}"),
+    ('\u{0A8F}', "\u{0A85}\u{0AC7}"),
+    ('\u{0A90}', "\u{0A85}\u{0AC8}"),
+    ('\u{0A91}', "\u{0A85}\u{0ABE}\u{0AC5}"),
+    ('\u{0A93}', "\u{0A85}\u{0ABE}\u{0AC7}"),
+    ('\u{0A94}', "\u{0A85}\u{0ABE}\u{0AC8}"),
+    ('\u{0ABC}', "\u{0323}"),
+    ('\u{0ABD}', "\u{093D}"),
+    ('\u{0AC1}', "\u{0941}"),
+    ('\u{0AC2}', "\u{0942}"),
+    ('\u{0ACD}', "\u{094D}"),
+    ('\u{0AE6}', "o"),
+    ('\u{0AE8}', "\u{0968}"),
+    ('\u{0AE9}', "\u{0969}"),
+    ('\u{0AEA}', "\u{096A}"),
+    ('\u{0AEE}', "\u{096E}"),
+    ('\u{0AF0}', "\u{0970}"),
+    ('\u{0B01}', "\u{0306}\u{0307}"),
+    ('\u{0B03}', "8"),
+    ('\u{0B06}', "\u{0B05}\u{0B3E}"),
+    ('\u{0B20}', "O"),
+    ('\u{0B3C}', "\u{0323}"),
+    ('\u{0B66}', "O"),
+    ('\u{0B68}', "9"),
+    ('\u{0B82}', "\u{030A}"),
+    ('\u{0B8A}', "\u{0B89}\u{0BB3}"),
+    ('\u{0B9C}', "\u{0B90}"),
+    ('\u{0BB0}', "\u{0B88}"),
+    ('\u{0BBE}', "\u{0B88}"),
+    ('\u{0BC8}', "\u{0BA9}"),
+    ('\u{0BCA}', "\u{0BC6}\u{0B88}"),
+    ('\u{0BCB}', "\u{0BC7}\u{0B88}"),
+    ('\u{0BCC}', "\u{0BC6}\u{0BB3}"),
+    ('\u{0BCD}', "\u{0307}"),
+    ('\u{0BD7}', "\u{0BB3}"),
+    ('\u{0BE6}', "o"),
+    ('\u{0BE7}', "\u{0B95}"),
+    ('\u{0BE8}', "\u{0B89}"),
+    ('\u{0BEA}', "\u{0B9A}"),
+    ('\u{0BEB}', "\u{0B88}\u{0BC1}"),
+    ('\u{0BEC}', "\u{0B9A}\u{0BC1}"),
+    ('\u{0BED}', "\u{0B8E}"),
+    ('\u{0BEE}', "\u{0B85}"),
+    ('\u{0BF0}', "\u{0BAF}"),
+    ('\u{0BF2}', "\u{0B9A}\u{0BC2}"),
+    ('\u{0BF4}', "\u{0BAE}\u{0BC0}"),
+    ('\u{0BF5}', "\u{0BF3}"),
+    ('\u{0BF7}', "\u{0B8E}\u{0BB5}"),
+    ('\u{0BF8}', "\u{0BB7}"),
+    ('\u{0BFA}', "\u{0BA8}\u{0BC0}"),
+    ('\u{0C00}', "\u{0306}\u{0307}"),
+    ('\u{0C02}', "o"),
+    ('\u{0C03}', "\u{0983}"),
+    ('\u{0C13}', "\u{0C12}\u{0C55}"),
+    ('\u{0C14}', "\u{0C12}\u{0C4C}"),
+    ('\u{0C20}', "\u{0C30}\u{05BC}"),
+    ('\u{0C22}', "\u{0C21}\u{0323}"),
+    ('\u{0C25}', "\u{0C27}\u{05BC}"),
+    ('\u{0C2D}', "\u{0C2C}\u{0323}"),
+    ('\u{0C2E}', "\u{0C35}\u{0C41}"),
+    ('\u{0C37}', "\u{0C35}\u{0323}"),
+    ('\u{0C39}', "\u{0C35}\u{0C3E}"),
+    ('\u{0C42}', "\u{0C41}\u{0C3E}"),
+    ('\u{0C44}', "\u{0C43}\u{0C3E}"),
+    ('\u{0C60}', "\u{0C0B}\u{0C3E}"),
+    ('\u{0C61}', "\u{0C0C}\u{0C3E}"),
+    ('\u{0C66}', "o"),
+    ('\u{0C81}', "\u{0306}\u{0307}"),
+    ('\u{0C82}', "o"),
+    ('\u{0C83}', "\u{0983}"),
+    ('\u{0C85}', "\u{0C05}"),
+    ('\u{0C86}', "\u{0C06}"),
+    ('\u{0C87}', "\u{0C07}"),
+    ('\u{0C92}', "\u{0C12}"),
+    ('\u{0C93}', "\u{0C12}\u{0C55}"),
+    ('\u{0C94}', "\u{0C12}\u{0C4C}"),
+    ('\u{0C9C}', "\u{0C1C}"),
+    ('\u{0C9E}', "\u{0C1E}"),
+    ('\u{0CA3}', "\u{0C23}"),
+    ('\u{0CAF}', "\u{0C2F}"),
+    ('\u{0CB1}', "\u{0C31}"),
+    ('\u{0CB2}', "\u{0C32}"),
+    ('\u{0CE1}', "\u{0C8C}\u{0CBE}"),
+    ('\u{0CE6}', "o"),
+    ('\u{0CE7}', "\u{0C67}"),
+    ('\u{0CE8}', "\u{0C68}"),
+    ('\u{0CEF}', "\u{0C6F}"),
+    ('\u{0D01}', "\u{0306}\u{0307}"),
+    ('\u{0D02}', "o"),
+    ('\u{0D03}', "\u{0983}"),
+    ('\u{0D08}', "\u{0D07}\u{0D57}"),
+    ('\u{0D09}', "\u{0B89}"),
+    ('\u{0D0A}', "\u{0B89}\u{0D57}"),
+    ('\u{0D0C}', "\u{0D28}\u{0D41}"),
+    ('\u{0D10}', "\u{0D0E}\u{0D46}"),
+    ('\u{0D13}', "\u{0D12}\u{0D3E}"),
+    ('\u{0D14}', "\u{0D12}\u{0D57}"),
+    ('\u{0D19}', "\u{0D28}\u{0D41}"),
+    ('\u{0D1C}', "\u{0B90}"),
+    ('\u{0D20}', "o"),
+    ('\u{0D23}', "\u{0BA3}"),
+    ('\u{0D31}', "\u{0D30}"),
+    ('\u{0D34}', "\u{0BB4}"),
+    ('\u{0D36}', "\u{0BB6}"),
+    ('\u{0D3A}', "\u{0B9F}\u{0BBF}"),
+    ('\u{0D3F}', "\u{0BBF}"),
+    ('\u{0D40}', "\u{0BBF}"),
+    ('\u{0D42}', "\u{0D41}"),
+    ('\u{0D43}', "\u{0D41}"),
+    ('\u{0D48}', "\u{0D46}\u{0D46}"),
+    ('\u{0D4E}', "\u{0971}"),
+    ('\u{0D5A}', "\u{0D28}\u{0D4D}\u{0D2E}"),
+    ('\u{0D5F}', "o\u{0D30}o"),
+    ('\u{0D61}', "\u{0D1E}"),
+    ('\u{0D66}', "o"),
+    ('\u{0D6A}', "\u{0D30}\u{0D4D}"),
+    ('\u{0D6B}', "\u{0D26}\u{0D4D}\u{0D30}"),
+    ('\u{0D6C}', "\u{0D28}\u{0D4D}\u{0D28}"),
+    ('\u{0D6D}', "9"),
+    ('\u{0D6E}', "\u{0D35}\u{0D4D}\u{0D30}"),
+    ('\u{0D6F}', "\u{0D28}\u{0D4D}"),
+    ('\u{0D76}', "\u{0D39}\u{0D4D}\u{0D2E}"),
+    ('\u{0D79}', "\u{0D28}\u{0D41}"),
+    ('\u{0D7B}', "\u{0D28}\u{0D4D}"),
+    ('\u{0D7C}', "\u{0D30}\u{0D4D}"),
+    ('\u{0D82}', "o"),
+    ('\u{0D83}', "\u{0983}"),
+    ('\u{0DE9}', "\u{0DE8}\u{0DCF}"),
+    ('\u{0DEA}', "\u{0DA2}"),
+    ('\u{0DEB}', "\u{0DAF}"),
+    ('\u{0DEF}', "\u{0DE8}\u{0DD3}"),
+    ('\u{0E03}', "\u{0E02}"),
+    ('\u{0E0B}', "\u{0E0A}"),
+    ('\u{0E0F}', "\u{0E0E}"),
+    ('\u{0E14}', "\u{0E04}"),
+    ('\u{0E15}', "\u{0E04}"),
+    ('\u{0E17}', "\u{0E11}"),
+    ('\u{0E21}', "\u{0E06}"),
+    ('\u{0E26}', "\u{0E20}"),
+    ('\u{0E33}', "\u{030A}\u{0E32}"),
+    ('\u{0E41}', "\u{0E40}\u{0E40}"),
+    ('\u{0E45}', "\u{0E32}"),
+    ('\u{0E4D}', "\u{030A}"),
+    ('\u{0E50}', "o"),
+    ('\u{0E88}', "\u{0E08}"),
+    ('\u{0E8D}', "\u{0E22}"),
+    ('\u{0E9A}', "\u{0E1A}"),
+    ('\u{0E9B}', "\u{0E1B}"),
+    ('\u{0E9D}', "\u{0E1D}"),
+    ('\u{0E9E}', "\u{0E1E}"),
+    ('\u{0E9F}', "\u{0E1F}"),
+    ('\u{0EB3}', "\u{030A}\u{0EB2}"),
+    ('\u{0EB8}', "\u{0E38}"),
+    ('\u{0EB9}', "\u{0E39}"),
+    ('\u{0EC8}', "\u{0E48}"),
+    ('\u{0EC9}', "\u{0E49}"),
+    ('\u{0ECA}', "\u{0E4A}"),
+    ('\u{0ECB}', "\u{0E4B}"),
+    ('\u{0ECD}', "\u{030A}"),
+    ('\u{0ED0}', "o"),
+    ('\u{0EDC}', "\u{0EAB}\u{0E99}"),
+    ('\u{0EDD}', "\u{0EAB}\u{0EA1}"),
+    ('\u{0F00}', "\u{0F68}\u{0F7C}\u{0F7E}"),
+    ('\u{0F02}', "\u{0F60}\u{0F74}\u{0F82}\u{0F7F}"),
+    ('\u{0F03}', "\u{0F60}\u{0F74}\u{0F82}\u{0F14}"),
+    ('\u{0F0C}', "\u{0F0B}"),
+    ('\u{0F0E}', "\u{0F0D}\u{0F0D}"),
+    ('\u{0F1B}', "\u{0F1A}\u{0F1A}"),
+    ('\u{0F1E}', "\u{0F1D}\u{0F1D}"),
+    ('\u{0F1F}', "\u{0F1A}\u{0F1D}"),
+    ('\u{0F37}', "\u{0325}"),
+    ('\u{0F6A}', "\u{0F62}"),
+    ('\u{0F77}', "\u{0FB2}\u{0F71}\u{0F80}"),
+    ('\u{0F79}', "\u{0FB3}\u{0F71}\u{0F80}"),
+    ('\u{0FCE}', "\u{0F1D}\u{0F1A}"),
+    ('\u{0FD5}', "\u{5350}"),
+    ('\u{0FD6}', "\u{534D}"),
+    ('\u{1000}', "\u{1002}\u{102C}"),
+    ('\u{1010}', "o\u{102C}"),
+    ('\u{101D}', "o"),
+    ('\u{101F}', "\u{1015}\u{102C}"),
+    ('\u{1029}', "\u{101E}\u{103C}"),
+    ('\u{102A}', "\u{101E}\u{103C}\u{1031}\u{102C}\u{103A}"),
+    ('\u{1036}', "\u{030A}"),
+    ('\u{1038}', "\u{0983}"),
+    ('\u{1040}', "o"),
+    ('\u{104B}', "\u{104A}\u{104A}"),
+    ('\u{1065}', "\u{1041}"),
+    ('\u{1066}', "\u{1015}\u{103E}"),
+    ('\u{106F}', "\u{1015}\u{102C}\u{103E}"),
+    ('\u{1070}', "\u{1003}\u{103E}"),
+    ('\u{107E}', "\u{107D}\u{103E}"),
+    ('\u{1081}', "\u{1002}\u{103E}"),
+    ('\u{109E}', "\u{1083}\u{030A}"),
+    ('\u{10A0}', "\u{A786}"),
+    ('\u{10E7}', "y"),
+    ('\u{10F3}', "\u{021D}"),
+    ('\u{10FF}', "o"),
+    ('\u{1101}', "\u{1100}\u{1100}"),
+    ('\u{1104}', "\u{1103}\u{1103}"),
+    ('\u{1108}', "\u{1107}\u{1107}"),
+    ('\u{110A}', "\u{1109}\u{1109}"),
+    ('\u{110D}', "\u{110C}\u{110C}"),
+    ('\u{1113}', "\u{1102}\u{1100}"),
+    ('\u{1114}', "\u{1102}\u{1102}"),
+    ('\u{1115}', "\u{1102}\u{1103}"),
+    ('\u{1116}', "\u{1102}\u{1107}"),
+    ('\u{1117}', "\u{1103}\u{1100}"),
+    ('\u{1118}', "\u{1105}\u{1102}"),
+    ('\u{1119}', "\u{1105}\u{1105}"),
+    ('\u{111A}', "\u{1105}\u{1112}"),
+    ('\u{111B}', "\u{1105}\u{110B}"),
+    ('\u{111C}', "\u{1106}\u{1107}"),
+    ('\u{111D}', "\u{1106}\u{110B}"),
+    ('\u{111E}', "\u{1107}\u{1100}"),
+    ('\u{111F}', "\u{1107}\u{1102}"),
+    ('\u{1120}', "\u{1107}\u{1103}"),
+    ('\u{1121}', "\u{1107}\u{1109}"),
+    ('\u{1122}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{1123}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{1124}', "\u{1107}\u{1109}\u{1107}"),
+    ('\u{1125}', "\u{1107}\u{1109}\u{1109}"),
+    ('\u{1126}', "\u{1107}\u{1109}\u{110C}"),
+    ('\u{1127}', "\u{1107}\u{110C}"),
+    ('\u{1128}', "\u{1107}\u{110E}"),
+    ('\u{1129}', "\u{1107}\u{1110}"),
+    ('\u{112A}', "\u{1107}\u{1111}"),
+    ('\u{112B}', "\u{1107}\u{110B}"),
+    ('\u{112C}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{112D}', "\u{1109}\u{1100}"),
+    ('\u{112E}', "\u{1109}\u{1102}"),
+    ('\u{112F}', "\u{1109}\u{1103}"),
+    ('\u{1130}', "\u{1109}\u{1105}"),
+    ('\u{1131}', "\u{1109}\u{1106}"),
+    ('\u{1132}', "\u{1109}\u{1107}"),
+    ('\u{1133}', "\u{1109}\u{1107}\u{1100}"),
+    ('\u{1134}', "\u{1109}\u{1109}\u{1109}"),
+    ('\u{1135}', "\u{1109}\u{110B}"),
+    ('\u{1136}', "\u{1109}\u{110C}"),
+    ('\u{1137}', "\u{1109}\u{110E}"),
+    ('\u{1138}', "\u{1109}\u{110F}"),
+    ('\u{1139}', "\u{1109}\u{1110}"),
+    ('\u{113A}', "\u{1109}\u{1111}"),
+    ('\u{113B}', "\u{1105}\u{1112}"),
+    ('\u{113D}', "\u{113C}\u{113C}"),
+    ('\u{113F}', "\u{113E}\u{113E}"),
+    ('\u{1141}', "\u{110B}\u{1100}"),
+    ('\u{1142}', "\u{110B}\u{1103}"),
+    ('\u{1143}', "\u{110B}\u{1106}"),
+    ('\u{1144}', "\u{110B}\u{1107}"),
+    ('\u{1145}', "\u{110B}\u{1109}"),
+    ('\u{1146}', "\u{110B}\u{1140}"),
+    ('\u{1147}', "\u{110B}\u{110B}"),
+    ('\u{1148}', "\u{110B}\u{110C}"),
+    ('\u{1149}', "\u{110B}\u{110E}"),
+    ('\u{114A}', "\u{110B}\u{1110}"),
+    ('\u{114B}', "\u{110B}\u{1111}"),
+    ('\u{114D}', "\u{110C}\u{110B}"),
+    ('\u{114F}', "\u{114E}\u{114E}"),
+    ('\u{1151}', "\u{1150}\u{1150}"),
+    ('\u{1152}', "\u{110E}\u{110F}"),
+    ('\u{1153}', "\u{110E}\u{1112}"),
+    ('\u{1156}', "\u{1111}\u{1107}"),
+    ('\u{1157}', "\u{1111}\u{110B}"),
+    ('\u{1158}', "\u{1112}\u{1112}"),
+    ('\u{115A}', "\u{1100}\u{1103}"),
+    ('\u{115B}', "\u{1102}\u{1109}"),
+    ('\u{115C}', "\u{1102}\u{110C}"),
+    ('\u{115D}', "\u{1102}\u{1112}"),
+    ('\u{115E}', "\u{1103}\u{1105}"),
+    ('\u{1162}', "\u{1161}\u{4E28}"),
+    ('\u{1164}', "\u{1163}\u{4E28}"),
+    ('\u{1166}', "\u{1165}\u{4E28}"),
+    ('\u{1168}', "\u{1167}\u{4E28}"),
+    ('\u{116A}', "\u{1169}\u{1161}"),
+    ('\u{116B}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{116C}', "\u{1169}\u{4E28}"),
+    ('\u{116F}', "\u{116E}\u{1165}"),
+    ('\u{1170}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{1171}', "\u{116E}\u{4E28}"),
+    ('\u{1173}', "\u{30FC}"),
+    ('\u{1174}', "\u{30FC}\u{4E28}"),
+    ('\u{1175}', "\u{4E28}"),
+    ('\u{1176}', "\u{1161}\u{1169}"),
+    ('\u{1177}', "\u{1161}\u{116E}"),
+    ('\u{1178}', "\u{1163}\u{1169}"),
+    ('\u{1179}', "\u{1163}\u{116D}"),
+    ('\u{117A}', "\u{1165}\u{1169}"),
+    ('\u{117B}', "\u{1165}\u{116E}"),
+    ('\u{117C}', "\u{1165}\u{30FC}"),
+    ('\u{117D}', "\u{1167}\u{1169}"),
+    ('\u{117E}', "\u{1167}\u{116E}"),
+    ('\u{117F}', "\u{1169}\u{1165}"),
+    ('\u{1180}', "\u{1169}\u{1165}\u{4E28}"),
+    ('\u{1181}', "\u{1169}\u{1167}\u{4E28}"),
+    ('\u{1182}', "\u{1169}\u{1169}"),
+    ('\u{1183}', "\u{1169}\u{116E}"),
+    ('\u{1184}', "\u{116D}\u{1163}"),
+    ('\u{1185}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{1186}', "\u{116D}\u{1163}"),
+    ('\u{1187}', "\u{116D}\u{1169}"),
+    ('\u{1188}', "\u{116D}\u{4E28}"),
+    ('\u{1189}', "\u{116E}\u{1161}"),
+    ('\u{118A}', "\u{116E}\u{1161}\u{4E28}"),
+    ('\u{118B}', "\u{116E}\u{1165}\u{30FC}"),
+    ('\u{118C}', "\u{116E}\u{1167}\u{4E28}"),
+    ('\u{118D}', "\u{116E}\u{116E}"),
+    ('\u{118E}', "\u{1172}\u{1161}"),
+    ('\u{118F}', "\u{1172}\u{1165}"),
+    ('\u{1190}', "\u{1172}\u{1165}\u{4E28}"),
+    ('\u{1191}', "\u{1172}\u{1167}"),
+    ('\u{1192}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{1193}', "\u{1172}\u{116E}"),
+    ('\u{1194}', "\u{1172}\u{4E28}"),
+    ('\u{1195}', "\u{30FC}\u{116E}"),
+    ('\u{1196}', "\u{30FC}\u{30FC}"),
+    ('\u{1197}', "\u{30FC}\u{4E28}\u{116E}"),
+    ('\u{1198}', "\u{4E28}\u{1161}"),
+    ('\u{1199}', "\u{4E28}\u{1163}"),
+    ('\u{119A}', "\u{4E28}\u{1169}"),
+    ('\u{119B}', "\u{4E28}\u{116E}"),
+    ('\u{119C}', "\u{4E28}\u{30FC}"),
+    ('\u{119D}', "\u{4E28}\u{119E}"),
+    ('\u{119F}', "\u{119E}\u{1165}"),
+    ('\u{11A0}', "\u{119E}\u{116E}"),
+    ('\u{11A1}', "\u{119E}\u{4E28}"),
+    ('\u{11A2}', "\u{119E}\u{119E}"),
+    ('\u{11A3}', "\u{1161}\u{30FC}"),
+    ('\u{11A4}', "\u{1163}\u{116E}"),
+    ('\u{11A5}', "\u{1167}\u{1163}"),
+    ('\u{11A6}', "\u{1169}\u{1163}"),
+    ('\u{11A7}', "\u{1169}\u{1163}\u{4E28}"),
+    ('\u{11A8}', "\u{1100}"),
+    ('\u{11A9}', "\u{1100}\u{1100}"),
+    ('\u{11AA}', "\u{1100}\u{1109}"),
+    ('\u{11AB}', "\u{1102}"),
+    ('\u{11AC}', "\u{1102}\u{110C}"),
+    ('\u{11AD}', "\u{1102}\u{1112}"),
+    ('\u{11AE}', "\u{1103}"),
+    ('\u{11AF}', "\u{1105}"),
+    ('\u{11B0}', "\u{1105}\u{1100}"),
+    ('\u{11B1}', "\u{1105}\u{1106}"),
+    ('\u{11B2}', "\u{1105}\u{1107}"),
+    ('\u{11B3}', "\u{1105}\u{1109}"),
+    ('\u{11B4}', "\u{1105}\u{1110}"),
+    ('\u{11B5}', "\u{1105}\u{1111}"),
+    ('\u{11B6}', "\u{1105}\u{1112}"),
+    ('\u{11B7}', "\u{1106}"),
+    ('\u{11B8}', "\u{1107}"),
+    ('\u{11B9}', "\u{1107}\u{1109}"),
+    ('\u{11BA}', "\u{1109}"),
+    ('\u{11BB}', "\u{1109}\u{1109}"),
+    ('\u{11BC}', "\u{110B}"),
+    ('\u{11BD}', "\u{110C}"),
+    ('\u{11BE}', "\u{110E}"),
+    ('\u{11BF}', "\u{110F}"),
+    ('\u{11C0}', "\u{1110}"),
+    ('\u{11C1}', "\u{1111}"),
+    ('\u{11C2}', "\u{1112}"),
+    ('\u{11C3}', "\u{1100}\u{1105}"),
+    ('\u{11C4}', "\u{1100}\u{1109}\u{1100}"),
+    ('\u{11C5}', "\u{1102}\u{1100}"),
+    ('\u{11C6}', "\u{1102}\u{1103}"),
+    ('\u{11C7}', "\u{1102}\u{1109}"),
+    ('\u{11C8}', "\u{1102}\u{1140}"),
+    ('\u{11C9}', "\u{1102}\u{1110}"),
+    ('\u{11CA}', "\u{1103}\u{1100}"),
+    ('\u{11CB}', "\u{1103}\u{1105}"),
+    ('\u{11CC}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{11CD}', "\u{1105}\u{1102}"),
+    ('\u{11CE}', "\u{1105}\u{1103}"),
+    ('\u{11CF}', "\u{1105}\u{1103}\u{1112}"),
+    ('\u{11D0}', "\u{1105}\u{1105}"),
+    ('\u{11D1}', "\u{1105}\u{1106}\u{1100}"),
+    ('\u{11D2}', "\u{1105}\u{1106}\u{1109}"),
+    ('\u{11D3}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{11D4}', "\u{1105}\u{1107}\u{1112}"),
+    ('\u{11D5}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{11D6}', "\u{1105}\u{1109}\u{1109}"),
+    ('\u{11D7}', "\u{1105}\u{1140}"),
+    ('\u{11D8}', "\u{1105}\u{110F}"),
+    ('\u{11D9}', "\u{1105}\u{1159}"),
+    ('\u{11DA}', "\u{1106}\u{1100}"),
+    ('\u{11DB}', "\u{1106}\u{1105}"),
+    ('\u{11DC}', "\u{1106}\u{1107}"),
+    ('\u{11DD}', "\u{1106}\u{1109}"),
+    ('\u{11DE}', "\u{1106}\u{1109}\u{1109}"),
+    ('\u{11DF}', "\u{1106}\u{1140}"),
+    ('\u{11E0}', "\u{1106}\u{110E}"),
+    ('\u{11E1}', "\u{1106}\u{1112}"),
+    ('\u{11E2}', "\u{1106}\u{110B}"),
+    ('\u{11E3}', "\u{1107}\u{1105}"),
+    ('\u{11E4}', "\u{1107}\u{1111}"),
+    ('\u{11E5}', "\u{1107}\u{1112}"),
+    ('\u{11E6}', "\u{1107}\u{110B}"),
+    ('\u{11E7}', "\u{1109}\u{1100}"),
+    ('\u{11E8}', "\u{1109}\u{1103}"),
+    ('\u{11E9}', "\u{1109}\u{1105}"),
+    ('\u{11EA}', "\u{1109}\u{1107}"),
+    ('\u{11EB}', "\u{1140}"),
+    ('\u{11EC}', "\u{110B}\u{1100}"),
+    ('\u{11ED}', "\u{110B}\u{1100}\u{1100}"),
+    ('\u{11EE}', "\u{110B}\u{110B}"),
+    ('\u{11EF}', "\u{110B}\u{110F}"),
+    ('\u{11F0}', "\u{114C}"),
+    ('\u{11F1}', "\u{110B}\u{1109}"),
+    ('\u{11F2}', "\u{110B}\u{1140}"),
+    ('\u{11F3}', "\u{1111}\u{1107}"),
+    ('\u{11F4}', "\u{1111}\u{110B}"),
+    ('\u{11F5}', "\u{1112}\u{1102}"),
+    ('\u{11F6}', "\u{1112}\u{1105}"),
+    ('\u{11F7}', "\u{1112}\u{1106}"),
+    ('\u{11F8}', "\u{1112}\u{1107}"),
+    ('\u{11F9}', "\u{1159}"),
+    ('\u{11FA}', "\u{1100}\u{1102}"),
+    ('\u{11FB}', "\u{1100}\u{1107}"),
+    ('\u{11FC}', "\u{1100}\u{110E}"),
+    ('\u{11FD}', "\u{1100}\u{110F}"),
+    ('\u{11FE}', "\u{1100}\u{1112}"),
+    ('\u{11FF}', "\u{1102}\u{1102}"),
+    ('\u{1200}', "U"),
+    ('\u{1223}', "\u{0270}"),
+    ('\u{1240}', "\u{03A6}"),
+    ('\u{1260}', "\u{0548}"),
+    ('\u{1294}', "\u{0571}"),
+    ('\u{12D0}', "O"),
+    ('\u{13A0}', "D"),
+    ('\u{13A1}', "R"),
+    ('\u{13A2}', "T"),
+    ('\u{13A4}', "O'"),
+    ('\u{13A5}', "i"),
+    ('\u{13A8}', "\u{2C75}"),
+    ('\u{13A9}', "Y"),
+    ('\u{13AA}', "A"),
+    ('\u{13AB}', "J"),
+    ('\u{13AC}', "E"),
+    ('\u{13AE}', "?"),
+    ('\u{13B0}', "\u{2C75}"),
+    ('\u{13B1}', "\u{0393}"),
+    ('\u{13B3}', "W"),
+    ('\u{13B7}', "M"),
+    ('\u{13BB}', "H"),
+    ('\u{13BD}', "Y"),
+    ('\u{13BE}', "O\u{0335}"),
+    ('\u{13BF}', "\u{01AB}"),
+    ('\u{13C0}', "G"),
+    ('\u{13C2}', "h"),
+    ('\u{13C3}', "Z"),
+    ('\u{13C7}', "\u{0460}"),
+    ('\u{13CB}', "\u{0190}"),
+    ('\u{13CC}', "U\u{0335}"),
+    ('\u{13CE}', "4"),
+    ('\u{13CF}', "b"),
+    ('\u{13D2}', "R"),
+    ('\u{13D4}', "W"),
+    ('\u{13D5}', "S"),
+    ('\u{13D9}', "V"),
+    ('\u{13DA}', "S"),
+    ('\u{13DE}', "L"),
+    ('\u{13DF}', "C"),
+    ('\u{13E2}', "P"),
+    ('\u{13E6}', "K"),
+    ('\u{13E7}', "d"),
+    ('\u{13EB}', "O\u{0335}"),
+    ('\u{13EE}', "6"),
+    ('\u{13F0}', "\u{00DF}"),
+    ('\u{13F2}', "h\u{0314}"),
+    ('\u{13F3}', "G"),
+    ('\u{13F4}', "B"),
+    ('\u{13FB}', "\u{0262}"),
+    ('\u{13FC}', "\u{0299}"),
+    ('\u{1400}', "="),
+    ('\u{1403}', "\u{0394}"),
+    ('\u{140C}', "\u{00B7}\u{1401}"),
+    ('\u{140D}', "\u{1401}\u{00B7}"),
+    ('\u{140E}', "\u{00B7}\u{0394}"),
+    ('\u{140F}', "\u{0394}\u{00B7}"),
+    ('\u{1410}', "\u{00B7}\u{1404}"),
+    ('\u{1411}', "\u{1404}\u{00B7}"),
+    ('\u{1412}', "\u{00B7}\u{1405}"),
+    ('\u{1413}', "\u{1405}\u{00B7}"),
+    ('\u{1414}', "\u{00B7}\u{1406}"),
+    ('\u{1415}', "\u{1406}\u{00B7}"),
+    ('\u{1417}', "\u{00B7}\u{140A}"),
+    ('\u{1418}', "\u{140A}\u{00B7}"),
+    ('\u{1419}', "\u{00B7}\u{140B}"),
+    ('\u{141A}', "\u{140B}\u{00B7}"),
+    ('\u{1427}', "\u{00B7}"),
+    ('\u{142B}', "\u{1401}\u{1420}"),
+    ('\u{142C}', "\u{0394}\u{1420}"),
+    ('\u{142D}', "\u{1405}\u{1420}"),
+    ('\u{142E}', "\u{140A}\u{1420}"),
+    ('\u{142F}', "V"),
+    ('\u{1431}', "\u{0245}"),
+    ('\u{1433}', ">"),
+    ('\u{1437}', "\u{00B7}>"),
+    ('\u{1438}', "<"),
+    ('\u{143A}', "\u{00B7}V"),
+    ('\u{143B}', "V\u{00B7}"),
+    ('\u{143C}', "\u{00B7}\u{0245}"),
+    ('\u{143D}', "\u{0245}\u{00B7}"),
+    ('\u{143E}', "\u{00B7}\u{1432}"),
+    ('\u{143F}', "\u{1432}\u{00B7}"),
+    ('\u{1440}', "\u{00B7}>"),
+    ('\u{1441}', ">\u{00B7}"),
+    ('\u{1442}', "\u{00B7}\u{1434}"),
+    ('\u{1443}', "\u{1434}\u{00B7}"),
+    ('\u{1444}', "\u{00B7}<"),
+    ('\u{1445}', "<\u{00B7}"),
+    ('\u{1446}', "\u{00B7}\u{1439}"),
+    ('\u{1447}', "\u{1439}\u{00B7}"),
+    ('\u{144A}', "'"),
+    ('\u{144C}', "U"),
+    ('\u{144E}', "\u{0548}"),
+    ('\u{1454}', "\u{00B7}\u{1450}"),
+    ('\u{1457}', "\u{00B7}U"),
+    ('\u{1458}', "U\u{00B7}"),
+    ('\u{1459}', "\u{00B7}\u{0548}"),
+    ('\u{145A}', "\u{0548}\u{00B7}"),
+    ('\u{145B}', "\u{00B7}\u{144F}"),
+    ('\u{145C}', "\u{144F}\u{00B7}"),
+    ('\u{145D}', "\u{00B7}\u{1450}"),
+    ('\u{145E}', "\u{1450}\u{00B7}"),
+    ('\u{145F}', "\u{00B7}\u{1451}"),
+    ('\u{1460}', "\u{1451}\u{00B7}"),
+    ('\u{1461}', "\u{00B7}\u{1455}"),
+    ('\u{1462}', "\u{1455}\u{00B7}"),
+    ('\u{1463}', "\u{00B7}\u{1456}"),
+    ('\u{1464}', "\u{1456}\u{00B7}"),
+    ('\u{1467}', "U'"),
+    ('\u{1468}', "\u{0548}'"),
+    ('\u{1469}', "\u{1450}'"),
+    ('\u{146A}', "\u{1455}'"),
+    ('\u{146D}', "P"),
+    ('\u{146F}', "d"),
+    ('\u{1472}', "b"),
+    ('\u{1473}', "b\u{0307}"),
+    ('\u{1474}', "\u{00B7}\u{146B}"),
+    ('\u{1475}', "\u{146B}\u{00B7}"),
+    ('\u{1476}', "\u{00B7}P"),
+    ('\u{1477}', "p\u{00B7}"),
+    ('\u{1478}', "\u{00B7}\u{146E}"),
+    ('\u{1479}', "\u{146E}\u{00B7}"),
+    ('\u{147A}', "\u{00B7}d"),
+    ('\u{147B}', "d\u{00B7}"),
+    ('\u{147C}', "\u{00B7}\u{1470}"),
+    ('\u{147D}', "\u{1470}\u{00B7}"),
+    ('\u{147E}', "\u{00B7}b"),
+    ('\u{147F}', "b\u{00B7}"),
+    ('\u{1480}', "\u{00B7}b\u{0307}"),
+    ('\u{1481}', "b\u{0307}\u{00B7}"),
+    ('\u{1485}', "\u{146B}'"),
+    ('\u{1486}', "P'"),
+    ('\u{1487}', "d'"),
+    ('\u{1488}', "b'"),
+    ('\u{148D}', "J"),
+    ('\u{1492}', "\u{00B7}\u{1489}"),
+    ('\u{1493}', "\u{1489}\u{00B7}"),
+    ('\u{1494}', "\u{00B7}\u{148B}"),
+    ('\u{1495}', "\u{148B}\u{00B7}"),
+    ('\u{1496}', "\u{00B7}\u{148C}"),
+    ('\u{1497}', "\u{148C}\u{00B7}"),
+    ('\u{1498}', "\u{00B7}J"),
+    ('\u{1499}', "J\u{00B7}"),
+    ('\u{149A}', "\u{00B7}\u{148E}"),
+    ('\u{149B}', "\u{148E}\u{00B7}"),
+    ('\u{149C}', "\u{00B7}\u{1490}"),
+    ('\u{149D}', "\u{1490}\u{00B7}"),
+    ('\u{149E}', "\u{00B7}\u{1491}"),
+    ('\u{149F}', "\u{1491}\u{00B7}"),
+    ('\u{14A5}', "\u{0393}"),
+    ('\u{14AA}', "L"),
+    ('\u{14AC}', "\u{00B7}\u{14A3}"),
+    ('\u{14AD}', "\u{14A3}\u{00B7}"),
+    ('\u{14AE}', "\u{00B7}\u{0393}"),
+    ('\u{14AF}', "\u{0393}\u{00B7}"),
+    ('\u{14B0}', "\u{00B7}\u{14A6}"),
+    ('\u{14B1}', "\u{14A6}\u{00B7}"),
+    ('\u{14B2}', "\u{00B7}\u{14A7}"),
+    ('\u{14B3}', "\u{14A7}\u{00B7}"),
+    ('\u{14B4}', "\u{00B7}\u{14A8}"),
+    ('\u{14B5}', "\u{14A8}\u{00B7}"),
+    ('\u{14B6}', "\u{00B7}L"),
+    ('\u{14B7}', "l\u{00B7}"),
+    ('\u{14B8}', "\u{00B7}\u{14AB}"),
+    ('\u{14B9}', "\u{14AB}\u{00B7}"),
+    ('\u{14BF}', "2"),
+    ('\u{14C9}', "\u{00B7}\u{14C0}"),
+    ('\u{14CA}', "\u{14C0}\u{00B7}"),
+    ('\u{14CB}', "\u{00B7}\u{14C7}"),
+    ('\u{14CC}', "\u{14C7}\u{00B7}"),
+    ('\u{14CD}', "\u{00B7}\u{14C8}"),
+    ('\u{14CE}', "\u{14C8}\u{00B7}"),
+    ('\u{14D1}', "\u{1421}"),
+    ('\u{14DC}', "\u{00B7}\u{14D3}"),
+    ('\u{14DD}', "\u{14D3}\u{00B7}"),
+    ('\u{14DE}', "\u{00B7}\u{14D5}"),
+    ('\u{14DF}', "\u{14D5}\u{00B7}"),
+    ('\u{14E0}', "\u{00B7}\u{14D6}"),
+    ('\u{14E1}', "\u{14D6}\u{00B7}"),
+    ('\u{14E2}', "\u{00B7}\u{14D7}"),
+    ('\u{14E3}', "\u{14D7}\u{00B7}"),
+    ('\u{14E4}', "\u{00B7}\u{14D8}"),
+    ('\u{14E5}', "\u{14D8}\u{00B7}"),
+    ('\u{14E6}', "\u{00B7}\u{14DA}"),
+    ('\u{14E7}', "\u{14DA}\u{00B7}"),
+    ('\u{14E8}', "\u{00B7}\u{14DB}"),
+    ('\u{14E9}', "\u{14DB}\u{00B7}"),
+    ('\u{14F6}', "\u{00B7}\u{14ED}"),
+    ('\u{14F7}', "\u{14ED}\u{00B7}"),
+    ('\u{14F8}', "\u{00B7}\u{14EF}"),
+    ('\u{14F9}', "\u{14EF}\u{00B7}"),
+    ('\u{14FA}', "\u{00B7}\u{14F0}"),
+    ('\u{14FB}', "\u{14F0}\u{00B7}"),
+    ('\u{14FC}', "\u{00B7}\u{14F1}"),
+    ('\u{14FD}', "\u{14F1}\u{00B7}"),
+    ('\u{14FE}', "\u{00B7}\u{14F2}"),
+    ('\u{14FF}', "\u{14F2}\u{00B7}"),
+    ('\u{1500}', "\u{00B7}\u{14F4}"),
+    ('\u{1501}', "\u{14F4}\u{00B7}"),
+    ('\u{1502}', "\u{00B7}\u{14F5}"),
+    ('\u{1503}', "\u{14F5}\u{00B7}"),
+    ('\u{150C}', "\u{150B}<"),
+    ('\u{150D}', "\u{150B}\u{1455}"),
+    ('\u{150E}', "\u{150B}b"),
+    ('\u{150F}', "\u{150B}\u{1490}"),
+    ('\u{1517}', "\u{00B7}\u{1510}"),
+    ('\u{1518}', "\u{1510}\u{00B7}"),
+    ('\u{1519}', "\u{00B7}\u{1511}"),
+    ('\u{151A}', "\u{1511}\u{00B7}"),
+    ('\u{151B}', "\u{00B7}\u{1512}"),
+    ('\u{151C}', "\u{1512}\u{00B7}"),
+    ('\u{151D}', "\u{00B7}\u{1513}"),
+    ('\u{151E}', "\u{1513}\u{00B7}"),
+    ('\u{151F}', "\u{00B7}\u{1514}"),
+    ('\u{1520}', "\u{1514}\u{00B7}"),
+    ('\u{1521}', "\u{00B7}\u{1515}"),
+    ('\u{1522}', "\u{1515}\u{00B7}"),
+    ('\u{1523}', "\u{00B7}\u{1516}"),
+    ('\u{1524}', "\u{1516}\u{00B7}"),
+    ('\u{152F}', "\u{00B7}4"),
+    ('\u{1530}', "4\u{00B7}"),
+    ('\u{1531}', "\u{00B7}\u{1528}"),
+    ('\u{1532}', "\u{1528}\u{00B7}"),
+    ('\u{1533}', "\u{00B7}\u{1529}"),
+    ('\u{1534}', "\u{1529}\u{00B7}"),
+    ('\u{1535}', "\u{00B7}\u{152A}"),
+    ('\u{1536}', "\u{152A}\u{00B7}"),
+    ('\u{1537}', "\u{00B7}\u{152B}"),
+    ('\u{1538}', "\u{152B}\u{00B7}"),
+    ('\u{1539}', "\u{00B7}\u{152D}"),
+    ('\u{153A}', "\u{152D}\u{00B7}"),
+    ('\u{153B}', "\u{00B7}\u{152E}"),
+    ('\u{153C}', "\u{152E}\u{00B7}"),
+    ('\u{1540}', "\u{1429}"),
+    ('\u{1541}', "x"),
+    ('\u{154E}', "\u{00B7}\u{154C}"),
+    ('\u{154F}', "\u{154C}\u{00B7}"),
+    ('\u{155B}', "\u{00B7}\u{155A}"),
+    ('\u{155C}', "\u{155A}\u{00B7}"),
+    ('\u{1568}', "\u{00B7}\u{1567}"),
+    ('\u{1569}', "\u{1567}\u{00B7}"),
+    ('\u{1577}', "\u{1E9F}"),
+    ('\u{157C}', "H"),
+    ('\u{157D}', "x"),
+    ('\u{157E}', "\u{1550}\u{146C}"),
+    ('\u{157F}', "\u{1550}P"),
+    ('\u{1580}', "\u{1550}\u{146E}"),
+    ('\u{1581}', "\u{1550}d"),
+    ('\u{1582}', "\u{1550}\u{1470}"),
+    ('\u{1583}', "\u{1550}b"),
+    ('\u{1584}', "\u{1550}b\u{0307}"),
+    ('\u{1585}', "\u{1550}\u{1483}"),
+    ('\u{1587}', "R"),
+    ('\u{158E}', "\u{1595}\u{148A}"),
+    ('\u{158F}', "\u{1595}\u{148B}"),
+    ('\u{1590}', "\u{1595}\u{148C}"),
+    ('\u{1591}', "\u{1595}J"),
+    ('\u{1592}', "\u{1595}\u{148E}"),
+    ('\u{1593}', "\u{1595}\u{1490}"),
+    ('\u{1594}', "\u{1595}\u{1491}"),
+    ('\u{15AF}', "b"),
+    ('\u{15B4}', "F"),
+    ('\u{15B5}', "\u{2132}"),
+    ('\u{15B7}', "\u{A7FB}"),
+    ('\u{15C4}', "\u{2C6F}"),
+    ('\u{15C5}', "A"),
+    ('\u{15DE}', "D"),
+    ('\u{15EA}', "D"),
+    ('\u{15EF}', "\u{0460}"),
+    ('\u{15F0}', "M"),
+    ('\u{15F7}', "B"),
+    ('\u{1602}', "\u{1490}"),
+    ('\u{1603}', "\u{1489}"),
+    ('\u{1604}', "\u{14D3}"),
+    ('\u{1607}', "\u{14DA}"),
+    ('\u{1622}', "\u{1543}"),
+    ('\u{1623}', "\u{1546}"),
+    ('\u{1624}', "\u{154A}"),
+    ('\u{162E}', "\u{01B1}"),
+    ('\u{162F}', "\u{03A9}"),
+    ('\u{1634}', "\u{01B1}"),
+    ('\u{1635}', "\u{03A9}"),
+    ('\u{166D}', "X"),
+    ('\u{166E}', "x"),
+    ('\u{166F}', "\u{1550}\u{146B}"),
+    ('\u{1670}', "\u{1595}\u{1489}"),
+    ('\u{1671}', "\u{1596}\u{148B}"),
+    ('\u{1672}', "\u{1596}\u{148C}"),
+    ('\u{1673}', "\u{1596}J"),
+    ('\u{1674}', "\u{1596}\u{148E}"),
+    ('\u{1675}', "\u{1596}\u{1490}"),
+    ('\u{1676}', "\u{1596}\u{1491}"),
+    ('\u{1677}', "\u{15A7}\u{00B7}"),
+    ('\u{1678}', "\u{15A8}\u{00B7}"),
+    ('\u{1679}', "\u{15A9}\u{00B7}"),
+    ('\u{167A}', "\u{15AA}\u{00B7}"),
+    ('\u{167B}', "\u{15AB}\u{00B7}"),
+    ('\u{167C}', "\u{15AC}\u{00B7}"),
+    ('\u{167D}', "\u{15AD}\u{00B7}"),
+    ('\u{1680}', " "),
+    ('\u{16B2}', "<"),
+    ('\u{16B7}', "X"),
+    ('\u{16C1}', "l"),
+    ('\u{16C2}', "\u{16BD}"),
+    ('\u{16CC}', "'"),
+    ('\u{16D5}', "K"),
+    ('\u{16D6}', "M"),
+    ('\u{16D8}', "\u{03A8}"),
+    ('\u{16E1}', "\u{16BC}"),
+    ('\u{16EB}', "\u{00B7}"),
+    ('\u{16EC}', ":"),
+    ('\u{16ED}', "+"),
+    ('\u{16F0}', "\u{03A6}"),
+    ('\u{1734}', "\u{1715}"),
+    ('\u{1735}', "/"),
+    ('\u{17A3}', "\u{17A2}"),
+    ('\u{17B7}', "\u{0E34}"),
+    ('\u{17B8}', "\u{0E35}"),
+    ('\u{17B9}', "\u{0E36}"),
+    ('\u{17BA}', "\u{0E37}"),
+    ('\u{17C6}', "\u{030A}"),
+    ('\u{17CB}', "\u{0E48}"),
+    ('\u{17D3}', "\u{030A}"),
+    ('\u{17D4}', "\u{0E2F}"),
+    ('\u{17D5}', "\u{0E5A}"),
+    ('\u{17D9}', "\u{0E4F}"),
+    ('\u{17DA}', "\u{0E5B}"),
+    ('\u{1803}', ":"),
+    ('\u{1809}', ":"),
+    ('\u{1855}', "\u{1835}"),
+    ('\u{1896}', "\u{185C}"),
+    ('\u{18B3}', "\u{00B7}\u{18B1}"),
+    ('\u{18B6}', "\u{00B7}\u{18B4}"),
+    ('\u{18B9}', "\u{00B7}\u{18B8}"),
+    ('\u{18C2}', "\u{00B7}\u{18C0}"),
+    ('\u{18C6}', "\u{00B7}\u{14C2}"),
+    ('\u{18C7}', "\u{14C2}\u{00B7}"),
+    ('\u{18C8}', "\u{00B7}\u{14C3}"),
+    ('\u{18C9}', "\u{14C3}\u{00B7}"),
+    ('\u{18CA}', "\u{00B7}\u{14C4}"),
+    ('\u{18CB}', "\u{14C4}\u{00B7}"),
+    ('\u{18CC}', "\u{00B7}\u{14C5}"),
+    ('\u{18CD}', "\u{14C5}\u{00B7}"),
+    ('\u{18CE}', "\u{00B7}\u{1543}"),
+    ('\u{18CF}', "\u{00B7}\u{1546}"),
+    ('\u{18D0}', "\u{00B7}\u{1547}"),
+    ('\u{18D1}', "\u{00B7}\u{1548}"),
+    ('\u{18D2}', "\u{00B7}\u{1549}"),
+    ('\u{18D3}', "\u{00B7}\u{154B}"),
+    ('\u{18DB}', "\u{18F5}"),
+    ('\u{18DC}', "\u{18DF}\u{141E}"),
+    ('\u{18DD}', "\u{141E}\u{18DF}"),
+    ('\u{18E0}', "\u{1543}\u{00B7}"),
+    ('\u{18E3}', "\u{155E}\u{00B7}"),
+    ('\u{18E4}', "\u{1566}\u{00B7}"),
+    ('\u{18E5}', "\u{156B}\u{00B7}"),
+    ('\u{18E8}', "\u{1586}\u{00B7}"),
+    ('\u{18EA}', "\u{1597}\u{00B7}"),
+    ('\u{18ED}', "\u{0460}\u{00B7}"),
+    ('\u{18F0}', "\u{15F4}\u{00B7}"),
+    ('\u{18F2}', "\u{161B}\u{00B7}"),
+    ('\u{19D0}', "\u{199E}"),
+    ('\u{19D1}', "\u{19B1}"),
+    ('\u{1A80}', "\u{1A45}"),
+    ('\u{1A90}', "\u{1A45}"),
+    ('\u{1AA9}', "\u{1AA8}\u{1AA8}"),
+    ('\u{1AAB}', "\u{1AAA}\u{1AA8}"),
+    ('\u{1AB4}', "\u{06DB}"),
+    ('\u{1AB7}', "\u{0328}"),
+    ('\u{1B52}', "\u{1B0D}"),
+    ('\u{1B53}', "\u{1B11}"),
+    ('\u{1B58}', "\u{1B28}"),
+    ('\u{1B5C}', "\u{1B50}"),
+    ('\u{1B5F}', "\u{1B5E}\u{1B5E}"),
+    ('\u{1C3C}', "\u{1C3B}\u{1C3B}"),
+    ('\u{1C7F}', "\u{1C7E}\u{1C7E}"),
+    ('\u{1CD0}', "\u{0302}"),
+    ('\u{1CD2}', "\u{0304}"),
+    ('\u{1CD3}', "''"),
+    ('\u{1CD5}', "\u{032B}"),
+    ('\u{1CD8}', "\u{032E}"),
+    ('\u{1CD9}', "\u{032D}"),
+    ('\u{1CDA}', "\u{030E}"),
+    ('\u{1CDC}', "\u{0329}"),
+    ('\u{1CDD}', "\u{0323}"),
+    ('\u{1CDE}', "\u{0324}"),
+    ('\u{1CED}', "\u{0316}"),
+    ('\u{1D04}', "c"),
+    ('\u{1D08}', "\u{025C}"),
+    ('\u{1D0B}', "\u{0138}"),
+    ('\u{1D0D}', "\u{028D}"),
+    ('\u{1D0F}', "o"),
+    ('\u{1D10}', "\u{0254}"),
+    ('\u{1D11}', "o"),
+    ('\u{1D14}', "\u{01DD}o"),
+    ('\u{1D1C}', "u"),
+    ('\u{1D20}', "v"),
+    ('\u{1D21}', "w"),
+    ('\u{1D22}', "z"),
+    ('\u{1D24}', "\u{01A8}"),
+    ('\u{1D26}', "r"),
+    ('\u{1D27}', "\u{028C}"),
+    ('\u{1D28}', "\u{03C0}"),
+    ('\u{1D29}', "\u{1D18}"),
+    ('\u{1D2B}', "\u{043B}"),
+    ('\u{1D3E}', "\u{18D6}"),
+    ('\u{1D52}', "\u{00BA}"),
+    ('\u{1D6B}', "ue"),
+    ('\u{1D6E}', "f\u{0334}"),
+    ('\u{1D6F}', "rn\u{0334}"),
+    ('\u{1D70}', "n\u{0334}"),
+    ('\u{1D72}', "r\u{0334}"),
+    ('\u{1D73}', "\u{027E}\u{0334}"),
+    ('\u{1D74}', "s\u{0334}"),
+    ('\u{1D75}', "t\u{0334}"),
+    ('\u{1D76}', "z\u{0334}"),
+    ('\u{1D78}', "\u{1D34}"),
+    ('\u{1D7B}', "i\u{0335}"),
+    ('\u{1D7C}', "i\u{0335}"),
+    ('\u{1D7D}', "p\u{0335}"),
+    ('\u{1D7E}', "u\u{0335}"),
+    ('\u{1D7F}', "\u{028A}\u{0335}"),
+    ('\u{1D83}', "g"),
+    ('\u{1D8C}', "y"),
+    ('\u{1D90}', "\u{024B}"),
+    ('\u{1D9F}', "\u{1D4B}"),
+    ('\u{1DA2}', "\u{1D4D}"),
+    ('\u{1DBA}', "\u{18D4}"),
+    ('\u{1DBB}', "\u{1646}"),
+    ('\u{1DEE}', "\u{2DEC}"),
+    ('\u{1E43}', "\u{AB51}"),
+    ('\u{1E9A}', "\u{1EA3}"),
+    ('\u{1E9D}', "f"),
+    ('\u{1E9E}', "\u{00DF}"),
+    ('\u{1EFF}', "y"),
+    ('\u{1F7D}', "\u{1FF4}"),
+    ('\u{1FBD}', "'"),
+    ('\u{1FBE}', "i"),
+    ('\u{1FBF}', "'"),
+    ('\u{1FC0}', "~"),
+    ('\u{1FEF}', "'"),
+    ('\u{1FF6}', "\u{13EF}"),
+    ('\u{1FFD}', "'"),
+    ('\u{1FFE}', "'"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200A}', " "),
+    ('\u{2010}', "-"),
+    ('\u{2011}', "-"),
+    ('\u{2012}', "-"),
+    ('\u{2013}', "-"),
+    ('\u{2014}', "\u{30FC}"),
+    ('\u{2015}', "\u{30FC}"),
+    ('\u{2016}', "ll"),
+    ('\u{2018}', "'"),
+    ('\u{2019}', "'"),
+    ('\u{201A}', ","),
+    ('\u{201B}', "'"),
+    ('\u{201C}', "''"),
+    ('\u{201D}', "''"),
+    ('\u{201F}', "''"),
+    ('\u{2022}', "\u{00B7}"),
+    ('\u{2024}', "."),
+    ('\u{2025}', ".."),
+    ('\u{2026}', "..."),
+    ('\u{2027}', "\u{00B7}"),
+    ('\u{2028}', " "),
+    ('\u{2029}', " "),
+    ('\u{202F}', " "),
+    ('\u{2030}', "\u{00BA}/\u{2080}\u{2080}"),
+    ('\u{2031}', "\u{00BA}/\u{2080}\u{2080}\u{2080}"),
+    ('\u{2032}', "'"),
+    ('\u{2033}', "''"),
+    ('\u{2034}', "'''"),
+    ('\u{2035}', "'"),
+    ('\u{2036}', "''"),
+    ('\u{2037}', "'''"),
+    ('\u{2039}', "<"),
+    ('\u{203A}', ">"),
+    ('\u{203C}', "!!"),
+    ('\u{203E}', "\u{02C9}"),
+    ('\u{2041}', "/"),
+    ('\u{2043}', "-"),
+    ('\u{2044}', "/"),
+    ('\u{2047}', "??"),
+    ('\u{2048}', "?!"),
+    ('\u{2049}', "!?"),
+    ('\u{204E}', "*"),
+    ('\u{2052}', "\u{00BA}/\u{2080}"),
+    ('\u{2053}', "~"),
+    ('\u{2057}', "''''"),
+    ('\u{205A}', ":"),
+    ('\u{205D}', "\u{2D57}"),
+    ('\u{205E}', "\u{2D42}"),
+    ('\u{205F}', " "),
+    ('\u{2070}', "\u{00BA}"),
+    ('\u{2079}', "\u{A770}"),
+    ('\u{20A1}', "C\u{20EB}"),
+    ('\u{20A4}', "\u{00A3}"),
+    ('\u{20A5}', "rn\u{0338}"),
+    ('\u{20A8}', "Rs"),
+    ('\u{20A9}', "W\u{0335}"),
+    ('\u{20AB}', "d\u{0335}\u{0331}"),
+    ('\u{20AC}', "\u{A792}"),
+    ('\u{20AD}', "K\u{0335}"),
+    ('\u{20AE}', "T\u{20EB}"),
+    ('\u{20B6}', "lt"),
+    ('\u{20BD}', "\u{0554}"),
+    ('\u{20DB}', "\u{06DB}"),
+    ('\u{2100}', "a/c"),
+    ('\u{2101}', "a/s"),
+    ('\u{2102}', "C"),
+    ('\u{2103}', "\u{00B0}C"),
+    ('\u{2105}', "c/o"),
+    ('\u{2106}', "c/u"),
+    ('\u{2107}', "\u{0190}"),
+    ('\u{2108}', "\u{042D}"),
+    ('\u{2109}', "\u{00B0}F"),
+    ('\u{210A}', "g"),
+    ('\u{210B}', "H"),
+    ('\u{210C}', "H"),
+    ('\u{210D}', "H"),
+    ('\u{210E}', "h"),
+    ('\u{210F}', "h\u{0335}"),
+    ('\u{2110}', "l"),
+    ('\u{2111}', "l"),
+    ('\u{2112}', "L"),
+    ('\u{2113}', "l"),
+    ('\u{2115}', "N"),
+    ('\u{2116}', "No"),
+    ('\u{2119}', "P"),
+    ('\u{211A}', "Q"),
+    ('\u{211B}', "R"),
+    ('\u{211C}', "R"),
+    ('\u{211D}', "R"),
+    ('\u{2121}', "TEL"),
+    ('\u{2124}', "Z"),
+    ('\u{2126}', "\u{03A9}"),
+    ('\u{2127}', "\u{01B1}"),
+    ('\u{2128}', "Z"),
+    ('\u{2129}', "\u{027F}"),
+    ('\u{212A}', "K"),
+    ('\u{212C}', "B"),
+    ('\u{212D}', "C"),
+    ('\u{212E}', "e"),
+    ('\u{212F}', "e"),
+    ('\u{2130}', "E"),
+    ('\u{2131}', "F"),
+    ('\u{2133}', "M"),
+    ('\u{2134}', "o"),
+    ('\u{2135}', "\u{05D0}"),
+    ('\u{2136}', "\u{05D1}"),
+    ('\u{2137}', "\u{05D2}"),
+    ('\u{2138}', "\u{05D3}"),
+    ('\u{2139}', "i"),
+    ('\u{213B}', "FAX"),
+    ('\u{213C}', "\u{03C0}"),
+    ('\u{213D}', "y"),
+    ('\u{213E}', "\u{0393}"),
+    ('\u{213F}', "\u{03A0}"),
+    ('\u{2140}', "\u{01A9}"),
+    ('\u{2141}', "\u{A4E8}"),
+    ('\u{2142}', "\u{A4F6}"),
+    ('\u{2143}', "\u{16F00}"),
+    ('\u{2145}', "D"),
+    ('\u{2146}', "d"),
+    ('\u{2147}', "e"),
+    ('\u{2148}', "i"),
+    ('\u{2149}', "j"),
+    ('\u{2160}', "l"),
+    ('\u{2161}', "ll"),
+    ('\u{2162}', "lll"),
+    ('\u{2163}', "lV"),
+    ('\u{2164}', "V"),
+    ('\u{2165}', "Vl"),
+    ('\u{2166}', "Vll"),
+    ('\u{2167}', "Vlll"),
+    ('\u{2168}', "lX"),
+    ('\u{2169}', "X"),
+    ('\u{216A}', "Xl"),
+    ('\u{216B}', "Xll"),
+    ('\u{216C}', "L"),
+    ('\u{216D}', "C"),
+    ('\u{216E}', "D"),
+    ('\u{216F}', "M"),
+    ('\u{2170}', "i"),
+    ('\u{2171}', "ii"),
+    ('\u{2172}', "iii"),
+    ('\u{2173}', "iv"),
+    ('\u{2174}', "v"),
+    ('\u{2175}', "vi"),
+    ('\u{2176}', "vii"),
+    ('\u{2177}', "viii"),
+    ('\u{2178}', "ix"),
+    ('\u{2179}', "x"),
+    ('\u{217A}', "xi"),
+    ('\u{217B}', "xii"),
+    ('\u{217C}', "l"),
+    ('\u{217D}', "c"),
+    ('\u{217E}', "d"),
+    ('\u{217F}', "rn"),
+    ('\u{2183}', "\u{0186}"),
+    ('\u{2184}', "\u{0254}"),
+    ('\u{2191}', "\u{16CF}"),
+    ('\u{2195}', "\u{16E8}"),
+    ('\u{21B5}', "\u{21B2}"),
+    ('\u{21BA}', "\u{1F10E}"),
+    ('\u{21BE}', "\u{16DA}"),
+    ('\u{21BF}', "\u{16D0}"),
+    ('\u{2200}', "\u{2C6F}"),
+    ('\u{2203}', "\u{018E}"),
+    ('\u{2206}', "\u{0394}"),
+    ('\u{220F}', "\u{03A0}"),
+    ('\u{2211}', "\u{01A9}"),
+    ('\u{2212}', "-"),
+    ('\u{2214}', "+\u{0307}"),
+    ('\u{2215}', "/"),
+    ('\u{2216}', "\\"),
+    ('\u{2217}', "*"),
+    ('\u{2218}', "\u{00B0}"),
+    ('\u{2219}', "\u{00B7}"),
+    ('\u{221E}', "oo"),
+    ('\u{2223}', "l"),
+    ('\u{2225}', "ll"),
+    ('\u{2228}', "v"),
+    ('\u{2229}', "\u{0548}"),
+    ('\u{222A}', "U"),
+    ('\u{222B}', "\u{0283}"),
+    ('\u{222C}', "\u{0283}\u{0283}"),
+    ('\u{222D}', "\u{0283}\u{0283}\u{0283}"),
+    ('\u{222F}', "\u{222E}\u{222E}"),
+    ('\u{2230}', "\u{222E}\u{222E}\u{222E}"),
+    ('\u{2236}', ":"),
+    ('\u{2238}', "-\u{0307}"),
+    ('\u{223C}', "~"),
+    ('\u{2250}', "=\u{0307}"),
+    ('\u{2251}', "=\u{0307}\u{0323}"),
+    ('\u{2257}', "=\u{030A}"),
+    ('\u{2259}', "=\u{0302}"),
+    ('\u{225A}', "=\u{0306}"),
+    ('\u{225E}', "=\u{036B}"),
+    ('\u{2263}', "\u{2261}"),
+    ('\u{226A}', "<<"),
+    ('\u{226B}', ">>"),
+    ('\u{2282}', "\u{1455}"),
+    ('\u{2283}', "\u{1450}"),
+    ('\u{2295}', "\u{102A8}"),
+    ('\u{2296}', "O\u{0335}"),
+    ('\u{2299}', "\u{0298}"),
+    ('\u{229D}', "O\u{0335}"),
+    ('\u{22A4}', "T"),
+    ('\u{22A5}', "\u{A4D5}"),
+    ('\u{22C0}', "\u{2227}"),
+    ('\u{22C1}', "v"),
+    ('\u{22C2}', "\u{0548}"),
+    ('\u{22C3}', "U"),
+    ('\u{22C4}', "\u{16DC}"),
+    ('\u{22C5}', "\u{00B7}"),
+    ('\u{22C8}', "\u{16DE}"),
+    ('\u{22D6}', "<\u{00B7}"),
+    ('\u{22D7}', "\u{00B7}>"),
+    ('\u{22D8}', "<<<"),
+    ('\u{22D9}', ">>>"),
+    ('\u{22EE}', "\u{2D57}"),
+    ('\u{22EF}', "\u{00B7}\u{00B7}\u{00B7}"),
+    ('\u{22F4}', "\u{A793}"),
+    ('\u{22FF}', "E"),
+    ('\u{2300}', "\u{2205}"),
+    ('\u{2325}', "\u{2324}"),
+    ('\u{2329}', "\u{276C}"),
+    ('\u{232A}', "\u{276D}"),
+    ('\u{2341}', "\u{303C}"),
+    ('\u{2359}', "\u{0394}\u{0332}"),
+    ('\u{235A}', "\u{16DC}\u{0332}"),
+    ('\u{235C}', "\u{00B0}\u{0332}"),
+    ('\u{235F}', "\u{229B}"),
+    ('\u{2361}', "T\u{0308}"),
+    ('\u{2362}', "\u{2207}\u{0308}"),
+    ('\u{2363}', "\u{22C6}\u{0308}"),
+    ('\u{2364}', "\u{00B0}\u{0308}"),
+    ('\u{2365}', "\u{0629}"),
+    ('\u{2368}', "~\u{0308}"),
+    ('\u{2369}', "\u{1435}"),
+    ('\u{236B}', "\u{2207}\u{0334}"),
+    ('\u{236C}', "O\u{0335}"),
+    ('\u{2373}', "i"),
+    ('\u{2374}', "p"),
+    ('\u{2375}', "\u{03C9}"),
+    ('\u{2376}', "a\u{0332}"),
+    ('\u{2377}', "\u{A793}\u{0332}"),
+    ('\u{2378}', "i\u{0332}"),
+    ('\u{2379}', "\u{03C9}\u{0332}"),
+    ('\u{237A}', "a"),
+    ('\u{237F}', "\u{16BD}"),
+    ('\u{239C}', "\u{4E28}"),
+    ('\u{239F}', "\u{4E28}"),
+    ('\u{23A2}', "\u{4E28}"),
+    ('\u{23A5}', "\u{4E28}"),
+    ('\u{23AA}', "\u{4E28}"),
+    ('\u{23AE}', "\u{4E28}"),
+    ('\u{23C1}', "\u{2355}"),
+    ('\u{23C2}', "\u{234E}"),
+    ('\u{23C3}', "\u{234B}"),
+    ('\u{23C6}', "\u{236D}"),
+    ('\u{23E8}', "\u{2081}\u{2080}"),
+    ('\u{23FC}', "\u{23FB}"),
+    ('\u{23FD}', "l"),
+    ('\u{23FE}', "\u{263E}"),
+    ('\u{244A}', "\\\\"),
+    ('\u{2460}', "\u{2780}"),
+    ('\u{2461}', "\u{2781}"),
+    ('\u{2462}', "\u{2782}"),
+    ('\u{2463}', "\u{2783}"),
+    ('\u{2464}', "\u{2784}"),
+    ('\u{2465}', "\u{2785}"),
+    ('\u{2466}', "\u{2786}"),
+    ('\u{2467}', "\u{2787}"),
+    ('\u{2468}', "\u{2788}"),
+    ('\u{2469}', "\u{2789}"),
+    ('\u{2474}', "(l)"),
+    ('\u{2475}', "(2)"),
+    ('\u{2476}', "(3)"),
+    ('\u{2477}', "(4)"),
+    ('\u{2478}', "(5)"),
+    ('\u{2479}', "(6)"),
+    ('\u{247A}', "(7)"),
+    ('\u{247B}', "(8)"),
+    ('\u{247C}', "(9)"),
+    ('\u{247D}', "(lO)"),
+    ('\u{247E}', "(ll)"),
+    ('\u{247F}', "(l2)"),
+    ('\u{2480}', "(l3)"),
+    ('\u{2481}', "(l4)"),
+    ('\u{2482}', "(l5)"),
+    ('\u{2483}', "(l6)"),
+    ('\u{2484}', "(l7)"),
+    ('\u{2485}', "(l8)"),
+    ('\u{2486}', "(l9)"),
+    ('\u{2487}', "(2O)"),
+    ('\u{2488}', "l."),
+    ('\u{2489}', "2."),
+    ('\u{248A}', "3."),
+    ('\u{248B}', "4."),
+    ('\u{248C}', "5."),
+    ('\u{248D}', "6."),
+    ('\u{248E}', "7."),
+    ('\u{248F}', "8."),
+    ('\u{2490}', "9."),
+    ('\u{2491}', "lO."),
+    ('\u{2492}', "ll."),
+    ('\u{2493}', "l2."),
+    ('\u{2494}', "l3."),
+    ('\u{2495}', "l4."),
+    ('\u{2496}', "l5."),
+    ('\u{2497}', "l6."),
+    ('\u{2498}', "l7."),
+    ('\u{2499}', "l8."),
+    ('\u{249A}', "l9."),
+    ('\u{249B}', "2O."),
+    ('\u{249C}', "(a)"),
+    ('\u{249D}', "(b)"),
+    ('\u{249E}', "(c)"),
+    ('\u{249F}', "(d)"),
+    ('\u{24A0}', "(e)"),
+    ('\u{24A1}', "(f)"),
+    ('\u{24A2}', "(g)"),
+    ('\u{24A3}', "(h)"),
+    ('\u{24A4}', "(i)"),
+    ('\u{24A5}', "(j)"),
+    ('\u{24A6}', "(k)"),
+    ('\u{24A7}', "(l)"),
+    ('\u{24A8}', "(rn)"),
+    ('\u{24A9}', "(n)"),
+    ('\u{24AA}', "(o)"),
+    ('\u{24AB}', "(p)"),
+    ('\u{24AC}', "(q)"),
+    ('\u{24AD}', "(r)"),
+    ('\u{24AE}', "(s)"),
+    ('\u{24AF}', "(t)"),
+    ('\u{24B0}', "(u)"),
+    ('\u{24B1}', "(v)"),
+    ('\u{24B2}', "(w)"),
+    ('\u{24B3}', "(x)"),
+    ('\u{24B4}', "(y)"),
+    ('\u{24B5}', "(z)"),
+    ('\u{24B8}', "\u{00A9}"),
+    ('\u{24C5}', "\u{2117}"),
+    ('\u{24C7}', "\u{00AE}"),
+    ('\u{24DB}', "\u{24BE}"),
+    ('\u{24EA}', "\u{1F10D}"),
+    ('\u{2500}', "\u{30FC}"),
+    ('\u{2501}', "\u{30FC}"),
+    ('\u{2503}', "\u{2502}"),
+    ('\u{250F}', "\u{250C}"),
+    ('\u{2523}', "\u{251C}"),
+    ('\u{2571}', "/"),
+    ('\u{2573}', "X"),
+    ('\u{2588}', "\u{220E}"),
+    ('\u{2590}', "\u{258C}"),
+    ('\u{2594}', "\u{02C9}"),
+    ('\u{2597}', "\u{2596}"),
+    ('\u{259D}', "\u{2598}"),
+    ('\u{25A0}', "\u{220E}"),
+    ('\u{25B1}', "\u{23E5}"),
+    ('\u{25B3}', "\u{0394}"),
+    ('\u{25B7}', "\u{22B3}"),
+    ('\u{25B8}', "\u{25B6}"),
+    ('\u{25BA}', "\u{25B6}"),
+    ('\u{25BD}', "\u{102BC}"),
+    ('\u{25C1}', "\u{22B2}"),
+    ('\u{25C7}', "\u{16DC}"),
+    ('\u{25CA}', "\u{16DC}"),
+    ('\u{25CB}', "\u{00B0}"),
+    ('\u{25CE}', "\u{233E}"),
+    ('\u{25E0}', "\u{2312}"),
+    ('\u{25E6}', "\u{00B0}"),
+    ('\u{2609}', "\u{0298}"),
+    ('\u{2610}', "\u{25A1}"),
+    ('\u{2625}', "\u{1099E}"),
+    ('\u{2630}', "\u{2CB6}"),
+    ('\u{2638}', "\u{2388}"),
+    ('\u{264E}', "\u{224F}"),
+    ('\u{2662}', "\u{16DC}"),
+    ('\u{2669}', "\u{1D158}\u{1D165}"),
+    ('\u{266A}', "\u{1D158}\u{1D165}\u{1D16E}"),
+    ('\u{26AC}', "\u{0970}"),
+    ('\u{2768}', "("),
+    ('\u{2769}', ")"),
+    ('\u{276E}', "<"),
+    ('\u{276F}', ">"),
+    ('\u{2772}', "("),
+    ('\u{2773}', ")"),
+    ('\u{2774}', "{"),
+    ('\u{2775}', "}"),
+    ('\u{2795}', "+"),
+    ('\u{2796}', "-"),
+    ('\u{2797}', "\u{00F7}"),
+    ('\u{27C2}', "\u{A4D5}"),
+    ('\u{27C8}', "\\\u{1455}"),
+    ('\u{27C9}', "\u{1450}/"),
+    ('\u{27CB}', "/"),
+    ('\u{27CD}', "\\"),
+    ('\u{27D9}', "T"),
+    ('\u{27E8}', "\u{276C}"),
+    ('\u{27E9}', "\u{276D}"),
+    ('\u{292B}', "x"),
+    ('\u{292C}', "x"),
+    ('\u{2963}', "\u{16D0}\u{16DA}"),
+    ('\u{2965}', "\u{21C3}\u{21C2}"),
+    ('\u{296E}', "\u{16D0}\u{21C2}"),
+    ('\u{296F}', "\u{21C3}\u{16DA}"),
+    ('\u{2999}', "\u{2D42}"),
+    ('\u{29B0}', "\u{2349}"),
+    ('\u{29BE}', "\u{233E}"),
+    ('\u{29C4}', "\u{303C}"),
+    ('\u{29C5}', "\u{2342}"),
+    ('\u{29C7}', "\u{233B}"),
+    ('\u{29D6}', "\u{102C0}"),
+    ('\u{29D9}', "\u{299A}"),
+    ('\u{29F4}', ":\u{2192}"),
+    ('\u{29F5}', "\\"),
+    ('\u{29F6}', "/\u{0304}"),
+    ('\u{29F8}', "/"),
+    ('\u{29F9}', "\\"),
+    ('\u{2A00}', "\u{0298}"),
+    ('\u{2A01}', "\u{102A8}"),
+    ('\u{2A02}', "\u{2297}"),
+    ('\u{2A03}', "\u{228D}"),
+    ('\u{2A04}', "\u{228E}"),
+    ('\u{2A05}', "\u{2293}"),
+    ('\u{2A06}', "\u{2294}"),
+    ('\u{2A0C}', "\u{0283}\u{0283}\u{0283}\u{0283}"),
+    ('\u{2A1D}', "\u{16DE}"),
+    ('\u{2A20}', ">>"),
+    ('\u{2A21}', "\u{16DA}"),
+    ('\u{2A22}', "+\u{030A}"),
+    ('\u{2A23}', "+\u{0302}"),
+    ('\u{2A24}', "+\u{0303}"),
+    ('\u{2A25}', "+\u{0323}"),
+    ('\u{2A26}', "+\u{0330}"),
+    ('\u{2A27}', "+\u{2082}"),
+    ('\u{2A29}', "-\u{0313}"),
+    ('\u{2A2A}', "-\u{0323}"),
+    ('\u{2A2F}', "x"),
+    ('\u{2A30}', "x\u{0307}"),
+    ('\u{2A3D}', "\u{2319}"),
+    ('\u{2A3E}', "\u{2A1F}"),
+    ('\u{2A3F}', "\u{2210}"),
+    ('\u{2A6A}', "~\u{0307}"),
+    ('\u{2A6E}', "=\u{20F0}"),
+    ('\u{2A74}', "::="),
+    ('\u{2A75}', "=="),
+    ('\u{2A76}', "==="),
+    ('\u{2AA5}', "><"),
+    ('\u{2AAA}', "\u{15D5}"),
+    ('\u{2AAB}', "\u{15D2}"),
+    ('\u{2AD7}', "\u{1450}\u{1455}"),
+    ('\u{2AFB}', "///"),
+    ('\u{2AFD}', "//"),
+    ('\u{2BEC}', "\u{219E}"),
+    ('\u{2BED}', "\u{219F}"),
+    ('\u{2BEE}', "\u{21A0}"),
+    ('\u{2BEF}', "\u{21A1}"),
+    ('\u{2C67}', "H\u{0329}"),
+    ('\u{2C69}', "K\u{0329}"),
+    ('\u{2C84}', "\u{0393}"),
+    ('\u{2C85}', "r"),
+    ('\u{2C86}', "\u{0394}"),
+    ('\u{2C88}', "\u{A792}"),
+    ('\u{2C89}', "\u{A793}"),
+    ('\u{2C8E}', "H"),
+    ('\u{2C92}', "l"),
+    ('\u{2C94}', "K"),
+    ('\u{2C95}', "\u{0138}"),
+    ('\u{2C96}', "\u{03BB}"),
+    ('\u{2C98}', "M"),
+    ('\u{2C9A}', "N"),
+    ('\u{2C9E}', "O"),
+    ('\u{2C9F}', "o"),
+    ('\u{2CA0}', "\u{03A0}"),
+    ('\u{2CA2}', "P"),
+    ('\u{2CA3}', "p"),
+    ('\u{2CA4}', "C"),
+    ('\u{2CA5}', "c"),
+    ('\u{2CA6}', "T"),
+    ('\u{2CA8}', "Y"),
+    ('\u{2CAA}', "\u{03A6}"),
+    ('\u{2CAB}', "\u{0278}"),
+    ('\u{2CAC}', "X"),
+    ('\u{2CAD}', "\u{03C7}"),
+    ('\u{2CAE}', "\u{03A8}"),
+    ('\u{2CB1}', "\u{03C9}"),
+    ('\u{2CB4}', "<\u{00B7}"),
+    ('\u{2CBA}', "-"),
+    ('\u{2CBC}', "\u{0428}"),
+    ('\u{2CBD}', "\u{0448}"),
+    ('\u{2CC6}', "/"),
+    ('\u{2CCA}', "9"),
+    ('\u{2CCC}', "3"),
+    ('\u{2CCD}', "\u{021D}"),
+    ('\u{2CD0}', "L"),
+    ('\u{2CD1}', "\u{029F}"),
+    ('\u{2CD2}', "6"),
+    ('\u{2CDC}', "\u{03EC}"),
+    ('\u{2CE4}', "\u{03D7}"),
+    ('\u{2CE9}', "\u{2627}"),
+    ('\u{2CF9}', "\\\\"),
+    ('\u{2D31}', "O\u{0335}"),
+    ('\u{2D37}', "\u{0245}"),
+    ('\u{2D38}', "V"),
+    ('\u{2D39}', "E"),
+    ('\u{2D3A}', "\u{018E}"),
+    ('\u{2D41}', "O\u{0338}"),
+    ('\u{2D48}', "\u{00B7}\u{00B7}\u{00B7}"),
+    ('\u{2D49}', "\u{01A9}"),
+    ('\u{2D4F}', "l"),
+    ('\u{2D51}', "!"),
+    ('\u{2D54}', "O"),
+    ('\u{2D55}', "Q"),
+    ('\u{2D59}', "\u{0298}"),
+    ('\u{2D5D}', "X"),
+    ('\u{2D60}', "\u{0394}"),
+    ('\u{2D63}', "\u{16EF}"),
+    ('\u{2DE8}', "\u{1DDF}"),
+    ('\u{2DEA}', "\u{030A}"),
+    ('\u{2DED}', "\u{0368}"),
+    ('\u{2DEF}', "\u{036F}"),
+    ('\u{2DF6}', "\u{0363}"),
+    ('\u{2DF7}', "\u{0364}"),
+    ('\u{2E1A}', "-\u{0308}"),
+    ('\u{2E1E}', "~\u{0307}"),
+    ('\u{2E1F}', "~\u{0323}"),
+    ('\u{2E26}', "\u{1455}"),
+    ('\u{2E27}', "\u{1450}"),
+    ('\u{2E28}', "(("),
+    ('\u{2E29}', "))"),
+    ('\u{2E2A}', "\u{2235}"),
+    ('\u{2E2B}', "\u{2234}"),
+    ('\u{2E2C}', "\u{2237}"),
+    ('\u{2E2E}', "\u{061F}"),
+    ('\u{2E30}', "\u{00B0}"),
+    ('\u{2E31}', "\u{00B7}"),
+    ('\u{2E32}', "\u{060C}"),
+    ('\u{2E35}', "\u{061B}"),
+    ('\u{2E39}', "\u{1E9F}"),
+    ('\u{2E3D}', "\u{2D42}"),
+    ('\u{2E3F}', "\u{00B6}"),
+    ('\u{2E40}', "="),
+    ('\u{2E82}', "\u{4E5B}"),
+    ('\u{2E83}', "\u{4E5A}"),
+    ('\u{2E85}', "\u{4EBB}"),
+    ('\u{2E89}', "\u{5202}"),
+    ('\u{2E8B}', "\u{353E}"),
+    ('\u{2E8E}', "\u{5140}"),
+    ('\u{2E8F}', "\u{5C23}"),
+    ('\u{2E90}', "\u{5C22}"),
+    ('\u{2E92}', "\u{5DF3}"),
+    ('\u{2E93}', "\u{5E7A}"),
+    ('\u{2E94}', "\u{5F51}"),
+    ('\u{2E96}', "\u{5FC4}"),
+    ('\u{2E97}', "\u{38FA}"),
+    ('\u{2E98}', "\u{624C}"),
+    ('\u{2E99}', "\u{6535}"),
+    ('\u{2E9B}', "\u{65E1}"),
+    ('\u{2E9E}', "\u{6B7A}"),
+    ('\u{2E9F}', "\u{6BCD}"),
+    ('\u{2EA0}', "\u{6C11}"),
+    ('\u{2EA1}', "\u{6C35}"),
+    ('\u{2EA2}', "\u{6C3A}"),
+    ('\u{2EA3}', "\u{706C}"),
+    ('\u{2EA4}', "\u{722B}"),
+    ('\u{2EA6}', "\u{4E2C}"),
+    ('\u{2EA8}', "\u{72AD}"),
+    ('\u{2EAB}', "\u{7F52}"),
+    ('\u{2EAD}', "\u{793B}"),
+    ('\u{2EAF}', "\u{7CF9}"),
+    ('\u{2EB1}', "\u{7F53}"),
+    ('\u{2EB2}', "\u{7F52}"),
+    ('\u{2EB9}', "\u{8002}"),
+    ('\u{2EBA}', "\u{8080}"),
+    ('\u{2EBE}', "\u{8279}"),
+    ('\u{2EBF}', "\u{8279}"),
+    ('\u{2EC0}', "\u{8279}"),
+    ('\u{2EC1}', "\u{864E}"),
+    ('\u{2EC2}', "\u{8864}"),
+    ('\u{2EC3}', "\u{8980}"),
+    ('\u{2EC4}', "\u{897F}"),
+    ('\u{2EC5}', "\u{89C1}"),
+    ('\u{2EC8}', "\u{8BA0}"),
+    ('\u{2EC9}', "\u{8D1D}"),
+    ('\u{2ECB}', "\u{8F66}"),
+    ('\u{2ECC}', "\u{8FB6}"),
+    ('\u{2ECD}', "\u{8FB6}"),
+    ('\u{2ECF}', "\u{961D}"),
+    ('\u{2ED0}', "\u{9485}"),
+    ('\u{2ED1}', "\u{9577}"),
+    ('\u{2ED2}', "\u{9578}"),
+    ('\u{2ED3}', "\u{957F}"),
+    ('\u{2ED4}', "\u{95E8}"),
+    ('\u{2ED6}', "\u{961D}"),
+    ('\u{2ED8}', "\u{9752}"),
+    ('\u{2ED9}', "\u{97E6}"),
+    ('\u{2EDA}', "\u{9875}"),
+    ('\u{2EDB}', "\u{98CE}"),
+    ('\u{2EDC}', "\u{98DE}"),
+    ('\u{2EDD}', "\u{98DF}"),
+    ('\u{2EDF}', "\u{98E0}"),
+    ('\u{2EE0}', "\u{9963}"),
+    ('\u{2EE2}', "\u{9A6C}"),
+    ('\u{2EE4}', "\u{9B3C}"),
+    ('\u{2EE5}', "\u{9C7C}"),
+    ('\u{2EE8}', "\u{9EA6}"),
+    ('\u{2EE9}', "\u{9EC4}"),
+    ('\u{2EEB}', "\u{6589}"),
+    ('\u{2EEC}', "\u{9F50}"),
+    ('\u{2EED}', "\u{6B6F}"),
+    ('\u{2EEE}', "\u{9F7F}"),
+    ('\u{2EEF}', "\u{7ADC}"),
+    ('\u{2EF0}', "\u{9F99}"),
+    ('\u{2EF2}', "\u{4E80}"),
+    ('\u{2EF3}', "\u{9F9F}"),
+    ('\u{2F00}', "\u{30FC}"),
+    ('\u{2F01}', "\u{4E28}"),
+    ('\u{2F02}', "\\"),
+    ('\u{2F03}', "/"),
+    ('\u{2F04}', "\u{4E59}"),
+    ('\u{2F05}', "\u{4E85}"),
+    ('\u{2F06}', "\u{4E8C}"),
+    ('\u{2F07}', "\u{4EA0}"),
+    ('\u{2F08}', "\u{4EBA}"),
+    ('\u{2F09}', "\u{513F}"),
+    ('\u{2F0A}', "\u{5165}"),
+    ('\u{2F0B}', "\u{516B}"),
+    ('\u{2F0C}', "\u{5182}"),
+    ('\u{2F0D}', "\u{5196}"),
+    ('\u{2F0E}', "\u{51AB}"),
+    ('\u{2F0F}', "\u{51E0}"),
+    ('\u{2F10}', "\u{51F5}"),
+    ('\u{2F11}', "\u{5200}"),
+    ('\u{2F12}', "\u{529B}"),
+    ('\u{2F13}', "\u{52F9}"),
+    ('\u{2F14}', "\u{5315}"),
+    ('\u{2F15}', "\u{531A}"),
+    ('\u{2F16}', "\u{5338}"),
+    ('\u{2F17}', "\u{5341}"),
+    ('\u{2F18}', "\u{535C}"),
+    ('\u{2F19}', "\u{5369}"),
+    ('\u{2F1A}', "\u{5382}"),
+    ('\u{2F1B}', "\u{53B6}"),
+    ('\u{2F1C}', "\u{53C8}"),
+    ('\u{2F1D}', "\u{53E3}"),
+    ('\u{2F1E}', "\u{53E3}"),
+    ('\u{2F1F}', "\u{571F}"),
+    ('\u{2F20}', "\u{571F}"),
+    ('\u{2F21}', "\u{5902}"),
+    ('\u{2F22}', "\u{590A}"),
+    ('\u{2F23}', "\u{5915}"),
+    ('\u{2F24}', "\u{5927}"),
+    ('\u{2F25}', "\u{5973}"),
+    ('\u{2F26}', "\u{5B50}"),
+    ('\u{2F27}', "\u{5B80}"),
+    ('\u{2F28}', "\u{5BF8}"),
+    ('\u{2F29}', "\u{5C0F}"),
+    ('\u{2F2A}', "\u{5C22}"),
+    ('\u{2F2B}', "\u{5C38}"),
+    ('\u{2F2C}', "\u{5C6E}"),
+    ('\u{2F2D}', "\u{5C71}"),
+    ('\u{2F2E}', "\u{5DDB}"),
+    ('\u{2F2F}', "\u{5DE5}"),
+    ('\u{2F30}', "\u{5DF1}"),
+    ('\u{2F31}', "\u{5DFE}"),
+    ('\u{2F32}', "\u{5E72}"),
+    ('\u{2F33}', "\u{5E7A}"),
+    ('\u{2F34}', "\u{5E7F}"),
+    ('\u{2F35}', "\u{5EF4}"),
+    ('\u{2F36}', "\u{5EFE}"),
+    ('\u{2F37}', "\u{5F0B}"),
+    ('\u{2F38}', "\u{5F13}"),
+    ('\u{2F39}', "\u{5F50}"),
+    ('\u{2F3A}', "\u{5F61}"),
+    ('\u{2F3B}', "\u{5F73}"),
+    ('\u{2F3C}', "\u{5FC3}"),
+    ('\u{2F3D}', "\u{6208}"),
+    ('\u{2F3E}', "\u{6236}"),
+    ('\u{2F3F}', "\u{624B}"),
+    ('\u{2F40}', "\u{652F}"),
+    ('\u{2F41}', "\u{6534}"),
+    ('\u{2F42}', "\u{6587}"),
+    ('\u{2F43}', "\u{6597}"),
+    ('\u{2F44}', "\u{65A4}"),
+    ('\u{2F45}', "\u{65B9}"),
+    ('\u{2F46}', "\u{65E0}"),
+    ('\u{2F47}', "\u{65E5}"),
+    ('\u{2F48}', "\u{66F0}"),
+    ('\u{2F49}', "\u{6708}"),
+    ('\u{2F4A}', "\u{6728}"),
+    ('\u{2F4B}', "\u{6B20}"),
+    ('\u{2F4C}', "\u{6B62}"),
+    ('\u{2F4D}', "\u{6B79}"),
+    ('\u{2F4E}', "\u{6BB3}"),
+    ('\u{2F4F}', "\u{6BCB}"),
+    ('\u{2F50}', "\u{6BD4}"),
+    ('\u{2F51}', "\u{6BDB}"),
+    ('\u{2F52}', "\u{6C0F}"),
+    ('\u{2F53}', "\u{6C14}"),
+    ('\u{2F54}', "\u{6C34}"),
+    ('\u{2F55}', "\u{706B}"),
+    ('\u{2F56}', "\u{722A}"),
+    ('\u{2F57}', "\u{7236}"),
+    ('\u{2F58}', "\u{723B}"),
+    ('\u{2F59}', "\u{723F}"),
+    ('\u{2F5A}', "\u{7247}"),
+    ('\u{2F5B}', "\u{7259}"),
+    ('\u{2F5C}', "\u{725B}"),
+    ('\u{2F5D}', "\u{72AC}"),
+    ('\u{2F5E}', "\u{7384}"),
+    ('\u{2F5F}', "\u{7389}"),
+    ('\u{2F60}', "\u{74DC}"),
+    ('\u{2F61}', "\u{74E6}"),
+    ('\u{2F62}', "\u{7518}"),
+    ('\u{2F63}', "\u{751F}"),
+    ('\u{2F64}', "\u{7528}"),
+    ('\u{2F65}', "\u{7530}"),
+    ('\u{2F66}', "\u{758B}"),
+    ('\u{2F67}', "\u{7592}"),
+    ('\u{2F68}', "\u{7676}"),
+    ('\u{2F69}', "\u{767D}"),
+    ('\u{2F6A}', "\u{76AE}"),
+    ('\u{2F6B}', "\u{76BF}"),
+    ('\u{2F6C}', "\u{76EE}"),
+    ('\u{2F6D}', "\u{77DB}"),
+    ('\u{2F6E}', "\u{77E2}"),
+    ('\u{2F6F}', "\u{77F3}"),
+    ('\u{2F70}', "\u{793A}"),
+    ('\u{2F71}', "\u{79B8}"),
+    ('\u{2F72}', "\u{79BE}"),
+    ('\u{2F73}', "\u{7A74}"),
+    ('\u{2F74}', "\u{7ACB}"),
+    ('\u{2F75}', "\u{7AF9}"),
+    ('\u{2F76}', "\u{7C73}"),
+    ('\u{2F77}', "\u{7CF8}"),
+    ('\u{2F78}', "\u{7F36}"),
+    ('\u{2F79}', "\u{7F51}"),
+    ('\u{2F7A}', "\u{7F8A}"),
+    ('\u{2F7B}', "\u{7FBD}"),
+    ('\u{2F7C}', "\u{8001}"),
+    ('\u{2F7D}', "\u{800C}"),
+    ('\u{2F7E}', "\u{8012}"),
+    ('\u{2F7F}', "\u{8033}"),
+    ('\u{2F80}', "\u{807F}"),
+    ('\u{2F81}', "\u{8089}"),
+    ('\u{2F82}', "\u{81E3}"),
+    ('\u{2F83}', "\u{81EA}"),
+    ('\u{2F84}', "\u{81F3}"),
+    ('\u{2F85}', "\u{81FC}"),
+    ('\u{2F86}', "\u{820C}"),
+    ('\u{2F87}', "\u{821B}"),
+    ('\u{2F88}', "\u{821F}"),
+    ('\u{2F89}', "\u{826E}"),
+    ('\u{2F8A}', "\u{8272}"),
+    ('\u{2F8B}', "\u{8278}"),
+    ('\u{2F8C}', "\u{864D}"),
+    ('\u{2F8D}', "\u{866B}"),
+    ('\u{2F8E}', "\u{8840}"),
+    ('\u{2F8F}', "\u{884C}"),
+    ('\u{2F90}', "\u{8863}"),
+    ('\u{2F91}', "\u{897E}"),
+    ('\u{2F92}', "\u{898B}"),
+    ('\u{2F93}', "\u{89D2}"),
+    ('\u{2F94}', "\u{8A00}"),
+    ('\u{2F95}', "\u{8C37}"),
+    ('\u{2F96}', "\u{8C46}"),
+    ('\u{2F97}', "\u{8C55}"),
+    ('\u{2F98}', "\u{8C78}"),
+    ('\u{2F99}', "\u{8C9D}"),
+    ('\u{2F9A}', "\u{8D64}"),
+    ('\u{2F9B}', "\u{8D70}"),
+    ('\u{2F9C}', "\u{8DB3}"),
+    ('\u{2F9D}', "\u{8EAB}"),
+    ('\u{2F9E}', "\u{8ECA}"),
+    ('\u{2F9F}', "\u{8F9B}"),
+    ('\u{2FA0}', "\u{8FB0}"),
+    ('\u{2FA1}', "\u{8FB5}"),
+    ('\u{2FA2}', "\u{9091}"),
+    ('\u{2FA3}', "\u{9149}"),
+    ('\u{2FA4}', "\u{91C6}"),
+    ('\u{2FA5}', "\u{91CC}"),
+    ('\u{2FA6}', "\u{91D1}"),
+    ('\u{2FA7}', "\u{9577}"),
+    ('\u{2FA8}', "\u{9580}"),
+    ('\u{2FA9}', "\u{961C}"),
+    ('\u{2FAA}', "\u{96B6}"),
+    ('\u{2FAB}', "\u{96B9}"),
+    ('\u{2FAC}', "\u{96E8}"),
+    ('\u{2FAD}', "\u{9751}"),
+    ('\u{2FAE}', "\u{975E}"),
+    ('\u{2FAF}', "\u{9762}"),
+    ('\u{2FB0}', "\u{9769}"),
+    ('\u{2FB1}', "\u{97CB}"),
+    ('\u{2FB2}', "\u{97ED}"),
+    ('\u{2FB3}', "\u{97F3}"),
+    ('\u{2FB4}', "\u{9801}"),
+    ('\u{2FB5}', "\u{98A8}"),
+    ('\u{2FB6}', "\u{98DB}"),
+    ('\u{2FB7}', "\u{98DF}"),
+    ('\u{2FB8}', "\u{9996}"),
+    ('\u{2FB9}', "\u{9999}"),
+    ('\u{2FBA}', "\u{99AC}"),
+    ('\u{2FBB}', "\u{9AA8}"),
+    ('\u{2FBC}', "\u{9AD8}"),
+    ('\u{2FBD}', "\u{9ADF}"),
+    ('\u{2FBE}', "\u{9B25}"),
+    ('\u{2FBF}', "\u{9B2F}"),
+    ('\u{2FC0}', "\u{9B32}"),
+    ('\u{2FC1}', "\u{9B3C}"),
+    ('\u{2FC2}', "\u{9B5A}"),
+    ('\u{2FC3}', "\u{9CE5}"),
+    ('\u{2FC4}', "\u{9E75}"),
+    ('\u{2FC5}', "\u{9E7F}"),
+    ('\u{2FC6}', "\u{9EA5}"),
+    ('\u{2FC7}', "\u{9EBB}"),
+    ('\u{2FC8}', "\u{9EC3}"),
+    ('\u{2FC9}', "\u{9ECD}"),
+    ('\u{2FCA}', "\u{9ED1}"),
+    ('\u{2FCB}', "\u{9EF9}"),
+    ('\u{2FCC}', "\u{9EFD}"),
+    ('\u{2FCD}', "\u{9F0E}"),
+    ('\u{2FCE}', "\u{9F13}"),
+    ('\u{2FCF}', "\u{9F20}"),
+    ('\u{2FD0}', "\u{9F3B}"),
+    ('\u{2FD1}', "\u{9F4A}"),
+    ('\u{2FD2}', "\u{9F52}"),
+    ('\u{2FD3}', "\u{9F8D}"),
+    ('\u{2FD4}', "\u{9F9C}"),
+    ('\u{2FD5}', "\u{9FA0}"),
+    ('\u{3002}', "\u{02F3}"),
+    ('\u{3003}', "''"),
+    ('\u{3007}', "O"),
+    ('\u{3008}', "\u{276C}"),
+    ('\u{3009}', "\u{276D}"),
+    ('\u{3012}', "\u{20B8}"),
+    ('\u{3014}', "("),
+    ('\u{3015}', ")"),
+    ('\u{301A}', "\u{27E6}"),
+    ('\u{301B}', "\u{27E7}"),
+    ('\u{302C}', "\u{0309}"),
+    ('\u{302D}', "\u{0325}"),
+    ('\u{3033}', "/"),
+    ('\u{3036}', "\u{20B8}"),
+    ('\u{3038}', "\u{5341}"),
+    ('\u{3039}', "\u{5344}"),
+    ('\u{303A}', "\u{5345}"),
+    ('\u{304F}', "\u{276C}"),
+    ('\u{309A}', "\u{030A}"),
+    ('\u{309B}', "\u{FF9E}"),
+    ('\u{309C}', "\u{FF9F}"),
+    ('\u{30A0}', "="),
+    ('\u{30A4}', "\u{4EBB}"),
+    ('\u{30A8}', "\u{5DE5}"),
+    ('\u{30AB}', "\u{529B}"),
+    ('\u{30BF}', "\u{5915}"),
+    ('\u{30C8}', "\u{535C}"),
+    ('\u{30CB}', "\u{4E8C}"),
+    ('\u{30CE}', "/"),
+    ('\u{30CF}', "\u{516B}"),
+    ('\u{30D8}', "\u{3078}"),
+    ('\u{30ED}', "\u{53E3}"),
+    ('\u{30FB}', "\u{00B7}"),
+    ('\u{3126}', "\u{513F}"),
+    ('\u{3131}', "\u{1100}"),
+    ('\u{3132}', "\u{1100}\u{1100}"),
+    ('\u{3133}', "\u{1100}\u{1109}"),
+    ('\u{3134}', "\u{1102}"),
+    ('\u{3135}', "\u{1102}\u{110C}"),
+    ('\u{3136}', "\u{1102}\u{1112}"),
+    ('\u{3137}', "\u{1103}"),
+    ('\u{3138}', "\u{1103}\u{1103}"),
+    ('\u{3139}', "\u{1105}"),
+    ('\u{313A}', "\u{1105}\u{1100}"),
+    ('\u{313B}', "\u{1105}\u{1106}"),
+    ('\u{313C}', "\u{1105}\u{1107}"),
+    ('\u{313D}', "\u{1105}\u{1109}"),
+    ('\u{313E}', "\u{1105}\u{1110}"),
+    ('\u{313F}', "\u{1105}\u{1111}"),
+    ('\u{3140}', "\u{1105}\u{1112}"),
+    ('\u{3141}', "\u{1106}"),
+    ('\u{3142}', "\u{1107}"),
+    ('\u{3143}', "\u{1107}\u{1107}"),
+    ('\u{3144}', "\u{1107}\u{1109}"),
+    ('\u{3145}', "\u{1109}"),
+    ('\u{3146}', "\u{1109}\u{1109}"),
+    ('\u{3147}', "\u{110B}"),
+    ('\u{3148}', "\u{110C}"),
+    ('\u{3149}', "\u{110C}\u{110C}"),
+    ('\u{314A}', "\u{110E}"),
+    ('\u{314B}', "\u{110F}"),
+    ('\u{314C}', "\u{1110}"),
+    ('\u{314D}', "\u{1111}"),
+    ('\u{314E}', "\u{1112}"),
+    ('\u{314F}', "\u{1161}"),
+    ('\u{3150}', "\u{1161}\u{4E28}"),
+    ('\u{3151}', "\u{1163}"),
+    ('\u{3152}', "\u{1163}\u{4E28}"),
+    ('\u{3153}', "\u{1165}"),
+    ('\u{3154}', "\u{1165}\u{4E28}"),
+    ('\u{3155}', "\u{1167}"),
+    ('\u{3156}', "\u{1167}\u{4E28}"),
+    ('\u{3157}', "\u{1169}"),
+    ('\u{3158}', "\u{1169}\u{1161}"),
+    ('\u{3159}', "\u{1169}\u{1161}\u{4E28}"),
+    ('\u{315A}', "\u{1169}\u{4E28}"),
+    ('\u{315B}', "\u{116D}"),
+    ('\u{315C}', "\u{116E}"),
+    ('\u{315D}', "\u{116E}\u{1165}"),
+    ('\u{315E}', "\u{116E}\u{1165}\u{4E28}"),
+    ('\u{315F}', "\u{116E}\u{4E28}"),
+    ('\u{3160}', "\u{1172}"),
+    ('\u{3161}', "\u{30FC}"),
+    ('\u{3162}', "\u{30FC}\u{4E28}"),
+    ('\u{3163}', "\u{4E28}"),
+    ('\u{3164}', "\u{1160}"),
+    ('\u{3165}', "\u{1102}\u{1102}"),
+    ('\u{3166}', "\u{1102}\u{1103}"),
+    ('\u{3167}', "\u{1102}\u{1109}"),
+    ('\u{3168}', "\u{1102}\u{1140}"),
+    ('\u{3169}', "\u{1105}\u{1100}\u{1109}"),
+    ('\u{316A}', "\u{1105}\u{1103}"),
+    ('\u{316B}', "\u{1105}\u{1107}\u{1109}"),
+    ('\u{316C}', "\u{1105}\u{1140}"),
+    ('\u{316D}', "\u{1105}\u{1159}"),
+    ('\u{316E}', "\u{1106}\u{1107}"),
+    ('\u{316F}', "\u{1106}\u{1109}"),
+    ('\u{3170}', "\u{1106}\u{1140}"),
+    ('\u{3171}', "\u{1106}\u{110B}"),
+    ('\u{3172}', "\u{1107}\u{1100}"),
+    ('\u{3173}', "\u{1107}\u{1103}"),
+    ('\u{3174}', "\u{1107}\u{1109}\u{1100}"),
+    ('\u{3175}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{3176}', "\u{1107}\u{110C}"),
+    ('\u{3177}', "\u{1107}\u{1110}"),
+    ('\u{3178}', "\u{1107}\u{110B}"),
+    ('\u{3179}', "\u{1107}\u{1107}\u{110B}"),
+    ('\u{317A}', "\u{1109}\u{1100}"),
+    ('\u{317B}', "\u{1109}\u{1102}"),
+    ('\u{317C}', "\u{1109}\u{1103}"),
+    ('\u{317D}', "\u{1109}\u{1107}"),
+    ('\u{317E}', "\u{1109}\u{110C}"),
+    ('\u{317F}', "\u{1140}"),
+    ('\u{3180}', "\u{110B}\u{110B}"),
+    ('\u{3181}', "\u{114C}"),
+    ('\u{3182}', "\u{110B}\u{1109}"),
+    ('\u{3183}', "\u{110B}\u{1140}"),
+    ('\u{3184}', "\u{1111}\u{110B}"),
+    ('\u{3185}', "\u{1112}\u{1112}"),
+    ('\u{3186}', "\u{1159}"),
+    ('\u{3187}', "\u{116D}\u{1163}"),
+    ('\u{3188}', "\u{116D}\u{1163}\u{4E28}"),
+    ('\u{3189}', "\u{116D}\u{4E28}"),
+    ('\u{318A}', "\u{1172}\u{1167}"),
+    ('\u{318B}', "\u{1172}\u{1167}\u{4E28}"),
+    ('\u{318C}', "\u{1172}\u{4E28}"),
+    ('\u{318D}', "\u{119E}"),
+    ('\u{318E}', "\u{119E}\u{4E28}"),
+    ('\u{31D0}', "\u{30FC}"),
+    ('\u{31D1}', "\u{4E28}"),
+    ('\u{31D3}', "/"),
+    ('\u{31D4}', "\\"),
+    ('\u{31D6}', "\u{4E5B}"),
+    ('\u{31DA}', "\u{4E85}"),
+    ('\u{31DB}', "\u{276C}"),
+    ('\u{31DF}', "\u{4E5A}"),
+    ('\u{31E0}', "\u{4E59}"),
+    ('\u{3200}', "(\u{1100})"),
+    ('\u{3201}', "(\u{1102})"),
+    ('\u{3202}', "(\u{1103})"),
+    ('\u{3203}', "(\u{1105})"),
+    ('\u{3204}', "(\u{1106})"),
+    ('\u{3205}', "(\u{1107})"),
+    ('\u{3206}', "(\u{1109})"),
+    ('\u{3207}', "(\u{110B})"),
+    ('\u{3208}', "(\u{110C})"),
+    ('\u{3209}', "(\u{110E})"),
+    ('\u{320A}', "(\u{110F})"),
+    ('\u{320B}', "(\u{1110})"),
+    ('\u{320C}', "(\u{1111})"),
+    ('\u{320D}', "(\u{1112})"),
+    ('\u{320E}', "(\u{AC00})"),
+    ('\u{320F}', "(\u{B098})"),
+    ('\u{3210}', "(\u{B2E4})"),
+    ('\u{3211}', "(\u{B77C})"),
+    ('\u{3212}', "(\u{B9C8})"),
+    ('\u{3213}', "(\u{BC14})"),
+    ('\u{3214}', "(\u{C0AC})"),
+    ('\u{3215}', "(\u{C544})"),
+    ('\u{3216}', "(\u{C790})"),
+    ('\u{3217}', "(\u{CC28})"),
+    ('\u{3218}', "(\u{CE74})"),
+    ('\u{3219}', "(\u{D0C0})"),
+    ('\u{321A}', "(\u{D30C})"),
+    ('\u{321B}', "(\u{D558})"),
+    ('\u{321C}', "(\u{C8FC})"),
+    ('\u{321D}', "(\u{C624}\u{C804})"),
+    ('\u{321E}', "(\u{C624}\u{D6C4})"),
+    ('\u{3220}', "(\u{30FC})"),
+    ('\u{3221}', "(\u{4E8C})"),
+    ('\u{3222}', "(\u{4E09})"),
+    ('\u{3223}', "(\u{56DB})"),
+    ('\u{3224}', "(\u{4E94})"),
+    ('\u{3225}', "(\u{516D})"),
+    ('\u{3226}', "(\u{4E03})"),
+    ('\u{3227}', "(\u{516B})"),
+    ('\u{3228}', "(\u{4E5D})"),
+    ('\u{3229}', "(\u{5341})"),
+    ('\u{322A}', "(\u{6708})"),
+    ('\u{322B}', "(\u{706B})"),
+    ('\u{322C}', "(\u{6C34})"),
+    ('\u{322D}', "(\u{6728})"),
+    ('\u{322E}', "(\u{91D1})"),
+    ('\u{322F}', "(\u{571F})"),
+    ('\u{3230}', "(\u{65E5})"),
+    ('\u{3231}', "(\u{682A})"),
+    ('\u{3232}', "(\u{6709})"),
+    ('\u{3233}', "(\u{793E})"),
+    ('\u{3234}', "(\u{540D})"),
+    ('\u{3235}', "(\u{7279})"),
+    ('\u{3236}', "(\u{8CA1})"),
+    ('\u{3237}', "(\u{795D})"),
+    ('\u{3238}', "(\u{52B4})"),
+    ('\u{3239}', "(\u{4EE3})"),
+    ('\u{323A}', "(\u{547C})"),
+    ('\u{323B}', "(\u{5B66})"),
+    ('\u{323C}', "(\u{76E3})"),
+    ('\u{323D}', "(\u{4F01})"),
+    ('\u{323E}', "(\u{8CC7})"),
+    ('\u{323F}', "(\u{5354})"),
+    ('\u{3240}', "(\u{796D})"),
+    ('\u{3241}', "(\u{4F11})"),
+    ('\u{3242}', "(\u{81EA})"),
+    ('\u{3243}', "(\u{81F3})"),
+    ('\u{32C0}', "l\u{6708}"),
+    ('\u{32C1}', "2\u{6708}"),
+    ('\u{32C2}', "3\u{6708}"),
+    ('\u{32C3}', "4\u{6708}"),
+    ('\u{32C4}', "5\u{6708}"),
+    ('\u{32C5}', "6\u{6708}"),
+    ('\u{32C6}', "7\u{6708}"),
+    ('\u{32C7}', "8\u{6708}"),
+    ('\u{32C8}', "9\u{6708}"),
+    ('\u{32C9}', "lO\u{6708}"),
+    ('\u{32CA}', "ll\u{6708}"),
+    ('\u{32CB}', "l2\u{6708}"),
+    ('\u{3358}', "O\u{70B9}"),
+    ('\u{3359}', "l\u{70B9}"),
+    ('\u{335A}', "2\u{70B9}"),
+    ('\u{335B}', "3\u{70B9}"),
+    ('\u{335C}', "4\u{70B9}"),
+    ('\u{335D}', "5\u{70B9}"),
+    ('\u{335E}', "6\u{70B9}"),
+    ('\u{335F}', "7\u{70B9}"),
+    ('\u{3360}', "8\u{70B9}"),
+    ('\u{3361}', "9\u{70B9}"),
+    ('\u{3362}', "lO\u{70B9}"),
+    ('\u{3363}', "ll\u{70B9}"),
+    ('\u{3364}', "l2\u{70B9}"),
+    ('\u{3365}', "l3\u{70B9}"),
+    ('\u{3366}', "l4\u{70B9}"),
+    ('\u{3367}', "l5\u{70B9}"),
+    ('\u{3368}', "l6\u{70B9}"),
+    ('\u{3369}', "l7\u{70B9}"),
+    ('\u{336A}', "l8\u{70B9}"),
+    ('\u{336B}', "l9\u{70B9}"),
+    ('\u{336C}', "2O\u{70B9}"),
+    ('\u{336D}', "2l\u{70B9}"),
+    ('\u{336E}', "22\u{70B9}"),
+    ('\u{336F}', "23\u{70B9}"),
+    ('\u{3370}', "24\u{70B9}"),
+    ('\u{33E0}', "l\u{65E5}"),
+    ('\u{33E1}', "2\u{65E5}"),
+    ('\u{33E2}', "3\u{65E5}"),
+    ('\u{33E3}', "4\u{65E5}"),
+    ('\u{33E4}', "5\u{65E5}"),
+    ('\u{33E5}', "6\u{65E5}"),
+    ('\u{33E6}', "7\u{65E5}"),
+    ('\u{33E7}', "8\u{65E5}"),
+    ('\u{33E8}', "9\u{65E5}"),
+    ('\u{33E9}', "lO\u{65E5}"),
+    ('\u{33EA}', "ll\u{65E5}"),
+    ('\u{33EB}', "l2\u{65E5}"),
+    ('\u{33EC}', "l3\u{65E5}"),
+    ('\u{33ED}', "l4\u{65E5}"),
+    ('\u{33EE}', "l5\u{65E5}"),
+    ('\u{33EF}', "l6\u{65E5}"),
+    ('\u{33F0}', "l7\u{65E5}"),
+    ('\u{33F1}', "l8\u{65E5}"),
+    ('\u{33F2}', "l9\u{65E5}"),
+    ('\u{33F3}', "2O\u{65E5}"),
+    ('\u{33F4}', "2l\u{65E5}"),
+    ('\u{33F5}', "22\u{65E5}"),
+    ('\u{33F6}', "23\u{65E5}"),
+    ('\u{33F7}', "24\u{65E5}"),
+    ('\u{33F8}', "25\u{65E5}"),
+    ('\u{33F9}', "26\u{65E5}"),
+    ('\u{33FA}', "27\u{65E5}"),
+    ('\u{33FB}', "28\u{65E5}"),
+    ('\u{33FC}', "29\u{65E5}"),
+    ('\u{33FD}', "3O\u{65E5}"),
+    ('\u{33FE}', "3l\u{65E5}"),
+    ('\u{39B3}', "\u{363D}"),
+    ('\u{439B}', "\u{3588}"),
+    ('\u{4420}', "\u{3B3B}"),
+    ('\u{4E00}', "\u{30FC}"),
+    ('\u{4E36}', "\\"),
+    ('\u{4E3F}', "/"),
+    ('\u{5002}', "\u{4F75}"),
+    ('\u{503C}', "\u{5024}"),
+    ('\u{555F}', "\u{5553}"),
+    ('\u{56D7}', "\u{53E3}"),
+    ('\u{586B}', "\u{5861}"),
+    ('\u{58EB}', "\u{571F}"),
+    ('\u{58FF}', "\u{58AB}"),
+    ('\u{5B00}', "\u{5AAF}"),
+    ('\u{5E32}', "\u{5E21}"),
+    ('\u{5E50}', "\u{3B3A}"),
+    ('\u{6238}', "\u{6236}"),
+    ('\u{6409}', "\u{3A41}"),
+    ('\u{6663}', "\u{403F}"),
+    ('\u{6669}', "\u{665A}"),
+    ('\u{66F6}', "\u{3ADA}"),
+    ('\u{6726}', "\u{4443}"),
+    ('\u{67FF}', "\u{676E}"),
+    ('\u{69E9}', "\u{3BA3}"),
+    ('\u{6A27}', "\u{699D}"),
+    ('\u{6F59}', "\u{6E88}"),
+    ('\u{784F}', "\u{7814}"),
+    ('\u{7D76}', "\u{7D55}"),
+    ('\u{80A6}', "\u{670C}"),
+    ('\u{80CA}', "\u{6710}"),
+    ('\u{80D0}', "\u{670F}"),
+    ('\u{80F6}', "\u{3B35}"),
+    ('\u{8101}', "\u{6713}"),
+    ('\u{8127}', "\u{6718}"),
+    ('\u{8141}', "\u{80FC}"),
+    ('\u{81A7}', "\u{6723}"),
+    ('\u{853F}', "\u{848D}"),
+    ('\u{8641}', "\u{8637}"),
+    ('\u{8A1E}', "\u{46B6}"),
+    ('\u{8A7D}', "\u{8A2E}"),
+    ('\u{8B8F}', "\u{8B86}"),
+    ('\u{8C63}', "\u{8C5C}"),
+    ('\u{8D86}', "\u{8D7F}"),
+    ('\u{8DFA}', "\u{8DE5}"),
+    ('\u{8E9B}', "\u{8E97}"),
+    ('\u{8F27}', "\u{8EFF}"),
+    ('\u{90DE}', "\u{90CE}"),
+    ('\u{93AE}', "\u{93AD}"),
+    ('\u{96B8}', "\u{96B7}"),
+    ('\u{9E43}', "\u{9E42}"),
+    ('\u{9ED2}', "\u{9ED1}"),
+    ('\u{9FC3}', "\u{4039}"),
+    ('\u{A494}', "\u{A2CD}"),
+    ('\u{A49C}', "\u{A0C0}"),
+    ('\u{A49E}', "\u{A04A}"),
+    ('\u{A4A7}', "\u{A458}"),
+    ('\u{A4A8}', "\u{A132}"),
+    ('\u{A4AC}', "\u{A050}"),
+    ('\u{A4B0}', "\u{A3C2}"),
+    ('\u{A4BA}', "\u{A3BF}"),
+    ('\u{A4BE}', "\u{A2B1}"),
+    ('\u{A4BF}', "\u{A259}"),
+    ('\u{A4C0}', "\u{A3AB}"),
+    ('\u{A4C2}', "\u{A3B5}"),
+    ('\u{A4D0}', "B"),
+    ('\u{A4D1}', "P"),
+    ('\u{A4D2}', "d"),
+    ('\u{A4D3}', "D"),
+    ('\u{A4D4}', "T"),
+    ('\u{A4D6}', "G"),
+    ('\u{A4D7}', "K"),
+    ('\u{A4D9}', "J"),
+    ('\u{A4DA}', "C"),
+    ('\u{A4DB}', "\u{0186}"),
+    ('\u{A4DC}', "Z"),
+    ('\u{A4DD}', "F"),
+    ('\u{A4DE}', "\u{2132}"),
+    ('\u{A4DF}', "M"),
+    ('\u{A4E0}', "N"),
+    ('\u{A4E1}', "L"),
+    ('\u{A4E2}', "S"),
+    ('\u{A4E3}', "R"),
+    ('\u{A4E5}', "\u{0245}"),
+    ('\u{A4E6}', "V"),
+    ('\u{A4E7}', "H"),
+    ('\u{A4EA}', "W"),
+    ('\u{A4EB}', "X"),
+    ('\u{A4EC}', "Y"),
+    ('\u{A4ED}', "\u{1660}"),
+    ('\u{A4EE}', "A"),
+    ('\u{A4EF}', "\u{2C6F}"),
+    ('\u{A4F0}', "E"),
+    ('\u{A4F1}', "\u{018E}"),
+    ('\u{A4F2}', "l"),
+    ('\u{A4F3}', "O"),
+    ('\u{A4F4}', "U"),
+    ('\u{A4F5}', "\u{0548}"),
+    ('\u{A4F7}', "\u{15E1}"),
+    ('\u{A4F8}', "."),
+    ('\u{A4F9}', ","),
+    ('\u{A4FA}', ".."),
+    ('\u{A4FB}', ".,"),
+    ('\u{A4FD}', ":"),
+    ('\u{A4FE}', "-."),
+    ('\u{A4FF}', "="),
+    ('\u{A60E}', "."),
+    ('\u{A644}', "2"),
+    ('\u{A645}', "\u{01A8}"),
+    ('\u{A647}', "i"),
+    ('\u{A64D}', "\u{03C9}"),
+    ('\u{A650}', "\u{042A}l"),
+    ('\u{A651}', "\u{02C9}bi"),
+    ('\u{A668}', "\u{0298}"),
+    ('\u{A66F}', "\u{20E9}"),
+    ('\u{A67C}', "\u{0306}"),
+    ('\u{A67E}', "\u{02C7}"),
+    ('\u{A695}', "h\u{0314}"),
+    ('\u{A698}', "OO"),
+    ('\u{A699}', "oo"),
+    ('\u{A69A}', "\u{102A8}"),
+    ('\u{A6A1}', "\u{0418}"),
+    ('\u{A6B0}', "\u{16B9}"),
+    ('\u{A6B1}', "\u{2C75}"),
+    ('\u{A6CD}', "\u{02A1}"),
+    ('\u{A6CE}', "\u{0245}"),
+    ('\u{A6DB}', "\u{03A0}"),
+    ('\u{A6DF}', "V"),
+    ('\u{A6EB}', "?"),
+    ('\u{A6EF}', "2"),
+    ('\u{A6F0}', "\u{0302}"),
+    ('\u{A6F1}', "\u{0304}"),
+    ('\u{A6F4}', "\u{A6F3}\u{A6F3}"),
+    ('\u{A714}', "\u{02EB}"),
+    ('\u{A716}', "\u{02EA}"),
+    ('\u{A728}', "T3"),
+    ('\u{A729}', "t\u{021D}"),
+    ('\u{A731}', "s"),
+    ('\u{A732}', "AA"),
+    ('\u{A733}', "aa"),
+    ('\u{A734}', "AO"),
+    ('\u{A735}', "ao"),
+    ('\u{A736}', "AU"),
+    ('\u{A737}', "au"),
+    ('\u{A738}', "AV"),
+    ('\u{A739}', "av"),
+    ('\u{A73A}', "AV"),
+    ('\u{A73B}', "av"),
+    ('\u{A73C}', "AY"),
+    ('\u{A73D}', "ay"),
+    ('\u{A740}', "K\u{0335}"),
+    ('\u{A74A}', "O\u{0335}"),
+    ('\u{A74B}', "o\u{0335}"),
+    ('\u{A74E}', "OO"),
+    ('\u{A74F}', "oo"),
+    ('\u{A75A}', "2"),
+    ('\u{A761}', "w\u{0326}"),
+    ('\u{A76A}', "3"),
+    ('\u{A76B}', "\u{021D}"),
+    ('\u{A76E}', "9"),
+    ('\u{A777}', "tf"),
+    ('\u{A778}', "&"),
+    ('\u{A77A}', "\u{A779}"),
+    ('\u{A789}', ":"),
+    ('\u{A78C}', "'"),
+    ('\u{A78F}', "\u{00B7}"),
+    ('\u{A795}', "\u{A727}"),
+    ('\u{A798}', "F"),
+    ('\u{A799}', "f"),
+    ('\u{A79A}', "\u{10412}"),
+    ('\u{A79B}', "\u{1043A}"),
+    ('\u{A79D}', "\u{029A}"),
+    ('\u{A79E}', "\u{A4E4}"),
+    ('\u{A79F}', "u"),
+    ('\u{A7AB}', "3"),
+    ('\u{A7B1}', "\u{A4D5}"),
+    ('\u{A7B2}', "J"),
+    ('\u{A7B3}', "X"),
+    ('\u{A7B4}', "B"),
+    ('\u{A7B5}', "\u{00DF}"),
+    ('\u{A7B6}', "\u{A64C}"),
+    ('\u{A7B7}', "\u{03C9}"),
+    ('\u{A7D6}', "\u{00DF}"),
+    ('\u{A7DA}', "\u{0245}"),
+    ('\u{A7DB}', "\u{03BB}"),
+    ('\u{A7DC}', "\u{0245}\u{0338}"),
+    ('\u{A7F7}', "\u{30FC}"),
+    ('\u{A830}', "\u{0964}"),
+    ('\u{A960}', "\u{1103}\u{1106}"),
+    ('\u{A961}', "\u{1103}\u{1107}"),
+    ('\u{A962}', "\u{1103}\u{1109}"),
+    ('\u{A963}', "\u{1103}\u{110C}"),
+    ('\u{A964}', "\u{1105}\u{1100}"),
+    ('\u{A965}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{A966}', "\u{1105}\u{1103}"),
+    ('\u{A967}', "\u{1105}\u{1103}\u{1103}"),
+    ('\u{A968}', "\u{1105}\u{1106}"),
+    ('\u{A969}', "\u{1105}\u{1107}"),
+    ('\u{A96A}', "\u{1105}\u{1107}\u{1107}"),
+    ('\u{A96B}', "\u{1105}\u{1107}\u{110B}"),
+    ('\u{A96C}', "\u{1105}\u{1109}"),
+    ('\u{A96D}', "\u{1105}\u{110C}"),
+    ('\u{A96E}', "\u{1105}\u{110F}"),
+    ('\u{A96F}', "\u{1106}\u{1100}"),
+    ('\u{A970}', "\u{1106}\u{1103}"),
+    ('\u{A971}', "\u{1106}\u{1109}"),
+    ('\u{A972}', "\u{1107}\u{1109}\u{1110}"),
+    ('\u{A973}', "\u{1107}\u{110F}"),
+    ('\u{A974}', "\u{1107}\u{1112}"),
+    ('\u{A975}', "\u{1109}\u{1109}\u{1107}"),
+    ('\u{A976}', "\u{110B}\u{1105}"),
+    ('\u{A977}', "\u{110B}\u{1112}"),
+    ('\u{A978}', "\u{110C}\u{110C}\u{1112}"),
+    ('\u{A979}', "\u{1110}\u{1110}"),
+    ('\u{A97A}', "\u{1111}\u{1112}"),
+    ('\u{A97B}', "\u{1112}\u{1109}"),
+    ('\u{A97C}', "\u{1159}\u{1159}"),
+    ('\u{A992}', "\u{2C3F}"),
+    ('\u{A9A3}', "\u{A99D}"),
+    ('\u{A9C6}', "\u{A9D0}"),
+    ('\u{A9CF}', "\u{0662}"),
+    ('\u{AA53}', "\u{AA01}"),
+    ('\u{AA56}', "\u{AA23}"),
+    ('\u{AB32}', "e"),
+    ('\u{AB35}', "f"),
+    ('\u{AB3D}', "o"),
+    ('\u{AB3E}', "o\u{0338}"),
+    ('\u{AB3F}', "\u{0254}\u{0338}"),
+    ('\u{AB41}', "\u{01DD}o\u{0338}"),
+    ('\u{AB42}', "\u{01DD}o\u{0335}"),
+    ('\u{AB47}', "r"),
+    ('\u{AB48}', "r"),
+    ('\u{AB4D}', "\u{0283}"),
+    ('\u{AB4E}', "u"),
+    ('\u{AB52}', "u"),
+    ('\u{AB53}', "\u{03C7}"),
+    ('\u{AB55}', "\u{03C7}"),
+    ('\u{AB5A}', "y"),
+    ('\u{AB60}', "\u{0459}"),
+    ('\u{AB62}', "\u{0254}e"),
+    ('\u{AB63}', "uo"),
+    ('\u{AB70}', "\u{1D05}"),
+    ('\u{AB71}', "\u{0280}"),
+    ('\u{AB72}', "\u{1D1B}"),
+    ('\u{AB74}', "o\u{031B}"),
+    ('\u{AB75}', "i"),
+    ('\u{AB7A}', "\u{1D00}"),
+    ('\u{AB7B}', "\u{1D0A}"),
+    ('\u{AB7C}', "\u{1D07}"),
+    ('\u{AB7E}', "\u{0242}"),
+    ('\u{AB80}', "\u{2C76}"),
+    ('\u{AB81}', "r"),
+    ('\u{AB83}', "w"),
+    ('\u{AB87}', "\u{028D}"),
+    ('\u{AB8B}', "\u{029C}"),
+    ('\u{AB8E}', "o\u{0335}"),
+    ('\u{AB90}', "\u{0262}"),
+    ('\u{AB93}', "z"),
+    ('\u{AB9B}', "\u{A793}"),
+    ('\u{AB9C}', "u\u{0335}"),
+    ('\u{AB9F}', "\u{0185}"),
+    ('\u{ABA2}', "\u{0280}"),
+    ('\u{ABA9}', "v"),
+    ('\u{ABAA}', "s"),
+    ('\u{ABAE}', "\u{029F}"),
+    ('\u{ABAF}', "c"),
+    ('\u{ABB2}', "\u{1D18}"),
+    ('\u{ABB6}', "\u{0138}"),
+    ('\u{ABBB}', "o\u{0335}"),
+    ('\u{D7B0}', "\u{1169}\u{1167}"),
+    ('\u{D7B1}', "\u{1169}\u{1169}\u{4E28}"),
+    ('\u{D7B2}', "\u{116D}\u{1161}"),
+    ('\u{D7B3}', "\u{116D}\u{1161}\u{4E28}"),
+    ('\u{D7B4}', "\u{116D}\u{1165}"),
+    ('\u{D7B5}', "\u{116E}\u{1167}"),
+    ('\u{D7B6}', "\u{116E}\u{4E28}\u{4E28}"),
+    ('\u{D7B7}', "\u{1172}\u{1161}\u{4E28}"),
+    ('\u{D7B8}', "\u{1172}\u{1169}"),
+    ('\u{D7B9}', "\u{30FC}\u{1161}"),
+    ('\u{D7BA}', "\u{30FC}\u{1165}"),
+    ('\u{D7BB}', "\u{30FC}\u{1165}\u{4E28}"),
+    ('\u{D7BC}', "\u{30FC}\u{1169}"),
+    ('\u{D7BD}', "\u{4E28}\u{1163}\u{1169}"),
+    ('\u{D7BE}', "\u{4E28}\u{1163}\u{4E28}"),
+    ('\u{D7BF}', "\u{4E28}\u{1167}"),
+    ('\u{D7C0}', "\u{4E28}\u{1167}\u{4E28}"),
+    ('\u{D7C1}', "\u{4E28}\u{1169}\u{4E28}"),
+    ('\u{D7C2}', "\u{4E28}\u{116D}"),
+    ('\u{D7C3}', "\u{4E28}\u{1172}"),
+    ('\u{D7C4}', "\u{4E28}\u{4E28}"),
+    ('\u{D7C5}', "\u{119E}\u{1161}"),
+    ('\u{D7C6}', "\u{119E}\u{1165}\u{4E28}"),
+    ('\u{D7CB}', "\u{1102}\u{1105}"),
+    ('\u{D7CC}', "\u{1102}\u{110E}"),
+    ('\u{D7CD}', "\u{1103}\u{1103}"),
+    ('\u{D7CE}', "\u{1103}\u{1103}\u{1107}"),
+    ('\u{D7CF}', "\u{1103}\u{1107}"),
+    ('\u{D7D0}', "\u{1103}\u{1109}"),
+    ('\u{D7D1}', "\u{1103}\u{1109}\u{1100}"),
+    ('\u{D7D2}', "\u{1103}\u{110C}"),
+    ('\u{D7D3}', "\u{1103}\u{110E}"),
+    ('\u{D7D4}', "\u{1103}\u{1110}"),
+    ('\u{D7D5}', "\u{1105}\u{1100}\u{1100}"),
+    ('\u{D7D6}', "\u{1105}\u{1100}\u{1112}"),
+    ('\u{D7D7}', "\u{1105}\u{1105}\u{110F}"),
+    ('\u{D7D8}', "\u{1105}\u{1106}\u{1112}"),
+    ('\u{D7D9}', "\u{1105}\u{1107}\u{1103}"),
+    ('\u{D7DA}', "\u{1105}\u{1107}\u{1111}"),
+    ('\u{D7DB}', "\u{1105}\u{114C}"),
+    ('\u{D7DC}', "\u{1105}\u{1159}\u{1112}"),
+    ('\u{D7DD}', "\u{1105}\u{110B}"),
+    ('\u{D7DE}', "\u{1106}\u{1102}"),
+    ('\u{D7DF}', "\u{1106}\u{1102}\u{1102}"),
+    ('\u{D7E0}', "\u{1106}\u{1106}"),
+    ('\u{D7E1}', "\u{1106}\u{1107}\u{1109}"),
+    ('\u{D7E2}', "\u{1106}\u{110C}"),
+    ('\u{D7E3}', "\u{1107}\u{1103}"),
+    ('\u{D7E4}', "\u{1107}\u{1105}\u{1111}"),
+    ('\u{D7E5}', "\u{1107}\u{1106}"),
+    ('\u{D7E6}', "\u{1107}\u{1107}"),
+    ('\u{D7E7}', "\u{1107}\u{1109}\u{1103}"),
+    ('\u{D7E8}', "\u{1107}\u{110C}"),
+    ('\u{D7E9}', "\u{1107}\u{110E}"),
+    ('\u{D7EA}', "\u{1109}\u{1106}"),
+    ('\u{D7EB}', "\u{1109}\u{1107}\u{110B}"),
+    ('\u{D7EC}', "\u{1109}\u{1109}\u{1100}"),
+    ('\u{D7ED}', "\u{1109}\u{1109}\u{1103}"),
+    ('\u{D7EE}', "\u{1109}\u{1140}"),
+    ('\u{D7EF}', "\u{1109}\u{110C}"),
+    ('\u{D7F0}', "\u{1109}\u{110E}"),
+    ('\u{D7F1}', "\u{1109}\u{1110}"),
+    ('\u{D7F2}', "\u{1105}\u{1112}"),
+    ('\u{D7F3}', "\u{1140}\u{1107}"),
+    ('\u{D7F4}', "\u{1140}\u{1107}\u{110B}"),
+    ('\u{D7F5}', "\u{114C}\u{1106}"),
+    ('\u{D7F6}', "\u{114C}\u{1112}"),
+    ('\u{D7F7}', "\u{110C}\u{1107}"),
+    ('\u{D7F8}', "\u{110C}\u{1107}\u{1107}"),
+    ('\u{D7F9}', "\u{110C}\u{110C}"),
+    ('\u{D7FA}', "\u{1111}\u{1109}"),
+    ('\u{D7FB}', "\u{1111}\u{1110}"),
+    ('\u{F900}', "\u{8C48}"),
+    ('\u{F901}', "\u{66F4}"),
+    ('\u{F902}', "\u{8ECA}"),
+    ('\u{F903}', "\u{8CC8}"),
+    ('\u{F904}', "\u{6ED1}"),
+    ('\u{F905}', "\u{4E32}"),
+    ('\u{F906}', "\u{53E5}"),
+    ('\u{F907}', "\u{9F9C}"),
+    ('\u{F908}', "\u{9F9C}"),
+    ('\u{F909}', "\u{5951}"),
+    ('\u{F90A}', "\u{91D1}"),
+    ('\u{F90B}', "\u{5587}"),
+    ('\u{F90C}', "\u{5948}"),
+    ('\u{F90D}', "\u{61F6}"),
+    ('\u{F90E}', "\u{7669}"),
+    ('\u{F90F}', "\u{7F85}"),
+    ('\u{F910}', "\u{863F}"),
+    ('\u{F911}', "\u{87BA}"),
+    ('\u{F912}', "\u{88F8}"),
+    ('\u{F913}', "\u{908F}"),
+    ('\u{F914}', "\u{6A02}"),
+    ('\u{F915}', "\u{6D1B}"),
+    ('\u{F916}', "\u{70D9}"),
+    ('\u{F917}', "\u{73DE}"),
+    ('\u{F918}', "\u{843D}"),
+    ('\u{F919}', "\u{916A}"),
+    ('\u{F91A}', "\u{99F1}"),
+    ('\u{F91B}', "\u{4E82}"),
+    ('\u{F91C}', "\u{5375}"),
+    ('\u{F91D}', "\u{6B04}"),
+    ('\u{F91E}', "\u{721B}"),
+    ('\u{F91F}', "\u{862D}"),
+    ('\u{F920}', "\u{9E1E}"),
+    ('\u{F921}', "\u{5D50}"),
+    ('\u{F922}', "\u{6FEB}"),
+    ('\u{F923}', "\u{85CD}"),
+    ('\u{F924}', "\u{8964}"),
+    ('\u{F925}', "\u{62C9}"),
+    ('\u{F926}', "\u{81D8}"),
+    ('\u{F927}', "\u{881F}"),
+    ('\u{F928}', "\u{5ECA}"),
+    ('\u{F929}', "\u{6717}"),
+    ('\u{F92A}', "\u{6D6A}"),
+    ('\u{F92B}', "\u{72FC}"),
+    ('\u{F92C}', "\u{90CE}"),
+    ('\u{F92D}', "\u{4F86}"),
+    ('\u{F92E}', "\u{51B7}"),
+    ('\u{F92F}', "\u{52DE}"),
+    ('\u{F930}', "\u{64C4}"),
+    ('\u{F931}', "\u{6AD3}"),
+    ('\u{F932}', "\u{7210}"),
+    ('\u{F933}', "\u{76E7}"),
+    ('\u{F934}', "\u{8001}"),
+    ('\u{F935}', "\u{8606}"),
+    ('\u{F936}', "\u{865C}"),
+    ('\u{F937}', "\u{8DEF}"),
+    ('\u{F938}', "\u{9732}"),
+    ('\u{F939}', "\u{9B6F}"),
+    ('\u{F93A}', "\u{9DFA}"),
+    ('\u{F93B}', "\u{788C}"),
+    ('\u{F93C}', "\u{797F}"),
+    ('\u{F93D}', "\u{7DA0}"),
+    ('\u{F93E}', "\u{83C9}"),
+    ('\u{F93F}', "\u{9304}"),
+    ('\u{F940}', "\u{9E7F}"),
+    ('\u{F941}', "\u{8AD6}"),
+    ('\u{F942}', "\u{58DF}"),
+    ('\u{F943}', "\u{5F04}"),
+    ('\u{F944}', "\u{7C60}"),
+    ('\u{F945}', "\u{807E}"),
+    ('\u{F946}', "\u{7262}"),
+    ('\u{F947}', "\u{78CA}"),
+    ('\u{F948}', "\u{8CC2}"),
+    ('\u{F949}', "\u{96F7}"),
+    ('\u{F94A}', "\u{58D8}"),
+    ('\u{F94B}', "\u{5C62}"),
+    ('\u{F94C}', "\u{6A13}"),
+    ('\u{F94D}', "\u{6DDA}"),
+    ('\u{F94E}', "\u{6F0F}"),
+    ('\u{F94F}', "\u{7D2F}"),
+    ('\u{F950}', "\u{7E37}"),
+    ('\u{F951}', "\u{964B}"),
+    ('\u{F952}', "\u{52D2}"),
+    ('\u{F953}', "\u{808B}"),
+    ('\u{F954}', "\u{51DC}"),
+    ('\u{F955}', "\u{51CC}"),
+    ('\u{F956}', "\u{7A1C}"),
+    ('\u{F957}', "\u{7DBE}"),
+    ('\u{F958}', "\u{83F1}"),
+    ('\u{F959}', "\u{9675}"),
+    ('\u{F95A}', "\u{8B80}"),
+    ('\u{F95B}', "\u{62CF}"),
+    ('\u{F95C}', "\u{6A02}"),
+    ('\u{F95D}', "\u{8AFE}"),
+    ('\u{F95E}', "\u{4E39}"),
+    ('\u{F95F}', "\u{5BE7}"),
+    ('\u{F960}', "\u{6012}"),
+    ('\u{F961}', "\u{7387}"),
+    ('\u{F962}', "\u{7570}"),
+    ('\u{F963}', "\u{5317}"),
+    ('\u{F964}', "\u{78FB}"),
+    ('\u{F965}', "\u{4FBF}"),
+    ('\u{F966}', "\u{5FA9}"),
+    ('\u{F967}', "\u{4E0D}"),
+    ('\u{F968}', "\u{6CCC}"),
+    ('\u{F969}', "\u{6578}"),
+    ('\u{F96A}', "\u{7D22}"),
+    ('\u{F96B}', "\u{53C3}"),
+    ('\u{F96C}', "\u{585E}"),
+    ('\u{F96D}', "\u{7701}"),
+    ('\u{F96E}', "\u{8449}"),
+    ('\u{F96F}', "\u{8AAA}"),
+    ('\u{F970}', "\u{6BBA}"),
+    ('\u{F971}', "\u{8FB0}"),
+    ('\u{F972}', "\u{6C88}"),
+    ('\u{F973}', "\u{62FE}"),
+    ('\u{F974}', "\u{82E5}"),
+    ('\u{F975}', "\u{63A0}"),
+    ('\u{F976}', "\u{7565}"),
+    ('\u{F977}', "\u{4EAE}"),
+    ('\u{F978}', "\u{5169}"),
+    ('\u{F979}', "\u{51C9}"),
+    ('\u{F97A}', "\u{6881}"),
+    ('\u{F97B}', "\u{7CE7}"),
+    ('\u{F97C}', "\u{826F}"),
+    ('\u{F97D}', "\u{8AD2}"),
+    ('\u{F97E}', "\u{91CF}"),
+    ('\u{F97F}', "\u{52F5}"),
+    ('\u{F980}', "\u{5442}"),
+    ('\u{F981}', "\u{5973}"),
+    ('\u{F982}', "\u{5EEC}"),
+    ('\u{F983}', "\u{65C5}"),
+    ('\u{F984}', "\u{6FFE}"),
+    ('\u{F985}', "\u{792A}"),
+    ('\u{F986}', "\u{95AD}"),
+    ('\u{F987}', "\u{9A6A}"),
+    ('\u{F988}', "\u{9E97}"),
+    ('\u{F989}', "\u{9ECE}"),
+    ('\u{F98A}', "\u{529B}"),
+    ('\u{F98B}', "\u{66C6}"),
+    ('\u{F98C}', "\u{6B77}"),
+    ('\u{F98D}', "\u{8F62}"),
+    ('\u{F98E}', "\u{5E74}"),
+    ('\u{F98F}', "\u{6190}"),
+    ('\u{F990}', "\u{6200}"),
+    ('\u{F991}', "\u{649A}"),
+    ('\u{F992}', "\u{6F23}"),
+    ('\u{F993}', "\u{7149}"),
+    ('\u{F994}', "\u{7489}"),
+    ('\u{F995}', "\u{79CA}"),
+    ('\u{F996}', "\u{7DF4}"),
+    ('\u{F997}', "\u{806F}"),
+    ('\u{F998}', "\u{8F26}"),
+    ('\u{F999}', "\u{84EE}"),
+    ('\u{F99A}', "\u{9023}"),
+    ('\u{F99B}', "\u{934A}"),
+    ('\u{F99C}', "\u{5217}"),
+    ('\u{F99D}', "\u{52A3}"),
+    ('\u{F99E}', "\u{54BD}"),
+    ('\u{F99F}', "\u{70C8}"),
+    ('\u{F9A0}', "\u{88C2}"),
+    ('\u{F9A1}', "\u{8AAA}"),
+    ('\u{F9A2}', "\u{5EC9}"),
+    ('\u{F9A3}', "\u{5FF5}"),
+    ('\u{F9A4}', "\u{637B}"),
+    ('\u{F9A5}', "\u{6BAE}"),
+    ('\u{F9A6}', "\u{7C3E}"),
+    ('\u{F9A7}', "\u{7375}"),
+    ('\u{F9A8}', "\u{4EE4}"),
+    ('\u{F9A9}', "\u{56F9}"),
+    ('\u{F9AA}', "\u{5BE7}"),
+    ('\u{F9AB}', "\u{5DBA}"),
+    ('\u{F9AC}', "\u{601C}"),
+    ('\u{F9AD}', "\u{73B2}"),
+    ('\u{F9AE}', "\u{7469}"),
+    ('\u{F9AF}', "\u{7F9A}"),
+    ('\u{F9B0}', "\u{8046}"),
+    ('\u{F9B1}', "\u{9234}"),
+    ('\u{F9B2}', "\u{96F6}"),
+    ('\u{F9B3}', "\u{9748}"),
+    ('\u{F9B4}', "\u{9818}"),
+    ('\u{F9B5}', "\u{4F8B}"),
+    ('\u{F9B6}', "\u{79AE}"),
+    ('\u{F9B7}', "\u{91B4}"),
+    ('\u{F9B8}', "\u{96B7}"),
+    ('\u{F9B9}', "\u{60E1}"),
+    ('\u{F9BA}', "\u{4E86}"),
+    ('\u{F9BB}', "\u{50DA}"),
+    ('\u{F9BC}', "\u{5BEE}"),
+    ('\u{F9BD}', "\u{5C3F}"),
+    ('\u{F9BE}', "\u{6599}"),
+    ('\u{F9BF}', "\u{6A02}"),
+    ('\u{F9C0}', "\u{71CE}"),
+    ('\u{F9C1}', "\u{7642}"),
+    ('\u{F9C2}', "\u{84FC}"),
+    ('\u{F9C3}', "\u{907C}"),
+    ('\u{F9C4}', "\u{9F8D}"),
+    ('\u{F9C5}', "\u{6688}"),
+    ('\u{F9C6}', "\u{962E}"),
+    ('\u{F9C7}', "\u{5289}"),
+    ('\u{F9C8}', "\u{677B}"),
+    ('\u{F9C9}', "\u{67F3}"),
+    ('\u{F9CA}', "\u{6D41}"),
+    ('\u{F9CB}', "\u{6E9C}"),
+    ('\u{F9CC}', "\u{7409}"),
+    ('\u{F9CD}', "\u{7559}"),
+    ('\u{F9CE}', "\u{786B}"),
+    ('\u{F9CF}', "\u{7D10}"),
+    ('\u{F9D0}', "\u{985E}"),
+    ('\u{F9D1}', "\u{516D}"),
+    ('\u{F9D2}', "\u{622E}"),
+    ('\u{F9D3}', "\u{9678}"),
+    ('\u{F9D4}', "\u{502B}"),
+    ('\u{F9D5}', "\u{5D19}"),
+    ('\u{F9D6}', "\u{6DEA}"),
+    ('\u{F9D7}', "\u{8F2A}"),
+    ('\u{F9D8}', "\u{5F8B}"),
+    ('\u{F9D9}', "\u{6144}"),
+    ('\u{F9DA}', "\u{6817}"),
+    ('\u{F9DB}', "\u{7387}"),
+    ('\u{F9DC}', "\u{9686}"),
+    ('\u{F9DD}', "\u{5229}"),
+    ('\u{F9DE}', "\u{540F}"),
+    ('\u{F9DF}', "\u{5C65}"),
+    ('\u{F9E0}', "\u{6613}"),
+    ('\u{F9E1}', "\u{674E}"),
+    ('\u{F9E2}', "\u{68A8}"),
+    ('\u{F9E3}', "\u{6CE5}"),
+    ('\u{F9E4}', "\u{7406}"),
+    ('\u{F9E5}', "\u{75E2}"),
+    ('\u{F9E6}', "\u{7F79}"),
+    ('\u{F9E7}', "\u{88CF}"),
+    ('\u{F9E8}', "\u{88E1}"),
+    ('\u{F9E9}', "\u{91CC}"),
+    ('\u{F9EA}', "\u{96E2}"),
+    ('\u{F9EB}', "\u{533F}"),
+    ('\u{F9EC}', "\u{6EBA}"),
+    ('\u{F9ED}', "\u{541D}"),
+    ('\u{F9EE}', "\u{71D0}"),
+    ('\u{F9EF}', "\u{7498}"),
+    ('\u{F9F0}', "\u{85FA}"),
+    ('\u{F9F1}', "\u{96A3}"),
+    ('\u{F9F2}', "\u{9C57}"),
+    ('\u{F9F3}', "\u{9E9F}"),
+    ('\u{F9F4}', "\u{6797}"),
+    ('\u{F9F5}', "\u{6DCB}"),
+    ('\u{F9F6}', "\u{81E8}"),
+    ('\u{F9F7}', "\u{7ACB}"),
+    ('\u{F9F8}', "\u{7B20}"),
+    ('\u{F9F9}', "\u{7C92}"),
+    ('\u{F9FA}', "\u{72C0}"),
+    ('\u{F9FB}', "\u{7099}"),
+    ('\u{F9FC}', "\u{8B58}"),
+    ('\u{F9FD}', "\u{4EC0}"),
+    ('\u{F9FE}', "\u{8336}"),
+    ('\u{F9FF}', "\u{523A}"),
+    ('\u{FA00}', "\u{5207}"),
+    ('\u{FA01}', "\u{5EA6}"),
+    ('\u{FA02}', "\u{62D3}"),
+    ('\u{FA03}', "\u{7CD6}"),
+    ('\u{FA04}', "\u{5B85}"),
+    ('\u{FA05}', "\u{6D1E}"),
+    ('\u{FA06}', "\u{66B4}"),
+    ('\u{FA07}', "\u{8F3B}"),
+    ('\u{FA08}', "\u{884C}"),
+    ('\u{FA09}', "\u{964D}"),
+    ('\u{FA0A}', "\u{898B}"),
+    ('\u{FA0B}', "\u{5ED3}"),
+    ('\u{FA0C}', "\u{5140}"),
+    ('\u{FA0D}', "\u{55C0}"),
+    ('\u{FA10}', "\u{585A}"),
+    ('\u{FA12}', "\u{6674}"),
+    ('\u{FA15}', "\u{51DE}"),
+    ('\u{FA16}', "\u{732A}"),
+    ('\u{FA17}', "\u{76CA}"),
+    ('\u{FA18}', "\u{793C}"),
+    ('\u{FA19}', "\u{795E}"),
+    ('\u{FA1A}', "\u{7965}"),
+    ('\u{FA1B}', "\u{798F}"),
+    ('\u{FA1C}', "\u{9756}"),
+    ('\u{FA1D}', "\u{7CBE}"),
+    ('\u{FA1E}', "\u{7FBD}"),
+    ('\u{FA20}', "\u{8612}"),
+    ('\u{FA22}', "\u{8AF8}"),
+    ('\u{FA25}', "\u{9038}"),
+    ('\u{FA26}', "\u{90FD}"),
+    ('\u{FA2A}', "\u{98EF}"),
+    ('\u{FA2B}', "\u{98FC}"),
+    ('\u{FA2C}', "\u{9928}"),
+    ('\u{FA2D}', "\u{9DB4}"),
+    ('\u{FA2E}', "\u{90CE}"),
+    ('\u{FA2F}', "\u{96B7}"),
+    ('\u{FA30}', "\u{4FAE}"),
+    ('\u{FA31}', "\u{50E7}"),
+    ('\u{FA32}', "\u{514D}"),
+    ('\u{FA33}', "\u{52C9}"),
+    ('\u{FA34}', "\u{52E4}"),
+    ('\u{FA35}', "\u{5351}"),
+    ('\u{FA36}', "\u{559D}"),
+    ('\u{FA37}', "\u{5606}"),
+    ('\u{FA38}', "\u{5668}"),
+    ('\u{FA39}', "\u{5840}"),
+    ('\u{FA3A}', "\u{58A8}"),
+    ('\u{FA3B}', "\u{5C64}"),
+    ('\u{FA3C}', "\u{5C6E}"),
+    ('\u{FA3D}', "\u{6094}"),
+    ('\u{FA3E}', "\u{6168}"),
+    ('\u{FA3F}', "\u{618E}"),
+    ('\u{FA40}', "\u{61F2}"),
+    ('\u{FA41}', "\u{654F}"),
+    ('\u{FA42}', "\u{65E2}"),
+    ('\u{FA43}', "\u{6691}"),
+    ('\u{FA44}', "\u{6885}"),
+    ('\u{FA45}', "\u{6D77}"),
+    ('\u{FA46}', "\u{6E1A}"),
+    ('\u{FA47}', "\u{6F22}"),
+    ('\u{FA48}', "\u{716E}"),
+    ('\u{FA49}', "\u{722B}"),
+    ('\u{FA4A}', "\u{7422}"),
+    ('\u{FA4B}', "\u{7891}"),
+    ('\u{FA4C}', "\u{793E}"),
+    ('\u{FA4D}', "\u{7949}"),
+    ('\u{FA4E}', "\u{7948}"),
+    ('\u{FA4F}', "\u{7950}"),
+    ('\u{FA50}', "\u{7956}"),
+    ('\u{FA51}', "\u{795D}"),
+    ('\u{FA52}', "\u{798D}"),
+    ('\u{FA53}', "\u{798E}"),
+    ('\u{FA54}', "\u{7A40}"),
+    ('\u{FA55}', "\u{7A81}"),
+    ('\u{FA56}', "\u{7BC0}"),
+    ('\u{FA57}', "\u{7DF4}"),
+    ('\u{FA58}', "\u{7E09}"),
+    ('\u{FA59}', "\u{7E41}"),
+    ('\u{FA5A}', "\u{7F72}"),
+    ('\u{FA5B}', "\u{8005}"),
+    ('\u{FA5C}', "\u{81ED}"),
+    ('\u{FA5D}', "\u{8279}"),
+    ('\u{FA5E}', "\u{8279}"),
+    ('\u{FA5F}', "\u{8457}"),
+    ('\u{FA60}', "\u{8910}"),
+    ('\u{FA61}', "\u{8996}"),
+    ('\u{FA62}', "\u{8B01}"),
+    ('\u{FA63}', "\u{8B39}"),
+    ('\u{FA64}', "\u{8CD3}"),
+    ('\u{FA65}', "\u{8D08}"),
+    ('\u{FA66}', "\u{8FB6}"),
+    ('\u{FA67}', "\u{9038}"),
+    ('\u{FA68}', "\u{96E3}"),
+    ('\u{FA69}', "\u{97FF}"),
+    ('\u{FA6A}', "\u{983B}"),
+    ('\u{FA6B}', "\u{6075}"),
+    ('\u{FA6C}', "\u{242EE}"),
+    ('\u{FA6D}', "\u{8218}"),
+    ('\u{FA70}', "\u{4E26}"),
+    ('\u{FA71}', "\u{51B5}"),
+    ('\u{FA72}', "\u{5168}"),
+    ('\u{FA73}', "\u{4F80}"),
+    ('\u{FA74}', "\u{5145}"),
+    ('\u{FA75}', "\u{5180}"),
+    ('\u{FA76}', "\u{52C7}"),
+    ('\u{FA77}', "\u{52FA}"),
+    ('\u{FA78}', "\u{559D}"),
+    ('\u{FA79}', "\u{5555}"),
+    ('\u{FA7A}', "\u{5599}"),
+    ('\u{FA7B}', "\u{55E2}"),
+    ('\u{FA7C}', "\u{585A}"),
+    ('\u{FA7D}', "\u{58B3}"),
+    ('\u{FA7E}', "\u{5944}"),
+    ('\u{FA7F}', "\u{5954}"),
+    ('\u{FA80}', "\u{5A62}"),
+    ('\u{FA81}', "\u{5B28}"),
+    ('\u{FA82}', "\u{5ED2}"),
+    ('\u{FA83}', "\u{5ED9}"),
+    ('\u{FA84}', "\u{5F69}"),
+    ('\u{FA85}', "\u{5FAD}"),
+    ('\u{FA86}', "\u{60D8}"),
+    ('\u{FA87}', "\u{614E}"),
+    ('\u{FA88}', "\u{6108}"),
+    ('\u{FA89}', "\u{618E}"),
+    ('\u{FA8A}', "\u{6160}"),
+    ('\u{FA8B}', "\u{61F2}"),
+    ('\u{FA8C}', "\u{6234}"),
+    ('\u{FA8D}', "\u{63C4}"),
+    ('\u{FA8E}', "\u{641C}"),
+    ('\u{FA8F}', "\u{6452}"),
+    ('\u{FA90}', "\u{6556}"),
+    ('\u{FA91}', "\u{6674}"),
+    ('\u{FA92}', "\u{6717}"),
+    ('\u{FA93}', "\u{671B}"),
+    ('\u{FA94}', "\u{6756}"),
+    ('\u{FA95}', "\u{6B79}"),
+    ('\u{FA96}', "\u{6BBA}"),
+    ('\u{FA97}', "\u{6D41}"),
+    ('\u{FA98}', "\u{6EDB}"),
+    ('\u{FA99}', "\u{6ECB}"),
+    ('\u{FA9A}', "\u{6F22}"),
+    ('\u{FA9B}', "\u{701E}"),
+    ('\u{FA9C}', "\u{716E}"),
+    ('\u{FA9D}', "\u{77A7}"),
+    ('\u{FA9E}', "\u{7235}"),
+    ('\u{FA9F}', "\u{72AF}"),
+    ('\u{FAA0}', "\u{732A}"),
+    ('\u{FAA1}', "\u{7471}"),
+    ('\u{FAA2}', "\u{7506}"),
+    ('\u{FAA3}', "\u{753B}"),
+    ('\u{FAA4}', "\u{761D}"),
+    ('\u{FAA5}', "\u{761F}"),
+    ('\u{FAA6}', "\u{76CA}"),
+    ('\u{FAA7}', "\u{76DB}"),
+    ('\u{FAA8}', "\u{76F4}"),
+    ('\u{FAA9}', "\u{774A}"),
+    ('\u{FAAA}', "\u{7740}"),
+    ('\u{FAAB}', "\u{78CC}"),
+    ('\u{FAAC}', "\u{7AB1}"),
+    ('\u{FAAD}', "\u{7BC0}"),
+    ('\u{FAAE}', "\u{7C7B}"),
+    ('\u{FAAF}', "\u{7D5B}"),
+    ('\u{FAB0}', "\u{7DF4}"),
+    ('\u{FAB1}', "\u{7F3E}"),
+    ('\u{FAB2}', "\u{8005}"),
+    ('\u{FAB3}', "\u{8352}"),
+    ('\u{FAB4}', "\u{83EF}"),
+    ('\u{FAB5}', "\u{8779}"),
+    ('\u{FAB6}', "\u{8941}"),
+    ('\u{FAB7}', "\u{8986}"),
+    ('\u{FAB8}', "\u{8996}"),
+    ('\u{FAB9}', "\u{8ABF}"),
+    ('\u{FABA}', "\u{8AF8}"),
+    ('\u{FABB}', "\u{8ACB}"),
+    ('\u{FABC}', "\u{8B01}"),
+    ('\u{FABD}', "\u{8AFE}"),
+    ('\u{FABE}', "\u{8AED}"),
+    ('\u{FABF}', "\u{8B39}"),
+    ('\u{FAC0}', "\u{8B8A}"),
+    ('\u{FAC1}', "\u{8D08}"),
+    ('\u{FAC2}', "\u{8F38}"),
+    ('\u{FAC3}', "\u{9072}"),
+    ('\u{FAC4}', "\u{9199}"),
+    ('\u{FAC5}', "\u{9276}"),
+    ('\u{FAC6}', "\u{967C}"),
+    ('\u{FAC7}', "\u{96E3}"),
+    ('\u{FAC8}', "\u{9756}"),
+    ('\u{FAC9}', "\u{97DB}"),
+    ('\u{FACA}', "\u{97FF}"),
+    ('\u{FACB}', "\u{980B}"),
+    ('\u{FACC}', "\u{983B}"),
+    ('\u{FACD}', "\u{9B12}"),
+    ('\u{FACE}', "\u{9F9C}"),
+    ('\u{FACF}', "\u{2284A}"),
+    ('\u{FAD0}', "\u{22844}"),
+    ('\u{FAD1}', "\u{233D5}"),
+    ('\u{FAD2}', "\u{3B9D}"),
+    ('\u{FAD3}', "\u{4018}"),
+    ('\u{FAD4}', "\u{4039}"),
+    ('\u{FAD5}', "\u{25249}"),
+    ('\u{FAD6}', "\u{25CD0}"),
+    ('\u{FAD7}', "\u{27ED3}"),
+    ('\u{FAD8}', "\u{9F43}"),
+    ('\u{FAD9}', "\u{9F8E}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{0574}\u{0576}"),
+    ('\u{FB14}', "\u{0574}\u{0565}"),
+    ('\u{FB15}', "\u{0574}\u{056B}"),
+    ('\u{FB16}', "\u{057E}\u{0576}"),
+    ('\u{FB17}', "\u{0574}\u{056D}"),
+    ('\u{FB20}', "\u{05E2}"),
+    ('\u{FB21}', "\u{05D0}"),
+    ('\u{FB22}', "\u{05D3}"),
+    ('\u{FB23}', "\u{05D4}"),
+    ('\u{FB24}', "\u{05DB}"),
+    ('\u{FB25}', "\u{05DC}"),
+    ('\u{FB26}', "\u{05DD}"),
+    ('\u{FB27}', "\u{05E8}"),
+    ('\u{FB28}', "\u{05EA}"),
+    ('\u{FB29}', "-\u{0307}"),
+    ('\u{FB2B}', "\u{FB2A}"),
+    ('\u{FB2D}', "\u{FB2C}"),
+    ('\u{FB2F}', "\u{FB2E}"),
+    ('\u{FB30}', "\u{FB2E}"),
+    ('\u{FB39}', "\u{FB1D}"),
+    ('\u{FB49}', "\u{FB2A}"),
+    ('\u{FB4F}', "\u{05D0}\u{05DC}"),
+    ('\u{FB50}', "\u{0671}"),
+    ('\u{FB51}', "\u{0671}"),
+    ('\u{FB52}', "\u{067B}"),
+    ('\u{FB53}', "\u{067B}"),
+    ('\u{FB54}', "\u{067B}"),
+    ('\u{FB55}', "\u{067B}"),
+    ('\u{FB56}', "\u{0649}\u{06DB}"),
+    ('\u{FB57}', "\u{0649}\u{06DB}"),
+    ('\u{FB58}', "\u{0649}\u{06DB}"),
+    ('\u{FB59}', "\u{0649}\u{06DB}"),
+    ('\u{FB5A}', "\u{0680}"),
+    ('\u{FB5B}', "\u{0680}"),
+    ('\u{FB5C}', "\u{0680}"),
+    ('\u{FB5D}', "\u{0680}"),
+    ('\u{FB5E}', "\u{067A}"),
+    ('\u{FB5F}', "\u{067A}"),
+    ('\u{FB60}', "\u{067A}"),
+    ('\u{FB61}', "\u{067A}"),
+    ('\u{FB62}', "\u{067F}"),
+    ('\u{FB63}', "\u{067F}"),
+    ('\u{FB64}', "\u{067F}"),
+    ('\u{FB65}', "\u{067F}"),
+    ('\u{FB66}', "\u{0649}\u{0615}"),
+    ('\u{FB67}', "\u{0649}\u{0615}"),
+    ('\u{FB68}', "\u{0649}\u{0615}"),
+    ('\u{FB69}', "\u{0649}\u{0615}"),
+    ('\u{FB6A}', "\u{06A1}\u{06DB}"),
+    ('\u{FB6B}', "\u{06A1}\u{06DB}"),
+    ('\u{FB6C}', "\u{06A1}\u{06DB}"),
+    ('\u{FB6D}', "\u{06A1}\u{06DB}"),
+    ('\u{FB6E}', "\u{06A6}"),
+    ('\u{FB6F}', "\u{06A6}"),
+    ('\u{FB70}', "\u{06A6}"),
+    ('\u{FB71}', "\u{06A6}"),
+    ('\u{FB72}', "\u{0684}"),
+    ('\u{FB73}', "\u{0684}"),
+    ('\u{FB74}', "\u{0684}"),
+    ('\u{FB75}', "\u{0684}"),
+    ('\u{FB76}', "\u{0683}"),
+    ('\u{FB77}', "\u{0683}"),
+    ('\u{FB78}', "\u{0683}"),
+    ('\u{FB79}', "\u{0683}"),
+    ('\u{FB7A}', "\u{0686}"),
+    ('\u{FB7B}', "\u{0686}"),
+    ('\u{FB7C}', "\u{0686}"),
+    ('\u{FB7D}', "\u{0686}"),
+    ('\u{FB7E}', "\u{0687}"),
+    ('\u{FB7F}', "\u{0687}"),
+    ('\u{FB80}', "\u{0687}"),
+    ('\u{FB81}', "\u{0687}"),
+    ('\u{FB82}', "\u{068D}"),
+    ('\u{FB83}', "\u{068D}"),
+    ('\u{FB84}', "\u{068C}"),
+    ('\u{FB85}', "\u{068C}"),
+    ('\u{FB86}', "\u{062F}\u{06DB}"),
+    ('\u{FB87}', "\u{062F}\u{06DB}"),
+    ('\u{FB88}', "\u{062F}\u{0615}"),
+    ('\u{FB89}', "\u{062F}\u{0615}"),
+    ('\u{FB8A}', "\u{0631}\u{06DB}"),
+    ('\u{FB8B}', "\u{0631}\u{06DB}"),
+    ('\u{FB8C}', "\u{0631}\u{0615}"),
+    ('\u{FB8D}', "\u{0631}\u{0615}"),
+    ('\u{FB8E}', "\u{0643}"),
+    ('\u{FB8F}', "\u{0643}"),
+    ('\u{FB90}', "\u{0643}"),
+    ('\u{FB91}', "\u{0643}"),
+    ('\u{FB92}', "\u{06AF}"),
+    ('\u{FB93}', "\u{06AF}"),
+    ('\u{FB94}', "\u{06AF}"),
+    ('\u{FB95}', "\u{06AF}"),
+    ('\u{FB96}', "\u{06B3}"),
+    ('\u{FB97}', "\u{06B3}"),
+    ('\u{FB98}', "\u{06B3}"),
+    ('\u{FB99}', "\u{06B3}"),
+    ('\u{FB9A}', "\u{06B1}"),
+    ('\u{FB9B}', "\u{06B1}"),
+    ('\u{FB9C}', "\u{06B1}"),
+    ('\u{FB9D}', "\u{06B1}"),
+    ('\u{FB9E}', "\u{0649}"),
+    ('\u{FB9F}', "\u{0649}"),
+    ('\u{FBA0}', "\u{0649}\u{0615}"),
+    ('\u{FBA1}', "\u{0649}\u{0615}"),
+    ('\u{FBA2}', "\u{0649}\u{0615}"),
+    ('\u{FBA3}', "\u{0649}\u{0615}"),
+    ('\u{FBA4}', "\u{06C0}"),
+    ('\u{FBA5}', "\u{06C0}"),
+    ('\u{FBA6}', "o"),
+    ('\u{FBA7}', "o"),
+    ('\u{FBA8}', "o"),
+    ('\u{FBA9}', "o"),
+    ('\u{FBAA}', "o"),
+    ('\u{FBAB}', "o"),
+    ('\u{FBAC}', "o"),
+    ('\u{FBAD}', "o"),
+    ('\u{FBAE}', "\u{0649}"),
+    ('\u{FBAF}', "\u{0649}"),
+    ('\u{FBB0}', "\u{06D3}"),
+    ('\u{FBB1}', "\u{06D3}"),
+    ('\u{FBD3}', "\u{0643}\u{06DB}"),
+    ('\u{FBD4}', "\u{0643}\u{06DB}"),
+    ('\u{FBD5}', "\u{0643}\u{06DB}"),
+    ('\u{FBD6}', "\u{0643}\u{06DB}"),
+    ('\u{FBD7}', "\u{0648}\u{0313}"),
+    ('\u{FBD8}', "\u{0648}\u{0313}"),
+    ('\u{FBD9}', "\u{0648}\u{0306}"),
+    ('\u{FBDA}', "\u{0648}\u{0306}"),
+    ('\u{FBDB}', "\u{0648}\u{0670}"),
+    ('\u{FBDC}', "\u{0648}\u{0670}"),
+    ('\u{FBDD}', "\u{0648}\u{0313}\u{0674}"),
+    ('\u{FBDE}', "\u{0648}\u{06DB}"),
+    ('\u{FBDF}', "\u{0648}\u{06DB}"),
+    ('\u{FBE0}', "\u{06C5}"),
+    ('\u{FBE1}', "\u{06C5}"),
+    ('\u{FBE2}', "\u{0648}\u{0302}"),
+    ('\u{FBE3}', "\u{0648}\u{0302}"),
+    ('\u{FBE4}', "\u{067B}"),
+    ('\u{FBE5}', "\u{067B}"),
+    ('\u{FBE6}', "\u{067B}"),
+    ('\u{FBE7}', "\u{067B}"),
+    ('\u{FBE8}', "\u{0649}"),
+    ('\u{FBE9}', "\u{0649}"),
+    ('\u{FBEA}', "\u{0649}\u{0674}l"),
+    ('\u{FBEB}', "\u{0649}\u{0674}l"),
+    ('\u{FBEC}', "\u{0649}\u{0674}o"),
+    ('\u{FBED}', "\u{0649}\u{0674}o"),
+    ('\u{FBEE}', "\u{0649}\u{0674}\u{0648}"),
+    ('\u{FBEF}', "\u{0649}\u{0674}\u{0648}"),
+    ('\u{FBF0}', "\u{0649}\u{0674}\u{0648}\u{0313}"),
+    ('\u{FBF1}', "\u{0649}\u{0674}\u{0648}\u{0313}"),
+    ('\u{FBF2}', "\u{0649}\u{0674}\u{0648}\u{0306}"),
+    ('\u{FBF3}', "\u{0649}\u{0674}\u{0648}\u{0306}"),
+    ('\u{FBF4}', "\u{0649}\u{0674}\u{0648}\u{0670}"),
+    ('\u{FBF5}', "\u{0649}\u{0674}\u{0648}\u{0670}"),
+    ('\u{FBF6}', "\u{0649}\u{0674}\u{067B}"),
+    ('\u{FBF7}', "\u{0649}\u{0674}\u{067B}"),
+    ('\u{FBF8}', "\u{0649}\u{0674}\u{067B}"),
+    ('\u{FBF9}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FBFA}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FBFB}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FBFC}', "\u{0649}"),
+    ('\u{FBFD}', "\u{0649}"),
+    ('\u{FBFE}', "\u{0649}"),
+    ('\u{FBFF}', "\u{0649}"),
+    ('\u{FC00}', "\u{0649}\u{0674}\u{062C}"),
+    ('\u{FC01}', "\u{0649}\u{0674}\u{062D}"),
+    ('\u{FC02}', "\u{0649}\u{0674}\u{0645}"),
+    ('\u{FC03}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FC04}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FC05}', "\u{0628}\u{062C}"),
+    ('\u{FC06}', "\u{0628}\u{062D}"),
+    ('\u{FC07}', "\u{0628}\u{062E}"),
+    ('\u{FC08}', "\u{0628}\u{0645}"),
+    ('\u{FC09}', "\u{0628}\u{0649}"),
+    ('\u{FC0A}', "\u{0628}\u{0649}"),
+    ('\u{FC0B}', "\u{062A}\u{062C}"),
+    ('\u{FC0C}', "\u{062A}\u{062D}"),
+    ('\u{FC0D}', "\u{062A}\u{062E}"),
+    ('\u{FC0E}', "\u{062A}\u{0645}"),
+    ('\u{FC0F}', "\u{062A}\u{0649}"),
+    ('\u{FC10}', "\u{062A}\u{0649}"),
+    ('\u{FC11}', "\u{0649}\u{06DB}\u{062C}"),
+    ('\u{FC12}', "\u{0649}\u{06DB}\u{0645}"),
+    ('\u{FC13}', "\u{0649}\u{06DB}\u{0649}"),
+    ('\u{FC14}', "\u{0649}\u{06DB}\u{0649}"),
+    ('\u{FC15}', "\u{062C}\u{062D}"),
+    ('\u{FC16}', "\u{062C}\u{0645}"),
+    ('\u{FC17}', "\u{062D}\u{062C}"),
+    ('\u{FC18}', "\u{062D}\u{0645}"),
+    ('\u{FC19}', "\u{062E}\u{062C}"),
+    ('\u{FC1A}', "\u{062E}\u{062D}"),
+    ('\u{FC1B}', "\u{062E}\u{0645}"),
+    ('\u{FC1C}', "\u{0633}\u{062C}"),
+    ('\u{FC1D}', "\u{0633}\u{062D}"),
+    ('\u{FC1E}', "\u{0633}\u{062E}"),
+    ('\u{FC1F}', "\u{0633}\u{0645}"),
+    ('\u{FC20}', "\u{0635}\u{062D}"),
+    ('\u{FC21}', "\u{0635}\u{0645}"),
+    ('\u{FC22}', "\u{0636}\u{062C}"),
+    ('\u{FC23}', "\u{0636}\u{062D}"),
+    ('\u{FC24}', "\u{0636}\u{062E}"),
+    ('\u{FC25}', "\u{0636}\u{0645}"),
+    ('\u{FC26}', "\u{0637}\u{062D}"),
+    ('\u{FC27}', "\u{0637}\u{0645}"),
+    ('\u{FC28}', "\u{0638}\u{0645}"),
+    ('\u{FC29}', "\u{0639}\u{062C}"),
+    ('\u{FC2A}', "\u{0639}\u{0645}"),
+    ('\u{FC2B}', "\u{063A}\u{062C}"),
+    ('\u{FC2C}', "\u{063A}\u{0645}"),
+    ('\u{FC2D}', "\u{0641}\u{062C}"),
+    ('\u{FC2E}', "\u{0641}\u{062D}"),
+    ('\u{FC2F}', "\u{0641}\u{062E}"),
+    ('\u{FC30}', "\u{0641}\u{0645}"),
+    ('\u{FC31}', "\u{0641}\u{0649}"),
+    ('\u{FC32}', "\u{0641}\u{0649}"),
+    ('\u{FC33}', "\u{0642}\u{062D}"),
+    ('\u{FC34}', "\u{0642}\u{0645}"),
+    ('\u{FC35}', "\u{0642}\u{0649}"),
+    ('\u{FC36}', "\u{0642}\u{0649}"),
+    ('\u{FC37}', "\u{0643}l"),
+    ('\u{FC38}', "\u{0643}\u{062C}"),
+    ('\u{FC39}', "\u{0643}\u{062D}"),
+    ('\u{FC3A}', "\u{0643}\u{062E}"),
+    ('\u{FC3B}', "\u{0643}\u{0644}"),
+    ('\u{FC3C}', "\u{0643}\u{0645}"),
+    ('\u{FC3D}', "\u{0643}\u{0649}"),
+    ('\u{FC3E}', "\u{0643}\u{0649}"),
+    ('\u{FC3F}', "\u{0644}\u{062C}"),
+    ('\u{FC40}', "\u{0644}\u{062D}"),
+    ('\u{FC41}', "\u{0644}\u{062E}"),
+    ('\u{FC42}', "\u{0644}\u{0645}"),
+    ('\u{FC43}', "\u{0644}\u{0649}"),
+    ('\u{FC44}', "\u{0644}\u{0649}"),
+    ('\u{FC45}', "\u{0645}\u{062C}"),
+    ('\u{FC46}', "\u{0645}\u{062D}"),
+    ('\u{FC47}', "\u{0645}\u{062E}"),
+    ('\u{FC48}', "\u{0645}\u{0645}"),
+    ('\u{FC49}', "\u{0645}\u{0649}"),
+    ('\u{FC4A}', "\u{0645}\u{0649}"),
+    ('\u{FC4B}', "\u{0628}\u{062E}"),
+    ('\u{FC4C}', "\u{0646}\u{062D}"),
+    ('\u{FC4D}', "\u{0646}\u{062E}"),
+    ('\u{FC4E}', "\u{0646}\u{0645}"),
+    ('\u{FC4F}', "\u{0646}\u{0649}"),
+    ('\u{FC50}', "\u{0646}\u{0649}"),
+    ('\u{FC51}', "o\u{062C}"),
+    ('\u{FC52}', "o\u{0645}"),
+    ('\u{FC53}', "o\u{0649}"),
+    ('\u{FC54}', "o\u{0649}"),
+    ('\u{FC55}', "\u{0649}\u{062C}"),
+    ('\u{FC56}', "\u{0649}\u{062D}"),
+    ('\u{FC57}', "\u{0649}\u{062E}"),
+    ('\u{FC58}', "\u{0649}\u{0645}"),
+    ('\u{FC59}', "\u{0649}\u{0649}"),
+    ('\u{FC5A}', "\u{0649}\u{0649}"),
+    ('\u{FC5B}', "\u{0630}\u{0670}"),
+    ('\u{FC5C}', "\u{0631}\u{0670}"),
+    ('\u{FC5D}', "\u{0649}\u{0670}"),
+    ('\u{FC5E}', "\u{FE72}\u{0651}"),
+    ('\u{FC5F}', "\u{FE74}\u{0651}"),
+    ('\u{FC60}', "\u{FE76}\u{0651}"),
+    ('\u{FC61}', "\u{FE78}\u{0651}"),
+    ('\u{FC62}', "\u{FE7A}\u{0651}"),
+    ('\u{FC63}', "\u{FE7C}\u{0670}"),
+    ('\u{FC64}', "\u{0649}\u{0674}\u{0631}"),
+    ('\u{FC65}', "\u{0649}\u{0674}\u{0632}"),
+    ('\u{FC66}', "\u{0649}\u{0674}\u{0645}"),
+    ('\u{FC67}', "\u{0649}\u{0674}\u{0646}"),
+    ('\u{FC68}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FC69}', "\u{0649}\u{0674}\u{0649}"),
+    ('\u{FC6A}', "\u{0628}\u{0631}"),
+    ('\u{FC6B}', "\u{0628}\u{0632}"),
+    ('\u{FC6C}', "\u{0628}\u{0645}"),
+    ('\u{FC6D}', "\u{0628}\u{0646}"),
+    ('\u{FC6E}', "\u{0628}\u{0649}"),
+    ('\u{FC6F}', "\u{0628}\u{0649}"),
+    ('\u{FC70}', "\u{062A}\u{0631}"),
+    ('\u{FC71}', "\u{062A}\u{0632}"),
+    ('\u{FC72}', "\u{062A}\u{0645}"),
+    ('\u{FC73}', "\u{062A}\u{0646}"),
+    ('\u{FC74}', "\u{062A}\u{0649}"),
+    ('\u{FC75}', "\u{062A}\u{0649}"),
+    ('\u{FC76}', "\u{0649}\u{06DB}\u{0631}"),
+    ('\u{FC77}', "\u{0649}\u{06DB}\u{0632}"),
+    ('\u{FC78}', "\u{0649}\u{06DB}\u{0645}"),
+    ('\u{FC79}', "\u{0649}\u{06DB}\u{0646}"),
+    ('\u{FC7A}', "\u{0649}\u{06DB}\u{0649}"),
+    ('\u{FC7B}', "\u{0649}\u{06DB}\u{0649}"),
+    ('\u{FC7C}', "\u{0641}\u{0649}"),
+    ('\u{FC7D}', "\u{0641}\u{0649}"),
+    ('\u{FC7E}', "\u{0642}\u{0649}"),
+    ('\u{FC7F}', "\u{0642}\u{0649}"),
+    ('\u{FC80}', "\u{0643}l"),
+    ('\u{FC81}', "\u{0643}\u{0644}"),
+    ('\u{FC82}', "\u{0643}\u{0645}"),
+    ('\u{FC83}', "\u{0643}\u{0649}"),
+    ('\u{FC84}', "\u{0643}\u{0649}"),
+    ('\u{FC85}', "\u{0644}\u{0645}"),
+    ('\u{FC86}', "\u{0644}\u{0649}"),
+    ('\u{FC87}', "\u{0644}\u{0649}"),
+    ('\u{FC88}', "\u{0645}l"),
+    ('\u{FC89}', "\u{0645}\u{0645}"),
+    ('\u{FC8A}', "\u{0646}\u{0631}"),
+    ('\u{FC8B}', "\u{0646}\u{0632}"),
+    ('\u{FC8C}', "\u{0646}\u{0645}"),
+    ('\u{FC8D}', "\u{0646}\u{0646}"),
+    ('\u{FC8E}', "\u{0646}\u{0649}"),
+    ('\u{FC8F}', "\u{0646}\u{0649}"),
+    ('\u{FC90}', "\u{0649}\u{0670}"),
+    ('\u{FC91}', "\u{0649}\u{0631}"),
+    ('\u{FC92}', "\u{0649}\u{0632}"),
+    ('\u{FC93}', "\u{0649}\u{0645}"),
+    ('\u{FC94}', "\u{0649}\u{0646}"),
+    ('\u{FC95}', "\u{0649}\u{0649}"),
+    ('\u{FC96}', "\u{0649}\u{0649}"),
+    ('\u{FC97}', "\u{0649}\u{0674}\u{062C}"),
+    ('\u{FC98}', "\u{0649}\u{0674}\u{062D}"),
+    ('\u{FC99}', "\u{0649}\u{0674}\u{062E}"),
+    ('\u{FC9A}', "\u{0649}\u{0674}\u{0645}"),
+    ('\u{FC9B}', "\u{0649}\u{0674}o"),
+    ('\u{FC9C}', "\u{0628}\u{062C}"),
+    ('\u{FC9D}', "\u{0628}\u{062D}"),
+    ('\u{FC9E}', "\u{0628}\u{062E}"),
+    ('\u{FC9F}', "\u{0628}\u{0645}"),
+    ('\u{FCA0}', "\u{0628}o"),
+    ('\u{FCA1}', "\u{062A}\u{062C}"),
+    ('\u{FCA2}', "\u{062A}\u{062D}"),
+    ('\u{FCA3}', "\u{062A}\u{062E}"),
+    ('\u{FCA4}', "\u{062A}\u{0645}"),
+    ('\u{FCA5}', "\u{062A}o"),
+    ('\u{FCA6}', "\u{0649}\u{06DB}\u{0645}"),
+    ('\u{FCA7}', "\u{062C}\u{062D}"),
+    ('\u{FCA8}', "\u{062C}\u{0645}"),
+    ('\u{FCA9}', "\u{062D}\u{062C}"),
+    ('\u{FCAA}', "\u{062D}\u{0645}"),
+    ('\u{FCAB}', "\u{062E}\u{062C}"),
+    ('\u{FCAC}', "\u{062E}\u{0645}"),
+    ('\u{FCAD}', "\u{0633}\u{062C}"),
+    ('\u{FCAE}', "\u{0633}\u{062D}"),
+    ('\u{FCAF}', "\u{0633}\u{062E}"),
+    ('\u{FCB0}', "\u{0633}\u{0645}"),
+    ('\u{FCB1}', "\u{0635}\u{062D}"),
+    ('\u{FCB2}', "\u{0635}\u{062E}"),
+    ('\u{FCB3}', "\u{0635}\u{0645}"),
+    ('\u{FCB4}', "\u{0636}\u{062C}"),
+    ('\u{FCB5}', "\u{0636}\u{062D}"),
+    ('\u{FCB6}', "\u{0636}\u{062E}"),
+    ('\u{FCB7}', "\u{0636}\u{0645}"),
+    ('\u{FCB8}', "\u{0637}\u{062D}"),
+    ('\u{FCB9}', "\u{0638}\u{0645}"),
+    ('\u{FCBA}', "\u{0639}\u{062C}"),
+    ('\u{FCBB}', "\u{0639}\u{0645}"),
+    ('\u{FCBC}', "\u{063A}\u{062C}"),
+    ('\u{FCBD}', "\u{063A}\u{0645}"),
+    ('\u{FCBE}', "\u{0641}\u{062C}"),
+    ('\u{FCBF}', "\u{0641}\u{062D}"),
+    ('\u{FCC0}', "\u{0641}\u{062E}"),
+    ('\u{FCC1}', "\u{0641}\u{0645}"),
+    ('\u{FCC2}', "\u{0642}\u{062D}"),
+    ('\u{FCC3}', "\u{0642}\u{0645}"),
+    ('\u{FCC4}', "\u{0643}\u{062C}"),
+    ('\u{FCC5}', "\u{0643}\u{062D}"),
+    ('\u{FCC6}', "\u{0643}\u{062E}"),
+    ('\u{FCC7}', "\u{0643}\u{0644}"),
+    ('\u{FCC8}', "\u{0643}\u{0645}"),
+    ('\u{FCC9}', "\u{0644}\u{062C}"),
+    ('\u{FCCA}', "\u{0644}\u{062D}"),
+    ('\u{FCCB}', "\u{0644}\u{062E}"),
+    ('\u{FCCC}', "\u{0644}\u{0645}"),
+    ('\u{FCCD}', "\u{0644}o"),
+    ('\u{FCCE}', "\u{0645}\u{062C}"),
+    ('\u{FCCF}', "\u{0645}\u{062D}"),
+    ('\u{FCD0}', "\u{0645}\u{062E}"),
+    ('\u{FCD1}', "\u{0645}\u{0645}"),
+    ('\u{FCD2}', "\u{0628}\u{062E}"),
+    ('\u{FCD3}', "\u{0646}\u{062D}"),
+    ('\u{FCD4}', "\u{0646}\u{062E}"),
+    ('\u{FCD5}', "\u{0646}\u{0645}"),
+    ('\u{FCD6}', "\u{0646}o"),
+    ('\u{FCD7}', "o\u{062C}"),
+    ('\u{FCD8}', "o\u{0645}"),
+    ('\u{FCD9}', "o\u{0670}"),
+    ('\u{FCDA}', "\u{0649}\u{062C}"),
+    ('\u{FCDB}', "\u{0649}\u{062D}"),
+    ('\u{FCDC}', "\u{0649}\u{062E}"),
+    ('\u{FCDD}', "\u{0649}\u{0645}"),
+    ('\u{FCDE}', "\u{0649}o"),
+    ('\u{FCDF}', "\u{0649}\u{0674}\u{0645}"),
+    ('\u{FCE0}', "\u{0649}\u{0674}o"),
+    ('\u{FCE1}', "\u{0628}\u{0645}"),
+    ('\u{FCE2}', "\u{0628}o"),
+    ('\u{FCE3}', "\u{062A}\u{0645}"),
+    ('\u{FCE4}', "\u{062A}o"),
+    ('\u{FCE5}', "\u{0649}\u{06DB}\u{0645}"),
+    ('\u{FCE6}', "\u{0649}\u{06DB}o"),
+    ('\u{FCE7}', "\u{0633}\u{0645}"),
+    ('\u{FCE8}', "\u{0633}o"),
+    ('\u{FCE9}', "\u{0633}\u{06DB}\u{0645}"),
+    ('\u{FCEA}', "\u{0633}\u{06DB}o"),
+    ('\u{FCEB}', "\u{0643}\u{0644}"),
+    ('\u{FCEC}', "\u{0643}\u{0645}"),
+    ('\u{FCED}', "\u{0644}\u{0645}"),
+    ('\u{FCEE}', "\u{0646}\u{0645}"),
+    ('\u{FCEF}', "\u{0646}o"),
+    ('\u{FCF0}', "\u{0649}\u{0645}"),
+    ('\u{FCF1}', "\u{0649}o"),
+    ('\u{FCF2}', "\u{FE77}\u{0651}"),
+    ('\u{FCF3}', "\u{FE79}\u{0651}"),
+    ('\u{FCF4}', "\u{FE7B}\u{0651}"),
+    ('\u{FCF5}', "\u{0637}\u{0649}"),
+    ('\u{FCF6}', "\u{0637}\u{0649}"),
+    ('\u{FCF7}', "\u{0639}\u{0649}"),
+    ('\u{FCF8}', "\u{0639}\u{0649}"),
+    ('\u{FCF9}', "\u{063A}\u{0649}"),
+    ('\u{FCFA}', "\u{063A}\u{0649}"),
+    ('\u{FCFB}', "\u{0633}\u{0649}"),
+    ('\u{FCFC}', "\u{0633}\u{0649}"),
+    ('\u{FCFD}', "\u{0633}\u{06DB}\u{0649}"),
+    ('\u{FCFE}', "\u{0633}\u{06DB}\u{0649}"),
+    ('\u{FCFF}', "\u{062D}\u{0649}"),
+    ('\u{FD00}', "\u{062D}\u{0649}"),
+    ('\u{FD01}', "\u{062C}\u{0649}"),
+    ('\u{FD02}', "\u{062C}\u{0649}"),
+    ('\u{FD03}', "\u{062E}\u{0649}"),
+    ('\u{FD04}', "\u{062E}\u{0649}"),
+    ('\u{FD05}', "\u{0635}\u{0649}"),
+    ('\u{FD06}', "\u{0635}\u{0649}"),
+    ('\u{FD07}', "\u{0636}\u{0649}"),
+    ('\u{FD08}', "\u{0636}\u{0649}"),
+    ('\u{FD09}', "\u{0633}\u{06DB}\u{062C}"),
+    ('\u{FD0A}', "\u{0633}\u{06DB}\u{062D}"),
+    ('\u{FD0B}', "\u{0633}\u{06DB}\u{062E}"),
+    ('\u{FD0C}', "\u{0633}\u{06DB}\u{0645}"),
+    ('\u{FD0D}', "\u{0633}\u{06DB}\u{0631}"),
+    ('\u{FD0E}', "\u{0633}\u{0631}"),
+    ('\u{FD0F}', "\u{0635}\u{0631}"),
+    ('\u{FD10}', "\u{0636}\u{0631}"),
+    ('\u{FD11}', "\u{0637}\u{0649}"),
+    ('\u{FD12}', "\u{0637}\u{0649}"),
+    ('\u{FD13}', "\u{0639}\u{0649}"),
+    ('\u{FD14}', "\u{0639}\u{0649}"),
+    ('\u{FD15}', "\u{063A}\u{0649}"),
+    ('\u{FD16}', "\u{063A}\u{0649}"),
+    ('\u{FD17}', "\u{0633}\u{0649}"),
+    ('\u{FD18}', "\u{0633}\u{0649}"),
+    ('\u{FD19}', "\u{0633}\u{06DB}\u{0649}"),
+    ('\u{FD1A}', "\u{0633}\u{06DB}\u{0649}"),
+    ('\u{FD1B}', "\u{062D}\u{0649}"),
+    ('\u{FD1C}', "\u{062D}\u{0649}"),
+    ('\u{FD1D}', "\u{062C}\u{0649}"),
+    ('\u{FD1E}', "\u{062C}\u{0649}"),
+    ('\u{FD1F}', "\u{062E}\u{0649}"),
+    ('\u{FD20}', "\u{062E}\u{0649}"),
+    ('\u{FD21}', "\u{0635}\u{0649}"),
+    ('\u{FD22}', "\u{0635}\u{0649}"),
+    ('\u{FD23}', "\u{0636}\u{0649}"),
+    ('\u{FD24}', "\u{0636}\u{0649}"),
+    ('\u{FD25}', "\u{0633}\u{06DB}\u{062C}"),
+    ('\u{FD26}', "\u{0633}\u{06DB}\u{062D}"),
+    ('\u{FD27}', "\u{0633}\u{06DB}\u{062E}"),
+    ('\u{FD28}', "\u{0633}\u{06DB}\u{0645}"),
+    ('\u{FD29}', "\u{0633}\u{06DB}\u{0631}"),
+    ('\u{FD2A}', "\u{0633}\u{0631}"),
+    ('\u{FD2B}', "\u{0635}\u{0631}"),
+    ('\u{FD2C}', "\u{0636}\u{0631}"),
+    ('\u{FD2D}', "\u{0633}\u{06DB}\u{062C}"),
+    ('\u{FD2E}', "\u{0633}\u{06DB}\u{062D}"),
+    ('\u{FD2F}', "\u{0633}\u{06DB}\u{062E}"),
+    ('\u{FD30}', "\u{0633}\u{06DB}\u{0645}"),
+    ('\u{FD31}', "\u{0633}o"),
+    ('\u{FD32}', "\u{0633}\u{06DB}o"),
+    ('\u{FD33}', "\u{0637}\u{0645}"),
+    ('\u{FD34}', "\u{0633}\u{062C}"),
+    ('\u{FD35}', "\u{0633}\u{062D}"),
+    ('\u{FD36}', "\u{0633}\u{062E}"),
+    ('\u{FD37}', "\u{0633}\u{06DB}\u{062C}"),
+    ('\u{FD38}', "\u{0633}\u{06DB}\u{062D}"),
+    ('\u{FD39}', "\u{0633}\u{06DB}\u{062E}"),
+    ('\u{FD3A}', "\u{0637}\u{0645}"),
+    ('\u{FD3B}', "\u{0638}\u{0645}"),
+    ('\u{FD3C}', "l\u{030B}"),
+    ('\u{FD3D}', "l\u{030B}"),
+    ('\u{FD3E}', "("),
+    ('\u{FD3F}', ")"),
+    ('\u{FD50}', "\u{062A}\u{062C}\u{0645}"),
+    ('\u{FD51}', "\u{062A}\u{062D}\u{062C}"),
+    ('\u{FD52}', "\u{062A}\u{062D}\u{062C}"),
+    ('\u{FD53}', "\u{062A}\u{062D}\u{0645}"),
+    ('\u{FD54}', "\u{062A}\u{062E}\u{0645}"),
+    ('\u{FD55}', "\u{062A}\u{0645}\u{062C}"),
+    ('\u{FD56}', "\u{062A}\u{0645}\u{062D}"),
+    ('\u{FD57}', "\u{062A}\u{0645}\u{062E}"),
+    ('\u{FD58}', "\u{062C}\u{0645}\u{062D}"),
+    ('\u{FD59}', "\u{062C}\u{0645}\u{062D}"),
+    ('\u{FD5A}', "\u{062D}\u{0645}\u{0649}"),
+    ('\u{FD5B}', "\u{062D}\u{0645}\u{0649}"),
+    ('\u{FD5C}', "\u{0633}\u{062D}\u{062C}"),
+    ('\u{FD5D}', "\u{0633}\u{062C}\u{062D}"),
+    ('\u{FD5E}', "\u{0633}\u{062C}\u{0649}"),
+    ('\u{FD5F}', "\u{0633}\u{0645}\u{062D}"),
+    ('\u{FD60}', "\u{0633}\u{0645}\u{062D}"),
+    ('\u{FD61}', "\u{0633}\u{0645}\u{062C}"),
+    ('\u{FD62}', "\u{0633}\u{0645}\u{0645}"),
+    ('\u{FD63}', "\u{0633}\u{0645}\u{0645}"),
+    ('\u{FD64}', "\u{0635}\u{062D}\u{062D}"),
+    ('\u{FD65}', "\u{0635}\u{062D}\u{062D}"),
+    ('\u{FD66}', "\u{0635}\u{0645}\u{0645}"),
+    ('\u{FD67}', "\u{0633}\u{06DB}\u{062D}\u{0645}"),
+    ('\u{FD68}', "\u{0633}\u{06DB}\u{062D}\u{0645}"),
+    ('\u{FD69}', "\u{0633}\u{06DB}\u{062C}\u{0649}"),
+    ('\u{FD6A}', "\u{0633}\u{06DB}\u{0645}\u{062E}"),
+    ('\u{FD6B}', "\u{0633}\u{06DB}\u{0645}\u{062E}"),
+    ('\u{FD6C}', "\u{0633}\u{06DB}\u{0645}\u{0645}"),
+    ('\u{FD6D}', "\u{0633}\u{06DB}\u{0645}\u{0645}"),
+    ('\u{FD6E}', "\u{0636}\u{062D}\u{0649}"),
+    ('\u{FD6F}', "\u{0636}\u{062E}\u{0645}"),
+    ('\u{FD70}', "\u{0636}\u{062E}\u{0645}"),
+    ('\u{FD71}', "\u{0637}\u{0645}\u{062D}"),
+    ('\u{FD72}', "\u{0637}\u{0645}\u{062D}"),
+    ('\u{FD73}', "\u{0637}\u{0645}\u{0645}"),
+    ('\u{FD74}', "\u{0637}\u{0645}\u{0649}"),
+    ('\u{FD75}', "\u{0639}\u{062C}\u{0645}"),
+    ('\u{FD76}', "\u{0639}\u{0645}\u{0645}"),
+    ('\u{FD77}', "\u{0639}\u{0645}\u{0645}"),
+    ('\u{FD78}', "\u{0639}\u{0645}\u{0649}"),
+    ('\u{FD79}', "\u{063A}\u{0645}\u{0645}"),
+    ('\u{FD7A}', "\u{063A}\u{0645}\u{0649}"),
+    ('\u{FD7B}', "\u{063A}\u{0645}\u{0649}"),
+    ('\u{FD7C}', "\u{0641}\u{062E}\u{0645}"),
+    ('\u{FD7D}', "\u{0641}\u{062E}\u{0645}"),
+    ('\u{FD7E}', "\u{0642}\u{0645}\u{062D}"),
+    ('\u{FD7F}', "\u{0642}\u{0645}\u{0645}"),
+    ('\u{FD80}', "\u{0644}\u{062D}\u{0645}"),
+    ('\u{FD81}', "\u{0644}\u{062D}\u{0649}"),
+    ('\u{FD82}', "\u{0644}\u{062D}\u{0649}"),
+    ('\u{FD83}', "\u{0644}\u{062C}\u{062C}"),
+    ('\u{FD84}', "\u{0644}\u{062C}\u{062C}"),
+    ('\u{FD85}', "\u{0644}\u{062E}\u{0645}"),
+    ('\u{FD86}', "\u{0644}\u{062E}\u{0645}"),
+    ('\u{FD87}', "\u{0644}\u{0645}\u{062D}"),
+    ('\u{FD88}', "\u{0644}\u{0645}\u{062D}"),
+    ('\u{FD89}', "\u{0645}\u{062D}\u{062C}"),
+    ('\u{FD8A}', "\u{0645}\u{062D}\u{0645}"),
+    ('\u{FD8B}', "\u{0645}\u{062D}\u{0649}"),
+    ('\u{FD8C}', "\u{0645}\u{062C}\u{062D}"),
+    ('\u{FD8D}', "\u{0645}\u{062C}\u{0645}"),
+    ('\u{FD8E}', "\u{0645}\u{062E}\u{062C}"),
+    ('\u{FD8F}', "\u{0645}\u{062E}\u{0645}"),
+    ('\u{FD92}', "\u{0645}\u{062C}\u{062E}"),
+    ('\u{FD93}', "o\u{0645}\u{062C}"),
+    ('\u{FD94}', "o\u{0645}\u{0645}"),
+    ('\u{FD95}', "\u{0646}\u{062D}\u{0645}"),
+    ('\u{FD96}', "\u{0646}\u{062D}\u{0649}"),
+    ('\u{FD97}', "\u{0646}\u{062C}\u{0645}"),
+    ('\u{FD98}', "\u{0646}\u{062C}\u{0645}"),
+    ('\u{FD99}', "\u{0646}\u{062C}\u{0649}"),
+    ('\u{FD9A}', "\u{0646}\u{0645}\u{0649}"),
+    ('\u{FD9B}', "\u{0646}\u{0645}\u{0649}"),
+    ('\u{FD9C}', "\u{0649}\u{0645}\u{0645}"),
+    ('\u{FD9D}', "\u{0649}\u{0645}\u{0645}"),
+    ('\u{FD9E}', "\u{0628}\u{062E}\u{0649}"),
+    ('\u{FD9F}', "\u{062A}\u{062C}\u{0649}"),
+    ('\u{FDA0}', "\u{062A}\u{062C}\u{0649}"),
+    ('\u{FDA1}', "\u{062A}\u{062E}\u{0649}"),
+    ('\u{FDA2}', "\u{062A}\u{062E}\u{0649}"),
+    ('\u{FDA3}', "\u{062A}\u{0645}\u{0649}"),
+    ('\u{FDA4}', "\u{062A}\u{0645}\u{0649}"),
+    ('\u{FDA5}', "\u{062C}\u{0645}\u{0649}"),
+    ('\u{FDA6}', "\u{062C}\u{062D}\u{0649}"),
+    ('\u{FDA7}', "\u{062C}\u{0645}\u{0649}"),
+    ('\u{FDA8}', "\u{0633}\u{062E}\u{0649}"),
+    ('\u{FDA9}', "\u{0635}\u{062D}\u{0649}"),
+    ('\u{FDAA}', "\u{0633}\u{06DB}\u{062D}\u{0649}"),
+    ('\u{FDAB}', "\u{0636}\u{062D}\u{0649}"),
+    ('\u{FDAC}', "\u{0644}\u{062C}\u{0649}"),
+    ('\u{FDAD}', "\u{0644}\u{0645}\u{0649}"),
+    ('\u{FDAE}', "\u{0649}\u{062D}\u{0649}"),
+    ('\u{FDAF}', "\u{0649}\u{062C}\u{0649}"),
+    ('\u{FDB0}', "\u{0649}\u{0645}\u{0649}"),
+    ('\u{FDB1}', "\u{0645}\u{0645}\u{0649}"),
+    ('\u{FDB2}', "\u{0642}\u{0645}\u{0649}"),
+    ('\u{FDB3}', "\u{0646}\u{062D}\u{0649}"),
+    ('\u{FDB4}', "\u{0642}\u{0645}\u{062D}"),
+    ('\u{FDB5}', "\u{0644}\u{062D}\u{0645}"),
+    ('\u{FDB6}', "\u{0639}\u{0645}\u{0649}"),
+    ('\u{FDB7}', "\u{0643}\u{0645}\u{0649}"),
+    ('\u{FDB8}', "\u{0646}\u{062C}\u{062D}"),
+    ('\u{FDB9}', "\u{0645}\u{062E}\u{0649}"),
+    ('\u{FDBA}', "\u{0644}\u{062C}\u{0645}"),
+    ('\u{FDBB}', "\u{0643}\u{0645}\u{0645}"),
+    ('\u{FDBC}', "\u{0644}\u{062C}\u{0645}"),
+    ('\u{FDBD}', "\u{0646}\u{062C}\u{062D}"),
+    ('\u{FDBE}', "\u{062C}\u{062D}\u{0649}"),
+    ('\u{FDBF}', "\u{062D}\u{062C}\u{0649}"),
+    ('\u{FDC0}', "\u{0645}\u{062C}\u{0649}"),
+    ('\u{FDC1}', "\u{0641}\u{0645}\u{0649}"),
+    ('\u{FDC2}', "\u{0628}\u{062D}\u{0649}"),
+    ('\u{FDC3}', "\u{0643}\u{0645}\u{0645}"),
+    ('\u{FDC4}', "\u{0639}\u{062C}\u{0645}"),
+    ('\u{FDC5}', "\u{0635}\u{0645}\u{0645}"),
+    ('\u{FDC6}', "\u{0633}\u{062E}\u{0649}"),
+    ('\u{FDC7}', "\u{0646}\u{062C}\u{0649}"),
+    ('\u{FDF0}', "\u{0635}\u{0644}\u{0649}"),
+    ('\u{FDF1}', "\u{0642}\u{0644}\u{0649}"),
+    ('\u{FDF2}', "l\u{0644}\u{0644}\u{0651}\u{0670}o"),
+    ('\u{FDF3}', "l\u{0643}\u{0628}\u{0631}"),
+    ('\u{FDF4}', "\u{0645}\u{062D}\u{0645}\u{062F}"),
+    ('\u{FDF5}', "\u{0635}\u{0644}\u{0639}\u{0645}"),
+    ('\u{FDF6}', "\u{0631}\u{0633}\u{0648}\u{0644}"),
+    ('\u{FDF7}', "\u{0639}\u{0644}\u{0649}o"),
+    ('\u{FDF8}', "\u{0648}\u{0633}\u{0644}\u{0645}"),
+    ('\u{FDF9}', "\u{0635}\u{0644}\u{0649}"),
+    ('\u{FDFA}', "\u{0635}\u{0644}\u{0649} l\u{0644}\u{0644}o \u{0639}\u{0644}\u{0649}o \u{0648}\u{0633}\u{0644}\u{0645}"),
+    ('\u{FDFB}', "\u{062C}\u{0644} \u{062C}\u{0644}l\u{0644}o"),
+    ('\u{FDFC}', "\u{0631}\u{0649}l\u{0644}"),
+    ('\u{FE19}', "\u{2D57}"),
+    ('\u{FE30}', ":"),
+    ('\u{FE31}', "\u{2502}"),
+    ('\u{FE34}', "\u{2307}"),
+    ('\u{FE35}', "\u{23DC}"),
+    ('\u{FE36}', "\u{23DD}"),
+    ('\u{FE37}', "\u{23DE}"),
+    ('\u{FE38}', "\u{23DF}"),
+    ('\u{FE39}', "\u{23E0}"),
+    ('\u{FE3A}', "\u{23E1}"),
+    ('\u{FE49}', "\u{02C9}"),
+    ('\u{FE4A}', "\u{02C9}"),
+    ('\u{FE4B}', "\u{02C9}"),
+    ('\u{FE4C}', "\u{02C9}"),
+    ('\u{FE4D}', "_"),
+    ('\u{FE4E}', "_"),
+    ('\u{FE4F}', "_"),
+    ('\u{FE58}', "-"),
+    ('\u{FE68}', "\\"),
+    ('\u{FE80}', "\u{0621}"),
+    ('\u{FE81}', "\u{0622}"),
+    ('\u{FE82}', "\u{0622}"),
+    ('\u{FE83}', "l\u{0674}"),
+    ('\u{FE84}', "l\u{0674}"),
+    ('\u{FE85}', "\u{0648}\u{0674}"),
+    ('\u{FE86}', "\u{0648}\u{0674}"),
+    ('\u{FE87}', "l\u{0655}"),
+    ('\u{FE88}', "l\u{0655}"),
+    ('\u{FE89}', "\u{0649}\u{0674}"),
+    ('\u{FE8A}', "\u{0649}\u{0674}"),
+    ('\u{FE8B}', "\u{0649}\u{0674}"),
+    ('\u{FE8C}', "\u{0649}\u{0674}"),
+    ('\u{FE8D}', "l"),
+    ('\u{FE8E}', "l"),
+    ('\u{FE8F}', "\u{0628}"),
+    ('\u{FE90}', "\u{0628}"),
+    ('\u{FE91}', "\u{0628}"),
+    ('\u{FE92}', "\u{0628}"),
+    ('\u{FE93}', "\u{0629}"),
+    ('\u{FE94}', "\u{0629}"),
+    ('\u{FE95}', "\u{062A}"),
+    ('\u{FE96}', "\u{062A}"),
+    ('\u{FE97}', "\u{062A}"),
+    ('\u{FE98}', "\u{062A}"),
+    ('\u{FE99}', "\u{0649}\u{06DB}"),
+    ('\u{FE9A}', "\u{0649}\u{06DB}"),
+    ('\u{FE9B}', "\u{0649}\u{06DB}"),
+    ('\u{FE9C}', "\u{0649}\u{06DB}"),
+    ('\u{FE9D}', "\u{062C}"),
+    ('\u{FE9E}', "\u{062C}"),
+    ('\u{FE9F}', "\u{062C}"),
+    ('\u{FEA0}', "\u{062C}"),
+    ('\u{FEA1}', "\u{062D}"),
+    ('\u{FEA2}', "\u{062D}"),
+    ('\u{FEA3}', "\u{062D}"),
+    ('\u{FEA4}', "\u{062D}"),
+    ('\u{FEA5}', "\u{062E}"),
+    ('\u{FEA6}', "\u{062E}"),
+    ('\u{FEA7}', "\u{062E}"),
+    ('\u{FEA8}', "\u{062E}"),
+    ('\u{FEA9}', "\u{062F}"),
+    ('\u{FEAA}', "\u{062F}"),
+    ('\u{FEAB}', "\u{0630}"),
+    ('\u{FEAC}', "\u{0630}"),
+    ('\u{FEAD}', "\u{0631}"),
+    ('\u{FEAE}', "\u{0631}"),
+    ('\u{FEAF}', "\u{0632}"),
+    ('\u{FEB0}', "\u{0632}"),
+    ('\u{FEB1}', "\u{0633}"),
+    ('\u{FEB2}', "\u{0633}"),
+    ('\u{FEB3}', "\u{0633}"),
+    ('\u{FEB4}', "\u{0633}"),
+    ('\u{FEB5}', "\u{0633}\u{06DB}"),
+    ('\u{FEB6}', "\u{0633}\u{06DB}"),
+    ('\u{FEB7}', "\u{0633}\u{06DB}"),
+    ('\u{FEB8}', "\u{0633}\u{06DB}"),
+    ('\u{FEB9}', "\u{0635}"),
+    ('\u{FEBA}', "\u{0635}"),
+    ('\u{FEBB}', "\u{0635}"),
+    ('\u{FEBC}', "\u{0635}"),
+    ('\u{FEBD}', "\u{0636}"),
+    ('\u{FEBE}', "\u{0636}"),
+    ('\u{FEBF}', "\u{0636}"),
+    ('\u{FEC0}', "\u{0636}"),
+    ('\u{FEC1}', "\u{0637}"),
+    ('\u{FEC2}', "\u{0637}"),
+    ('\u{FEC3}', "\u{0637}"),
+    ('\u{FEC4}', "\u{0637}"),
+    ('\u{FEC5}', "\u{0638}"),
+    ('\u{FEC6}', "\u{0638}"),
+    ('\u{FEC7}', "\u{0638}"),
+    ('\u{FEC8}', "\u{0638}"),
+    ('\u{FEC9}', "\u{0639}"),
+    ('\u{FECA}', "\u{0639}"),
+    ('\u{FECB}', "\u{0639}"),
+    ('\u{FECC}', "\u{0639}"),
+    ('\u{FECD}', "\u{063A}"),
+    ('\u{FECE}', "\u{063A}"),
+    ('\u{FECF}', "\u{063A}"),
+    ('\u{FED0}', "\u{063A}"),
+    ('\u{FED1}', "\u{0641}"),
+    ('\u{FED2}', "\u{0641}"),
+    ('\u{FED3}', "\u{0641}"),
+    ('\u{FED4}', "\u{0641}"),
+    ('\u{FED5}', "\u{0642}"),
+    ('\u{FED6}', "\u{0642}"),
+    ('\u{FED7}', "\u{0642}"),
+    ('\u{FED8}', "\u{0642}"),
+    ('\u{FED9}', "\u{0643}"),
+    ('\u{FEDA}', "\u{0643}"),
+    ('\u{FEDB}', "\u{0643}"),
+    ('\u{FEDC}', "\u{0643}"),
+    ('\u{FEDD}', "\u{0644}"),
+    ('\u{FEDE}', "\u{0644}"),
+    ('\u{FEDF}', "\u{0644}"),
+    ('\u{FEE0}', "\u{0644}"),
+    ('\u{FEE1}', "\u{0645}"),
+    ('\u{FEE2}', "\u{0645}"),
+    ('\u{FEE3}', "\u{0645}"),
+    ('\u{FEE4}', "\u{0645}"),
+    ('\u{FEE5}', "\u{0646}"),
+    ('\u{FEE6}', "\u{0646}"),
+    ('\u{FEE7}', "\u{0646}"),
+    ('\u{FEE8}', "\u{0646}"),
+    ('\u{FEE9}', "o"),
+    ('\u{FEEA}', "o"),
+    ('\u{FEEB}', "o"),
+    ('\u{FEEC}', "o"),
+    ('\u{FEED}', "\u{0648}"),
+    ('\u{FEEE}', "\u{0648}"),
+    ('\u{FEEF}', "\u{0649}"),
+    ('\u{FEF0}', "\u{0649}"),
+    ('\u{FEF1}', "\u{0649}"),
+    ('\u{FEF2}', "\u{0649}"),
+    ('\u{FEF3}', "\u{0649}"),
+    ('\u{FEF4}', "\u{0649}"),
+    ('\u{FEF5}', "\u{0644}\u{0622}"),
+    ('\u{FEF6}', "\u{0644}\u{0622}"),
+    ('\u{FEF7}', "\u{0644}l\u{0674}"),
+    ('\u{FEF8}', "\u{0644}l\u{0674}"),
+    ('\u{FEF9}', "\u{0644}l\u{0655}"),
+    ('\u{FEFA}', "\u{0644}l\u{0655}"),
+    ('\u{FEFB}', "\u{0644}l"),
+    ('\u{FEFC}', "\u{0644}l"),
+    ('\u{FF01}', "!"),
+    ('\u{FF02}', "''"),
+    ('\u{FF07}', "'"),
+    ('\u{FF0D}', "\u{30FC}"),
+    ('\u{FF1A}', ":"),
+    ('\u{FF21}', "A"),
+    ('\u{FF22}', "B"),
+    ('\u{FF23}', "C"),
+    ('\u{FF25}', "E"),
+    ('\u{FF28}', "H"),
+    ('\u{FF29}', "l"),
+    ('\u{FF2A}', "J"),
+    ('\u{FF2B}', "K"),
+    ('\u{FF2D}', "M"),
+    ('\u{FF2E}', "N"),
+    ('\u{FF2F}', "O"),
+    ('\u{FF30}', "P"),
+    ('\u{FF33}', "S"),
+    ('\u{FF34}', "T"),
+    ('\u{FF38}', "X"),
+    ('\u{FF39}', "Y"),
+    ('\u{FF3A}', "Z"),
+    ('\u{FF3B}', "("),
+    ('\u{FF3C}', "\\"),
+    ('\u{FF3D}', ")"),
+    ('\u{FF3E}', "\u{FE3F}"),
+    ('\u{FF40}', "'"),
+    ('\u{FF41}', "a"),
+    ('\u{FF43}', "c"),
+    ('\u{FF45}', "e"),
+    ('\u{FF47}', "g"),
+    ('\u{FF48}', "h"),
+    ('\u{FF49}', "i"),
+    ('\u{FF4A}', "j"),
+    ('\u{FF4C}', "l"),
+    ('\u{FF4F}', "o"),
+    ('\u{FF50}', "p"),
+    ('\u{FF53}', "s"),
+    ('\u{FF56}', "v"),
+    ('\u{FF58}', "x"),
+    ('\u{FF59}', "y"),
+    ('\u{FF5C}', "\u{2502}"),
+    ('\u{FF5E}', "\u{301C}"),
+    ('\u{FF65}', "\u{00B7}"),
+    ('\u{FFE3}', "\u{02C9}"),
+    ('\u{FFE8}', "l"),
+    ('\u{FFED}', "\u{25AA}"),
+    ('\u{10101}', "\u{00B7}"),
+    ('\u{1018E}', "N\u{030A}"),
+    ('\u{10196}', "X\u{0335}"),
+    ('\u{10197}', "V\u{0335}"),
+    ('\u{10198}', "l\u{0335}l\u{0335}S\u{0335}"),
+    ('\u{10199}', "l\u{0335}l\u{0335}"),
+    ('\u{101A0}', "\u{2CE8}"),
+    ('\u{10282}', "B"),
+    ('\u{10285}', "\u{0394}"),
+    ('\u{10286}', "E"),
+    ('\u{10287}', "F"),
+    ('\u{1028A}', "l"),
+    ('\u{1028D}', "\u{0245}"),
+    ('\u{10290}', "X"),
+    ('\u{10292}', "O"),
+    ('\u{10294}', "\u{16DC}"),
+    ('\u{10295}', "P"),
+    ('\u{10296}', "S"),
+    ('\u{10297}', "T"),
+    ('\u{1029B}', "+"),
+    ('\u{102A0}', "A"),
+    ('\u{102A1}', "B"),
+    ('\u{102A2}', "C"),
+    ('\u{102A3}', "\u{0394}"),
+    ('\u{102A5}', "F"),
+    ('\u{102AB}', "O"),
+    ('\u{102AD}', "\u{03D8}"),
+    ('\u{102B0}', "M"),
+    ('\u{102B1}', "T"),
+    ('\u{102B2}', "Y"),
+    ('\u{102B3}', "\u{03A6}"),
+    ('\u{102B4}', "X"),
+    ('\u{102B5}', "\u{03A8}"),
+    ('\u{102B6}', "\u{03A9}"),
+    ('\u{102B8}', "\u{2D40}"),
+    ('\u{102CF}', "H"),
+    ('\u{102E1}', "\u{062F}"),
+    ('\u{102E4}', "\u{0648}"),
+    ('\u{102E8}', "\u{0637}"),
+    ('\u{102F2}', "\u{0635}"),
+    ('\u{102F5}', "Z"),
+    ('\u{10301}', "B"),
+    ('\u{10302}', "C"),
+    ('\u{10309}', "l"),
+    ('\u{10311}', "M"),
+    ('\u{10312}', "\u{03D8}"),
+    ('\u{10315}', "T"),
+    ('\u{10317}', "X"),
+    ('\u{1031A}', "8"),
+    ('\u{1031F}', "*"),
+    ('\u{10320}', "l"),
+    ('\u{10322}', "X"),
+    ('\u{103D1}', "\u{10382}"),
+    ('\u{103D3}', "\u{10393}"),
+    ('\u{10401}', "\u{0190}"),
+    ('\u{10404}', "O"),
+    ('\u{10411}', "\u{A4F6}"),
+    ('\u{10415}', "C"),
+    ('\u{1041B}', "L"),
+    ('\u{1041F}', "\u{2C70}"),
+    ('\u{10420}', "S"),
+    ('\u{10423}', "\u{0186}"),
+    ('\u{10425}', "\u{0418}"),
+    ('\u{10429}', "\u{A793}"),
+    ('\u{1042A}', "\u{029A}"),
+    ('\u{1042C}', "o"),
+    ('\u{1043D}', "c"),
+    ('\u{1043F}', "\u{0277}"),
+    ('\u{10442}', "\u{025E}"),
+    ('\u{10443}', "\u{029F}"),
+    ('\u{10448}', "s"),
+    ('\u{1044B}', "\u{0254}"),
+    ('\u{1044D}', "\u{1D0E}"),
+    ('\u{104A0}', "\u{10486}"),
+    ('\u{104B0}', "\u{0245}"),
+    ('\u{104B4}', "R"),
+    ('\u{104BC}', "\u{04C3}"),
+    ('\u{104C2}', "O"),
+    ('\u{104C3}', "\u{0298}"),
+    ('\u{104C4}', "\u{00DE}"),
+    ('\u{104CD}', "\u{040B}"),
+    ('\u{104CE}', "U"),
+    ('\u{104D0}', "\u{16E6}"),
+    ('\u{104D1}', "\u{03A8}"),
+    ('\u{104D2}', "7"),
+    ('\u{104D8}', "\u{028C}"),
+    ('\u{104DB}', "\u{03BB}"),
+    ('\u{104EA}', "o"),
+    ('\u{104EB}', "\u{A669}"),
+    ('\u{104F6}', "u"),
+    ('\u{104F9}', "\u{03C8}"),
+    ('\u{10513}', "N"),
+    ('\u{10516}', "O"),
+    ('\u{10518}', "K"),
+    ('\u{1051C}', "C"),
+    ('\u{1051D}', "V"),
+    ('\u{10525}', "F"),
+    ('\u{10526}', "L"),
+    ('\u{10527}', "X"),
+    ('\u{10A3A}', "\u{0323}"),
+    ('\u{10A50}', "."),
+    ('\u{10A57}', "\u{10A56}\u{10A56}"),
+    ('\u{10CFA}', "\u{10CA5}"),
+    ('\u{10CFC}', "\u{10C82}"),
+    ('\u{110BB}', "\u{0970}"),
+    ('\u{111C7}', "\u{0970}"),
+    ('\u{111CA}', "\u{0323}"),
+    ('\u{111CB}', "\u{093A}"),
+    ('\u{111DB}', "\u{A8FC}"),
+    ('\u{111DC}', "\u{A8FB}"),
+    ('\u{111DE}', "\u{2248}"),
+    ('\u{11300}', "\u{030A}"),
+    ('\u{11413}', "\u{11434}\u{11442}\u{11412}"),
+    ('\u{11419}', "\u{11434}\u{11442}\u{11418}"),
+    ('\u{11424}', "\u{11434}\u{11442}\u{11423}"),
+    ('\u{1142A}', "\u{11434}\u{11442}\u{11429}"),
+    ('\u{1142D}', "\u{11434}\u{11442}\u{1142C}"),
+    ('\u{1142F}', "\u{11434}\u{11442}\u{1142E}"),
+    ('\u{1144C}', "\u{1144B}\u{1144B}"),
+    ('\u{11492}', "\u{0998}"),
+    ('\u{11494}', "\u{099A}"),
+    ('\u{11496}', "\u{099C}"),
+    ('\u{11498}', "\u{099E}"),
+    ('\u{11499}', "\u{099F}"),
+    ('\u{1149B}', "\u{09A1}"),
+    ('\u{1149D}', "\u{09B2}"),
+    ('\u{1149E}', "\u{09A4}"),
+    ('\u{1149F}', "\u{09A5}"),
+    ('\u{114A0}', "\u{09A6}"),
+    ('\u{114A1}', "\u{09A7}"),
+    ('\u{114A2}', "\u{09A8}"),
+    ('\u{114A3}', "\u{09AA}"),
+    ('\u{114A7}', "\u{09AE}"),
+    ('\u{114A8}', "\u{09AF}"),
+    ('\u{114A9}', "\u{09AC}"),
+    ('\u{114AA}', "\u{09A3}"),
+    ('\u{114AB}', "\u{09B0}"),
+    ('\u{114AD}', "\u{09B7}"),
+    ('\u{114AE}', "\u{09B8}"),
+    ('\u{114B0}', "\u{09BE}"),
+    ('\u{114B1}', "\u{09BF}"),
+    ('\u{114B9}', "\u{09C7}"),
+    ('\u{114BC}', "\u{09CB}"),
+    ('\u{114BD}', "\u{09D7}"),
+    ('\u{114BE}', "\u{09CC}"),
+    ('\u{114BF}', "\u{0306}\u{0307}"),
+    ('\u{114C1}', "\u{0983}"),
+    ('\u{114C2}', "\u{09CD}"),
+    ('\u{114C3}', "\u{0323}"),
+    ('\u{114C4}', "\u{09BD}"),
+    ('\u{114C5}', "w\u{0307}"),
+    ('\u{114D0}', "O"),
+    ('\u{114D1}', "\u{09E7}"),
+    ('\u{114D2}', "\u{09E8}"),
+    ('\u{114D6}', "\u{09EC}"),
+    ('\u{115D8}', "\u{11582}"),
+    ('\u{115D9}', "\u{11582}"),
+    ('\u{115DA}', "\u{11583}"),
+    ('\u{115DB}', "\u{11584}"),
+    ('\u{115DC}', "\u{115B2}"),
+    ('\u{115DD}', "\u{115B3}"),
+    ('\u{11642}', "\u{11641}\u{11641}"),
+    ('\u{11700}', "rn"),
+    ('\u{11706}', "v"),
+    ('\u{1170A}', "w"),
+    ('\u{1170E}', "w"),
+    ('\u{1170F}', "w"),
+    ('\u{118A0}', "V"),
+    ('\u{118A2}', "F"),
+    ('\u{118A3}', "L"),
+    ('\u{118A4}', "Y"),
+    ('\u{118A6}', "E"),
+    ('\u{118A8}', "\u{2207}"),
+    ('\u{118A9}', "Z"),
+    ('\u{118AC}', "9"),
+    ('\u{118AE}', "E"),
+    ('\u{118AF}', "4"),
+    ('\u{118B2}', "L"),
+    ('\u{118B5}', "O"),
+    ('\u{118B7}', "\u{16DC}"),
+    ('\u{118B8}', "U"),
+    ('\u{118BB}', "5"),
+    ('\u{118BC}', "T"),
+    ('\u{118C0}', "v"),
+    ('\u{118C1}', "s"),
+    ('\u{118C2}', "F"),
+    ('\u{118C3}', "i"),
+    ('\u{118C4}', "z"),
+    ('\u{118C6}', "7"),
+    ('\u{118C8}', "o"),
+    ('\u{118CA}', "3"),
+    ('\u{118CC}', "9"),
+    ('\u{118CE}', "\u{A793}"),
+    ('\u{118D5}', "6"),
+    ('\u{118D6}', "9"),
+    ('\u{118D7}', "o"),
+    ('\u{118D8}', "u"),
+    ('\u{118DC}', "y"),
+    ('\u{118E0}', "O"),
+    ('\u{118E3}', "rn"),
+    ('\u{118E4}', "\u{0669}"),
+    ('\u{118E5}', "Z"),
+    ('\u{118E6}', "W"),
+    ('\u{118E9}', "C"),
+    ('\u{118EC}', "X"),
+    ('\u{118EF}', "W"),
+    ('\u{118F2}', "C"),
+    ('\u{11AE6}', "\u{11AE5}\u{11AEF}"),
+    ('\u{11AE7}', "\u{11AE5}\u{11AF0}"),
+    ('\u{11AE8}', "\u{11AE5}\u{11AE5}"),
+    ('\u{11AE9}', "\u{11AE5}\u{11AE5}\u{11AEF}"),
+    ('\u{11AEA}', "\u{11AE5}\u{11AE5}\u{11AF0}"),
+    ('\u{11AEC}', "\u{11AEB}\u{11AEF}"),
+    ('\u{11AED}', "\u{11AEB}\u{11AEB}"),
+    ('\u{11AEE}', "\u{11AEB}\u{11AEB}\u{11AEF}"),
+    ('\u{11AF4}', "\u{11AF3}\u{11AEF}"),
+    ('\u{11AF5}', "\u{11AF3}\u{11AF0}"),
+    ('\u{11AF6}', "\u{11AF3}\u{11AF3}"),
+    ('\u{11AF7}', "\u{11AF3}\u{11AF3}\u{11AEF}"),
+    ('\u{11AF8}', "\u{11AF3}\u{11AF3}\u{11AF0}"),
+    ('\u{11C42}', "\u{11C41}\u{11C41}"),
+    ('\u{11CB2}', "\u{11CAA}"),
+    ('\u{12038}', "\u{1039A}"),
+    ('\u{132F9}', "\u{1099E}"),
+    ('\u{16F07}', "\u{0393}"),
+    ('\u{16F08}', "V"),
+    ('\u{16F0A}', "T"),
+    ('\u{16F16}', "L"),
+    ('\u{16F1A}', "\u{0394}"),
+    ('\u{16F1C}', "\u{A658}"),
+    ('\u{16F26}', "\u{A4F6}"),
+    ('\u{16F28}', "l"),
+    ('\u{16F2D}', "\u{0190}"),
+    ('\u{16F35}', "R"),
+    ('\u{16F3A}', "S"),
+    ('\u{16F3B}', "3"),
+    ('\u{16F3D}', "\u{0245}"),
+    ('\u{16F3F}', ">"),
+    ('\u{16F40}', "A"),
+    ('\u{16F42}', "U"),
+    ('\u{16F43}', "Y"),
+    ('\u{16F51}', "'"),
+    ('\u{16F52}', "'"),
+    ('\u{1CCD6}', "A"),
+    ('\u{1CCD7}', "B"),
+    ('\u{1CCD8}', "C"),
+    ('\u{1CCD9}', "D"),
+    ('\u{1CCDA}', "E"),
+    ('\u{1CCDB}', "F"),
+    ('\u{1CCDC}', "G"),
+    ('\u{1CCDD}', "H"),
+    ('\u{1CCDE}', "l"),
+    ('\u{1CCDF}', "J"),
+    ('\u{1CCE0}', "K"),
+    ('\u{1CCE1}', "L"),
+    ('\u{1CCE2}', "M"),
+    ('\u{1CCE3}', "N"),
+    ('\u{1CCE4}', "O"),
+    ('\u{1CCE5}', "P"),
+    ('\u{1CCE6}', "Q"),
+    ('\u{1CCE7}', "R"),
+    ('\u{1CCE8}', "S"),
+    ('\u{1CCE9}', "T"),
+    ('\u{1CCEA}', "U"),
+    ('\u{1CCEB}', "V"),
+    ('\u{1CCEC}', "W"),
+    ('\u{1CCED}', "X"),
+    ('\u{1CCEE}', "Y"),
+    ('\u{1CCEF}', "Z"),
+    ('\u{1CCF0}', "O"),
+    ('\u{1CCF1}', "l"),
+    ('\u{1CCF2}', "2"),
+    ('\u{1CCF3}', "3"),
+    ('\u{1CCF4}', "4"),
+    ('\u{1CCF5}', "5"),
+    ('\u{1CCF6}', "6"),
+    ('\u{1CCF7}', "7"),
+    ('\u{1CCF8}', "8"),
+    ('\u{1CCF9}', "9"),
+    ('\u{1D114}', "{"),
+    ('\u{1D16D}', "."),
+    ('\u{1D202}', "\u{04FE}"),
+    ('\u{1D206}', "3"),
+    ('\u{1D20B}', "\u{0418}"),
+    ('\u{1D20D}', "V"),
+    ('\u{1D20F}', "\\"),
+    ('\u{1D212}', "7"),
+    ('\u{1D213}', "F"),
+    ('\u{1D214}', "\u{102BC}"),
+    ('\u{1D215}', "\u{A4F6}"),
+    ('\u{1D216}', "R"),
+    ('\u{1D217}', "\u{2C6F}"),
+    ('\u{1D21A}', "O\u{0335}"),
+    ('\u{1D21B}', "\u{2144}"),
+    ('\u{1D21C}', "\u{A4D5}"),
+    ('\u{1D221}', "\u{0190}"),
+    ('\u{1D222}', "\u{0460}"),
+    ('\u{1D22A}', "L"),
+    ('\u{1D22B}', "\u{A4F6}"),
+    ('\u{1D230}', "\u{A7FB}"),
+    ('\u{1D236}', "<"),
+    ('\u{1D237}', ">"),
+    ('\u{1D238}', "\u{228F}"),
+    ('\u{1D239}', "\u{2290}"),
+    ('\u{1D23A}', "/"),
+    ('\u{1D23B}', "\\"),
+    ('\u{1D23F}', "\u{16CB}"),
+    ('\u{1D245}', "\u{0548}"),
+    ('\u{1D400}', "A"),
+    ('\u{1D401}', "B"),
+    ('\u{1D402}', "C"),
+    ('\u{1D403}', "D"),
+    ('\u{1D404}', "E"),
+    ('\u{1D405}', "F"),
+    ('\u{1D406}', "G"),
+    ('\u{1D407}', "H"),
+    ('\u{1D408}', "l"),
+    ('\u{1D409}', "J"),
+    ('\u{1D40A}', "K"),
+    ('\u{1D40B}', "L"),
+    ('\u{1D40C}', "M"),
+    ('\u{1D40D}', "N"),
+    ('\u{1D40E}', "O"),
+    ('\u{1D40F}', "P"),
+    ('\u{1D410}', "Q"),
+    ('\u{1D411}', "R"),
+    ('\u{1D412}', "S"),
+    ('\u{1D413}', "T"),
+    ('\u{1D414}', "U"),
+    ('\u{1D415}', "V"),
+    ('\u{1D416}', "W"),
+    ('\u{1D417}', "X"),
+    ('\u{1D418}', "Y"),
+    ('\u{1D419}', "Z"),
+    ('\u{1D41A}', "a"),
+    ('\u{1D41B}', "b"),
+    ('\u{1D41C}', "c"),
+    ('\u{1D41D}', "d"),
+    ('\u{1D41E}', "e"),
+    ('\u{1D41F}', "f"),
+    ('\u{1D420}', "g"),
+    ('\u{1D421}', "h"),
+    ('\u{1D422}', "i"),
+    ('\u{1D423}', "j"),
+    ('\u{1D424}', "k"),
+    ('\u{1D425}', "l"),
+    ('\u{1D426}', "rn"),
+    ('\u{1D427}', "n"),
+    ('\u{1D428}', "o"),
+    ('\u{1D429}', "p"),
+    ('\u{1D42A}', "q"),
+    ('\u{1D42B}', "r"),
+    ('\u{1D42C}', "s"),
+    ('\u{1D42D}', "t"),
+    ('\u{1D42E}', "u"),
+    ('\u{1D42F}', "v"),
+    ('\u{1D430}', "w"),
+    ('\u{1D431}', "x"),
+    ('\u{1D432}', "y"),
+    ('\u{1D433}', "z"),
+    ('\u{1D434}', "A"),
+    ('\u{1D435}', "B"),
+    ('\u{1D436}', "C"),
+    ('\u{1D437}', "D"),
+    ('\u{1D438}', "E"),
+    ('\u{1D439}', "F"),
+    ('\u{1D43A}', "G"),
+    ('\u{1D43B}', "H"),
+    ('\u{1D43C}', "l"),
+    ('\u{1D43D}', "J"),
+    ('\u{1D43E}', "K"),
+    ('\u{1D43F}', "L"),
+    ('\u{1D440}', "M"),
+    ('\u{1D441}', "N"),
+    ('\u{1D442}', "O"),
+    ('\u{1D443}', "P"),
+    ('\u{1D444}', "Q"),
+    ('\u{1D445}', "R"),
+    ('\u{1D446}', "S"),
+    ('\u{1D447}', "T"),
+    ('\u{1D448}', "U"),
+    ('\u{1D449}', "V"),
+    ('\u{1D44A}', "W"),
+    ('\u{1D44B}', "X"),
+    ('\u{1D44C}', "Y"),
+    ('\u{1D44D}', "Z"),
+    ('\u{1D44E}', "a"),
+    ('\u{1D44F}', "b"),
+    ('\u{1D450}', "c"),
+    ('\u{1D451}', "d"),
+    ('\u{1D452}', "e"),
+    ('\u{1D453}', "f"),
+    ('\u{1D454}', "g"),
+    ('\u{1D456}', "i"),
+    ('\u{1D457}', "j"),
+    ('\u{1D458}', "k"),
+    ('\u{1D459}', "l"),
+    ('\u{1D45A}', "rn"),
+    ('\u{1D45B}', "n"),
+    ('\u{1D45C}', "o"),
+    ('\u{1D45D}', "p"),
+    ('\u{1D45E}', "q"),
+    ('\u{1D45F}', "r"),
+    ('\u{1D460}', "s"),
+    ('\u{1D461}', "t"),
+    ('\u{1D462}', "u"),
+    ('\u{1D463}', "v"),
+    ('\u{1D464}', "w"),
+    ('\u{1D465}', "x"),
+    ('\u{1D466}', "y"),
+    ('\u{1D467}', "z"),
+    ('\u{1D468}', "A"),
+    ('\u{1D469}', "B"),
+    ('\u{1D46A}', "C"),
+    ('\u{1D46B}', "D"),
+    ('\u{1D46C}', "E"),
+    ('\u{1D46D}', "F"),
+    ('\u{1D46E}', "G"),
+    ('\u{1D46F}', "H"),
+    ('\u{1D470}', "l"),
+    ('\u{1D471}', "J"),
+    ('\u{1D472}', "K"),
+    ('\u{1D473}', "L"),
+    ('\u{1D474}', "M"),
+    ('\u{1D475}', "N"),
+    ('\u{1D476}', "O"),
+    ('\u{1D477}', "P"),
+    ('\u{1D478}', "Q"),
+    ('\u{1D479}', "R"),
+    ('\u{1D47A}', "S"),
+    ('\u{1D47B}', "T"),
+    ('\u{1D47C}', "U"),
+    ('\u{1D47D}', "V"),
+    ('\u{1D47E}', "W"),
+    ('\u{1D47F}', "X"),
+    ('\u{1D480}', "Y"),
+    ('\u{1D481}', "Z"),
+    ('\u{1D482}', "a"),
+    ('\u{1D483}', "b"),
+    ('\u{1D484}', "c"),
+    ('\u{1D485}', "d"),
+    ('\u{1D486}', "e"),
+    ('\u{1D487}', "f"),
+    ('\u{1D488}', "g"),
+    ('\u{1D489}', "h"),
+    ('\u{1D48A}', "i"),
+    ('\u{1D48B}', "j"),
+    ('\u{1D48C}', "k"),
+    ('\u{1D48D}', "l"),
+    ('\u{1D48E}', "rn"),
+    ('\u{1D48F}', "n"),
+    ('\u{1D490}', "o"),
+    ('\u{1D491}', "p"),
+    ('\u{1D492}', "q"),
+    ('\u{1D493}', "r"),
+    ('\u{1D494}', "s"),
+    ('\u{1D495}', "t"),
+    ('\u{1D496}', "u"),
+    ('\u{1D497}', "v"),
+    ('\u{1D498}', "w"),
+    ('\u{1D499}', "x"),
+    ('\u{1D49A}', "y"),
+    ('\u{1D49B}', "z"),
+    ('\u{1D49C}', "A"),
+    ('\u{1D49E}', "C"),
+    ('\u{1D49F}', "D"),
+    ('\u{1D4A2}', "G"),
+    ('\u{1D4A5}', "J"),
+    ('\u{1D4A6}', "K"),
+    ('\u{1D4A9}', "N"),
+    ('\u{1D4AA}', "O"),
+    ('\u{1D4AB}', "P"),
+    ('\u{1D4AC}', "Q"),
+    ('\u{1D4AE}', "S"),
+    ('\u{1D4AF}', "T"),
+    ('\u{1D4B0}', "U"),
+    ('\u{1D4B1}', "V"),
+    ('\u{1D4B2}', "W"),
+    ('\u{1D4B3}', "X"),
+    ('\u{1D4B4}', "Y"),
+    ('\u{1D4B5}', "Z"),
+    ('\u{1D4B6}', "a"),
+    ('\u{1D4B7}', "b"),
+    ('\u{1D4B8}', "c"),
+    ('\u{1D4B9}', "d"),
+    ('\u{1D4BB}', "f"),
+    ('\u{1D4BD}', "h"),
+    ('\u{1D4BE}', "i"),
+    ('\u{1D4BF}', "j"),
+    ('\u{1D4C0}', "k"),
+    ('\u{1D4C1}', "l"),
+    ('\u{1D4C2}', "rn"),
+    ('\u{1D4C3}', "n"),
+    ('\u{1D4C5}', "p"),
+    ('\u{1D4C6}', "q"),
+    ('\u{1D4C7}', "r"),
+    ('\u{1D4C8}', "s"),
+    ('\u{1D4C9}', "t"),
+    ('\u{1D4CA}', "u"),
+    ('\u{1D4CB}', "v"),
+    ('\u{1D4CC}', "w"),
+    ('\u{1D4CD}', "x"),
+    ('\u{1D4CE}', "y"),
+    ('\u{1D4CF}', "z"),
+    ('\u{1D4D0}', "A"),
+    ('\u{1D4D1}', "B"),
+    ('\u{1D4D2}', "C"),
+    ('\u{1D4D3}', "D"),
+    ('\u{1D4D4}', "E"),
+    ('\u{1D4D5}', "F"),
+    ('\u{1D4D6}', "G"),
+    ('\u{1D4D7}', "H"),
+    ('\u{1D4D8}', "l"),
+    ('\u{1D4D9}', "J"),
+    ('\u{1D4DA}', "K"),
+    ('\u{1D4DB}', "L"),
+    ('\u{1D4DC}', "M"),
+    ('\u{1D4DD}', "N"),
+    ('\u{1D4DE}', "O"),
+    ('\u{1D4DF}', "P"),
+    ('\u{1D4E0}', "Q"),
+    ('\u{1D4E1}', "R"),
+    ('\u{1D4E2}', "S"),
+    ('\u{1D4E3}', "T"),
+    ('\u{1D4E4}', "U"),
+    ('\u{1D4E5}', "V"),
+    ('\u{1D4E6}', "W"),
+    ('\u{1D4E7}', "X"),
+    ('\u{1D4E8}', "Y"),
+    ('\u{1D4E9}', "Z"),
+    ('\u{1D4EA}', "a"),
+    ('\u{1D4EB}', "b"),
+    ('\u{1D4EC}', "c"),
+    ('\u{1D4ED}', "d"),
+    ('\u{1D4EE}', "e"),
+    ('\u{1D4EF}', "f"),
+    ('\u{1D4F0}', "g"),
+    ('\u{1D4F1}', "h"),
+    ('\u{1D4F2}', "i"),
+    ('\u{1D4F3}', "j"),
+    ('\u{1D4F4}', "k"),
+    ('\u{1D4F5}', "l"),
+    ('\u{1D4F6}', "rn"),
+    ('\u{1D4F7}', "n"),
+    ('\u{1D4F8}', "o"),
+    ('\u{1D4F9}', "p"),
+    ('\u{1D4FA}', "q"),
+    ('\u{1D4FB}', "r"),
+    ('\u{1D4FC}', "s"),
+    ('\u{1D4FD}', "t"),
+    ('\u{1D4FE}', "u"),
+    ('\u{1D4FF}', "v"),
+    ('\u{1D500}', "w"),
+    ('\u{1D501}', "x"),
+    ('\u{1D502}', "y"),
+    ('\u{1D503}', "z"),
+    ('\u{1D504}', "A"),
+    ('\u{1D505}', "B"),
+    ('\u{1D507}', "D"),
+    ('\u{1D508}', "E"),
+    ('\u{1D509}', "F"),
+    ('\u{1D50A}', "G"),
+    ('\u{1D50D}', "J"),
+    ('\u{1D50E}', "K"),
+    ('\u{1D50F}', "L"),
+    ('\u{1D510}', "M"),
+    ('\u{1D511}', "N"),
+    ('\u{1D512}', "O"),
+    ('\u{1D513}', "P"),
+    ('\u{1D514}', "Q"),
+    ('\u{1D516}', "S"),
+    ('\u{1D517}', "T"),
+    ('\u{1D518}', "U"),
+    ('\u{1D519}', "V"),
+    ('\u{1D51A}', "W"),
+    ('\u{1D51B}', "X"),
+    ('\u{1D51C}', "Y"),
+    ('\u{1D51E}', "a"),
+    ('\u{1D51F}', "b"),
+    ('\u{1D520}', "c"),
+    ('\u{1D521}', "d"),
+    ('\u{1D522}', "e"),
+    ('\u{1D523}', "f"),
+    ('\u{1D524}', "g"),
+    ('\u{1D525}', "h"),
+    ('\u{1D526}', "i"),
+    ('\u{1D527}', "j"),
+    ('\u{1D528}', "k"),
+    ('\u{1D529}', "l"),
+    ('\u{1D52A}', "rn"),
+    ('\u{1D52B}', "n"),
+    ('\u{1D52C}', "o"),
+    ('\u{1D52D}', "p"),
+    ('\u{1D52E}', "q"),
+    ('\u{1D52F}', "r"),
+    ('\u{1D530}', "s"),
+    ('\u{1D531}', "t"),
+    ('\u{1D532}', "u"),
+    ('\u{1D533}', "v"),
+    ('\u{1D534}', "w"),
+    ('\u{1D535}', "x"),
+    ('\u{1D536}', "y"),
+    ('\u{1D537}', "z"),
+    ('\u{1D538}', "A"),
+    ('\u{1D539}', "B"),
+    ('\u{1D53B}', "D"),
+    ('\u{1D53C}', "E"),
+    ('\u{1D53D}', "F"),
+    ('\u{1D53E}', "G"),
+    ('\u{1D540}', "l"),
+    ('\u{1D541}', "J"),
+    ('\u{1D542}', "K"),
+    ('\u{1D543}', "L"),
+    ('\u{1D544}', "M"),
+    ('\u{1D546}', "O"),
+    ('\u{1D54A}', "S"),
+    ('\u{1D54B}', "T"),
+    ('\u{1D54C}', "U"),
+    ('\u{1D54D}', "V"),
+    ('\u{1D54E}', "W"),
+    ('\u{1D54F}', "X"),
+    ('\u{1D550}', "Y"),
+    ('\u{1D552}', "a"),
+    ('\u{1D553}', "b"),
+    ('\u{1D554}', "c"),
+    ('\u{1D555}', "d"),
+    ('\u{1D556}', "e"),
+    ('\u{1D557}', "f"),
+    ('\u{1D558}', "g"),
+    ('\u{1D559}', "h"),
+    ('\u{1D55A}', "i"),
+    ('\u{1D55B}', "j"),
+    ('\u{1D55C}', "k"),
+    ('\u{1D55D}', "l"),
+    ('\u{1D55E}', "rn"),
+    ('\u{1D55F}', "n"),
+    ('\u{1D560}', "o"),
+    ('\u{1D561}', "p"),
+    ('\u{1D562}', "q"),
+    ('\u{1D563}', "r"),
+    ('\u{1D564}', "s"),
+    ('\u{1D565}', "t"),
+    ('\u{1D566}', "u"),
+    ('\u{1D567}', "v"),
+    ('\u{1D568}', "w"),
+    ('\u{1D569}', "x"),
+    ('\u{1D56A}', "y"),
+    ('\u{1D56B}', "z"),
+    ('\u{1D56C}', "A"),
+    ('\u{1D56D}', "B"),
+    ('\u{1D56E}', "C"),
+    ('\u{1D56F}', "D"),
+    ('\u{1D570}', "E"),
+    ('\u{1D571}', "F"),
+    ('\u{1D572}', "G"),
+    ('\u{1D573}', "H"),
+    ('\u{1D574}', "l"),
+    ('\u{1D575}', "J"),
+    ('\u{1D576}', "K"),
+    ('\u{1D577}', "L"),
+    ('\u{1D578}', "M"),
+    ('\u{1D579}', "N"),
+    ('\u{1D57A}', "O"),
+    ('\u{1D57B}', "P"),
+    ('\u{1D57C}', "Q"),
+    ('\u{1D57D}', "R"),
+    ('\u{1D57E}', "S"),
+    ('\u{1D57F}', "T"),
+    ('\u{1D580}', "U"),
+    ('\u{1D581}', "V"),
+    ('\u{1D582}', "W"),
+    ('\u{1D583}', "X"),
+    ('\u{1D584}', "Y"),
+    ('\u{1D585}', "Z"),
+    ('\u{1D586}', "a"),
+    ('\u{1D587}', "b"),
+    ('\u{1D588}', "c"),
+    ('\u{1D589}', "d"),
+    ('\u{1D58A}', "e"),
+    ('\u{1D58B}', "f"),
+    ('\u{1D58C}', "g"),
+    ('\u{1D58D}', "h"),
+    ('\u{1D58E}', "i"),
+    ('\u{1D58F}', "j"),
+    ('\u{1D590}', "k"),
+    ('\u{1D591}', "l"),
+    ('\u{1D592}', "rn"),
+    ('\u{1D593}', "n"),
+    ('\u{1D594}', "o"),
+    ('\u{1D595}', "p"),
+    ('\u{1D596}', "q"),
+    ('\u{1D597}', "r"),
+    ('\u{1D598}', "s"),
+    ('\u{1D599}', "t"),
+    ('\u{1D59A}', "u"),
+    ('\u{1D59B}', "v"),
+    ('\u{1D59C}', "w"),
+    ('\u{1D59D}', "x"),
+    ('\u{1D59E}', "y"),
+    ('\u{1D59F}', "z"),
+    ('\u{1D5A0}', "A"),
+    ('\u{1D5A1}', "B"),
+    ('\u{1D5A2}', "C"),
+    ('\u{1D5A3}', "D"),
+    ('\u{1D5A4}', "E"),
+    ('\u{1D5A5}', "F"),
+    ('\u{1D5A6}', "G"),
+    ('\u{1D5A7}', "H"),
+    ('\u{1D5A8}', "l"),
+    ('\u{1D5A9}', "J"),
+    ('\u{1D5AA}', "K"),
+    ('\u{1D5AB}', "L"),
+    ('\u{1D5AC}', "M"),
+    ('\u{1D5AD}', "N"),
+    ('\u{1D5AE}', "O"),
+    ('\u{1D5AF}', "P"),
+    ('\u{1D5B0}', "Q"),
+    ('\u{1D5B1}', "R"),
+    ('\u{1D5B2}', "S"),
+    ('\u{1D5B3}', "T"),
+    ('\u{1D5B4}', "U"),
+    ('\u{1D5B5}', "V"),
+    ('\u{1D5B6}', "W"),
+    ('\u{1D5B7}', "X"),
+    ('\u{1D5B8}', "Y"),
+    ('\u{1D5B9}', "Z"),
+    ('\u{1D5BA}', "a"),
+    ('\u{1D5BB}', "b"),
+    ('\u{1D5BC}', "c"),
+    ('\u{1D5BD}', "d"),
+    ('\u{1D5BE}', "e"),
+    ('\u{1D5BF}', "f"),
+    ('\u{1D5C0}', "g"),
+    ('\u{1D5C1}', "h"),
+    ('\u{1D5C2}', "i"),
+    ('\u{1D5C3}', "j"),
+    ('\u{1D5C4}', "k"),
+    ('\u{1D5C5}', "l"),
+    ('\u{1D5C6}', "rn"),
+    ('\u{1D5C7}', "n"),
+    ('\u{1D5C8}', "o"),
+    ('\u{1D5C9}', "p"),
+    ('\u{1D5CA}', "q"),
+    ('\u{1D5CB}', "r"),
+    ('\u{1D5CC}', "s"),
+    ('\u{1D5CD}', "t"),
+    ('\u{1D5CE}', "u"),
+    ('\u{1D5CF}', "v"),
+    ('\u{1D5D0}', "w"),
+    ('\u{1D5D1}', "x"),
+    ('\u{1D5D2}', "y"),
+    ('\u{1D5D3}', "z"),
+    ('\u{1D5D4}', "A"),
+    ('\u{1D5D5}', "B"),
+    ('\u{1D5D6}', "C"),
+    ('\u{1D5D7}', "D"),
+    ('\u{1D5D8}', "E"),
+    ('\u{1D5D9}', "F"),
+    ('\u{1D5DA}', "G"),
+    ('\u{1D5DB}', "H"),
+    ('\u{1D5DC}', "l"),
+    ('\u{1D5DD}', "J"),
+    ('\u{1D5DE}', "K"),
+    ('\u{1D5DF}', "L"),
+    ('\u{1D5E0}', "M"),
+    ('\u{1D5E1}', "N"),
+    ('\u{1D5E2}', "O"),
+    ('\u{1D5E3}', "P"),
+    ('\u{1D5E4}', "Q"),
+    ('\u{1D5E5}', "R"),
+    ('\u{1D5E6}', "S"),
+    ('\u{1D5E7}', "T"),
+    ('\u{1D5E8}', "U"),
+    ('\u{1D5E9}', "V"),
+    ('\u{1D5EA}', "W"),
+    ('\u{1D5EB}', "X"),
+    ('\u{1D5EC}', "Y"),
+    ('\u{1D5ED}', "Z"),
+    ('\u{1D5EE}', "a"),
+    ('\u{1D5EF}', "b"),
+    ('\u{1D5F0}', "c"),
+    ('\u{1D5F1}', "d"),
+    ('\u{1D5F2}', "e"),
+    ('\u{1D5F3}', "f"),
+    ('\u{1D5F4}', "g"),
+    ('\u{1D5F5}', "h"),
+    ('\u{1D5F6}', "i"),
+    ('\u{1D5F7}', "j"),
+    ('\u{1D5F8}', "k"),
+    ('\u{1D5F9}', "l"),
+    ('\u{1D5FA}', "rn"),
+    ('\u{1D5FB}', "n"),
+    ('\u{1D5FC}', "o"),
+    ('\u{1D5FD}', "p"),
+    ('\u{1D5FE}', "q"),
+    ('\u{1D5FF}', "r"),
+    ('\u{1D600}', "s"),
+    ('\u{1D601}', "t"),
+    ('\u{1D602}', "u"),
+    ('\u{1D603}', "v"),
+    ('\u{1D604}', "w"),
+    ('\u{1D605}', "x"),
+    ('\u{1D606}', "y"),
+    ('\u{1D607}', "z"),
+    ('\u{1D608}', "A"),
+    ('\u{1D609}', "B"),
+    ('\u{1D60A}', "C"),
+    ('\u{1D60B}', "D"),
+    ('\u{1D60C}', "E"),
+    ('\u{1D60D}', "F"),
+    ('\u{1D60E}', "G"),
+    ('\u{1D60F}', "H"),
+    ('\u{1D610}', "l"),
+    ('\u{1D611}', "J"),
+    ('\u{1D612}', "K"),
+    ('\u{1D613}', "L"),
+    ('\u{1D614}', "M"),
+    ('\u{1D615}', "N"),
+    ('\u{1D616}', "O"),
+    ('\u{1D617}', "P"),
+    ('\u{1D618}', "Q"),
+    ('\u{1D619}', "R"),
+    ('\u{1D61A}', "S"),
+    ('\u{1D61B}', "T"),
+    ('\u{1D61C}', "U"),
+    ('\u{1D61D}', "V"),
+    ('\u{1D61E}', "W"),
+    ('\u{1D61F}', "X"),
+    ('\u{1D620}', "Y"),
+    ('\u{1D621}', "Z"),
+    ('\u{1D622}', "a"),
+    ('\u{1D623}', "b"),
+    ('\u{1D624}', "c"),
+    ('\u{1D625}', "d"),
+    ('\u{1D626}', "e"),
+    ('\u{1D627}', "f"),
+    ('\u{1D628}', "g"),
+    ('\u{1D629}', "h"),
+    ('\u{1D62A}', "i"),
+    ('\u{1D62B}', "j"),
+    ('\u{1D62C}', "k"),
+    ('\u{1D62D}', "l"),
+    ('\u{1D62E}', "rn"),
+    ('\u{1D62F}', "n"),
+    ('\u{1D630}', "o"),
+    ('\u{1D631}', "p"),
+    ('\u{1D632}', "q"),
+    ('\u{1D633}', "r"),
+    ('\u{1D634}', "s"),
+    ('\u{1D635}', "t"),
+    ('\u{1D636}', "u"),
+    ('\u{1D637}', "v"),
+    ('\u{1D638}', "w"),
+    ('\u{1D639}', "x"),
+    ('\u{1D63A}', "y"),
+    ('\u{1D63B}', "z"),
+    ('\u{1D63C}', "A"),
+    ('\u{1D63D}', "B"),
+    ('\u{1D63E}', "C"),
+    ('\u{1D63F}', "D"),
+    ('\u{1D640}', "E"),
+    ('\u{1D641}', "F"),
+    ('\u{1D642}', "G"),
+    ('\u{1D643}', "H"),
+    ('\u{1D644}', "l"),
+    ('\u{1D645}', "J"),
+    ('\u{1D646}', "K"),
+    ('\u{1D647}', "L"),
+    ('\u{1D648}', "M"),
+    ('\u{1D649}', "N"),
+    ('\u{1D64A}', "O"),
+    ('\u{1D64B}', "P"),
+    ('\u{1D64C}', "Q"),
+    ('\u{1D64D}', "R"),
+    ('\u{1D64E}', "S"),
+    ('\u{1D64F}', "T"),
+    ('\u{1D650}', "U"),
+    ('\u{1D651}', "V"),
+    ('\u{1D652}', "W"),
+    ('\u{1D653}', "X"),
+    ('\u{1D654}', "Y"),
+    ('\u{1D655}', "Z"),
+    ('\u{1D656}', "a"),
+    ('\u{1D657}', "b"),
+    ('\u{1D658}', "c"),
+    ('\u{1D659}', "d"),
+    ('\u{1D65A}', "e"),
+    ('\u{1D65B}', "f"),
+    ('\u{1D65C}', "g"),
+    ('\u{1D65D}', "h"),
+    ('\u{1D65E}', "i"),
+    ('\u{1D65F}', "j"),
+    ('\u{1D660}', "k"),
+    ('\u{1D661}', "l"),
+    ('\u{1D662}', "rn"),
+    ('\u{1D663}', "n"),
+    ('\u{1D664}', "o"),
+    ('\u{1D665}', "p"),
+    ('\u{1D666}', "q"),
+    ('\u{1D667}', "r"),
+    ('\u{1D668}', "s"),
+    ('\u{1D669}', "t"),
+    ('\u{1D66A}', "u"),
+    ('\u{1D66B}', "v"),
+    ('\u{1D66C}', "w"),
+    ('\u{1D66D}', "x"),
+    ('\u{1D66E}', "y"),
+    ('\u{1D66F}', "z"),
+    ('\u{1D670}', "A"),
+    ('\u{1D671}', "B"),
+    ('\u{1D672}', "C"),
+    ('\u{1D673}', "D"),
+    ('\u{1D674}', "E"),
+    ('\u{1D675}', "F"),
+    ('\u{1D676}', "G"),
+    ('\u{1D677}', "H"),
+    ('\u{1D678}', "l"),
+    ('\u{1D679}', "J"),
+    ('\u{1D67A}', "K"),
+    ('\u{1D67B}', "L"),
+    ('\u{1D67C}', "M"),
+    ('\u{1D67D}', "N"),
+    ('\u{1D67E}', "O"),
+    ('\u{1D67F}', "P"),
+    ('\u{1D680}', "Q"),
+    ('\u{1D681}', "R"),
+    ('\u{1D682}', "S"),
+    ('\u{1D683}', "T"),
+    ('\u{1D684}', "U"),
+    ('\u{1D685}', "V"),
+    ('\u{1D686}', "W"),
+    ('\u{1D687}', "X"),
+    ('\u{1D688}', "Y"),
+    ('\u{1D689}', "Z"),
+    ('\u{1D68A}', "a"),
+    ('\u{1D68B}', "b"),
+    ('\u{1D68C}', "c"),
+    ('\u{1D68D}', "d"),
+    ('\u{1D68E}', "e"),
+    ('\u{1D68F}', "f"),
+    ('\u{1D690}', "g"),
+    ('\u{1D691}', "h"),
+    ('\u{1D692}', "i"),
+    ('\u{1D693}', "j"),
+    ('\u{1D694}', "k"),
+    ('\u{1D695}', "l"),
+    ('\u{1D696}', "rn"),
+    ('\u{1D697}', "n"),
+    ('\u{1D698}', "o"),
+    ('\u{1D699}', "p"),
+    ('\u{1D69A}', "q"),
+    ('\u{1D69B}', "r"),
+    ('\u{1D69C}', "s"),
+    ('\u{1D69D}', "t"),
+    ('\u{1D69E}', "u"),
+    ('\u{1D69F}', "v"),
+    ('\u{1D6A0}', "w"),
+    ('\u{1D6A1}', "x"),
+    ('\u{1D6A2}', "y"),
+    ('\u{1D6A3}', "z"),
+    ('\u{1D6A4}', "i"),
+    ('\u{1D6A5}', "\u{0237}"),
+    ('\u{1D6A8}', "A"),
+    ('\u{1D6A9}', "B"),
+    ('\u{1D6AA}', "\u{0393}"),
+    ('\u{1D6AB}', "\u{0394}"),
+    ('\u{1D6AC}', "E"),
+    ('\u{1D6AD}', "Z"),
+    ('\u{1D6AE}', "H"),
+    ('\u{1D6AF}', "O\u{0335}"),
+    ('\u{1D6B0}', "l"),
+    ('\u{1D6B1}', "K"),
+    ('\u{1D6B2}', "\u{0245}"),
+    ('\u{1D6B3}', "M"),
+    ('\u{1D6B4}', "N"),
+    ('\u{1D6B5}', "\u{039E}"),
+    ('\u{1D6B6}', "O"),
+    ('\u{1D6B7}', "\u{03A0}"),
+    ('\u{1D6B8}', "P"),
+    ('\u{1D6B9}', "O\u{0335}"),
+    ('\u{1D6BA}', "\u{01A9}"),
+    ('\u{1D6BB}', "T"),
+    ('\u{1D6BC}', "Y"),
+    ('\u{1D6BD}', "\u{03A6}"),
+    ('\u{1D6BE}', "X"),
+    ('\u{1D6BF}', "\u{03A8}"),
+    ('\u{1D6C0}', "\u{03A9}"),
+    ('\u{1D6C1}', "\u{2207}"),
+    ('\u{1D6C2}', "a"),
+    ('\u{1D6C3}', "\u{00DF}"),
+    ('\u{1D6C4}', "y"),
+    ('\u{1D6C5}', "\u{1E9F}"),
+    ('\u{1D6C6}', "\u{A793}"),
+    ('\u{1D6C7}', "\u{03B6}"),
+    ('\u{1D6C8}', "n\u{0329}"),
+    ('\u{1D6C9}', "O\u{0335}"),
+    ('\u{1D6CA}', "i"),
+    ('\u{1D6CB}', "\u{0138}"),
+    ('\u{1D6CC}', "\u{03BB}"),
+    ('\u{1D6CD}', "\u{03BC}"),
+    ('\u{1D6CE}', "v"),
+    ('\u{1D6CF}', "\u{03BE}"),
+    ('\u{1D6D0}', "o"),
+    ('\u{1D6D1}', "\u{03C0}"),
+    ('\u{1D6D2}', "p"),
+    ('\u{1D6D3}', "\u{03C2}"),
+    ('\u{1D6D4}', "o"),
+    ('\u{1D6D5}', "\u{1D1B}"),
+    ('\u{1D6D6}', "u"),
+    ('\u{1D6D7}', "\u{0278}"),
+    ('\u{1D6D8}', "\u{03C7}"),
+    ('\u{1D6D9}', "\u{03C8}"),
+    ('\u{1D6DA}', "\u{03C9}"),
+    ('\u{1D6DB}', "\u{2202}"),
+    ('\u{1D6DC}', "\u{A793}"),
+    ('\u{1D6DD}', "O\u{0335}"),
+    ('\u{1D6DE}', "\u{0138}"),
+    ('\u{1D6DF}', "\u{0278}"),
+    ('\u{1D6E0}', "p"),
+    ('\u{1D6E1}', "\u{03C0}"),
+    ('\u{1D6E2}', "A"),
+    ('\u{1D6E3}', "B"),
+    ('\u{1D6E4}', "\u{0393}"),
+    ('\u{1D6E5}', "\u{0394}"),
+    ('\u{1D6E6}', "E"),
+    ('\u{1D6E7}', "Z"),
+    ('\u{1D6E8}', "H"),
+    ('\u{1D6E9}', "O\u{0335}"),
+    ('\u{1D6EA}', "l"),
+    ('\u{1D6EB}', "K"),
+    ('\u{1D6EC}', "\u{0245}"),
+    ('\u{1D6ED}', "M"),
+    ('\u{1D6EE}', "N"),
+    ('\u{1D6EF}', "\u{039E}"),
+    ('\u{1D6F0}', "O"),
+    ('\u{1D6F1}', "\u{03A0}"),
+    ('\u{1D6F2}', "P"),
+    ('\u{1D6F3}', "O\u{0335}"),
+    ('\u{1D6F4}', "\u{01A9}"),
+    ('\u{1D6F5}', "T"),
+    ('\u{1D6F6}', "Y"),
+    ('\u{1D6F7}', "\u{03A6}"),
+    ('\u{1D6F8}', "X"),
+    ('\u{1D6F9}', "\u{03A8}"),
+    ('\u{1D6FA}', "\u{03A9}"),
+    ('\u{1D6FB}', "\u{2207}"),
+    ('\u{1D6FC}', "a"),
+    ('\u{1D6FD}', "\u{00DF}"),
+    ('\u{1D6FE}', "y"),
+    ('\u{1D6FF}', "\u{1E9F}"),
+    ('\u{1D700}', "\u{A793}"),
+    ('\u{1D701}', "\u{03B6}"),
+    ('\u{1D702}', "n\u{0329}"),
+    ('\u{1D703}', "O\u{0335}"),
+    ('\u{1D704}', "i"),
+    ('\u{1D705}', "\u{0138}"),
+    ('\u{1D706}', "\u{03BB}"),
+    ('\u{1D707}', "\u{03BC}"),
+    ('\u{1D708}', "v"),
+    ('\u{1D709}', "\u{03BE}"),
+    ('\u{1D70A}', "o"),
+    ('\u{1D70B}', "\u{03C0}"),
+    ('\u{1D70C}', "p"),
+    ('\u{1D70D}', "\u{03C2}"),
+    ('\u{1D70E}', "o"),
+    ('\u{1D70F}', "\u{1D1B}"),
+    ('\u{1D710}', "u"),
+    ('\u{1D711}', "\u{0278}"),
+    ('\u{1D712}', "\u{03C7}"),
+    ('\u{1D713}', "\u{03C8}"),
+    ('\u{1D714}', "\u{03C9}"),
+    ('\u{1D715}', "\u{2202}"),
+    ('\u{1D716}', "\u{A793}"),
+    ('\u{1D717}', "O\u{0335}"),
+    ('\u{1D718}', "\u{0138}"),
+    ('\u{1D719}', "\u{0278}"),
+    ('\u{1D71A}', "p"),
+    ('\u{1D71B}', "\u{03C0}"),
+    ('\u{1D71C}', "A"),
+    ('\u{1D71D}', "B"),
+    ('\u{1D71E}', "\u{0393}"),
+    ('\u{1D71F}', "\u{0394}"),
+    ('\u{1D720}', "E"),
+    ('\u{1D721}', "Z"),
+    ('\u{1D722}', "H"),
+    ('\u{1D723}', "O\u{0335}"),
+    ('\u{1D724}', "l"),
+    ('\u{1D725}', "K"),
+    ('\u{1D726}', "\u{0245}"),
+    ('\u{1D727}', "M"),
+    ('\u{1D728}', "N"),
+    ('\u{1D729}', "\u{039E}"),
+    ('\u{1D72A}', "O"),
+    ('\u{1D72B}', "\u{03A0}"),
+    ('\u{1D72C}', "P"),
+    ('\u{1D72D}', "O\u{0335}"),
+    ('\u{1D72E}', "\u{01A9}"),
+    ('\u{1D72F}', "T"),
+    ('\u{1D730}', "Y"),
+    ('\u{1D731}', "\u{03A6}"),
+    ('\u{1D732}', "X"),
+    ('\u{1D733}', "\u{03A8}"),
+    ('\u{1D734}', "\u{03A9}"),
+    ('\u{1D735}', "\u{2207}"),
+    ('\u{1D736}', "a"),
+    ('\u{1D737}', "\u{00DF}"),
+    ('\u{1D738}', "y"),
+    ('\u{1D739}', "\u{1E9F}"),
+    ('\u{1D73A}', "\u{A793}"),
+    ('\u{1D73B}', "\u{03B6}"),
+    ('\u{1D73C}', "n\u{0329}"),
+    ('\u{1D73D}', "O\u{0335}"),
+    ('\u{1D73E}', "i"),
+    ('\u{1D73F}', "\u{0138}"),
+    ('\u{1D740}', "\u{03BB}"),
+    ('\u{1D741}', "\u{03BC}"),
+    ('\u{1D742}', "v"),
+    ('\u{1D743}', "\u{03BE}"),
+    ('\u{1D744}', "o"),
+    ('\u{1D745}', "\u{03C0}"),
+    ('\u{1D746}', "p"),
+    ('\u{1D747}', "\u{03C2}"),
+    ('\u{1D748}', "o"),
+    ('\u{1D749}', "\u{1D1B}"),
+    ('\u{1D74A}', "u"),
+    ('\u{1D74B}', "\u{0278}"),
+    ('\u{1D74C}', "\u{03C7}"),
+    ('\u{1D74D}', "\u{03C8}"),
+    ('\u{1D74E}', "\u{03C9}"),
+    ('\u{1D74F}', "\u{2202}"),
+    ('\u{1D750}', "\u{A793}"),
+    ('\u{1D751}', "O\u{0335}"),
+    ('\u{1D752}', "\u{0138}"),
+    ('\u{1D753}', "\u{0278}"),
+    ('\u{1D754}', "p"),
+    ('\u{1D755}', "\u{03C0}"),
+    ('\u{1D756}', "A"),
+    ('\u{1D757}', "B"),
+    ('\u{1D758}', "\u{0393}"),
+    ('\u{1D759}', "\u{0394}"),
+    ('\u{1D75A}', "E"),
+    ('\u{1D75B}', "Z"),
+    ('\u{1D75C}', "H"),
+    ('\u{1D75D}', "O\u{0335}"),
+    ('\u{1D75E}', "l"),
+    ('\u{1D75F}', "K"),
+    ('\u{1D760}', "\u{0245}"),
+    ('\u{1D761}', "M"),
+    ('\u{1D762}', "N"),
+    ('\u{1D763}', "\u{039E}"),
+    ('\u{1D764}', "O"),
+    ('\u{1D765}', "\u{03A0}"),
+    ('\u{1D766}', "P"),
+    ('\u{1D767}', "O\u{0335}"),
+    ('\u{1D768}', "\u{01A9}"),
+    ('\u{1D769}', "T"),
+    ('\u{1D76A}', "Y"),
+    ('\u{1D76B}', "\u{03A6}"),
+    ('\u{1D76C}', "X"),
+    ('\u{1D76D}', "\u{03A8}"),
+    ('\u{1D76E}', "\u{03A9}"),
+    ('\u{1D76F}', "\u{2207}"),
+    ('\u{1D770}', "a"),
+    ('\u{1D771}', "\u{00DF}"),
+    ('\u{1D772}', "y"),
+    ('\u{1D773}', "\u{1E9F}"),
+    ('\u{1D774}', "\u{A793}"),
+    ('\u{1D775}', "\u{03B6}"),
+    ('\u{1D776}', "n\u{0329}"),
+    ('\u{1D777}', "O\u{0335}"),
+    ('\u{1D778}', "i"),
+    ('\u{1D779}', "\u{0138}"),
+    ('\u{1D77A}', "\u{03BB}"),
+    ('\u{1D77B}', "\u{03BC}"),
+    ('\u{1D77C}', "v"),
+    ('\u{1D77D}', "\u{03BE}"),
+    ('\u{1D77E}', "o"),
+    ('\u{1D77F}', "\u{03C0}"),
+    ('\u{1D780}', "p"),
+    ('\u{1D781}', "\u{03C2}"),
+    ('\u{1D782}', "o"),
+    ('\u{1D783}', "\u{1D1B}"),
+    ('\u{1D784}', "u"),
+    ('\u{1D785}', "\u{0278}"),
+    ('\u{1D786}', "\u{03C7}"),
+    ('\u{1D787}', "\u{03C8}"),
+    ('\u{1D788}', "\u{03C9}"),
+    ('\u{1D789}', "\u{2202}"),
+    ('\u{1D78A}', "\u{A793}"),
+    ('\u{1D78B}', "O\u{0335}"),
+    ('\u{1D78C}', "\u{0138}"),
+    ('\u{1D78D}', "\u{0278}"),
+    ('\u{1D78E}', "p"),
+    ('\u{1D78F}', "\u{03C0}"),
+    ('\u{1D790}', "A"),
+    ('\u{1D791}', "B"),
+    ('\u{1D792}', "\u{0393}"),
+    ('\u{1D793}', "\u{0394}"),
+    ('\u{1D794}', "E"),
+    ('\u{1D795}', "Z"),
+    ('\u{1D796}', "H"),
+    ('\u{1D797}', "O\u{0335}"),
+    ('\u{1D798}', "l"),
+    ('\u{1D799}', "K"),
+    ('\u{1D79A}', "\u{0245}"),
+    ('\u{1D79B}', "M"),
+    ('\u{1D79C}', "N"),
+    ('\u{1D79D}', "\u{039E}"),
+    ('\u{1D79E}', "O"),
+    ('\u{1D79F}', "\u{03A0}"),
+    ('\u{1D7A0}', "P"),
+    ('\u{1D7A1}', "O\u{0335}"),
+    ('\u{1D7A2}', "\u{01A9}"),
+    ('\u{1D7A3}', "T"),
+    ('\u{1D7A4}', "Y"),
+    ('\u{1D7A5}', "\u{03A6}"),
+    ('\u{1D7A6}', "X"),
+    ('\u{1D7A7}', "\u{03A8}"),
+    ('\u{1D7A8}', "\u{03A9}"),
+    ('\u{1D7A9}', "\u{2207}"),
+    ('\u{1D7AA}', "a"),
+    ('\u{1D7AB}', "\u{00DF}"),
+    ('\u{1D7AC}', "y"),
+    ('\u{1D7AD}', "\u{1E9F}"),
+    ('\u{1D7AE}', "\u{A793}"),
+    ('\u{1D7AF}', "\u{03B6}"),
+    ('\u{1D7B0}', "n\u{0329}"),
+    ('\u{1D7B1}', "O\u{0335}"),
+    ('\u{1D7B2}', "i"),
+    ('\u{1D7B3}', "\u{0138}"),
+    ('\u{1D7B4}', "\u{03BB}"),
+    ('\u{1D7B5}', "\u{03BC}"),
+    ('\u{1D7B6}', "v"),
+    ('\u{1D7B7}', "\u{03BE}"),
+    ('\u{1D7B8}', "o"),
+    ('\u{1D7B9}', "\u{03C0}"),
+    ('\u{1D7BA}', "p"),
+    ('\u{1D7BB}', "\u{03C2}"),
+    ('\u{1D7BC}', "o"),
+    ('\u{1D7BD}', "\u{1D1B}"),
+    ('\u{1D7BE}', "u"),
+    ('\u{1D7BF}', "\u{0278}"),
+    ('\u{1D7C0}', "\u{03C7}"),
+    ('\u{1D7C1}', "\u{03C8}"),
+    ('\u{1D7C2}', "\u{03C9}"),
+    ('\u{1D7C3}', "\u{2202}"),
+    ('\u{1D7C4}', "\u{A793}"),
+    ('\u{1D7C5}', "O\u{0335}"),
+    ('\u{1D7C6}', "\u{0138}"),
+    ('\u{1D7C7}', "\u{0278}"),
+    ('\u{1D7C8}', "p"),
+    ('\u{1D7C9}', "\u{03C0}"),
+    ('\u{1D7CA}', "F"),
+    ('\u{1D7CB}', "\u{03DD}"),
+    ('\u{1D7CE}', "O"),
+    ('\u{1D7CF}', "l"),
+    ('\u{1D7D0}', "2"),
+    ('\u{1D7D1}', "3"),
+    ('\u{1D7D2}', "4"),
+    ('\u{1D7D3}', "5"),
+    ('\u{1D7D4}', "6"),
+    ('\u{1D7D5}', "7"),
+    ('\u{1D7D6}', "8"),
+    ('\u{1D7D7}', "9"),
+    ('\u{1D7D8}', "O"),
+    ('\u{1D7D9}', "l"),
+    ('\u{1D7DA}', "2"),
+    ('\u{1D7DB}', "3"),
+    ('\u{1D7DC}', "4"),
+    ('\u{1D7DD}', "5"),
+    ('\u{1D7DE}', "6"),
+    ('\u{1D7DF}', "7"),
+    ('\u{1D7E0}', "8"),
+    ('\u{1D7E1}', "9"),
+    ('\u{1D7E2}', "O"),
+    ('\u{1D7E3}', "l"),
+    ('\u{1D7E4}', "2"),
+    ('\u{1D7E5}', "3"),
+    ('\u{1D7E6}', "4"),
+    ('\u{1D7E7}', "5"),
+    ('\u{1D7E8}', "6"),
+    ('\u{1D7E9}', "7"),
+    ('\u{1D7EA}', "8"),
+    ('\u{1D7EB}', "9"),
+    ('\u{1D7EC}', "O"),
+    ('\u{1D7ED}', "l"),
+    ('\u{1D7EE}', "2"),
+    ('\u{1D7EF}', "3"),
+    ('\u{1D7F0}', "4"),
+    ('\u{1D7F1}', "5"),
+    ('\u{1D7F2}', "6"),
+    ('\u{1D7F3}', "7"),
+    ('\u{1D7F4}', "8"),
+    ('\u{1D7F5}', "9"),
+    ('\u{1D7F6}', "O"),
+    ('\u{1D7F7}', "l"),
+    ('\u{1D7F8}', "2"),
+    ('\u{1D7F9}', "3"),
+    ('\u{1D7FA}', "4"),
+    ('\u{1D7FB}', "5"),
+    ('\u{1D7FC}', "6"),
+    ('\u{1D7FD}', "7"),
+    ('\u{1D7FE}', "8"),
+    ('\u{1D7FF}', "9"),
+    ('\u{1E8C7}', "l"),
+    ('\u{1E8C8}', "\u{2220}"),
+    ('\u{1E8C9}', "\u{0663}"),
+    ('\u{1E8CB}', "8"),
+    ('\u{1E8CC}', "\u{2202}"),
+    ('\u{1E8CD}', "\u{2202}\u{0335}"),
+    ('\u{1EE00}', "l"),
+    ('\u{1EE01}', "\u{0628}"),
+    ('\u{1EE02}', "\u{062C}"),
+    ('\u{1EE03}', "\u{062F}"),
+    ('\u{1EE05}', "\u{0648}"),
+    ('\u{1EE06}', "\u{0632}"),
+    ('\u{1EE07}', "\u{062D}"),
+    ('\u{1EE08}', "\u{0637}"),
+    ('\u{1EE09}', "\u{0649}"),
+    ('\u{1EE0A}', "\u{0643}"),
+    ('\u{1EE0B}', "\u{0644}"),
+    ('\u{1EE0C}', "\u{0645}"),
+    ('\u{1EE0D}', "\u{0646}"),
+    ('\u{1EE0E}', "\u{0633}"),
+    ('\u{1EE0F}', "\u{0639}"),
+    ('\u{1EE10}', "\u{0641}"),
+    ('\u{1EE11}', "\u{0635}"),
+    ('\u{1EE12}', "\u{0642}"),
+    ('\u{1EE13}', "\u{0631}"),
+    ('\u{1EE14}', "\u{0633}\u{06DB}"),
+    ('\u{1EE15}', "\u{062A}"),
+    ('\u{1EE16}', "\u{0649}\u{06DB}"),
+    ('\u{1EE17}', "\u{062E}"),
+    ('\u{1EE18}', "\u{0630}"),
+    ('\u{1EE19}', "\u{0636}"),
+    ('\u{1EE1A}', "\u{0638}"),
+    ('\u{1EE1B}', "\u{063A}"),
+    ('\u{1EE1C}', "\u{0649}"),
+    ('\u{1EE1D}', "\u{0649}"),
+    ('\u{1EE1E}', "\u{06A1}"),
+    ('\u{1EE1F}', "\u{06A1}"),
+    ('\u{1EE21}', "\u{0628}"),
+    ('\u{1EE22}', "\u{062C}"),
+    ('\u{1EE24}', "o"),
+    ('\u{1EE27}', "\u{062D}"),
+    ('\u{1EE29}', "\u{0649}"),
+    ('\u{1EE2A}', "\u{0643}"),
+    ('\u{1EE2B}', "\u{0644}"),
+    ('\u{1EE2C}', "\u{0645}"),
+    ('\u{1EE2D}', "\u{0646}"),
+    ('\u{1EE2E}', "\u{0633}"),
+    ('\u{1EE2F}', "\u{0639}"),
+    ('\u{1EE30}', "\u{0641}"),
+    ('\u{1EE31}', "\u{0635}"),
+    ('\u{1EE32}', "\u{0642}"),
+    ('\u{1EE34}', "\u{0633}\u{06DB}"),
+    ('\u{1EE35}', "\u{062A}"),
+    ('\u{1EE36}', "\u{0649}\u{06DB}"),
+    ('\u{1EE37}', "\u{062E}"),
+    ('\u{1EE39}', "\u{0636}"),
+    ('\u{1EE3B}', "\u{063A}"),
+    ('\u{1EE42}', "\u{062C}"),
+    ('\u{1EE47}', "\u{062D}"),
+    ('\u{1EE49}', "\u{0649}"),
+    ('\u{1EE4B}', "\u{0644}"),
+    ('\u{1EE4D}', "\u{0646}"),
+    ('\u{1EE4E}', "\u{0633}"),
+    ('\u{1EE4F}', "\u{0639}"),
+    ('\u{1EE51}', "\u{0635}"),
+    ('\u{1EE52}', "\u{0642}"),
+    ('\u{1EE54}', "\u{0633}\u{06DB}"),
+    ('\u{1EE57}', "\u{062E}"),
+    ('\u{1EE59}', "\u{0636}"),
+    ('\u{1EE5B}', "\u{063A}"),
+    ('\u{1EE5D}', "\u{0649}"),
+    ('\u{1EE5F}', "\u{06A1}"),
+    ('\u{1EE61}', "\u{0628}"),
+    ('\u{1EE62}', "\u{062C}"),
+    ('\u{1EE64}', "o"),
+    ('\u{1EE67}', "\u{062D}"),
+    ('\u{1EE68}', "\u{0637}"),
+    ('\u{1EE69}', "\u{0649}"),
+    ('\u{1EE6A}', "\u{0643}"),
+    ('\u{1EE6C}', "\u{0645}"),
+    ('\u{1EE6D}', "\u{0646}"),
+    ('\u{1EE6E}', "\u{0633}"),
+    ('\u{1EE6F}', "\u{0639}"),
+    ('\u{1EE70}', "\u{0641}"),
+    ('\u{1EE71}', "\u{0635}"),
+    ('\u{1EE72}', "\u{0642}"),
+    ('\u{1EE74}', "\u{0633}\u{06DB}"),
+    ('\u{1EE75}', "\u{062A}"),
+    ('\u{1EE76}', "\u{0649}\u{06DB}"),
+    ('\u{1EE77}', "\u{062E}"),
+    ('\u{1EE79}', "\u{0636}"),
+    ('\u{1EE7A}', "\u{0638}"),
+    ('\u{1EE7B}', "\u{063A}"),
+    ('\u{1EE7C}', "\u{0649}"),
+    ('\u{1EE7E}', "\u{06A1}"),
+    ('\u{1EE80}', "l"),
+    ('\u{1EE81}', "\u{0628}"),
+    ('\u{1EE82}', "\u{062C}"),
+    ('\u{1EE83}', "\u{062F}"),
+    ('\u{1EE84}', "o"),
+    ('\u{1EE85}', "\u{0648}"),
+    ('\u{1EE86}', "\u{0632}"),
+    ('\u{1EE87}', "\u{062D}"),
+    ('\u{1EE88}', "\u{0637}"),
+    ('\u{1EE89}', "\u{0649}"),
+    ('\u{1EE8B}', "\u{0644}"),
+    ('\u{1EE8C}', "\u{0645}"),
+    ('\u{1EE8D}', "\u{0646}"),
+    ('\u{1EE8E}', "\u{0633}"),
+    ('\u{1EE8F}', "\u{0639}"),
+    ('\u{1EE90}', "\u{0641}"),
+    ('\u{1EE91}', "\u{0635}"),
+    ('\u{1EE92}', "\u{0642}"),
+    ('\u{1EE93}', "\u{0631}"),
+    ('\u{1EE94}', "\u{0633}\u{06DB}"),
+    ('\u{1EE95}', "\u{062A}"),
+    ('\u{1EE96}', "\u{0649}\u{06DB}"),
+    ('\u{1EE97}', "\u{062E}"),
+    ('\u{1EE98}', "\u{0630}"),
+    ('\u{1EE99}', "\u{0636}"),
+    ('\u{1EE9A}', "\u{0638}"),
+    ('\u{1EE9B}', "\u{063A}"),
+    ('\u{1EEA1}', "\u{0628}"),
+    ('\u{1EEA2}', "\u{062C}"),
+    ('\u{1EEA3}', "\u{062F}"),
+    ('\u{1EEA5}', "\u{0648}"),
+    ('\u{1EEA6}', "\u{0632}"),
+    ('\u{1EEA7}', "\u{062D}"),
+    ('\u{1EEA8}', "\u{0637}"),
+    ('\u{1EEA9}', "\u{0649}"),
+    ('\u{1EEAB}', "\u{0644}"),
+    ('\u{1EEAC}', "\u{0645}"),
+    ('\u{1EEAD}', "\u{0646}"),
+    ('\u{1EEAE}', "\u{0633}"),
+    ('\u{1EEAF}', "\u{0639}"),
+    ('\u{1EEB0}', "\u{0641}"),
+    ('\u{1EEB1}', "\u{0635}"),
+    ('\u{1EEB2}', "\u{0642}"),
+    ('\u{1EEB3}', "\u{0631}"),
+    ('\u{1EEB4}', "\u{0633}\u{06DB}"),
+    ('\u{1EEB5}', "\u{062A}"),
+    ('\u{1EEB6}', "\u{0649}\u{06DB}"),
+    ('\u{1EEB7}', "\u{062E}"),
+    ('\u{1EEB8}', "\u{0630}"),
+    ('\u{1EEB9}', "\u{0636}"),
+    ('\u{1EEBA}', "\u{0638}"),
+    ('\u{1EEBB}', "\u{063A}"),
+    ('\u{1F100}', "O."),
+    ('\u{1F101}', "O,"),
+    ('\u{1F102}', "l,"),
+    ('\u{1F103}', "2,"),
+    ('\u{1F104}', "3,"),
+    ('\u{1F105}', "4,"),
+    ('\u{1F106}', "5,"),
+    ('\u{1F107}', "6,"),
+    ('\u{1F108}', "7,"),
+    ('\u{1F109}', "8,"),
+    ('\u{1F10A}', "9,"),
+    ('\u{1F10F}', "$\u{20E0}"),
+    ('\u{1F110}', "(A)"),
+    ('\u{1F111}', "(B)"),
+    ('\u{1F112}', "(C)"),
+    ('\u{1F113}', "(D)"),
+    ('\u{1F114}', "(E)"),
+    ('\u{1F115}', "(F)"),
+    ('\u{1F116}', "(G)"),
+    ('\u{1F117}', "(H)"),
+    ('\u{1F118}', "(l)"),
+    ('\u{1F119}', "(J)"),
+    ('\u{1F11A}', "(K)"),
+    ('\u{1F11B}', "(L)"),
+    ('\u{1F11C}', "(M)"),
+    ('\u{1F11D}', "(N)"),
+    ('\u{1F11E}', "(O)"),
+    ('\u{1F11F}', "(P)"),
+    ('\u{1F120}', "(Q)"),
+    ('\u{1F121}', "(R)"),
+    ('\u{1F122}', "(S)"),
+    ('\u{1F123}', "(T)"),
+    ('\u{1F124}', "(U)"),
+    ('\u{1F125}', "(V)"),
+    ('\u{1F126}', "(W)"),
+    ('\u{1F127}', "(X)"),
+    ('\u{1F128}', "(Y)"),
+    ('\u{1F129}', "(Z)"),
+    ('\u{1F12A}', "(S)"),
+    ('\u{1F16D}', "\u{33C4}\u{0009}\u{20DD}"),
+    ('\u{1F16E}', "C\u{20E0}"),
+    ('\u{1F240}', "(\u{672C})"),
+    ('\u{1F241}', "(\u{4E09})"),
+    ('\u{1F242}', "(\u{4E8C})"),
+    ('\u{1F243}', "(\u{5B89})"),
+    ('\u{1F244}', "(\u{70B9})"),
+    ('\u{1F245}', "(\u{6253})"),
+    ('\u{1F246}', "(\u{76D7})"),
+    ('\u{1F247}', "(\u{52DD})"),
+    ('\u{1F248}', "(\u{6557})"),
+    ('\u{1F312}', "\u{263D}"),
+    ('\u{1F318}', "\u{263E}"),
+    ('\u{1F319}', "\u{263D}"),
+    ('\u{1F700}', "QE"),
+    ('\u{1F701}', "\u{A658}"),
+    ('\u{1F702}', "\u{0394}"),
+    ('\u{1F704}', "\u{102BC}"),
+    ('\u{1F707}', "AR"),
+    ('\u{1F708}', "V\u{1DE4}"),
+    ('\u{1F70A}', "\u{2629}"),
+    ('\u{1F714}', "O\u{0335}"),
+    ('\u{1F728}', "\u{102A8}"),
+    ('\u{1F73A}', "\u{29DF}"),
+    ('\u{1F74C}', "C"),
+    ('\u{1F754}', "\u{16DC}"),
+    ('\u{1F755}', "\u{22A1}"),
+    ('\u{1F75C}', "sss"),
+    ('\u{1F75E}', "\u{224F}"),
+    ('\u{1F768}', "T"),
+    ('\u{1F76B}', "MB"),
+    ('\u{1F76C}', "VB"),
+    ('\u{1F771}', "\u{22A0}"),
+    ('\u{1FBF0}', "O"),
+    ('\u{1FBF1}', "l"),
+    ('\u{1FBF2}', "2"),
+    ('\u{1FBF3}', "3"),
+    ('\u{1FBF4}', "4"),
+    ('\u{1FBF5}', "5"),
+    ('\u{1FBF6}', "6"),
+    ('\u{1FBF7}', "7"),
+    ('\u{1FBF8}', "8"),
+    ('\u{1FBF9}', "9"),
+    ('\u{21FE8}', "\u{276C}"),
+    ('\u{2F800}', "\u{4E3D}"),
+    ('\u{2F801}', "\u{4E38}"),
+    ('\u{2F802}', "\u{4E41}"),
+    ('\u{2F803}', "\u{20122}"),
+    ('\u{2F804}', "\u{4F60}"),
+    ('\u{2F805}', "\u{4FAE}"),
+    ('\u{2F806}', "\u{4FBB}"),
+    ('\u{2F807}', "\u{4F75}"),
+    ('\u{2F808}', "\u{507A}"),
+    ('\u{2F809}', "\u{5099}"),
+    ('\u{2F80A}', "\u{50E7}"),
+    ('\u{2F80B}', "\u{50CF}"),
+    ('\u{2F80C}', "\u{349E}"),
+    ('\u{2F80D}', "\u{2063A}"),
+    ('\u{2F80E}', "\u{514D}"),
+    ('\u{2F80F}', "\u{5154}"),
+    ('\u{2F810}', "\u{5164}"),
+    ('\u{2F811}', "\u{5177}"),
+    ('\u{2F812}', "\u{2051C}"),
+    ('\u{2F813}', "\u{34B9}"),
+    ('\u{2F814}', "\u{5167}"),
+    ('\u{2F815}', "\u{518D}"),
+    ('\u{2F816}', "\u{2054B}"),
+    ('\u{2F817}', "\u{5197}"),
+    ('\u{2F818}', "\u{51A4}"),
+    ('\u{2F819}', "\u{4ECC}"),
+    ('\u{2F81A}', "\u{51AC}"),
+    ('\u{2F81B}', "\u{51B5}"),
+    ('\u{2F81C}', "\u{291DF}"),
+    ('\u{2F81D}', "\u{51F5}"),
+    ('\u{2F81E}', "\u{5203}"),
+    ('\u{2F81F}', "\u{34DF}"),
+    ('\u{2F820}', "\u{523B}"),
+    ('\u{2F821}', "\u{5246}"),
+    ('\u{2F822}', "\u{5272}"),
+    ('\u{2F823}', "\u{5277}"),
+    ('\u{2F824}', "\u{3515}"),
+    ('\u{2F825}', "\u{52C7}"),
+    ('\u{2F826}', "\u{52C9}"),
+    ('\u{2F827}', "\u{52E4}"),
+    ('\u{2F828}', "\u{52FA}"),
+    ('\u{2F829}', "\u{5305}"),
+    ('\u{2F82A}', "\u{5306}"),
+    ('\u{2F82B}', "\u{5317}"),
+    ('\u{2F82C}', "\u{5349}"),
+    ('\u{2F82D}', "\u{5351}"),
+    ('\u{2F82E}', "\u{535A}"),
+    ('\u{2F82F}', "\u{5373}"),
+    ('\u{2F830}', "\u{537D}"),
+    ('\u{2F831}', "\u{537F}"),
+    ('\u{2F832}', "\u{537F}"),
+    ('\u{2F833}', "\u{537F}"),
+    ('\u{2F834}', "\u{20A2C}"),
+    ('\u{2F835}', "\u{7070}"),
+    ('\u{2F836}', "\u{53CA}"),
+    ('\u{2F837}', "\u{53DF}"),
+    ('\u{2F838}', "\u{20B63}"),
+    ('\u{2F839}', "\u{53EB}"),
+    ('\u{2F83A}', "\u{53F1}"),
+    ('\u{2F83B}', "\u{5406}"),
+    ('\u{2F83C}', "\u{549E}"),
+    ('\u{2F83D}', "\u{5438}"),
+    ('\u{2F83E}', "\u{5448}"),
+    ('\u{2F83F}', "\u{5468}"),
+    ('\u{2F840}', "\u{54A2}"),
+    ('\u{2F841}', "\u{54F6}"),
+    ('\u{2F842}', "\u{5510}"),
+    ('\u{2F843}', "\u{5553}"),
+    ('\u{2F844}', "\u{5563}"),
+    ('\u{2F845}', "\u{5584}"),
+    ('\u{2F846}', "\u{5584}"),
+    ('\u{2F847}', "\u{5599}"),
+    ('\u{2F848}', "\u{55AB}"),
+    ('\u{2F849}', "\u{55B3}"),
+    ('\u{2F84A}', "\u{55C2}"),
+    ('\u{2F84B}', "\u{5716}"),
+    ('\u{2F84C}', "\u{5606}"),
+    ('\u{2F84D}', "\u{5717}"),
+    ('\u{2F84E}', "\u{5651}"),
+    ('\u{2F84F}', "\u{5674}"),
+    ('\u{2F850}', "\u{5207}"),
+    ('\u{2F851}', "\u{58EE}"),
+    ('\u{2F852}', "\u{57CE}"),
+    ('\u{2F853}', "\u{57F4}"),
+    ('\u{2F854}', "\u{580D}"),
+    ('\u{2F855}', "\u{578B}"),
+    ('\u{2F856}', "\u{5832}"),
+    ('\u{2F857}', "\u{5831}"),
+    ('\u{2F858}', "\u{58AC}"),
+    ('\u{2F859}', "\u{214E4}"),
+    ('\u{2F85A}', "\u{58F2}"),
+    ('\u{2F85B}', "\u{58F7}"),
+    ('\u{2F85C}', "\u{5906}"),
+    ('\u{2F85D}', "\u{591A}"),
+    ('\u{2F85E}', "\u{5922}"),
+    ('\u{2F85F}', "\u{5962}"),
+    ('\u{2F860}', "\u{216A8}"),
+    ('\u{2F861}', "\u{216EA}"),
+    ('\u{2F862}', "\u{59EC}"),
+    ('\u{2F863}', "\u{5A1B}"),
+    ('\u{2F864}', "\u{5A27}"),
+    ('\u{2F865}', "\u{59D8}"),
+    ('\u{2F866}', "\u{5A66}"),
+    ('\u{2F867}', "\u{36EE}"),
+    ('\u{2F868}', "\u{36FC}"),
+    ('\u{2F869}', "\u{5B08}"),
+    ('\u{2F86A}', "\u{5B3E}"),
+    ('\u{2F86B}', "\u{5B3E}"),
+    ('\u{2F86C}', "\u{219C8}"),
+    ('\u{2F86D}', "\u{5BC3}"),
+    ('\u{2F86E}', "\u{5BD8}"),
+    ('\u{2F86F}', "\u{5BE7}"),
+    ('\u{2F870}', "\u{5BF3}"),
+    ('\u{2F871}', "\u{21B18}"),
+    ('\u{2F872}', "\u{5BFF}"),
+    ('\u{2F873}', "\u{5C06}"),
+    ('\u{2F874}', "\u{5F53}"),
+    ('\u{2F875}', "\u{5C22}"),
+    ('\u{2F876}', "\u{3781}"),
+    ('\u{2F877}', "\u{5C60}"),
+    ('\u{2F878}', "\u{5C6E}"),
+    ('\u{2F879}', "\u{5CC0}"),
+    ('\u{2F87A}', "\u{5C8D}"),
+    ('\u{2F87B}', "\u{21DE4}"),
+    ('\u{2F87C}', "\u{5D43}"),
+    ('\u{2F87D}', "\u{21DE6}"),
+    ('\u{2F87E}', "\u{5D6E}"),
+    ('\u{2F87F}', "\u{5D6B}"),
+    ('\u{2F880}', "\u{5D7C}"),
+    ('\u{2F881}', "\u{5DE1}"),
+    ('\u{2F882}', "\u{5DE2}"),
+    ('\u{2F883}', "\u{382F}"),
+    ('\u{2F884}', "\u{5DFD}"),
+    ('\u{2F885}', "\u{5E28}"),
+    ('\u{2F886}', "\u{5E3D}"),
+    ('\u{2F887}', "\u{5E69}"),
+    ('\u{2F888}', "\u{3862}"),
+    ('\u{2F889}', "\u{22183}"),
+    ('\u{2F88A}', "\u{387C}"),
+    ('\u{2F88B}', "\u{5EB0}"),
+    ('\u{2F88C}', "\u{5EB3}"),
+    ('\u{2F88D}', "\u{5EB6}"),
+    ('\u{2F88E}', "\u{5ECA}"),
+    ('\u{2F88F}', "\u{2A392}"),
+    ('\u{2F890}', "\u{5EFE}"),
+    ('\u{2F891}', "\u{22331}"),
+    ('\u{2F892}', "\u{22331}"),
+    ('\u{2F893}', "\u{8201}"),
+    ('\u{2F894}', "\u{5F22}"),
+    ('\u{2F895}', "\u{5F22}"),
+    ('\u{2F896}', "\u{38C7}"),
+    ('\u{2F897}', "\u{232B8}"),
+    ('\u{2F898}', "\u{261DA}"),
+    ('\u{2F899}', "\u{5F62}"),
+    ('\u{2F89A}', "\u{5F6B}"),
+    ('\u{2F89B}', "\u{38E3}"),
+    ('\u{2F89C}', "\u{5F9A}"),
+    ('\u{2F89D}', "\u{5FCD}"),
+    ('\u{2F89E}', "\u{5FD7}"),
+    ('\u{2F89F}', "\u{5FF9}"),
+    ('\u{2F8A0}', "\u{6081}"),
+    ('\u{2F8A1}', "\u{393A}"),
+    ('\u{2F8A2}', "\u{391C}"),
+    ('\u{2F8A3}', "\u{6094}"),
+    ('\u{2F8A4}', "\u{226D4}"),
+    ('\u{2F8A5}', "\u{60C7}"),
+    ('\u{2F8A6}', "\u{6148}"),
+    ('\u{2F8A7}', "\u{614C}"),
+    ('\u{2F8A8}', "\u{614E}"),
+    ('\u{2F8A9}', "\u{614C}"),
+    ('\u{2F8AA}', "\u{617A}"),
+    ('\u{2F8AB}', "\u{618E}"),
+    ('\u{2F8AC}', "\u{61B2}"),
+    ('\u{2F8AD}', "\u{61A4}"),
+    ('\u{2F8AE}', "\u{61AF}"),
+    ('\u{2F8AF}', "\u{61DE}"),
+    ('\u{2F8B0}', "\u{61F2}"),
+    ('\u{2F8B1}', "\u{61F6}"),
+    ('\u{2F8B2}', "\u{6210}"),
+    ('\u{2F8B3}', "\u{621B}"),
+    ('\u{2F8B4}', "\u{625D}"),
+    ('\u{2F8B5}', "\u{62B1}"),
+    ('\u{2F8B6}', "\u{62D4}"),
+    ('\u{2F8B7}', "\u{6350}"),
+    ('\u{2F8B8}', "\u{22B0C}"),
+    ('\u{2F8B9}', "\u{633D}"),
+    ('\u{2F8BA}', "\u{62FC}"),
+    ('\u{2F8BB}', "\u{6368}"),
+    ('\u{2F8BC}', "\u{6383}"),
+    ('\u{2F8BD}', "\u{63E4}"),
+    ('\u{2F8BE}', "\u{22BF1}"),
+    ('\u{2F8BF}', "\u{6422}"),
+    ('\u{2F8C0}', "\u{63C5}"),
+    ('\u{2F8C1}', "\u{63A9}"),
+    ('\u{2F8C2}', "\u{3A2E}"),
+    ('\u{2F8C3}', "\u{6469}"),
+    ('\u{2F8C4}', "\u{647E}"),
+    ('\u{2F8C5}', "\u{649D}"),
+    ('\u{2F8C6}', "\u{6477}"),
+    ('\u{2F8C7}', "\u{3A6C}"),
+    ('\u{2F8C8}', "\u{654F}"),
+    ('\u{2F8C9}', "\u{656C}"),
+    ('\u{2F8CA}', "\u{2300A}"),
+    ('\u{2F8CB}', "\u{65E3}"),
+    ('\u{2F8CC}', "\u{66F8}"),
+    ('\u{2F8CD}', "\u{6649}"),
+    ('\u{2F8CE}', "\u{3B19}"),
+    ('\u{2F8CF}', "\u{6691}"),
+    ('\u{2F8D0}', "\u{3B08}"),
+    ('\u{2F8D1}', "\u{3AE4}"),
+    ('\u{2F8D2}', "\u{5192}"),
+    ('\u{2F8D3}', "\u{5195}"),
+    ('\u{2F8D4}', "\u{6700}"),
+    ('\u{2F8D5}', "\u{669C}"),
+    ('\u{2F8D6}', "\u{80AD}"),
+    ('\u{2F8D7}', "\u{43D9}"),
+    ('\u{2F8D8}', "\u{6717}"),
+    ('\u{2F8D9}', "\u{671B}"),
+    ('\u{2F8DA}', "\u{6721}"),
+    ('\u{2F8DB}', "\u{675E}"),
+    ('\u{2F8DC}', "\u{6753}"),
+    ('\u{2F8DD}', "\u{233C3}"),
+    ('\u{2F8DE}', "\u{3B49}"),
+    ('\u{2F8DF}', "\u{67FA}"),
+    ('\u{2F8E0}', "\u{6785}"),
+    ('\u{2F8E1}', "\u{6852}"),
+    ('\u{2F8E2}', "\u{6885}"),
+    ('\u{2F8E3}', "\u{2346D}"),
+    ('\u{2F8E4}', "\u{688E}"),
+    ('\u{2F8E5}', "\u{681F}"),
+    ('\u{2F8E6}', "\u{6914}"),
+    ('\u{2F8E7}', "\u{3B9D}"),
+    ('\u{2F8E8}', "\u{6942}"),
+    ('\u{2F8E9}', "\u{69A3}"),
+    ('\u{2F8EA}', "\u{69EA}"),
+    ('\u{2F8EB}', "\u{6AA8}"),
+    ('\u{2F8EC}', "\u{236A3}"),
+    ('\u{2F8ED}', "\u{6ADB}"),
+    ('\u{2F8EE}', "\u{3C18}"),
+    ('\u{2F8EF}', "\u{6B21}"),
+    ('\u{2F8F0}', "\u{238A7}"),
+    ('\u{2F8F1}', "\u{6B54}"),
+    ('\u{2F8F2}', "\u{3C4E}"),
+    ('\u{2F8F3}', "\u{6B72}"),
+    ('\u{2F8F4}', "\u{6B9F}"),
+    ('\u{2F8F5}', "\u{6BBA}"),
+    ('\u{2F8F6}', "\u{6BBB}"),
+    ('\u{2F8F7}', "\u{23A8D}"),
+    ('\u{2F8F8}', "\u{21D0B}"),
+    ('\u{2F8F9}', "\u{23AFA}"),
+    ('\u{2F8FA}', "\u{6C4E}"),
+    ('\u{2F8FB}', "\u{23CBC}"),
+    ('\u{2F8FC}', "\u{6CBF}"),
+    ('\u{2F8FD}', "\u{6CCD}"),
+    ('\u{2F8FE}', "\u{6C67}"),
+    ('\u{2F8FF}', "\u{6D16}"),
+    ('\u{2F900}', "\u{6D3E}"),
+    ('\u{2F901}', "\u{6D77}"),
+    ('\u{2F902}', "\u{6D41}"),
+    ('\u{2F903}', "\u{6D69}"),
+    ('\u{2F904}', "\u{6D78}"),
+    ('\u{2F905}', "\u{6D85}"),
+    ('\u{2F906}', "\u{23D1E}"),
+    ('\u{2F907}', "\u{6D34}"),
+    ('\u{2F908}', "\u{6E2F}"),
+    ('\u{2F909}', "\u{6E6E}"),
+    ('\u{2F90A}', "\u{3D33}"),
+    ('\u{2F90B}', "\u{6ECB}"),
+    ('\u{2F90C}', "\u{6EC7}"),
+    ('\u{2F90D}', "\u{23ED1}"),
+    ('\u{2F90E}', "\u{6DF9}"),
+    ('\u{2F90F}', "\u{6F6E}"),
+    ('\u{2F910}', "\u{23F5E}"),
+    ('\u{2F911}', "\u{23F8E}"),
+    ('\u{2F912}', "\u{6FC6}"),
+    ('\u{2F913}', "\u{7039}"),
+    ('\u{2F914}', "\u{701E}"),
+    ('\u{2F915}', "\u{701B}"),
+    ('\u{2F916}', "\u{3D96}"),
+    ('\u{2F917}', "\u{704A}"),
+    ('\u{2F918}', "\u{707D}"),
+    ('\u{2F919}', "\u{7077}"),
+    ('\u{2F91A}', "\u{70AD}"),
+    ('\u{2F91B}', "\u{20525}"),
+    ('\u{2F91C}', "\u{7145}"),
+    ('\u{2F91D}', "\u{24263}"),
+    ('\u{2F91E}', "\u{719C}"),
+    ('\u{2F91F}', "\u{243AB}"),
+    ('\u{2F920}', "\u{7228}"),
+    ('\u{2F921}', "\u{7235}"),
+    ('\u{2F922}', "\u{7250}"),
+    ('\u{2F923}', "\u{24608}"),
+    ('\u{2F924}', "\u{7280}"),
+    ('\u{2F925}', "\u{7295}"),
+    ('\u{2F926}', "\u{24735}"),
+    ('\u{2F927}', "\u{24814}"),
+    ('\u{2F928}', "\u{737A}"),
+    ('\u{2F929}', "\u{738B}"),
+    ('\u{2F92A}', "\u{3EAC}"),
+    ('\u{2F92B}', "\u{73A5}"),
+    ('\u{2F92C}', "\u{3EB8}"),
+    ('\u{2F92D}', "\u{3EB8}"),
+    ('\u{2F92E}', "\u{7447}"),
+    ('\u{2F92F}', "\u{745C}"),
+    ('\u{2F930}', "\u{7471}"),
+    ('\u{2F931}', "\u{7485}"),
+    ('\u{2F932}', "\u{74CA}"),
+    ('\u{2F933}', "\u{3F1B}"),
+    ('\u{2F934}', "\u{7524}"),
+    ('\u{2F935}', "\u{24C36}"),
+    ('\u{2F936}', "\u{753E}"),
+    ('\u{2F937}', "\u{24C92}"),
+    ('\u{2F938}', "\u{7570}"),
+    ('\u{2F939}', "\u{2219F}"),
+    ('\u{2F93A}', "\u{7610}"),
+    ('\u{2F93B}', "\u{24FA1}"),
+    ('\u{2F93C}', "\u{24FB8}"),
+    ('\u{2F93D}', "\u{25044}"),
+    ('\u{2F93E}', "\u{3FFC}"),
+    ('\u{2F93F}', "\u{4008}"),
+    ('\u{2F940}', "\u{76F4}"),
+    ('\u{2F941}', "\u{250F3}"),
+    ('\u{2F942}', "\u{250F2}"),
+    ('\u{2F943}', "\u{25119}"),
+    ('\u{2F944}', "\u{25133}"),
+    ('\u{2F945}', "\u{771E}"),
+    ('\u{2F946}', "\u{771F}"),
+    ('\u{2F947}', "\u{771F}"),
+    ('\u{2F948}', "\u{774A}"),
+    ('\u{2F949}', "\u{4039}"),
+    ('\u{2F94A}', "\u{778B}"),
+    ('\u{2F94B}', "\u{4046}"),
+    ('\u{2F94C}', "\u{4096}"),
+    ('\u{2F94D}', "\u{2541D}"),
+    ('\u{2F94E}', "\u{784E}"),
+    ('\u{2F94F}', "\u{788C}"),
+    ('\u{2F950}', "\u{78CC}"),
+    ('\u{2F951}', "\u{40E3}"),
+    ('\u{2F952}', "\u{25626}"),
+    ('\u{2F953}', "\u{7956}"),
+    ('\u{2F954}', "\u{2569A}"),
+    ('\u{2F955}', "\u{256C5}"),
+    ('\u{2F956}', "\u{798F}"),
+    ('\u{2F957}', "\u{79EB}"),
+    ('\u{2F958}', "\u{412F}"),
+    ('\u{2F959}', "\u{7A40}"),
+    ('\u{2F95A}', "\u{7A4A}"),
+    ('\u{2F95B}', "\u{7A4F}"),
+    ('\u{2F95C}', "\u{2597C}"),
+    ('\u{2F95D}', "\u{25AA7}"),
+    ('\u{2F95E}', "\u{25AA7}"),
+    ('\u{2F95F}', "\u{7AEE}"),
+    ('\u{2F960}', "\u{4202}"),
+    ('\u{2F961}', "\u{25BAB}"),
+    ('\u{2F962}', "\u{7BC6}"),
+    ('\u{2F963}', "\u{7BC9}"),
+    ('\u{2F964}', "\u{4227}"),
+    ('\u{2F965}', "\u{25C80}"),
+    ('\u{2F966}', "\u{7CD2}"),
+    ('\u{2F967}', "\u{42A0}"),
+    ('\u{2F968}', "\u{7CE8}"),
+    ('\u{2F969}', "\u{7CE3}"),
+    ('\u{2F96A}', "\u{7D00}"),
+    ('\u{2F96B}', "\u{25F86}"),
+    ('\u{2F96C}', "\u{7D63}"),
+    ('\u{2F96D}', "\u{4301}"),
+    ('\u{2F96E}', "\u{7DC7}"),
+    ('\u{2F96F}', "\u{7E02}"),
+    ('\u{2F970}', "\u{7E45}"),
+    ('\u{2F971}', "\u{4334}"),
+    ('\u{2F972}', "\u{26228}"),
+    ('\u{2F973}', "\u{26247}"),
+    ('\u{2F974}', "\u{4359}"),
+    ('\u{2F975}', "\u{262D9}"),
+    ('\u{2F976}', "\u{7F7A}"),
+    ('\u{2F977}', "\u{2633E}"),
+    ('\u{2F978}', "\u{7F95}"),
+    ('\u{2F979}', "\u{7FFA}"),
+    ('\u{2F97A}', "\u{8005}"),
+    ('\u{2F97B}', "\u{264DA}"),
+    ('\u{2F97C}', "\u{26523}"),
+    ('\u{2F97D}', "\u{8060}"),
+    ('\u{2F97E}', "\u{265A8}"),
+    ('\u{2F97F}', "\u{8070}"),
+    ('\u{2F980}', "\u{2335F}"),
+    ('\u{2F981}', "\u{43D5}"),
+    ('\u{2F982}', "\u{80B2}"),
+    ('\u{2F983}', "\u{8103}"),
+    ('\u{2F984}', "\u{440B}"),
+    ('\u{2F985}', "\u{813E}"),
+    ('\u{2F986}', "\u{5AB5}"),
+    ('\u{2F987}', "\u{267A7}"),
+    ('\u{2F988}', "\u{267B5}"),
+    ('\u{2F989}', "\u{23393}"),
+    ('\u{2F98A}', "\u{2339C}"),
+    ('\u{2F98B}', "\u{8201}"),
+    ('\u{2F98C}', "\u{8204}"),
+    ('\u{2F98D}', "\u{8F9E}"),
+    ('\u{2F98E}', "\u{446B}"),
+    ('\u{2F98F}', "\u{8291}"),
+    ('\u{2F990}', "\u{828B}"),
+    ('\u{2F991}', "\u{829D}"),
+    ('\u{2F992}', "\u{52B3}"),
+    ('\u{2F993}', "\u{82B1}"),
+    ('\u{2F994}', "\u{82B3}"),
+    ('\u{2F995}', "\u{82BD}"),
+    ('\u{2F996}', "\u{82E6}"),
+    ('\u{2F997}', "\u{26B3C}"),
+    ('\u{2F998}', "\u{82E5}"),
+    ('\u{2F999}', "\u{831D}"),
+    ('\u{2F99A}', "\u{8363}"),
+    ('\u{2F99B}', "\u{83AD}"),
+    ('\u{2F99C}', "\u{8323}"),
+    ('\u{2F99D}', "\u{83BD}"),
+    ('\u{2F99E}', "\u{83E7}"),
+    ('\u{2F99F}', "\u{8457}"),
+    ('\u{2F9A0}', "\u{8353}"),
+    ('\u{2F9A1}', "\u{83CA}"),
+    ('\u{2F9A2}', "\u{83CC}"),
+    ('\u{2F9A3}', "\u{83DC}"),
+    ('\u{2F9A4}', "\u{26C36}"),
+    ('\u{2F9A5}', "\u{26D6B}"),
+    ('\u{2F9A6}', "\u{26CD5}"),
+    ('\u{2F9A7}', "\u{452B}"),
+    ('\u{2F9A8}', "\u{84F1}"),
+    ('\u{2F9A9}', "\u{84F3}"),
+    ('\u{2F9AA}', "\u{8516}"),
+    ('\u{2F9AB}', "\u{273CA}"),
+    ('\u{2F9AC}', "\u{8564}"),
+    ('\u{2F9AD}', "\u{26F2C}"),
+    ('\u{2F9AE}', "\u{455D}"),
+    ('\u{2F9AF}', "\u{4561}"),
+    ('\u{2F9B0}', "\u{26FB1}"),
+    ('\u{2F9B1}', "\u{270D2}"),
+    ('\u{2F9B2}', "\u{456B}"),
+    ('\u{2F9B3}', "\u{8650}"),
+    ('\u{2F9B4}', "\u{865C}"),
+    ('\u{2F9B5}', "\u{8667}"),
+    ('\u{2F9B6}', "\u{8669}"),
+    ('\u{2F9B7}', "\u{86A9}"),
+    ('\u{2F9B8}', "\u{8688}"),
+    ('\u{2F9B9}', "\u{870E}"),
+    ('\u{2F9BA}', "\u{86E2}"),
+    ('\u{2F9BB}', "\u{8779}"),
+    ('\u{2F9BC}', "\u{8728}"),
+    ('\u{2F9BD}', "\u{876B}"),
+    ('\u{2F9BE}', "\u{8786}"),
+    ('\u{2F9BF}', "\u{45D7}"),
+    ('\u{2F9C0}', "\u{87E1}"),
+    ('\u{2F9C1}', "\u{8801}"),
+    ('\u{2F9C2}', "\u{45F9}"),
+    ('\u{2F9C3}', "\u{8860}"),
+    ('\u{2F9C4}', "\u{8863}"),
+    ('\u{2F9C5}', "\u{27667}"),
+    ('\u{2F9C6}', "\u{88D7}"),
+    ('\u{2F9C7}', "\u{88DE}"),
+    ('\u{2F9C8}', "\u{4635}"),
+    ('\u{2F9C9}', "\u{88FA}"),
+    ('\u{2F9CA}', "\u{34BB}"),
+    ('\u{2F9CB}', "\u{278AE}"),
+    ('\u{2F9CC}', "\u{27966}"),
+    ('\u{2F9CD}', "\u{46BE}"),
+    ('\u{2F9CE}', "\u{46C7}"),
+    ('\u{2F9CF}', "\u{8AA0}"),
+    ('\u{2F9D0}', "\u{8AED}"),
+    ('\u{2F9D1}', "\u{8B8A}"),
+    ('\u{2F9D2}', "\u{8C55}"),
+    ('\u{2F9D3}', "\u{27CA8}"),
+    ('\u{2F9D4}', "\u{8CAB}"),
+    ('\u{2F9D5}', "\u{8CC1}"),
+    ('\u{2F9D6}', "\u{8D1B}"),
+    ('\u{2F9D7}', "\u{8D77}"),
+    ('\u{2F9D8}', "\u{27F2F}"),
+    ('\u{2F9D9}', "\u{20804}"),
+    ('\u{2F9DA}', "\u{8DCB}"),
+    ('\u{2F9DB}', "\u{8DBC}"),
+    ('\u{2F9DC}', "\u{8DF0}"),
+    ('\u{2F9DD}', "\u{208DE}"),
+    ('\u{2F9DE}', "\u{8ED4}"),
+    ('\u{2F9DF}', "\u{8F38}"),
+    ('\u{2F9E0}', "\u{285D2}"),
+    ('\u{2F9E1}', "\u{285ED}"),
+    ('\u{2F9E2}', "\u{9094}"),
+    ('\u{2F9E3}', "\u{90F1}"),
+    ('\u{2F9E4}', "\u{9111}"),
+    ('\u{2F9E5}', "\u{2872E}"),
+    ('\u{2F9E6}', "\u{911B}"),
+    ('\u{2F9E7}', "\u{9238}"),
+    ('\u{2F9E8}', "\u{92D7}"),
+    ('\u{2F9E9}', "\u{92D8}"),
+    ('\u{2F9EA}', "\u{927C}"),
+    ('\u{2F9EB}', "\u{93F9}"),
+    ('\u{2F9EC}', "\u{9415}"),
+    ('\u{2F9ED}', "\u{28BFA}"),
+    ('\u{2F9EE}', "\u{958B}"),
+    ('\u{2F9EF}', "\u{4995}"),
+    ('\u{2F9F0}', "\u{95B7}"),
+    ('\u{2F9F1}', "\u{28D77}"),
+    ('\u{2F9F2}', "\u{49E6}"),
+    ('\u{2F9F3}', "\u{96C3}"),
+    ('\u{2F9F4}', "\u{5DB2}"),
+    ('\u{2F9F5}', "\u{9723}"),
+    ('\u{2F9F6}', "\u{29145}"),
+    ('\u{2F9F7}', "\u{2921A}"),
+    ('\u{2F9F8}', "\u{4A6E}"),
+    ('\u{2F9F9}', "\u{4A76}"),
+    ('\u{2F9FA}', "\u{97E0}"),
+    ('\u{2F9FB}', "\u{2940A}"),
+    ('\u{2F9FC}', "\u{4AB2}"),
+    ('\u{2F9FD}', "\u{29496}"),
+    ('\u{2F9FE}', "\u{980B}"),
+    ('\u{2F9FF}', "\u{980B}"),
+    ('\u{2FA00}', "\u{9829}"),
+    ('\u{2FA01}', "\u{295B6}"),
+    ('\u{2FA02}', "\u{98E2}"),
+    ('\u{2FA03}', "\u{4B33}"),
+    ('\u{2FA04}', "\u{9929}"),
+    ('\u{2FA05}', "\u{99A7}"),
+    ('\u{2FA06}', "\u{99C2}"),
+    ('\u{2FA07}', "\u{99FE}"),
+    ('\u{2FA08}', "\u{4BCE}"),
+    ('\u{2FA09}', "\u{29B30}"),
+    ('\u{2FA0A}', "\u{9B12}"),
+    ('\u{2FA0B}', "\u{9C40}"),
+    ('\u{2FA0C}', "\u{9CFD}"),
+    ('\u{2FA0D}', "\u{4CCE}"),
+    ('\u{2FA0E}', "\u{4CED}"),
+    ('\u{2FA0F}', "\u{9D67}"),
+    ('\u{2FA10}', "\u{2A0CE}"),
+    ('\u{2FA11}', "\u{4CF8}"),
+    ('\u{2FA12}', "\u{2A105}"),
+    ('\u{2FA13}', "\u{2A20E}"),
+    ('\u{2FA14}', "\u{2A291}"),
+    ('\u{2FA15}', "\u{9EBB}"),
+    ('\u{2FA16}', "\u{4D56}"),
+    ('\u{2FA17}', "\u{9EF9}"),
+    ('\u{2FA18}', "\u{9EFE}"),
+    ('\u{2FA19}', "\u{9F05}"),
+    ('\u{2FA1A}', "\u{9F0F}"),
+    ('\u{2FA1B}', "\u{9F16}"),
+    ('\u{2FA1C}', "\u{9F3B}"),
+    ('\u{2FA1D}', "\u{2A600}"),
+];
@@ -1,7 +1,34 @@
 //! Splits `subject` into an chuncks according to given rules.
 
+use regex::Regex;
 use stfu8;
 use unicode_segmentation::UnicodeSegmentation;
+
+/// The unit which a string is measured or processed in, e.g. by the `distance` functions and `query::fuzzy_match`.
+/// More units may be added, so the `match`es on `Unit` outside of this crate need a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Unit {
+    /// Unicode scalar values, see `split::chars`: "e\u{0301}" is two units.
+    Char,
+    /// Extended grapheme clusters, see `split::graphemes`: "e\u{0301}" is one unit.
+    Grapheme,
+    /// Words, see `split::words`: "Hello, world" is two units, the punctuation and whitespace are skipped.
+    Word,
+}
+
+/// Splits `subject` into the given `unit`s. Unlike `split::chars` and `split::graphemes` returns an empty array for an empty `subject`.
+pub(crate) fn units(subject: &str, unit: Unit) -> Vec<&str> {
+    if subject.is_empty() {
+        return vec![];
+    }
+    match unit {
+        Unit::Char => chars(subject),
+        Unit::Grapheme => graphemes(subject),
+        Unit::Word => words(subject),
+    }
+}
+
 /// Splits `subject` into an array of characters.
 ///
 /// # Arguments
@@ -22,7 +49,31 @@ pub fn chars(subject: &str) -> Vec<&str> {
     if subject.is_empty() {
         return vec![""];
     }
-    subject.split_terminator("").skip(1).collect::<Vec<_>>()
+    chars_iter(subject).collect()
+}
+
+/// Returns a lazy iterator over the characters of `subject`. Unlike `split::chars` yields nothing for an empty `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into characters.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::chars_iter("cloud").take(3).collect::<Vec<_>>();
+/// // => ["c", "l", "o"]
+/// split::chars_iter("błąd").rev().collect::<Vec<_>>();
+/// // => ["d", "ą", "ł", "b"]
+/// use voca_rs::Voca;
+/// "cloud"._chars_iter().next();
+/// // => Some("c")
+/// ```
+pub fn chars_iter(subject: &str) -> impl DoubleEndedIterator<Item = &str> {
+    subject
+        .char_indices()
+        .map(move |(index, c)| &subject[index..index + c.len_utf8()])
 }
 
 /// Splits `subject` into an array of chunks by `separator`.
@@ -46,10 +97,427 @@ pub fn split<'a>(subject: &'a str, pattern: &str) -> Vec<&'a str> {
     if subject.is_empty() {
         return vec![""];
     }
+    split_iter(subject, pattern).collect()
+}
+
+/// Returns a lazy iterator over the chunks of `subject` split by `pattern`. Unlike `split::split` yields nothing for an empty `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into chunks.
+/// * `pattern` - The pattern to match the separator.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::split_iter("rage against the dying of the light", " ").nth(1);
+/// // => Some("against")
+/// split::split_iter("*dying*star*", "*").collect::<Vec<_>>();
+/// // => ["", "dying", "star"]
+/// use voca_rs::Voca;
+/// "rage against the light"._split_iter(" ").last();
+/// // => Some("light")
+/// ```
+pub fn split_iter<'a: 'b, 'b>(
+    subject: &'a str,
+    pattern: &'b str,
+) -> impl Iterator<Item = &'a str> + 'b {
+    let (whole, chunks) = if pattern.is_empty() {
+        (Some(subject).filter(|s| !s.is_empty()), None)
+    } else {
+        (None, Some(subject.split_terminator(pattern)))
+    };
+    whole.into_iter().chain(chunks.into_iter().flatten())
+}
+
+/// Splits `subject` into at most `limit` chunks by `pattern`, the last chunk contains the rest of `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into chunks.
+/// * `pattern` - The pattern to match the separator.
+/// * `limit` - The maximum number of chunks.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::splitn("rage against the dying of the light", " ", 3);
+/// // => ["rage", "against", "the dying of the light"]
+/// split::splitn("key=value=more", "=", 2);
+/// // => ["key", "value=more"]
+/// use voca_rs::Voca;
+/// "key=value=more"._splitn("=", 2);
+/// // => ["key", "value=more"]
+/// ```
+pub fn splitn<'a>(subject: &'a str, pattern: &str, limit: usize) -> Vec<&'a str> {
+    if subject.is_empty() && limit > 0 {
+        return vec![""];
+    }
     if pattern.is_empty() {
-        return vec![subject];
+        return vec![subject].into_iter().take(limit).collect();
     }
-    subject.split_terminator(pattern).collect::<Vec<_>>()
+    subject.splitn(limit, pattern).collect()
+}
+
+/// Splits `subject` into at most `limit` chunks by `pattern` starting from the end, the first chunk contains the rest of `subject`.
+/// The chunks are returned in the order they appear in `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into chunks.
+/// * `pattern` - The pattern to match the separator.
+/// * `limit` - The maximum number of chunks.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::rsplitn("rage against the dying of the light", " ", 3);
+/// // => ["rage against the dying of", "the", "light"]
+/// split::rsplitn("archive.tar.gz", ".", 2);
+/// // => ["archive.tar", "gz"]
+/// use voca_rs::Voca;
+/// "archive.tar.gz"._rsplitn(".", 2);
+/// // => ["archive.tar", "gz"]
+/// ```
+pub fn rsplitn<'a>(subject: &'a str, pattern: &str, limit: usize) -> Vec<&'a str> {
+    if subject.is_empty() && limit > 0 {
+        return vec![""];
+    }
+    if pattern.is_empty() {
+        return vec![subject].into_iter().take(limit).collect();
+    }
+    let mut chunks = subject.rsplitn(limit, pattern).collect::<Vec<_>>();
+    chunks.reverse();
+    chunks
+}
+
+/// What `split::split_regex_with` does with the matched delimiters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Delimiters {
+    /// The delimiters are removed, but their capture groups are kept as separate chunks like in Python's `re.split`.
+    Drop,
+    /// The delimiters are kept as separate chunks.
+    Separate,
+    /// The delimiters are attached to the end of the previous chunk.
+    AttachToPrevious,
+    /// The delimiters are attached to the start of the next chunk.
+    AttachToNext,
+}
+
+/// The options of `split::split_regex_with`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitRegexOptions {
+    /// The maximum number of chunks, not counting the separate delimiters, the last chunk contains the rest of `subject`. Default value is `None`, i.e. no limit.
+    pub limit: Option<usize>,
+    /// What to do with the delimiters. Default value is `Delimiters::Drop`.
+    pub delimiters: Delimiters,
+}
+
+impl Default for SplitRegexOptions {
+    fn default() -> SplitRegexOptions {
+        SplitRegexOptions {
+            limit: None,
+            delimiters: Delimiters::Drop,
+        }
+    }
+}
+
+/// Splits `subject` into an array of chunks by the regular expression `pattern`.
+/// The capture groups of `pattern` are included in the result like in Python's `re.split`, the groups which did not participate in the match give empty strings.
+/// The empty matches at the start and at the end of `subject` do not split it.
+/// NOTE: Returns `subject` as the only chunk if `pattern` is not a valid regular expression.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into chunks.
+/// * `pattern` - The RegExp pattern to match the separator, it is transformed to Regex::new(pattern).
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::split_regex("one, two;three", r"[,;]\s*");
+/// // => ["one", "two", "three"]
+/// split::split_regex("1+2-3", r"([+-])");
+/// // => ["1", "+", "2", "-", "3"]
+/// split::split_regex("abc", "");
+/// // => ["a", "b", "c"]
+/// use voca_rs::Voca;
+/// "one, two;three"._split_regex(r"[,;]\s*");
+/// // => ["one", "two", "three"]
+/// ```
+pub fn split_regex<'a>(subject: &'a str, pattern: &str) -> Vec<&'a str> {
+    split_regex_with(subject, pattern, &SplitRegexOptions::default())
+}
+
+/// Splits `subject` into an array of chunks by the regular expression `pattern`, limiting the number of chunks and keeping the delimiters, see `split::split_regex`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into chunks.
+/// * `pattern` - The RegExp pattern to match the separator, it is transformed to Regex::new(pattern).
+/// * `options` - The limit and what to do with the delimiters.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::split_regex_with("a1b22c333d", r"\d+", &split::SplitRegexOptions {
+///     limit: Some(3),
+///     delimiters: split::Delimiters::Separate,
+/// });
+/// // => ["a", "1", "b", "22", "c333d"]
+/// split::split_regex_with("Hi! How are you? Fine.", r"[!?.]\s*", &split::SplitRegexOptions {
+///     limit: None,
+///     delimiters: split::Delimiters::AttachToPrevious,
+/// });
+/// // => ["Hi! ", "How are you? ", "Fine.", ""]
+/// use voca_rs::Voca;
+/// "a1b22c"._split_regex_with(r"\d+", &split::SplitRegexOptions {
+///     limit: None,
+///     delimiters: split::Delimiters::AttachToNext,
+/// });
+/// // => ["a", "1b", "22c"]
+/// ```
+pub fn split_regex_with<'a>(
+    subject: &'a str,
+    pattern: &str,
+    options: &SplitRegexOptions,
+) -> Vec<&'a str> {
+    if options.limit == Some(0) {
+        return vec![];
+    }
+    let re = match Regex::new(pattern) {
+        Ok(re) => re,
+        Err(_) => return vec![subject],
+    };
+    let mut chunks = vec![];
+    let mut chunk_start = 0;
+    let mut count = 1;
+    for captures in re.captures_iter(subject) {
+        if options.limit.is_some_and(|limit| count >= limit) {
+            break;
+        }
+        let delimiter = captures.get(0).unwrap();
+        if delimiter.start() == delimiter.end()
+            && (delimiter.start() == 0 || delimiter.end() == subject.len())
+        {
+            continue;
+        }
+        match options.delimiters {
+            Delimiters::Drop => {
+                chunks.push(&subject[chunk_start..delimiter.start()]);
+                chunks.extend(captures.iter().skip(1).map(|group| match group {
+                    Some(group) => group.as_str(),
+                    None => "",
+                }));
+                chunk_start = delimiter.end();
+            }
+            Delimiters::Separate => {
+                chunks.push(&subject[chunk_start..delimiter.start()]);
+                chunks.push(delimiter.as_str());
+                chunk_start = delimiter.end();
+            }
+            Delimiters::AttachToPrevious => {
+                chunks.push(&subject[chunk_start..delimiter.end()]);
+                chunk_start = delimiter.end();
+            }
+            Delimiters::AttachToNext => {
+                chunks.push(&subject[chunk_start..delimiter.start()]);
+                chunk_start = delimiter.start();
+            }
+        }
+        count += 1;
+    }
+    chunks.push(&subject[chunk_start..]);
+    chunks
+}
+
+/// The error of `split::shell_words` and `split::split_quoted`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplitError {
+    /// The quote opened at the byte index is not closed.
+    UnbalancedQuote(usize),
+    /// The escape character at the byte index ends the string, so there is nothing to escape.
+    DanglingEscape(usize),
+}
+
+impl std::fmt::Display for SplitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            SplitError::UnbalancedQuote(index) => write!(f, "unbalanced quote at {}", index),
+            SplitError::DanglingEscape(index) => write!(f, "nothing to escape at {}", index),
+        }
+    }
+}
+
+impl std::error::Error for SplitError {}
+
+/// The options of `split::split_quoted`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitOptions {
+    /// The character separating the tokens. When `None` the tokens are separated by runs of whitespace. Default value is `Some(',')`.
+    pub delimiter: Option<char>,
+    /// The characters which quote a part of a token, so the delimiters inside it are kept. Default value is `['"', '\'']`.
+    pub quotes: Vec<char>,
+    /// The character which makes the next character literal, both inside and outside the quotes. Default value is `Some('\\')`.
+    pub escape: Option<char>,
+}
+
+impl Default for SplitOptions {
+    fn default() -> SplitOptions {
+        SplitOptions {
+            delimiter: Some(','),
+            quotes: vec!['"', '\''],
+            escape: Some('\\'),
+        }
+    }
+}
+
+/// Splits `subject` into words the way a POSIX shell does. The words are separated by whitespace,
+/// the single quotes keep everything literally, the double quotes and the backslash allow escaping.
+/// The words without quotes and escapes are borrowed from `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into words.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::shell_words(r#"a "b c" 'd e' f\ g"#);
+/// // => Ok(["a", "b c", "d e", "f g"])
+/// split::shell_words(r#"echo "it's \"fine\"""#);
+/// // => Ok(["echo", "it's \"fine\""])
+/// split::shell_words("echo 'oops");
+/// // => Err(split::SplitError::UnbalancedQuote(5))
+/// use voca_rs::Voca;
+/// r#"a "b c""#._shell_words();
+/// // => Ok(["a", "b c"])
+/// ```
+pub fn shell_words(subject: &str) -> Result<Vec<std::borrow::Cow<'_, str>>, SplitError> {
+    let options = SplitOptions {
+        delimiter: None,
+        ..SplitOptions::default()
+    };
+    split_tokens(subject, &options, true)
+}
+
+/// Splits `subject` by the delimiter respecting the quotes and the escapes, e.g. the fields of a CSV-like line.
+/// Unlike `split::split`, the delimiters inside the quotes or after the escape character do not split.
+/// The quotes and the escape characters are removed from the tokens, the tokens without them are borrowed from `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split.
+/// * `options` - The delimiter, quote and escape characters.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::split_quoted(r#"one,"two, three",four\,five"#, &split::SplitOptions::default());
+/// // => Ok(["one", "two, three", "four,five"])
+/// split::split_quoted("a;;'b;c'", &split::SplitOptions {
+///     delimiter: Some(';'),
+///     quotes: vec!['\''],
+///     escape: None,
+/// });
+/// // => Ok(["a", "", "b;c"])
+/// use voca_rs::Voca;
+/// r#"one,"two, three""#._split_quoted(&split::SplitOptions::default());
+/// // => Ok(["one", "two, three"])
+/// ```
+pub fn split_quoted<'a>(
+    subject: &'a str,
+    options: &SplitOptions,
+) -> Result<Vec<std::borrow::Cow<'a, str>>, SplitError> {
+    split_tokens(subject, options, false)
+}
+
+fn split_tokens<'a>(
+    subject: &'a str,
+    options: &SplitOptions,
+    posix: bool,
+) -> Result<Vec<std::borrow::Cow<'a, str>>, SplitError> {
+    let mut tokens = vec![];
+    if subject.is_empty() {
+        return Ok(tokens);
+    }
+    let is_delimiter = |c: char| match options.delimiter {
+        Some(delimiter) => c == delimiter,
+        None => c.is_whitespace(),
+    };
+    let mut push_token = |token: &mut String, start: usize, end: usize| {
+        let raw = &subject[start..end];
+        tokens.push(if token == raw {
+            std::borrow::Cow::Borrowed(raw)
+        } else {
+            std::borrow::Cow::Owned(token.clone())
+        });
+        token.clear();
+    };
+    let mut token = String::new();
+    // the fields between the delimiters always exist, while the whitespace separated words start with their first character
+    let mut token_start = if options.delimiter.is_some() {
+        Some(0)
+    } else {
+        None
+    };
+    let mut chars = subject.char_indices();
+    while let Some((index, c)) = chars.next() {
+        if is_delimiter(c) {
+            if let Some(start) = token_start {
+                push_token(&mut token, start, index);
+            }
+            token_start = options.delimiter.map(|d| index + d.len_utf8());
+            continue;
+        }
+        if Some(c) == options.escape {
+            match chars.next() {
+                // a line continuation
+                Some((_, '\n')) if posix => {}
+                Some((_, escaped)) => {
+                    token_start.get_or_insert(index);
+                    token.push(escaped);
+                }
+                None => return Err(SplitError::DanglingEscape(index)),
+            }
+            continue;
+        }
+        token_start.get_or_insert(index);
+        if !options.quotes.contains(&c) {
+            token.push(c);
+            continue;
+        }
+        // in a POSIX shell the single quotes have no escapes and the double quotes escape only the special characters
+        let escapes = !(posix && c == '\'');
+        loop {
+            match chars.next() {
+                Some((_, q)) if q == c => break,
+                Some((_, e)) if escapes && Some(e) == options.escape => match chars.next() {
+                    Some((_, '\n')) if posix => {}
+                    Some((_, escaped)) => {
+                        if posix && !matches!(escaped, '\\' | '"' | '$' | '`') {
+                            token.push(e);
+                        }
+                        token.push(escaped);
+                    }
+                    None => return Err(SplitError::UnbalancedQuote(index)),
+                },
+                Some((_, other)) => token.push(other),
+                None => return Err(SplitError::UnbalancedQuote(index)),
+            }
+        }
+    }
+    if let Some(start) = token_start {
+        push_token(&mut token, start, subject.len());
+    }
+    Ok(tokens)
 }
 
 /// Splits `subject` into an array of words.
@@ -71,61 +539,206 @@ pub fn split<'a>(subject: &'a str, pattern: &str) -> Vec<&'a str> {
 /// // => ["Sześć", "звёзд", "are", "dying"]
 /// ```
 pub fn words(subject: &str) -> Vec<&str> {
-    fn split_camel_case(string: &str) -> Vec<&str> {
-        // https://github.com/withoutboats/heck/blob/master/src/lib.rs
-        #[derive(Clone, Copy, PartialEq)]
-        enum WordMode {
-            /// There have been no lowercase or uppercase characters in the current word.
-            Boundary,
-            /// The previous cased character in the current word is lowercase.
-            Lowercase,
-            /// The previous cased character in the current word is uppercase.
-            Uppercase,
-        }
-        let mut words = Vec::new();
-        let mut word_start = 0;
-        let mut char_indices = string.char_indices().peekable();
-        let mut mode = WordMode::Boundary;
-        while let Some((c_idx, c)) = char_indices.next() {
-            if let Some(&(next_idx, next)) = char_indices.peek() {
-                let next_mode = if c.is_lowercase() {
-                    WordMode::Lowercase
-                } else if c.is_uppercase() {
-                    WordMode::Uppercase
-                } else {
-                    mode
-                };
-
-                // not uppercase and next is uppercase
-                if next_mode == WordMode::Lowercase && next.is_uppercase() {
-                    words.push(&string[word_start..next_idx]);
-                    word_start = next_idx;
-                    mode = WordMode::Boundary;
-                // Otherwise if current and previous are uppercase and next
-                // is lowercase, word boundary before
-                } else if mode == WordMode::Uppercase && c.is_uppercase() && next.is_lowercase() {
-                    words.push(&string[word_start..c_idx]);
-                    word_start = c_idx;
-                    mode = WordMode::Boundary;
-                // Otherwise no word boundary, just update the mode
-                } else {
-                    mode = next_mode;
-                }
+    words_iter(subject).collect()
+}
+
+/// Returns a lazy iterator over the words of `subject`, see `split::words`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into words.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::words_iter("LazyLoad with XMLHttpRequest").take(3).collect::<Vec<_>>();
+/// // => ["Lazy", "Load", "with"]
+/// split::words_iter("Sześć звёзд are dying").next_back();
+/// // => Some("dying")
+/// use voca_rs::Voca;
+/// "Sześć звёзд are dying"._words_iter().next();
+/// // => Some("Sześć")
+/// ```
+pub fn words_iter(subject: &str) -> impl DoubleEndedIterator<Item = &str> {
+    let splitting_punctuation = ['-', '_'];
+    subject
+        .unicode_words()
+        .flat_map(move |w| w.split_terminator(move |c| splitting_punctuation.contains(&c)))
+        .filter(|w| !w.is_empty())
+        .flat_map(split_camel_case)
+}
+
+fn split_camel_case(string: &str) -> Vec<&str> {
+    // https://github.com/withoutboats/heck/blob/master/src/lib.rs
+    #[derive(Clone, Copy, PartialEq)]
+    enum WordMode {
+        /// There have been no lowercase or uppercase characters in the current word.
+        Boundary,
+        /// The previous cased character in the current word is lowercase.
+        Lowercase,
+        /// The previous cased character in the current word is uppercase.
+        Uppercase,
+    }
+    let mut words = Vec::new();
+    let mut word_start = 0;
+    let mut char_indices = string.char_indices().peekable();
+    let mut mode = WordMode::Boundary;
+    while let Some((c_idx, c)) = char_indices.next() {
+        if let Some(&(next_idx, next)) = char_indices.peek() {
+            let next_mode = if c.is_lowercase() {
+                WordMode::Lowercase
+            } else if c.is_uppercase() {
+                WordMode::Uppercase
+            } else {
+                mode
+            };
+
+            // not uppercase and next is uppercase
+            if next_mode == WordMode::Lowercase && next.is_uppercase() {
+                words.push(&string[word_start..next_idx]);
+                word_start = next_idx;
+                mode = WordMode::Boundary;
+            // Otherwise if current and previous are uppercase and next
+            // is lowercase, word boundary before
+            } else if mode == WordMode::Uppercase && c.is_uppercase() && next.is_lowercase() {
+                words.push(&string[word_start..c_idx]);
+                word_start = c_idx;
+                mode = WordMode::Boundary;
+            // Otherwise no word boundary, just update the mode
+            } else {
+                mode = next_mode;
             }
         }
-        words.push(&string[word_start..]);
-        words
     }
+    words.push(&string[word_start..]);
+    words
+}
 
-    let splitting_punctuation = ['-', '_'];
+/// The kind of a segment returned by `split::word_bounds`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordKind {
+    /// A word containing letters, e.g. "iPhone", "can't" or "R2D2".
+    Word,
+    /// A number, e.g. "42" or "3.14".
+    Number,
+    /// A run of whitespace.
+    Space,
+    /// Punctuation, symbols and emoji.
+    Punctuation,
+}
 
-    let split_by_whitespace_and_punctuation = subject
-        .unicode_words()
-        .flat_map(|w| w.split_terminator(|c| splitting_punctuation.contains(&c)))
-        .filter(|w| !w.is_empty());
+/// A segment returned by `split::word_bounds`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WordBound<'a> {
+    /// The text of the segment.
+    pub text: &'a str,
+    /// The byte range of the segment in the subject.
+    pub range: std::ops::Range<usize>,
+    /// The kind of the segment.
+    pub kind: WordKind,
+}
+
+/// Splits `subject` into segments at the word boundaries according to the Unicode Text Segmentation (UAX #29).
+/// Unlike `split::words` the words are not broken at the hyphens, underscores or case changes, and the spaces and punctuation are kept as separate segments.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into segments.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::word_bounds("My iPhone costs $3.50")
+///     .iter()
+///     .map(|bound| bound.text)
+///     .collect::<Vec<_>>();
+/// // => ["My", " ", "iPhone", " ", "costs", " ", "$", "3.50"]
+/// split::word_bounds("can't stop")[0].range.clone();
+/// // => 0..5
+/// use voca_rs::Voca;
+/// "can't stop"._word_bounds()[1].kind;
+/// // => WordKind::Space
+/// ```
+pub fn word_bounds(subject: &str) -> Vec<WordBound<'_>> {
+    subject
+        .split_word_bound_indices()
+        .map(|(index, text)| {
+            let kind = if text.chars().all(char::is_whitespace) {
+                WordKind::Space
+            } else if text.chars().any(char::is_alphabetic) {
+                WordKind::Word
+            } else if text.chars().next().is_some_and(char::is_numeric) {
+                WordKind::Number
+            } else {
+                WordKind::Punctuation
+            };
+            WordBound {
+                text,
+                range: index..index + text.len(),
+                kind,
+            }
+        })
+        .collect()
+}
+
+/// Splits `subject` into an array of sentences according to the Unicode Text Segmentation (UAX #29).
+/// The common abbreviations like "Mr." or "Dr." and the initials like "J. R. R." do not end a sentence.
+/// The whitespace around the sentences is trimmed.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into sentences.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::sentences("Hello world! How are you? I'm fine.");
+/// // => ["Hello world!", "How are you?", "I'm fine."]
+/// split::sentences("Mr. Smith met Dr. Watson. They talked.");
+/// // => ["Mr. Smith met Dr. Watson.", "They talked."]
+/// use voca_rs::Voca;
+/// "Hello world! How are you?"._sentences();
+/// // => ["Hello world!", "How are you?"]
+/// ```
+pub fn sentences(subject: &str) -> Vec<&str> {
+    let mut sentences = vec![];
+    let mut start = 0;
+    for (index, sentence) in subject.split_sentence_bound_indices() {
+        let end = index + sentence.len();
+        let last_word = sentence
+            .trim_end()
+            .rsplit(char::is_whitespace)
+            .next()
+            .unwrap_or("");
+        if end < subject.len() && is_abbreviation(last_word) {
+            continue;
+        }
+        let sentence = subject[start..end].trim();
+        if !sentence.is_empty() {
+            sentences.push(sentence);
+        }
+        start = end;
+    }
+    sentences
+}
 
-    let res = split_by_whitespace_and_punctuation.flat_map(split_camel_case);
-    res.collect()
+const ABBREVIATIONS: [&str; 16] = [
+    "Mr", "Mrs", "Ms", "Dr", "Prof", "Sr", "Jr", "St", "Mt", "Gen", "Gov", "Sen", "Rev", "Capt",
+    "Lt", "vs",
+];
+
+fn is_abbreviation(word: &str) -> bool {
+    let word = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+    match word.strip_suffix('.') {
+        Some(stem) => {
+            ABBREVIATIONS.contains(&stem)
+                || (stem.chars().count() == 1 && stem.chars().all(char::is_uppercase))
+        }
+        None => false,
+    }
 }
 
 /// Splits `subject` into an array of graphemes
@@ -148,7 +761,362 @@ pub fn graphemes(subject: &str) -> Vec<&str> {
     if subject.is_empty() {
         return vec![""];
     }
-    UnicodeSegmentation::graphemes(subject, true).collect::<Vec<&str>>()
+    graphemes_iter(subject).collect()
+}
+
+/// Returns a lazy iterator over the graphemes of `subject`. Unlike `split::graphemes` yields nothing for an empty `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into graphemes.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::graphemes_iter("a̐éö̲\r\n").nth(1);
+/// // => Some("é")
+/// split::graphemes_iter("a̐éö̲").rev().collect::<Vec<_>>();
+/// // => ["ö̲", "é", "a̐"]
+/// use voca_rs::Voca;
+/// "a̐éö̲"._graphemes_iter().count();
+/// // => 3
+/// ```
+pub fn graphemes_iter(subject: &str) -> impl DoubleEndedIterator<Item = &str> {
+    UnicodeSegmentation::graphemes(subject, true)
+}
+
+/// Returns the emoji of `subject` (see `query::is_emoji`) together with their byte positions.
+///
+/// # Arguments
+///
+/// * `subject` - The string to find emoji in.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::emoji("Hi 👋🏽, nice 🎉");
+/// // => [(3, "👋🏽"), (18, "🎉")]
+/// split::emoji("no emoji");
+/// // => []
+/// use voca_rs::Voca;
+/// "Hi 👋🏽"._emoji();
+/// // => [(3, "👋🏽")]
+/// ```
+pub fn emoji(subject: &str) -> Vec<(usize, &str)> {
+    UnicodeSegmentation::grapheme_indices(subject, true)
+        .filter(|(_, grapheme)| crate::query::is_emoji_grapheme(grapheme))
+        .collect()
+}
+
+/// Splits `subject` into an array of chunks of `size` graphemes. The last chunk may be shorter.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into chunks.
+/// * `size` - The number of graphemes in a chunk. If `0`, `subject` is returned as the only chunk.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::chunk("4111111111111111", 4);
+/// // => ["4111", "1111", "1111", "1111"]
+/// split::chunk("a̐éö̲xy", 2);
+/// // => ["a̐é", "ö̲x", "y"]
+/// use voca_rs::Voca;
+/// "4111111111111111"._chunk(4);
+/// // => ["4111", "1111", "1111", "1111"]
+/// ```
+pub fn chunk(subject: &str, size: usize) -> Vec<&str> {
+    if subject.is_empty() {
+        return vec![];
+    }
+    if size == 0 {
+        return vec![subject];
+    }
+    let mut chunks = vec![];
+    let mut start = 0;
+    for (i, (index, _)) in subject.grapheme_indices(true).enumerate() {
+        if i > 0 && i % size == 0 {
+            chunks.push(&subject[start..index]);
+            start = index;
+        }
+    }
+    chunks.push(&subject[start..]);
+    chunks
+}
+
+/// Splits `subject` at the first occurrence of `separator` into the part before it, the separator itself and the part after it, like Python's `str.partition`.
+/// The occurrences which break a grapheme are skipped. If `separator` is not found, returns `subject` and two empty strings.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split.
+/// * `separator` - The string to split at.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::partition("key=value=more", "=");
+/// // => ("key", "=", "value=more")
+/// split::partition("cafe\u{0301} and tea", "e");
+/// // => ("cafe\u{0301} and t", "e", "a")
+/// split::partition("no separator", "=");
+/// // => ("no separator", "", "")
+/// use voca_rs::Voca;
+/// "key=value=more"._partition("=");
+/// // => ("key", "=", "value=more")
+/// ```
+pub fn partition<'a>(subject: &'a str, separator: &str) -> (&'a str, &'a str, &'a str) {
+    let found = if separator.is_empty() {
+        None
+    } else {
+        subject
+            .match_indices(separator)
+            .find(|&(index, _)| is_grapheme_match(subject, index, separator.len()))
+    };
+    match found {
+        Some((index, _)) => split_around(subject, index, separator.len()),
+        None => (subject, "", ""),
+    }
+}
+
+/// Splits `subject` at the last occurrence of `separator` into the part before it, the separator itself and the part after it, like Python's `str.rpartition`.
+/// The occurrences which break a grapheme are skipped. If `separator` is not found, returns two empty strings and `subject`.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split.
+/// * `separator` - The string to split at.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::rpartition("archive.tar.gz", ".");
+/// // => ("archive.tar", ".", "gz")
+/// split::rpartition("no separator", "=");
+/// // => ("", "", "no separator")
+/// use voca_rs::Voca;
+/// "archive.tar.gz"._rpartition(".");
+/// // => ("archive.tar", ".", "gz")
+/// ```
+pub fn rpartition<'a>(subject: &'a str, separator: &str) -> (&'a str, &'a str, &'a str) {
+    let found = if separator.is_empty() {
+        None
+    } else {
+        subject
+            .rmatch_indices(separator)
+            .find(|&(index, _)| is_grapheme_match(subject, index, separator.len()))
+    };
+    match found {
+        Some((index, _)) => split_around(subject, index, separator.len()),
+        None => ("", "", subject),
+    }
+}
+
+fn split_around(subject: &str, index: usize, length: usize) -> (&str, &str, &str) {
+    (
+        &subject[..index],
+        &subject[index..index + length],
+        &subject[index + length..],
+    )
+}
+
+fn is_grapheme_match(subject: &str, index: usize, length: usize) -> bool {
+    is_grapheme_boundary(subject, index) && is_grapheme_boundary(subject, index + length)
+}
+
+fn is_grapheme_boundary(subject: &str, index: usize) -> bool {
+    unicode_segmentation::GraphemeCursor::new(index, subject.len(), true)
+        .is_boundary(subject, 0)
+        .unwrap_or(false)
+}
+
+/// Splits `subject` into two parts before the grapheme at `position`. If `position` is out of bounds, the second part is empty.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split.
+/// * `position` - The grapheme index to split at.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::split_at_grapheme("a̐éö̲", 1);
+/// // => ("a̐", "éö̲")
+/// split::split_at_grapheme("rain", 10);
+/// // => ("rain", "")
+/// use voca_rs::Voca;
+/// "a̐éö̲"._split_at_grapheme(1);
+/// // => ("a̐", "éö̲")
+/// ```
+pub fn split_at_grapheme(subject: &str, position: usize) -> (&str, &str) {
+    let index = subject
+        .grapheme_indices(true)
+        .nth(position)
+        .map_or(subject.len(), |(index, _)| index);
+    subject.split_at(index)
+}
+
+/// Splits `subject` into two parts, the first one being the longest prefix which fits into the display `width` (see `count::display_width`).
+/// The graphemes are never broken.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split.
+/// * `width` - The maximum display width of the first part.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::split_at_width("日本語テキスト", 5);
+/// // => ("日本", "語テキスト")
+/// split::split_at_width("Hello world", 5);
+/// // => ("Hello", " world")
+/// use voca_rs::Voca;
+/// "日本語テキスト"._split_at_width(5);
+/// // => ("日本", "語テキスト")
+/// ```
+pub fn split_at_width(subject: &str, width: usize) -> (&str, &str) {
+    let mut used = 0;
+    for (index, grapheme) in subject.grapheme_indices(true) {
+        used += crate::count::grapheme_width(grapheme);
+        if used > width {
+            return subject.split_at(index);
+        }
+    }
+    (subject, "")
+}
+
+/// Returns the n-grams of graphemes of `subject`. If `pad` is not empty, `subject` is padded with `n - 1` copies of `pad` on both sides,
+/// so the graphemes at the edges appear in as many n-grams as the others.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into n-grams.
+/// * `n` - The number of graphemes in an n-gram.
+/// * `pad` - The string to pad `subject` with. Default value is "", i.e. no padding.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::char_ngrams("night", 2, "");
+/// // => ["ni", "ig", "gh", "ht"]
+/// split::char_ngrams("café", 3, "$");
+/// // => ["$$c", "$ca", "caf", "afé", "fé$", "é$$"]
+/// use voca_rs::Voca;
+/// "night"._char_ngrams(2, "");
+/// // => ["ni", "ig", "gh", "ht"]
+/// ```
+pub fn char_ngrams(subject: &str, n: usize, pad: &str) -> Vec<String> {
+    if n == 0 || subject.is_empty() {
+        return vec![];
+    }
+    let padding = vec![pad; n - 1];
+    let graphemes = padding
+        .iter()
+        .cloned()
+        .chain(graphemes_iter(subject))
+        .chain(padding.iter().cloned())
+        .filter(|g| !g.is_empty())
+        .collect::<Vec<_>>();
+    graphemes.windows(n).map(|window| window.concat()).collect()
+}
+
+/// Returns the n-grams of words of `subject` (see `split::words`), the words in an n-gram are joined with a space.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into n-grams.
+/// * `n` - The number of words in an n-gram.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::word_ngrams("the quick brown fox", 2);
+/// // => ["the quick", "quick brown", "brown fox"]
+/// use voca_rs::Voca;
+/// "the quick brown fox"._word_ngrams(3);
+/// // => ["the quick brown", "quick brown fox"]
+/// ```
+pub fn word_ngrams(subject: &str, n: usize) -> Vec<String> {
+    if n == 0 {
+        return vec![];
+    }
+    words(subject)
+        .windows(n)
+        .map(|window| window.join(" "))
+        .collect()
+}
+
+/// Returns the set of the lowercased word n-grams (shingles) of `subject`, e.g. for MinHash or for the Jaccard similarity of two texts.
+/// If `subject` has fewer than `n` words, the only shingle is all of its words.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into shingles.
+/// * `n` - The number of words in a shingle.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// let a = split::shingles("A rose is a rose is a rose", 3);
+/// // => {"a rose is", "rose is a", "is a rose"}
+/// let b = split::shingles("A rose is a flower", 3);
+/// // => {"a rose is", "rose is a", "is a flower"}
+/// a.intersection(&b).count() as f64 / a.union(&b).count() as f64;
+/// // => 0.5
+/// use voca_rs::Voca;
+/// "A rose is a rose"._shingles(4).len();
+/// // => 2
+/// ```
+pub fn shingles(subject: &str, n: usize) -> std::collections::HashSet<String> {
+    let words = words(subject);
+    if n == 0 || words.is_empty() {
+        return std::collections::HashSet::new();
+    }
+    words
+        .windows(n.min(words.len()))
+        .map(|window| window.join(" ").to_lowercase())
+        .collect()
+}
+
+/// Returns the units (characters, graphemes or words) of `subject` for which `predicate` returns true.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into units.
+/// * `unit` - The unit to iterate: `Unit::Char`, `Unit::Grapheme` or `Unit::Word`.
+/// * `predicate` - The predicate function or closure invoked on each unit with a parameter `(string)`.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// use voca_rs::split::Unit;
+/// split::filter_where("a1b2c3", Unit::Char, query::is_digit);
+/// // => ["1", "2", "3"]
+/// let stop_words = ["the", "a"];
+/// split::filter_where("the cat saw a dog", Unit::Word, |w| !stop_words.contains(&w));
+/// // => ["cat", "saw", "dog"]
+/// use voca_rs::Voca;
+/// "a1b2c3"._filter_where(Unit::Char, &query::is_digit);
+/// // => ["1", "2", "3"]
+/// ```
+pub fn filter_where(subject: &str, unit: Unit, predicate: impl Fn(&str) -> bool) -> Vec<&str> {
+    units(subject, unit)
+        .into_iter()
+        .filter(|u| predicate(u))
+        .collect()
 }
 
 /// Returns an array of Unicode code point values from characters of `subject`.
@@ -176,3 +1144,448 @@ pub fn code_points(subject: &str) -> Vec<u16> {
     }
     stfu8::decode_u16(subject).unwrap()
 }
+
+/// The line terminator, see `split::lines` and `manipulate::normalize_line_endings`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Line feed `"\n"`, used on Unix.
+    Lf,
+    /// Carriage return and line feed `"\r\n"`, used on Windows.
+    CrLf,
+    /// Carriage return `"\r"`, used on classic Mac OS.
+    Cr,
+}
+
+impl LineEnding {
+    /// Returns the terminator as a string.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Cr => "\r",
+        }
+    }
+}
+
+/// Splits `subject` into an array of lines. The lines can be terminated by `"\n"`, `"\r\n"` or `"\r"`.
+/// A terminator at the end of `subject` does not start a new line.
+///
+/// # Arguments
+///
+/// * `subject` - The string to split into lines.
+/// * `keep_terminators` - Whether to keep the line terminators at the end of the lines.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::lines("one\r\ntwo\nthree", false);
+/// // => ["one", "two", "three"]
+/// split::lines("one\r\ntwo\n\nthree\n", true);
+/// // => ["one\r\n", "two\n", "\n", "three\n"]
+/// use voca_rs::Voca;
+/// "one\r\ntwo\nthree"._lines(false);
+/// // => ["one", "two", "three"]
+/// ```
+pub fn lines(subject: &str, keep_terminators: bool) -> Vec<&str> {
+    let mut lines = vec![];
+    let mut start = 0;
+    for (index, ending) in line_endings(subject) {
+        let end = index + ending.as_str().len();
+        lines.push(&subject[start..if keep_terminators { end } else { index }]);
+        start = end;
+    }
+    if start < subject.len() {
+        lines.push(&subject[start..]);
+    }
+    lines
+}
+
+/// Returns the byte indexes and the kinds of the line terminators in `subject`.
+pub(crate) fn line_endings(subject: &str) -> Vec<(usize, LineEnding)> {
+    let bytes = subject.as_bytes();
+    let mut endings = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\n' => endings.push((index, LineEnding::Lf)),
+            b'\r' if bytes.get(index + 1) == Some(&b'\n') => {
+                endings.push((index, LineEnding::CrLf));
+                index += 1;
+            }
+            b'\r' => endings.push((index, LineEnding::Cr)),
+            _ => {}
+        }
+        index += 1;
+    }
+    endings
+}
+
+/// Splits a line returned by `split::lines` into its content and its terminator.
+pub(crate) fn line_terminator(line: &str) -> (&str, &str) {
+    let content = line.trim_end_matches(['\n', '\r']);
+    (content, &line[content.len()..])
+}
+
+/// Returns the line break opportunities in `subject` according to the Unicode Line Breaking Algorithm (UAX #14).
+/// Each opportunity is a pair of the byte index where the next line may start and whether the break is mandatory (after a newline).
+/// The break at the end of `subject` is not included.
+/// NOTE: The line breaking classes are derived from the character ranges rather than from the full `LineBreak.txt` table:
+/// South East Asian scripts are treated as alphabetic, so Thai or Khmer words are not broken without spaces.
+///
+/// # Arguments
+///
+/// * `subject` - The string to find the line breaks in.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// split::line_breaks("Hello world");
+/// // => [(6, false)]
+/// split::line_breaks("one-two\nthree");
+/// // => [(4, false), (8, true)]
+/// split::line_breaks("日本語。");
+/// // => [(3, false), (6, false)]
+/// use voca_rs::Voca;
+/// "Hello world"._line_breaks();
+/// // => [(6, false)]
+/// ```
+pub fn line_breaks(subject: &str) -> Vec<(usize, bool)> {
+    use self::BreakClass::*;
+    let mut breaks = vec![];
+    let mut chars = subject.char_indices();
+    let first = match chars.next() {
+        Some((_, c)) => c,
+        None => return breaks,
+    };
+    // the class before the current position with the combining marks resolved (LB9, LB10)
+    let mut before = resolve_combining(break_class(first));
+    // the class before the spaces and whether there were spaces (LB8, LB14 - LB17)
+    let mut before_spaces = before;
+    let mut previous_char = first;
+    let mut regional_indicators = usize::from(before == Ri);
+    for (index, c) in chars {
+        let class = break_class(c);
+        // LB9: the combining marks take the class of their base
+        let is_attached =
+            matches!(class, Cm | Zwj) && !matches!(before, Bk | Cr | Lf | Nl | Sp | Zw);
+        let after = if is_attached {
+            before
+        } else {
+            resolve_combining(class)
+        };
+        let decision = if is_attached {
+            None
+        } else {
+            break_between(
+                before,
+                before_spaces,
+                previous_char,
+                after,
+                c,
+                regional_indicators,
+            )
+        };
+        if let Some(mandatory) = decision {
+            breaks.push((index, mandatory));
+        }
+        if after == Ri && before == Ri {
+            regional_indicators += 1;
+        } else {
+            regional_indicators = usize::from(after == Ri);
+        }
+        if after != Sp {
+            before_spaces = after;
+        }
+        before = after;
+        previous_char = c;
+    }
+    breaks
+}
+
+/// Decides whether there is a line break opportunity between `before` and `after`.
+/// Returns `Some(true)` for the mandatory break, `Some(false)` for the break opportunity and `None` otherwise.
+fn break_between(
+    before: BreakClass,
+    before_spaces: BreakClass,
+    previous_char: char,
+    after: BreakClass,
+    after_char: char,
+    regional_indicators: usize,
+) -> Option<bool> {
+    use self::BreakClass::*;
+    let allowed = match (before, after) {
+        // LB4, LB5: always break after the hard line breaks
+        (Cr, Lf) => false,
+        (Bk, _) | (Cr, _) | (Lf, _) | (Nl, _) => return Some(true),
+        // LB6, LB7: do not break before the hard line breaks, spaces and zero width space
+        (_, Bk) | (_, Cr) | (_, Lf) | (_, Nl) | (_, Sp) | (_, Zw) => false,
+        // LB8: break after zero width space
+        _ if before_spaces == Zw => true,
+        // LB8a: do not break after zero width joiner
+        _ if previous_char == '\u{200D}' => false,
+        // LB11 - LB13: word joiner, non-breaking glue and closing punctuation
+        (Wj, _) | (_, Wj) | (Gl, _) => false,
+        (Sp, Gl) | (Ba, Gl) | (Hy, Gl) => true,
+        (_, Gl) | (_, Cl) | (_, Cp) | (_, Ex) | (_, Is) | (_, Sy) => false,
+        // LB14 - LB17: do not break after opening punctuation and in some quotation and dash sequences
+        _ if before_spaces == Op => false,
+        (_, Op) if before_spaces == Qu => false,
+        (_, Ns) if before_spaces == Cl || before_spaces == Cp => false,
+        (_, B2) if before_spaces == B2 => false,
+        // LB18: break after spaces
+        (Sp, _) => true,
+        // LB19: do not break around quotation marks
+        (Qu, _) | (_, Qu) => false,
+        // LB21, LB22: do not break before hyphens, small kana and ellipsis, or after the "break before" characters
+        (_, Ba) | (_, Hy) | (_, Ns) | (_, In) | (Bb, _) => false,
+        // LB23 - LB25: numbers with their prefixes and postfixes
+        (Al, Nu) | (Nu, Al) => false,
+        (Pr, Id) | (Pr, Eb) | (Pr, Em) | (Id, Po) | (Eb, Po) | (Em, Po) => false,
+        (Pr, Al) | (Po, Al) | (Al, Pr) | (Al, Po) => false,
+        (Cl, Po) | (Cp, Po) | (Cl, Pr) | (Cp, Pr) | (Nu, Po) | (Nu, Pr) => false,
+        (Po, Op) | (Po, Nu) | (Pr, Op) | (Pr, Nu) | (Hy, Nu) | (Is, Nu) | (Nu, Nu) | (Sy, Nu) => {
+            false
+        }
+        // LB26, LB27: Korean syllable blocks
+        (Jl, Jl) | (Jl, Jv) | (Jl, H2) | (Jl, H3) => false,
+        (Jv, Jv) | (Jv, Jt) | (H2, Jv) | (H2, Jt) | (Jt, Jt) | (H3, Jt) => false,
+        (Jl, Po) | (Jv, Po) | (Jt, Po) | (H2, Po) | (H3, Po) => false,
+        (Pr, Jl) | (Pr, Jv) | (Pr, Jt) | (Pr, H2) | (Pr, H3) => false,
+        // LB28 - LB30: letters, numbers and narrow parentheses
+        (Al, Al) | (Is, Al) => false,
+        (Al, Op) | (Nu, Op) => crate::count::char_width(after_char) == 2,
+        (Cp, Al) | (Cp, Nu) => crate::count::char_width(previous_char) == 2,
+        // LB30a: break between the pairs of regional indicators (flags)
+        (Ri, Ri) => regional_indicators % 2 == 0,
+        // LB30b: do not break between emoji base and emoji modifier
+        (Eb, Em) | (Id, Em) => false,
+        // LB31: break everywhere else
+        _ => true,
+    };
+    if allowed {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// The line breaking classes of UAX #14 used by `split::line_breaks`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BreakClass {
+    Bk,
+    Cr,
+    Lf,
+    Nl,
+    Sp,
+    Zw,
+    Zwj,
+    Cm,
+    Wj,
+    Gl,
+    Ba,
+    Bb,
+    Hy,
+    B2,
+    Cl,
+    Cp,
+    Ex,
+    In,
+    Ns,
+    Op,
+    Qu,
+    Is,
+    Nu,
+    Po,
+    Pr,
+    Sy,
+    Al,
+    Id,
+    Eb,
+    Em,
+    Ri,
+    H2,
+    H3,
+    Jl,
+    Jv,
+    Jt,
+}
+
+/// LB10: the combining marks without a base are treated as alphabetic.
+fn resolve_combining(class: BreakClass) -> BreakClass {
+    match class {
+        BreakClass::Cm | BreakClass::Zwj => BreakClass::Al,
+        _ => class,
+    }
+}
+
+fn break_class(c: char) -> BreakClass {
+    use self::BreakClass::*;
+    let code = c as u32;
+    match c {
+        '\n' => Lf,
+        '\r' => Cr,
+        '\u{85}' => Nl,
+        '\u{0B}' | '\u{0C}' | '\u{2028}' | '\u{2029}' => Bk,
+        ' ' => Sp,
+        '\u{200B}' => Zw,
+        '\u{200D}' => Zwj,
+        '\u{2060}' | '\u{FEFF}' => Wj,
+        '\u{A0}' | '\u{202F}' | '\u{2007}' | '\u{2011}' | '\u{180E}' | '\u{0F0C}' => Gl,
+        '\t'
+        | '\u{AD}'
+        | '\u{58A}'
+        | '\u{1680}'
+        | '\u{2000}'..='\u{2006}'
+        | '\u{2008}'..='\u{200A}'
+        | '\u{2010}'
+        | '\u{2012}'
+        | '\u{2013}'
+        | '\u{205F}'
+        | '\u{3000}'
+        | '|' => Ba,
+        '\u{B4}' | '\u{2C8}' | '\u{2CC}' | '\u{2DF}' => Bb,
+        '-' => Hy,
+        '\u{2014}' => B2,
+        '}' | '\u{3001}' | '\u{3002}' | '\u{3009}' | '\u{300B}' | '\u{300D}' | '\u{300F}'
+        | '\u{3011}' | '\u{3015}' | '\u{3017}' | '\u{3019}' | '\u{301B}' | '\u{FE50}'
+        | '\u{FE52}' | '\u{FF0C}' | '\u{FF0E}' | '\u{FF5D}' | '\u{FF61}' | '\u{FF63}'
+        | '\u{FF64}' => Cl,
+        ')' | ']' | '\u{FF09}' | '\u{FF3D}' => Cp,
+        '(' | '[' | '{' | '\u{A1}' | '\u{BF}' | '\u{3008}' | '\u{300A}' | '\u{300C}'
+        | '\u{300E}' | '\u{3010}' | '\u{3014}' | '\u{3016}' | '\u{3018}' | '\u{301A}'
+        | '\u{FF08}' | '\u{FF3B}' | '\u{FF5B}' | '\u{FF62}' => Op,
+        '"' | '\'' | '\u{AB}' | '\u{BB}' | '\u{2018}' | '\u{2019}' | '\u{201B}' | '\u{201C}'
+        | '\u{201D}' | '\u{201F}' | '\u{2039}' | '\u{203A}' => Qu,
+        '!' | '?' | '\u{FF01}' | '\u{FF1F}' => Ex,
+        ',' | '.' | ':' | ';' | '\u{37E}' | '\u{589}' | '\u{60C}' | '\u{60D}' => Is,
+        '/' => Sy,
+        '\u{2024}' | '\u{2025}' | '\u{2026}' | '\u{FE19}' => In,
+        '\u{203C}'
+        | '\u{2047}'..='\u{2049}'
+        | '\u{3005}'
+        | '\u{301C}'
+        | '\u{303B}'
+        | '\u{3041}'
+        | '\u{3043}'
+        | '\u{3045}'
+        | '\u{3047}'
+        | '\u{3049}'
+        | '\u{3063}'
+        | '\u{3083}'
+        | '\u{3085}'
+        | '\u{3087}'
+        | '\u{308E}'
+        | '\u{3095}'
+        | '\u{3096}'
+        | '\u{309B}'..='\u{309E}'
+        | '\u{30A0}'
+        | '\u{30A1}'
+        | '\u{30A3}'
+        | '\u{30A5}'
+        | '\u{30A7}'
+        | '\u{30A9}'
+        | '\u{30C3}'
+        | '\u{30E3}'
+        | '\u{30E5}'
+        | '\u{30E7}'
+        | '\u{30EE}'
+        | '\u{30F5}'
+        | '\u{30F6}'
+        | '\u{30FB}'..='\u{30FE}'
+        | '\u{31F0}'..='\u{31FF}'
+        | '\u{FF1A}'
+        | '\u{FF1B}'
+        | '\u{FF65}'
+        | '\u{FF67}'..='\u{FF70}'
+        | '\u{FF9E}'
+        | '\u{FF9F}' => Ns,
+        '0'..='9'
+        | '\u{660}'..='\u{669}'
+        | '\u{6F0}'..='\u{6F9}'
+        | '\u{966}'..='\u{96F}'
+        | '\u{9E6}'..='\u{9EF}'
+        | '\u{E50}'..='\u{E59}' => Nu,
+        '%'
+        | '\u{A2}'
+        | '\u{B0}'
+        | '\u{2030}'..='\u{2037}'
+        | '\u{2103}'
+        | '\u{2109}'
+        | '\u{FF05}'
+        | '\u{FFE0}' => Po,
+        '$'
+        | '+'
+        | '\\'
+        | '\u{A3}'
+        | '\u{A5}'
+        | '\u{B1}'
+        | '\u{2116}'
+        | '\u{2212}'
+        | '\u{20A0}'..='\u{20CF}'
+        | '\u{FF04}'
+        | '\u{FFE1}'
+        | '\u{FFE5}'
+        | '\u{FFE6}' => Pr,
+        '\u{1F1E6}'..='\u{1F1FF}' => Ri,
+        '\u{1F3FB}'..='\u{1F3FF}' => Em,
+        '\u{261D}'
+        | '\u{26F9}'
+        | '\u{270A}'..='\u{270D}'
+        | '\u{1F385}'
+        | '\u{1F3C2}'..='\u{1F3C4}'
+        | '\u{1F3C7}'
+        | '\u{1F3CA}'..='\u{1F3CC}'
+        | '\u{1F442}'
+        | '\u{1F443}'
+        | '\u{1F446}'..='\u{1F450}'
+        | '\u{1F466}'..='\u{1F478}'
+        | '\u{1F47C}'
+        | '\u{1F481}'..='\u{1F483}'
+        | '\u{1F485}'..='\u{1F487}'
+        | '\u{1F4AA}'
+        | '\u{1F574}'
+        | '\u{1F575}'
+        | '\u{1F57A}'
+        | '\u{1F590}'
+        | '\u{1F595}'
+        | '\u{1F596}'
+        | '\u{1F645}'..='\u{1F647}'
+        | '\u{1F64B}'..='\u{1F64F}'
+        | '\u{1F6A3}'
+        | '\u{1F6B4}'..='\u{1F6B6}'
+        | '\u{1F6C0}'
+        | '\u{1F6CC}'
+        | '\u{1F90C}'
+        | '\u{1F90F}'
+        | '\u{1F918}'..='\u{1F91F}'
+        | '\u{1F926}'
+        | '\u{1F930}'..='\u{1F939}'
+        | '\u{1F93C}'..='\u{1F93E}'
+        | '\u{1F977}'
+        | '\u{1F9B5}'
+        | '\u{1F9B6}'
+        | '\u{1F9B8}'
+        | '\u{1F9B9}'
+        | '\u{1F9BB}'
+        | '\u{1F9CD}'..='\u{1F9CF}'
+        | '\u{1F9D1}'..='\u{1F9DD}'
+        | '\u{1FAC3}'..='\u{1FAC5}'
+        | '\u{1FAF0}'..='\u{1FAF8}' => Eb,
+        '\u{1100}'..='\u{115F}' | '\u{A960}'..='\u{A97C}' => Jl,
+        '\u{1160}'..='\u{11A7}' | '\u{D7B0}'..='\u{D7C6}' => Jv,
+        '\u{11A8}'..='\u{11FF}' | '\u{D7CB}'..='\u{D7FB}' => Jt,
+        '\u{AC00}'..='\u{D7A3}' if (code - 0xAC00) % 28 == 0 => H2,
+        '\u{AC00}'..='\u{D7A3}' => H3,
+        _ if crate::utils::is_combining_mark(c)
+            || (c.is_control() && c != '\u{85}')
+            || ('\u{FE00}'..='\u{FE0F}').contains(&c)
+            || ('\u{E0100}'..='\u{E01EF}').contains(&c) =>
+        {
+            Cm
+        }
+        _ if crate::count::char_width(c) == 2 || ('\u{1F000}'..='\u{1FAFF}').contains(&c) => Id,
+        _ => Al,
+    }
+}
@@ -56,6 +56,106 @@ pub fn strip_tags(subject: &str) -> String {
     }
 }
 
+/// Strips the trailing whitespace from every line of `subject`. The line terminators are kept.
+///
+/// # Arguments
+///
+/// * `subject` - The string to strip from.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// strip::strip_trailing_whitespace("summer  \r\nis nice\t\n");
+/// // => "summer\r\nis nice\n"
+/// use voca_rs::Voca;
+/// "summer  \nis nice\t"._strip_trailing_whitespace();
+/// // => "summer\nis nice"
+/// ```
+pub fn strip_trailing_whitespace(subject: &str) -> String {
+    crate::split::lines(subject, true)
+        .iter()
+        .map(|line| {
+            let (content, terminator) = crate::split::line_terminator(line);
+            format!("{}{}", content.trim_end(), terminator)
+        })
+        .collect()
+}
+
+/// Strips the Unicode bidirectional formatting characters from `subject` (see `query::has_bidi_controls`).
+///
+/// # Arguments
+///
+/// * `subject` - The string to strip from.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// strip::strip_bidi_controls("access\u{202E}level");
+/// // => "accesslevel"
+/// strip::strip_bidi_controls("\u{2067}שלום\u{2069} world");
+/// // => "שלום world"
+/// use voca_rs::Voca;
+/// "access\u{202E}level"._strip_bidi_controls();
+/// // => "accesslevel"
+/// ```
+pub fn strip_bidi_controls(subject: &str) -> String {
+    subject
+        .chars()
+        .filter(|&c| !crate::query::is_bidi_control(c))
+        .collect()
+}
+
+/// Strips the emoji from `subject` (see `query::is_emoji`).
+///
+/// # Arguments
+///
+/// * `subject` - The string to strip from.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// strip::strip_emoji("Great job 👍🏽🎉!");
+/// // => "Great job !"
+/// strip::strip_emoji("#1 ©");
+/// // => "#1 ©"
+/// use voca_rs::Voca;
+/// "I ❤️ Rust"._strip_emoji();
+/// // => "I  Rust"
+/// ```
+pub fn strip_emoji(subject: &str) -> String {
+    crate::split::graphemes_iter(subject)
+        .filter(|grapheme| !crate::query::is_emoji_grapheme(grapheme))
+        .collect()
+}
+
+/// Strips the emoji skin tone modifiers (U+1F3FB..U+1F3FF) from `subject`, so the emoji are displayed in the default yellow tone.
+///
+/// # Arguments
+///
+/// * `subject` - The string to strip from.
+///
+/// # Example
+///
+/// ```
+/// use voca_rs::*;
+/// strip::strip_skin_tones("👍🏽 👋🏿");
+/// // => "👍 👋"
+/// strip::strip_skin_tones("👩🏻‍💻");
+/// // => "👩‍💻"
+/// use voca_rs::Voca;
+/// "👍🏽"._strip_skin_tones();
+/// // => "👍"
+/// ```
+pub fn strip_skin_tones(subject: &str) -> String {
+    subject
+        .chars()
+        .filter(|c| !matches!(c, '\u{1F3FB}'..='\u{1F3FF}'))
+        .collect()
+}
+
 #[derive(Clone, Copy, PartialEq)]
 enum StateMode {
     Output,
@@ -65,9 +165,9 @@ enum StateMode {
 }
 
 fn unicode_string_range(subject: &str, start: usize, end: usize) -> String {
-    graphemes(subject)[start..end]
-        .iter()
-        .map(|c| (*c).to_string())
+    crate::split::graphemes_iter(subject)
+        .skip(start)
+        .take(end - start)
         .collect::<String>()
 }
 
@@ -5,12 +5,18 @@ extern crate voca_rs;
 mod unit {
     mod case;
     mod chop;
+    mod compare;
     mod count;
+    mod distance;
     mod escape;
     mod index;
     mod manipulate;
+    mod parse;
+    mod phonetic;
     mod query;
+    mod readability;
     mod readme;
+    mod security;
     mod split;
     mod strip;
     mod utils;
@@ -232,6 +232,34 @@ fn _prune() {
     assert_eq!("Once upon a time"._prune(7, ""), "Once...");
 }
 #[test]
+fn prune_width() {
+    assert_eq!(voca_rs::chop::prune_width("", 5, ""), "");
+    assert_eq!(voca_rs::chop::prune_width("Once upon", 10, ""), "Once upon");
+    assert_eq!(voca_rs::chop::prune_width("Once upon", 9, ""), "Once upon");
+    assert_eq!(
+        voca_rs::chop::prune_width("Once upon a time", 7, ""),
+        "Once..."
+    );
+    assert_eq!(voca_rs::chop::prune_width("Once upon a time", 3, ""), "...");
+    assert_eq!(
+        voca_rs::chop::prune_width("東京 大阪 京都", 10, "…"),
+        "東京 大阪…"
+    );
+    assert_eq!(
+        voca_rs::chop::prune_width("東京 大阪 京都", 9, "…"),
+        "東京…"
+    );
+    assert_eq!(
+        voca_rs::chop::prune_width("Привет, мир!", 10, ""),
+        "Привет..."
+    );
+    assert_eq!(voca_rs::chop::prune_width("Once upon", 2, "..."), "");
+}
+#[test]
+fn _prune_width() {
+    assert_eq!("Once upon a time"._prune_width(7, ""), "Once...");
+}
+#[test]
 fn removeprefix() {
     assert_eq!(voca_rs::chop::removeprefix("", ""), "");
     assert_eq!(
@@ -394,6 +422,36 @@ fn _truncate() {
     assert_eq!("Once upon a time"._truncate(7, ""), "Once...");
 }
 #[test]
+fn truncate_width() {
+    assert_eq!(voca_rs::chop::truncate_width("", 5, ""), "");
+    assert_eq!(
+        voca_rs::chop::truncate_width("Once upon", 10, ""),
+        "Once upon"
+    );
+    assert_eq!(
+        voca_rs::chop::truncate_width("Once upon a time", 7, ""),
+        "Once..."
+    );
+    assert_eq!(
+        voca_rs::chop::truncate_width("日本語のテキスト", 9, "…"),
+        "日本語の…"
+    );
+    assert_eq!(
+        voca_rs::chop::truncate_width("日本語のテキスト", 8, "…"),
+        "日本語…"
+    );
+    assert_eq!(
+        voca_rs::chop::truncate_width("cafe\u{0301} latte", 6, "…"),
+        "cafe\u{0301} …"
+    );
+    assert_eq!(voca_rs::chop::truncate_width("👍🏽👍🏽👍🏽", 5, "."), "👍🏽👍🏽.");
+    assert_eq!(voca_rs::chop::truncate_width("abcdef", 2, "..."), "ab");
+}
+#[test]
+fn _truncate_width() {
+    assert_eq!("Once upon a time"._truncate_width(7, ""), "Once...");
+}
+#[test]
 fn limit_words() {
     assert_eq!(voca_rs::chop::limit_words("", 0, ""), "");
     assert_eq!(voca_rs::chop::limit_words("a", 0, ""), "");
@@ -446,3 +504,10 @@ fn min() {
 fn _min() {
     assert_eq!("rain"._min_code_point(), "a");
 }
+#[test]
+fn first_of_long_string() {
+    let long = "rain ".repeat(1_000_000);
+    assert_eq!(voca_rs::chop::first(&long, 3), "rai");
+    assert_eq!(voca_rs::chop::char_at(&long, 2), "i");
+    assert_eq!(voca_rs::chop::grapheme_at(&long, 3), "n");
+}
@@ -0,0 +1,120 @@
+//! voca_rs::compare testing
+use std::cmp::Ordering;
+use voca_rs::Voca;
+
+#[test]
+fn natural() {
+    assert_eq!(voca_rs::compare::natural("", ""), Ordering::Equal);
+    assert_eq!(voca_rs::compare::natural("", "a"), Ordering::Less);
+    assert_eq!(voca_rs::compare::natural("file9", "file10"), Ordering::Less);
+    assert_eq!(
+        voca_rs::compare::natural("file10", "file9"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        voca_rs::compare::natural("file10", "file10"),
+        Ordering::Equal
+    );
+    assert_eq!(voca_rs::compare::natural("file1", "file01"), Ordering::Less);
+    assert_eq!(voca_rs::compare::natural("file01", "file2"), Ordering::Less);
+    assert_eq!(
+        voca_rs::compare::natural("v1.10.0", "v1.9.2"),
+        Ordering::Greater
+    );
+    assert_eq!(voca_rs::compare::natural("a2b", "a2c"), Ordering::Less);
+    assert_eq!(
+        voca_rs::compare::natural("99999999999999999999999", "100000000000000000000000"),
+        Ordering::Less
+    );
+    let mut files = vec!["file10.txt", "file2.txt", "File1.txt", "file1.txt"];
+    files.sort_by(|a, b| voca_rs::compare::natural(a, b));
+    assert_eq!(files, ["File1.txt", "file1.txt", "file2.txt", "file10.txt"]);
+}
+#[test]
+fn _natural() {
+    assert_eq!("file9"._compare_natural("file10"), Ordering::Less);
+}
+#[test]
+fn case_insensitive() {
+    assert_eq!(voca_rs::compare::case_insensitive("", ""), Ordering::Equal);
+    assert_eq!(
+        voca_rs::compare::case_insensitive("Apple", "apple"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        voca_rs::compare::case_insensitive("STRASSE", "straße"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        voca_rs::compare::case_insensitive("apple", "Banana"),
+        Ordering::Less
+    );
+    assert_eq!(
+        voca_rs::compare::case_insensitive("ЁЛКА", "ёлка"),
+        Ordering::Equal
+    );
+}
+#[test]
+fn _case_insensitive() {
+    assert_eq!("Apple"._compare_case_insensitive("apple"), Ordering::Equal);
+}
+#[test]
+fn ignore_diacritics() {
+    assert_eq!(
+        voca_rs::compare::ignore_diacritics("café", "cafe"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        voca_rs::compare::ignore_diacritics("Łódź", "Lodz"),
+        Ordering::Equal
+    );
+    assert_eq!(
+        voca_rs::compare::ignore_diacritics("äpfel", "birnen"),
+        Ordering::Less
+    );
+    assert_eq!(
+        voca_rs::compare::ignore_diacritics("Café", "cafe"),
+        Ordering::Less
+    );
+}
+#[test]
+fn _ignore_diacritics() {
+    assert_eq!("café"._compare_ignore_diacritics("cafe"), Ordering::Equal);
+}
+#[test]
+fn collate() {
+    assert_eq!(voca_rs::compare::collate("", ""), Ordering::Equal);
+    assert_eq!(voca_rs::compare::collate("cote", "côte"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("côte", "coter"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("apple", "Apple"), Ordering::Less);
+    assert_eq!(
+        voca_rs::compare::collate("Zebra", "apple"),
+        Ordering::Greater
+    );
+    assert_eq!(voca_rs::compare::collate("co-op", "coop"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("co-op", "cop"), Ordering::Less);
+    assert_eq!(
+        voca_rs::compare::collate("ｃａｔ", "cat"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        voca_rs::compare::collate("e\u{0301}clair", "éclair"),
+        Ordering::Equal
+    );
+    assert_eq!(voca_rs::compare::collate("9", "a"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("z", "α"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("ω", "а"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("一", "丁"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("龜", "㐀"), Ordering::Less);
+    assert_eq!(voca_rs::compare::collate("㐀", "\u{0378}"), Ordering::Less);
+    let mut words = vec!["Zebra", "éclair", "apple", "Eclair", "ähnlich", "birne"];
+    words.sort_by(|a, b| voca_rs::compare::collate(a, b));
+    assert_eq!(
+        words,
+        ["ähnlich", "apple", "birne", "Eclair", "éclair", "Zebra"]
+    );
+}
+#[test]
+fn _collate() {
+    assert_eq!("cote"._compare_collate("côte"), Ordering::Less);
+}
@@ -1,4 +1,5 @@
 //! voca_rs::count testing
+use voca_rs::split::Unit;
 use voca_rs::Voca;
 
 #[test]
@@ -66,6 +67,40 @@ fn substrings() {
 fn _substrings() {
     assert_eq!("******"._count_substrings("*"), 6);
 }
+#[test]
+fn substrings_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!(voca_rs::count::count_substrings_folded("", "", &options), 0);
+    assert_eq!(
+        voca_rs::count::count_substrings_folded("abc", "", &options),
+        0
+    );
+    assert_eq!(
+        voca_rs::count::count_substrings_folded("Straße, STRASSE, strasse", "strasse", &options),
+        3
+    );
+    assert_eq!(
+        voca_rs::count::count_substrings_folded(
+            "Cafe\u{0301} del Mar Café del Mar CAFE",
+            "café",
+            &options
+        ),
+        3
+    );
+    assert_eq!(
+        voca_rs::count::count_substrings_folded(
+            "Cafe\u{0301} del Mar Café del Mar CAFE",
+            "Café",
+            &voca_rs::utils::MatchOptions::default()
+        ),
+        1
+    );
+}
+#[test]
+fn _substrings_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!("Boys, BOYS"._count_substrings_folded("boys", &options), 2);
+}
 
 #[test]
 fn count_where() {
@@ -111,6 +146,88 @@ fn count_where() {
 fn _count_where() {
     assert_eq!("hola!"._count_where(voca_rs::query::is_alpha), 4);
 }
+#[test]
+fn count_units_where() {
+    assert_eq!(
+        voca_rs::count::count_units_where("hola!", Unit::Grapheme, voca_rs::query::is_alpha),
+        4
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("2022", Unit::Grapheme, |s: &str| -> bool { s == "2" }),
+        3
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("", Unit::Grapheme, voca_rs::query::is_alpha),
+        0
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("abc", Unit::Grapheme, voca_rs::query::is_alpha),
+        3
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("africa654", Unit::Grapheme, voca_rs::query::is_alpha),
+        6
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("790", Unit::Grapheme, voca_rs::query::is_alpha),
+        0
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("790", Unit::Grapheme, voca_rs::query::is_alphadigit),
+        3
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where(
+            voca_rs::utils::PRINTABLE,
+            Unit::Grapheme,
+            voca_rs::query::is_digit
+        ),
+        10
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("****--**--**", Unit::Grapheme, |s: &str| -> bool {
+            s == "*"
+        }),
+        8
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("****--**--**", Unit::Grapheme, |_s: &str| -> bool {
+            false
+        }),
+        0
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("cafe\u{0301}", Unit::Char, |c| c == "e"),
+        1
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("cafe\u{0301}", Unit::Grapheme, |g| g == "e"),
+        0
+    );
+    let allowed = ["red", "green"];
+    assert_eq!(
+        voca_rs::count::count_units_where("red, blue and green", Unit::Word, |w| allowed
+            .contains(&w)),
+        2
+    );
+    assert_eq!(
+        voca_rs::count::count_units_where("", Unit::Word, |_w| true),
+        0
+    );
+}
+#[test]
+fn _count_units_where() {
+    assert_eq!(
+        "hola!"._count_units_where(Unit::Grapheme, &voca_rs::query::is_alpha),
+        4
+    );
+    let allowed = ["red", "green"];
+    let subject: &dyn Voca = &String::from("red, blue and green");
+    assert_eq!(
+        subject._count_units_where(Unit::Word, &|w| allowed.contains(&w)),
+        2
+    );
+}
 
 #[test]
 fn words() {
@@ -142,6 +259,55 @@ fn words() {
 fn _words() {
     assert_eq!("Gravity - can cross dimensions!"._count_words(""), 4);
 }
+#[test]
+fn count_words_cjk() {
+    assert_eq!(voca_rs::count::count_words_cjk(""), 0);
+    assert_eq!(voca_rs::count::count_words_cjk("Hello world"), 2);
+    assert_eq!(voca_rs::count::count_words_cjk("我爱北京"), 4);
+    assert_eq!(voca_rs::count::count_words_cjk("我爱北京。"), 4);
+    assert_eq!(
+        voca_rs::count::count_words_cjk("Rust言語でプログラミング"),
+        11
+    );
+    assert_eq!(voca_rs::count::count_words_cjk("ｶﾀｶﾅ test"), 5);
+    assert_eq!(voca_rs::count::count_words_cjk("한국어 단어"), 2);
+    assert_eq!(voca_rs::count::count_words_cjk("𠀀𠀁 ok"), 3);
+}
+#[test]
+fn _count_words_cjk() {
+    assert_eq!("我爱北京"._count_words_cjk(), 4);
+}
+#[test]
+fn reading_time() {
+    use std::time::Duration;
+    assert_eq!(
+        voca_rs::count::reading_time("", 200, 500),
+        Duration::from_secs(0)
+    );
+    assert_eq!(
+        voca_rs::count::reading_time(&"word ".repeat(400), 200, 500),
+        Duration::from_secs(120)
+    );
+    assert_eq!(
+        voca_rs::count::reading_time(&"字".repeat(1000), 200, 500),
+        Duration::from_secs(120)
+    );
+    assert_eq!(
+        voca_rs::count::reading_time("我爱北京 and Tokyo", 200, 500),
+        Duration::from_secs_f64(1.08)
+    );
+    assert_eq!(
+        voca_rs::count::reading_time("我爱北京 and Tokyo", 0, 0),
+        Duration::from_secs(0)
+    );
+}
+#[test]
+fn _reading_time() {
+    assert_eq!(
+        "word ".repeat(400)._reading_time(200, 500),
+        std::time::Duration::from_secs(120)
+    );
+}
 
 #[test]
 fn unique_words() {
@@ -186,3 +352,168 @@ fn _unique_words() {
     assert_eq!(""._count_unique_words(""), 0);
     assert_eq!("hello world wonderful world"._count_unique_words(""), 3);
 }
+#[test]
+fn display_width() {
+    assert_eq!(voca_rs::count::display_width(""), 0);
+    assert_eq!(voca_rs::count::display_width("rain"), 4);
+    assert_eq!(voca_rs::count::display_width("cafe\u{0301}"), 4);
+    assert_eq!(voca_rs::count::display_width("日本語"), 6);
+    assert_eq!(voca_rs::count::display_width("ｆｕｌｌ"), 8);
+    assert_eq!(voca_rs::count::display_width("한국어"), 6);
+    assert_eq!(voca_rs::count::display_width("\u{1100}\u{1161}\u{11A8}"), 2);
+    assert_eq!(voca_rs::count::display_width("👍"), 2);
+    assert_eq!(voca_rs::count::display_width("👍🏽 ok"), 5);
+    assert_eq!(voca_rs::count::display_width("👨‍👩‍👧"), 2);
+    assert_eq!(voca_rs::count::display_width("🇺🇦"), 2);
+    assert_eq!(voca_rs::count::display_width("❤"), 1);
+    assert_eq!(voca_rs::count::display_width("❤\u{FE0F}"), 2);
+    assert_eq!(voca_rs::count::display_width("a\u{200B}b"), 2);
+    assert_eq!(voca_rs::count::display_width("\t"), 0);
+    assert_eq!(voca_rs::count::display_width("שָׁלוֹם"), 4);
+    assert_eq!(voca_rs::count::display_width("مَرْحَبًا"), 5);
+    assert_eq!(voca_rs::count::display_width("กิน"), 2);
+    assert_eq!(voca_rs::count::display_width("नमस्ते"), 4);
+    assert_eq!(voca_rs::count::display_width("1\u{20DD}"), 1);
+}
+#[test]
+fn _display_width() {
+    assert_eq!("日本語"._display_width(), 6);
+}
+#[test]
+fn word_frequencies() {
+    use voca_rs::count::WordFrequencyOptions;
+    let default = WordFrequencyOptions::default();
+    assert!(voca_rs::count::word_frequencies("", &default).is_empty());
+    assert_eq!(
+        voca_rs::count::word_frequencies("The cat and the hat", &default),
+        [
+            ("the".to_string(), 2),
+            ("cat".to_string(), 1),
+            ("and".to_string(), 1),
+            ("hat".to_string(), 1)
+        ]
+    );
+    assert_eq!(
+        voca_rs::count::word_frequencies(
+            "The cat and the hat",
+            &WordFrequencyOptions {
+                stop_words: vec!["THE".to_string(), "and".to_string()],
+                ..Default::default()
+            }
+        ),
+        [("cat".to_string(), 1), ("hat".to_string(), 1)]
+    );
+    assert_eq!(
+        voca_rs::count::word_frequencies(
+            "a bb a ccc bb bb, 42!",
+            &WordFrequencyOptions {
+                min_length: 2,
+                ..Default::default()
+            }
+        ),
+        [
+            ("bb".to_string(), 3),
+            ("ccc".to_string(), 1),
+            ("42".to_string(), 1)
+        ]
+    );
+    assert_eq!(
+        voca_rs::count::word_frequencies(
+            "Café cafe CAFÉ",
+            &WordFrequencyOptions {
+                folding: voca_rs::utils::MatchOptions::insensitive(),
+                ..Default::default()
+            }
+        ),
+        [("cafe".to_string(), 3)]
+    );
+    assert_eq!(
+        voca_rs::count::word_frequencies(
+            "Word word",
+            &WordFrequencyOptions {
+                folding: Default::default(),
+                ..Default::default()
+            }
+        ),
+        [("Word".to_string(), 1), ("word".to_string(), 1)]
+    );
+}
+#[test]
+fn _word_frequencies() {
+    assert_eq!(
+        "b a b"._word_frequencies(&voca_rs::count::WordFrequencyOptions::default()),
+        [("b".to_string(), 2), ("a".to_string(), 1)]
+    );
+}
+#[test]
+fn char_frequencies() {
+    assert!(voca_rs::count::char_frequencies("").is_empty());
+    assert_eq!(
+        voca_rs::count::char_frequencies("hello"),
+        [("l", 2), ("h", 1), ("e", 1), ("o", 1)]
+    );
+    assert_eq!(
+        voca_rs::count::char_frequencies("cafe\u{0301} e"),
+        [
+            ("c", 1),
+            ("a", 1),
+            ("f", 1),
+            ("e\u{0301}", 1),
+            (" ", 1),
+            ("e", 1)
+        ]
+    );
+}
+#[test]
+fn _char_frequencies() {
+    assert_eq!("aab"._char_frequencies(), [("a", 2), ("b", 1)]);
+}
+#[test]
+fn text_stats() {
+    let empty = voca_rs::count::text_stats("");
+    assert_eq!(
+        empty,
+        voca_rs::count::TextStats {
+            chars: 0,
+            graphemes: 0,
+            words: 0,
+            sentences: 0,
+            lines: 0,
+            average_word_length: 0.0,
+            longest_word: String::new(),
+        }
+    );
+    assert_eq!(
+        voca_rs::count::text_stats("Quick brown fox.\nIt jumps!"),
+        voca_rs::count::TextStats {
+            chars: 26,
+            graphemes: 26,
+            words: 5,
+            sentences: 2,
+            lines: 2,
+            average_word_length: 4.0,
+            longest_word: "Quick".to_string(),
+        }
+    );
+    let stats = voca_rs::count::text_stats("Cafe\u{0301} noir");
+    assert_eq!(stats.chars, 10);
+    assert_eq!(stats.graphemes, 9);
+    assert_eq!(stats.average_word_length, 4.0);
+    assert_eq!(stats.longest_word, "Cafe\u{0301}");
+}
+#[test]
+fn _text_stats() {
+    assert_eq!("The quick brown fox."._text_stats().words, 4);
+}
+#[test]
+fn count_emoji() {
+    assert_eq!(voca_rs::count::count_emoji(""), 0);
+    assert_eq!(voca_rs::count::count_emoji("No emoji here #1 ©"), 0);
+    assert_eq!(voca_rs::count::count_emoji("Great job 👍🏽🎉!"), 2);
+    assert_eq!(voca_rs::count::count_emoji("👨‍👩‍👧 in 🇺🇦"), 2);
+    assert_eq!(voca_rs::count::count_emoji("1️⃣2️⃣3️⃣"), 3);
+}
+#[test]
+fn _count_emoji() {
+    assert_eq!("Great job 👍🏽🎉!"._count_emoji(), 2);
+}
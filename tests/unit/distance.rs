@@ -0,0 +1,287 @@
+//! voca_rs::distance testing
+use voca_rs::split::Unit;
+use voca_rs::Voca;
+
+#[test]
+fn levenshtein() {
+    assert_eq!(voca_rs::distance::levenshtein("", "", Unit::Char), 0);
+    assert_eq!(voca_rs::distance::levenshtein("", "abc", Unit::Char), 3);
+    assert_eq!(voca_rs::distance::levenshtein("abc", "", Unit::Grapheme), 3);
+    assert_eq!(
+        voca_rs::distance::levenshtein("kitten", "sitting", Unit::Char),
+        3
+    );
+    assert_eq!(
+        voca_rs::distance::levenshtein("flaw", "lawn", Unit::Char),
+        2
+    );
+    assert_eq!(
+        voca_rs::distance::levenshtein("błąd", "blad", Unit::Char),
+        2
+    );
+    assert_eq!(
+        voca_rs::distance::levenshtein("cafe\u{0301}", "café", Unit::Char),
+        2
+    );
+    assert_eq!(
+        voca_rs::distance::levenshtein("cafe\u{0301}", "cafe", Unit::Grapheme),
+        1
+    );
+    assert_eq!(
+        voca_rs::distance::levenshtein("👨‍👩‍👧", "👨‍👩‍👦", Unit::Grapheme),
+        1
+    );
+    assert_eq!(
+        voca_rs::distance::levenshtein("a̐éö̲", "a̐éö̲", Unit::Grapheme),
+        0
+    );
+}
+#[test]
+fn _levenshtein() {
+    assert_eq!("kitten"._levenshtein("sitting", Unit::Char), 3);
+}
+#[test]
+fn damerau_levenshtein() {
+    assert_eq!(
+        voca_rs::distance::damerau_levenshtein("", "", Unit::Char),
+        0
+    );
+    assert_eq!(
+        voca_rs::distance::damerau_levenshtein("", "ab", Unit::Char),
+        2
+    );
+    assert_eq!(
+        voca_rs::distance::damerau_levenshtein("teh", "the", Unit::Char),
+        1
+    );
+    assert_eq!(
+        voca_rs::distance::damerau_levenshtein("ca", "abc", Unit::Char),
+        2
+    );
+    assert_eq!(
+        voca_rs::distance::damerau_levenshtein("kitten", "sitting", Unit::Char),
+        3
+    );
+    assert_eq!(
+        voca_rs::distance::damerau_levenshtein("żółw", "żłów", Unit::Grapheme),
+        1
+    );
+}
+#[test]
+fn _damerau_levenshtein() {
+    assert_eq!("teh"._damerau_levenshtein("the", Unit::Char), 1);
+}
+#[test]
+fn hamming() {
+    assert_eq!(voca_rs::distance::hamming("", "", Unit::Char), Some(0));
+    assert_eq!(
+        voca_rs::distance::hamming("karolin", "kathrin", Unit::Char),
+        Some(3)
+    );
+    assert_eq!(
+        voca_rs::distance::hamming("łódź", "lodz", Unit::Char),
+        Some(3)
+    );
+    assert_eq!(voca_rs::distance::hamming("abc", "ab", Unit::Char), None);
+    assert_eq!(
+        voca_rs::distance::hamming("cafe\u{0301}", "cafe", Unit::Char),
+        None
+    );
+    assert_eq!(
+        voca_rs::distance::hamming("cafe\u{0301}", "cafe", Unit::Grapheme),
+        Some(1)
+    );
+}
+#[test]
+fn _hamming() {
+    assert_eq!("karolin"._hamming("kathrin", Unit::Char), Some(3));
+}
+#[test]
+fn jaro() {
+    assert_eq!(voca_rs::distance::jaro("", "", Unit::Char), 1.0);
+    assert_eq!(voca_rs::distance::jaro("", "a", Unit::Char), 0.0);
+    assert_eq!(voca_rs::distance::jaro("abc", "xyz", Unit::Char), 0.0);
+    assert_eq!(voca_rs::distance::jaro("same", "same", Unit::Char), 1.0);
+    assert!((voca_rs::distance::jaro("martha", "marhta", Unit::Char) - 0.944444).abs() < 0.0001);
+    assert!((voca_rs::distance::jaro("dwayne", "duane", Unit::Char) - 0.822222).abs() < 0.0001);
+}
+#[test]
+fn _jaro() {
+    assert!(("martha"._jaro("marhta", Unit::Char) - 0.944444).abs() < 0.0001);
+}
+#[test]
+fn jaro_winkler() {
+    assert_eq!(voca_rs::distance::jaro_winkler("", "", Unit::Char), 1.0);
+    assert!(
+        (voca_rs::distance::jaro_winkler("martha", "marhta", Unit::Char) - 0.961111).abs() < 0.0001
+    );
+    assert!(
+        (voca_rs::distance::jaro_winkler("dixon", "dicksonx", Unit::Char) - 0.813333).abs()
+            < 0.0001
+    );
+    assert!(
+        voca_rs::distance::jaro_winkler("Müller", "Mueller", Unit::Grapheme)
+            > voca_rs::distance::jaro("Müller", "Mueller", Unit::Grapheme)
+    );
+}
+#[test]
+fn _jaro_winkler() {
+    assert!(("martha"._jaro_winkler("marhta", Unit::Char) - 0.961111).abs() < 0.0001);
+}
+#[test]
+fn longest_common_subsequence() {
+    assert_eq!(
+        voca_rs::distance::longest_common_subsequence("", "abc", Unit::Char),
+        ""
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_subsequence("ABCBDAB", "BDCABA", Unit::Char),
+        "BDAB"
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_subsequence("Zażółć gęślą", "Zazolc gesla", Unit::Char),
+        "Za gl"
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_subsequence(
+            "the quick brown fox",
+            "the slow brown dog, the fox",
+            Unit::Word
+        ),
+        "the brown fox"
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_subsequence("cafe\u{0301}s", "cafes", Unit::Grapheme),
+        "cafs"
+    );
+}
+#[test]
+fn _longest_common_subsequence() {
+    assert_eq!(
+        "ABCBDAB"._longest_common_subsequence("BDCABA", Unit::Char),
+        "BDAB"
+    );
+}
+#[test]
+fn longest_common_substring() {
+    assert_eq!(
+        voca_rs::distance::longest_common_substring("", "abc", Unit::Char),
+        ""
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_substring("abc", "xyz", Unit::Char),
+        ""
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_substring(
+            "The quick, brown fox",
+            "a quick brown dog",
+            Unit::Word
+        ),
+        "quick brown"
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_substring(
+            "The quick brown fox",
+            "a quick brown dog",
+            Unit::Char
+        ),
+        " quick brown "
+    );
+    assert_eq!(
+        voca_rs::distance::longest_common_substring("Straße", "Strasse", Unit::Char),
+        "Stra"
+    );
+}
+#[test]
+fn _longest_common_substring() {
+    assert_eq!(
+        "The quick brown fox"._longest_common_substring("a quick brown dog", Unit::Char),
+        " quick brown "
+    );
+}
+#[test]
+fn sorensen_dice() {
+    assert_eq!(voca_rs::distance::sorensen_dice("", "", Unit::Char), 1.0);
+    assert_eq!(voca_rs::distance::sorensen_dice("a", "b", Unit::Char), 0.0);
+    assert_eq!(
+        voca_rs::distance::sorensen_dice("night", "nacht", Unit::Char),
+        0.25
+    );
+    assert_eq!(
+        voca_rs::distance::sorensen_dice("Jürgen", "Jürgen", Unit::Grapheme),
+        1.0
+    );
+    assert_eq!(
+        voca_rs::distance::sorensen_dice("aaaa", "aa", Unit::Char),
+        0.5
+    );
+}
+#[test]
+fn _sorensen_dice() {
+    assert_eq!("night"._sorensen_dice("nacht", Unit::Char), 0.25);
+}
+#[test]
+fn suggest() {
+    let commands = ["status", "stash", "commit", "checkout", "cherry-pick"];
+    assert_eq!(voca_rs::distance::suggest("", &commands, 3), []);
+    assert_eq!(voca_rs::distance::suggest("status", &commands, 0), []);
+    assert_eq!(voca_rs::distance::suggest("status", &[], 3), []);
+    assert_eq!(
+        voca_rs::distance::suggest("status", &commands, 1),
+        [("status", 1.0)]
+    );
+    assert_eq!(
+        voca_rs::distance::suggest("STATUS", &commands, 1),
+        [("status", 1.0)]
+    );
+    assert_eq!(
+        voca_rs::distance::suggest("stauts", &commands, 1)[0].0,
+        "status"
+    );
+    assert_eq!(
+        voca_rs::distance::suggest("chekout", &commands, 1)[0].0,
+        "checkout"
+    );
+    assert_eq!(
+        voca_rs::distance::suggest("cherry", &commands, 1)[0].0,
+        "cherry-pick"
+    );
+    assert_eq!(voca_rs::distance::suggest("zzz", &commands, 3), []);
+    assert_eq!(
+        voca_rs::distance::suggest("Muller", &["Müller", "Miller", "Mahler"], 1)[0],
+        ("Müller", 1.0)
+    );
+    let suggestions = voca_rs::distance::suggest("sta", &commands, 5);
+    assert_eq!(suggestions.len(), 2);
+    assert!(suggestions[0].1 >= suggestions[1].1);
+}
+#[test]
+fn _suggest() {
+    assert_eq!(
+        "stauts"._suggest(&["status", "stash", "commit"], 1)[0].0,
+        "status"
+    );
+}
+#[test]
+fn suggest_with() {
+    let options = voca_rs::distance::SuggestOptions {
+        min_score: 0.3,
+        max_distance: Some(2),
+        ..Default::default()
+    };
+    let suggestions =
+        voca_rs::distance::suggest_with("colour", &["color", "column", "cooler"], 3, &options);
+    assert_eq!(suggestions[0].0, "color");
+    assert!(suggestions.iter().all(|(c, _)| *c != "column"));
+    let case_sensitive = voca_rs::distance::SuggestOptions {
+        match_options: voca_rs::utils::MatchOptions::default(),
+        ..Default::default()
+    };
+    assert!(voca_rs::distance::suggest_with("STATUS", &["status"], 1, &case_sensitive).is_empty());
+    let strict = voca_rs::distance::SuggestOptions {
+        min_score: 0.99,
+        ..Default::default()
+    };
+    assert!(voca_rs::distance::suggest_with("stauts", &["status"], 1, &strict).is_empty());
+}
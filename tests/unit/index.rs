@@ -27,6 +27,42 @@ fn _index_of() {
     assert_eq!("Rain, dear rain"._index_of("ear", 0), 7);
 }
 #[test]
+fn index_of_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!(voca_rs::index::index_of_folded("", "", 0, &options), 0);
+    assert_eq!(voca_rs::index::index_of_folded("Rain", "r", 0, &options), 0);
+    assert_eq!(voca_rs::index::index_of_folded("rain", "N", 3, &options), 0);
+    assert_eq!(
+        voca_rs::index::index_of_folded("rain", "a", 10, &options),
+        -1
+    );
+    assert_eq!(
+        voca_rs::index::index_of_folded("Rain, dear rain", "EAR", 0, &options),
+        7
+    );
+    assert_eq!(
+        voca_rs::index::index_of_folded("Zażółć gęślą jaźń", "GESLA", 0, &options),
+        7
+    );
+    assert_eq!(
+        voca_rs::index::index_of_folded("Die Straße ist lang", "strasse", 0, &options),
+        4
+    );
+    assert_eq!(
+        voca_rs::index::index_of_folded("cafe\u{0301} ＢＡＲ", "bar", 0, &options),
+        6
+    );
+    assert_eq!(
+        voca_rs::index::index_of_folded("rain", "z", 0, &options),
+        -1
+    );
+}
+#[test]
+fn _index_of_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!("Rain, dear rain"._index_of_folded("EAR", 0, &options), 7);
+}
+#[test]
 fn index_all() {
     assert_eq!(voca_rs::index::index_all("", "", 0), []);
     assert_eq!(voca_rs::index::index_all("rain", "r", 0), [0]);
@@ -133,3 +169,40 @@ fn search() {
 fn _search() {
     assert_eq!("we have a mission"._search("mission", 0), 10);
 }
+#[test]
+fn find_where() {
+    use voca_rs::split::Unit;
+    assert_eq!(
+        voca_rs::index::find_where("", Unit::Char, voca_rs::query::is_digit),
+        None
+    );
+    assert_eq!(
+        voca_rs::index::find_where("hello 2 you", Unit::Char, voca_rs::query::is_digit),
+        Some(6)
+    );
+    assert_eq!(
+        voca_rs::index::find_where("hello", Unit::Char, voca_rs::query::is_digit),
+        None
+    );
+    assert_eq!(
+        voca_rs::index::find_where("cafe\u{0301}!", Unit::Grapheme, |g| g == "e\u{0301}"),
+        Some(3)
+    );
+    assert_eq!(
+        voca_rs::index::find_where("cafe\u{0301}!", Unit::Char, |c| c == "!"),
+        Some(5)
+    );
+    let min = 5;
+    assert_eq!(
+        voca_rs::index::find_where("the quick brown fox", Unit::Word, |w| w.len() >= min),
+        Some(1)
+    );
+}
+#[test]
+fn _find_where() {
+    use voca_rs::split::Unit;
+    assert_eq!(
+        "hello 2 you"._find_where(Unit::Char, &voca_rs::query::is_digit),
+        Some(6)
+    );
+}
@@ -218,6 +218,24 @@ fn _pad() {
     assert_eq!("dog"._pad(5, ""), " dog ");
 }
 #[test]
+fn pad_width() {
+    assert_eq!(voca_rs::manipulate::pad_width("", 2, ""), "  ");
+    assert_eq!(voca_rs::manipulate::pad_width("dog", 5, ""), " dog ");
+    assert_eq!(voca_rs::manipulate::pad_width("dog", 2, ""), "dog");
+    assert_eq!(voca_rs::manipulate::pad_width("日本", 8, "-"), "--日本--");
+    assert_eq!(voca_rs::manipulate::pad_width("日本", 7, "-="), "-日本-=");
+    assert_eq!(voca_rs::manipulate::pad_width("bird", 8, "・"), "・bird・");
+    assert_eq!(voca_rs::manipulate::pad_width("bird", 7, "・"), " bird・");
+    assert_eq!(
+        voca_rs::manipulate::pad_width("cafe\u{0301}", 6, "\u{0301}"),
+        " cafe\u{0301} "
+    );
+}
+#[test]
+fn _pad_width() {
+    assert_eq!("日本"._pad_width(8, ""), "  日本  ");
+}
+#[test]
 fn pad_left() {
     assert_eq!(voca_rs::manipulate::pad_left("", 0, ""), "");
     assert_eq!(voca_rs::manipulate::pad_left("abc", 1, ""), "abc");
@@ -254,6 +272,20 @@ fn _pad_left() {
     assert_eq!("dog"._pad_left(5, ""), "  dog");
 }
 #[test]
+fn pad_left_width() {
+    assert_eq!(voca_rs::manipulate::pad_left_width("dog", 5, ""), "  dog");
+    assert_eq!(
+        voca_rs::manipulate::pad_left_width("日本", 6, "-"),
+        "--日本"
+    );
+    assert_eq!(voca_rs::manipulate::pad_left_width("日本", 3, "-"), "日本");
+    assert_eq!(voca_rs::manipulate::pad_left_width("ok", 5, "👍"), "👍 ok");
+}
+#[test]
+fn _pad_left_width() {
+    assert_eq!("日本"._pad_left_width(6, ""), "  日本");
+}
+#[test]
 fn pad_right() {
     assert_eq!(voca_rs::manipulate::pad_right("", 0, ""), "");
     assert_eq!(voca_rs::manipulate::pad_right("abc", 1, ""), "abc");
@@ -290,6 +322,23 @@ fn _pad_right() {
     assert_eq!("dog"._pad_right(5, ""), "dog  ");
 }
 #[test]
+fn pad_right_width() {
+    assert_eq!(voca_rs::manipulate::pad_right_width("dog", 5, ""), "dog  ");
+    assert_eq!(
+        voca_rs::manipulate::pad_right_width("日本", 6, "-"),
+        "日本--"
+    );
+    assert_eq!(voca_rs::manipulate::pad_right_width("日本", 3, "-"), "日本");
+    assert_eq!(
+        voca_rs::manipulate::pad_right_width("ok", 7, "日本"),
+        "ok日本 "
+    );
+}
+#[test]
+fn _pad_right_width() {
+    assert_eq!("日本"._pad_right_width(6, ""), "日本  ");
+}
+#[test]
 fn repeat() {
     assert_eq!(voca_rs::manipulate::repeat("", 1), "");
     assert_eq!(voca_rs::manipulate::repeat("w", 3), "www");
@@ -374,6 +423,73 @@ fn _replace_all() {
     assert_eq!("swan"._replace_all("wa", "u"), "sun");
 }
 #[test]
+fn replace_all_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("", "", "", &options),
+        ""
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("abc", "", "x", &options),
+        "abc"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("Swan", "WA", "u", &options),
+        "Sun"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("Straße or STRASSE", "strasse", "street", &options),
+        "street or street"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded(
+            "Café del Mar cafe\u{0301}!",
+            "cafe",
+            "Bar",
+            &options
+        ),
+        "Bar del Mar Bar!"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("ＡＢＣ abc", "abc", "x", &options),
+        "x x"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("ﬁle", "i", "X", &options),
+        "ﬁle"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("ﬁle profile", "fi", "FI", &options),
+        "FIle proFIle"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("straße", "s", "X", &options),
+        "Xtraße"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("straße", "ss", "X", &options),
+        "straXe"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded("ßs", "ss", "X", &options),
+        "Xs"
+    );
+    assert_eq!(
+        voca_rs::manipulate::replace_all_folded(
+            "domestic Duck",
+            "d",
+            "D",
+            &voca_rs::utils::MatchOptions::default()
+        ),
+        "Domestic Duck"
+    );
+}
+#[test]
+fn _replace_all_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!("Swan"._replace_all_folded("WA", "u", &options), "Sun");
+}
+#[test]
 fn reverse() {
     assert_eq!(voca_rs::manipulate::reverse(""), "");
     assert_eq!(voca_rs::manipulate::reverse("abc"), "cba");
@@ -649,6 +765,249 @@ fn _word_wrap() {
     assert_eq!("Hello world"._word_wrap(5, "", ""), "Hello\nworld");
 }
 #[test]
+fn word_wrap_width() {
+    assert_eq!(voca_rs::manipulate::word_wrap_width("", 5, "", ""), "");
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("Hello world", 5, "", ""),
+        "Hello\nworld"
+    );
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("Hello world", 20, "", ""),
+        "Hello world"
+    );
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("東京 大阪 京都", 9, "", ""),
+        "東京 大阪\n京都"
+    );
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("東京 大阪 京都", 8, "", ""),
+        "東京\n大阪\n京都"
+    );
+    // the no-break spaces keep the words together
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("Wait 10\u{00A0}km now", 7, "", ""),
+        "Wait\n10\u{00A0}km\nnow"
+    );
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("東京 大阪 京都", 9, "<br/>", "__"),
+        "__東京 大阪<br/>__京都"
+    );
+    assert_eq!(
+        voca_rs::manipulate::word_wrap_width("a verylongword b", 4, "", ""),
+        "a\nverylongword\nb"
+    );
+}
+#[test]
+fn _word_wrap_width() {
+    assert_eq!(
+        "東京 大阪 京都"._word_wrap_width(9, "", ""),
+        "東京 大阪\n京都"
+    );
+}
+#[test]
+fn word_wrap_with() {
+    use voca_rs::manipulate::{word_wrap_with, WrapOptions};
+    let width = |width: usize| WrapOptions {
+        width,
+        ..Default::default()
+    };
+    assert_eq!(word_wrap_with("", &width(10)), "");
+    assert_eq!(word_wrap_with("Hello world", &width(20)), "Hello world");
+    assert_eq!(word_wrap_with("Hello world", &width(5)), "Hello\nworld");
+    assert_eq!(
+        word_wrap_with("The quick brown fox", &width(10)),
+        "The quick\nbrown fox"
+    );
+    assert_eq!(
+        word_wrap_with("日本語のテキストです。", &width(10)),
+        "日本語のテ\nキストで\nす。"
+    );
+    assert_eq!(
+        word_wrap_with("https://example.com/path", &width(10)),
+        "https://\nexample.co\nm/path"
+    );
+    assert_eq!(
+        word_wrap_with("well-known fact", &width(7)),
+        "well-\nknown\nfact"
+    );
+    assert_eq!(
+        word_wrap_with("hyphen\u{AD}ation rules", &width(8)),
+        "hyphen-\nation\nrules"
+    );
+    assert_eq!(
+        word_wrap_with("first line\n\nsecond paragraph\n", &width(8)),
+        "first\nline\n\nsecond\nparagrap\nh\n"
+    );
+    assert_eq!(
+        word_wrap_with(
+            "first line\nsame paragraph",
+            &WrapOptions {
+                width: 20,
+                preserve_paragraphs: false,
+                ..Default::default()
+            }
+        ),
+        "first line same\nparagraph"
+    );
+    assert_eq!(
+        word_wrap_with(
+            "a verylongword",
+            &WrapOptions {
+                width: 5,
+                break_long_words: false,
+                ..Default::default()
+            }
+        ),
+        "a\nverylongword"
+    );
+    assert_eq!(
+        word_wrap_with(
+            "one two three four\nfive",
+            &WrapOptions {
+                width: 12,
+                initial_indent: "* ".to_string(),
+                subsequent_indent: "  ".to_string(),
+                ..Default::default()
+            }
+        ),
+        "* one two\n  three four\n* five"
+    );
+    assert_eq!(
+        word_wrap_with(
+            "Hello world",
+            &WrapOptions {
+                width: 5,
+                newline: "<br/>".to_string(),
+                trim_trailing_whitespace: false,
+                ..Default::default()
+            }
+        ),
+        "Hello <br/>world"
+    );
+}
+#[test]
+fn _word_wrap_with() {
+    assert_eq!(
+        "The quick brown fox"._word_wrap_with(&voca_rs::manipulate::WrapOptions {
+            width: 10,
+            ..Default::default()
+        }),
+        "The quick\nbrown fox"
+    );
+}
+#[test]
+fn fill() {
+    assert_eq!(voca_rs::manipulate::fill("", 10), "");
+    assert_eq!(voca_rs::manipulate::fill("one", 10), "one");
+    // greedy wrapping would give "aaa bb\ncc\nddddd"
+    assert_eq!(
+        voca_rs::manipulate::fill("aaa bb cc ddddd", 6),
+        "aaa\nbb cc\nddddd"
+    );
+    assert_eq!(
+        voca_rs::manipulate::fill("one  two\nthree\n\n\n  four five", 9),
+        "one two\nthree\n\nfour five"
+    );
+    assert_eq!(
+        voca_rs::manipulate::fill("a verylongword b", 5),
+        "a\nverylongword\nb"
+    );
+    assert_eq!(
+        voca_rs::manipulate::fill("日本 語の テキスト", 8),
+        "日本\n語の\nテキスト"
+    );
+    // the no-break spaces keep the words together
+    assert_eq!(
+        voca_rs::manipulate::fill("page 10\u{00A0}km", 6),
+        "page\n10\u{00A0}km"
+    );
+    assert_eq!(
+        voca_rs::manipulate::fill("a b\u{202F}c d\u{2007}e", 3),
+        "a\nb\u{202F}c\nd\u{2007}e"
+    );
+}
+#[test]
+fn _fill() {
+    assert_eq!("aaa bb cc ddddd"._fill(6), "aaa\nbb cc\nddddd");
+}
+#[test]
+fn fill_with() {
+    use voca_rs::manipulate::{fill_with, Align, FillOptions};
+    let aligned = |width: usize, align: Align| FillOptions {
+        width,
+        align,
+        ..Default::default()
+    };
+    assert_eq!(
+        fill_with(
+            "The quick brown fox jumps over the lazy dog",
+            &aligned(12, Align::Justify)
+        ),
+        "The    quick\nbrown    fox\njumps   over\nthe lazy dog"
+    );
+    assert_eq!(
+        fill_with("one two\n\nthree four five", &aligned(10, Align::Justify)),
+        "one two\n\nthree four\nfive"
+    );
+    assert_eq!(
+        fill_with("one two three", &aligned(9, Align::Right)),
+        "  one two\n    three"
+    );
+    assert_eq!(
+        fill_with("one two three", &aligned(9, Align::Center)),
+        " one two\n  three"
+    );
+    assert_eq!(
+        fill_with("日本 語", &aligned(10, Align::Right)),
+        "   日本 語"
+    );
+    let hyphenation = vec!["hy-phen-ation".to_string(), "dic-tion-ary".to_string()];
+    assert_eq!(
+        fill_with(
+            "a hyphenation test",
+            &FillOptions {
+                width: 10,
+                hyphenation: hyphenation.clone(),
+                ..Default::default()
+            }
+        ),
+        "a hyphen-\nation test"
+    );
+    assert_eq!(
+        fill_with(
+            "the Dictionary, again",
+            &FillOptions {
+                width: 9,
+                hyphenation: hyphenation.clone(),
+                ..Default::default()
+            }
+        ),
+        "the Dic-\ntionary,\nagain"
+    );
+    assert_eq!(
+        fill_with(
+            "a hyphenation",
+            &FillOptions {
+                width: 20,
+                hyphenation,
+                ..Default::default()
+            }
+        ),
+        "a hyphenation"
+    );
+}
+#[test]
+fn _fill_with() {
+    assert_eq!(
+        "one two three"._fill_with(&voca_rs::manipulate::FillOptions {
+            width: 9,
+            align: voca_rs::manipulate::Align::Right,
+            ..Default::default()
+        }),
+        "  one two\n    three"
+    );
+}
+#[test]
 fn finish() {
     assert_eq!(voca_rs::manipulate::finish("", ""), "");
     assert_eq!(voca_rs::manipulate::finish("foo bar", "bar"), "foo bar");
@@ -693,3 +1052,188 @@ fn start() {
         "¡Как слышно, приём!"
     );
 }
+#[test]
+fn dedent() {
+    assert_eq!(voca_rs::manipulate::dedent(""), "");
+    assert_eq!(
+        voca_rs::manipulate::dedent("    hello\n      world\n\n    !"),
+        "hello\n  world\n\n!"
+    );
+    assert_eq!(
+        voca_rs::manipulate::dedent("  hello\n \t \n  world\n"),
+        "hello\n\nworld\n"
+    );
+    assert_eq!(voca_rs::manipulate::dedent("\tone\r\n\ttwo"), "one\r\ntwo");
+    assert_eq!(voca_rs::manipulate::dedent("\tone\n  two"), "\tone\n  two");
+    assert_eq!(voca_rs::manipulate::dedent("one\n  two"), "one\n  two");
+}
+#[test]
+fn _dedent() {
+    assert_eq!("    hello\n      world"._dedent(), "hello\n  world");
+}
+#[test]
+fn indent() {
+    assert_eq!(
+        voca_rs::manipulate::indent("", "> ", None::<fn(&str) -> bool>),
+        ""
+    );
+    assert_eq!(
+        voca_rs::manipulate::indent("hello\n\nworld", "> ", None::<fn(&str) -> bool>),
+        "> hello\n\n> world"
+    );
+    assert_eq!(
+        voca_rs::manipulate::indent("hello\r\n  \r\nworld\n", "  ", None::<fn(&str) -> bool>),
+        "  hello\r\n  \r\n  world\n"
+    );
+    assert_eq!(
+        voca_rs::manipulate::indent("hello\n\nworld", "> ", Some(|_: &str| true)),
+        "> hello\n> \n> world"
+    );
+    assert_eq!(
+        voca_rs::manipulate::indent(
+            "- one\ntwo",
+            "  ",
+            Some(|line: &str| !line.starts_with('-'))
+        ),
+        "- one\n  two"
+    );
+    let marker = String::from("#");
+    assert_eq!(
+        voca_rs::manipulate::indent(
+            "# one\ntwo",
+            "// ",
+            Some(|line: &str| line.starts_with(&marker))
+        ),
+        "// # one\ntwo"
+    );
+}
+#[test]
+fn _indent() {
+    assert_eq!("hello\nworld"._indent("  ", None), "  hello\n  world");
+    let marker = '-';
+    assert_eq!(
+        "- one\ntwo"._indent("  ", Some(&|line: &str| !line.starts_with(marker))),
+        "- one\n  two"
+    );
+}
+#[test]
+fn normalize_line_endings() {
+    use voca_rs::split::LineEnding;
+    assert_eq!(
+        voca_rs::manipulate::normalize_line_endings("", LineEnding::Lf),
+        ""
+    );
+    assert_eq!(
+        voca_rs::manipulate::normalize_line_endings("one\r\ntwo\rthree\n", LineEnding::Lf),
+        "one\ntwo\nthree\n"
+    );
+    assert_eq!(
+        voca_rs::manipulate::normalize_line_endings("one\ntwo\r\n", LineEnding::CrLf),
+        "one\r\ntwo\r\n"
+    );
+    assert_eq!(
+        voca_rs::manipulate::normalize_line_endings("one\n\r\ntwo", LineEnding::Cr),
+        "one\r\rtwo"
+    );
+    assert_eq!(
+        voca_rs::manipulate::normalize_line_endings("\r\r\n", LineEnding::Lf),
+        "\n\n"
+    );
+}
+#[test]
+fn _normalize_line_endings() {
+    assert_eq!(
+        "one\r\ntwo"._normalize_line_endings(voca_rs::split::LineEnding::Lf),
+        "one\ntwo"
+    );
+}
+#[test]
+fn bidi_isolate() {
+    assert_eq!(voca_rs::manipulate::bidi_isolate(""), "");
+    assert_eq!(
+        voca_rs::manipulate::bidi_isolate("שלום"),
+        "\u{2068}שלום\u{2069}"
+    );
+    assert_eq!(
+        voca_rs::manipulate::bidi_isolate("abc"),
+        "\u{2068}abc\u{2069}"
+    );
+    assert_eq!(
+        voca_rs::manipulate::bidi_isolate("\u{2067}abc"),
+        "\u{2068}\u{2067}abc\u{2069}\u{2069}"
+    );
+    assert_eq!(
+        voca_rs::manipulate::bidi_isolate("\u{2069}abc"),
+        "\u{2068}abc\u{2069}"
+    );
+    assert_eq!(
+        voca_rs::manipulate::bidi_isolate("a\u{2069}b\u{2066}c\u{2069}\u{2069}d"),
+        "\u{2068}ab\u{2066}c\u{2069}d\u{2069}"
+    );
+}
+#[test]
+fn _bidi_isolate() {
+    assert_eq!("שלום"._bidi_isolate(), "\u{2068}שלום\u{2069}");
+}
+#[test]
+fn shortcodes_to_emoji() {
+    assert_eq!(voca_rs::manipulate::shortcodes_to_emoji(""), "");
+    assert_eq!(
+        voca_rs::manipulate::shortcodes_to_emoji("Ship it :rocket::tada:"),
+        "Ship it 🚀🎉"
+    );
+    assert_eq!(
+        voca_rs::manipulate::shortcodes_to_emoji(":+1: at 10:30 :unknown:"),
+        "👍 at 10:30 :unknown:"
+    );
+    assert_eq!(
+        voca_rs::manipulate::shortcodes_to_emoji(":thumbsup::-1::100:"),
+        "👍👎💯"
+    );
+    assert_eq!(
+        voca_rs::manipulate::shortcodes_to_emoji("I :heart: Rust"),
+        "I ❤\u{FE0F} Rust"
+    );
+    assert_eq!(
+        voca_rs::manipulate::shortcodes_to_emoji(":pinched_fingers: :ukraine: :octocat:"),
+        "🤌 🇺🇦 :octocat:"
+    );
+}
+#[test]
+fn _shortcodes_to_emoji() {
+    assert_eq!("I :heart: Rust"._shortcodes_to_emoji(), "I ❤️ Rust");
+}
+#[test]
+fn emoji_to_shortcodes() {
+    assert_eq!(voca_rs::manipulate::emoji_to_shortcodes(""), "");
+    assert_eq!(
+        voca_rs::manipulate::emoji_to_shortcodes("Ship it 🚀🎉"),
+        "Ship it :rocket::tada:"
+    );
+    assert_eq!(
+        voca_rs::manipulate::emoji_to_shortcodes("I ❤\u{FE0F} Rust 👍🏽"),
+        "I :heart: Rust 👍🏽"
+    );
+    assert_eq!(
+        voca_rs::manipulate::emoji_to_shortcodes("❤ ☺ 1 ©"),
+        "❤ ☺ 1 ©"
+    );
+    assert_eq!(
+        voca_rs::manipulate::emoji_to_shortcodes("☺\u{FE0F} 1\u{FE0F}\u{20E3} 🇺🇦"),
+        ":relaxed: :one: :ukraine:"
+    );
+    assert_eq!(
+        voca_rs::manipulate::emoji_to_shortcodes("❤️👍"),
+        ":heart::+1:"
+    );
+    assert_eq!(
+        voca_rs::manipulate::emoji_to_shortcodes(&voca_rs::manipulate::shortcodes_to_emoji(
+            ":smile: :sob:"
+        )),
+        ":smile: :sob:"
+    );
+}
+#[test]
+fn _emoji_to_shortcodes() {
+    assert_eq!("Ship it 🚀"._emoji_to_shortcodes(), "Ship it :rocket:");
+}
@@ -0,0 +1,165 @@
+//! voca_rs::parse testing
+use voca_rs::parse::{Number, NumberOptions};
+use voca_rs::Voca;
+
+#[test]
+fn parse_number() {
+    assert_eq!(voca_rs::parse::parse_number(""), None);
+    assert_eq!(voca_rs::parse::parse_number("1e400"), None);
+    assert_eq!(voca_rs::parse::parse_number("0"), Some(Number::Integer(0)));
+    assert_eq!(
+        voca_rs::parse::parse_number("+42"),
+        Some(Number::Integer(42))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("-42"),
+        Some(Number::Integer(-42))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("3000000000"),
+        Some(Number::Integer(3_000_000_000))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("1_000"),
+        Some(Number::Integer(1000))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("1,234,567"),
+        Some(Number::Integer(1_234_567))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("1,234.56"),
+        Some(Number::Float(1234.56))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("-20.5"),
+        Some(Number::Float(-20.5))
+    );
+    assert_eq!(voca_rs::parse::parse_number(".5"), Some(Number::Float(0.5)));
+    assert_eq!(voca_rs::parse::parse_number("5."), Some(Number::Float(5.0)));
+    assert_eq!(
+        voca_rs::parse::parse_number("1.5E+2"),
+        Some(Number::Float(150.0))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("125e-3"),
+        Some(Number::Float(0.125))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("0xFF"),
+        Some(Number::Integer(255))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("-0x10"),
+        Some(Number::Integer(-16))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("0o17"),
+        Some(Number::Integer(15))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("0b1010_1010"),
+        Some(Number::Integer(170))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number("170141183460469231731687303715884105728"),
+        Some(Number::Float(1.7014118346046923e38))
+    );
+    assert_eq!(voca_rs::parse::parse_number("e5e"), None);
+    assert_eq!(voca_rs::parse::parse_number("1e"), None);
+    assert_eq!(voca_rs::parse::parse_number("1e5e"), None);
+    assert_eq!(voca_rs::parse::parse_number("e5"), None);
+    assert_eq!(voca_rs::parse::parse_number("."), None);
+    assert_eq!(voca_rs::parse::parse_number("-"), None);
+    assert_eq!(voca_rs::parse::parse_number("1.2.3"), None);
+    assert_eq!(voca_rs::parse::parse_number("1,2"), None);
+    assert_eq!(voca_rs::parse::parse_number("12,34"), None);
+    assert_eq!(voca_rs::parse::parse_number("1__000"), None);
+    assert_eq!(voca_rs::parse::parse_number("_1"), None);
+    assert_eq!(voca_rs::parse::parse_number("0x"), None);
+    assert_eq!(voca_rs::parse::parse_number("0x12z"), None);
+    assert_eq!(voca_rs::parse::parse_number("0b102"), None);
+    assert_eq!(voca_rs::parse::parse_number(" 1"), None);
+    assert_eq!(voca_rs::parse::parse_number("inf"), None);
+    assert_eq!(voca_rs::parse::parse_number("٤٢"), None);
+    assert_eq!(Number::Integer(3).as_f64(), 3.0);
+}
+#[test]
+fn _parse_number() {
+    assert_eq!("1_000"._parse_number(), Some(Number::Integer(1000)));
+}
+#[test]
+fn parse_number_with() {
+    let german = NumberOptions {
+        thousands_separator: Some('.'),
+        decimal_separator: ',',
+        ..NumberOptions::default()
+    };
+    assert_eq!(
+        voca_rs::parse::parse_number_with("1.234,56", &german),
+        Some(Number::Float(1234.56))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number_with("1.234", &german),
+        Some(Number::Integer(1234))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number_with("2,5", &german),
+        Some(Number::Float(2.5))
+    );
+    assert_eq!(voca_rs::parse::parse_number_with("1,234.56", &german), None);
+    let comma_only = NumberOptions {
+        thousands_separator: Some(','),
+        decimal_separator: ',',
+        ..NumberOptions::default()
+    };
+    assert_eq!(
+        voca_rs::parse::parse_number_with("1,5", &comma_only),
+        Some(Number::Float(1.5))
+    );
+    let strict = NumberOptions {
+        thousands_separator: None,
+        underscores: false,
+        radix_prefixes: false,
+        ..NumberOptions::default()
+    };
+    assert_eq!(voca_rs::parse::parse_number_with("1,000", &strict), None);
+    assert_eq!(voca_rs::parse::parse_number_with("1_000", &strict), None);
+    assert_eq!(voca_rs::parse::parse_number_with("0xFF", &strict), None);
+    assert_eq!(
+        voca_rs::parse::parse_number_with("1000", &strict),
+        Some(Number::Integer(1000))
+    );
+    let unicode = NumberOptions {
+        unicode_digits: true,
+        ..NumberOptions::default()
+    };
+    assert_eq!(
+        voca_rs::parse::parse_number_with("٤٢", &unicode),
+        Some(Number::Integer(42))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number_with("１２３", &unicode),
+        Some(Number::Integer(123))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number_with("१०.५", &unicode),
+        Some(Number::Float(10.5))
+    );
+    assert_eq!(
+        voca_rs::parse::parse_number_with("\u{1D7D7}\u{1D7E2}", &unicode),
+        Some(Number::Integer(90))
+    );
+    assert_eq!(voca_rs::parse::parse_number_with("Ⅻ", &unicode), None);
+}
+#[test]
+fn _parse_number_with() {
+    let unicode = NumberOptions {
+        unicode_digits: true,
+        ..NumberOptions::default()
+    };
+    assert_eq!(
+        "１２３"._parse_number_with(&unicode),
+        Some(Number::Integer(123))
+    );
+}
@@ -0,0 +1,161 @@
+//! voca_rs::phonetic testing
+use voca_rs::Voca;
+
+#[test]
+fn soundex() {
+    assert_eq!(voca_rs::phonetic::soundex(""), "");
+    assert_eq!(voca_rs::phonetic::soundex("123"), "");
+    assert_eq!(voca_rs::phonetic::soundex("A"), "A000");
+    assert_eq!(voca_rs::phonetic::soundex("Robert"), "R163");
+    assert_eq!(voca_rs::phonetic::soundex("Rupert"), "R163");
+    assert_eq!(voca_rs::phonetic::soundex("Rubin"), "R150");
+    assert_eq!(voca_rs::phonetic::soundex("Ashcraft"), "A261");
+    assert_eq!(voca_rs::phonetic::soundex("Ashcroft"), "A261");
+    assert_eq!(voca_rs::phonetic::soundex("Tymczak"), "T522");
+    assert_eq!(voca_rs::phonetic::soundex("Pfister"), "P236");
+    assert_eq!(voca_rs::phonetic::soundex("Honeyman"), "H555");
+    assert_eq!(voca_rs::phonetic::soundex("O'Hara"), "O600");
+    assert_eq!(voca_rs::phonetic::soundex("Müller"), "M460");
+    assert_eq!(voca_rs::phonetic::soundex("Щербаков"), "S612");
+}
+#[test]
+fn _soundex() {
+    assert_eq!("Robert"._soundex(), "R163");
+}
+#[test]
+fn refined_soundex() {
+    assert_eq!(voca_rs::phonetic::refined_soundex(""), "");
+    assert_eq!(voca_rs::phonetic::refined_soundex("Braz"), "B1905");
+    assert_eq!(voca_rs::phonetic::refined_soundex("testing"), "T6036084");
+    assert_eq!(voca_rs::phonetic::refined_soundex("TESTING"), "T6036084");
+    assert_eq!(voca_rs::phonetic::refined_soundex("The"), "T60");
+    assert_eq!(voca_rs::phonetic::refined_soundex("quick"), "Q503");
+    assert_eq!(voca_rs::phonetic::refined_soundex("brown"), "B1908");
+    assert_eq!(voca_rs::phonetic::refined_soundex("fox"), "F205");
+    assert_eq!(voca_rs::phonetic::refined_soundex("jumped"), "J408106");
+    assert_eq!(voca_rs::phonetic::refined_soundex("lazy"), "L7050");
+    assert_eq!(voca_rs::phonetic::refined_soundex("dogs"), "D6043");
+}
+#[test]
+fn _refined_soundex() {
+    assert_eq!("Braz"._refined_soundex(), "B1905");
+}
+#[test]
+fn metaphone() {
+    assert_eq!(voca_rs::phonetic::metaphone(""), "");
+    assert_eq!(voca_rs::phonetic::metaphone("a"), "A");
+    assert_eq!(voca_rs::phonetic::metaphone("Thompson"), "0MPSN");
+    assert_eq!(voca_rs::phonetic::metaphone("howl"), "HL");
+    assert_eq!(voca_rs::phonetic::metaphone("testing"), "TSTNK");
+    assert_eq!(voca_rs::phonetic::metaphone("The"), "0");
+    assert_eq!(voca_rs::phonetic::metaphone("quick"), "KK");
+    assert_eq!(voca_rs::phonetic::metaphone("brown"), "BRN");
+    assert_eq!(voca_rs::phonetic::metaphone("fox"), "FKS");
+    assert_eq!(voca_rs::phonetic::metaphone("jumped"), "JMPT");
+    assert_eq!(voca_rs::phonetic::metaphone("over"), "OFR");
+    assert_eq!(voca_rs::phonetic::metaphone("lazy"), "LS");
+    assert_eq!(voca_rs::phonetic::metaphone("dogs"), "TKS");
+    assert_eq!(voca_rs::phonetic::metaphone("Knight"), "NT");
+    assert_eq!(voca_rs::phonetic::metaphone("Schmidt"), "SKMTT");
+    assert_eq!(voca_rs::phonetic::metaphone("Wright"), "RT");
+    assert_eq!(voca_rs::phonetic::metaphone("White"), "WT");
+    assert_eq!(voca_rs::phonetic::metaphone("Xavier"), "SFR");
+    assert_eq!(voca_rs::phonetic::metaphone("Aeneas"), "ENS");
+    assert_eq!(voca_rs::phonetic::metaphone("Christine"), "KRSTN");
+    assert_eq!(voca_rs::phonetic::metaphone("Dumb"), "TM");
+    assert_eq!(voca_rs::phonetic::metaphone("Science"), "SNS");
+    assert_eq!(voca_rs::phonetic::metaphone("Edge"), "EJ");
+    assert_eq!(voca_rs::phonetic::metaphone("Nation"), "NXN");
+    assert_eq!(voca_rs::phonetic::metaphone("Фёдор"), "FTR");
+}
+#[test]
+fn _metaphone() {
+    assert_eq!("Thompson"._metaphone(), "0MPSN");
+}
+#[test]
+fn double_metaphone() {
+    let dm = |s: &str| {
+        let (primary, alternate) = voca_rs::phonetic::double_metaphone(s);
+        format!("{} {}", primary, alternate)
+    };
+    assert_eq!(dm(""), " ");
+    assert_eq!(dm("Smith"), "SM0 XMT");
+    assert_eq!(dm("Schmidt"), "XMT SMT");
+    assert_eq!(dm("Thompson"), "TMPS TMPS");
+    assert_eq!(dm("Jose"), "HS HS");
+    assert_eq!(dm("Michael"), "MKL MXL");
+    assert_eq!(dm("Arnow"), "ARN ARNF");
+    assert_eq!(dm("Arnoff"), "ARNF ARNF");
+    assert_eq!(dm("Caesar"), "SSR SSR");
+    assert_eq!(dm("Chemistry"), "KMST KMST");
+    assert_eq!(dm("Xavier"), "SF SFR");
+    assert_eq!(dm("Wasserman"), "ASRM FSRM");
+    assert_eq!(dm("Gallegos"), "KLKS KKS");
+    assert_eq!(dm("Filipowicz"), "FLPT FLPF");
+    assert_eq!(dm("Zhao"), "J J");
+    assert_eq!(dm("Laugh"), "LF LF");
+    assert_eq!(dm("Hugh"), "H H");
+    assert_eq!(dm("Edge"), "AJ AJ");
+    assert_eq!(dm("Accident"), "AKST AKST");
+    assert_eq!(dm("Bacci"), "PX PX");
+    assert_eq!(dm("Mac Gregor"), "MKRK MKRK");
+    assert_eq!(dm("Ägypten"), "AKPT AJPT");
+}
+#[test]
+fn _double_metaphone() {
+    assert_eq!(
+        "Smith"._double_metaphone(),
+        ("SM0".to_string(), "XMT".to_string())
+    );
+}
+#[test]
+fn nysiis() {
+    assert_eq!(voca_rs::phonetic::nysiis(""), "");
+    assert_eq!(voca_rs::phonetic::nysiis("Bishop"), "BASAP");
+    assert_eq!(voca_rs::phonetic::nysiis("Macintosh"), "MCANT");
+    assert_eq!(voca_rs::phonetic::nysiis("Knuth"), "NAT");
+    assert_eq!(voca_rs::phonetic::nysiis("Brian"), "BRAN");
+    assert_eq!(voca_rs::phonetic::nysiis("Brown"), "BRAN");
+    assert_eq!(voca_rs::phonetic::nysiis("Brun"), "BRAN");
+    assert_eq!(voca_rs::phonetic::nysiis("Carlisle"), "CARLAS");
+    assert_eq!(voca_rs::phonetic::nysiis("Schmidt"), "SNAD");
+    assert_eq!(voca_rs::phonetic::nysiis("Phillipson"), "FALAPS");
+    assert_eq!(voca_rs::phonetic::nysiis("Иванов"), "IVANAV");
+}
+#[test]
+fn _nysiis() {
+    assert_eq!("Bishop"._nysiis(), "BASAP");
+}
+#[test]
+fn cologne() {
+    assert_eq!(voca_rs::phonetic::cologne(""), "");
+    assert_eq!(
+        voca_rs::phonetic::cologne("Müller-Lüdenscheidt"),
+        "65752682"
+    );
+    assert_eq!(voca_rs::phonetic::cologne("Wikipedia"), "3412");
+    assert_eq!(voca_rs::phonetic::cologne("Breschnew"), "17863");
+    assert_eq!(voca_rs::phonetic::cologne("Meier"), "67");
+    assert_eq!(voca_rs::phonetic::cologne("Mayr"), "67");
+    assert_eq!(voca_rs::phonetic::cologne("Maier"), "67");
+    assert_eq!(voca_rs::phonetic::cologne("Christoph"), "47823");
+    assert_eq!(voca_rs::phonetic::cologne("Xaver"), "4837");
+    assert_eq!(voca_rs::phonetic::cologne("Anna"), "06");
+}
+#[test]
+fn _cologne() {
+    assert_eq!("Müller-Lüdenscheidt"._cologne(), "65752682");
+}
+#[test]
+fn caverphone() {
+    assert_eq!(voca_rs::phonetic::caverphone(""), "");
+    assert_eq!(voca_rs::phonetic::caverphone("Thompson"), "TMPSN11111");
+    assert_eq!(voca_rs::phonetic::caverphone("Stevenson"), "STFNSN1111");
+    assert_eq!(voca_rs::phonetic::caverphone("Lee"), "LA11111111");
+    assert_eq!(voca_rs::phonetic::caverphone("Peter"), "PTA1111111");
+    assert_eq!(voca_rs::phonetic::caverphone("Darda"), "TTA1111111");
+}
+#[test]
+fn _caverphone() {
+    assert_eq!("Thompson"._caverphone(), "TMPSN11111");
+}
@@ -14,6 +14,36 @@ fn _ends_with() {
     assert!("Zażółć gęślą jaźń"._ends_with("jaźń"));
 }
 #[test]
+fn ends_with_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert!(voca_rs::query::ends_with_folded("", "", &options));
+    assert!(voca_rs::query::ends_with_folded(
+        "Hauptstraße",
+        "STRASSE",
+        &options
+    ));
+    assert!(voca_rs::query::ends_with_folded(
+        "CAFÉ",
+        "cafe\u{0301}",
+        &options
+    ));
+    assert!(!voca_rs::query::ends_with_folded(
+        "Hauptstraße",
+        "STRASSE",
+        &voca_rs::utils::MatchOptions::default()
+    ));
+    assert!(!voca_rs::query::ends_with_folded(
+        "say hello to my little friend",
+        "little",
+        &options
+    ));
+}
+#[test]
+fn _ends_with_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert!("Hauptstraße"._ends_with_folded("STRASSE", &options));
+}
+#[test]
 fn includes() {
     assert!(voca_rs::query::includes("", "", 0));
     assert!(voca_rs::query::includes("a", "a", 0));
@@ -34,6 +64,73 @@ fn _includes() {
     assert!("abc"._includes("c", 2));
 }
 #[test]
+fn includes_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert!(voca_rs::query::includes_folded("", "", 0, &options));
+    assert!(voca_rs::query::includes_folded(
+        "Strasse", "STRASSE", 0, &options
+    ));
+    assert!(voca_rs::query::includes_folded(
+        "straße", "STRASSE", 0, &options
+    ));
+    assert!(voca_rs::query::includes_folded(
+        "STRASSE", "straße", 0, &options
+    ));
+    assert!(voca_rs::query::includes_folded(
+        "Crème brûlée",
+        "BRULEE",
+        0,
+        &options
+    ));
+    assert!(voca_rs::query::includes_folded(
+        "ＶＯＣＡ ｒｓ",
+        "Voca rs",
+        0,
+        &options
+    ));
+    assert!(voca_rs::query::includes_folded(
+        "ΣΊΣΥΦΟΣ",
+        "σίσυφος",
+        0,
+        &options
+    ));
+    assert!(!voca_rs::query::includes_folded("galaxy", "G", 1, &options));
+    assert!(!voca_rs::query::includes_folded(
+        "galaxy", "g", 10, &options
+    ));
+    let case_only = voca_rs::utils::MatchOptions {
+        ignore_case: true,
+        ..Default::default()
+    };
+    assert!(voca_rs::query::includes_folded(
+        "straße", "STRASSE", 0, &case_only
+    ));
+    assert!(!voca_rs::query::includes_folded(
+        "Crème", "CREME", 0, &case_only
+    ));
+    let diacritics_only = voca_rs::utils::MatchOptions {
+        ignore_diacritics: true,
+        ..Default::default()
+    };
+    assert!(voca_rs::query::includes_folded(
+        "Crème",
+        "Creme",
+        0,
+        &diacritics_only
+    ));
+    assert!(!voca_rs::query::includes_folded(
+        "Crème",
+        "CREME",
+        0,
+        &diacritics_only
+    ));
+}
+#[test]
+fn _includes_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert!("Straße"._includes_folded("STRASSE", 0, &options));
+}
+#[test]
 fn is_alpha() {
     assert!(voca_rs::query::is_alpha("bart"));
     assert!(voca_rs::query::is_alpha("café"));
@@ -266,6 +363,14 @@ fn is_numeric() {
     assert_eq!(voca_rs::query::is_numeric("five"), false);
     assert_eq!(voca_rs::query::is_numeric(".."), false);
     assert_eq!(voca_rs::query::is_numeric(" "), false);
+    assert!(voca_rs::query::is_numeric("3000000000"));
+    assert!(voca_rs::query::is_numeric("1_000"));
+    assert!(voca_rs::query::is_numeric("0b1010"));
+    assert!(voca_rs::query::is_numeric("0o17"));
+    assert!(voca_rs::query::is_numeric("1,234.56"));
+    assert_eq!(voca_rs::query::is_numeric("e5e"), false);
+    assert_eq!(voca_rs::query::is_numeric("1e5e"), false);
+    assert_eq!(voca_rs::query::is_numeric("1,2"), false);
 }
 #[test]
 fn _is_numeric() {
@@ -275,6 +380,38 @@ fn _is_numeric() {
     assert_eq!("0x123z"._is_numeric(), false);
 }
 #[test]
+fn is_numeric_with() {
+    let french = voca_rs::parse::NumberOptions {
+        thousands_separator: Some('\u{202F}'),
+        decimal_separator: ',',
+        ..voca_rs::parse::NumberOptions::default()
+    };
+    assert_eq!(voca_rs::query::is_numeric_with("", &french), true);
+    assert_eq!(
+        voca_rs::query::is_numeric_with("1\u{202F}234,5", &french),
+        true
+    );
+    assert_eq!(voca_rs::query::is_numeric_with("3,14", &french), true);
+    assert_eq!(voca_rs::query::is_numeric_with("1,234.5", &french), false);
+    assert_eq!(voca_rs::query::is_numeric_with("e5e", &french), false);
+    // the numbers too big for f64 are not numeric
+    assert_eq!(voca_rs::query::is_numeric_with("1e400", &french), false);
+    assert_eq!(voca_rs::query::is_numeric_with("-1e400", &french), false);
+    let unicode = voca_rs::parse::NumberOptions {
+        unicode_digits: true,
+        ..voca_rs::parse::NumberOptions::default()
+    };
+    assert_eq!(voca_rs::query::is_numeric_with("٣٫", &unicode), false);
+    assert_eq!(voca_rs::query::is_numeric_with("٣", &unicode), true);
+}
+#[test]
+fn _is_numeric_with() {
+    assert_eq!(
+        "1,000"._is_numeric_with(&voca_rs::parse::NumberOptions::default()),
+        true
+    );
+}
+#[test]
 fn is_pascal_case() {
     assert!(voca_rs::query::is_pascal_case(""));
     assert!(voca_rs::query::is_pascal_case("BirdFlight"));
@@ -469,6 +606,8 @@ fn query() {
     assert_eq!(voca_rs::query::query("the world is yours", "eht", 0), false);
     assert_eq!(voca_rs::query::query("abc", "c", 20), false);
     assert_eq!(voca_rs::query::query("abc", "z", 0), false);
+    assert_eq!(voca_rs::query::query("abc", "a", 3), false);
+    assert_eq!(voca_rs::query::query("abc", "", 3), true);
 }
 #[test]
 fn _query() {
@@ -476,6 +615,64 @@ fn _query() {
     assert_eq!("the world is yours"._query("eht", 0), false);
 }
 #[test]
+fn fuzzy_match() {
+    use voca_rs::split::Unit;
+    let positions = |subject: &str, search: &str, unit: Unit| {
+        voca_rs::query::fuzzy_match(subject, search, unit).map(|m| m.positions)
+    };
+    assert_eq!(positions("", "", Unit::Char), Some(vec![]));
+    assert_eq!(positions("abc", "", Unit::Char), Some(vec![]));
+    assert_eq!(positions("", "a", Unit::Char), None);
+    assert_eq!(positions("ab", "abc", Unit::Char), None);
+    assert_eq!(positions("shipyard", "ys", Unit::Char), None);
+    assert_eq!(
+        positions("the quick brown fox", "quick fox", Unit::Word),
+        Some(vec![1, 3])
+    );
+    assert_eq!(
+        positions("src/main.rs", "smr", Unit::Char),
+        Some(vec![0, 4, 9])
+    );
+    assert_eq!(
+        positions("getElementById", "gebi", Unit::Char),
+        Some(vec![0, 3, 10, 12])
+    );
+    assert_eq!(positions("foo bar", "fb", Unit::Char), Some(vec![0, 4]));
+    assert_eq!(positions("xxaxbxab", "ab", Unit::Char), Some(vec![6, 7]));
+    assert_eq!(
+        positions("Café del Mar", "cdm", Unit::Grapheme),
+        Some(vec![0, 5, 9])
+    );
+    assert_eq!(
+        positions("cafe\u{0301} bar", "e\u{0301}b", Unit::Grapheme),
+        Some(vec![3, 5])
+    );
+    assert_eq!(
+        positions("cafe\u{0301} bar", "eb", Unit::Char),
+        Some(vec![3, 6])
+    );
+    // smart case
+    assert_eq!(positions("FooBar", "fb", Unit::Char), Some(vec![0, 3]));
+    assert_eq!(positions("foobar", "fB", Unit::Char), None);
+    assert_eq!(positions("fooBar", "fB", Unit::Char), Some(vec![0, 3]));
+    let boundary = voca_rs::query::fuzzy_match("foo_bar", "fb", Unit::Char).unwrap();
+    let inside = voca_rs::query::fuzzy_match("foobar", "fb", Unit::Char).unwrap();
+    assert!(boundary.score > inside.score);
+    let consecutive = voca_rs::query::fuzzy_match("abcx", "abc", Unit::Char).unwrap();
+    let gapped = voca_rs::query::fuzzy_match("axbxc", "abc", Unit::Char).unwrap();
+    assert!(consecutive.score > gapped.score);
+}
+#[test]
+fn _fuzzy_match() {
+    assert_eq!(
+        "src/main.rs"
+            ._fuzzy_match("smr", voca_rs::split::Unit::Char)
+            .unwrap()
+            .positions,
+        [0, 4, 9]
+    );
+}
+#[test]
 fn starts_with() {
     assert!(voca_rs::query::starts_with(
         "the world is yours",
@@ -494,3 +691,275 @@ fn _starts_with() {
     assert!("the world is yours"._starts_with("the world"));
     assert_eq!("the world is yours"._starts_with("s"), false);
 }
+#[test]
+fn starts_with_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert!(voca_rs::query::starts_with_folded("", "", &options));
+    assert!(voca_rs::query::starts_with_folded(
+        "Ärger im Paradies",
+        "arger",
+        &options
+    ));
+    assert!(voca_rs::query::starts_with_folded(
+        "ＡＢＣ",
+        "abc",
+        &options
+    ));
+    assert!(!voca_rs::query::starts_with_folded(
+        "say hello to my little friend",
+        "hello",
+        &options
+    ));
+}
+#[test]
+fn _starts_with_folded() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert!("Ärger im Paradies"._starts_with_folded("arger", &options));
+}
+#[test]
+fn has_mixed_line_endings() {
+    assert!(!voca_rs::query::has_mixed_line_endings(""));
+    assert!(!voca_rs::query::has_mixed_line_endings("one"));
+    assert!(!voca_rs::query::has_mixed_line_endings("one\r\ntwo\r\n"));
+    assert!(!voca_rs::query::has_mixed_line_endings("one\rtwo\r"));
+    assert!(voca_rs::query::has_mixed_line_endings("one\r\ntwo\nthree"));
+    assert!(voca_rs::query::has_mixed_line_endings("one\rtwo\n"));
+}
+#[test]
+fn _has_mixed_line_endings() {
+    assert!("one\r\ntwo\nthree"._has_mixed_line_endings());
+}
+#[test]
+fn line_ending() {
+    use voca_rs::split::LineEnding;
+    assert_eq!(voca_rs::query::line_ending(""), None);
+    assert_eq!(voca_rs::query::line_ending("one"), None);
+    assert_eq!(voca_rs::query::line_ending("one\n"), Some(LineEnding::Lf));
+    assert_eq!(
+        voca_rs::query::line_ending("one\r\ntwo\r\n"),
+        Some(LineEnding::CrLf)
+    );
+    assert_eq!(
+        voca_rs::query::line_ending("one\rtwo"),
+        Some(LineEnding::Cr)
+    );
+    assert_eq!(
+        voca_rs::query::line_ending("one\ntwo\r\nthree\n"),
+        Some(LineEnding::Lf)
+    );
+    assert_eq!(
+        voca_rs::query::line_ending("one\r\ntwo\nthree"),
+        Some(LineEnding::CrLf)
+    );
+}
+#[test]
+fn _line_ending() {
+    assert_eq!(
+        "one\r\ntwo"._line_ending(),
+        Some(voca_rs::split::LineEnding::CrLf)
+    );
+}
+#[test]
+fn scripts() {
+    use voca_rs::query::Script;
+    assert_eq!(voca_rs::query::scripts(""), []);
+    assert_eq!(voca_rs::query::scripts("1 + 2 = 3!"), []);
+    assert_eq!(
+        voca_rs::query::scripts("Hello, мир!"),
+        [Script::Latin, Script::Cyrillic]
+    );
+    assert_eq!(
+        voca_rs::query::scripts("раypal"),
+        [Script::Cyrillic, Script::Latin]
+    );
+    assert_eq!(
+        voca_rs::query::scripts("日本語のテキスト"),
+        [Script::Han, Script::Hiragana, Script::Katakana]
+    );
+    assert_eq!(
+        voca_rs::query::scripts("مرحبا، שלום"),
+        [Script::Arabic, Script::Hebrew]
+    );
+    assert_eq!(voca_rs::query::scripts("cafe\u{0301}"), [Script::Latin]);
+    assert_eq!(Script::OldItalic.as_str(), "Old_Italic");
+}
+#[test]
+fn _scripts() {
+    use voca_rs::query::Script;
+    assert_eq!("Hello, мир!"._scripts(), [Script::Latin, Script::Cyrillic]);
+}
+#[test]
+fn dominant_script() {
+    use voca_rs::query::Script;
+    assert_eq!(voca_rs::query::dominant_script(""), None);
+    assert_eq!(voca_rs::query::dominant_script("42 !"), None);
+    assert_eq!(
+        voca_rs::query::dominant_script("раypal"),
+        Some(Script::Latin)
+    );
+    assert_eq!(
+        voca_rs::query::dominant_script("Привет, world"),
+        Some(Script::Cyrillic)
+    );
+    assert_eq!(
+        voca_rs::query::dominant_script("ab αβ"),
+        Some(Script::Latin)
+    );
+    assert_eq!(
+        voca_rs::query::dominant_script("東京タワー"),
+        Some(Script::Han)
+    );
+}
+#[test]
+fn _dominant_script() {
+    use voca_rs::query::Script;
+    assert_eq!("раypal"._dominant_script(), Some(Script::Latin));
+}
+#[test]
+fn is_single_script() {
+    assert!(voca_rs::query::is_single_script(""));
+    assert!(voca_rs::query::is_single_script("12:30"));
+    assert!(voca_rs::query::is_single_script("paypal"));
+    assert!(voca_rs::query::is_single_script("Hello, world!"));
+    assert!(!voca_rs::query::is_single_script("раypal"));
+    assert!(!voca_rs::query::is_single_script("abc αβγ"));
+    assert!(voca_rs::query::is_single_script("日本語のテキスト"));
+    assert!(voca_rs::query::is_single_script("カー"));
+    assert!(!voca_rs::query::is_single_script("aー"));
+    assert!(voca_rs::query::is_single_script("한국어 漢字"));
+    assert!(!voca_rs::query::is_single_script("のは한국"));
+    assert!(voca_rs::query::is_single_script("سلام، دنیا"));
+}
+#[test]
+fn _is_single_script() {
+    assert!(!"раypal"._is_single_script());
+}
+#[test]
+fn filter_by_category() {
+    use voca_rs::query::GeneralCategory;
+    assert_eq!(
+        voca_rs::query::filter_by_category("", &[GeneralCategory::Letter]),
+        ""
+    );
+    assert_eq!(voca_rs::query::filter_by_category("abc", &[]), "");
+    assert_eq!(
+        voca_rs::query::filter_by_category("R2-D2 & C-3PO!", &[GeneralCategory::Letter]),
+        "RDCPO"
+    );
+    assert_eq!(
+        voca_rs::query::filter_by_category(
+            "R2-D2 & C-3PO!",
+            &[GeneralCategory::Number, GeneralCategory::Punctuation]
+        ),
+        "2-2&-3!"
+    );
+    assert_eq!(
+        voca_rs::query::filter_by_category("Price: 12 €", &[GeneralCategory::Symbol]),
+        "€"
+    );
+    assert_eq!(
+        voca_rs::query::filter_by_category("a b\u{00A0}c", &[GeneralCategory::Separator]),
+        " \u{00A0}"
+    );
+    assert_eq!(
+        voca_rs::query::filter_by_category("cafe\u{0301}", &[GeneralCategory::Mark]),
+        "\u{0301}"
+    );
+    assert_eq!(
+        voca_rs::query::filter_by_category("½ Ⅻ ٣", &[GeneralCategory::Number]),
+        "½Ⅻ٣"
+    );
+    assert_eq!(
+        voca_rs::query::filter_by_category("a\u{200B}b\tc", &[GeneralCategory::Other]),
+        "\u{200B}\t"
+    );
+}
+#[test]
+fn _filter_by_category() {
+    use voca_rs::query::GeneralCategory;
+    assert_eq!(
+        "R2-D2"._filter_by_category(&[GeneralCategory::Letter]),
+        "RD"
+    );
+}
+#[test]
+fn has_bidi_controls() {
+    assert!(!voca_rs::query::has_bidi_controls(""));
+    assert!(!voca_rs::query::has_bidi_controls("access level"));
+    assert!(!voca_rs::query::has_bidi_controls("שלום world"));
+    assert!(voca_rs::query::has_bidi_controls("access\u{202E}level"));
+    assert!(voca_rs::query::has_bidi_controls(
+        "user\u{2067}name\u{2069}"
+    ));
+    assert!(voca_rs::query::has_bidi_controls("a\u{200F}"));
+    assert!(voca_rs::query::has_bidi_controls("\u{061C}"));
+}
+#[test]
+fn _has_bidi_controls() {
+    assert!("access\u{202E}level"._has_bidi_controls());
+}
+#[test]
+fn base_direction() {
+    use voca_rs::query::Direction;
+    assert_eq!(voca_rs::query::base_direction(""), None);
+    assert_eq!(voca_rs::query::base_direction("123!"), None);
+    assert_eq!(
+        voca_rs::query::base_direction("Hello, שלום"),
+        Some(Direction::LeftToRight)
+    );
+    assert_eq!(
+        voca_rs::query::base_direction("123 مرحبا world"),
+        Some(Direction::RightToLeft)
+    );
+    assert_eq!(
+        voca_rs::query::base_direction("Привет"),
+        Some(Direction::LeftToRight)
+    );
+    assert_eq!(
+        voca_rs::query::base_direction("\u{2068}שלום\u{2069} commented"),
+        Some(Direction::LeftToRight)
+    );
+    assert_eq!(voca_rs::query::base_direction("\u{2067}שלום"), None);
+    assert_eq!(
+        voca_rs::query::base_direction("\u{200F}abc"),
+        Some(Direction::RightToLeft)
+    );
+    assert_eq!(
+        voca_rs::query::base_direction("\u{0301}שלום"),
+        Some(Direction::RightToLeft)
+    );
+    assert_eq!(voca_rs::query::base_direction("42\nשלום"), None);
+    assert_eq!(
+        voca_rs::query::base_direction("\u{05BE}abc"),
+        Some(Direction::RightToLeft)
+    );
+    assert_eq!(
+        voca_rs::query::base_direction("\u{00AA}שלום"),
+        Some(Direction::LeftToRight)
+    );
+}
+#[test]
+fn _base_direction() {
+    use voca_rs::query::Direction;
+    assert_eq!("שלום"._base_direction(), Some(Direction::RightToLeft));
+}
+#[test]
+fn is_emoji() {
+    assert!(!voca_rs::query::is_emoji(""));
+    assert!(voca_rs::query::is_emoji("😄"));
+    assert!(voca_rs::query::is_emoji("👍🏽"));
+    assert!(voca_rs::query::is_emoji("👨‍👩‍👧"));
+    assert!(voca_rs::query::is_emoji("🇺🇦"));
+    assert!(voca_rs::query::is_emoji("1️⃣"));
+    assert!(voca_rs::query::is_emoji("❤️"));
+    assert!(voca_rs::query::is_emoji("🚀🎉"));
+    assert!(!voca_rs::query::is_emoji("1"));
+    assert!(!voca_rs::query::is_emoji("#"));
+    assert!(!voca_rs::query::is_emoji("©"));
+    assert!(!voca_rs::query::is_emoji("❤︎"));
+    assert!(!voca_rs::query::is_emoji("ok 👌"));
+}
+#[test]
+fn _is_emoji() {
+    assert!("❤️"._is_emoji());
+}
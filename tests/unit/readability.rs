@@ -0,0 +1,137 @@
+//! voca_rs::readability testing
+use voca_rs::Voca;
+
+const EASY: &str = "The cat sat on the mat.";
+const HARD: &str = "Existing computational methodologies demonstrate considerable variability. \
+                    Nevertheless, researchers continue investigating alternatives.";
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "{} is not {}",
+        actual,
+        expected
+    );
+}
+
+#[test]
+fn syllables() {
+    assert_eq!(voca_rs::readability::syllables(""), 0);
+    assert_eq!(voca_rs::readability::syllables("42"), 0);
+    assert_eq!(voca_rs::readability::syllables("the"), 1);
+    assert_eq!(voca_rs::readability::syllables("readability"), 5);
+    assert_eq!(voca_rs::readability::syllables("syllable"), 3);
+    assert_eq!(voca_rs::readability::syllables("table"), 2);
+    assert_eq!(voca_rs::readability::syllables("whole"), 1);
+    assert_eq!(voca_rs::readability::syllables("jumped"), 1);
+    assert_eq!(voca_rs::readability::syllables("wanted"), 2);
+    assert_eq!(voca_rs::readability::syllables("makes"), 1);
+    assert_eq!(voca_rs::readability::syllables("boxes"), 2);
+    assert_eq!(voca_rs::readability::syllables("piano"), 3);
+    assert_eq!(voca_rs::readability::syllables("nation"), 2);
+    assert_eq!(voca_rs::readability::syllables("yellow"), 2);
+    assert_eq!(voca_rs::readability::syllables("free"), 1);
+    assert_eq!(voca_rs::readability::syllables("Beautiful"), 3);
+    assert_eq!(voca_rs::readability::syllables("Zürich"), 2);
+    // the exception dictionary
+    assert_eq!(voca_rs::readability::syllables("people"), 2);
+    assert_eq!(voca_rs::readability::syllables("every"), 2);
+    assert_eq!(voca_rs::readability::syllables("Create"), 2);
+}
+#[test]
+fn _syllables() {
+    assert_eq!("syllable"._syllables(), 3);
+}
+#[test]
+fn flesch_reading_ease() {
+    assert_eq!(voca_rs::readability::flesch_reading_ease(""), 0.0);
+    assert_eq!(voca_rs::readability::flesch_reading_ease("..."), 0.0);
+    assert_close(
+        voca_rs::readability::flesch_reading_ease(EASY),
+        116.14500000000001,
+    );
+    assert_close(
+        voca_rs::readability::flesch_reading_ease(HARD),
+        -152.52931818181816,
+    );
+    // the numbers are not words, the case changes do not split the words but the hyphens do
+    assert_eq!(voca_rs::readability::flesch_reading_ease("42."), 0.0);
+    assert_close(
+        voca_rs::readability::flesch_reading_ease("The 2 cats sat on the 10 mats."),
+        voca_rs::readability::flesch_reading_ease("The cats sat on the mats."),
+    );
+    assert_close(
+        voca_rs::readability::flesch_reading_ease("An iPhone case."),
+        voca_rs::readability::flesch_reading_ease("An iphone case."),
+    );
+    assert_close(
+        voca_rs::readability::flesch_reading_ease("A well-known cat."),
+        voca_rs::readability::flesch_reading_ease("A well known cat."),
+    );
+}
+#[test]
+fn _flesch_reading_ease() {
+    assert_close(EASY._flesch_reading_ease(), 116.14500000000001);
+}
+#[test]
+fn flesch_kincaid_grade() {
+    assert_eq!(voca_rs::readability::flesch_kincaid_grade(""), 0.0);
+    assert_close(
+        voca_rs::readability::flesch_kincaid_grade(EASY),
+        -1.4499999999999993,
+    );
+    assert_close(
+        voca_rs::readability::flesch_kincaid_grade(HARD),
+        35.90045454545455,
+    );
+}
+#[test]
+fn _flesch_kincaid_grade() {
+    assert_close(EASY._flesch_kincaid_grade(), -1.4499999999999993);
+}
+#[test]
+fn gunning_fog() {
+    assert_eq!(voca_rs::readability::gunning_fog(""), 0.0);
+    assert_close(voca_rs::readability::gunning_fog(EASY), 2.4000000000000004);
+    assert_close(voca_rs::readability::gunning_fog(HARD), 42.2);
+}
+#[test]
+fn _gunning_fog() {
+    assert_close(EASY._gunning_fog(), 2.4000000000000004);
+}
+#[test]
+fn smog() {
+    assert_eq!(voca_rs::readability::smog(""), 0.0);
+    assert_close(voca_rs::readability::smog(EASY), 3.1291);
+    assert_close(voca_rs::readability::smog(HARD), 16.52667757954773);
+}
+#[test]
+fn _smog() {
+    assert_close(EASY._smog(), 3.1291);
+}
+#[test]
+fn coleman_liau() {
+    assert_eq!(voca_rs::readability::coleman_liau(""), 0.0);
+    assert_close(voca_rs::readability::coleman_liau(EASY), -4.073333333333334);
+    assert_close(voca_rs::readability::coleman_liau(HARD), 45.101818181818174);
+}
+#[test]
+fn _coleman_liau() {
+    assert_close(EASY._coleman_liau(), -4.073333333333334);
+}
+#[test]
+fn automated_readability_index() {
+    assert_eq!(voca_rs::readability::automated_readability_index(""), 0.0);
+    assert_close(
+        voca_rs::readability::automated_readability_index(EASY),
+        -5.085000000000001,
+    );
+    assert_close(
+        voca_rs::readability::automated_readability_index(HARD),
+        34.414545454545454,
+    );
+}
+#[test]
+fn _automated_readability_index() {
+    assert_close(EASY._automated_readability_index(), -5.085000000000001);
+}
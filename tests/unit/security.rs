@@ -0,0 +1,114 @@
+//! voca_rs::security testing
+use voca_rs::security::RestrictionLevel;
+use voca_rs::Voca;
+
+#[test]
+fn skeleton() {
+    assert_eq!(voca_rs::security::skeleton(""), "");
+    assert_eq!(voca_rs::security::skeleton("paypal"), "paypal");
+    assert_eq!(voca_rs::security::skeleton("раypal"), "paypal");
+    assert_eq!(voca_rs::security::skeleton("раураl"), "paypal");
+    assert_eq!(voca_rs::security::skeleton("microsoft"), "rnicrosoft");
+    assert_eq!(voca_rs::security::skeleton("Il1|"), "llll");
+    assert_eq!(voca_rs::security::skeleton("G00GLE"), "GOOGLE");
+    assert_eq!(voca_rs::security::skeleton("ＧＯＯＧＬＥ"), "ＧOOＧＬE");
+    assert_eq!(voca_rs::security::skeleton("admin1"), "adrninl");
+    assert_eq!(voca_rs::security::skeleton("ⅿ"), "rn");
+    assert_eq!(voca_rs::security::skeleton("Ⅸ"), "lX");
+    assert_eq!(voca_rs::security::skeleton("ﬁ"), "fi");
+    assert_eq!(voca_rs::security::skeleton("ℌe1\u{200B}1o"), "Hello");
+    assert_eq!(voca_rs::security::skeleton("é"), "e\u{0301}");
+    assert_eq!(voca_rs::security::skeleton("e\u{0301}"), "e\u{0301}");
+    assert_eq!(voca_rs::security::skeleton("50%"), "5O\u{00BA}/\u{2080}");
+    assert_eq!(voca_rs::security::skeleton("ΑΒΕΗ"), "ABEH");
+    assert_eq!(voca_rs::security::skeleton("pay\u{200B}pal"), "paypal");
+    assert_eq!(voca_rs::security::skeleton("a\u{00A0}b–c’d"), "a b-c'd");
+    assert_eq!(voca_rs::security::skeleton("日本語"), "日本語");
+}
+#[test]
+fn _skeleton() {
+    assert_eq!("раypal"._skeleton(), "paypal");
+}
+#[test]
+fn is_confusable() {
+    assert!(voca_rs::security::is_confusable("", ""));
+    assert!(voca_rs::security::is_confusable("paypal", "paypal"));
+    assert!(voca_rs::security::is_confusable("раypal", "paypal"));
+    assert!(voca_rs::security::is_confusable("rnicrosoft", "microsoft"));
+    assert!(voca_rs::security::is_confusable("admin1", "adminl"));
+    assert!(voca_rs::security::is_confusable("ѕсоре", "scope"));
+    assert!(voca_rs::security::is_confusable("café", "cafe\u{0301}"));
+    assert!(voca_rs::security::is_confusable("ⅿ", "m"));
+    assert!(!voca_rs::security::is_confusable("paypal", "PayPal"));
+    assert!(!voca_rs::security::is_confusable("paypal", "paypa"));
+}
+#[test]
+fn _is_confusable() {
+    assert!("раypal"._is_confusable("paypal"));
+}
+#[test]
+fn is_mixed_script() {
+    assert!(!voca_rs::security::is_mixed_script(""));
+    assert!(!voca_rs::security::is_mixed_script("paypal"));
+    assert!(!voca_rs::security::is_mixed_script("Ελληνικά"));
+    assert!(!voca_rs::security::is_mixed_script("日本語のテキスト"));
+    assert!(voca_rs::security::is_mixed_script("раypal"));
+    assert!(voca_rs::security::is_mixed_script("Tokyo東京"));
+}
+#[test]
+fn _is_mixed_script() {
+    assert!("раypal"._is_mixed_script());
+}
+#[test]
+fn restriction_level() {
+    assert_eq!(
+        voca_rs::security::restriction_level(""),
+        RestrictionLevel::Ascii
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("paypal_42"),
+        RestrictionLevel::Ascii
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("café"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("Ελληνικά"),
+        RestrictionLevel::SingleScript
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("Tokyo東京"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("Seoul서울"),
+        RestrictionLevel::HighlyRestrictive
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("shalomשלום"),
+        RestrictionLevel::ModeratelyRestrictive
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("раypal"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("alphaαβ"),
+        RestrictionLevel::MinimallyRestrictive
+    );
+    assert_eq!(
+        voca_rs::security::restriction_level("ᚠᚢᚦ"),
+        RestrictionLevel::Unrestricted
+    );
+    assert!(
+        voca_rs::security::restriction_level("раypal") > RestrictionLevel::ModeratelyRestrictive
+    );
+}
+#[test]
+fn _restriction_level() {
+    assert_eq!(
+        "раypal"._restriction_level(),
+        RestrictionLevel::MinimallyRestrictive
+    );
+}
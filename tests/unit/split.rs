@@ -18,6 +18,28 @@ fn _to_chars() {
     assert_eq!("gravity"._chars(), ["g", "r", "a", "v", "i", "t", "y"]);
 }
 #[test]
+fn chars_iter() {
+    assert_eq!(voca_rs::split::chars_iter("").count(), 0);
+    assert_eq!(
+        voca_rs::split::chars_iter("gravity")
+            .take(3)
+            .collect::<Vec<_>>(),
+        ["g", "r", "a"]
+    );
+    assert_eq!(
+        voca_rs::split::chars_iter("błąd").rev().collect::<Vec<_>>(),
+        ["d", "ą", "ł", "b"]
+    );
+    assert_eq!(
+        voca_rs::split::chars_iter("e\u{0301}").collect::<Vec<_>>(),
+        ["e", "\u{0301}"]
+    );
+}
+#[test]
+fn _chars_iter() {
+    assert_eq!("cloud"._chars_iter().next(), Some("c"));
+}
+#[test]
 fn by_pattern() {
     assert_eq!(
         voca_rs::split::split("gravity can cross dimensions", " "),
@@ -39,6 +61,261 @@ fn _by_pattern() {
     );
 }
 #[test]
+fn split_iter() {
+    assert_eq!(voca_rs::split::split_iter("", " ").count(), 0);
+    assert_eq!(voca_rs::split::split_iter("", "").count(), 0);
+    assert_eq!(
+        voca_rs::split::split_iter("dying star", "").collect::<Vec<_>>(),
+        ["dying star"]
+    );
+    assert_eq!(
+        voca_rs::split::split_iter("*dying*star*", "*").collect::<Vec<_>>(),
+        ["", "dying", "star"]
+    );
+    let pattern = String::from(" ");
+    assert_eq!(
+        voca_rs::split::split_iter("rage against the light", &pattern).nth(1),
+        Some("against")
+    );
+}
+#[test]
+fn _split_iter() {
+    assert_eq!(
+        "rage against the light"._split_iter(" ").last(),
+        Some("light")
+    );
+}
+#[test]
+fn splitn() {
+    assert_eq!(voca_rs::split::splitn("", " ", 2), [""]);
+    assert!(voca_rs::split::splitn("a b", " ", 0).is_empty());
+    assert_eq!(voca_rs::split::splitn("a b", "", 2), ["a b"]);
+    assert_eq!(
+        voca_rs::split::splitn("rage against the dying of the light", " ", 3),
+        ["rage", "against", "the dying of the light"]
+    );
+    assert_eq!(voca_rs::split::splitn("a,b,", ",", 5), ["a", "b", ""]);
+    assert_eq!(
+        voca_rs::split::splitn("ключ→значение→ещё", "→", 2),
+        ["ключ", "значение→ещё"]
+    );
+}
+#[test]
+fn _splitn() {
+    assert_eq!("key=value=more"._splitn("=", 2), ["key", "value=more"]);
+}
+#[test]
+fn rsplitn() {
+    assert_eq!(voca_rs::split::rsplitn("", " ", 2), [""]);
+    assert!(voca_rs::split::rsplitn("a b", " ", 0).is_empty());
+    assert_eq!(
+        voca_rs::split::rsplitn("rage against the dying of the light", " ", 3),
+        ["rage against the dying of", "the", "light"]
+    );
+    assert_eq!(
+        voca_rs::split::rsplitn("archive.tar.gz", ".", 2),
+        ["archive.tar", "gz"]
+    );
+    assert_eq!(voca_rs::split::rsplitn("a.b", ".", 5), ["a", "b"]);
+}
+#[test]
+fn _rsplitn() {
+    assert_eq!("archive.tar.gz"._rsplitn(".", 2), ["archive.tar", "gz"]);
+}
+#[test]
+fn split_regex() {
+    assert_eq!(voca_rs::split::split_regex("", ","), [""]);
+    assert_eq!(
+        voca_rs::split::split_regex("one, two;three", r"[,;]\s*"),
+        ["one", "two", "three"]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex("1+2-3", r"([+-])"),
+        ["1", "+", "2", "-", "3"]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex("a1b", r"(\d)|(x)"),
+        ["a", "1", "", "b"]
+    );
+    assert_eq!(voca_rs::split::split_regex("abc", ""), ["a", "b", "c"]);
+    assert_eq!(
+        voca_rs::split::split_regex("żółw", "x*"),
+        ["ż", "ó", "ł", "w"]
+    );
+    assert_eq!(voca_rs::split::split_regex(",a,", ","), ["", "a", ""]);
+    assert_eq!(voca_rs::split::split_regex("a(b", "("), ["a(b"]);
+}
+#[test]
+fn _split_regex() {
+    assert_eq!(
+        "one, two;three"._split_regex(r"[,;]\s*"),
+        ["one", "two", "three"]
+    );
+}
+#[test]
+fn split_regex_with() {
+    use voca_rs::split::{Delimiters, SplitRegexOptions};
+    let options = |limit, delimiters| SplitRegexOptions { limit, delimiters };
+    assert!(
+        voca_rs::split::split_regex_with("a1b", r"\d", &options(Some(0), Delimiters::Drop))
+            .is_empty()
+    );
+    assert_eq!(
+        voca_rs::split::split_regex_with("a1b22c333d", r"\d+", &options(Some(3), Delimiters::Drop)),
+        ["a", "b", "c333d"]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex_with(
+            "a1b22c333d",
+            r"\d+",
+            &options(Some(3), Delimiters::Separate)
+        ),
+        ["a", "1", "b", "22", "c333d"]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex_with(
+            "Hi! How are you? Fine.",
+            r"[!?.]\s*",
+            &options(None, Delimiters::AttachToPrevious)
+        ),
+        ["Hi! ", "How are you? ", "Fine.", ""]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex_with(
+            "a1b22c",
+            r"\d+",
+            &options(None, Delimiters::AttachToNext)
+        ),
+        ["a", "1b", "22c"]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex_with("1+2", r"([+-])", &options(None, Delimiters::Separate)),
+        ["1", "+", "2"]
+    );
+    assert_eq!(
+        voca_rs::split::split_regex_with("a,b,c", ",", &options(Some(1), Delimiters::Separate)),
+        ["a,b,c"]
+    );
+}
+#[test]
+fn _split_regex_with() {
+    assert_eq!(
+        "a1b22c"._split_regex_with(
+            r"\d+",
+            &voca_rs::split::SplitRegexOptions {
+                limit: None,
+                delimiters: voca_rs::split::Delimiters::AttachToNext,
+            }
+        ),
+        ["a", "1b", "22c"]
+    );
+}
+#[test]
+fn shell_words() {
+    use std::borrow::Cow;
+    use voca_rs::split::SplitError;
+    assert_eq!(voca_rs::split::shell_words(""), Ok(vec![]));
+    assert_eq!(voca_rs::split::shell_words("  \t "), Ok(vec![]));
+    assert_eq!(
+        voca_rs::split::shell_words(r#"a "b c" 'd e' f\ g"#).unwrap(),
+        ["a", "b c", "d e", "f g"]
+    );
+    assert_eq!(
+        voca_rs::split::shell_words(r#"echo "it's \"fine\"""#).unwrap(),
+        ["echo", "it's \"fine\""]
+    );
+    assert_eq!(
+        voca_rs::split::shell_words(r#"'a\b' "c\d" "e\\f" g\\h"#).unwrap(),
+        [r"a\b", r"c\d", r"e\f", r"g\h"]
+    );
+    assert_eq!(
+        voca_rs::split::shell_words("pre\"fix\"'ed' '' \"\"").unwrap(),
+        ["prefixed", "", ""]
+    );
+    assert_eq!(
+        voca_rs::split::shell_words("one \\\n two").unwrap(),
+        ["one", "two"]
+    );
+    assert_eq!(
+        voca_rs::split::shell_words("grüße 'aus Köln'").unwrap(),
+        ["grüße", "aus Köln"]
+    );
+    let words = voca_rs::split::shell_words("plain 'quoted'").unwrap();
+    assert!(matches!(words[0], Cow::Borrowed("plain")));
+    assert!(matches!(words[1], Cow::Owned(_)));
+    assert_eq!(
+        voca_rs::split::shell_words("echo 'oops"),
+        Err(SplitError::UnbalancedQuote(5))
+    );
+    assert_eq!(
+        voca_rs::split::shell_words(r#"echo "oops\""#),
+        Err(SplitError::UnbalancedQuote(5))
+    );
+    assert_eq!(
+        voca_rs::split::shell_words("oops\\"),
+        Err(SplitError::DanglingEscape(4))
+    );
+    assert_eq!(
+        SplitError::UnbalancedQuote(5).to_string(),
+        "unbalanced quote at 5"
+    );
+}
+#[test]
+fn _shell_words() {
+    assert_eq!(r#"a "b c""#._shell_words().unwrap(), ["a", "b c"]);
+}
+#[test]
+fn split_quoted() {
+    use voca_rs::split::{SplitError, SplitOptions};
+    let csv = SplitOptions::default();
+    assert_eq!(voca_rs::split::split_quoted("", &csv), Ok(vec![]));
+    assert_eq!(
+        voca_rs::split::split_quoted(r#"one,"two, three",four\,five"#, &csv).unwrap(),
+        ["one", "two, three", "four,five"]
+    );
+    assert_eq!(
+        voca_rs::split::split_quoted(",a,,b,", &csv).unwrap(),
+        ["", "a", "", "b", ""]
+    );
+    assert_eq!(
+        voca_rs::split::split_quoted(r#"'it\'s',"say \"hi\"""#, &csv).unwrap(),
+        ["it's", "say \"hi\""]
+    );
+    let semicolons = SplitOptions {
+        delimiter: Some(';'),
+        quotes: vec!['\''],
+        escape: None,
+    };
+    assert_eq!(
+        voca_rs::split::split_quoted(r#"a;;'b;c';"d;e""#, &semicolons).unwrap(),
+        ["a", "", "b;c", "\"d", "e\""]
+    );
+    let words = SplitOptions {
+        delimiter: None,
+        quotes: vec!['«', '"'],
+        escape: Some('^'),
+    };
+    assert_eq!(
+        voca_rs::split::split_quoted("  «a b«  c^ d  ", &words).unwrap(),
+        ["a b", "c d"]
+    );
+    assert_eq!(
+        voca_rs::split::split_quoted("a,\"b", &csv),
+        Err(SplitError::UnbalancedQuote(2))
+    );
+    assert_eq!(
+        voca_rs::split::split_quoted("a,b\\", &csv),
+        Err(SplitError::DanglingEscape(3))
+    );
+}
+#[test]
+fn _split_quoted() {
+    assert_eq!(
+        r#"one,"two, three""#._split_quoted(&voca_rs::split::SplitOptions::default()).unwrap(),
+        ["one", "two, three"]
+    );
+}
+#[test]
 fn words() {
     assert_eq!(
         voca_rs::split::words("gravity can cross dimensions"),
@@ -73,6 +350,110 @@ fn _words() {
     );
 }
 #[test]
+fn words_iter() {
+    assert_eq!(voca_rs::split::words_iter("").count(), 0);
+    assert_eq!(
+        voca_rs::split::words_iter("LazyLoad with XMLHttpRequest")
+            .take(3)
+            .collect::<Vec<_>>(),
+        ["Lazy", "Load", "with"]
+    );
+    assert_eq!(
+        voca_rs::split::words_iter("snake_case XMLHttp")
+            .rev()
+            .collect::<Vec<_>>(),
+        ["Http", "XML", "case", "snake"]
+    );
+}
+#[test]
+fn _words_iter() {
+    assert_eq!("Sześć звёзд are dying"._words_iter().next(), Some("Sześć"));
+}
+#[test]
+fn word_bounds() {
+    use voca_rs::split::{WordBound, WordKind};
+    assert!(voca_rs::split::word_bounds("").is_empty());
+    let texts = |subject| {
+        voca_rs::split::word_bounds(subject)
+            .into_iter()
+            .map(|bound| bound.text)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        texts("My iPhone costs $3.50"),
+        ["My", " ", "iPhone", " ", "costs", " ", "$", "3.50"]
+    );
+    assert_eq!(texts("e-mail"), ["e", "-", "mail"]);
+    assert_eq!(texts("snake_case can't"), ["snake_case", " ", "can't"]);
+    assert_eq!(
+        voca_rs::split::word_bounds("can't stop")[0],
+        WordBound {
+            text: "can't",
+            range: 0..5,
+            kind: WordKind::Word,
+        }
+    );
+    let kinds = voca_rs::split::word_bounds("Ünïcode  42, ok?")
+        .into_iter()
+        .map(|bound| (bound.range, bound.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        [
+            (0..9, WordKind::Word),
+            (9..11, WordKind::Space),
+            (11..13, WordKind::Number),
+            (13..14, WordKind::Punctuation),
+            (14..15, WordKind::Space),
+            (15..17, WordKind::Word),
+            (17..18, WordKind::Punctuation),
+        ]
+    );
+}
+#[test]
+fn _word_bounds() {
+    assert_eq!(
+        "can't stop"._word_bounds()[1].kind,
+        voca_rs::split::WordKind::Space
+    );
+}
+#[test]
+fn sentences() {
+    assert!(voca_rs::split::sentences("").is_empty());
+    assert!(voca_rs::split::sentences("  ").is_empty());
+    assert_eq!(
+        voca_rs::split::sentences("Hello world! How are you? I'm fine."),
+        ["Hello world!", "How are you?", "I'm fine."]
+    );
+    assert_eq!(
+        voca_rs::split::sentences("Mr. Smith met Dr. Watson. They talked."),
+        ["Mr. Smith met Dr. Watson.", "They talked."]
+    );
+    assert_eq!(
+        voca_rs::split::sentences("J. R. R. Tolkien wrote it. It is long"),
+        ["J. R. R. Tolkien wrote it.", "It is long"]
+    );
+    assert_eq!(
+        voca_rs::split::sentences("Pi is 3.14 e.g. in math. Yes."),
+        ["Pi is 3.14 e.g. in math.", "Yes."]
+    );
+    assert_eq!(
+        voca_rs::split::sentences("First line\n\nSecond line"),
+        ["First line", "Second line"]
+    );
+    assert_eq!(
+        voca_rs::split::sentences("Он пришёл. Она ушла."),
+        ["Он пришёл.", "Она ушла."]
+    );
+}
+#[test]
+fn _sentences() {
+    assert_eq!(
+        "Hello world! How are you?"._sentences(),
+        ["Hello world!", "How are you?"]
+    );
+}
+#[test]
 fn to_graphemes() {
     assert_eq!(
         voca_rs::split::graphemes("a̐éö̲\r\n"),
@@ -85,6 +466,259 @@ fn _to_graphemes() {
     assert_eq!("a̐éö̲\r\n"._graphemes(), ["a̐", "é", "ö̲", "\r\n"]);
 }
 #[test]
+fn graphemes_iter() {
+    assert_eq!(voca_rs::split::graphemes_iter("").count(), 0);
+    assert_eq!(
+        voca_rs::split::graphemes_iter("a̐éö̲\r\n").nth(3),
+        Some("\r\n")
+    );
+    assert_eq!(
+        voca_rs::split::graphemes_iter("a̐éö̲")
+            .rev()
+            .collect::<Vec<_>>(),
+        ["ö̲", "é", "a̐"]
+    );
+}
+#[test]
+fn _graphemes_iter() {
+    assert_eq!("a̐éö̲"._graphemes_iter().count(), 3);
+}
+#[test]
+fn chunk() {
+    assert!(voca_rs::split::chunk("", 3).is_empty());
+    assert_eq!(voca_rs::split::chunk("rain", 0), ["rain"]);
+    assert_eq!(
+        voca_rs::split::chunk("4111111111111111", 4),
+        ["4111", "1111", "1111", "1111"]
+    );
+    assert_eq!(voca_rs::split::chunk("a̐éö̲xy", 2), ["a̐é", "ö̲x", "y"]);
+    assert_eq!(voca_rs::split::chunk("rain", 10), ["rain"]);
+    assert_eq!(voca_rs::split::chunk("👍🏽👍🏽👍🏽", 1), ["👍🏽", "👍🏽", "👍🏽"]);
+}
+#[test]
+fn _chunk() {
+    assert_eq!("4111111111111111"._chunk(8), ["41111111", "11111111"]);
+}
+#[test]
+fn partition() {
+    assert_eq!(voca_rs::split::partition("", "="), ("", "", ""));
+    assert_eq!(voca_rs::split::partition("a=b", ""), ("a=b", "", ""));
+    assert_eq!(
+        voca_rs::split::partition("key=value=more", "="),
+        ("key", "=", "value=more")
+    );
+    assert_eq!(
+        voca_rs::split::partition("cafe\u{0301} and tea", "e"),
+        ("cafe\u{0301} and t", "e", "a")
+    );
+    assert_eq!(
+        voca_rs::split::partition("no separator", "="),
+        ("no separator", "", "")
+    );
+    assert_eq!(
+        voca_rs::split::partition("ключ::значение", "::"),
+        ("ключ", "::", "значение")
+    );
+}
+#[test]
+fn _partition() {
+    assert_eq!("key=value=more"._partition("="), ("key", "=", "value=more"));
+}
+#[test]
+fn rpartition() {
+    assert_eq!(voca_rs::split::rpartition("", "="), ("", "", ""));
+    assert_eq!(
+        voca_rs::split::rpartition("archive.tar.gz", "."),
+        ("archive.tar", ".", "gz")
+    );
+    assert_eq!(
+        voca_rs::split::rpartition("tea and cafe\u{0301}", "e"),
+        ("t", "e", "a and cafe\u{0301}")
+    );
+    assert_eq!(
+        voca_rs::split::rpartition("no separator", "="),
+        ("", "", "no separator")
+    );
+}
+#[test]
+fn _rpartition() {
+    assert_eq!(
+        "archive.tar.gz"._rpartition("."),
+        ("archive.tar", ".", "gz")
+    );
+}
+#[test]
+fn split_at_grapheme() {
+    assert_eq!(voca_rs::split::split_at_grapheme("", 1), ("", ""));
+    assert_eq!(voca_rs::split::split_at_grapheme("a̐éö̲", 0), ("", "a̐éö̲"));
+    assert_eq!(voca_rs::split::split_at_grapheme("a̐éö̲", 1), ("a̐", "éö̲"));
+    assert_eq!(voca_rs::split::split_at_grapheme("a̐éö̲", 3), ("a̐éö̲", ""));
+    assert_eq!(voca_rs::split::split_at_grapheme("rain", 10), ("rain", ""));
+}
+#[test]
+fn _split_at_grapheme() {
+    assert_eq!("a̐éö̲"._split_at_grapheme(1), ("a̐", "éö̲"));
+}
+#[test]
+fn split_at_width() {
+    assert_eq!(voca_rs::split::split_at_width("", 3), ("", ""));
+    assert_eq!(
+        voca_rs::split::split_at_width("日本語テキスト", 5),
+        ("日本", "語テキスト")
+    );
+    assert_eq!(
+        voca_rs::split::split_at_width("Hello world", 5),
+        ("Hello", " world")
+    );
+    assert_eq!(
+        voca_rs::split::split_at_width("cafe\u{0301}s", 4),
+        ("cafe\u{0301}", "s")
+    );
+    assert_eq!(voca_rs::split::split_at_width("日本", 1), ("", "日本"));
+    assert_eq!(voca_rs::split::split_at_width("rain", 0), ("", "rain"));
+    assert_eq!(voca_rs::split::split_at_width("rain", 10), ("rain", ""));
+}
+#[test]
+fn _split_at_width() {
+    assert_eq!("日本語テキスト"._split_at_width(5), ("日本", "語テキスト"));
+}
+#[test]
+fn char_ngrams() {
+    assert!(voca_rs::split::char_ngrams("", 2, "").is_empty());
+    assert!(voca_rs::split::char_ngrams("night", 0, "").is_empty());
+    assert!(voca_rs::split::char_ngrams("ab", 3, "").is_empty());
+    assert_eq!(
+        voca_rs::split::char_ngrams("night", 2, ""),
+        ["ni", "ig", "gh", "ht"]
+    );
+    assert_eq!(voca_rs::split::char_ngrams("ab", 1, "$"), ["a", "b"]);
+    assert_eq!(
+        voca_rs::split::char_ngrams("café", 3, "$"),
+        ["$$c", "$ca", "caf", "afé", "fé$", "é$$"]
+    );
+    assert_eq!(
+        voca_rs::split::char_ngrams("cafe\u{0301}", 2, " "),
+        [" c", "ca", "af", "fe\u{0301}", "e\u{0301} "]
+    );
+}
+#[test]
+fn _char_ngrams() {
+    assert_eq!("night"._char_ngrams(2, ""), ["ni", "ig", "gh", "ht"]);
+}
+#[test]
+fn word_ngrams() {
+    assert!(voca_rs::split::word_ngrams("", 2).is_empty());
+    assert!(voca_rs::split::word_ngrams("one two", 0).is_empty());
+    assert!(voca_rs::split::word_ngrams("one two", 3).is_empty());
+    assert_eq!(
+        voca_rs::split::word_ngrams("the quick, brown fox!", 2),
+        ["the quick", "quick brown", "brown fox"]
+    );
+    assert_eq!(
+        voca_rs::split::word_ngrams("Sześć звёзд", 1),
+        ["Sześć", "звёзд"]
+    );
+}
+#[test]
+fn _word_ngrams() {
+    assert_eq!(
+        "the quick brown fox"._word_ngrams(3),
+        ["the quick brown", "quick brown fox"]
+    );
+}
+#[test]
+fn shingles() {
+    use std::collections::HashSet;
+    assert!(voca_rs::split::shingles("", 2).is_empty());
+    assert!(voca_rs::split::shingles("one two", 0).is_empty());
+    let a = voca_rs::split::shingles("A rose is a rose is a rose", 3);
+    let b = voca_rs::split::shingles("A rose is a flower", 3);
+    assert_eq!(
+        a,
+        ["a rose is", "rose is a", "is a rose"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<HashSet<_>>()
+    );
+    assert_eq!(
+        a.intersection(&b).count() as f64 / a.union(&b).count() as f64,
+        0.5
+    );
+    assert_eq!(
+        voca_rs::split::shingles("Hello World", 5),
+        ["hello world"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<HashSet<_>>()
+    );
+}
+#[test]
+fn _shingles() {
+    assert_eq!("A rose is a rose"._shingles(4).len(), 2);
+}
+#[test]
+fn line_breaks() {
+    assert_eq!(voca_rs::split::line_breaks(""), []);
+    assert_eq!(voca_rs::split::line_breaks("Hello"), []);
+    assert_eq!(voca_rs::split::line_breaks("Hello world"), [(6, false)]);
+    assert_eq!(voca_rs::split::line_breaks("Hello  world"), [(7, false)]);
+    assert_eq!(
+        voca_rs::split::line_breaks("one-two\nthree"),
+        [(4, false), (8, true)]
+    );
+    assert_eq!(voca_rs::split::line_breaks("a\r\nb"), [(3, true)]);
+    assert_eq!(
+        voca_rs::split::line_breaks("日本語。"),
+        [(3, false), (6, false)]
+    );
+    // small kana and prolonged sound mark do not start a line
+    assert_eq!(voca_rs::split::line_breaks("キャー"), []);
+    // no break before closing or after opening punctuation
+    assert_eq!(
+        voca_rs::split::line_breaks("(a b) c"),
+        [(3, false), (6, false)]
+    );
+    assert_eq!(
+        voca_rs::split::line_breaks("\"Hi!\" he said."),
+        [(6, false), (9, false)]
+    );
+    // numbers with prefixes and postfixes
+    assert_eq!(
+        voca_rs::split::line_breaks("$100 50% 3.14"),
+        [(5, false), (9, false)]
+    );
+    assert_eq!(voca_rs::split::line_breaks("-5"), []);
+    // no-break space, word joiner and zero width space
+    assert_eq!(voca_rs::split::line_breaks("10\u{A0}km"), []);
+    assert_eq!(voca_rs::split::line_breaks("a\u{2060}b"), []);
+    assert_eq!(voca_rs::split::line_breaks("a\u{200B}b"), [(4, false)]);
+    // soft hyphen and URLs
+    assert_eq!(voca_rs::split::line_breaks("hy\u{AD}phen"), [(4, false)]);
+    assert_eq!(
+        voca_rs::split::line_breaks("https://example.com/path"),
+        [(8, false), (20, false)]
+    );
+    // combining marks, emoji and flags
+    assert_eq!(voca_rs::split::line_breaks("cafe\u{301} bar"), [(7, false)]);
+    assert_eq!(voca_rs::split::line_breaks("👍🏽👨‍👩‍👧"), [(8, false)]);
+    assert_eq!(voca_rs::split::line_breaks("🇺🇦🇺🇦"), [(8, false)]);
+    // Hangul syllables break like ideographs
+    assert_eq!(
+        voca_rs::split::line_breaks("한국어 텍스트"),
+        [
+            (3, false),
+            (6, false),
+            (10, false),
+            (13, false),
+            (16, false)
+        ]
+    );
+}
+#[test]
+fn _line_breaks() {
+    assert_eq!("Hello world"._line_breaks(), [(6, false)]);
+}
+#[test]
 fn code_points() {
     assert_eq!(voca_rs::split::code_points(""), []);
     assert_eq!(voca_rs::split::code_points("rain"), [114, 97, 105, 110]);
@@ -101,3 +735,69 @@ fn code_points() {
 fn _code_points() {
     assert_eq!("rain"._code_points(), [114, 97, 105, 110]);
 }
+#[test]
+fn lines() {
+    assert!(voca_rs::split::lines("", false).is_empty());
+    assert_eq!(
+        voca_rs::split::lines("one\r\ntwo\nthree", false),
+        ["one", "two", "three"]
+    );
+    assert_eq!(
+        voca_rs::split::lines("one\r\ntwo\n\nthree\n", true),
+        ["one\r\n", "two\n", "\n", "three\n"]
+    );
+    assert_eq!(
+        voca_rs::split::lines("one\rtwo\r\r\n", false),
+        ["one", "two", ""]
+    );
+    assert_eq!(voca_rs::split::lines("\n", false), [""]);
+    assert_eq!(voca_rs::split::lines("über\nmaß", true), ["über\n", "maß"]);
+}
+#[test]
+fn _lines() {
+    assert_eq!("one\r\ntwo\nthree"._lines(false), ["one", "two", "three"]);
+}
+#[test]
+fn filter_where() {
+    use voca_rs::split::Unit;
+    assert_eq!(
+        voca_rs::split::filter_where("", Unit::Char, |_c| true),
+        Vec::<&str>::new()
+    );
+    assert_eq!(
+        voca_rs::split::filter_where("a1b2c3", Unit::Char, voca_rs::query::is_digit),
+        ["1", "2", "3"]
+    );
+    assert_eq!(
+        voca_rs::split::filter_where("cafe\u{0301}s", Unit::Grapheme, |g| g.len() > 1),
+        ["e\u{0301}"]
+    );
+    let stop_words = ["the", "a"];
+    assert_eq!(
+        voca_rs::split::filter_where("the cat saw a dog", Unit::Word, |w| !stop_words
+            .contains(&w)),
+        ["cat", "saw", "dog"]
+    );
+}
+#[test]
+fn _filter_where() {
+    use voca_rs::split::Unit;
+    assert_eq!(
+        "a1b2c3"._filter_where(Unit::Char, &voca_rs::query::is_digit),
+        ["1", "2", "3"]
+    );
+}
+#[test]
+fn emoji() {
+    assert_eq!(voca_rs::split::emoji(""), []);
+    assert_eq!(voca_rs::split::emoji("no emoji #1"), []);
+    assert_eq!(
+        voca_rs::split::emoji("Hi 👋🏽, nice 🎉"),
+        [(3, "👋🏽"), (18, "🎉")]
+    );
+    assert_eq!(voca_rs::split::emoji("👨‍👩‍👧🇺🇦"), [(0, "👨‍👩‍👧"), (18, "🇺🇦")]);
+}
+#[test]
+fn _emoji() {
+    assert_eq!("Hi 👋🏽"._emoji(), [(3, "👋🏽")]);
+}
@@ -226,6 +226,29 @@ fn _strip_tags() {
         "Summer is nice"
     );
 }
+#[test]
+fn strip_trailing_whitespace() {
+    assert_eq!(voca_rs::strip::strip_trailing_whitespace(""), "");
+    assert_eq!(
+        voca_rs::strip::strip_trailing_whitespace("summer  \r\nis nice\t\n"),
+        "summer\r\nis nice\n"
+    );
+    assert_eq!(
+        voca_rs::strip::strip_trailing_whitespace("  \n\u{3000}summer\u{3000}\r"),
+        "\n\u{3000}summer\r"
+    );
+    assert_eq!(
+        voca_rs::strip::strip_trailing_whitespace("no change"),
+        "no change"
+    );
+}
+#[test]
+fn _strip_trailing_whitespace() {
+    assert_eq!(
+        "summer  \nis nice\t"._strip_trailing_whitespace(),
+        "summer\nis nice"
+    );
+}
 
 #[test]
 fn partial_directive() {
@@ -240,3 +263,52 @@ fn partial_directive() {
     assert_eq!(voca_rs::strip::strip_tags(">天地不仁<"), ">天地不仁");
     assert_eq!(voca_rs::strip::strip_tags("\u{00a0}<!"), "\u{a0}");
 }
+#[test]
+fn strip_bidi_controls() {
+    assert_eq!(voca_rs::strip::strip_bidi_controls(""), "");
+    assert_eq!(
+        voca_rs::strip::strip_bidi_controls("access level"),
+        "access level"
+    );
+    assert_eq!(
+        voca_rs::strip::strip_bidi_controls("access\u{202E}level"),
+        "accesslevel"
+    );
+    assert_eq!(
+        voca_rs::strip::strip_bidi_controls("\u{2067}שלום\u{2069} world\u{200E}"),
+        "שלום world"
+    );
+    assert_eq!(
+        voca_rs::strip::strip_bidi_controls("zero\u{200B}width"),
+        "zero\u{200B}width"
+    );
+}
+#[test]
+fn _strip_bidi_controls() {
+    assert_eq!("access\u{202E}level"._strip_bidi_controls(), "accesslevel");
+}
+#[test]
+fn strip_emoji() {
+    assert_eq!(voca_rs::strip::strip_emoji(""), "");
+    assert_eq!(
+        voca_rs::strip::strip_emoji("Great job 👍🏽🎉!"),
+        "Great job !"
+    );
+    assert_eq!(voca_rs::strip::strip_emoji("#1 ©"), "#1 ©");
+    assert_eq!(voca_rs::strip::strip_emoji("👨‍👩‍👧🇺🇦1️⃣"), "");
+}
+#[test]
+fn _strip_emoji() {
+    assert_eq!("I ❤️ Rust"._strip_emoji(), "I  Rust");
+}
+#[test]
+fn strip_skin_tones() {
+    assert_eq!(voca_rs::strip::strip_skin_tones(""), "");
+    assert_eq!(voca_rs::strip::strip_skin_tones("👍🏽 👋🏿"), "👍 👋");
+    assert_eq!(voca_rs::strip::strip_skin_tones("👩🏻‍💻"), "👩‍💻");
+    assert_eq!(voca_rs::strip::strip_skin_tones("👍"), "👍");
+}
+#[test]
+fn _strip_skin_tones() {
+    assert_eq!("👍🏽"._strip_skin_tones(), "👍");
+}
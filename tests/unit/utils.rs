@@ -102,3 +102,53 @@ fn test_unidecode_char() {
     assert_eq!(voca_rs::utils::unidecode_char('亰'), "Jing ");
     assert_eq!(voca_rs::utils::unidecode_char('ᔕ'), "sha");
 }
+#[test]
+fn fold() {
+    let options = voca_rs::utils::MatchOptions::insensitive();
+    assert_eq!(voca_rs::utils::fold("", &options), "");
+    assert_eq!(voca_rs::utils::fold("Straße", &options), "strasse");
+    assert_eq!(voca_rs::utils::fold("STRAẞE", &options), "strasse");
+    assert_eq!(voca_rs::utils::fold("ﬁne", &options), "fine");
+    assert_eq!(voca_rs::utils::fold("Ｃafe\u{0301}", &options), "cafe");
+    assert_eq!(voca_rs::utils::fold("Zażółć", &options), "zazolc");
+    assert_eq!(voca_rs::utils::fold("ΌΣΟΣ", &options), "οσοσ");
+    assert_eq!(voca_rs::utils::fold("ὅσος", &options), "οσοσ");
+    assert_eq!(voca_rs::utils::fold("Ёлка", &options), "елка");
+    assert_eq!(voca_rs::utils::fold("שָׁלוֹם", &options), "שלום");
+    assert_eq!(voca_rs::utils::fold("مَرْحَبًا", &options), "مرحبا");
+    assert_eq!(voca_rs::utils::fold("İstanbul", &options), "istanbul");
+    assert_eq!(voca_rs::utils::fold("क्षत्रिय", &options), "क्षत्रिय");
+    let case_only = voca_rs::utils::MatchOptions {
+        ignore_case: true,
+        ..Default::default()
+    };
+    // the full case folding differs from lowercasing the uppercase
+    assert_eq!(voca_rs::utils::fold("ẞ", &case_only), "ss");
+    assert_eq!(voca_rs::utils::fold("ı", &case_only), "ı");
+    assert_eq!(voca_rs::utils::fold("İ", &case_only), "i\u{0307}");
+    assert_eq!(voca_rs::utils::fold("ꭰꮿ", &case_only), "ᎠᏯ");
+    assert_eq!(voca_rs::utils::fold("ᎠᏯ", &case_only), "ᎠᏯ");
+    assert_eq!(voca_rs::utils::fold("ὈΔΥΣΣΕΎΣ", &case_only), "ὀδυσσεύσ");
+    // the letters added in Unicode 15.0 and 16.0
+    assert_eq!(
+        voca_rs::utils::fold("\u{A7CB}\u{A7DC}", &case_only),
+        "\u{0264}\u{019B}"
+    );
+    assert_eq!(voca_rs::utils::fold("\u{10D50}", &case_only), "\u{10D70}");
+    assert_eq!(voca_rs::utils::fold("ὀδυσσεύς", &case_only), "ὀδυσσεύσ");
+    assert_eq!(voca_rs::utils::fold("ﬅ", &case_only), "st");
+    assert_eq!(voca_rs::utils::fold("ǅ", &case_only), "ǆ");
+    assert_eq!(voca_rs::utils::fold("ᾈ", &case_only), "ἀι");
+    assert_eq!(
+        voca_rs::utils::fold("Straße", &voca_rs::utils::MatchOptions::default()),
+        "Straße"
+    );
+    let width_only = voca_rs::utils::MatchOptions {
+        ignore_width: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        voca_rs::utils::fold("ＡＢＣ\u{3000}１２３", &width_only),
+        "ABC 123"
+    );
+}